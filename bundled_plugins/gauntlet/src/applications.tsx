@@ -44,6 +44,10 @@ export default async function Applications(context: GeneratorContext<object, Ent
                             ),
                             accessories: applicationAccessories(id, experimentalWindowTracking),
                             icon: data.icon, // TODO lazy icons
+                            preview: applicationPreview(data.name, [
+                                ["Desktop File", data.desktop_file_path],
+                                ["Window Class", data.startup_wm_class],
+                            ]),
                             "__linux__": {
                                 startupWmClass: data.startup_wm_class,
                                 desktopFilePath: data.desktop_file_path
@@ -62,6 +66,10 @@ export default async function Applications(context: GeneratorContext<object, Ent
                             ),
                             accessories: applicationAccessories(id, experimentalWindowTracking),
                             icon: data.icon, // TODO lazy icons
+                            preview: applicationPreview(data.name, [
+                                ["Desktop File", data.desktop_file_path],
+                                ["Window Class", data.startup_wm_class],
+                            ]),
                             "__linux__": {
                                 startupWmClass: data.startup_wm_class,
                                 desktopFilePath: data.desktop_file_path
@@ -176,6 +184,7 @@ export default async function Applications(context: GeneratorContext<object, Ent
                         }
                     ],
                     icon: data.icon,
+                    preview: applicationPreview(data.name, [["Path", data.path]]),
                 }),
                 add,
                 remove,
@@ -197,6 +206,7 @@ export default async function Applications(context: GeneratorContext<object, Ent
                         }
                     ],
                     icon: data.icon,
+                    preview: applicationPreview(data.name, [["Path", data.path]]),
                 }),
                 add,
                 remove,
@@ -206,6 +216,15 @@ export default async function Applications(context: GeneratorContext<object, Ent
     }
 }
 
+// shown in preview pane of main view, details without value are skipped
+function applicationPreview(name: string, details: [string, string | undefined][]): string {
+    const lines = details
+        .filter(([_label, value]) => !!value)
+        .map(([label, value]) => `- ${label}: ${value}`);
+
+    return [`## ${name}`, "", ...lines].join("\n")
+}
+
 async function genericGenerator<DATA>(
     directoriesToWatch: string[],
     appFromPath: (path: string) => Promise<undefined | DesktopPathAction<DATA>>,
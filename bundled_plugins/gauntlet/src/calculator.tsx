@@ -2,10 +2,22 @@ import { Action, ActionPanel, Content, Icons, Inline } from "@project-gauntlet/a
 import { ReactNode } from "react";
 import { Clipboard, showHud } from "@project-gauntlet/api/helpers";
 import { run_numbat } from "gauntlet:bridge/internal-all";
+import { ColorConversion, parseColor } from "./color";
+import { isNowPlayingQuery, NowPlayingView } from "./media";
 
 export default function Calculator(props: { text: string }): ReactNode | undefined {
     const text = props.text;
 
+    // only one inline view per plugin is supported, so color conversion and media controls are shown by the same entrypoint
+    const color = parseColor(text);
+    if (color) {
+        return <ColorConversion color={color}/>
+    }
+
+    if (isNowPlayingQuery(text)) {
+        return <NowPlayingView/>
+    }
+
     if (text.length < 3) {
         return undefined
     }
@@ -0,0 +1,183 @@
+import { Action, ActionPanel, Content, Icons, Inline } from "@project-gauntlet/api/components";
+import { ReactElement } from "react";
+import { Clipboard, showHud } from "@project-gauntlet/api/helpers";
+
+export type Rgb = { red: number, green: number, blue: number }
+
+const hexPattern = /^#([0-9a-f]{3}|[0-9a-f]{6})$/i;
+const rgbPattern = /^rgba?\(\s*(\d{1,3})\s*,\s*(\d{1,3})\s*,\s*(\d{1,3})\s*(?:,\s*[\d.]+%?\s*)?\)$/i;
+const hslPattern = /^hsla?\(\s*([\d.]+)(?:deg)?\s*,\s*([\d.]+)%\s*,\s*([\d.]+)%\s*(?:,\s*[\d.]+%?\s*)?\)$/i;
+
+// "#ff0000", "#f00", "rgb(255, 0, 0)" or "hsl(0, 100%, 50%)"
+export function parseColor(text: string): Rgb | undefined {
+    text = text.trim();
+
+    const hex = hexPattern.exec(text);
+    if (hex) {
+        const value = hex[1].length === 3
+            ? hex[1].split("").map(char => char + char).join("")
+            : hex[1];
+
+        return {
+            red: parseInt(value.slice(0, 2), 16),
+            green: parseInt(value.slice(2, 4), 16),
+            blue: parseInt(value.slice(4, 6), 16),
+        }
+    }
+
+    const rgb = rgbPattern.exec(text);
+    if (rgb) {
+        const [red, green, blue] = rgb.slice(1, 4).map(value => parseInt(value, 10));
+
+        if (red > 255 || green > 255 || blue > 255) {
+            return undefined
+        }
+
+        return { red, green, blue }
+    }
+
+    const hsl = hslPattern.exec(text);
+    if (hsl) {
+        const [hue, saturation, lightness] = hsl.slice(1, 4).map(value => parseFloat(value));
+
+        if (saturation > 100 || lightness > 100) {
+            return undefined
+        }
+
+        return hslToRgb(hue % 360, saturation / 100, lightness / 100)
+    }
+
+    return undefined
+}
+
+export function formatHex({ red, green, blue }: Rgb): string {
+    return "#" + [red, green, blue]
+        .map(value => value.toString(16).padStart(2, "0"))
+        .join("")
+}
+
+function formatRgb({ red, green, blue }: Rgb): string {
+    return `rgb(${red}, ${green}, ${blue})`
+}
+
+function formatHsl(color: Rgb): string {
+    const { hue, saturation, lightness } = rgbToHsl(color);
+
+    return `hsl(${Math.round(hue)}, ${Math.round(saturation * 100)}%, ${Math.round(lightness * 100)}%)`
+}
+
+function hslToRgb(hue: number, saturation: number, lightness: number): Rgb {
+    const chroma = (1 - Math.abs(2 * lightness - 1)) * saturation;
+    const x = chroma * (1 - Math.abs((hue / 60) % 2 - 1));
+    const m = lightness - chroma / 2;
+
+    const [red, green, blue] =
+        hue < 60 ? [chroma, x, 0] :
+        hue < 120 ? [x, chroma, 0] :
+        hue < 180 ? [0, chroma, x] :
+        hue < 240 ? [0, x, chroma] :
+        hue < 300 ? [x, 0, chroma] :
+        [chroma, 0, x];
+
+    return {
+        red: Math.round((red + m) * 255),
+        green: Math.round((green + m) * 255),
+        blue: Math.round((blue + m) * 255),
+    }
+}
+
+function rgbToHsl({ red, green, blue }: Rgb): { hue: number, saturation: number, lightness: number } {
+    const r = red / 255;
+    const g = green / 255;
+    const b = blue / 255;
+
+    const max = Math.max(r, g, b);
+    const min = Math.min(r, g, b);
+    const delta = max - min;
+
+    const lightness = (max + min) / 2;
+
+    if (delta === 0) {
+        return { hue: 0, saturation: 0, lightness }
+    }
+
+    const saturation = delta / (1 - Math.abs(2 * lightness - 1));
+
+    const hue =
+        max === r ? 60 * (((g - b) / delta) % 6) :
+        max === g ? 60 * ((b - r) / delta + 2) :
+        60 * ((r - g) / delta + 4);
+
+    return { hue: hue < 0 ? hue + 360 : hue, saturation, lightness }
+}
+
+// image widget only accepts urls and assets, so swatch is a small uncompressed bmp encoded as data url
+function swatchDataUrl({ red, green, blue }: Rgb): string {
+    const size = 32;
+    const rowSize = size * 3; // multiple of 4, so rows don't need padding
+    const headerSize = 54;
+
+    const bytes = new Uint8Array(headerSize + rowSize * size);
+    const view = new DataView(bytes.buffer);
+
+    // file header
+    bytes[0] = 0x42; // "B"
+    bytes[1] = 0x4D; // "M"
+    view.setUint32(2, bytes.length, true);
+    view.setUint32(10, headerSize, true);
+
+    // info header
+    view.setUint32(14, 40, true);
+    view.setInt32(18, size, true);
+    view.setInt32(22, size, true);
+    view.setUint16(26, 1, true); // color planes
+    view.setUint16(28, 24, true); // bits per pixel
+    view.setUint32(34, rowSize * size, true);
+
+    for (let index = headerSize; index < bytes.length; index += 3) {
+        bytes[index] = blue;
+        bytes[index + 1] = green;
+        bytes[index + 2] = red;
+    }
+
+    return "data:image/bmp;base64," + btoa(String.fromCharCode(...bytes))
+}
+
+export function ColorConversion(props: { color: Rgb }): ReactElement {
+    const formats = [
+        { name: "HEX", value: formatHex(props.color) },
+        { name: "RGB", value: formatRgb(props.color) },
+        { name: "HSL", value: formatHsl(props.color) },
+    ];
+
+    return (
+        <Inline
+            actions={
+                <ActionPanel>
+                    {formats.map(format => (
+                        <Action
+                            key={format.name}
+                            label={`Copy ${format.name}`}
+                            onAction={async () => {
+                                await Clipboard.writeText(format.value)
+                                showHud(`${format.name} color copied`)
+                            }}
+                        />
+                    ))}
+                </ActionPanel>
+            }
+        >
+            <Inline.Left>
+                <Content.Image source={{ url: swatchDataUrl(props.color) }}/>
+            </Inline.Left>
+            <Inline.Separator icon={Icons.ArrowRight}/>
+            <Inline.Right>
+                {formats.map(format => (
+                    <Content.Paragraph key={format.name}>
+                        {format.value}
+                    </Content.Paragraph>
+                ))}
+            </Inline.Right>
+        </Inline>
+    )
+}
@@ -0,0 +1,94 @@
+import { Action, ActionPanel, Content, Icons, Inline } from "@project-gauntlet/api/components";
+import { ReactElement, useEffect, useState } from "react";
+import { showHud } from "@project-gauntlet/api/helpers";
+import { media_control, media_now_playing } from "gauntlet:bridge/internal-all";
+
+const REFRESH_INTERVAL_MS = 1000;
+
+const keywords = ["now playing", "media", "music", "play", "pause", "next", "previous"];
+
+// inline view is shown when query looks like beginning of one of the keywords
+export function isNowPlayingQuery(text: string): boolean {
+    const query = text.trim().toLowerCase();
+
+    if (query.length < 3) {
+        return false
+    }
+
+    return keywords.some(keyword => keyword.startsWith(query))
+}
+
+export function NowPlayingView(): ReactElement | null {
+    const [nowPlaying, setNowPlaying] = useState<NowPlaying | null>(null);
+
+    useEffect(() => {
+        let cancelled = false;
+
+        const refresh = async () => {
+            try {
+                const nowPlaying = await media_now_playing();
+
+                if (!cancelled) {
+                    setNowPlaying(nowPlaying)
+                }
+            } catch (e) {
+                console.error("Unable to get currently playing media", e)
+            }
+        };
+
+        refresh();
+
+        const interval = setInterval(refresh, REFRESH_INTERVAL_MS);
+
+        return () => {
+            cancelled = true;
+            clearInterval(interval)
+        }
+    }, []);
+
+    // nothing is rendered, so inline view is not shown
+    if (!nowPlaying) {
+        return null
+    }
+
+    const control = async (command: MediaCommand) => {
+        try {
+            await media_control(nowPlaying.player, command);
+
+            setNowPlaying(await media_now_playing())
+        } catch (e) {
+            showHud(`Unable to control media player: ${e}`)
+        }
+    };
+
+    const subtitle = [nowPlaying.artist, nowPlaying.album]
+        .filter(value => !!value)
+        .join(" - ");
+
+    // only remote images can be shown, local files are skipped
+    const artwork = nowPlaying.artwork_url && /^(https?|data):/.test(nowPlaying.artwork_url)
+        ? { url: nowPlaying.artwork_url }
+        : Icons.Music;
+
+    return (
+        <Inline
+            actions={
+                <ActionPanel>
+                    <Action label={nowPlaying.playing ? "Pause" : "Play"} onAction={() => control("play-pause")}/>
+                    <Action label="Next Track" onAction={() => control("next")}/>
+                    <Action label="Previous Track" onAction={() => control("previous")}/>
+                </ActionPanel>
+            }
+        >
+            <Inline.Center>
+                <Content.MediaItem
+                    title={nowPlaying.title}
+                    subtitle={subtitle || undefined}
+                    artwork={artwork}
+                    position={nowPlaying.position}
+                    duration={nowPlaying.duration}
+                />
+            </Inline.Center>
+        </Inline>
+    )
+}
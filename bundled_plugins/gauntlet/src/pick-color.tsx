@@ -0,0 +1,19 @@
+import { showHud } from "@project-gauntlet/api/helpers";
+import { pick_color_from_screen, show_window_with_search_query } from "gauntlet:bridge/internal-all";
+
+export default async function PickColor(): Promise<void> {
+    let color;
+
+    try {
+        color = await pick_color_from_screen();
+    } catch (e) {
+        console.error("Unable to pick color from screen", e);
+        showHud(`Unable to pick color: ${e}`);
+        return
+    }
+
+    if (color) {
+        // picked color is shown with its conversions by inline view
+        await show_window_with_search_query(color)
+    }
+}
@@ -0,0 +1,137 @@
+import React, { ReactElement, useEffect, useState } from "react";
+import { Action, ActionPanel, List, TextAccessory } from "@project-gauntlet/api/components";
+import { showHud } from "@project-gauntlet/api/helpers";
+import { process_kill, process_list, process_terminate } from "gauntlet:bridge/internal-all";
+
+const REFRESH_INTERVAL_MS = 2000;
+const MAX_SHOWN_PROCESSES = 200;
+
+export default function Processes(): ReactElement {
+    const [processes, setProcesses] = useState<ProcessInfo[]>([]);
+    const [searchText, setSearchText] = useState<string | undefined>("");
+
+    useEffect(() => {
+        const refresh = () => setProcesses(process_list());
+
+        refresh();
+
+        const interval = setInterval(refresh, REFRESH_INTERVAL_MS);
+
+        return () => clearInterval(interval)
+    }, []);
+
+    const signal = (id: string | undefined, force: boolean) => {
+        if (!id) {
+            return
+        }
+
+        const pid = Number(id);
+        const process = processes.find(process => process.pid === pid);
+        const name = process?.name ?? id;
+
+        try {
+            if (force) {
+                process_kill(pid)
+            } else {
+                process_terminate(pid)
+            }
+
+            showHud(`${force ? "Killed" : "Terminated"} ${name}`)
+        } catch (e) {
+            showHud(`Unable to stop ${name}: ${e}`)
+        }
+
+        setProcesses(process_list())
+    };
+
+    const query = (searchText ?? "").trim().toLowerCase();
+
+    const shown = processes
+        .map(process => ({ process, score: fuzzyScore(process.name.toLowerCase(), query) }))
+        .filter(({ score }) => score !== undefined)
+        .sort((a, b) => (b.score! - a.score!) || (b.process.cpu_usage - a.process.cpu_usage))
+        .slice(0, MAX_SHOWN_PROCESSES)
+        .map(({ process }) => process);
+
+    return (
+        <List
+            isLoading={processes.length === 0}
+            actions={
+                <ActionPanel>
+                    <Action label="Terminate process" onAction={id => signal(id, false)}/>
+                    <Action label="Force kill process" onAction={id => signal(id, true)}/>
+                </ActionPanel>
+            }
+        >
+            <List.SearchBar
+                placeholder="Search processes by name..."
+                value={searchText}
+                onChange={setSearchText}
+            />
+            <List.Columns>
+                <List.Columns.Column title="Name"/>
+                <List.Columns.Column title="CPU"/>
+                <List.Columns.Column title="Memory"/>
+            </List.Columns>
+            {
+                shown.map(process => (
+                    <List.Item
+                        key={process.pid}
+                        id={`${process.pid}`}
+                        title={process.name}
+                        subtitle={`PID ${process.pid}`}
+                        accessories={[
+                            <TextAccessory text={formatCpu(process.cpu_usage)}/>,
+                            <TextAccessory text={formatMemory(process.memory)}/>
+                        ]}
+                    />
+                ))
+            }
+        </List>
+    )
+}
+
+// subsequence match, consecutive and early matches score higher. undefined if not matching
+export function fuzzyScore(text: string, query: string): number | undefined {
+    if (query.length === 0) {
+        return 0
+    }
+
+    let score = 0;
+    let textIndex = 0;
+    let previousMatch = -2;
+
+    for (const char of query) {
+        const found = text.indexOf(char, textIndex);
+        if (found === -1) {
+            return undefined
+        }
+
+        score += found === previousMatch + 1 ? 3 : 1;
+        if (found === 0) {
+            score += 2;
+        }
+
+        previousMatch = found;
+        textIndex = found + 1;
+    }
+
+    return score
+}
+
+function formatCpu(cpuUsage: number): string {
+    return `${cpuUsage.toFixed(1)}%`
+}
+
+function formatMemory(bytes: number): string {
+    const units = ["B", "KB", "MB", "GB", "TB"];
+
+    let value = bytes;
+    let unit = 0;
+    while (value >= 1024 && unit < units.length - 1) {
+        value /= 1024;
+        unit++;
+    }
+
+    return `${value.toFixed(unit === 0 ? 0 : 1)} ${units[unit]}`
+}
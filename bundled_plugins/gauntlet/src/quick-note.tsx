@@ -0,0 +1,68 @@
+import { ReactElement, useState } from "react";
+import { Action, ActionPanel, Form } from "@project-gauntlet/api/components";
+import { useEntrypointPreferences } from "@project-gauntlet/api/hooks";
+import { showHud } from "@project-gauntlet/api/helpers";
+import { hide_window, quick_note_append, quick_note_run_hook } from "gauntlet:bridge/internal-all";
+
+interface QuickNotePreferences {
+    notesFile: string
+    noteHook: string
+}
+
+// when hook is set, note is handed to that command as is, without timestamp, and notes file is not written.
+// typing starts right away without focusing the text area, "Enter" saves the note.
+// when text area is focused "Enter" inserts new line and note is saved with "Ctrl+Enter"
+export default function QuickNote(): ReactElement {
+    const { notesFile, noteHook } = useEntrypointPreferences<QuickNotePreferences>();
+    const [note, setNote] = useState<string | undefined>("");
+
+    const save = async (value: string | undefined) => {
+        const text = (value ?? "").trim();
+
+        if (text.length === 0) {
+            showHud("Note is empty");
+            return
+        }
+
+        try {
+            if (noteHook.trim().length !== 0) {
+                await quick_note_run_hook(noteHook, text);
+            } else {
+                quick_note_append(notesFile, `\n## ${formatTimestamp(new Date())}\n\n${text}\n`);
+            }
+        } catch (e) {
+            console.error("Unable to save note", e);
+            showHud(`Unable to save note: ${e}`);
+            return
+        }
+
+        showHud("Note saved");
+
+        // onSubmit of text area doesn't support closing the window by returning result like actions do
+        await hide_window()
+    };
+
+    return (
+        <Form
+            actions={
+                <ActionPanel>
+                    <Action label="Save Note" onAction={() => save(note)}/>
+                </ActionPanel>
+            }
+        >
+            <Form.TextArea
+                placeholder="Start typing..."
+                value={note}
+                onChange={setNote}
+                onSubmit={save}
+            />
+        </Form>
+    )
+}
+
+// "2024-01-31 14:05", in local time
+function formatTimestamp(date: Date): string {
+    const pad = (value: number) => value.toString().padStart(2, "0");
+
+    return `${date.getFullYear()}-${pad(date.getMonth() + 1)}-${pad(date.getDate())} ${pad(date.getHours())}:${pad(date.getMinutes())}`
+}
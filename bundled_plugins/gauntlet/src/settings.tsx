@@ -1,5 +1,5 @@
 import { open_settings } from "gauntlet:bridge/internal-all";
 
-export default function Settings(): void {
-    open_settings()
+export default async function Settings(): Promise<void> {
+    await open_settings()
 }
@@ -0,0 +1,201 @@
+import React, { ReactElement, useEffect, useRef, useState } from "react";
+import { Action, ActionPanel, Content, Detail, Form } from "@project-gauntlet/api/components";
+import { useEntrypointPreferences, useNavigation } from "@project-gauntlet/api/hooks";
+import { Clipboard, showHud } from "@project-gauntlet/api/helpers";
+import { shell_command_kill, shell_command_poll, shell_command_run } from "gauntlet:bridge/internal-all";
+
+const POLL_INTERVAL_MS = 100;
+
+// commands matching these patterns require explicit confirmation before running
+const DESTRUCTIVE_PATTERNS: RegExp[] = [
+    /\brm\s+(-[a-zA-Z]*[rf][a-zA-Z]*\s+)+/,
+    /\bmkfs(\.\w+)?\b/,
+    /\bdd\s+.*\bof=/,
+    /\b(shutdown|reboot|poweroff|halt)\b/,
+    /\bchmod\s+-R\b/,
+    /\bchown\s+-R\b/,
+    />\s*\/dev\/(sd|nvme|hd|disk)/,
+    /:\(\)\s*\{.*\};\s*:/,
+    /\bgit\s+(reset\s+--hard|clean\s+-[a-zA-Z]*f)/,
+    /\b(del|rd|rmdir)\s+\/[sq]/i,
+    /\bformat\s+[a-z]:/i,
+];
+
+export function isDestructiveCommand(command: string): boolean {
+    return DESTRUCTIVE_PATTERNS.some(pattern => pattern.test(command))
+}
+
+interface ShellPreferences {
+    enabled: boolean
+}
+
+export default function ShellCommand(): ReactElement {
+    const { enabled } = useEntrypointPreferences<ShellPreferences>();
+    const { pushView } = useNavigation();
+    const [command, setCommand] = useState<string>("");
+
+    if (!enabled) {
+        return (
+            <Detail>
+                <Detail.Content>
+                    <Detail.Content.H3>
+                        Running shell commands is disabled
+                    </Detail.Content.H3>
+                    <Detail.Content.Paragraph>
+                        Enable "Allow running shell commands" in preferences of this entrypoint in Gauntlet Settings
+                    </Detail.Content.Paragraph>
+                </Detail.Content>
+            </Detail>
+        )
+    }
+
+    const run = () => {
+        const trimmed = command.trim();
+        if (trimmed.length === 0) {
+            return
+        }
+
+        if (isDestructiveCommand(trimmed)) {
+            pushView(<ShellCommandConfirmation command={trimmed}/>)
+        } else {
+            pushView(<ShellCommandOutput command={trimmed}/>)
+        }
+    };
+
+    return (
+        <Form
+            actions={
+                <ActionPanel>
+                    <Action label="Run command" onAction={run}/>
+                </ActionPanel>
+            }
+        >
+            <Form.TextField
+                label="Command"
+                value={command}
+                onChange={value => setCommand(value ?? "")}
+            />
+        </Form>
+    )
+}
+
+function ShellCommandConfirmation({ command }: { command: string }): ReactElement {
+    const { popView, pushView } = useNavigation();
+
+    return (
+        <Detail
+            actions={
+                <ActionPanel>
+                    <Action label="Cancel" onAction={() => popView()}/>
+                    <Action label="Run anyway" onAction={() => pushView(<ShellCommandOutput command={command}/>)}/>
+                </ActionPanel>
+            }
+        >
+            <Detail.Content>
+                <Detail.Content.H3>
+                    This command looks destructive. Are you sure you want to run it?
+                </Detail.Content.H3>
+                <Detail.Content.CodeBlock>
+                    {command}
+                </Detail.Content.CodeBlock>
+            </Detail.Content>
+        </Detail>
+    )
+}
+
+function ShellCommandOutput({ command }: { command: string }): ReactElement {
+    const [lines, setLines] = useState<ShellOutputLine[]>([]);
+    const [exitCode, setExitCode] = useState<number | undefined>(undefined);
+    const [finished, setFinished] = useState<boolean>(false);
+    const [error, setError] = useState<string | undefined>(undefined);
+    const commandId = useRef<number | undefined>(undefined);
+
+    useEffect(() => {
+        let id: number;
+        try {
+            id = shell_command_run(command);
+        } catch (e) {
+            setError(String(e));
+            setFinished(true);
+            return
+        }
+
+        commandId.current = id;
+
+        const interval = setInterval(() => {
+            try {
+                const poll = shell_command_poll(id);
+
+                if (poll.lines.length > 0) {
+                    setLines(prev => [...prev, ...poll.lines])
+                }
+
+                if (poll.finished) {
+                    clearInterval(interval);
+                    commandId.current = undefined;
+                    setExitCode(poll.exit_code);
+                    setFinished(true);
+                }
+            } catch (e) {
+                clearInterval(interval);
+                commandId.current = undefined;
+                setError(String(e));
+                setFinished(true);
+            }
+        }, POLL_INTERVAL_MS);
+
+        return () => {
+            clearInterval(interval);
+            if (commandId.current !== undefined) {
+                shell_command_kill(commandId.current);
+            }
+        }
+    }, [command]);
+
+    const output = lines.map(line => line.text).join("\n");
+
+    const status = error !== undefined
+        ? `Failed to run: ${error}`
+        : finished
+            ? `Finished with exit code ${exitCode ?? "unknown"}`
+            : "Running...";
+
+    return (
+        <Detail
+            isLoading={!finished}
+            actions={
+                <ActionPanel>
+                    <Action
+                        label="Copy output"
+                        onAction={async () => {
+                            await Clipboard.writeText(output);
+                            showHud("Output copied")
+                        }}
+                    />
+                    {
+                        !finished && (
+                            <Action
+                                label="Stop command"
+                                onAction={() => {
+                                    if (commandId.current !== undefined) {
+                                        shell_command_kill(commandId.current);
+                                    }
+                                }}
+                            />
+                        )
+                    }
+                </ActionPanel>
+            }
+        >
+            <Detail.Metadata>
+                <Detail.Metadata.Value label="Command">{command}</Detail.Metadata.Value>
+                <Detail.Metadata.Value label="Status">{status}</Detail.Metadata.Value>
+            </Detail.Metadata>
+            <Detail.Content>
+                <Detail.Content.CodeBlock>
+                    {output}
+                </Detail.Content.CodeBlock>
+            </Detail.Content>
+        </Detail>
+    )
+}
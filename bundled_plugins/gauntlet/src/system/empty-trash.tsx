@@ -0,0 +1,12 @@
+import React, { ReactElement } from "react";
+import { SystemCommandConfirmation } from "./shared";
+
+export default function EmptyTrash(): ReactElement {
+    return (
+        <SystemCommandConfirmation
+            command="empty-trash"
+            question="Are you sure you want to permanently delete all items in the trash? This cannot be undone."
+            confirmLabel="Empty Trash"
+        />
+    )
+}
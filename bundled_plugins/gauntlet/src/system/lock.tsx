@@ -0,0 +1,5 @@
+import { runSystemCommand } from "./shared";
+
+export default async function Lock(): Promise<void> {
+    await runSystemCommand("lock")
+}
@@ -0,0 +1,12 @@
+import React, { ReactElement } from "react";
+import { SystemCommandConfirmation } from "./shared";
+
+export default function LogOut(): ReactElement {
+    return (
+        <SystemCommandConfirmation
+            command="log-out"
+            question="Are you sure you want to log out? Unsaved work in open applications may be lost."
+            confirmLabel="Log Out"
+        />
+    )
+}
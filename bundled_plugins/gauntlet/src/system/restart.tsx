@@ -0,0 +1,12 @@
+import React, { ReactElement } from "react";
+import { SystemCommandConfirmation } from "./shared";
+
+export default function Restart(): ReactElement {
+    return (
+        <SystemCommandConfirmation
+            command="restart"
+            question="Are you sure you want to restart the computer? Unsaved work in open applications may be lost."
+            confirmLabel="Restart"
+        />
+    )
+}
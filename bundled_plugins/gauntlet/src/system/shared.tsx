@@ -0,0 +1,44 @@
+import React, { ReactElement } from "react";
+import { Action, ActionPanel, Detail } from "@project-gauntlet/api/components";
+import { useNavigation } from "@project-gauntlet/api/hooks";
+import { showHud } from "@project-gauntlet/api/helpers";
+import { run_system_command } from "gauntlet:bridge/internal-all";
+
+export async function runSystemCommand(command: SystemCommand): Promise<boolean> {
+    try {
+        await run_system_command(command);
+        return true
+    } catch (e) {
+        console.error("Unable to run system command", command, e);
+        showHud(`Unable to run system command: ${e}`);
+        return false
+    }
+}
+
+export function SystemCommandConfirmation(props: { command: SystemCommand, question: string, confirmLabel: string }): ReactElement {
+    const { popView } = useNavigation();
+
+    return (
+        <Detail
+            actions={
+                <ActionPanel>
+                    <Action
+                        label={props.confirmLabel}
+                        onAction={async () => {
+                            if (await runSystemCommand(props.command)) {
+                                return { close: true }
+                            }
+                        }}
+                    />
+                    <Action label="Cancel" onAction={() => popView()}/>
+                </ActionPanel>
+            }
+        >
+            <Detail.Content>
+                <Detail.Content.H3>
+                    {props.question}
+                </Detail.Content.H3>
+            </Detail.Content>
+        </Detail>
+    )
+}
@@ -0,0 +1,12 @@
+import React, { ReactElement } from "react";
+import { SystemCommandConfirmation } from "./shared";
+
+export default function ShutDown(): ReactElement {
+    return (
+        <SystemCommandConfirmation
+            command="shut-down"
+            question="Are you sure you want to shut down the computer? Unsaved work in open applications may be lost."
+            confirmLabel="Shut Down"
+        />
+    )
+}
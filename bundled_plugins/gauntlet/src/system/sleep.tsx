@@ -0,0 +1,5 @@
+import { runSystemCommand } from "./shared";
+
+export default async function Sleep(): Promise<void> {
+    await runSystemCommand("sleep")
+}
@@ -46,6 +46,17 @@ export default function FormView(): ReactElement {
                     console.log(`uncontrolled value: ${value}`)
                 }}
             />
+            <Form.TextArea
+                label={"Text Area"}
+                placeholder={"Write something..."}
+                maxLength={500}
+                onChange={value => {
+                    console.log(`uncontrolled value: ${value}`)
+                }}
+                onSubmit={value => {
+                    console.log(`submitted value: ${value}`)
+                }}
+            />
             <Form.Checkbox
                 label={"My checkbox"}
                 title={"Checkbox title"}
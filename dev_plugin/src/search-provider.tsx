@@ -0,0 +1,26 @@
+import { SearchProviderContext, SearchProviderResult, showHud } from "@project-gauntlet/api/helpers";
+
+export default async function SearchProvider({ query, signal }: SearchProviderContext): Promise<SearchProviderResult[]> {
+    // simulate slow source, to make sure outdated searches are aborted
+    await new Promise<void>(resolve => setTimeout(resolve, 300))
+
+    if (signal.aborted) {
+        return []
+    }
+
+    return [
+        {
+            id: 'echo',
+            name: `Echo: ${query}`,
+            actions: [
+                {
+                    label: "Show query",
+                    run: () => {
+                        showHud(query)
+                    }
+                }
+            ],
+            accessories: [{ text: `${query.length} characters` }]
+        }
+    ]
+}
@@ -66,11 +66,23 @@ declare global {
             ["gauntlet:code_block"]: {
                 children?: StringComponent;
             };
+            ["gauntlet:skeleton"]: {
+                variant?: SkeletonVariant;
+                width?: number;
+                height?: number;
+            };
+            ["gauntlet:media_item"]: {
+                title: string;
+                subtitle?: string;
+                artwork?: ImageLike;
+                position?: number;
+                duration?: number;
+            };
             ["gauntlet:paragraph"]: {
                 children?: StringComponent;
             };
             ["gauntlet:content"]: {
-                children?: ElementComponent<typeof Paragraph | typeof Image | typeof H1 | typeof H2 | typeof H3 | typeof H4 | typeof H5 | typeof H6 | typeof HorizontalBreak | typeof CodeBlock>;
+                children?: ElementComponent<typeof Paragraph | typeof Image | typeof H1 | typeof H2 | typeof H3 | typeof H4 | typeof H5 | typeof H6 | typeof HorizontalBreak | typeof CodeBlock | typeof Skeleton | typeof MediaItem>;
             };
             ["gauntlet:detail"]: {
                 children?: ElementComponent<typeof ActionPanel | typeof Metadata | typeof Content>;
@@ -79,13 +91,26 @@ declare global {
             ["gauntlet:text_field"]: {
                 label?: string;
                 value?: string;
+                placeholder?: string;
+                debounce?: number;
                 onChange?: (value: string | undefined) => void;
+                onSubmit?: (value: string | undefined) => void;
+                onFocus?: () => void;
+                onBlur?: () => void;
             };
             ["gauntlet:password_field"]: {
                 label?: string;
                 value?: string;
                 onChange?: (value: string | undefined) => void;
             };
+            ["gauntlet:text_area"]: {
+                label?: string;
+                value?: string;
+                placeholder?: string;
+                maxLength?: number;
+                onChange?: (value: string | undefined) => void;
+                onSubmit?: (value: string | undefined) => void;
+            };
             ["gauntlet:checkbox"]: {
                 label?: string;
                 title?: string;
@@ -109,7 +134,7 @@ declare global {
             };
             ["gauntlet:separator"]: {};
             ["gauntlet:form"]: {
-                children?: ElementComponent<typeof ActionPanel | typeof TextField | typeof PasswordField | typeof Checkbox | typeof DatePicker | typeof Select | typeof Separator>;
+                children?: ElementComponent<typeof ActionPanel | typeof TextField | typeof PasswordField | typeof TextArea | typeof Checkbox | typeof DatePicker | typeof Select | typeof Separator>;
                 isLoading?: boolean;
             };
             ["gauntlet:inline_separator"]: {
@@ -119,6 +144,7 @@ declare global {
                 children?: ElementComponent<typeof ActionPanel | typeof Content | typeof InlineSeparator | typeof Content | typeof Content>;
             };
             ["gauntlet:empty_view"]: {
+                children?: ElementComponent<typeof Action>;
                 title: string;
                 description?: string;
                 image?: ImageLike;
@@ -137,12 +163,29 @@ declare global {
                 placeholder?: string;
                 onChange?: (value: string | undefined) => void;
             };
+            ["gauntlet:tab"]: {
+                id: string;
+                title: string;
+            };
+            ["gauntlet:tabs"]: {
+                children?: ElementComponent<typeof Tab>;
+                value?: string;
+                onChange?: (tabId: string) => void;
+            };
+            ["gauntlet:list_column"]: {
+                title: string;
+            };
+            ["gauntlet:list_columns"]: {
+                children?: ElementComponent<typeof ListColumn>;
+            };
             ["gauntlet:list_item"]: {
                 children?: ElementComponent<typeof TextAccessory | typeof IconAccessory>;
                 id: string;
                 title: string;
                 subtitle?: string;
                 icon?: ImageLike;
+                dragFile?: string;
+                dragText?: string;
             };
             ["gauntlet:list_section"]: {
                 children?: ElementComponent<typeof ListItem>;
@@ -150,15 +193,19 @@ declare global {
                 subtitle?: string;
             };
             ["gauntlet:list"]: {
-                children?: ElementComponent<typeof ActionPanel | typeof ListItem | typeof ListSection | typeof SearchBar | typeof EmptyView | typeof Detail>;
+                children?: ElementComponent<typeof ActionPanel | typeof ListItem | typeof ListSection | typeof SearchBar | typeof Tabs | typeof ListColumns | typeof EmptyView | typeof Detail>;
                 isLoading?: boolean;
+                hasMore?: boolean;
                 onItemFocusChange?: (itemId: string | undefined) => void;
+                onLoadMore?: () => void;
             };
             ["gauntlet:grid_item"]: {
                 children?: ElementComponent<typeof IconAccessory | typeof Content>;
                 id: string;
                 title?: string;
                 subtitle?: string;
+                dragFile?: string;
+                dragText?: string;
             };
             ["gauntlet:grid_section"]: {
                 children?: ElementComponent<typeof GridItem>;
@@ -167,7 +214,7 @@ declare global {
                 columns?: number;
             };
             ["gauntlet:grid"]: {
-                children?: ElementComponent<typeof ActionPanel | typeof GridItem | typeof GridSection | typeof SearchBar | typeof EmptyView>;
+                children?: ElementComponent<typeof ActionPanel | typeof GridItem | typeof GridSection | typeof SearchBar | typeof Tabs | typeof EmptyView>;
                 isLoading?: boolean;
                 columns?: number;
                 onItemFocusChange?: (itemId: string | undefined) => void;
@@ -355,6 +402,11 @@ export enum Icons {
     Indent = "Indent",
     Unindent = "Unindent"
 }
+export enum SkeletonVariant {
+    Line = "Line",
+    Circle = "Circle",
+    Rect = "Rect"
+}
 export type ImageSourceUrl = {
     url: string;
 };
@@ -503,6 +555,24 @@ export interface CodeBlockProps {
 export const CodeBlock: FC<CodeBlockProps> = (props: CodeBlockProps): ReactNode => {
     return <gauntlet:code_block>{props.children}</gauntlet:code_block>;
 };
+export interface SkeletonProps {
+    variant?: SkeletonVariant;
+    width?: number;
+    height?: number;
+}
+export const Skeleton: FC<SkeletonProps> = (props: SkeletonProps): ReactNode => {
+    return <gauntlet:skeleton variant={props.variant} width={props.width} height={props.height}></gauntlet:skeleton>;
+};
+export interface MediaItemProps {
+    title: string;
+    subtitle?: string;
+    artwork?: ImageLike;
+    position?: number;
+    duration?: number;
+}
+export const MediaItem: FC<MediaItemProps> = (props: MediaItemProps): ReactNode => {
+    return <gauntlet:media_item title={props.title} subtitle={props.subtitle} artwork={props.artwork} position={props.position} duration={props.duration}></gauntlet:media_item>;
+};
 export interface ParagraphProps {
     children?: StringComponent;
 }
@@ -510,7 +580,7 @@ export const Paragraph: FC<ParagraphProps> = (props: ParagraphProps): ReactNode
     return <gauntlet:paragraph>{props.children}</gauntlet:paragraph>;
 };
 export interface ContentProps {
-    children?: ElementComponent<typeof Paragraph | typeof Image | typeof H1 | typeof H2 | typeof H3 | typeof H4 | typeof H5 | typeof H6 | typeof HorizontalBreak | typeof CodeBlock>;
+    children?: ElementComponent<typeof Paragraph | typeof Image | typeof H1 | typeof H2 | typeof H3 | typeof H4 | typeof H5 | typeof H6 | typeof HorizontalBreak | typeof CodeBlock | typeof Skeleton | typeof MediaItem>;
 }
 export const Content: FC<ContentProps> & {
     Paragraph: typeof Paragraph;
@@ -523,6 +593,8 @@ export const Content: FC<ContentProps> & {
     H6: typeof H6;
     HorizontalBreak: typeof HorizontalBreak;
     CodeBlock: typeof CodeBlock;
+    Skeleton: typeof Skeleton;
+    MediaItem: typeof MediaItem;
 } = (props: ContentProps): ReactNode => {
     return <gauntlet:content>{props.children}</gauntlet:content>;
 };
@@ -536,6 +608,8 @@ Content.H5 = H5;
 Content.H6 = H6;
 Content.HorizontalBreak = HorizontalBreak;
 Content.CodeBlock = CodeBlock;
+Content.Skeleton = Skeleton;
+Content.MediaItem = MediaItem;
 export interface DetailProps {
     children?: ElementComponent<typeof Metadata | typeof Content>;
     isLoading?: boolean;
@@ -552,10 +626,15 @@ Detail.Content = Content;
 export interface TextFieldProps {
     label?: string;
     value?: string;
+    placeholder?: string;
+    debounce?: number;
     onChange?: (value: string | undefined) => void;
+    onSubmit?: (value: string | undefined) => void;
+    onFocus?: () => void;
+    onBlur?: () => void;
 }
 export const TextField: FC<TextFieldProps> = (props: TextFieldProps): ReactNode => {
-    return <gauntlet:text_field label={props.label} value={props.value} onChange={props.onChange}></gauntlet:text_field>;
+    return <gauntlet:text_field label={props.label} value={props.value} placeholder={props.placeholder} debounce={props.debounce} onChange={props.onChange} onSubmit={props.onSubmit} onFocus={props.onFocus} onBlur={props.onBlur}></gauntlet:text_field>;
 };
 export interface PasswordFieldProps {
     label?: string;
@@ -565,6 +644,17 @@ export interface PasswordFieldProps {
 export const PasswordField: FC<PasswordFieldProps> = (props: PasswordFieldProps): ReactNode => {
     return <gauntlet:password_field label={props.label} value={props.value} onChange={props.onChange}></gauntlet:password_field>;
 };
+export interface TextAreaProps {
+    label?: string;
+    value?: string;
+    placeholder?: string;
+    maxLength?: number;
+    onChange?: (value: string | undefined) => void;
+    onSubmit?: (value: string | undefined) => void;
+}
+export const TextArea: FC<TextAreaProps> = (props: TextAreaProps): ReactNode => {
+    return <gauntlet:text_area label={props.label} value={props.value} placeholder={props.placeholder} maxLength={props.maxLength} onChange={props.onChange} onSubmit={props.onSubmit}></gauntlet:text_area>;
+};
 export interface CheckboxProps {
     label?: string;
     title?: string;
@@ -605,13 +695,14 @@ export const Separator: FC = (): ReactNode => {
     return <gauntlet:separator></gauntlet:separator>;
 };
 export interface FormProps {
-    children?: ElementComponent<typeof TextField | typeof PasswordField | typeof Checkbox | typeof DatePicker | typeof Select | typeof Separator>;
+    children?: ElementComponent<typeof TextField | typeof PasswordField | typeof TextArea | typeof Checkbox | typeof DatePicker | typeof Select | typeof Separator>;
     isLoading?: boolean;
     actions?: ElementComponent<typeof ActionPanel>;
 }
 export const Form: FC<FormProps> & {
     TextField: typeof TextField;
     PasswordField: typeof PasswordField;
+    TextArea: typeof TextArea;
     Checkbox: typeof Checkbox;
     DatePicker: typeof DatePicker;
     Select: typeof Select;
@@ -621,6 +712,7 @@ export const Form: FC<FormProps> & {
 };
 Form.TextField = TextField;
 Form.PasswordField = PasswordField;
+Form.TextArea = TextArea;
 Form.Checkbox = Checkbox;
 Form.DatePicker = DatePicker;
 Form.Select = Select;
@@ -648,13 +740,17 @@ Inline.Separator = InlineSeparator;
 Inline.Right = Content;
 Inline.Center = Content;
 export interface EmptyViewProps {
+    children?: ElementComponent<typeof Action>;
     title: string;
     description?: string;
     image?: ImageLike;
 }
-export const EmptyView: FC<EmptyViewProps> = (props: EmptyViewProps): ReactNode => {
-    return <gauntlet:empty_view title={props.title} description={props.description} image={props.image}></gauntlet:empty_view>;
+export const EmptyView: FC<EmptyViewProps> & {
+    Action: typeof Action;
+} = (props: EmptyViewProps): ReactNode => {
+    return <gauntlet:empty_view title={props.title} description={props.description} image={props.image}>{props.children}</gauntlet:empty_view>;
 };
+EmptyView.Action = Action;
 export interface IconAccessoryProps {
     icon: ImageLike;
     tooltip?: string;
@@ -678,15 +774,50 @@ export interface SearchBarProps {
 export const SearchBar: FC<SearchBarProps> = (props: SearchBarProps): ReactNode => {
     return <gauntlet:search_bar value={props.value} placeholder={props.placeholder} onChange={props.onChange}></gauntlet:search_bar>;
 };
+export interface TabProps {
+    id: string;
+    title: string;
+}
+export const Tab: FC<TabProps> = (props: TabProps): ReactNode => {
+    return <gauntlet:tab id={props.id} title={props.title}></gauntlet:tab>;
+};
+export interface TabsProps {
+    children?: ElementComponent<typeof Tab>;
+    value?: string;
+    onChange?: (tabId: string) => void;
+}
+export const Tabs: FC<TabsProps> & {
+    Tab: typeof Tab;
+} = (props: TabsProps): ReactNode => {
+    return <gauntlet:tabs value={props.value} onChange={props.onChange}>{props.children}</gauntlet:tabs>;
+};
+Tabs.Tab = Tab;
+export interface ListColumnProps {
+    title: string;
+}
+export const ListColumn: FC<ListColumnProps> = (props: ListColumnProps): ReactNode => {
+    return <gauntlet:list_column title={props.title}></gauntlet:list_column>;
+};
+export interface ListColumnsProps {
+    children?: ElementComponent<typeof ListColumn>;
+}
+export const ListColumns: FC<ListColumnsProps> & {
+    Column: typeof ListColumn;
+} = (props: ListColumnsProps): ReactNode => {
+    return <gauntlet:list_columns>{props.children}</gauntlet:list_columns>;
+};
+ListColumns.Column = ListColumn;
 export interface ListItemProps {
     id: string;
     title: string;
     subtitle?: string;
     icon?: ImageLike;
     accessories?: (ElementComponent<typeof TextAccessory> | ElementComponent<typeof IconAccessory>)[];
+    dragFile?: string;
+    dragText?: string;
 }
 export const ListItem: FC<ListItemProps> = (props: ListItemProps): ReactNode => {
-    return <gauntlet:list_item id={props.id} title={props.title} subtitle={props.subtitle} icon={props.icon}>{props.accessories as any}</gauntlet:list_item>;
+    return <gauntlet:list_item id={props.id} title={props.title} subtitle={props.subtitle} icon={props.icon} dragFile={props.dragFile} dragText={props.dragText}>{props.accessories as any}</gauntlet:list_item>;
 };
 export interface ListSectionProps {
     children?: ElementComponent<typeof ListItem>;
@@ -700,23 +831,29 @@ export const ListSection: FC<ListSectionProps> & {
 };
 ListSection.Item = ListItem;
 export interface ListProps {
-    children?: ElementComponent<typeof ListItem | typeof ListSection | typeof SearchBar | typeof EmptyView | typeof Detail>;
+    children?: ElementComponent<typeof ListItem | typeof ListSection | typeof SearchBar | typeof Tabs | typeof ListColumns | typeof EmptyView | typeof Detail>;
     actions?: ElementComponent<typeof ActionPanel>;
     isLoading?: boolean;
+    hasMore?: boolean;
     onItemFocusChange?: (itemId: string | undefined) => void;
+    onLoadMore?: () => void;
 }
 export const List: FC<ListProps> & {
     Item: typeof ListItem;
     Section: typeof ListSection;
     SearchBar: typeof SearchBar;
+    Tabs: typeof Tabs;
+    Columns: typeof ListColumns;
     EmptyView: typeof EmptyView;
     Detail: typeof Detail;
 } = (props: ListProps): ReactNode => {
-    return <gauntlet:list isLoading={props.isLoading} onItemFocusChange={props.onItemFocusChange}>{props.actions as any}{props.children}</gauntlet:list>;
+    return <gauntlet:list isLoading={props.isLoading} hasMore={props.hasMore} onItemFocusChange={props.onItemFocusChange} onLoadMore={props.onLoadMore}>{props.actions as any}{props.children}</gauntlet:list>;
 };
 List.Item = ListItem;
 List.Section = ListSection;
 List.SearchBar = SearchBar;
+List.Tabs = Tabs;
+List.Columns = ListColumns;
 List.EmptyView = EmptyView;
 List.Detail = Detail;
 export interface GridItemProps {
@@ -725,11 +862,13 @@ export interface GridItemProps {
     title?: string;
     subtitle?: string;
     accessory?: ElementComponent<typeof IconAccessory>;
+    dragFile?: string;
+    dragText?: string;
 }
 export const GridItem: FC<GridItemProps> & {
     Content: typeof Content;
 } = (props: GridItemProps): ReactNode => {
-    return <gauntlet:grid_item id={props.id} title={props.title} subtitle={props.subtitle}>{props.accessory as any}{props.children}</gauntlet:grid_item>;
+    return <gauntlet:grid_item id={props.id} title={props.title} subtitle={props.subtitle} dragFile={props.dragFile} dragText={props.dragText}>{props.accessory as any}{props.children}</gauntlet:grid_item>;
 };
 GridItem.Content = Content;
 export interface GridSectionProps {
@@ -745,7 +884,7 @@ export const GridSection: FC<GridSectionProps> & {
 };
 GridSection.Item = GridItem;
 export interface GridProps {
-    children?: ElementComponent<typeof GridItem | typeof GridSection | typeof SearchBar | typeof EmptyView>;
+    children?: ElementComponent<typeof GridItem | typeof GridSection | typeof SearchBar | typeof Tabs | typeof EmptyView>;
     isLoading?: boolean;
     actions?: ElementComponent<typeof ActionPanel>;
     columns?: number;
@@ -755,6 +894,7 @@ export const Grid: FC<GridProps> & {
     Item: typeof GridItem;
     Section: typeof GridSection;
     SearchBar: typeof SearchBar;
+    Tabs: typeof Tabs;
     EmptyView: typeof EmptyView;
 } = (props: GridProps): ReactNode => {
     return <gauntlet:grid isLoading={props.isLoading} columns={props.columns} onItemFocusChange={props.onItemFocusChange}>{props.actions as any}{props.children}</gauntlet:grid>;
@@ -762,4 +902,5 @@ export const Grid: FC<GridProps> & {
 Grid.Item = GridItem;
 Grid.Section = GridSection;
 Grid.SearchBar = SearchBar;
+Grid.Tabs = Tabs;
 Grid.EmptyView = EmptyView;
@@ -1,15 +1,37 @@
 // @ts-ignore TODO how to add declaration for this?
-import { getAssetData, getAssetDataSync, getPluginPreferences, getEntrypointPreferences, showHudWindow } from "ext:gauntlet/renderer.js";
+import { getAssetData, getAssetDataSync, getPluginPreferences, getEntrypointPreferences, showHudWindow, showDesktopNotification } from "ext:gauntlet/renderer.js";
 import {
     clipboard_clear,
+    clipboard_paste_text,
     clipboard_read,
     clipboard_read_text,
     clipboard_write,
+    clipboard_write_sensitive_text,
     clipboard_write_text,
+    database_close,
+    database_execute,
+    database_open,
+    database_query,
     environment_gauntlet_version,
     environment_is_development,
     environment_plugin_cache_dir,
-    environment_plugin_data_dir
+    environment_plugin_data_dir,
+    environment_variables,
+    fs_read_dir,
+    fs_read_file,
+    fs_read_text_file,
+    fs_stat,
+    fs_unwatch,
+    fs_watch,
+    fs_watch_next,
+    fs_write_file,
+    fs_write_text_file,
+    oauth_authorize,
+    oauth_remove_tokens,
+    run_in_terminal,
+    subprocess_kill,
+    subprocess_read,
+    subprocess_spawn
 } from "ext:core/ops";
 import type { FC } from "react";
 
@@ -21,15 +43,37 @@ export function assetData(path: string): Promise<ArrayBuffer> {
     return getAssetData(path)
 }
 
+// instantiates WebAssembly module placed in plugin assets directory.
+// compilation errors, including modules which exceed size limit, are rethrown with path of the module
+export async function instantiateWasm(path: string, imports?: WebAssembly.Imports): Promise<WebAssembly.Instance> {
+    const data = await assetData(path);
+
+    try {
+        const { instance } = await WebAssembly.instantiate(data, imports);
+
+        return instance
+    } catch (e) {
+        throw new Error(`Unable to instantiate WebAssembly module '${path}': ${e instanceof Error ? e.message : e}`, { cause: e })
+    }
+}
+
 export function showHud(display: string): void {
     return showHudWindow(display)
 }
 
 export interface GeneratedEntrypoint {
     name: string
+    // shown next to the name in main search results
+    subtitle?: string
+    // additional words by which entry can be found in main search, ignored for results of search providers
+    keywords?: string[]
     actions: GeneratedEntrypointAction[]
     icon?: ArrayBuffer
     accessories?: GeneratedEntrypointAccessory[]
+    // markdown shown in preview pane of main view when entry is focused, headings, list items and paragraphs are supported
+    preview?: string
+    // absolute path of local file which entry represents, it can be previewed using space bar in main view
+    file?: string
 }
 
 export type GeneratedEntrypointAction = GeneratedEntrypointActionRun | GeneratedEntrypointActionView
@@ -68,9 +112,24 @@ export type GeneratorContext<P = object, E = object> = {
     entrypointPreferences: E,
 };
 
+export type SearchProviderResult = GeneratedEntrypoint & {
+    // unique among results returned by the same search provider
+    id: string
+}
+
+export type SearchProviderContext<P = object, E = object> = {
+    query: string,
+    // aborted when user changes search query before results were returned
+    signal: AbortSignal,
+    pluginPreferences: P,
+    entrypointPreferences: E,
+};
+
 export type CommandContext<P = object, E = object> = {
     pluginPreferences: P,
     entrypointPreferences: E,
+    // values of arguments declared in plugin manifest, keyed by argument id. Optional arguments without value are absent
+    arguments: { [id: string]: string },
 };
 
 export const Clipboard: Clipboard = {
@@ -111,6 +170,15 @@ export const Clipboard: Clipboard = {
     writeText: async function (data: string): Promise<void> {
         return await clipboard_write_text(data)
     },
+    // removed from clipboard after timeout configured by user, unless clipboard content was replaced in the meantime
+    writeSensitiveText: async function (data: string): Promise<void> {
+        return await clipboard_write_sensitive_text(data)
+    },
+    // hides gauntlet window and pastes text into application which was active before it was opened.
+    // text is left in clipboard, on wayland only applications running under xwayland are supported
+    pasteText: async function (data: string, options?: { sensitive?: boolean }): Promise<void> {
+        return await clipboard_paste_text(data, options?.sensitive ?? false)
+    },
     clear: async function (): Promise<void> {
         await clipboard_clear()
     }
@@ -121,9 +189,355 @@ export interface Clipboard {
     readText(): Promise<string | undefined>;
     write(data: { ["text/plain"]?: string, ["image/png"]?: ArrayBuffer }): Promise<void>;
     writeText(data: string): Promise<void>;
+    writeSensitiveText(data: string): Promise<void>;
+    pasteText(data: string, options?: { sensitive?: boolean }): Promise<void>;
     clear(): Promise<void>;
 }
 
+export const Terminal: Terminal = {
+    // runs command in terminal emulator configured by user. program has to be listed in "exec" permissions.
+    // user's shell is opened when command is empty
+    run: async function (command: string[], options?: { cwd?: string }): Promise<void> {
+        await run_in_terminal(command, options?.cwd)
+    }
+}
+
+export interface Terminal {
+    run(command: string[], options?: { cwd?: string }): Promise<void>;
+}
+
+export const Process: Process = {
+    // program has to be listed in "exec" permissions. output is read as soon as process is started,
+    // so status resolves even if output is never iterated
+    run: function (program: string, args?: string[], options?: ProcessRunOptions): RunningProcess {
+        const id = subprocess_spawn(program, args ?? [], options?.cwd, options?.env ?? {}, options?.timeout);
+
+        const chunks: ProcessOutputChunk[] = [];
+        let finished = false;
+        let wakeUp: (() => void) | undefined;
+
+        const notify = () => {
+            wakeUp?.();
+            wakeUp = undefined;
+        }
+
+        const status = (async (): Promise<ProcessStatus> => {
+            try {
+                while (true) {
+                    const chunk = await subprocess_read(id);
+
+                    if (chunk == null) {
+                        throw new Error(`Process '${program}' has stopped without exit status`)
+                    }
+
+                    if (chunk.type === "exit") {
+                        return {
+                            code: chunk.code ?? undefined,
+                            success: chunk.code === 0,
+                            timedOut: chunk.timedOut,
+                            killed: chunk.killed,
+                        }
+                    }
+
+                    chunks.push({ stream: chunk.type, text: chunk.text });
+                    notify();
+                }
+            } finally {
+                finished = true;
+                notify();
+            }
+        })();
+
+        async function* output(): AsyncGenerator<ProcessOutputChunk> {
+            while (true) {
+                const chunk = chunks.shift();
+
+                if (chunk) {
+                    yield chunk;
+                } else if (finished) {
+                    return;
+                } else {
+                    await new Promise<void>(resolve => {
+                        wakeUp = resolve
+                    });
+                }
+            }
+        }
+
+        return {
+            output: output(),
+            status,
+            kill: () => subprocess_kill(id),
+        }
+    }
+}
+
+export interface Process {
+    run(program: string, args?: string[], options?: ProcessRunOptions): RunningProcess;
+}
+
+export interface ProcessRunOptions {
+    cwd?: string;
+    // added to environment of plugin runtime process
+    env?: { [name: string]: string };
+    // in milliseconds, process is killed when it runs longer
+    timeout?: number;
+}
+
+export interface RunningProcess {
+    // can only be iterated once
+    output: AsyncIterable<ProcessOutputChunk>;
+    status: Promise<ProcessStatus>;
+    kill(): void;
+}
+
+export interface ProcessOutputChunk {
+    stream: "stdout" | "stderr";
+    text: string;
+}
+
+export interface ProcessStatus {
+    // undefined when process was stopped by signal
+    code?: number;
+    success: boolean;
+    timedOut: boolean;
+    killed: boolean;
+}
+
+export const FS: FS = {
+    // paths have to be absolute and inside of directories listed in "filesystem" permissions
+    // or approved by user in plugin settings. directories which can be written can also be read
+    readDir: async function (path: string): Promise<DirEntry[]> {
+        return await fs_read_dir(path)
+    },
+    readFile: async function (path: string): Promise<Uint8Array> {
+        return await fs_read_file(path)
+    },
+    readTextFile: async function (path: string): Promise<string> {
+        return await fs_read_text_file(path)
+    },
+    writeFile: async function (path: string, data: Uint8Array | string): Promise<void> {
+        if (typeof data === "string") {
+            await fs_write_text_file(path, data)
+        } else {
+            await fs_write_file(path, data)
+        }
+    },
+    stat: async function (path: string): Promise<FileInfo> {
+        const stat = await fs_stat(path);
+
+        return {
+            isFile: stat.isFile,
+            isDirectory: stat.isDirectory,
+            isSymlink: stat.isSymlink,
+            size: stat.size,
+            modified: stat.modified != null ? new Date(stat.modified) : undefined,
+            created: stat.created != null ? new Date(stat.created) : undefined,
+        }
+    },
+    // callback is called for every change until watcher is closed
+    watch: function (path: string, callback: (event: WatchEvent) => void, options?: { recursive?: boolean }): FileWatcher {
+        const id = fs_watch(path, options?.recursive ?? false);
+
+        (async () => {
+            while (true) {
+                const event = await fs_watch_next(id);
+
+                if (event == null) {
+                    return;
+                }
+
+                try {
+                    callback(event)
+                } catch (e) {
+                    console.error(`Error in file watcher callback for '${path}'`, e)
+                }
+            }
+        })();
+
+        return {
+            close: () => fs_unwatch(id),
+        }
+    }
+}
+
+export interface FS {
+    readDir(path: string): Promise<DirEntry[]>;
+    readFile(path: string): Promise<Uint8Array>;
+    readTextFile(path: string): Promise<string>;
+    // file is created if it doesn't exist
+    writeFile(path: string, data: Uint8Array | string): Promise<void>;
+    // symlinks are not followed
+    stat(path: string): Promise<FileInfo>;
+    watch(path: string, callback: (event: WatchEvent) => void, options?: { recursive?: boolean }): FileWatcher;
+}
+
+export interface DirEntry {
+    name: string;
+    // absolute
+    path: string;
+    isFile: boolean;
+    isDirectory: boolean;
+    isSymlink: boolean;
+}
+
+export interface FileInfo {
+    isFile: boolean;
+    isDirectory: boolean;
+    isSymlink: boolean;
+    size: number;
+    // undefined when not supported by platform
+    modified?: Date;
+    created?: Date;
+}
+
+export interface WatchEvent {
+    kind: "create" | "modify" | "remove" | "other";
+    paths: string[];
+}
+
+export interface FileWatcher {
+    close(): void;
+}
+
+export const Database: Database = {
+    // database is stored in plugin data directory and removed when plugin is uninstalled.
+    // name can only contain letters, numbers, '-' and '_'
+    open: async function (name?: string): Promise<DatabaseConnection> {
+        const id = await database_open(name ?? "default");
+
+        return {
+            query: async function <T extends DatabaseRow = DatabaseRow>(sql: string, params?: DatabaseParam[]): Promise<T[]> {
+                const result = await database_query(id, sql, params ?? []);
+
+                return result.rows.map(row => {
+                    const object: DatabaseRow = {};
+
+                    result.columns.forEach((column, index) => {
+                        object[column] = row[index]
+                    });
+
+                    return object as T
+                })
+            },
+            execute: async function (sql: string, params?: DatabaseParam[]): Promise<DatabaseExecuteResult> {
+                return await database_execute(id, sql, params ?? [])
+            },
+            close: () => database_close(id),
+        }
+    }
+}
+
+export interface Database {
+    open(name?: string): Promise<DatabaseConnection>;
+}
+
+// "?" placeholders in sql are replaced with params in order. booleans are stored as 0 and 1
+export type DatabaseParam = null | boolean | number | string;
+
+export type DatabaseValue = null | number | string | Uint8Array;
+
+export type DatabaseRow = { [column: string]: DatabaseValue };
+
+export interface DatabaseConnection {
+    // rows are returned as objects keyed by column name
+    query<T extends DatabaseRow = DatabaseRow>(sql: string, params?: DatabaseParam[]): Promise<T[]>;
+    execute(sql: string, params?: DatabaseParam[]): Promise<DatabaseExecuteResult>;
+    close(): void;
+}
+
+export interface DatabaseExecuteResult {
+    changes: number;
+    lastInsertRowId: number;
+}
+
+export const Notifications: Notifications = {
+    // shown as desktop notification. not shown if user disabled notifications for the plugin or focus mode is enabled.
+    // action buttons are only supported on linux
+    show: async function (notification: Notification): Promise<void> {
+        await showDesktopNotification(notification.title, notification.body, notification.actions ?? [])
+    }
+}
+
+export interface Notification {
+    title: string;
+    body?: string;
+    actions?: NotificationAction[];
+}
+
+export interface NotificationAction {
+    label: string;
+    // called when user clicks the button, even if plugin view is not open
+    onAction: () => void;
+}
+
+export interface Notifications {
+    show(notification: Notification): Promise<void>;
+}
+
+export const OAuth: OAuth = {
+    // opens browser to let user authorize and resolves once tokens are received.
+    // tokens are stored in system keychain, refreshed when expired, and reused on subsequent calls
+    authorize: async function (config: OAuthConfig): Promise<OAuthTokens> {
+        const opConfig = oauthConfig(config);
+
+        while (true) {
+            const tokens = await oauth_authorize(opConfig);
+
+            if (tokens) {
+                return {
+                    accessToken: tokens.access_token,
+                    tokenType: tokens.token_type,
+                    expiresAt: tokens.expires_at != undefined ? new Date(tokens.expires_at) : undefined,
+                    scopes: tokens.scopes,
+                }
+            }
+
+            // authorization is still in progress
+            await new Promise(resolve => setTimeout(resolve, 500));
+        }
+    },
+    removeTokens: async function (config: OAuthConfig): Promise<void> {
+        await oauth_remove_tokens(oauthConfig(config))
+    }
+}
+
+function oauthConfig(config: OAuthConfig) {
+    return {
+        client_id: config.clientId,
+        client_secret: config.clientSecret,
+        authorize_url: config.authorizeUrl,
+        token_url: config.tokenUrl,
+        scopes: config.scopes ?? [],
+        redirect_port: config.redirectPort,
+        extra_authorize_params: config.extraAuthorizeParams ?? {},
+    }
+}
+
+export interface OAuthConfig {
+    clientId: string;
+    clientSecret?: string;
+    authorizeUrl: string;
+    // host of the token url needs to be listed in network permissions
+    tokenUrl: string;
+    scopes?: string[];
+    // redirect url is http://127.0.0.1:<port>/callback, random free port is used if not specified
+    redirectPort?: number;
+    extraAuthorizeParams?: { [name: string]: string };
+}
+
+export interface OAuthTokens {
+    accessToken: string;
+    tokenType: string;
+    expiresAt?: Date;
+    scopes: string[];
+}
+
+export interface OAuth {
+    authorize(config: OAuthConfig): Promise<OAuthTokens>;
+    removeTokens(config: OAuthConfig): Promise<void>;
+}
+
 export const Environment: Environment = {
     get gauntletVersion(): number {
         return environment_gauntlet_version()
@@ -137,6 +551,9 @@ export const Environment: Environment = {
     get pluginCacheDir(): string {
         return environment_plugin_cache_dir()
     },
+    get variables(): { [name: string]: string } {
+        return environment_variables()
+    },
 }
 
 export interface Environment {
@@ -144,5 +561,7 @@ export interface Environment {
     get isDevelopment(): boolean;
     get pluginDataDir(): string;
     get pluginCacheDir(): string;
+    // set by user in plugin settings, also available to processes started by plugin
+    get variables(): { [name: string]: string };
 }
 
@@ -1,20 +1,60 @@
 import { ReactNode, useRef, useId, useState, useCallback, useEffect, MutableRefObject, Dispatch, SetStateAction } from 'react';
 // @ts-ignore TODO how to add declaration for this?
-import { useGauntletContext } from "ext:gauntlet/renderer.js";
+import { useGauntletContext, useNavigationEntry } from "ext:gauntlet/renderer.js";
 
-export function useNavigation(): { popView: () => void, pushView: (component: ReactNode) => void } {
-    const { popView, pushView }: { popView: () => void, pushView: (component: ReactNode) => void } = useGauntletContext();
+// title of pushed view is shown in plugin view breadcrumbs, entrypoint name is used if not specified
+export function useNavigation(): { popView: () => void, pushView: (component: ReactNode, title?: string) => void } {
+    const { popView, pushView }: { popView: () => void, pushView: (component: ReactNode, title?: string) => void } = useGauntletContext();
 
     return {
         popView: () => {
             popView()
         },
-        pushView: (component: ReactNode) => {
-            pushView(component)
+        pushView: (component: ReactNode, title?: string) => {
+            pushView(component, title)
         }
     }
 }
 
+export type KeyHandlerShortcut = {
+    key: string // keyboard event code, e.g. "KeyR" or "Digit1"
+    modifierShift?: boolean
+    modifierControl?: boolean
+    modifierAlt?: boolean
+    modifierMeta?: boolean
+}
+
+// handler is called when shortcut is pressed while view in which the hook is used is shown,
+// pressed keys are not typed into search bar and do not move focus
+export function useKeyHandler(shortcut: KeyHandlerShortcut, handler: () => void): void {
+    const { addKeyHandler }: { addKeyHandler: (entry: any, keyHandler: any) => () => void } = useGauntletContext();
+    const entry = useNavigationEntry();
+
+    const handlerRef = useRef(handler);
+    handlerRef.current = handler;
+
+    const key = shortcut.key;
+    const modifierShift = !!shortcut.modifierShift;
+    const modifierControl = !!shortcut.modifierControl;
+    const modifierAlt = !!shortcut.modifierAlt;
+    const modifierMeta = !!shortcut.modifierMeta;
+
+    useEffect(() => {
+        if (!entry) {
+            return
+        }
+
+        return addKeyHandler(entry, {
+            key,
+            modifierShift,
+            modifierControl,
+            modifierAlt,
+            modifierMeta,
+            handler: () => handlerRef.current()
+        })
+    }, [entry, key, modifierShift, modifierControl, modifierAlt, modifierMeta]);
+}
+
 export function usePluginPreferences<T extends Record<string, any>>(): T {
     const { pluginPreferences }: { pluginPreferences: () => T } = useGauntletContext();
 
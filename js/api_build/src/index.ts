@@ -14,6 +14,71 @@ function generate(componentModelPath: string, outFile: string) {
     writeFileSync(outFile, result)
 }
 
+// properties of widgets are validated by renderer before they are sent to rust side,
+// where properties unknown to component model would be silently dropped
+function generateWidgetProperties(componentModelPath: string, outFile: string) {
+    const content = readFileSync(componentModelPath).toString();
+    const model = JSON.parse(content) as Component[]
+
+    const resultFile = ts.createSourceFile("unused", "", ts.ScriptTarget.Latest, false, ts.ScriptKind.TS);
+    const printer = ts.createPrinter({ newLine: ts.NewLineKind.LineFeed });
+
+    const result = printer.printNode(ts.EmitHint.Unspecified, makeWidgetProperties(model), resultFile);
+
+    writeFileSync(outFile, result)
+}
+
+function makeWidgetProperties(model: Component[]): ts.SourceFile {
+    const widgetTypeDeclaration = ts.factory.createTypeAliasDeclaration(
+        [ts.factory.createToken(ts.SyntaxKind.ExportKeyword)],
+        ts.factory.createIdentifier("WidgetType"),
+        undefined,
+        ts.factory.createUnionTypeNode(
+            model.map(component => ts.factory.createLiteralTypeNode(ts.factory.createStringLiteral(`gauntlet:${component.internalName}`)))
+        )
+    );
+
+    const widgetPropertiesDeclaration = ts.factory.createVariableStatement(
+        [ts.factory.createToken(ts.SyntaxKind.ExportKeyword)],
+        ts.factory.createVariableDeclarationList(
+            [
+                ts.factory.createVariableDeclaration(
+                    ts.factory.createIdentifier("widgetProperties"),
+                    undefined,
+                    ts.factory.createTypeReferenceNode(
+                        ts.factory.createIdentifier("Record"),
+                        [
+                            ts.factory.createTypeReferenceNode(ts.factory.createIdentifier("WidgetType"), undefined),
+                            ts.factory.createArrayTypeNode(ts.factory.createKeywordTypeNode(ts.SyntaxKind.StringKeyword))
+                        ]
+                    ),
+                    ts.factory.createObjectLiteralExpression(
+                        model.map(component => {
+                            const props = component.type === "root" ? [] : component.props;
+
+                            return ts.factory.createPropertyAssignment(
+                                ts.factory.createStringLiteral(`gauntlet:${component.internalName}`),
+                                ts.factory.createArrayLiteralExpression(
+                                    props.map(prop => ts.factory.createStringLiteral(prop.name)),
+                                    false
+                                )
+                            )
+                        }),
+                        true
+                    )
+                )
+            ],
+            ts.NodeFlags.Const
+        )
+    );
+
+    return ts.factory.createSourceFile(
+        [widgetTypeDeclaration, widgetPropertiesDeclaration],
+        ts.factory.createToken(ts.SyntaxKind.EndOfFileToken),
+        ts.NodeFlags.None
+    )
+}
+
 function makeComponents(modelInput: Component[]): ts.SourceFile {
     const model = modelInput.filter((component): component is StandardComponent => component.type === "standard");
 
@@ -767,4 +832,11 @@ if (!existsSync(genDir)) {
     mkdirSync(genDir);
 }
 
-generate("./component_model.json", `${genDir}/components.tsx`)
\ No newline at end of file
+generate("./component_model.json", `${genDir}/components.tsx`)
+
+const rendererGenDir = "../react_renderer/src/gen";
+if (!existsSync(rendererGenDir)) {
+    mkdirSync(rendererGenDir);
+}
+
+generateWidgetProperties("./component_model.json", `${rendererGenDir}/widget-properties.ts`)
\ No newline at end of file
@@ -284,6 +284,7 @@ function packageForLinux(projectRoot: string, arch: string, profile: string): {
 
     const sourceExecutableFilePath = path.join(releaseDirPath, 'gauntlet');
     const sourceDesktopFilePath = path.join(assetsDirPath, 'gauntlet.desktop');
+    const sourceDeepLinkDesktopFilePath = path.join(assetsDirPath, 'gauntlet-deeplink.desktop');
     const sourceServiceFilePath = path.join(assetsDirPath, 'gauntlet.service');
     const sourceLogoFilePath = path.join(assetsDirPath, 'icon_256.png');
 
@@ -295,6 +296,9 @@ function packageForLinux(projectRoot: string, arch: string, profile: string): {
     const targetDesktopFileName = 'gauntlet.desktop';
     const targetDesktopFilePath = path.join(bundleDir, targetDesktopFileName);
 
+    const targetDeepLinkDesktopFileName = 'gauntlet-deeplink.desktop';
+    const targetDeepLinkDesktopFilePath = path.join(bundleDir, targetDeepLinkDesktopFileName);
+
     const targetServiceFileName = 'gauntlet.service';
     const targetServiceFilePath = path.join(bundleDir, targetServiceFileName);
 
@@ -308,10 +312,11 @@ function packageForLinux(projectRoot: string, arch: string, profile: string): {
 
     copyFileSync(sourceExecutableFilePath, targetExecutableFilePath)
     copyFileSync(sourceDesktopFilePath, targetDesktopFilePath)
+    copyFileSync(sourceDeepLinkDesktopFilePath, targetDeepLinkDesktopFilePath)
     copyFileSync(sourceServiceFilePath, targetServiceFilePath)
     copyFileSync(sourceLogoFilePath, targetLogoFilePath)
 
-    spawnWithErrors(`tar`, ['-czvf', archiveFileName, targetExecutableFileName, targetDesktopFileName, targetServiceFileName, targetLogoFileName], {
+    spawnWithErrors(`tar`, ['-czvf', archiveFileName, targetExecutableFileName, targetDesktopFileName, targetDeepLinkDesktopFileName, targetServiceFileName, targetLogoFileName], {
         cwd: bundleDir
     })
 
@@ -1,7 +1,10 @@
 import type { FC } from "react";
 import { runEntrypointGenerators, runGeneratedEntrypoint, runGeneratedEntrypointAction } from "./entrypoint-generator";
 import { reloadSearchIndex } from "./search-index";
-import { closeView, handleEvent, handlePluginViewKeyboardEvent, renderInlineView, renderView } from "./render";
+import { runSearchProviders } from "./search-provider";
+import { installHttpCache } from "./http-cache";
+import { handleNotificationClosed } from "ext:gauntlet/renderer.js";
+import { closeView, handleEvent, handlePluginViewKeyboardEvent, popPluginView, renderInlineView, renderView } from "./render";
 import {
     entrypoint_preferences_required,
     get_entrypoint_preferences,
@@ -10,9 +13,11 @@ import {
     op_inline_view_entrypoint_id,
     op_log_trace,
     op_plugin_get_pending_event,
+    op_preload_entrypoint_ids,
+    plugin_onboarding_required,
     plugin_preferences_required,
-    show_plugin_error_view,
-    show_preferences_required_view
+    show_onboarding_view,
+    show_plugin_error_view
 } from "ext:core/ops";
 
 
@@ -37,21 +42,97 @@ async function checkRequiredPreferences(entrypointId: string): Promise<boolean>
     return pluginPreferencesRequired || entrypointPreferencesRequired;
 }
 
-async function checkRequiredPreferencesAndAsk(entrypointId: string): Promise<boolean> {
+// onboarding is shown the first time plugin is used and every time required preferences are missing
+async function checkOnboardingAndAsk(entrypointId: string): Promise<boolean> {
+    const onboardingRequired = await plugin_onboarding_required();
     const pluginPreferencesRequired = await plugin_preferences_required();
     const entrypointPreferencesRequired = await entrypoint_preferences_required(entrypointId);
 
-    const required = pluginPreferencesRequired || entrypointPreferencesRequired;
+    const required = onboardingRequired || pluginPreferencesRequired || entrypointPreferencesRequired;
     if (required) {
-        show_preferences_required_view(entrypointId, pluginPreferencesRequired, entrypointPreferencesRequired)
+        show_onboarding_view(entrypointId)
     }
 
     return required;
 }
 
+// at most one inline view render is in progress, text that arrives in the meantime replaces the one waiting for its turn.
+// signal passed to inline view is aborted as soon as newer text arrives
+let inlineViewInProgress = false
+let pendingInlineViewText: string | undefined = undefined
+let currentInlineView: AbortController | undefined = undefined
+
+function openInlineView(text: string) {
+    currentInlineView?.abort()
+    pendingInlineViewText = text
+
+    if (!inlineViewInProgress) {
+        // noinspection JSIgnoredPromiseFromCall
+        runPendingInlineViews()
+    }
+}
+
+async function runPendingInlineViews() {
+    inlineViewInProgress = true
+
+    try {
+        while (pendingInlineViewText !== undefined) {
+            const text = pendingInlineViewText;
+            pendingInlineViewText = undefined
+
+            const abortController = new AbortController();
+            currentInlineView = abortController
+
+            await runInlineView(text, abortController.signal)
+        }
+    } finally {
+        inlineViewInProgress = false
+    }
+}
+
+async function runInlineView(text: string, signal: AbortSignal) {
+    const entrypointId = op_inline_view_entrypoint_id();
+
+    if (entrypointId) {
+        if (await checkRequiredPreferences(entrypointId)) {
+            return;
+        }
+
+        try {
+            const handler: FC<{ text: string, signal: AbortSignal }> = (await import(`gauntlet:entrypoint?${entrypointId}`)).default;
+
+            if (signal.aborted) {
+                return;
+            }
+
+            renderInlineView(entrypointId, getEntrypointName(entrypointId), handler, text, signal)
+        } catch (e) {
+            console.error("Error occurred when rendering inline view", e)
+        }
+    }
+}
+
+// dynamic imports are cached by the runtime, so loading modules of views at plugin start
+// means that first opening of the view doesn't have to wait for them to be parsed and evaluated
+async function preloadEntrypoints() {
+    for (const entrypointId of op_preload_entrypoint_ids()) {
+        try {
+            await import(`gauntlet:entrypoint?${entrypointId}`)
+        } catch (e) {
+            // error is reported again when the view is opened
+            op_log_trace("plugin_loop", `Unable to preload entrypoint ${entrypointId}: ${Deno.inspect(e)}`)
+        }
+    }
+}
+
 export async function runPluginLoop() {
+    installHttpCache();
+
     await runEntrypointGenerators();
 
+    // noinspection ES6MissingAwait
+    preloadEntrypoints();
+
     // runtime is stopped using tokio cancellation
     // noinspection InfiniteLoopJS
     while (true) {
@@ -78,7 +159,7 @@ export async function runPluginLoop() {
             case "OpenView": {
                 const entrypointId = pluginEvent.entrypointId
                 try {
-                    if (await checkRequiredPreferencesAndAsk(entrypointId)) {
+                    if (await checkOnboardingAndAsk(entrypointId)) {
                         break;
                     }
 
@@ -94,22 +175,27 @@ export async function runPluginLoop() {
                 closeView()
                 break;
             }
+            case "PopView": {
+                popPluginView()
+                break;
+            }
             case "RunCommand": {
                 try {
-                    if (await checkRequiredPreferencesAndAsk(pluginEvent.entrypointId)) {
+                    if (await checkOnboardingAndAsk(pluginEvent.entrypointId)) {
                         break;
                     }
 
                     type CommandContext<P = object, E = object> = {
                         pluginPreferences: P,
                         entrypointPreferences: E,
+                        arguments: { [id: string]: string },
                     };
 
                     const pluginPreferences = get_plugin_preferences();
                     const entrypointPreferences = get_entrypoint_preferences(pluginEvent.entrypointId);
 
                     const command: (context: CommandContext) => Promise<void> | void = (await import(`gauntlet:entrypoint?${pluginEvent.entrypointId}`)).default;
-                    command({ pluginPreferences, entrypointPreferences })
+                    command({ pluginPreferences, entrypointPreferences, arguments: pluginEvent.arguments })
                 } catch (e) {
                     console.error("Error occurred when running a command", pluginEvent.entrypointId, e)
                 }
@@ -124,21 +210,7 @@ export async function runPluginLoop() {
                 break;
             }
             case "OpenInlineView": {
-                const entrypointId = op_inline_view_entrypoint_id();
-
-                if (entrypointId) {
-                    if (await checkRequiredPreferences(entrypointId)) {
-                        break;
-                    }
-
-                    try {
-                        const handler: FC<{ text: string }> = (await import(`gauntlet:entrypoint?${entrypointId}`)).default;
-
-                        renderInlineView(entrypointId, getEntrypointName(entrypointId), handler, pluginEvent.text)
-                    } catch (e) {
-                        console.error("Error occurred when rendering inline view", e)
-                    }
-                }
+                openInlineView(pluginEvent.text)
                 break;
             }
             case "RefreshSearchIndex": {
@@ -146,6 +218,24 @@ export async function runPluginLoop() {
                 reloadSearchIndex(false)
                 break;
             }
+            case "Search": {
+                // noinspection ES6MissingAwait
+                runSearchProviders(pluginEvent.text, pluginEvent.searchId)
+                break;
+            }
+            case "NotificationClosed": {
+                try {
+                    handleNotificationClosed(pluginEvent.notificationId, pluginEvent.actionIndex)
+                } catch (e) {
+                    console.error("Error occurred when handling notification action", pluginEvent.notificationId, e)
+                }
+                break;
+            }
+            case "ViewRenderFailed": {
+                console.error("Error occurred when showing view", pluginEvent.entrypointId, pluginEvent.message)
+                show_plugin_error_view(pluginEvent.entrypointId, pluginEvent.renderLocation)
+                break;
+            }
         }
     }
 }
@@ -10,12 +10,17 @@ import {
 import { reloadSearchIndex } from "./search-index";
 import type { FC } from "react";
 import { renderView } from "./render";
+import { searchProviderEntrypoint } from "./search-provider";
 
-interface GeneratedEntrypoint { // TODO is it possible to import api here
+export interface GeneratedEntrypoint { // TODO is it possible to import api here
     name: string
+    subtitle?: string
+    keywords?: string[]
     actions: GeneratedEntrypointAction[]
     icon?: ArrayBuffer
     accessories?: GeneratedEntrypointAccessory[]
+    preview?: string
+    file?: string
 }
 
 type GeneratedEntrypointAction = GeneratedEntrypointActionRun | GeneratedEntrypointActionView
@@ -43,7 +48,7 @@ export type GeneratorContext<P = object, E = object> = {
 
 type Generator = (props: GeneratorContext) => void | (() => (void | Promise<void>)) | Promise<void | (() => (void | Promise<void>))>
 
-type ProcessedGeneratedEntrypoint = {
+export type ProcessedGeneratedEntrypoint = {
     generatorEntrypointId: string,
     id: string,
     uuid: string,
@@ -102,37 +107,7 @@ export async function runEntrypointGenerators(): Promise<void> {
                     throw new Error(`Error when adding entry '${id}': at least one action should be provided`)
                 }
 
-                const derivedActions: GeneratedEntrypointDerivedAction[] = []
-                for (const action of data.actions) {
-                    const label = action.label;
-
-                    const run = "run" in action;
-                    const view = "view" in action;
-
-                    if (run && view) {
-                        throw new Error(`only one of 'run' or 'view' properties can be specified in action: '${label}'`)
-                    }
-
-                    if (!run && !view) {
-                        throw new Error(`one of 'run' or 'view' properties has to be specified in action: '${label}'`)
-                    }
-
-                    if (run) {
-                        derivedActions.push({
-                            type: "Command",
-                            ref: action.ref,
-                            label: action.label,
-                            run: action.run,
-                        })
-                    } else if (view) {
-                        derivedActions.push({
-                            type: "View",
-                            ref: action.ref,
-                            label: action.label,
-                            view: action.view,
-                        })
-                    }
-                }
+                const derivedActions = deriveActions(data)
 
                 const lookupId = generatorEntrypointId + ":" + id;
 
@@ -198,12 +173,50 @@ export async function runEntrypointGenerators(): Promise<void> {
     }
 }
 
+export function deriveActions(data: GeneratedEntrypoint): GeneratedEntrypointDerivedAction[] {
+    const derivedActions: GeneratedEntrypointDerivedAction[] = []
+    for (const action of data.actions) {
+        const label = action.label;
+
+        const run = "run" in action;
+        const view = "view" in action;
+
+        if (run && view) {
+            throw new Error(`only one of 'run' or 'view' properties can be specified in action: '${label}'`)
+        }
+
+        if (!run && !view) {
+            throw new Error(`one of 'run' or 'view' properties has to be specified in action: '${label}'`)
+        }
+
+        if (run) {
+            derivedActions.push({
+                type: "Command",
+                ref: action.ref,
+                label: action.label,
+                run: action.run,
+            })
+        } else if (view) {
+            derivedActions.push({
+                type: "View",
+                ref: action.ref,
+                label: action.label,
+                view: action.view,
+            })
+        }
+    }
+
+    return derivedActions
+}
+
 export function generatedEntrypointSearchIndex(): GeneratedSearchItem[] {
     return Object.entries(storedGeneratedEntrypoints).map(([entrypointLookupId, value]) => ({
         generator_entrypoint_id: value.generatorEntrypointId,
         entrypoint_id: entrypointLookupId,
         entrypoint_uuid: value.uuid,
         entrypoint_name: value.command.name,
+        entrypoint_subtitle: value.command.subtitle,
+        entrypoint_keywords: value.command.keywords || [],
         entrypoint_icon: value.command.icon,
         entrypoint_actions: value.derivedActions
             .map(action => ({
@@ -211,31 +224,38 @@ export function generatedEntrypointSearchIndex(): GeneratedSearchItem[] {
                 action_type: action.type,
                 label: action.label
             })),
-        entrypoint_accessories: value.command.accessories || []
+        entrypoint_accessories: value.command.accessories || [],
+        entrypoint_preview: value.command.preview,
+        entrypoint_file: value.command.file,
     }))
 }
 
+// entries returned by search providers are run the same way as generated entrypoints
+function findGeneratedEntrypoint(entrypointId: string): ProcessedGeneratedEntrypoint | undefined {
+    return storedGeneratedEntrypoints[entrypointId] ?? searchProviderEntrypoint(entrypointId)
+}
+
 export async function runGeneratedEntrypointAction(entrypointId: string, key: string, modifierShift: boolean, modifierControl: boolean, modifierAlt: boolean, modifierMeta: boolean) {
-    const command = storedGeneratedEntrypoints[entrypointId];
+    const command = findGeneratedEntrypoint(entrypointId);
 
     if (command) {
         const id = await fetch_action_id_for_shortcut(command.generatorEntrypointId, key, modifierShift, modifierControl, modifierAlt, modifierMeta);
         if (id) {
             const action = command.derivedActions.find(value => value.ref == id);
             if (action) {
-                runAction(entrypointId, action)
+                runAction(entrypointId, command, action)
             }
         }
     }
 }
 
 export function runGeneratedEntrypoint(entrypointId: string, action_index: number) {
-    const generatedEntrypoint = storedGeneratedEntrypoints[entrypointId];
+    const generatedEntrypoint = findGeneratedEntrypoint(entrypointId);
 
     if (generatedEntrypoint) {
         const action = generatedEntrypoint.derivedActions[action_index];
         if (action) {
-            runAction(entrypointId, action)
+            runAction(entrypointId, generatedEntrypoint, action)
         } else {
             throw new Error("Generated command with entrypoint id '" + entrypointId + "' doesn't have action with index: " + action_index)
         }
@@ -244,7 +264,7 @@ export function runGeneratedEntrypoint(entrypointId: string, action_index: numbe
     }
 }
 
-function runAction(entrypointId: string, action: GeneratedEntrypointDerivedAction) {
+function runAction(entrypointId: string, generatedEntrypoint: ProcessedGeneratedEntrypoint, action: GeneratedEntrypointDerivedAction) {
     switch (action.type) {
         case "Command": {
             action.run()
@@ -252,11 +272,7 @@ function runAction(entrypointId: string, action: GeneratedEntrypointDerivedActio
             break;
         }
         case "View": {
-            const entrypointName = storedGeneratedEntrypoints[entrypointId]
-                .command
-                .name
-
-            renderView(entrypointId, entrypointName, action.view)
+            renderView(entrypointId, generatedEntrypoint.command.name, action.view)
             break;
         }
     }
@@ -0,0 +1,106 @@
+import { op_http_cache_config, op_http_cache_get, op_http_cache_put, op_http_cache_refresh } from "ext:core/ops";
+
+// replaces fetch for plugins which enabled http cache in manifest.
+// only GET requests without credentials are cached, stale responses are revalidated using ETag and Last-Modified headers
+export function installHttpCache() {
+    const config = op_http_cache_config();
+
+    if (!config) {
+        return
+    }
+
+    const networkFetch = globalThis.fetch;
+
+    globalThis.fetch = async (input: RequestInfo | URL, init?: RequestInit): Promise<Response> => {
+        const request = new Request(input, init);
+
+        if (!isCacheable(request, init)) {
+            return await networkFetch(request)
+        }
+
+        const cached = await op_http_cache_get(request.url, [...request.headers]);
+
+        if (cached) {
+            if (cached.fresh) {
+                return cachedResponse(cached)
+            }
+
+            if (config.stale_while_revalidate) {
+                revalidate(networkFetch, request, cached)
+                    .catch(reason => console.warn(`Unable to revalidate cached response for ${request.url}:`, reason));
+
+                return cachedResponse(cached)
+            }
+        }
+
+        return await revalidate(networkFetch, request, cached ?? undefined)
+    }
+}
+
+function isCacheable(request: Request, init: RequestInit | undefined): boolean {
+    if (request.method !== "GET") {
+        return false
+    }
+
+    if (init?.cache === "no-store" || init?.cache === "reload") {
+        return false
+    }
+
+    // responses to authorized requests are specific to the credentials, they are never shared
+    if (request.headers.has("Authorization") || request.headers.has("Cookie")) {
+        return false
+    }
+
+    // plugin does its own revalidation
+    return !request.headers.has("If-None-Match") && !request.headers.has("If-Modified-Since")
+}
+
+async function revalidate(networkFetch: typeof fetch, request: Request, cached: HttpCacheEntry | undefined): Promise<Response> {
+    const headers = new Headers(request.headers);
+
+    if (cached?.etag) {
+        headers.set("If-None-Match", cached.etag);
+    }
+
+    if (cached?.last_modified) {
+        headers.set("If-Modified-Since", cached.last_modified);
+    }
+
+    const response = await networkFetch(new Request(request, { headers }));
+
+    if (cached && response.status === 304) {
+        try {
+            await op_http_cache_refresh(request.url, [...request.headers], [...response.headers]);
+        } catch (e) {
+            console.warn(`Unable to refresh cached response for ${request.url}:`, e);
+        }
+
+        return cachedResponse(cached)
+    }
+
+    if (response.status !== 200) {
+        return response
+    }
+
+    const body = new Uint8Array(await response.arrayBuffer());
+
+    try {
+        await op_http_cache_put(request.url, [...request.headers], response.status, response.statusText, [...response.headers], body);
+    } catch (e) {
+        console.warn(`Unable to cache response for ${request.url}:`, e);
+    }
+
+    return new Response(body, {
+        status: response.status,
+        statusText: response.statusText,
+        headers: response.headers,
+    })
+}
+
+function cachedResponse(cached: HttpCacheEntry): Response {
+    return new Response(cached.body, {
+        status: cached.status,
+        statusText: cached.status_text,
+        headers: cached.headers,
+    })
+}
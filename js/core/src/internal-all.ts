@@ -3,4 +3,18 @@ export {
     open_settings,
     current_os,
     wayland,
+    shell_command_run,
+    shell_command_poll,
+    shell_command_kill,
+    run_system_command,
+    media_now_playing,
+    media_control,
+    quick_note_append,
+    quick_note_run_hook,
+    process_list,
+    process_terminate,
+    process_kill,
+    pick_color_from_screen,
+    show_window_with_search_query,
+    hide_window,
 } from "ext:core/ops";
@@ -5,19 +5,17 @@ import {
     op_log_trace,
     hide_window
 } from "ext:core/ops";
-import { clearRenderer, render } from "ext:gauntlet/renderer.js";
+import { clearRenderer, findKeyHandler, popView, render, topmostContainer } from "ext:gauntlet/renderer.js";
 import type { FC } from "react";
 
-let latestRootUiWidget: UiWidget | undefined = undefined
-
 export function renderView(entrypointId: string, entrypointName: string, View: FC) {
-    latestRootUiWidget = render(entrypointId, entrypointName, "View", <View/>);
+    render(entrypointId, entrypointName, "View", <View/>);
 }
 
-export function renderInlineView(entrypointId: string, entrypointName: string, Handler: FC<{ text: string }>, text: string) {
-    latestRootUiWidget = render(entrypointId, entrypointName, "InlineView", <Handler text={text}/>);
+export function renderInlineView(entrypointId: string, entrypointName: string, Handler: FC<{ text: string, signal: AbortSignal }>, text: string, signal: AbortSignal) {
+    const rootUiWidget = render(entrypointId, entrypointName, "InlineView", <Handler text={text} signal={signal}/>);
 
-    if (latestRootUiWidget.widgetChildren.length === 0) {
+    if (rootUiWidget.widgetChildren.length === 0) {
         op_log_debug("plugin_loop", `Inline view rendered no children, clearing inline view...`)
         clear_inline_view()
     }
@@ -27,9 +25,21 @@ export function closeView() {
     clearRenderer()
 }
 
+export function popPluginView() {
+    popView()
+}
+
 export async function handlePluginViewKeyboardEvent(entrypointId: string, key: string, modifierShift: boolean, modifierControl: boolean, modifierAlt: boolean, modifierMeta: boolean) {
-    if (latestRootUiWidget) {
-        const actionHandlers = findAllActionHandlers(latestRootUiWidget);
+    // key handlers registered by view take precedence over action shortcuts
+    const keyHandler = findKeyHandler(key, modifierShift, modifierControl, modifierAlt, modifierMeta);
+    if (keyHandler) {
+        keyHandler()
+        return
+    }
+
+    const rootUiWidget = topmostContainer();
+    if (rootUiWidget) {
+        const actionHandlers = findAllActionHandlers(rootUiWidget);
 
         const id = await fetch_action_id_for_shortcut(entrypointId, key, modifierShift, modifierControl, modifierAlt, modifierMeta);
 
@@ -64,11 +74,79 @@ function findAllActionHandlers(widget: UiWidget): { id: string, onAction: () =>
     return result;
 }
 
+function convertPropertyValue(value: PropertyValue): any {
+    switch (value.type) {
+        case "Undefined": {
+            return undefined
+        }
+        case "String": {
+            return value.value
+        }
+        case "Number": {
+            return value.value
+        }
+        case "Bool": {
+            return value.value
+        }
+        case "Color": {
+            return value.value
+        }
+        case "Enum": {
+            return value.value
+        }
+        case "Bytes": {
+            return new Uint8Array(value.value).buffer
+        }
+        case "Array": {
+            return value.value.map(item => convertPropertyValue(item))
+        }
+        case "Object": {
+            return Object.fromEntries(
+                Object.entries(value.value)
+                    .map(([key, item]) => [key, convertPropertyValue(item)])
+            )
+        }
+    }
+}
+
+// change events of text fields with debounce property, only the last one is delivered after the delay
+const debouncedEvents = new Map<number, number>();
+
+function debounceDelay(widget: UiWidget, eventName: string): number | undefined {
+    if (widget.widgetType === "gauntlet:text_field" && eventName === "onChange") {
+        const debounce = widget.widgetProperties["debounce"];
+        if (typeof debounce === "number" && debounce > 0) {
+            return debounce
+        }
+    }
+
+    return undefined
+}
+
 export function handleEvent(event: ViewEvent) {
+    const rootUiWidget = topmostContainer();
+    const widgetWithId = rootUiWidget ? findWidgetWithId(rootUiWidget, event.widgetId) : undefined;
+    const delay = widgetWithId ? debounceDelay(widgetWithId, event.eventName) : undefined;
+
+    if (delay) {
+        clearTimeout(debouncedEvents.get(event.widgetId));
+
+        // handler is looked up again when timer fires, so the one from the latest render is called
+        debouncedEvents.set(event.widgetId, setTimeout(() => {
+            debouncedEvents.delete(event.widgetId);
+            handleEventNow(event)
+        }, delay));
+    } else {
+        handleEventNow(event)
+    }
+}
+
+function handleEventNow(event: ViewEvent) {
     op_log_trace("plugin_event_handler", `Handling view event: ${Deno.inspect(event)}`);
-    op_log_trace("plugin_event_handler", `Root widget: ${Deno.inspect(latestRootUiWidget)}`);
-    if (latestRootUiWidget) {
-        const widgetWithId = findWidgetWithId(latestRootUiWidget, event.widgetId);
+    const rootUiWidget = topmostContainer();
+    op_log_trace("plugin_event_handler", `Root widget: ${Deno.inspect(rootUiWidget)}`);
+    if (rootUiWidget) {
+        const widgetWithId = findWidgetWithId(rootUiWidget, event.widgetId);
         op_log_trace("plugin_event_handler", `Found widget with id ${event.widgetId}: ${Deno.inspect(widgetWithId)}`)
 
         if (widgetWithId) {
@@ -80,22 +158,7 @@ export function handleEvent(event: ViewEvent) {
                 if (typeof property === "function") {
 
                     const eventArgs = event.eventArguments
-                        .map(arg => {
-                            switch (arg.type) {
-                                case "Undefined": {
-                                    return undefined
-                                }
-                                case "String": {
-                                    return arg.value
-                                }
-                                case "Number": {
-                                    return arg.value
-                                }
-                                case "Bool": {
-                                    return arg.value
-                                }
-                            }
-                        });
+                        .map(arg => convertPropertyValue(arg));
 
                     op_log_trace("plugin_event_handler", `Calling handler with arguments ${Deno.inspect(eventArgs)}`);
 
@@ -0,0 +1,104 @@
+import {
+    get_entrypoint_preferences,
+    get_plugin_preferences,
+    get_search_provider_entrypoint_ids,
+    op_log_debug,
+    search_provider_results
+} from "ext:core/ops";
+import { deriveActions, GeneratedEntrypoint, ProcessedGeneratedEntrypoint } from "./entrypoint-generator";
+
+type SearchProviderResult = GeneratedEntrypoint & {
+    id: string
+}
+
+export type SearchProviderContext<P = object, E = object> = {
+    query: string,
+    signal: AbortSignal,
+    pluginPreferences: P,
+    entrypointPreferences: E,
+};
+
+type SearchProvider = (props: SearchProviderContext) => SearchProviderResult[] | Promise<SearchProviderResult[]>
+
+// results for current search query only, previous ones are discarded when new query arrives
+let storedSearchProviderEntrypoints: { [lookupEntrypointId: string]: ProcessedGeneratedEntrypoint } = {}
+let currentSearch: AbortController | undefined = undefined
+
+export function searchProviderEntrypoint(entrypointId: string): ProcessedGeneratedEntrypoint | undefined {
+    return storedSearchProviderEntrypoints[entrypointId]
+}
+
+export async function runSearchProviders(query: string, searchId: number): Promise<void> {
+    currentSearch?.abort()
+
+    const abortController = new AbortController();
+    currentSearch = abortController
+    storedSearchProviderEntrypoints = {}
+
+    const entrypointIds = await get_search_provider_entrypoint_ids();
+    for (const providerEntrypointId of entrypointIds) {
+        // noinspection ES6MissingAwait
+        (async () => {
+            try {
+                const provider: SearchProvider = (await import(`gauntlet:entrypoint?${providerEntrypointId}`)).default;
+
+                op_log_debug("search_provider", `Running search provider entrypoint '${providerEntrypointId}' for search ${searchId}`)
+
+                const pluginPreferences = get_plugin_preferences();
+                const entrypointPreferences = get_entrypoint_preferences(providerEntrypointId);
+
+                const results = await provider({ query, signal: abortController.signal, pluginPreferences, entrypointPreferences })
+
+                if (abortController.signal.aborted) {
+                    return
+                }
+
+                const searchItems: GeneratedSearchItem[] = []
+
+                for (const result of results) {
+                    if (result.actions.length < 1) {
+                        throw new Error(`Error when returning search result '${result.id}': at least one action should be provided`)
+                    }
+
+                    const lookupId = providerEntrypointId + ":" + result.id;
+
+                    const processed: ProcessedGeneratedEntrypoint = {
+                        generatorEntrypointId: providerEntrypointId,
+                        id: result.id,
+                        uuid: crypto.randomUUID(),
+                        command: result,
+                        derivedActions: deriveActions(result),
+                    }
+
+                    storedSearchProviderEntrypoints[lookupId] = processed
+
+                    searchItems.push({
+                        generator_entrypoint_id: providerEntrypointId,
+                        entrypoint_id: lookupId,
+                        entrypoint_uuid: processed.uuid,
+                        entrypoint_name: result.name,
+                        entrypoint_subtitle: result.subtitle,
+                        entrypoint_icon: result.icon,
+                        entrypoint_actions: processed.derivedActions
+                            .map(action => ({
+                                id: action.ref,
+                                action_type: action.type,
+                                label: action.label
+                            })),
+                        entrypoint_accessories: result.accessories || [],
+                        entrypoint_preview: result.preview,
+                        entrypoint_file: result.file,
+                    })
+                }
+
+                await search_provider_results(searchId, providerEntrypointId, searchItems)
+            } catch (e) {
+                if (abortController.signal.aborted) {
+                    return
+                }
+
+                console.error(`Error occurred when calling search provider for entrypoint: ${providerEntrypointId}`, e)
+            }
+        })()
+    }
+}
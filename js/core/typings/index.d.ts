@@ -3,6 +3,8 @@ declare module "ext:gauntlet/renderer.js" {
 
     export const render: (entrypointId: string, entrypointName: string, renderLocation: RenderLocation, component: ReactNode) => UiWidget;
     export const clearRenderer: () => void;
+    export const popView: () => void;
+    export const topmostContainer: () => UiWidget | undefined;
 }
 
 declare module "gauntlet:core" {
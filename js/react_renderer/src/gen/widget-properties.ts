@@ -0,0 +1,54 @@
+export type WidgetType = "gauntlet:text_part" | "gauntlet:action" | "gauntlet:action_panel_section" | "gauntlet:action_panel" | "gauntlet:metadata_link" | "gauntlet:metadata_tag_item" | "gauntlet:metadata_tag_list" | "gauntlet:metadata_separator" | "gauntlet:metadata_value" | "gauntlet:metadata_icon" | "gauntlet:metadata" | "gauntlet:image" | "gauntlet:h1" | "gauntlet:h2" | "gauntlet:h3" | "gauntlet:h4" | "gauntlet:h5" | "gauntlet:h6" | "gauntlet:horizontal_break" | "gauntlet:code_block" | "gauntlet:skeleton" | "gauntlet:media_item" | "gauntlet:paragraph" | "gauntlet:content" | "gauntlet:detail" | "gauntlet:text_field" | "gauntlet:password_field" | "gauntlet:text_area" | "gauntlet:checkbox" | "gauntlet:date_picker" | "gauntlet:select_item" | "gauntlet:select" | "gauntlet:separator" | "gauntlet:form" | "gauntlet:inline_separator" | "gauntlet:inline" | "gauntlet:empty_view" | "gauntlet:accessory_icon" | "gauntlet:accessory_text" | "gauntlet:search_bar" | "gauntlet:tab" | "gauntlet:tabs" | "gauntlet:list_column" | "gauntlet:list_columns" | "gauntlet:list_item" | "gauntlet:list_section" | "gauntlet:list" | "gauntlet:grid_item" | "gauntlet:grid_section" | "gauntlet:grid" | "gauntlet:root";
+export const widgetProperties: Record<WidgetType, string[]> = {
+    "gauntlet:text_part": ["value"],
+    "gauntlet:action": ["id", "label", "onAction"],
+    "gauntlet:action_panel_section": ["title"],
+    "gauntlet:action_panel": ["title"],
+    "gauntlet:metadata_link": ["label", "href"],
+    "gauntlet:metadata_tag_item": ["onClick"],
+    "gauntlet:metadata_tag_list": ["label"],
+    "gauntlet:metadata_separator": [],
+    "gauntlet:metadata_value": ["label"],
+    "gauntlet:metadata_icon": ["icon", "label"],
+    "gauntlet:metadata": [],
+    "gauntlet:image": ["source"],
+    "gauntlet:h1": [],
+    "gauntlet:h2": [],
+    "gauntlet:h3": [],
+    "gauntlet:h4": [],
+    "gauntlet:h5": [],
+    "gauntlet:h6": [],
+    "gauntlet:horizontal_break": [],
+    "gauntlet:code_block": [],
+    "gauntlet:skeleton": ["variant", "width", "height"],
+    "gauntlet:media_item": ["title", "subtitle", "artwork", "position", "duration"],
+    "gauntlet:paragraph": [],
+    "gauntlet:content": [],
+    "gauntlet:detail": ["isLoading", "actions"],
+    "gauntlet:text_field": ["label", "value", "placeholder", "debounce", "onChange", "onSubmit", "onFocus", "onBlur"],
+    "gauntlet:password_field": ["label", "value", "onChange"],
+    "gauntlet:text_area": ["label", "value", "placeholder", "maxLength", "onChange", "onSubmit"],
+    "gauntlet:checkbox": ["label", "title", "value", "onChange"],
+    "gauntlet:date_picker": ["label", "value", "onChange"],
+    "gauntlet:select_item": ["value"],
+    "gauntlet:select": ["label", "value", "onChange"],
+    "gauntlet:separator": [],
+    "gauntlet:form": ["isLoading", "actions"],
+    "gauntlet:inline_separator": ["icon"],
+    "gauntlet:inline": ["actions"],
+    "gauntlet:empty_view": ["title", "description", "image"],
+    "gauntlet:accessory_icon": ["icon", "tooltip"],
+    "gauntlet:accessory_text": ["text", "icon", "tooltip"],
+    "gauntlet:search_bar": ["value", "placeholder", "onChange"],
+    "gauntlet:tab": ["id", "title"],
+    "gauntlet:tabs": ["value", "onChange"],
+    "gauntlet:list_column": ["title"],
+    "gauntlet:list_columns": [],
+    "gauntlet:list_item": ["id", "title", "subtitle", "icon", "accessories", "dragFile", "dragText"],
+    "gauntlet:list_section": ["title", "subtitle"],
+    "gauntlet:list": ["actions", "isLoading", "hasMore", "onItemFocusChange", "onLoadMore"],
+    "gauntlet:grid_item": ["id", "title", "subtitle", "accessory", "dragFile", "dragText"],
+    "gauntlet:grid_section": ["title", "subtitle", "columns"],
+    "gauntlet:grid": ["isLoading", "actions", "columns", "onItemFocusChange"],
+    "gauntlet:root": []
+};
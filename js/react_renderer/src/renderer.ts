@@ -1,5 +1,5 @@
 import ReactReconciler, { HostConfig, OpaqueHandle } from "react-reconciler";
-import { createContext, ReactNode, useContext } from 'react';
+import { Component as ReactComponent, createContext, createElement, ErrorInfo, Fragment, ReactNode, useContext } from 'react';
 import { DefaultEventPriority } from 'react-reconciler/constants';
 import {
     asset_data,
@@ -9,8 +9,10 @@ import {
     op_component_model,
     op_log_trace,
     op_react_replace_view,
-    show_hud
+    show_hud,
+    show_notification
 } from "ext:core/ops";
+import { widgetProperties, WidgetType } from "./gen/widget-properties";
 
 // Usage of MessageChannel seems to block Deno runtime from exiting
 // causing plugin to be in stuck state where it is disabled but still have running runtime
@@ -42,22 +44,44 @@ type UpdatePayload = string[];
 type SuspenseInstance = never;
 type ChildSet = UiWidget[]
 
+type NavigationEntry = {
+    title: string
+    container: RootUiWidget
+    render: (node: ReactNode) => void
+    // last rendered content, sent again when view becomes topmost after views above it are popped
+    content: any | undefined
+    keyHandlers: KeyHandler[]
+}
+
+type KeyHandler = {
+    key: string
+    modifierShift: boolean
+    modifierControl: boolean
+    modifierAlt: boolean
+    modifierMeta: boolean
+    handler: () => void
+}
+
 class GauntletContextValue {
-    private _navStack: ReactNode[] = []
+    private _navStack: NavigationEntry[] = []
     private _renderLocation: RenderLocation | undefined
-    private _rerender: ((node: ReactNode) => void) | undefined
+    private _createEntry: ((title: string) => NavigationEntry) | undefined
     private _entrypointId: string | undefined;
     private _entrypointName: string | undefined;
-    private _clear: (() => void) | undefined;
 
-    reset(entrypointId: string, entrypointName: string, renderLocation: RenderLocation, view: ReactNode, rerender: (node: ReactNode) => void, clear: () => void) {
+    reset(entrypointId: string, entrypointName: string, renderLocation: RenderLocation, createEntry: (title: string) => NavigationEntry) {
+        const previousNavStack = this._navStack;
+
         this._entrypointId = entrypointId
         this._entrypointName = entrypointName
         this._renderLocation = renderLocation
-        this._rerender = rerender
-        this._clear = clear
+        this._createEntry = createEntry
         this._navStack = []
-        this._navStack.push(view)
+
+        // unmount previous views, so their effects are cleaned up. they are not in the stack anymore, so nothing is sent
+        for (const entry of previousNavStack) {
+            entry.render(null)
+        }
     }
 
     renderLocation = (): RenderLocation => {
@@ -68,8 +92,12 @@ class GauntletContextValue {
         return this._navStack.length === 1
     }
 
-    topmostView = () => {
-        return this._navStack[this._navStack.length - 1]
+    topmostContainer = (): RootUiWidget | undefined => {
+        return this._navStack[this._navStack.length - 1]?.container
+    }
+
+    breadcrumbs = (): string[] => {
+        return this._navStack.map(entry => entry.title)
     }
 
     entrypointId = () => {
@@ -80,24 +108,98 @@ class GauntletContextValue {
         return this._entrypointName!!
     }
 
-    rerender = (component: ReactNode) => {
-        this._rerender!!(component)
-    };
+    // every view in navigation stack is rendered into separate root,
+    // so state of views below the topmost one is preserved while they are not shown
+    viewRendered = (container: RootUiWidget, content: any) => {
+        const entry = this._navStack.find(entry => entry.container === container);
+
+        // view was already popped
+        if (!entry) {
+            return
+        }
+
+        entry.content = content
+
+        if (entry === this._navStack[this._navStack.length - 1]) {
+            this.sendView(entry)
+        }
+    }
+
+    private sendView = (entry: NavigationEntry) => {
+        op_react_replace_view(
+            this.renderLocation(),
+            this.isBottommostView(),
+            this.entrypointId(),
+            this.entrypointName(),
+            this.breadcrumbs(),
+            entry.keyHandlers.map(({ handler, ...shortcut }) => shortcut),
+            entry.content
+        )
+    }
+
+    // frontend needs to know which keys are handled by the view, so view is sent again when handlers change
+    addKeyHandler = (entry: NavigationEntry, keyHandler: KeyHandler): () => void => {
+        entry.keyHandlers.push(keyHandler)
+        this.keyHandlersChanged(entry)
+
+        return () => {
+            entry.keyHandlers = entry.keyHandlers.filter(value => value !== keyHandler)
+            this.keyHandlersChanged(entry)
+        }
+    }
+
+    private keyHandlersChanged = (entry: NavigationEntry) => {
+        if (entry === this._navStack[this._navStack.length - 1] && entry.content) {
+            this.sendView(entry)
+        }
+    }
+
+    findKeyHandler = (key: string, modifierShift: boolean, modifierControl: boolean, modifierAlt: boolean, modifierMeta: boolean): (() => void) | undefined => {
+        const entry = this._navStack[this._navStack.length - 1];
+
+        // the last registered handler wins if more than one is registered for the same shortcut
+        return entry?.keyHandlers
+            .findLast(value => value.key === key
+                && value.modifierShift === modifierShift
+                && value.modifierControl === modifierControl
+                && value.modifierAlt === modifierAlt
+                && value.modifierMeta === modifierMeta)
+            ?.handler
+    }
 
     clear = () => {
-        this._clear!!()
+        while (this._navStack.length > 1) {
+            this._navStack.pop()!!.render(null)
+        }
+
+        this._navStack[0]?.render(null)
     };
 
-    pushView = (component: ReactNode) => {
-        this._navStack.push(component)
+    pushView = (component: ReactNode, title?: string) => {
+        const entry = this._createEntry!!(title ?? this.entrypointName());
+
+        this._navStack.push(entry)
 
-        this.rerender(component)
+        if (this.renderLocation() === "View") {
+            entry.render(createElement(navigationEntryContext.Provider, { value: entry }, createElement(ViewErrorBoundary, {}, component)))
+        } else {
+            entry.render(createElement(navigationEntryContext.Provider, { value: entry }, component))
+        }
     };
 
     popView = () => {
-        this._navStack.pop();
+        if (this._navStack.length <= 1) {
+            return
+        }
+
+        const entry = this._navStack.pop()!!;
+
+        entry.render(null)
 
-        this.rerender(this.topmostView())
+        const topmost = this._navStack[this._navStack.length - 1];
+        if (topmost.content) {
+            this.sendView(topmost)
+        }
     };
 
     entrypointPreferences = () => {
@@ -109,6 +211,60 @@ class GauntletContextValue {
     }
 }
 
+type ViewErrorBoundaryProps = {
+    children?: ReactNode
+}
+
+type ViewErrorBoundaryState = {
+    error: unknown | undefined
+    attempt: number
+}
+
+// error thrown when rendering the view is shown inside the view itself,
+// instead of replacing whole plugin view with error screen.
+// "Reload View" remounts the children, so their state is reset
+class ViewErrorBoundary extends ReactComponent<ViewErrorBoundaryProps, ViewErrorBoundaryState> {
+    state: ViewErrorBoundaryState = {
+        error: undefined,
+        attempt: 0,
+    }
+
+    static getDerivedStateFromError(error: unknown): Partial<ViewErrorBoundaryState> {
+        return { error }
+    }
+
+    componentDidCatch(error: unknown, errorInfo: ErrorInfo) {
+        console.error("Error occurred when rendering view", error, errorInfo.componentStack)
+    }
+
+    reload = () => {
+        this.setState(state => ({ error: undefined, attempt: state.attempt + 1 }))
+    }
+
+    render(): ReactNode {
+        if (this.state.error === undefined) {
+            return createElement(Fragment, { key: this.state.attempt }, this.props.children)
+        }
+
+        const error = this.state.error;
+        const description = error instanceof Error ? error.message : String(error);
+
+        return createElement(
+            "gauntlet:list",
+            {},
+            createElement(
+                "gauntlet:empty_view",
+                {
+                    title: "Error occurred when rendering view",
+                    description,
+                    image: "ExclamationMark"
+                },
+                createElement("gauntlet:action", { label: "Reload View", onAction: this.reload })
+            )
+        )
+    }
+}
+
 const gauntletContextValue = new GauntletContextValue()
 const gauntletContext = createContext(gauntletContextValue);
 
@@ -116,6 +272,13 @@ export function useGauntletContext() {
     return useContext(gauntletContext);
 }
 
+// every view in navigation stack has its own entry, so hooks can register things which belong only to that view
+const navigationEntryContext = createContext<NavigationEntry | undefined>(undefined);
+
+export function useNavigationEntry() {
+    return useContext(navigationEntryContext);
+}
+
 export async function getAssetData(path: string): Promise<ArrayBuffer> {
     const vecU8 = await asset_data(path);
     return new Uint8Array(vecU8).buffer; // FIXME move array creation into rust if possible
@@ -138,12 +301,36 @@ export function showHudWindow(display: string): void {
     show_hud(display)
 }
 
+// callbacks of notification action buttons, keyed by notification id,
+// removed when notification is closed
+const notificationActions = new Map<number, (() => void)[]>();
+
+export async function showDesktopNotification(title: string, body: string | undefined, actions: { label: string, onAction: () => void }[]): Promise<void> {
+    const notificationId = await show_notification(title, body, actions.map(action => action.label));
+
+    if (actions.length > 0) {
+        notificationActions.set(notificationId, actions.map(action => action.onAction))
+    }
+}
+
+export function handleNotificationClosed(notificationId: number, actionIndex: number | null): void {
+    const actions = notificationActions.get(notificationId);
+
+    notificationActions.delete(notificationId);
+
+    if (actions && actionIndex != null) {
+        actions[actionIndex]?.()
+    }
+}
+
 function createWidget(hostContext: HostContext, type: ComponentType, properties: Props, children: UiWidget[] = []): Instance {
     const props = Object.fromEntries(
         Object.entries(properties)
             .filter(([key, _]) => key !== "children")
     );
 
+    validateWidget(type, props)
+
     const instance: Instance = {
         widgetId: hostContext.nextId,
         widgetType: type,
@@ -155,6 +342,22 @@ function createWidget(hostContext: HostContext, type: ComponentType, properties:
     return instance
 }
 
+// widgets are deserialized on rust side using types generated from the same component model as widget properties,
+// but properties unknown to it would be silently dropped there, so they are reported here with a readable error
+function validateWidget(type: ComponentType, props: Props) {
+    const knownProps: string[] | undefined = widgetProperties[type as WidgetType];
+
+    if (knownProps === undefined) {
+        throw new Error(`Unknown widget type: ${type}`)
+    }
+
+    for (const name of Object.keys(props)) {
+        if (!knownProps.includes(name)) {
+            throw new Error(`Unknown property "${name}" of widget ${type}, expected one of: ${knownProps.join(", ")}`)
+        }
+    }
+}
+
 const componentModel = op_component_model();
 
 export const createHostConfig = (): HostConfig<
@@ -347,13 +550,7 @@ export const createHostConfig = (): HostConfig<
 
         // op_log_info("renderer_js_persistence", `Converted container: ${Deno.inspect(containerComponent, { depth: Number.MAX_VALUE })}`)
 
-        op_react_replace_view(
-            gauntletContextValue.renderLocation(),
-            gauntletContextValue.isBottommostView(),
-            gauntletContextValue.entrypointId(),
-            gauntletContextValue.entrypointName(),
-            containerComponent
-        )
+        gauntletContextValue.viewRendered(container, containerComponent)
     },
 
     cloneHiddenInstance(
@@ -437,56 +634,65 @@ export function clearRenderer() {
     gauntletContextValue.clear()
 }
 
+export function popView() {
+    gauntletContextValue.popView()
+}
+
+// container of the view which is currently shown, events are handled by its widgets
+export function topmostContainer(): RootUiWidget | undefined {
+    return gauntletContextValue.topmostContainer()
+}
+
+export function findKeyHandler(key: string, modifierShift: boolean, modifierControl: boolean, modifierAlt: boolean, modifierMeta: boolean): (() => void) | undefined {
+    return gauntletContextValue.findKeyHandler(key, modifierShift, modifierControl, modifierAlt, modifierMeta)
+}
+
 export function render(entrypointId: string, entrypointName: string, renderLocation: RenderLocation, view: ReactNode): UiWidget {
     const hostConfig = createHostConfig();
 
     // const reconciler = ReactReconciler(createTracedHostConfig(hostConfig));
     const reconciler = ReactReconciler(hostConfig);
 
-    const container: RootUiWidget = {
-        widgetId: 0,
-        widgetType: "gauntlet:root",
-        widgetProperties: {},
-        widgetChildren: [],
-    };
-
-    gauntletContextValue.reset(
-        entrypointId,
-        entrypointName,
-        renderLocation,
-        view,
-        (node: ReactNode) => {
-            reconciler.updateContainer(
-                node,
-                root,
-                null,
-                null
-            );
-        },
-        () => {
-            reconciler.updateContainer(
-                null,
-                root,
-                null,
-                null
-            );
+    const createEntry = (title: string): NavigationEntry => {
+        const container: RootUiWidget = {
+            widgetId: 0,
+            widgetType: "gauntlet:root",
+            widgetProperties: {},
+            widgetChildren: [],
+        };
+
+        const root = reconciler.createContainer(
+            container,
+            0,
+            null,
+            false,
+            false,
+            "",
+            error => {
+                console.error("Recoverable error occurred when rendering view", error)
+            },
+            null
+        );
+
+        return {
+            title,
+            container,
+            render: (node: ReactNode) => {
+                reconciler.updateContainer(
+                    node,
+                    root,
+                    null,
+                    null
+                );
+            },
+            content: undefined,
+            keyHandlers: []
         }
-    )
-
-    const root = reconciler.createContainer(
-        container,
-        0,
-        null,
-        false,
-        false,
-        "",
-        error => {
-            console.error("Recoverable error occurred when rendering view", error)
-        },
-        null
-    );
+    }
+
+    gauntletContextValue.reset(entrypointId, entrypointName, renderLocation, createEntry)
 
-    gauntletContextValue.rerender(gauntletContextValue.topmostView())
+    gauntletContextValue.pushView(view)
 
-    return container
+    return gauntletContextValue.topmostContainer()!!
 }
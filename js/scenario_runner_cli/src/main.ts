@@ -12,7 +12,14 @@ program
 program.command('run-scenarios')
     .argument('[plugin]')
     .action(async (plugin) => {
-        await runScenarios(plugin)
+        await runScenarios(plugin, false)
+    });
+
+program.command('check-scenarios')
+    .description('Run scenarios and fail if produced views differ from saved ones')
+    .argument('[plugin]')
+    .action(async (plugin) => {
+        await runScenarios(plugin, true)
     });
 
 program.command('run-screenshot-gen')
@@ -28,7 +35,7 @@ async function sleep(ms: number) {
     return new Promise((r) => setTimeout(r, ms));
 }
 
-async function runScenarios(expectedPlugin: string | undefined) {
+async function runScenarios(expectedPlugin: string | undefined, check: boolean) {
     const projectRoot = path.resolve(process.cwd(), '..', '..');
 
     const scenarios = path.join(projectRoot, "scenarios");
@@ -57,6 +64,7 @@ async function runScenarios(expectedPlugin: string | undefined) {
                 GAUNTLET_SCENARIO_RUNNER_TYPE: "scenario_runner",
                 GAUNTLET_SCENARIOS_DIR: scenarios,
                 GAUNTLET_SCENARIO_PLUGIN_NAME: pluginName,
+                ...(check ? { GAUNTLET_SCENARIO_CHECK: "true" } : {}),
                 XDG_DATA_HOME: path.join(scenariosRun, "data"),
                 XDG_CONFIG_HOME: path.join(scenariosRun, "config"),
                 XDG_CACHE_HOME: path.join(scenariosRun, "cache"),
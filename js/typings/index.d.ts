@@ -45,7 +45,104 @@ type MacOSDesktopSettings13AndPostData = {
     icon: ArrayBuffer | undefined,
 }
 
-type PluginEvent = ViewEvent | NotReactsKeyboardEvent | RunCommand | RunGeneratedEntrypoint | OpenView | CloseView | OpenInlineView | RefreshSearchIndex
+type ShellOutputLine = {
+    type: "stdout" | "stderr"
+    text: string
+}
+
+type ShellCommandPoll = {
+    lines: ShellOutputLine[]
+    finished: boolean
+    exit_code: number | undefined
+}
+
+type OAuthConfig = {
+    client_id: string
+    client_secret: string | undefined
+    authorize_url: string
+    token_url: string
+    scopes: string[]
+    redirect_port: number | undefined
+    extra_authorize_params: { [name: string]: string }
+}
+
+type OAuthTokens = {
+    access_token: string
+    token_type: string
+    expires_at: number | undefined
+    scopes: string[]
+}
+
+type HttpCacheEntry = {
+    status: number
+    status_text: string
+    headers: [string, string][]
+    body: Uint8Array
+    fresh: boolean
+    etag: string | undefined
+    last_modified: string | undefined
+}
+
+type SubprocessChunk = {
+    type: "stdout" | "stderr"
+    text: string
+} | {
+    type: "exit"
+    code: number | null
+    timedOut: boolean
+    killed: boolean
+}
+
+type FsDirEntry = {
+    name: string
+    path: string
+    isFile: boolean
+    isDirectory: boolean
+    isSymlink: boolean
+}
+
+type FsStat = {
+    isFile: boolean
+    isDirectory: boolean
+    isSymlink: boolean
+    size: number
+    modified: number | null
+    created: number | null
+}
+
+type FsWatchEvent = {
+    kind: "create" | "modify" | "remove" | "other"
+    paths: string[]
+}
+
+type DatabaseQueryResult = {
+    columns: string[]
+    rows: (null | number | string | Uint8Array)[][]
+}
+
+type SystemCommand = "lock" | "sleep" | "log-out" | "restart" | "shut-down" | "empty-trash"
+
+type MediaCommand = "play-pause" | "next" | "previous"
+
+type NowPlaying = {
+    player: string
+    title: string
+    artist: string | undefined
+    album: string | undefined
+    artwork_url: string | undefined
+    position: number | undefined
+    duration: number | undefined
+    playing: boolean
+}
+
+type ProcessInfo = {
+    pid: number
+    name: string
+    cpu_usage: number
+    memory: number
+}
+
+type PluginEvent = ViewEvent | NotReactsKeyboardEvent | RunCommand | RunGeneratedEntrypoint | OpenView | CloseView | PopView | OpenInlineView | RefreshSearchIndex | Search | NotificationClosed | ViewRenderFailed
 type RenderLocation = "InlineView" | "View"
 
 type ViewEvent = {
@@ -57,6 +154,14 @@ type ViewEvent = {
 
 type KeyboardEventOrigin = "MainView" | "PluginView"
 
+type KeyHandlerShortcut = {
+    key: string
+    modifierShift: boolean
+    modifierControl: boolean
+    modifierAlt: boolean
+    modifierMeta: boolean
+}
+
 // naming to avoid collision
 type NotReactsKeyboardEvent = {
     type: "KeyboardEvent"
@@ -78,9 +183,14 @@ type CloseView = {
     type: "CloseView"
 }
 
+type PopView = {
+    type: "PopView"
+}
+
 type RunCommand = {
     type: "RunCommand"
     entrypointId: string
+    arguments: { [id: string]: string }
 }
 
 type RunGeneratedEntrypoint = {
@@ -98,10 +208,34 @@ type RefreshSearchIndex = {
     type: "RefreshSearchIndex"
 }
 
-type PropertyValue = PropertyValueString | PropertyValueNumber | PropertyValueBool | PropertyValueUndefined
+type Search = {
+    type: "Search"
+    text: string
+    searchId: number
+}
+
+type NotificationClosed = {
+    type: "NotificationClosed"
+    notificationId: number
+    actionIndex: number | null
+}
+
+type ViewRenderFailed = {
+    type: "ViewRenderFailed"
+    entrypointId: string
+    renderLocation: RenderLocation
+    message: string
+}
+
+type PropertyValue = PropertyValueString | PropertyValueNumber | PropertyValueBool | PropertyValueColor | PropertyValueEnum | PropertyValueBytes | PropertyValueArray | PropertyValueObject | PropertyValueUndefined
 type PropertyValueString = { type: "String", value: string }
 type PropertyValueNumber = { type: "Number", value: number }
 type PropertyValueBool = { type: "Bool", value: boolean }
+type PropertyValueColor = { type: "Color", value: string }
+type PropertyValueEnum = { type: "Enum", value: string }
+type PropertyValueBytes = { type: "Bytes", value: number[] }
+type PropertyValueArray = { type: "Array", value: PropertyValue[] }
+type PropertyValueObject = { type: "Object", value: Record<string, PropertyValue> }
 type PropertyValueUndefined = { type: "Undefined" }
 
 type UiWidget = {
@@ -129,11 +263,16 @@ interface GeneratedEntrypointIconAccessory {
 
 type GeneratedSearchItem = {
     entrypoint_name: string,
+    entrypoint_subtitle?: string,
+    entrypoint_keywords?: string[],
+    generator_entrypoint_id: string,
     entrypoint_id: string,
     entrypoint_uuid: string,
     entrypoint_icon: ArrayBuffer | undefined,
     entrypoint_actions: GeneratedSearchItemAction[],
     entrypoint_accessories: GeneratedEntrypointAccessory[],
+    entrypoint_preview?: string,
+    entrypoint_file?: string,
 }
 
 type GeneratedSearchItemAction = {
@@ -143,10 +282,24 @@ type GeneratedSearchItemAction = {
 }
 
 declare module "gauntlet:bridge/internal-all" {
-    function open_settings(): void
+    function open_settings(): Promise<void>
     function run_numbat(input: string): { left: string, right: string }
     function current_os(): string
     function wayland(): boolean
+    function shell_command_run(command: string): number
+    function shell_command_poll(id: number): ShellCommandPoll
+    function shell_command_kill(id: number): void
+    function run_system_command(command: SystemCommand): Promise<void>
+    function media_now_playing(): Promise<NowPlaying | null>
+    function media_control(player: string, command: MediaCommand): Promise<void>
+    function quick_note_append(path: string, text: string): string
+    function quick_note_run_hook(hook: string, text: string): Promise<void>
+    function process_list(): ProcessInfo[]
+    function process_terminate(pid: number): void
+    function process_kill(pid: number): void
+    function pick_color_from_screen(): Promise<string | null>
+    function show_window_with_search_query(query: string): Promise<void>
+    function hide_window(): Promise<void>
 }
 
 declare module "gauntlet:bridge/internal-linux" {
@@ -180,7 +333,7 @@ declare module "gauntlet:bridge/internal-windows" {
 }
 
 declare module "ext:core/ops" {
-    function open_settings(): void
+    function open_settings(): Promise<void>
     function run_numbat(input: string): { left: string, right: string }
 
     function current_os(): string
@@ -222,24 +375,31 @@ declare module "ext:core/ops" {
 
     function op_inline_view_entrypoint_id(): string | null;
     function op_entrypoint_names(): Record<string, string | undefined>;
+    function op_preload_entrypoint_ids(): string[];
     function clear_inline_view(): void;
     function op_plugin_get_pending_event(): Promise<PluginEvent>;
     function hide_window(): void;
 
     function get_entrypoint_generator_entrypoint_ids(): Promise<string[]>
 
+    function get_search_provider_entrypoint_ids(): Promise<string[]>
+
+    function search_provider_results(searchId: number, entrypointId: string, results: GeneratedSearchItem[]): Promise<void>;
+
     function get_plugin_preferences(): Record<string, any>;
     function get_entrypoint_preferences(entrypointId: string): Record<string, any>;
     function plugin_preferences_required(): Promise<boolean>;
     function entrypoint_preferences_required(entrypointId: string): Promise<boolean>;
-    function show_preferences_required_view(entrypointId: string, pluginPreferencesRequired: boolean, entrypointPreferencesRequired: boolean): void;
+    function plugin_onboarding_required(): Promise<boolean>;
+    function show_onboarding_view(entrypointId: string): void;
 
     function reload_search_index(searchItems: GeneratedSearchItem[], refreshSearchList: boolean): Promise<void>;
 
     function show_hud(display: string): void;
+    function show_notification(title: string, body: string | undefined, actions: string[]): Promise<number>;
     function update_loading_bar(entrypoint_id: string, show: boolean): void;
 
-    function op_react_replace_view(render_location: RenderLocation, top_level_view: boolean, entrypoint_id: string, entrypoint_name: string, container: any): void;
+    function op_react_replace_view(render_location: RenderLocation, top_level_view: boolean, entrypoint_id: string, entrypoint_name: string, breadcrumbs: string[], key_handlers: KeyHandlerShortcut[], container: any): void;
     function show_plugin_error_view(entrypoint_id: string, render_location: RenderLocation): void;
 
     function fetch_action_id_for_shortcut(entrypointId: string, key: string, modifierShift: boolean, modifierControl: boolean, modifierAlt: boolean, modifierMeta: boolean): Promise<string | undefined>;
@@ -248,12 +408,44 @@ declare module "ext:core/ops" {
     function clipboard_read_text(): Promise<string | undefined>;
     function clipboard_write(data: { text_data?: string, png_data?: number[] }): Promise<void>;
     function clipboard_write_text(data: string): Promise<void>;
+    function clipboard_write_sensitive_text(data: string): Promise<void>;
+    function clipboard_paste_text(data: string, sensitive: boolean): Promise<void>;
     function clipboard_clear(): Promise<void>;
 
+    function run_in_terminal(command: string[], cwd: string | undefined): Promise<void>;
+
+    function oauth_authorize(config: OAuthConfig): Promise<OAuthTokens | undefined>;
+    function oauth_remove_tokens(config: OAuthConfig): Promise<void>;
+
+    function op_http_cache_config(): { stale_while_revalidate: boolean } | null;
+    function op_http_cache_get(url: string, requestHeaders: [string, string][]): Promise<HttpCacheEntry | null>;
+    function op_http_cache_put(url: string, requestHeaders: [string, string][], status: number, statusText: string, headers: [string, string][], body: Uint8Array): Promise<void>;
+    function op_http_cache_refresh(url: string, requestHeaders: [string, string][], headers: [string, string][]): Promise<void>;
+
     function environment_gauntlet_version(): number;
     function environment_is_development(): boolean;
     function environment_plugin_data_dir(): string;
     function environment_plugin_cache_dir(): string;
+    function environment_variables(): { [name: string]: string };
+
+    function subprocess_spawn(program: string, args: string[], cwd: string | undefined, env: { [name: string]: string }, timeout: number | undefined): number;
+    function subprocess_read(id: number): Promise<SubprocessChunk | null>;
+    function subprocess_kill(id: number): void;
+
+    function fs_read_dir(path: string): Promise<FsDirEntry[]>;
+    function fs_read_file(path: string): Promise<Uint8Array>;
+    function fs_read_text_file(path: string): Promise<string>;
+    function fs_write_file(path: string, data: Uint8Array): Promise<void>;
+    function fs_write_text_file(path: string, data: string): Promise<void>;
+    function fs_stat(path: string): Promise<FsStat>;
+    function fs_watch(path: string, recursive: boolean): number;
+    function fs_watch_next(id: number): Promise<FsWatchEvent | null>;
+    function fs_unwatch(id: number): void;
+
+    function database_open(name: string): Promise<number>;
+    function database_query(id: number, sql: string, params: (null | boolean | number | string)[]): Promise<DatabaseQueryResult>;
+    function database_execute(id: number, sql: string, params: (null | boolean | number | string)[]): Promise<{ changes: number, lastInsertRowId: number }>;
+    function database_close(id: number): void;
 }
 
 // component model types
@@ -295,6 +487,7 @@ type SharedTypeUnion = {
 type TextPartComponent = {
     type: "text_part",
     internalName: string,
+    props: Property[],
 }
 
 type Property = {
@@ -1,8 +1,12 @@
-use anyhow::{anyhow, Context};
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
 use clap::Parser;
-use gauntlet_client::open_window;
+use gauntlet_client::{handle_deep_link, hide_window, open_window, print_renderer_info, print_usage_stats, reindex, save_profile, start_remote_client, switch_profile, toggle_window};
+use gauntlet_common::logging::{init_logging, REMOTE_CLIENT_LOG_FILE_PREFIX, SERVER_LOG_FILE_PREFIX, SETTINGS_LOG_FILE_PREFIX};
 use gauntlet_management_client::start_management_client;
-use gauntlet_server::start;
+use gauntlet_server::{start, start_headless, validate_plugin};
+use gauntlet_server::bench::run_benchmarks;
 
 #[derive(Debug, clap::Parser)]
 struct Cli {
@@ -11,88 +15,131 @@ struct Cli {
 
     #[arg(long)]
     minimized: bool,
+
+    /// Log level in RUST_LOG format, e.g. "info" or "info,plugin=debug", takes precedence over RUST_LOG env variable
+    #[arg(long, global = true)]
+    log_level: Option<String>,
 }
 
 #[derive(Debug, clap::Subcommand)]
 enum Commands {
     Open,
+    /// Open main window if it is hidden, hide it otherwise. Useful for binding to compositor shortcuts
+    Toggle,
+    Hide,
     Settings,
+    /// Rebuild search index from data provided by plugins. Useful if search results look outdated or broken
+    Reindex,
+    /// Show locally collected usage stats: most used commands, plugins never used and average search-to-launch time
+    Stats,
+    /// Show which renderer is used by main window. Renderer can be changed in "renderer" section of config file
+    Renderer,
+    /// Measure search and view rendering performance with generated data
+    #[command(hide = true)]
+    Bench,
+    /// Handle gauntlet:// deep link, e.g. gauntlet://run/<plugin-id>/<entrypoint-id>?arg=<value>
+    Deeplink {
+        uri: String,
+    },
+    /// Save and switch between named settings profiles, e.g. "work" and "home"
+    Profile {
+        #[command(subcommand)]
+        command: ProfileCommands,
+    },
+    /// Plugin development tools
+    Plugin {
+        #[command(subcommand)]
+        command: PluginCommands,
+    },
+    /// Run server without window, frontend running on another machine can attach to it using "remote" command.
+    /// Token required to attach is generated on first start and stored in state directory in "remote_frontend_token" file
+    Headless {
+        /// Address to listen on for remote frontend, e.g. 0.0.0.0:7649
+        #[arg(long)]
+        listen: SocketAddr,
+        /// Path to PEM encoded TLS certificate
+        #[arg(long)]
+        cert: PathBuf,
+        /// Path to PEM encoded TLS private key
+        #[arg(long)]
+        key: PathBuf,
+    },
+    /// Show window of headless server running on another machine
+    Remote {
+        /// Address of headless server in host:port format. Host needs to match the one in server certificate
+        address: String,
+        /// Token from "remote_frontend_token" file in state directory of headless server
+        #[arg(long)]
+        token: String,
+        /// Path to PEM encoded TLS certificate of headless server, only this certificate is trusted
+        #[arg(long)]
+        cert: PathBuf,
+    },
 }
 
-pub fn init() {
-    tracing_subscriber::fmt::init();
+#[derive(Debug, clap::Subcommand)]
+enum ProfileCommands {
+    /// Save enabled plugins, their preferences and theme under given name, overwriting existing profile with the same name
+    Save {
+        name: String,
+    },
+    /// Restore enabled plugins, their preferences and theme from profile with given name
+    Switch {
+        name: String,
+    },
+}
 
-    let cli = Cli::parse();
+#[derive(Debug, clap::Subcommand)]
+enum PluginCommands {
+    /// Validate plugin manifest and print problems with suggestions how to fix them. Exits with non-zero code if manifest is not valid
+    Validate {
+        /// Path to gauntlet.toml or directory containing it
+        path: String,
+    },
+}
 
-    match &cli.command {
-        None => {
-            if cfg!(feature = "release") {
-                #[cfg(target_os = "macos")]
-                let result = setup_auto_launch_macos();
+pub fn init() {
+    let cli = Cli::parse();
 
-                #[cfg(target_os = "windows")]
-                let result = setup_auto_launch_windows();
+    // short-lived commands only log to stderr
+    let log_file_prefix = match &cli.command {
+        None | Some(Commands::Headless { .. }) => Some(SERVER_LOG_FILE_PREFIX),
+        Some(Commands::Remote { .. }) => Some(REMOTE_CLIENT_LOG_FILE_PREFIX),
+        Some(Commands::Settings) => Some(SETTINGS_LOG_FILE_PREFIX),
+        Some(Commands::Open) | Some(Commands::Toggle) | Some(Commands::Hide) | Some(Commands::Reindex) | Some(Commands::Stats) | Some(Commands::Renderer) | Some(Commands::Bench) | Some(Commands::Deeplink { .. }) | Some(Commands::Profile { .. }) | Some(Commands::Plugin { .. }) => None,
+    };
 
-                #[cfg(any(target_os = "macos", target_os = "windows"))]
-                if let Err(err) = &result {
-                    tracing::warn!("error occurred when setting up auto-launch {:?}", err)
-                }
-            }
+    let _log_guard = init_logging(cli.log_level.as_deref(), log_file_prefix);
 
+    match &cli.command {
+        None => {
             start(cli.minimized)
         }
         Some(command) => {
             match command {
                 Commands::Open => open_window(),
+                Commands::Toggle => toggle_window(),
+                Commands::Hide => hide_window(),
                 Commands::Settings => start_management_client(),
+                Commands::Reindex => reindex(),
+                Commands::Stats => print_usage_stats(),
+                Commands::Renderer => print_renderer_info(),
+                Commands::Bench => run_benchmarks(),
+                Commands::Deeplink { uri } => handle_deep_link(uri.clone()),
+                Commands::Profile { command } => {
+                    match command {
+                        ProfileCommands::Save { name } => save_profile(name.clone()),
+                        ProfileCommands::Switch { name } => switch_profile(name.clone()),
+                    }
+                }
+                Commands::Plugin { command } => {
+                    match command {
+                        PluginCommands::Validate { path } => validate_plugin(path),
+                    }
+                }
+                Commands::Headless { listen, cert, key } => start_headless(*listen, cert.clone(), key.clone()),
+                Commands::Remote { address, token, cert } => start_remote_client(address.clone(), token.clone(), cert.clone()),
             };
         }
     }
 }
-
-#[cfg(target_os = "macos")]
-fn setup_auto_launch_macos() -> anyhow::Result<()> {
-    let app_path = std::env::current_exe()
-        .context("Unable to get current_exe from env")?;
-
-    // expect Gauntlet.app in path according to macos app bundle structure
-    let app_path_fn = || {
-        let path = std::path::PathBuf::from(&app_path);
-        let path = path.parent()?.parent()?.parent()?;
-        let extension = path.extension()?.to_str()?;
-        match extension == "app" {
-            true => Some(path.as_os_str().to_str()?.to_string()),
-            false => None,
-        }
-    };
-
-    let app_path = app_path_fn()
-        .ok_or(anyhow!("Unexpected executable path: {:?}", &app_path))?;
-
-    setup_auto_launch(app_path)
-}
-
-
-#[cfg(target_os = "windows")]
-fn setup_auto_launch_windows() -> anyhow::Result<()> {
-    let app_path = std::env::current_exe()
-        .context("Unable to get current_exe from env")?
-        .as_os_str()
-        .to_str()
-        .ok_or(anyhow!("failed to convert app_path to utf-8"))?
-        .to_string();
-
-    setup_auto_launch(app_path)
-}
-
-#[cfg(any(target_os = "macos", target_os = "windows"))]
-fn setup_auto_launch(app_path: String) -> anyhow::Result<()> {
-    auto_launch::AutoLaunchBuilder::new()
-        .set_app_name("Gauntlet")
-        .set_app_path(&app_path)
-        .set_args(&["--minimized"])
-        .build()
-        .and_then(|auto| auto.enable())?;
-
-    Ok(())
-}
\ No newline at end of file
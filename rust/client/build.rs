@@ -37,31 +37,10 @@ fn main() -> anyhow::Result<()> {
                     output.push_str("        event_arguments: vec![\n",);
 
                     for arg in arguments {
-                        match arg.property_type {
-                            PropertyType::String => {
-                                if arg.optional {
-                                    output.push_str(&format!("            {}.map(|{}| gauntlet_common::model::UiPropertyValue::String({})).unwrap_or_else(|| gauntlet_common::model::UiPropertyValue::Undefined),\n", arg.name, arg.name, arg.name));
-                                } else {
-                                    output.push_str(&format!("            gauntlet_common::model::UiPropertyValue::String({}),\n", arg.name));
-                                }
-                            }
-                            PropertyType::Number => {
-                                if arg.optional {
-                                    output.push_str(&format!("            {}.map(|{}| gauntlet_common::model::UiPropertyValue::Number({})).unwrap_or_else(|| gauntlet_common::model::UiPropertyValue::Undefined),\n", arg.name, arg.name, arg.name));
-                                } else {
-                                    output.push_str(&format!("            gauntlet_common::model::UiPropertyValue::Number({}),\n", arg.name));
-                                }
-                            }
-                            PropertyType::Boolean => {
-                                if arg.optional {
-                                    output.push_str(&format!("            {}.map(|{}| gauntlet_common::model::UiPropertyValue::Bool({})).unwrap_or_else(|| gauntlet_common::model::UiPropertyValue::Undefined),\n", arg.name, arg.name, arg.name));
-                                } else {
-                                    output.push_str(&format!("            gauntlet_common::model::UiPropertyValue::Bool({}),\n", arg.name));
-                                }
-                            }
-                            _ => {
-                                panic!("not yet supported")
-                            }
+                        if arg.optional {
+                            output.push_str(&format!("            {}.map(|{}| {}).unwrap_or_else(|| gauntlet_common::model::UiPropertyValue::Undefined),\n", arg.name, arg.name, generate_property_value(&arg.property_type, &arg.name)));
+                        } else {
+                            output.push_str(&format!("            {},\n", generate_property_value(&arg.property_type, &arg.name)));
                         }
                     }
 
@@ -85,6 +64,21 @@ fn generate_file<P: AsRef<Path>>(path: P, text: &str) -> std::io::Result<()> {
     f.write_all(text.as_bytes())
 }
 
+// expression which converts event argument to property value sent to plugin
+fn generate_property_value(property_type: &PropertyType, value: &str) -> String {
+    match property_type {
+        PropertyType::String => format!("gauntlet_common::model::UiPropertyValue::String({})", value),
+        PropertyType::Number => format!("gauntlet_common::model::UiPropertyValue::Number({})", value),
+        PropertyType::Boolean => format!("gauntlet_common::model::UiPropertyValue::Bool({})", value),
+        PropertyType::Array { item } => {
+            format!("gauntlet_common::model::UiPropertyValue::Array({}.into_iter().map(|item| {}).collect())", value, generate_property_value(item, "item"))
+        }
+        _ => {
+            panic!("not yet supported")
+        }
+    }
+}
+
 fn generate_type(property: &Property, name: &ComponentName) -> String {
     match property.optional {
         true => generate_optional_type(&property.property_type, format!("{}{}", name, &property.name.to_case(Case::Pascal))),
@@ -1,21 +1,57 @@
+use std::path::PathBuf;
+
+use gauntlet_common::crash::init_crash_handler;
 use gauntlet_common::dirs::Dirs;
 use gauntlet_common::model::{BackendRequestData, BackendResponseData, UiRequestData, UiResponseData};
-use gauntlet_common::rpc::backend_api::BackendApi;
+use gauntlet_common::rpc::backend_api::{BackendApi, BackendApiError};
+use gauntlet_common::rpc::remote::connect_remote_backend;
 use gauntlet_utils::channel::{RequestReceiver, RequestSender};
 use crate::ui::GauntletComplexTheme;
 
 pub(in crate) mod ui;
 pub(in crate) mod model;
 pub mod global_shortcut;
+#[cfg(target_os = "macos")]
+mod url_events;
 
 pub fn start_client(
     minimized: bool,
     frontend_receiver: RequestReceiver<UiRequestData, UiResponseData>,
     backend_sender: RequestSender<BackendRequestData, BackendResponseData>,
 ) {
+    #[cfg(target_os = "macos")]
+    url_events::register_url_event_handler();
+
     ui::run(minimized, frontend_receiver, backend_sender);
 }
 
+// frontend connected to headless server running on another machine
+pub fn start_remote_client(address: String, token: String, cert: PathBuf) {
+    init_crash_handler("client");
+
+    // connection is handled by runtime worker threads while main thread is used by ui
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .expect("unable to start remote connection tokio runtime");
+
+    let result = runtime.block_on(async {
+        connect_remote_backend(&address, token, &cert).await
+    });
+
+    match result {
+        Ok((frontend_receiver, backend_sender)) => {
+            ui::run(false, frontend_receiver, backend_sender);
+        }
+        Err(err) => {
+            tracing::error!("Unable to connect to remote server: {:?}", err);
+            std::process::exit(1)
+        }
+    }
+
+    drop(runtime);
+}
+
 pub fn open_window() {
     tokio::runtime::Builder::new_current_thread()
         .enable_all()
@@ -39,6 +75,211 @@ pub fn open_window() {
         })
 }
 
+pub fn toggle_window() {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("unable to start server tokio runtime")
+        .block_on(async {
+            let result = BackendApi::new().await;
+
+            match result {
+                Ok(mut backend_api) => {
+                    if let Err(err) = backend_api.toggle_window().await {
+                        tracing::error!("Unable to toggle window: {:?}", err)
+                    }
+                }
+                Err(_) => {
+                    tracing::error!("Unable to connect to server. Please check if you have Gauntlet running on your PC")
+                }
+            }
+        })
+}
+
+pub fn reindex() {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("unable to start server tokio runtime")
+        .block_on(async {
+            let result = BackendApi::new().await;
+
+            match result {
+                Ok(mut backend_api) => {
+                    if let Err(err) = backend_api.reindex().await {
+                        tracing::error!("Unable to rebuild search index: {:?}", err)
+                    }
+                }
+                Err(_) => {
+                    tracing::error!("Unable to connect to server. Please check if you have Gauntlet running on your PC")
+                }
+            }
+        })
+}
+
+const STATS_MOST_USED_LIMIT: usize = 10;
+
+pub fn print_usage_stats() {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("unable to start server tokio runtime")
+        .block_on(async {
+            let result = BackendApi::new().await;
+
+            let mut backend_api = match result {
+                Ok(backend_api) => backend_api,
+                Err(_) => {
+                    eprintln!("Unable to connect to server. Please check if you have Gauntlet running on your PC");
+                    std::process::exit(1)
+                }
+            };
+
+            let result = async {
+                let enabled = backend_api.get_usage_stats_enabled().await?;
+                let stats = backend_api.usage_stats().await?;
+
+                Ok::<_, BackendApiError>((enabled, stats))
+            }.await;
+
+            let (enabled, stats) = match result {
+                Ok(result) => result,
+                Err(err) => {
+                    eprintln!("Unable to get usage stats: {:#}", err);
+                    std::process::exit(1)
+                }
+            };
+
+            if !enabled {
+                println!("Usage stats collection is disabled, it can be enabled in settings");
+                println!();
+            }
+
+            println!("Most used commands:");
+
+            if stats.entrypoints.is_empty() {
+                println!("  none");
+            }
+
+            for entrypoint in stats.entrypoints.iter().take(STATS_MOST_USED_LIMIT) {
+                println!("  {:>6}  {} - {}", entrypoint.launch_count, entrypoint.entrypoint_name, entrypoint.plugin_name);
+            }
+
+            println!();
+            println!("Plugins never used:");
+
+            if stats.unused_plugins.is_empty() {
+                println!("  none");
+            }
+
+            for plugin in &stats.unused_plugins {
+                println!("  {} ({})", plugin.plugin_name, plugin.plugin_id.to_string());
+            }
+
+            println!();
+
+            match stats.average_search_to_launch {
+                None => println!("Average search-to-launch time: unknown"),
+                Some(duration) => println!("Average search-to-launch time: {:.1}s", duration.as_secs_f64()),
+            }
+        })
+}
+
+pub fn print_renderer_info() {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("unable to start server tokio runtime")
+        .block_on(async {
+            let result = BackendApi::new().await;
+
+            let mut backend_api = match result {
+                Ok(backend_api) => backend_api,
+                Err(_) => {
+                    eprintln!("Unable to connect to server. Please check if you have Gauntlet running on your PC");
+                    std::process::exit(1)
+                }
+            };
+
+            match backend_api.renderer_info().await {
+                Ok(Some(renderer)) => {
+                    println!("Backend: {}", renderer.backend);
+                    println!("Adapter: {}", renderer.adapter);
+                }
+                Ok(None) => {
+                    println!("Renderer is not known yet, main window hasn't been started");
+                }
+                Err(err) => {
+                    eprintln!("Unable to get renderer info: {:#}", err);
+                    std::process::exit(1)
+                }
+            }
+        })
+}
+
+pub fn save_profile(name: String) {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("unable to start server tokio runtime")
+        .block_on(async {
+            let result = BackendApi::new().await;
+
+            match result {
+                Ok(mut backend_api) => {
+                    if let Err(err) = backend_api.save_profile(name).await {
+                        tracing::error!("Unable to save profile: {:?}", err)
+                    }
+                }
+                Err(_) => {
+                    tracing::error!("Unable to connect to server. Please check if you have Gauntlet running on your PC")
+                }
+            }
+        })
+}
+
+pub fn switch_profile(name: String) {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("unable to start server tokio runtime")
+        .block_on(async {
+            let result = BackendApi::new().await;
+
+            match result {
+                Ok(mut backend_api) => {
+                    if let Err(err) = backend_api.switch_profile(name).await {
+                        tracing::error!("Unable to switch profile: {:?}", err)
+                    }
+                }
+                Err(_) => {
+                    tracing::error!("Unable to connect to server. Please check if you have Gauntlet running on your PC")
+                }
+            }
+        })
+}
+
+pub fn hide_window() {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("unable to start server tokio runtime")
+        .block_on(async {
+            let result = BackendApi::new().await;
+
+            match result {
+                Ok(mut backend_api) => {
+                    if let Err(err) = backend_api.hide_window().await {
+                        tracing::error!("Unable to hide window: {:?}", err)
+                    }
+                }
+                Err(_) => {
+                    tracing::error!("Unable to connect to server. Please check if you have Gauntlet running on your PC")
+                }
+            }
+        })
+}
+
 pub fn open_settings_window() {
     tokio::runtime::Builder::new_current_thread()
         .enable_all()
@@ -60,3 +301,24 @@ pub fn open_settings_window() {
         })
 }
 
+
+pub fn handle_deep_link(uri: String) {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("unable to start server tokio runtime")
+        .block_on(async {
+            let result = BackendApi::new().await;
+
+            match result {
+                Ok(mut backend_api) => {
+                    if let Err(err) = backend_api.handle_deep_link(uri).await {
+                        tracing::error!("Unable to handle deep link: {:?}", err)
+                    }
+                }
+                Err(_) => {
+                    tracing::error!("Unable to connect to server. Please check if you have Gauntlet running on your PC")
+                }
+            }
+        })
+}
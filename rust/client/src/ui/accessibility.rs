@@ -0,0 +1,369 @@
+use accesskit::{Node, NodeId, Role, Tree, TreeUpdate};
+use iced::window;
+use iced::Task;
+
+use crate::ui::AppMsg;
+
+const WINDOW_NODE_ID: NodeId = NodeId(0);
+const SEARCH_FIELD_NODE_ID: NodeId = NodeId(1);
+const SEARCH_RESULTS_NODE_ID: NodeId = NodeId(2);
+const ACTION_PANEL_NODE_ID: NodeId = NodeId(3);
+// search results and actions are numbered after fixed nodes, index of item is added to the start
+const SEARCH_RESULT_NODE_ID_START: u64 = 1 << 32;
+const ACTION_NODE_ID_START: u64 = 2 << 32;
+
+// iced doesn't provide accessibility tree, so what is shown in main window is described to screen readers separately.
+// only main view is described in detail, for plugin views only window title is available
+#[derive(Debug, Clone, PartialEq)]
+pub enum AccessibleView {
+    Main {
+        prompt: String,
+        results: Vec<AccessibleItem>,
+        focused_result: Option<usize>,
+        action_panel: Option<AccessibleActionPanel>,
+    },
+    Other {
+        title: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessibleItem {
+    pub label: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessibleActionPanel {
+    pub title: String,
+    pub actions: Vec<String>,
+    pub focused_action: Option<usize>,
+}
+
+// screen reader announces node which has focus in the tree, so focus is moved to focused result or action
+pub fn build_tree(view: &AccessibleView) -> TreeUpdate {
+    let mut window = Node::new(Role::Window);
+    window.set_label("Gauntlet");
+
+    let mut nodes = vec![];
+
+    let focus = match view {
+        AccessibleView::Main { prompt, results, focused_result, action_panel } => {
+            let mut search_field = Node::new(Role::SearchInput);
+            search_field.set_label(gauntlet_common::tr!("accessibility-search-field"));
+            search_field.set_value(prompt.as_str());
+
+            let mut search_results = Node::new(Role::ListBox);
+            search_results.set_label(gauntlet_common::tr!("accessibility-search-results"));
+
+            for (index, result) in results.iter().enumerate() {
+                let id = NodeId(SEARCH_RESULT_NODE_ID_START + index as u64);
+
+                let mut node = Node::new(Role::ListBoxOption);
+                node.set_label(result.label.as_str());
+                if let Some(description) = &result.description {
+                    node.set_description(description.as_str());
+                }
+                node.set_selected(*focused_result == Some(index));
+                node.set_position_in_set(index + 1);
+                node.set_size_of_set(results.len());
+
+                search_results.push_child(id);
+                nodes.push((id, node));
+            }
+
+            search_field.push_controlled(SEARCH_RESULTS_NODE_ID);
+
+            window.push_child(SEARCH_FIELD_NODE_ID);
+            window.push_child(SEARCH_RESULTS_NODE_ID);
+
+            nodes.push((SEARCH_FIELD_NODE_ID, search_field));
+            nodes.push((SEARCH_RESULTS_NODE_ID, search_results));
+
+            let focused_action = action_panel.as_ref()
+                .map(|action_panel| {
+                    let mut menu = Node::new(Role::Menu);
+                    menu.set_label(action_panel.title.as_str());
+
+                    for (index, action) in action_panel.actions.iter().enumerate() {
+                        let id = NodeId(ACTION_NODE_ID_START + index as u64);
+
+                        let mut node = Node::new(Role::MenuItem);
+                        node.set_label(action.as_str());
+                        node.set_position_in_set(index + 1);
+                        node.set_size_of_set(action_panel.actions.len());
+
+                        menu.push_child(id);
+                        nodes.push((id, node));
+                    }
+
+                    window.push_child(ACTION_PANEL_NODE_ID);
+                    nodes.push((ACTION_PANEL_NODE_ID, menu));
+
+                    action_panel.focused_action
+                        .map(|index| NodeId(ACTION_NODE_ID_START + index as u64))
+                        .unwrap_or(ACTION_PANEL_NODE_ID)
+                });
+
+            match (focused_action, focused_result) {
+                (Some(focused_action), _) => focused_action,
+                (None, Some(index)) => NodeId(SEARCH_RESULT_NODE_ID_START + *index as u64),
+                (None, None) => SEARCH_FIELD_NODE_ID,
+            }
+        }
+        AccessibleView::Other { title } => {
+            window.set_label(title.as_str());
+
+            WINDOW_NODE_ID
+        }
+    };
+
+    nodes.push((WINDOW_NODE_ID, window));
+
+    let mut tree = Tree::new(WINDOW_NODE_ID);
+    tree.app_name = Some("Gauntlet".to_string());
+
+    TreeUpdate {
+        nodes,
+        tree: Some(tree),
+        focus,
+    }
+}
+
+// full tree is sent every time, it is small enough and adapters only announce nodes which changed
+pub fn update_tree(window_id: window::Id, tree: TreeUpdate) -> Task<AppMsg> {
+    #[cfg(any(target_os = "macos", target_os = "windows"))]
+    {
+        window::run_with_handle(window_id, move |handle| platform::update_tree(Some(handle), tree))
+            .map(|_| AppMsg::Noop)
+    }
+
+    // at-spi adapter doesn't need a window handle, it talks to accessibility bus directly.
+    // window handle is also not available for layer shell windows
+    #[cfg(target_os = "linux")]
+    {
+        let _ = window_id;
+        platform::update_tree(None, tree);
+        Task::none()
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+    {
+        let _ = (window_id, tree);
+        Task::none()
+    }
+}
+
+// macos and windows adapters track focus of the window they are attached to, on linux it has to be provided
+pub fn update_window_focus(focused: bool) {
+    #[cfg(target_os = "linux")]
+    platform::update_window_focus(focused);
+
+    #[cfg(not(target_os = "linux"))]
+    let _ = focused;
+}
+
+#[cfg(any(target_os = "macos", target_os = "windows", target_os = "linux"))]
+mod platform {
+    use std::cell::RefCell;
+    use std::sync::Mutex;
+
+    use accesskit::{ActionHandler, ActionRequest, ActivationHandler, TreeUpdate};
+    use raw_window_handle::WindowHandle;
+
+    // adapters are created on first update, they are not Send so they are kept on event loop thread,
+    // where update and window handle tasks are run. latest tree is also needed when screen reader is started after the update
+    thread_local! {
+        static ADAPTER: RefCell<Option<Adapter>> = const { RefCell::new(None) };
+    }
+
+    static LATEST_TREE: Mutex<Option<TreeUpdate>> = Mutex::new(None);
+
+    struct TreeActivationHandler;
+
+    impl ActivationHandler for TreeActivationHandler {
+        fn request_initial_tree(&mut self) -> Option<TreeUpdate> {
+            LATEST_TREE.lock().unwrap().clone()
+        }
+    }
+
+    // ui is driven by keyboard, actions requested by assistive technologies are not supported yet
+    struct NoopActionHandler;
+
+    impl ActionHandler for NoopActionHandler {
+        fn do_action(&mut self, request: ActionRequest) {
+            tracing::debug!("Accessibility action is not supported: {:?}", request);
+        }
+    }
+
+    pub fn update_tree(handle: Option<WindowHandle<'_>>, tree: TreeUpdate) {
+        *LATEST_TREE.lock().unwrap() = Some(tree.clone());
+
+        // events can call back into adapter, so they are raised after it is no longer borrowed
+        let events = ADAPTER.with_borrow_mut(|adapter| {
+            if adapter.is_none() {
+                *adapter = Adapter::new(handle);
+            }
+
+            adapter.as_mut()?.update(tree)
+        });
+
+        if let Some(events) = events {
+            events.raise();
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn update_window_focus(focused: bool) {
+        ADAPTER.with_borrow_mut(|adapter| {
+            if let Some(Adapter(adapter)) = adapter {
+                adapter.update_window_focus_state(focused)
+            }
+        })
+    }
+
+    #[cfg(target_os = "macos")]
+    use accesskit_macos::QueuedEvents;
+
+    #[cfg(target_os = "macos")]
+    struct Adapter(accesskit_macos::SubclassingAdapter);
+
+    #[cfg(target_os = "macos")]
+    impl Adapter {
+        fn new(handle: Option<WindowHandle<'_>>) -> Option<Self> {
+            let raw_window_handle::RawWindowHandle::AppKit(handle) = handle?.as_raw() else {
+                return None;
+            };
+
+            // view pointer is valid for as long as window exists, main window is never closed
+            let adapter = unsafe {
+                accesskit_macos::SubclassingAdapter::new(handle.ns_view.as_ptr(), TreeActivationHandler, NoopActionHandler)
+            };
+
+            Some(Self(adapter))
+        }
+
+        fn update(&mut self, tree: TreeUpdate) -> Option<QueuedEvents> {
+            self.0.update_if_active(|| tree)
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    use accesskit_windows::QueuedEvents;
+
+    #[cfg(target_os = "windows")]
+    struct Adapter(accesskit_windows::SubclassingAdapter);
+
+    #[cfg(target_os = "windows")]
+    impl Adapter {
+        fn new(handle: Option<WindowHandle<'_>>) -> Option<Self> {
+            let raw_window_handle::RawWindowHandle::Win32(handle) = handle?.as_raw() else {
+                return None;
+            };
+
+            let hwnd = windows::Win32::Foundation::HWND(handle.hwnd.get() as _);
+
+            Some(Self(accesskit_windows::SubclassingAdapter::new(hwnd, TreeActivationHandler, NoopActionHandler)))
+        }
+
+        fn update(&mut self, tree: TreeUpdate) -> Option<QueuedEvents> {
+            self.0.update_if_active(|| tree)
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    struct Adapter(accesskit_unix::Adapter);
+
+    #[cfg(target_os = "linux")]
+    impl Adapter {
+        fn new(_handle: Option<WindowHandle<'_>>) -> Option<Self> {
+            Some(Self(accesskit_unix::Adapter::new(TreeActivationHandler, NoopActionHandler, NoopDeactivationHandler)))
+        }
+
+        fn update(&mut self, tree: TreeUpdate) -> Option<QueuedEvents> {
+            self.0.update_if_active(|| tree);
+
+            None
+        }
+    }
+
+    // at-spi adapter raises events itself
+    #[cfg(target_os = "linux")]
+    struct QueuedEvents;
+
+    #[cfg(target_os = "linux")]
+    impl QueuedEvents {
+        fn raise(self) {}
+    }
+
+    #[cfg(target_os = "linux")]
+    struct NoopDeactivationHandler;
+
+    #[cfg(target_os = "linux")]
+    impl accesskit::DeactivationHandler for NoopDeactivationHandler {
+        fn deactivate_accessibility(&mut self) {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(label: &str) -> AccessibleItem {
+        AccessibleItem {
+            label: label.to_string(),
+            description: None,
+        }
+    }
+
+    #[test]
+    fn focus_follows_focused_result() {
+        let view = AccessibleView::Main {
+            prompt: "fire".to_string(),
+            results: vec![item("Firefox"), item("Firewall")],
+            focused_result: Some(1),
+            action_panel: None,
+        };
+
+        let tree = build_tree(&view);
+
+        assert_eq!(tree.focus, NodeId(SEARCH_RESULT_NODE_ID_START + 1));
+
+        let (_, focused) = tree.nodes.iter()
+            .find(|(id, _)| *id == tree.focus)
+            .unwrap();
+
+        assert_eq!(focused.label(), Some("Firewall"));
+        assert_eq!(focused.is_selected(), Some(true));
+    }
+
+    #[test]
+    fn focus_follows_focused_action() {
+        let view = AccessibleView::Main {
+            prompt: "".to_string(),
+            results: vec![item("Firefox")],
+            focused_result: Some(0),
+            action_panel: Some(AccessibleActionPanel {
+                title: "Firefox".to_string(),
+                actions: vec!["Open".to_string(), "Copy".to_string()],
+                focused_action: Some(1),
+            }),
+        };
+
+        let tree = build_tree(&view);
+
+        assert_eq!(tree.focus, NodeId(ACTION_NODE_ID_START + 1));
+    }
+
+    #[test]
+    fn search_field_is_focused_without_results() {
+        let view = AccessibleView::Main {
+            prompt: "nothing".to_string(),
+            results: vec![],
+            focused_result: None,
+            action_panel: None,
+        };
+
+        assert_eq!(build_tree(&view).focus, SEARCH_FIELD_NODE_ID);
+    }
+}
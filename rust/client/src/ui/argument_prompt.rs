@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use iced::widget::text::Shaping;
+use iced::widget::text_input::focus;
+use iced::widget::{button, pick_list, row, text, text_input, value};
+use iced::{Alignment, Length, Task};
+use iced_fonts::{Bootstrap, BOOTSTRAP_FONT};
+
+use gauntlet_common::model::{EntrypointArgument, EntrypointArgumentType, EntrypointId, PluginId, SearchResult};
+use gauntlet_common::tr;
+
+use crate::ui::direction::directional;
+use crate::ui::theme::button::ButtonStyle;
+use crate::ui::theme::container::ContainerStyle;
+use crate::ui::theme::pick_list::PickListStyle;
+use crate::ui::theme::text::TextStyle;
+use crate::ui::theme::text_input::TextInputStyle;
+use crate::ui::theme::{Element, ThemableWidget};
+use crate::ui::AppMsg;
+
+// shown under main search bar to collect values of command arguments before running it
+pub struct ArgumentPrompt {
+    pub plugin_id: PluginId,
+    pub entrypoint_id: EntrypointId,
+    arguments: Vec<(EntrypointArgument, text_input::Id)>,
+    values: HashMap<String, String>,
+    error: Option<String>,
+    // main window loses focus while file dialog is open, it shouldn't be hidden because of that
+    picking_file: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ArgumentEnumItem {
+    value: String,
+    label: String,
+}
+
+impl Display for ArgumentEnumItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
+impl ArgumentPrompt {
+    pub fn new(search_result: &SearchResult) -> (ArgumentPrompt, Task<AppMsg>) {
+        let arguments: Vec<_> = search_result.entrypoint_arguments
+            .iter()
+            .map(|argument| (argument.clone(), text_input::Id::unique()))
+            .collect();
+
+        let focus_task = arguments.iter()
+            .find(|(argument, _)| !matches!(argument.argument_type, EntrypointArgumentType::Enum { .. }))
+            .map(|(_, id)| focus(id.clone()))
+            .unwrap_or_else(|| Task::none());
+
+        let prompt = ArgumentPrompt {
+            plugin_id: search_result.plugin_id.clone(),
+            entrypoint_id: search_result.entrypoint_id.clone(),
+            arguments,
+            values: HashMap::new(),
+            error: None,
+            picking_file: false,
+        };
+
+        (prompt, focus_task)
+    }
+
+    pub fn set_value(&mut self, id: String, value: String) {
+        self.error = None;
+
+        if value.is_empty() {
+            self.values.remove(&id);
+        } else {
+            self.values.insert(id, value);
+        }
+    }
+
+    pub fn is_picking_file(&self) -> bool {
+        self.picking_file
+    }
+
+    pub fn pick_file(&mut self, id: String) -> Task<AppMsg> {
+        self.picking_file = true;
+
+        Task::perform(
+            async move {
+                rfd::AsyncFileDialog::new()
+                    .pick_file()
+                    .await
+                    .map(|file| file.path().to_path_buf())
+            },
+            move |path| AppMsg::ArgumentPromptFilePicked { id: id.clone(), path }
+        )
+    }
+
+    // path is None if dialog was cancelled
+    pub fn file_picked(&mut self, id: String, path: Option<PathBuf>) {
+        self.picking_file = false;
+
+        if let Some(path) = path {
+            self.set_value(id, path.to_string_lossy().to_string());
+        }
+    }
+
+    // returns argument values if all of them are valid, otherwise shows an error
+    pub fn submit(&mut self) -> Option<HashMap<String, String>> {
+        for (argument, _) in &self.arguments {
+            match self.values.get(&argument.id) {
+                None => {
+                    if argument.required {
+                        self.error = Some(tr!("argument-required", name = argument.name.clone()));
+                        return None;
+                    }
+                }
+                Some(value) => {
+                    if let EntrypointArgumentType::File = argument.argument_type {
+                        if !Path::new(value).exists() {
+                            self.error = Some(tr!("argument-file-not-found", path = value.clone()));
+                            return None;
+                        }
+                    }
+                }
+            }
+        }
+
+        Some(self.values.clone())
+    }
+
+    pub fn view(&self) -> Element<'_, AppMsg> {
+        let mut items: Vec<Element<_>> = self.arguments
+            .iter()
+            .map(|(argument, input_id)| {
+                let placeholder = if argument.required {
+                    argument.name.clone()
+                } else {
+                    tr!("argument-optional", name = argument.name.clone())
+                };
+
+                let argument_id = argument.id.clone();
+
+                let input_value = self.values.get(&argument.id)
+                    .map(|value| value.as_str())
+                    .unwrap_or_default();
+
+                match &argument.argument_type {
+                    EntrypointArgumentType::String => {
+                        text_input(&placeholder, input_value)
+                            .id(input_id.clone())
+                            .on_input(move |value| AppMsg::ArgumentPromptChanged { id: argument_id.clone(), value })
+                            .on_submit(AppMsg::ArgumentPromptSubmit)
+                            .width(Length::Fill)
+                            .themed(TextInputStyle::FormInput)
+                    }
+                    EntrypointArgumentType::File => {
+                        let pick_file_msg = AppMsg::ArgumentPromptPickFile { id: argument_id.clone() };
+
+                        // path can also be typed or pasted
+                        let input: Element<_> = text_input(&placeholder, input_value)
+                            .id(input_id.clone())
+                            .on_input(move |value| AppMsg::ArgumentPromptChanged { id: argument_id.clone(), value })
+                            .on_submit(AppMsg::ArgumentPromptSubmit)
+                            .width(Length::Fill)
+                            .themed(TextInputStyle::FormInput);
+
+                        let icon: Element<_> = value(Bootstrap::FolderFill)
+                            .font(BOOTSTRAP_FONT)
+                            .into();
+
+                        let pick_file: Element<_> = button(icon)
+                            .on_press(pick_file_msg)
+                            .themed(ButtonStyle::Action);
+
+                        row(directional(vec![input, pick_file]))
+                            .spacing(4.0)
+                            .align_y(Alignment::Center)
+                            .width(Length::Fill)
+                            .into()
+                    }
+                    EntrypointArgumentType::Enum { enum_values } => {
+                        let items: Vec<_> = enum_values.iter()
+                            .map(|enum_value| ArgumentEnumItem {
+                                value: enum_value.value.clone(),
+                                label: enum_value.label.clone(),
+                            })
+                            .collect();
+
+                        let selected = self.values.get(&argument.id)
+                            .and_then(|value| items.iter().find(|item| &item.value == value))
+                            .cloned();
+
+                        pick_list(items, selected, move |item| AppMsg::ArgumentPromptChanged { id: argument_id.clone(), value: item.value })
+                            .placeholder(placeholder)
+                            .width(Length::Fill)
+                            .themed(PickListStyle::Default)
+                    }
+                }
+            })
+            .collect();
+
+        if let Some(error) = &self.error {
+            let error: Element<_> = text(error.clone())
+                .shaping(Shaping::Advanced)
+                .themed(TextStyle::MainListItemSubtext);
+
+            items.push(error);
+        }
+
+        let content: Element<_> = row(items)
+            .spacing(8.0)
+            .align_y(Alignment::Center)
+            .into();
+
+        iced::widget::container(content)
+            .width(Length::Fill)
+            .themed(ContainerStyle::MainSearchBar)
+    }
+}
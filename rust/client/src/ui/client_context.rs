@@ -3,22 +3,37 @@ use crate::ui::widget::{ActionPanel, ComponentWidgetEvent};
 use crate::ui::widget_container::PluginWidgetContainer;
 use crate::ui::AppMsg;
 use gauntlet_common::model::{EntrypointId, PhysicalShortcut, PluginId, RootWidget, UiRenderLocation, UiWidgetId};
+use iced::widget::image::Handle;
+use iced::window;
 use iced::Task;
 use std::collections::HashMap;
+use std::mem;
 use std::sync::Arc;
 
 pub struct ClientContext {
     inline_views: Vec<(PluginId, PluginWidgetContainer)>, // Vec to have stable ordering
     inline_view_shortcuts: HashMap<PluginId, HashMap<String, PhysicalShortcut>>,
+    // plugins listed first are shown on top, plugins not listed are shown after them in order of first render
+    inline_view_order: Vec<PluginId>,
+    // none means first inline view
+    focused_inline_view: Option<PluginId>,
     view: PluginWidgetContainer,
+    // plugin views moved out of main window into their own windows
+    detached_views: HashMap<window::Id, PluginWidgetContainer>,
+    // plugin views under the one opened from command palette, they keep their state and are shown again when it is closed
+    suspended_views: Vec<(PluginId, PluginWidgetContainer)>,
 }
 
 impl ClientContext {
-    pub fn new() -> Self {
+    pub fn new(inline_view_order: Vec<PluginId>) -> Self {
         Self {
             inline_views: vec![],
             inline_view_shortcuts: HashMap::new(),
+            inline_view_order,
+            focused_inline_view: None,
             view: PluginWidgetContainer::new(),
+            detached_views: HashMap::new(),
+            suspended_views: vec![],
         }
     }
 
@@ -26,13 +41,46 @@ impl ClientContext {
         &self.inline_views
     }
 
-    pub fn get_first_inline_view_container(&self) -> Option<&PluginWidgetContainer> {
-        self.inline_views.first()
+    pub fn get_focused_inline_view_container(&self) -> Option<&PluginWidgetContainer> {
+        let focused = self.focused_inline_view
+            .as_ref()
+            .and_then(|plugin_id| self.inline_views.iter().find(|(id, _)| id == plugin_id));
+
+        focused.or_else(|| self.inline_views.first())
             .map(|(_, container)| container)
     }
 
-    pub fn get_first_inline_view_action_panel(&self) -> Option<ActionPanel> {
-        self.get_first_inline_view_container()
+    pub fn is_inline_view_focused(&self, plugin_id: &PluginId) -> bool {
+        self.get_focused_inline_view_container()
+            .is_some_and(|container| &container.get_plugin_id() == plugin_id)
+    }
+
+    // cycles through inline views, wrapping around at both ends
+    pub fn focus_next_inline_view(&mut self) {
+        self.focus_inline_view_by_offset(1)
+    }
+
+    pub fn focus_previous_inline_view(&mut self) {
+        self.focus_inline_view_by_offset(self.inline_views.len().saturating_sub(1))
+    }
+
+    fn focus_inline_view_by_offset(&mut self, offset: usize) {
+        if self.inline_views.len() < 2 {
+            return
+        }
+
+        let current = self.get_focused_inline_view_container()
+            .map(|container| container.get_plugin_id())
+            .and_then(|plugin_id| self.inline_views.iter().position(|(id, _)| id == &plugin_id))
+            .unwrap_or(0);
+
+        let (plugin_id, _) = &self.inline_views[(current + offset) % self.inline_views.len()];
+
+        self.focused_inline_view = Some(plugin_id.clone());
+    }
+
+    pub fn get_focused_inline_view_action_panel(&self) -> Option<ActionPanel> {
+        self.get_focused_inline_view_container()
             .map(|container| {
                 match self.inline_view_shortcuts.get(&container.get_plugin_id()) {
                     None => container.get_action_panel(&HashMap::new()),
@@ -54,12 +102,24 @@ impl ClientContext {
             let (_, container) = &mut self.inline_views[index];
             container
         } else {
-            self.inline_views.push((plugin_id.clone(), PluginWidgetContainer::new()));
-            let (_, container) = self.inline_views.last_mut().expect("getting just pushed item");
+            let rank = self.inline_view_rank(plugin_id);
+
+            let index = self.inline_views.iter()
+                .position(|(id, _)| self.inline_view_rank(id) > rank)
+                .unwrap_or(self.inline_views.len());
+
+            self.inline_views.insert(index, (plugin_id.clone(), PluginWidgetContainer::new()));
+            let (_, container) = &mut self.inline_views[index];
             container
         }
     }
 
+    fn inline_view_rank(&self, plugin_id: &PluginId) -> usize {
+        self.inline_view_order.iter()
+            .position(|id| id == plugin_id)
+            .unwrap_or(self.inline_view_order.len())
+    }
+
     pub fn get_view_container(&self) -> &PluginWidgetContainer {
         &self.view
     }
@@ -76,28 +136,103 @@ impl ClientContext {
         self.view.get_entrypoint_id()
     }
 
+    // moves current plugin view into separate window, main window starts with empty view container
+    pub fn detach_view(&mut self, window_id: window::Id) {
+        let container = mem::replace(&mut self.view, PluginWidgetContainer::new());
+
+        self.detached_views.insert(window_id, container);
+    }
+
+    pub fn get_detached_view_window(&self, plugin_id: &PluginId) -> Option<window::Id> {
+        self.detached_views.iter()
+            .find(|(_, container)| &container.get_plugin_id() == plugin_id)
+            .map(|(window_id, _)| *window_id)
+    }
+
+    pub fn get_detached_view_container(&self, window_id: window::Id) -> Option<&PluginWidgetContainer> {
+        self.detached_views.get(&window_id)
+    }
+
+    pub fn remove_detached_view(&mut self, window_id: window::Id) -> Option<PluginId> {
+        self.detached_views.remove(&window_id)
+            .map(|container| container.get_plugin_id())
+    }
+
+    pub fn suspend_view(&mut self, plugin_id: PluginId) {
+        let container = mem::replace(&mut self.view, PluginWidgetContainer::new());
+
+        self.suspended_views.push((plugin_id, container));
+    }
+
+    pub fn resume_view(&mut self) {
+        if let Some((_, container)) = self.suspended_views.pop() {
+            self.view = container;
+        }
+    }
+
+    pub fn is_view_suspended(&self, plugin_id: &PluginId) -> bool {
+        self.suspended_views.iter().any(|(id, _)| id == plugin_id)
+    }
+
+    // returns plugins which views need to be closed
+    pub fn clear_suspended_views(&mut self) -> Vec<PluginId> {
+        self.suspended_views.drain(..)
+            .map(|(plugin_id, _)| plugin_id)
+            .collect()
+    }
+
     pub fn render_ui(
         &mut self,
         render_location: UiRenderLocation,
         container: Arc<RootWidget>,
-        images: HashMap<UiWidgetId, Vec<u8>>,
+        images: HashMap<UiWidgetId, Handle>,
         plugin_id: &PluginId,
         plugin_name: &str,
         entrypoint_id: &EntrypointId,
-        entrypoint_name: &str
+        entrypoint_name: &str,
+        breadcrumbs: Vec<String>,
+        key_handlers: Vec<PhysicalShortcut>,
     ) -> AppMsg {
         match render_location {
-            UiRenderLocation::InlineView => self.get_mut_inline_view_container(plugin_id).replace_view(container, images, plugin_id, plugin_name, entrypoint_id, entrypoint_name),
-            UiRenderLocation::View => self.get_mut_view_container().replace_view(container, images, plugin_id, plugin_name, entrypoint_id, entrypoint_name)
+            UiRenderLocation::InlineView => self.get_mut_inline_view_container(plugin_id).replace_view(container, images, plugin_id, plugin_name, entrypoint_id, entrypoint_name, breadcrumbs, key_handlers),
+            UiRenderLocation::View => {
+                match self.get_detached_view_window(plugin_id) {
+                    Some(window_id) => {
+                        let detached_container = self.detached_views.get_mut(&window_id)
+                            .expect("detached view container should exist for its window");
+
+                        // detached window has no search bar focus or scroll restoration handled by main window
+                        let _ = detached_container.replace_view(container, images, plugin_id, plugin_name, entrypoint_id, entrypoint_name, breadcrumbs, key_handlers);
+
+                        AppMsg::Noop
+                    }
+                    None => {
+                        match self.suspended_views.iter_mut().find(|(id, _)| id == plugin_id) {
+                            Some((_, suspended_container)) => {
+                                // suspended view is not shown, so focus changes requested by render are not needed
+                                let _ = suspended_container.replace_view(container, images, plugin_id, plugin_name, entrypoint_id, entrypoint_name, breadcrumbs, key_handlers);
+
+                                AppMsg::Noop
+                            }
+                            None => self.get_mut_view_container().replace_view(container, images, plugin_id, plugin_name, entrypoint_id, entrypoint_name, breadcrumbs, key_handlers)
+                        }
+                    }
+                }
+            }
         }
     }
 
+    pub fn view_has_key_handler(&self, shortcut: &PhysicalShortcut) -> bool {
+        self.view.has_key_handler(shortcut)
+    }
+
     pub fn set_inline_view_shortcuts(&mut self, shortcuts: HashMap<PluginId, HashMap<String, PhysicalShortcut>>) {
         self.inline_view_shortcuts = shortcuts;
     }
 
      pub fn clear_all_inline_views(&mut self) {
-        self.inline_views.clear()
+        self.inline_views.clear();
+        self.focused_inline_view = None;
     }
 
     pub fn clear_inline_view(&mut self, plugin_id: &PluginId) {
@@ -109,7 +244,16 @@ impl ClientContext {
     pub fn handle_event(&mut self, render_location: UiRenderLocation, plugin_id: &PluginId, event: ComponentWidgetEvent) -> Option<UiViewEvent> {
         match render_location {
             UiRenderLocation::InlineView => self.get_mut_inline_view_container(&plugin_id).handle_event(plugin_id.clone(), event),
-            UiRenderLocation::View => self.get_mut_view_container().handle_event(plugin_id.clone(), event)
+            UiRenderLocation::View => {
+                match self.get_detached_view_window(plugin_id) {
+                    Some(window_id) => {
+                        self.detached_views.get_mut(&window_id)
+                            .expect("detached view container should exist for its window")
+                            .handle_event(plugin_id.clone(), event)
+                    }
+                    None => self.get_mut_view_container().handle_event(plugin_id.clone(), event)
+                }
+            }
         }
     }
 
@@ -129,6 +273,14 @@ impl ClientContext {
         self.view.toggle_action_panel()
     }
 
+    pub fn select_tab(&mut self, tab_id: String) -> Task<AppMsg> {
+        self.view.select_tab(tab_id)
+    }
+
+    pub fn select_tab_by_index(&mut self, index: usize) -> Option<Task<AppMsg>> {
+        self.view.select_tab_by_index(index)
+    }
+
     pub fn get_action_ids(&self) -> Vec<UiWidgetId> {
         self.view.get_action_ids()
     }
@@ -137,6 +289,18 @@ impl ClientContext {
         self.view.get_focused_item_id()
     }
 
+    pub fn view_accepts_text(&self) -> bool {
+        self.view.accepts_text()
+    }
+
+    pub fn view_tab_count(&self) -> usize {
+        self.view.tab_count()
+    }
+
+    pub fn get_view_search_bar_with_text(&self) -> Option<UiWidgetId> {
+        self.view.get_search_bar_with_text()
+    }
+
     pub fn focus_up(&mut self) -> Task<AppMsg> {
         self.view.focus_up()
     }
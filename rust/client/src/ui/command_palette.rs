@@ -0,0 +1,113 @@
+use iced::widget::text_input::focus;
+use iced::widget::{column, container, mouse_area, scrollable, text_input};
+use iced::{Length, Task};
+
+use gauntlet_common::model::{PluginId, SearchResult, SearchResultEntrypointType};
+use gauntlet_common::tr;
+
+use crate::ui::scroll_handle::{ScrollHandle, estimated_main_list_item_height};
+use crate::ui::search_list::{search_list, SearchListEvent};
+use crate::ui::direction::text_alignment;
+use crate::ui::theme::container::ContainerStyle;
+use crate::ui::theme::text_input::TextInputStyle;
+use crate::ui::theme::{Element, ThemableWidget};
+use crate::ui::AppMsg;
+
+// search over all entrypoints shown on top of plugin view when main shortcut is pressed while it is open,
+// selected view is pushed on top of the current one which is reopened when the new one is closed
+pub struct CommandPalette {
+    pub search_field_id: text_input::Id,
+    pub query: String,
+    pub results: Vec<SearchResult>,
+    pub focused_result: ScrollHandle,
+}
+
+impl CommandPalette {
+    pub fn new() -> (CommandPalette, Task<AppMsg>) {
+        let search_field_id = text_input::Id::unique();
+
+        let command_palette = CommandPalette {
+            search_field_id: search_field_id.clone(),
+            query: "".to_string(),
+            results: vec![],
+            focused_result: ScrollHandle::new(true, estimated_main_list_item_height(), 5),
+        };
+
+        (command_palette, focus(search_field_id))
+    }
+
+    // results of outdated queries are ignored
+    pub fn set_results(&mut self, query: String, results: Vec<SearchResult>, open_view_plugin_ids: &[PluginId]) {
+        if query != self.query {
+            return;
+        }
+
+        // commands which require arguments can only be run from main view, where argument prompt is shown
+        self.results = results.into_iter()
+            .filter(|result| match result.entrypoint_type {
+                SearchResultEntrypointType::Command => result.entrypoint_arguments.iter().all(|argument| !argument.required),
+                SearchResultEntrypointType::View => !open_view_plugin_ids.contains(&result.plugin_id),
+                SearchResultEntrypointType::Generated => false,
+            })
+            .collect();
+
+        self.focused_result.reset(true);
+    }
+
+    pub fn focused(&self) -> Option<SearchResult> {
+        self.focused_result.get(&self.results).cloned()
+    }
+
+    pub fn view(&self) -> Element<'_, AppMsg> {
+        let input: Element<_> = text_input(&tr!("command-palette-placeholder"), &self.query)
+            .on_input(AppMsg::CommandPaletteQueryChanged)
+            .on_submit(AppMsg::CommandPaletteSubmit)
+            .ignore_with_modifiers(true)
+            .id(self.search_field_id.clone())
+            .width(Length::Fill)
+            .align_x(text_alignment())
+            .themed(TextInputStyle::MainSearch);
+
+        let input: Element<_> = container(input)
+            .width(Length::Fill)
+            .themed(ContainerStyle::MainSearchBar);
+
+        let list = search_list(&self.results, 0, false, None, None, &self.focused_result, false)
+            .map(|event| match event {
+                SearchListEvent::Primary(search_result) => AppMsg::RunCommandPaletteResult { search_result },
+                SearchListEvent::Focus(index) => AppMsg::CommandPaletteFocus { index },
+                _ => AppMsg::Noop,
+            });
+
+        let list = container(list)
+            .width(Length::Fill)
+            .themed(ContainerStyle::MainListInner);
+
+        let list: Element<_> = scrollable(list)
+            .id(self.focused_result.scrollable_id.clone())
+            .width(Length::Fill)
+            .into();
+
+        let list: Element<_> = container(list)
+            .width(Length::Fill)
+            .max_height(estimated_main_list_item_height() * 5.0)
+            .into();
+
+        let palette: Element<_> = column([input, list])
+            .into();
+
+        let palette: Element<_> = container(palette)
+            .width(Length::Fill)
+            .themed(ContainerStyle::ActionPanel);
+
+        let palette: Element<_> = container(palette)
+            .padding(32.0)
+            .center_x(Length::Fill)
+            .into();
+
+        // clicking outside of the palette closes it
+        mouse_area(palette)
+            .on_press(AppMsg::CloseCommandPalette)
+            .into()
+    }
+}
@@ -0,0 +1,177 @@
+use iced::advanced::layout::Limits;
+use iced::advanced::layout::Node;
+use iced::advanced::overlay;
+use iced::advanced::renderer;
+use iced::advanced::widget::tree::State;
+use iced::advanced::widget::tree::Tag;
+use iced::advanced::widget::Operation;
+use iced::advanced::widget::Tree;
+use iced::advanced::Clipboard;
+use iced::advanced::Layout;
+use iced::advanced::Shell;
+use iced::advanced::Widget;
+use iced::event::Status;
+use iced::mouse;
+use iced::mouse::Cursor;
+use iced::Element;
+use iced::Event;
+use iced::Length;
+use iced::Point;
+use iced::Rectangle;
+use iced::Size;
+use iced::Vector;
+
+// distance mouse has to move while pressed before it is considered a drag and not a click
+const DRAG_THRESHOLD: f32 = 6.0;
+
+// wraps widget, e.g. list item button, and publishes message when user starts dragging it with mouse.
+// events are still passed to the content, so clicking works as before
+pub struct DragSource<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    on_drag: Option<Message>,
+}
+
+impl<'a, Message, Theme, Renderer> DragSource<'a, Message, Theme, Renderer> {
+    #[must_use]
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            on_drag: None,
+        }
+    }
+
+    #[must_use]
+    pub fn on_drag(mut self, message: Message) -> Self {
+        self.on_drag = Some(message);
+        self
+    }
+}
+
+struct DragSourceState {
+    pressed_at: Option<Point>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for DragSource<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content.as_widget().layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(&tree.children[0], renderer, theme, style, layout, cursor, viewport)
+    }
+
+    fn tag(&self) -> Tag {
+        Tag::of::<DragSourceState>()
+    }
+
+    fn state(&self) -> State {
+        State::new(DragSourceState {
+            pressed_at: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content))
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget().operate(&mut tree.children[0], layout, renderer, operation)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> Status {
+        let state = tree.state.downcast_mut::<DragSourceState>();
+
+        // content, e.g. button, captures press event, so it is looked at before content receives it
+        match &event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                state.pressed_at = cursor.position_over(layout.bounds());
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                state.pressed_at = None;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some(pressed_at) = state.pressed_at {
+                    if pressed_at.distance(*position) > DRAG_THRESHOLD {
+                        state.pressed_at = None;
+
+                        if let Some(message) = &self.on_drag {
+                            shell.publish(message.clone());
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        self.content.as_widget_mut().on_event(&mut tree.children[0], event, layout, cursor, renderer, clipboard, shell, viewport)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(&tree.children[0], layout, cursor, viewport, renderer)
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<DragSource<'a, Message, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(drag_source: DragSource<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(drag_source)
+    }
+}
@@ -0,0 +1,192 @@
+use iced::advanced::layout::Limits;
+use iced::advanced::layout::Node;
+use iced::advanced::overlay;
+use iced::advanced::renderer;
+use iced::advanced::widget::operation::Focusable;
+use iced::advanced::widget::tree::State;
+use iced::advanced::widget::tree::Tag;
+use iced::advanced::widget::Id;
+use iced::advanced::widget::Operation;
+use iced::advanced::widget::Tree;
+use iced::advanced::Clipboard;
+use iced::advanced::Layout;
+use iced::advanced::Shell;
+use iced::advanced::Widget;
+use iced::event::Status;
+use iced::mouse;
+use iced::mouse::Cursor;
+use iced::Element;
+use iced::Event;
+use iced::Length;
+use iced::Rectangle;
+use iced::Size;
+use iced::Vector;
+
+// wraps focusable widget, e.g. text input, and publishes messages when its focus changes,
+// focus can change both because of user input and because of focus operations done by frontend,
+// the latter is noticed on the next event received by the widget
+pub struct FocusTracker<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    on_focus: Option<Message>,
+    on_blur: Option<Message>,
+}
+
+impl<'a, Message, Theme, Renderer> FocusTracker<'a, Message, Theme, Renderer> {
+    #[must_use]
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            on_focus: None,
+            on_blur: None,
+        }
+    }
+
+    #[must_use]
+    pub fn on_focus(mut self, message: Message) -> Self {
+        self.on_focus = Some(message);
+        self
+    }
+
+    #[must_use]
+    pub fn on_blur(mut self, message: Message) -> Self {
+        self.on_blur = Some(message);
+        self
+    }
+}
+
+struct FocusTrackerState {
+    focused: bool,
+}
+
+struct IsFocused {
+    focused: bool,
+}
+
+impl Operation for IsFocused {
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        self.focused = self.focused || state.is_focused();
+    }
+
+    fn container(&mut self, _id: Option<&Id>, _bounds: Rectangle, operate_on_children: &mut dyn FnMut(&mut dyn Operation)) {
+        operate_on_children(self)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for FocusTracker<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content.as_widget().layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(&tree.children[0], renderer, theme, style, layout, cursor, viewport)
+    }
+
+    fn tag(&self) -> Tag {
+        Tag::of::<FocusTrackerState>()
+    }
+
+    fn state(&self) -> State {
+        State::new(FocusTrackerState {
+            focused: false,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content))
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget().operate(&mut tree.children[0], layout, renderer, operation)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> Status {
+        let status = self.content.as_widget_mut().on_event(&mut tree.children[0], event, layout, cursor, renderer, clipboard, shell, viewport);
+
+        let mut operation = IsFocused { focused: false };
+
+        self.content.as_widget().operate(&mut tree.children[0], layout, renderer, &mut operation);
+
+        let state = tree.state.downcast_mut::<FocusTrackerState>();
+
+        if state.focused != operation.focused {
+            state.focused = operation.focused;
+
+            let message = if state.focused { &self.on_focus } else { &self.on_blur };
+
+            if let Some(message) = message {
+                shell.publish(message.clone());
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(&tree.children[0], layout, cursor, viewport, renderer)
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<FocusTracker<'a, Message, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(focus_tracker: FocusTracker<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(focus_tracker)
+    }
+}
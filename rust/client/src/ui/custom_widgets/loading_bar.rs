@@ -18,8 +18,20 @@ use iced::Rectangle;
 use iced::Shadow;
 use iced::Size;
 use iced::{window, Color};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
 
+// when enabled, loading bar is shown as a static bar instead of an animated one
+static REDUCED_MOTION: AtomicBool = AtomicBool::new(false);
+
+pub fn set_reduced_motion(reduced_motion: bool) {
+    REDUCED_MOTION.store(reduced_motion, Ordering::Relaxed);
+}
+
+fn is_reduced_motion() -> bool {
+    REDUCED_MOTION.load(Ordering::Relaxed)
+}
+
 pub struct LoadingBar<'a, Theme>
 where
     Theme: Catalog,
@@ -142,13 +154,19 @@ where
 
         let state = state.state.downcast_ref::<LoadingBarState>();
 
-        // works but quick and hacky
+        let (x, width) = if is_reduced_motion() {
+            (position.x, size.width)
+        } else {
+            // works but quick and hacky
+            (position.x + (size.width * state.t * 1.3) - self.segment_width, self.segment_width)
+        };
+
         renderer.fill_quad(
             renderer::Quad {
                 bounds: Rectangle {
-                    x: position.x + (size.width * state.t * 1.3) - self.segment_width,
+                    x,
                     y: position.y,
-                    width: self.segment_width,
+                    width,
                     height: size.height,
                 },
                 border: Border::default(),
@@ -185,7 +203,7 @@ where
         let bounds = layout.bounds();
 
         if let Event::Window(window::Event::RedrawRequested(now)) = event {
-            if is_visible(&bounds) {
+            if is_visible(&bounds) && !is_reduced_motion() {
                 let state = state.state.downcast_mut::<LoadingBarState>();
                 let duration = (now - state.last_update).as_secs_f32();
                 let increment = if self.rate == Duration::ZERO {
@@ -1 +1,4 @@
 pub mod loading_bar;
+pub mod focus_tracker;
+pub mod drag_source;
+pub mod theme_adapter;
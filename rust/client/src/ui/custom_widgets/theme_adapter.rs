@@ -0,0 +1,239 @@
+use iced::advanced::layout::Limits;
+use iced::advanced::layout::Node;
+use iced::advanced::overlay;
+use iced::advanced::renderer;
+use iced::advanced::widget::tree::State;
+use iced::advanced::widget::tree::Tag;
+use iced::advanced::widget::Operation;
+use iced::advanced::widget::Tree;
+use iced::advanced::Clipboard;
+use iced::advanced::Layout;
+use iced::advanced::Shell;
+use iced::advanced::Widget;
+use iced::application::DefaultStyle;
+use iced::event::Status;
+use iced::mouse;
+use iced::mouse::Cursor;
+use iced::Element;
+use iced::Event;
+use iced::Length;
+use iced::Point;
+use iced::Rectangle;
+use iced::Size;
+use iced::Vector;
+
+// shows content which was made for another theme type, e.g. settings ui inside launcher window,
+// content is drawn on top of background of its own theme
+pub struct ThemeAdapter<'a, Message, InnerTheme, Theme, Renderer> {
+    content: Element<'a, Message, InnerTheme, Renderer>,
+    theme: InnerTheme,
+    _theme: std::marker::PhantomData<Theme>,
+}
+
+impl<'a, Message, InnerTheme, Theme, Renderer> ThemeAdapter<'a, Message, InnerTheme, Theme, Renderer> {
+    #[must_use]
+    pub fn new(content: impl Into<Element<'a, Message, InnerTheme, Renderer>>, theme: InnerTheme) -> Self {
+        Self {
+            content: content.into(),
+            theme,
+            _theme: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, Message, InnerTheme, Theme, Renderer> Widget<Message, Theme, Renderer> for ThemeAdapter<'a, Message, InnerTheme, Theme, Renderer>
+where
+    InnerTheme: DefaultStyle,
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content.as_widget().layout(tree, renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let appearance = self.theme.default_style();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                ..renderer::Quad::default()
+            },
+            appearance.background_color,
+        );
+
+        let style = renderer::Style {
+            text_color: appearance.text_color,
+        };
+
+        self.content.as_widget().draw(tree, renderer, &self.theme, &style, layout, cursor, viewport)
+    }
+
+    fn tag(&self) -> Tag {
+        self.content.as_widget().tag()
+    }
+
+    fn state(&self) -> State {
+        self.content.as_widget().state()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.content.as_widget().children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.content.as_widget().diff(tree)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget().operate(tree, layout, renderer, operation)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> Status {
+        self.content.as_widget_mut().on_event(tree, event, layout, cursor, renderer, clipboard, shell, viewport)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(tree, layout, cursor, viewport, renderer)
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let Self { content, theme, .. } = self;
+
+        content.as_widget_mut()
+            .overlay(tree, layout, renderer, translation)
+            .map(|overlay| {
+                overlay::Element::new(Box::new(OverlayThemeAdapter {
+                    overlay,
+                    theme,
+                }))
+            })
+    }
+}
+
+// pick list menus and other overlays of content have to be drawn with content theme too
+struct OverlayThemeAdapter<'a, 'b, Message, InnerTheme, Renderer> {
+    overlay: overlay::Element<'a, Message, InnerTheme, Renderer>,
+    theme: &'b InnerTheme,
+}
+
+impl<'a, 'b, Message, InnerTheme, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer> for OverlayThemeAdapter<'a, 'b, Message, InnerTheme, Renderer>
+where
+    InnerTheme: DefaultStyle,
+    Renderer: renderer::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
+        self.overlay.layout(renderer, bounds)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let style = renderer::Style {
+            text_color: self.theme.default_style().text_color,
+        };
+
+        self.overlay.draw(renderer, self.theme, &style, layout, cursor)
+    }
+
+    fn operate(&mut self, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        self.overlay.operate(layout, renderer, operation)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> Status {
+        self.overlay.on_event(event, layout, cursor, renderer, clipboard, shell)
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.overlay.mouse_interaction(layout, cursor, viewport, renderer)
+    }
+
+    fn is_over(&self, layout: Layout<'_>, renderer: &Renderer, cursor_position: Point) -> bool {
+        self.overlay.is_over(layout, renderer, cursor_position)
+    }
+
+    fn overlay<'c>(&'c mut self, layout: Layout<'_>, renderer: &Renderer) -> Option<overlay::Element<'c, Message, Theme, Renderer>> {
+        let theme = self.theme;
+
+        self.overlay
+            .overlay(layout, renderer)
+            .map(|overlay| {
+                overlay::Element::new(Box::new(OverlayThemeAdapter {
+                    overlay,
+                    theme,
+                }))
+            })
+    }
+}
+
+impl<'a, Message, InnerTheme, Theme, Renderer> From<ThemeAdapter<'a, Message, InnerTheme, Theme, Renderer>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    InnerTheme: DefaultStyle + 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(theme_adapter: ThemeAdapter<'a, Message, InnerTheme, Theme, Renderer>) -> Self {
+        Self::new(theme_adapter)
+    }
+}
@@ -0,0 +1,101 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use gauntlet_common::model::{PhysicalKey, PhysicalShortcut};
+use iced::alignment::Horizontal;
+use iced::widget::{container, text};
+use iced::Length;
+
+use crate::ui::fonts::monospace_font;
+use crate::ui::theme::container::ContainerStyle;
+use crate::ui::theme::{Element, ThemableWidget};
+use crate::ui::AppMsg;
+
+const SAMPLE_LIMIT: usize = 120;
+
+// hidden overlay for diagnosing performance, timings are collected only while it is shown
+pub struct DebugHud {
+    last_frame: Option<Instant>,
+    frame_times: VecDeque<Duration>,
+    search_times: VecDeque<Duration>,
+}
+
+impl DebugHud {
+    pub fn new() -> Self {
+        Self {
+            last_frame: None,
+            frame_times: VecDeque::new(),
+            search_times: VecDeque::new(),
+        }
+    }
+
+    pub fn frame(&mut self, now: Instant) {
+        if let Some(last_frame) = self.last_frame {
+            push_sample(&mut self.frame_times, now.duration_since(last_frame));
+        }
+
+        self.last_frame = Some(now);
+    }
+
+    pub fn search(&mut self, duration: Duration) {
+        push_sample(&mut self.search_times, duration);
+    }
+
+    pub fn view(&self) -> Element<'_, AppMsg> {
+        let frame_average = average(&self.frame_times);
+        let fps = if frame_average.is_zero() { 0.0 } else { 1.0 / frame_average.as_secs_f64() };
+
+        let search_last = self.search_times.back().copied().unwrap_or_default();
+        let search_max = self.search_times.iter().max().copied().unwrap_or_default();
+
+        let content = format!(
+            "frame {:.1} ms ({:.0} fps) | search last {:.1} ms, avg {:.1} ms, max {:.1} ms",
+            millis(frame_average),
+            fps,
+            millis(search_last),
+            millis(average(&self.search_times)),
+            millis(search_max),
+        );
+
+        let content: Element<_> = text(content)
+            .font(monospace_font())
+            .size(12)
+            .into();
+
+        container(content)
+            .width(Length::Fill)
+            .align_x(Horizontal::Center)
+            .padding(4.0)
+            .themed(ContainerStyle::RootBottomPanel)
+    }
+}
+
+pub fn debug_hud_shortcut() -> PhysicalShortcut {
+    PhysicalShortcut {
+        physical_key: PhysicalKey::F12,
+        modifier_shift: true,
+        modifier_control: cfg!(not(target_os = "macos")),
+        modifier_alt: false,
+        modifier_meta: cfg!(target_os = "macos"),
+    }
+}
+
+fn push_sample(samples: &mut VecDeque<Duration>, sample: Duration) {
+    if samples.len() >= SAMPLE_LIMIT {
+        samples.pop_front();
+    }
+
+    samples.push_back(sample);
+}
+
+fn average(samples: &VecDeque<Duration>) -> Duration {
+    if samples.is_empty() {
+        return Duration::ZERO
+    }
+
+    samples.iter().sum::<Duration>() / samples.len() as u32
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
@@ -0,0 +1,64 @@
+use iced::widget::text::Shaping;
+use iced::widget::{button, column, container, row, text};
+use iced::Length;
+
+use gauntlet_common::tr;
+
+use crate::ui::theme::button::ButtonStyle;
+use crate::ui::theme::container::ContainerStyle;
+use crate::ui::theme::text::TextStyle;
+use crate::ui::theme::{Element, ThemableWidget};
+use crate::ui::AppMsg;
+
+// shown on top of main window when deep link from another application is about to run entrypoint
+// for the first time, after it is confirmed deep links to the same entrypoint run without asking
+pub struct DeepLinkConfirmation {
+    pub uri: String,
+    plugin_name: String,
+    entrypoint_name: String,
+}
+
+impl DeepLinkConfirmation {
+    pub fn new(uri: String, plugin_name: String, entrypoint_name: String) -> Self {
+        Self {
+            uri,
+            plugin_name,
+            entrypoint_name,
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, AppMsg> {
+        let title: Element<_> = text(tr!("deep-link-confirmation-title", entrypoint = self.entrypoint_name.clone(), plugin = self.plugin_name.clone()))
+            .shaping(Shaping::Advanced)
+            .into();
+
+        let uri: Element<_> = text(self.uri.clone())
+            .shaping(Shaping::Advanced)
+            .themed(TextStyle::MainListItemSubtext);
+
+        let run: Element<_> = button(text(tr!("deep-link-confirmation-run")))
+            .on_press(AppMsg::DeepLinkConfirmed)
+            .themed(ButtonStyle::Action);
+
+        let cancel: Element<_> = button(text(tr!("deep-link-confirmation-cancel")))
+            .on_press(AppMsg::DeepLinkCancelled)
+            .themed(ButtonStyle::Action);
+
+        let buttons: Element<_> = row([run, cancel])
+            .spacing(8.0)
+            .into();
+
+        let content: Element<_> = column([title, uri, buttons])
+            .spacing(8.0)
+            .into();
+
+        let content: Element<_> = container(content)
+            .width(Length::Fill)
+            .themed(ContainerStyle::ActionPanel);
+
+        container(content)
+            .padding(32.0)
+            .center_x(Length::Fill)
+            .into()
+    }
+}
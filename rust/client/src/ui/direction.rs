@@ -0,0 +1,19 @@
+use gauntlet_common::i18n::is_rtl;
+use iced::alignment::Horizontal;
+
+// order of row items, e.g. icon, title and shortcut hint, is reversed when active locale is right-to-left
+pub fn directional<T>(mut items: Vec<T>) -> Vec<T> {
+    if is_rtl() {
+        items.reverse();
+    }
+
+    items
+}
+
+pub fn text_alignment() -> Horizontal {
+    if is_rtl() {
+        Horizontal::Right
+    } else {
+        Horizontal::Left
+    }
+}
@@ -0,0 +1,89 @@
+use std::path::PathBuf;
+
+use iced::window;
+use iced::Task;
+
+use crate::ui::AppMsg;
+
+#[derive(Debug, Clone)]
+pub enum DragPayload {
+    File(PathBuf),
+    Text(String),
+}
+
+// drag is started by the system using native window handle, it is not supported by winit.
+// on linux native drag requires gtk window, so it is not available there
+pub fn start_drag(window_id: window::Id, payload: DragPayload) -> Task<AppMsg> {
+    #[cfg(any(target_os = "macos", target_os = "windows"))]
+    {
+        let path = match payload_file(payload) {
+            Ok(path) => path,
+            Err(err) => {
+                tracing::warn!("Unable to prepare dragged item: {:?}", err);
+                return Task::none()
+            }
+        };
+
+        window::run_with_handle(window_id, move |handle| native::start_drag(handle, path))
+            .map(|_| AppMsg::Noop)
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    {
+        let _ = (window_id, payload);
+        tracing::debug!("Dragging items out of window is not supported on this platform");
+        Task::none()
+    }
+}
+
+// text is dragged as a text file, because it is what both platforms support for dragging from other processes
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+fn payload_file(payload: DragPayload) -> anyhow::Result<PathBuf> {
+    match payload {
+        DragPayload::File(path) => {
+            if !path.is_absolute() || !path.exists() {
+                return Err(anyhow::anyhow!("Dragged file doesn't exist: {:?}", path))
+            }
+
+            Ok(path)
+        }
+        DragPayload::Text(text) => {
+            // each drag gets its own directory, so that file dropped by previous drag is not overwritten
+            // while receiving application may still be reading it. directory is left to system temp cleanup
+            let dir = tempfile::Builder::new()
+                .prefix("gauntlet-drag-")
+                .tempdir()?
+                .into_path();
+
+            let path = dir.join("Text.txt");
+
+            std::fs::write(&path, text)?;
+
+            Ok(path)
+        }
+    }
+}
+
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+mod native {
+    use std::path::PathBuf;
+
+    use drag::{DragItem, Image, Options};
+    use raw_window_handle::WindowHandle;
+
+    const DRAG_IMAGE: &[u8] = include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../assets/linux/icon_256.png"));
+
+    pub fn start_drag(handle: WindowHandle<'_>, path: PathBuf) {
+        let result = drag::start_drag(
+            &handle,
+            DragItem::Files(vec![path]),
+            Image::Raw(DRAG_IMAGE.to_vec()),
+            |result, _| tracing::debug!("Drag finished: {:?}", result),
+            Options::default(),
+        );
+
+        if let Err(err) = result {
+            tracing::warn!("Unable to start drag: {:?}", err);
+        }
+    }
+}
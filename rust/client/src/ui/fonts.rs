@@ -0,0 +1,79 @@
+use std::sync::OnceLock;
+
+use iced::font;
+use iced::Font;
+use iced::Task;
+
+use gauntlet_common::model::UiFontConfig;
+
+use crate::ui::AppMsg;
+
+static FONTS: OnceLock<Fonts> = OnceLock::new();
+
+struct Fonts {
+    default: Font,
+    monospace: Font,
+}
+
+// iced requires font family names to be static, fonts are configured only once on startup
+pub fn init_fonts(config: &UiFontConfig) {
+    let fonts = Fonts {
+        default: font_with_family(&config.family, Font::DEFAULT),
+        monospace: font_with_family(&config.monospace_family, Font::MONOSPACE),
+    };
+
+    let _ = FONTS.set(fonts);
+}
+
+pub fn default_font() -> Font {
+    FONTS.get()
+        .map(|fonts| fonts.default)
+        .unwrap_or(Font::DEFAULT)
+}
+
+pub fn monospace_font() -> Font {
+    FONTS.get()
+        .map(|fonts| fonts.monospace)
+        .unwrap_or(Font::MONOSPACE)
+}
+
+// glyphs missing in the selected font (e.g. cjk, emoji or nerd font icons) are looked up in other system fonts
+// and in all additionally loaded font files, as long as text is shaped with advanced shaping
+pub fn load_font_files(config: &UiFontConfig) -> Vec<Task<AppMsg>> {
+    config.files
+        .iter()
+        .filter_map(|path| {
+            match std::fs::read(path) {
+                Ok(bytes) => {
+                    let path = path.clone();
+
+                    let task = font::load(bytes)
+                        .map(move |result| {
+                            if let Err(err) = result {
+                                tracing::warn!("Unable to load font file {:?}: {:?}", path, err);
+                            }
+
+                            AppMsg::Noop
+                        });
+
+                    Some(task)
+                }
+                Err(err) => {
+                    tracing::warn!("Unable to read font file {:?}: {:?}", path, err);
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+fn font_with_family(family: &Option<String>, default: Font) -> Font {
+    match family {
+        None => default,
+        Some(family) => {
+            let family: &'static str = Box::leak(family.clone().into_boxed_str());
+
+            Font::with_name(family)
+        }
+    }
+}
@@ -7,10 +7,11 @@ use crate::ui::AppMsg;
 const HUD_WINDOW_WIDTH: f32 = 400.0;
 const HUD_WINDOW_HEIGHT: f32 = 40.0;
 
+// each hud is a separate window, which is closed after 2 seconds
 pub fn show_hud_window(
     #[cfg(target_os = "linux")]
     wayland: bool,
-) -> Task<AppMsg> {
+) -> (window::Id, Task<AppMsg>) {
     #[cfg(target_os = "linux")]
     if wayland {
         open_wayland()
@@ -22,7 +23,7 @@ pub fn show_hud_window(
     open_non_wayland()
 }
 
-fn open_non_wayland() -> Task<AppMsg> {
+fn open_non_wayland() -> (window::Id, Task<AppMsg>) {
     let settings = Settings {
         size: Size::new(HUD_WINDOW_WIDTH, HUD_WINDOW_HEIGHT),
         position: Position::SpecificWith(|window, screen| {
@@ -42,22 +43,30 @@ fn open_non_wayland() -> Task<AppMsg> {
         ..Default::default()
     };
 
-    window::open(settings)
-        .1
+    let (id, open_task) = window::open(settings);
+
+    let task = open_task
         .then(|id| sleep_for_2_seconds(id))
-        .then(|id| window::close(id))
+        .then(|id| window::close(id).chain(Task::done(AppMsg::WindowClosed(id))));
+
+    (id, task)
 }
 
 #[cfg(target_os = "linux")]
-fn open_wayland() -> Task<AppMsg> {
+fn open_wayland() -> (window::Id, Task<AppMsg>) {
     let id = window::Id::unique();
     let settings = layer_shell_settings();
 
-    Task::batch([
+    let task = Task::batch([
         Task::done(AppMsg::LayerShell(crate::ui::layer_shell::LayerShellAppMsg::NewLayerShell { id, settings })),
         sleep_for_2_seconds(id)
-            .then(|id| Task::done(AppMsg::LayerShell(crate::ui::layer_shell::LayerShellAppMsg::RemoveWindow(id))))
-    ])
+            .then(|id| {
+                Task::done(AppMsg::LayerShell(crate::ui::layer_shell::LayerShellAppMsg::RemoveWindow(id)))
+                    .chain(Task::done(AppMsg::WindowClosed(id)))
+            })
+    ]);
+
+    (id, task)
 }
 
 #[cfg(target_os = "linux")]
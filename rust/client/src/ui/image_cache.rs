@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
+
+use gauntlet_common::model::{ImageHash, UiWidgetId};
+use gauntlet_common::rpc::backend_api::BackendForFrontendApi;
+use iced::widget::image::Handle;
+use lru::LruCache;
+
+const IMAGE_CACHE_SIZE: usize = 256;
+
+// decoded images keyed by content hash, so repeated renders of the same view
+// don't transfer and decode images again
+#[derive(Clone)]
+pub struct ImageCache {
+    images: Arc<Mutex<LruCache<ImageHash, Handle>>>,
+}
+
+impl ImageCache {
+    pub fn new() -> Self {
+        Self {
+            images: Arc::new(Mutex::new(LruCache::new(NonZeroUsize::new(IMAGE_CACHE_SIZE).unwrap()))),
+        }
+    }
+
+    pub async fn resolve(&self, backend_api: &BackendForFrontendApi, images: HashMap<UiWidgetId, ImageHash>) -> HashMap<UiWidgetId, Handle> {
+        let missing = {
+            let mut cache = self.images.lock().unwrap();
+
+            images.values()
+                .filter(|hash| cache.get(*hash).is_none())
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+
+        if !missing.is_empty() {
+            match backend_api.images(missing).await {
+                Ok(fetched) => {
+                    let mut cache = self.images.lock().unwrap();
+
+                    for (hash, bytes) in fetched {
+                        match decode_image(&bytes) {
+                            Ok(handle) => {
+                                cache.put(hash, handle);
+                            }
+                            Err(err) => {
+                                tracing::warn!("unable to decode image with hash {}: {:?}", hash, err);
+                            }
+                        }
+                    }
+                }
+                Err(err) => {
+                    tracing::warn!("unable to fetch images: {:?}", err);
+                }
+            }
+        }
+
+        let mut cache = self.images.lock().unwrap();
+
+        images.into_iter()
+            .filter_map(|(widget_id, hash)| cache.get(&hash).map(|handle| (widget_id, handle.clone())))
+            .collect()
+    }
+}
+
+pub fn decode_image(bytes: &[u8]) -> anyhow::Result<Handle> {
+    let image = image::load_from_memory(bytes)?
+        .into_rgba8();
+
+    Ok(Handle::from_rgba(image.width(), image.height(), image.into_raw()))
+}
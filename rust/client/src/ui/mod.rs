@@ -7,35 +7,46 @@ use iced::futures::channel::mpsc::Sender;
 use iced::futures::SinkExt;
 use iced::keyboard::key::{Named, Physical};
 use iced::keyboard::{Key, Modifiers};
+use iced::widget::image::Handle;
 use iced::widget::scrollable::{scroll_to, AbsoluteOffset};
 use iced::widget::text::Shaping;
 use iced::widget::text_input::focus;
-use iced::widget::{button, column, container, horizontal_rule, horizontal_space, row, scrollable, text, text_input, Space};
+use iced::widget::{button, column, container, horizontal_rule, horizontal_space, mouse_area, row, scrollable, stack, text, text_input, value, vertical_rule, Space};
 use iced::window::{Level, Mode, Position, Screenshot};
 use iced::{event, executor, font, futures, keyboard, stream, window, Alignment, Event, Font, Length, Padding, Pixels, Point, Renderer, Settings, Size, Subscription, Task};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::rc::Rc;
 use std::sync::{Arc, Mutex as StdMutex, Mutex, RwLock as StdRwLock};
+use std::time::{Duration, Instant};
 use iced::alignment::{Horizontal, Vertical};
-use iced_fonts::BOOTSTRAP_FONT_BYTES;
+use iced_fonts::{Bootstrap, BOOTSTRAP_FONT, BOOTSTRAP_FONT_BYTES};
 use serde::Deserialize;
+use tokio::sync::mpsc::UnboundedReceiver;
 use tokio::sync::{Mutex as TokioMutex, RwLock as TokioRwLock};
 
 use client_context::ClientContext;
-use gauntlet_common::model::{BackendRequestData, BackendResponseData, EntrypointId, UiTheme, KeyboardEventOrigin, PhysicalKey, PhysicalShortcut, PluginId, RootWidget, RootWidgetMembers, SearchResult, SearchResultEntrypointAction, SearchResultEntrypointActionType, SearchResultEntrypointType, UiRenderLocation, UiRequestData, UiResponseData, UiSetupData, UiWidgetId, WindowPositionMode};
+use gauntlet_common::model::{BackendRequestData, BackendResponseData, Density, EntrypointId, FileQuicklook, UiTheme, UiThemeMode, KeyboardEventOrigin, KeymapAction, KeymapBinding, OnboardingPreference, PhysicalKey, PhysicalShortcut, PluginId, RootWidget, RootWidgetMembers, SearchResult, SearchResultEntrypointAction, SearchResultPreview, SearchResultsSort, SearchResultEntrypointActionType, SearchResultEntrypointType, UiEscapeBehavior, UiRenderLocation, UiRendererInfo, UiRequestData, UiResponseData, UiScale, UiSetupData, UiWidgetId, WindowPositionMode};
 use gauntlet_common::rpc::backend_api::{BackendApi, BackendForFrontendApi, BackendForFrontendApiError};
 use gauntlet_common::scenario_convert::{ui_render_location_from_scenario};
 use gauntlet_common::scenario_model::{ScenarioFrontendEvent, ScenarioUiRenderLocation};
+use gauntlet_common::{settings_env_data_to_string, tr, SettingsEnvData};
 use gauntlet_common_ui::physical_key_model;
+use gauntlet_management_client::{GauntletSettingsTheme, SettingsWindow, SettingsWindowMsg, SETTINGS_WINDOW_SIZE};
 use gauntlet_utils::channel::{RequestReceiver, RequestSender, Responder};
 
 use crate::model::UiViewEvent;
-use crate::ui::search_list::search_list;
+use crate::ui::argument_prompt::ArgumentPrompt;
+use crate::ui::deep_link_confirmation::DeepLinkConfirmation;
+use crate::ui::search_query::parse_search_query;
+use crate::ui::direction::{directional, text_alignment};
+use crate::ui::search_list::{plugin_result_positions, plugin_scope_title, search_list, PluginGroups, SearchListEvent};
+use crate::ui::search_result_actions::{action_labels, builtin_action_at, builtin_action_items, undo_shortcut, undo_shortcut_label, BuiltinSearchResultAction, DisabledEntrypoint};
 use crate::ui::theme::container::{ContainerStyle, ContainerStyleInner};
+use crate::ui::theme::text::TextStyle;
 use crate::ui::theme::text_input::TextInputStyle;
-use crate::ui::theme::{Element, ThemableWidget};
+use crate::ui::theme::{density_text_size, text_size, Element, ThemableWidget};
 use crate::ui::widget::{render_root, ActionPanel, ActionPanelItem, ComponentWidgetEvent};
 
 mod search_list;
@@ -43,48 +54,137 @@ mod widget;
 mod theme;
 mod client_context;
 mod widget_container;
-#[cfg(any(target_os = "macos", target_os = "windows"))]
 mod sys_tray;
 mod custom_widgets;
 mod scroll_handle;
 mod state;
 mod hud;
 mod grid_navigation;
+mod search_result_actions;
+mod argument_prompt;
+mod deep_link_confirmation;
+mod search_query;
+mod direction;
+mod debug_hud;
+mod shortcut_hints;
+mod onboarding;
+mod image_cache;
+mod skeleton;
+mod command_palette;
+mod fonts;
+mod renderer;
+mod ui_scale;
+mod window_overlay;
+mod drag_out;
+mod accessibility;
+mod windows;
+mod result_preview;
+mod quicklook;
 
 use crate::global_shortcut::{convert_physical_shortcut_to_hotkey, register_listener};
+use crate::ui::custom_widgets::loading_bar;
+use crate::ui::debug_hud::{debug_hud_shortcut, DebugHud};
+use crate::ui::onboarding::onboarding_view;
+use crate::ui::shortcut_hints::{detach_view_shortcut, pin_shortcut, shortcut_hints_view, ShortcutHintsContext};
+use crate::ui::image_cache::{decode_image, ImageCache};
 use crate::ui::custom_widgets::loading_bar::LoadingBar;
+use crate::ui::skeleton::skeleton_view;
+use crate::ui::command_palette::CommandPalette;
+use crate::ui::renderer::{configure_renderer, detect_renderer};
+use crate::ui::ui_scale::{detect_monitor, UiScaleState};
+use crate::ui::window_overlay::apply_window_overlay;
+use crate::ui::drag_out::{start_drag, DragPayload};
+use crate::ui::accessibility::{AccessibleActionPanel, AccessibleItem, AccessibleView};
 use crate::ui::hud::show_hud_window;
 use crate::ui::scroll_handle::ScrollHandle;
-use crate::ui::state::{ErrorViewData, Focus, GlobalState, LoadingBarState, MainViewState, PluginViewData, PluginViewState};
+use crate::ui::sys_tray::{SysTray, TrayEvent, TrayStatus};
+use crate::ui::state::{ErrorViewData, Focus, GlobalState, LoadingBarState, MainViewState, PluginScope, PluginViewData, PluginViewState};
 use crate::ui::widget_container::PluginWidgetContainer;
+use crate::ui::windows::{AppWindow, AppWindows};
+use crate::ui::result_preview::{toggle_preview_shortcut, ResultPreview, ResultPreviewContent};
+use crate::ui::quicklook::Quicklook;
+use crate::ui::custom_widgets::theme_adapter::ThemeAdapter;
 pub use theme::GauntletComplexTheme;
 
 pub struct AppModel {
     // logic
     backend_api: BackendForFrontendApi,
+    // used by settings window, which talks to server the same way as separate settings process
+    settings_backend_api: BackendApi,
     global_hotkey_manager: Arc<StdRwLock<GlobalHotKeyManager>>,
     current_hotkey: Arc<StdMutex<Option<HotKey>>>,
     frontend_receiver: Arc<TokioRwLock<RequestReceiver<UiRequestData, UiResponseData>>>,
-    main_window_id: window::Id,
+    image_cache: ImageCache,
+    tray_receiver: Arc<TokioRwLock<UnboundedReceiver<TrayEvent>>>,
+    windows: AppWindows,
+    settings_window: Option<SettingsWindow>,
     focused: bool,
     opened: bool,
     wayland: bool,
-    #[cfg(any(target_os = "macos", target_os = "windows"))]
-    tray_icon: tray_icon::TrayIcon,
+    tray: Option<SysTray>,
     theme: GauntletComplexTheme,
+    ui_theme: UiTheme,
+    density: Density,
+    ui_scale: UiScaleState,
+    text_size: f32,
     window_position_mode: WindowPositionMode,
+    window_overlay: bool,
+    search_results_sort: SearchResultsSort,
+    results_per_plugin: Option<usize>,
     close_on_unfocus: bool,
+    // pinned window is not hidden when it loses focus, regardless of close_on_unfocus
+    pinned: bool,
+    escape_behavior: UiEscapeBehavior,
+    reopen_last_view: bool,
     window_position_file: PathBuf,
+    keymap: Vec<KeymapBinding>,
 
     // ephemeral state
     prompt: String,
+    argument_prompt: Option<ArgumentPrompt>,
+    deep_link_confirmation: Option<DeepLinkConfirmation>,
+    last_plugin_view: Option<PluginViewData>,
+    saved_search_position: Option<SavedSearchPosition>,
+    // query for which currently shown search results were returned
+    search_results_query: String,
+    shortcut_hints: bool,
+    // kept between window openings until toggled again
+    preview_pane: bool,
+    result_preview: Option<ResultPreview>,
+    quicklook: Option<Quicklook>,
+    // space typed right after moving through results with arrow keys opens quicklook instead of being typed into search field
+    navigated_results: bool,
+    command_palette: Option<CommandPalette>,
+    // what was last described to screen readers, tree is not sent again if nothing changed
+    accessible_view: Option<AccessibleView>,
+    // views opened from command palette are stacked on top of the ones that were open before
+    suspended_plugin_views: Vec<PluginViewData>,
 
     // state
     client_context: ClientContext,
     global_state: GlobalState,
+    // search results without items of collapsed plugin groups and items over per plugin limit, focus index points into this list
     search_results: Vec<SearchResult>,
+    all_search_results: Vec<SearchResult>,
+    recent_search_results_count: usize,
+    // nothing matched the query, so results are fallback actions which receive the query
+    fallback_search_results: bool,
+    collapsed_plugin_groups: HashSet<PluginId>,
     loading_bar_state: HashMap<(PluginId, EntrypointId), ()>,
-    hud_display: Option<String>
+    backend_reconnecting: bool,
+    plugins_paused: bool,
+    offline_mode: bool,
+    focus_mode: bool,
+    undo_disable_entrypoint: Option<DisabledEntrypoint>,
+    debug_hud: Option<DebugHud>,
+}
+
+// position in main list saved when plugin view is opened,
+// restored when main view is shown again with the same query and the same results
+struct SavedSearchPosition {
+    query: String,
+    results: Vec<(PluginId, EntrypointId)>,
+    focused_search_result: ScrollHandle,
 }
 
 #[cfg(target_os = "linux")]
@@ -112,12 +212,41 @@ pub enum AppMsg {
     RunCommand {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
+        arguments: HashMap<String, String>,
+    },
+    ArgumentPromptChanged {
+        id: String,
+        value: String,
+    },
+    ArgumentPromptSubmit,
+    ArgumentPromptPickFile {
+        id: String,
+    },
+    ArgumentPromptFilePicked {
+        id: String,
+        path: Option<PathBuf>,
     },
     RunGeneratedEntrypoint {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
         action_index: usize
     },
+    OpenViewFromDeepLink {
+        plugin_id: PluginId,
+        plugin_name: String,
+        entrypoint_id: EntrypointId,
+        entrypoint_name: String,
+    },
+    ShowWindowWithSearchQuery {
+        query: String,
+    },
+    ConfirmDeepLink {
+        uri: String,
+        plugin_name: String,
+        entrypoint_name: String,
+    },
+    DeepLinkConfirmed,
+    DeepLinkCancelled,
     RunSearchItemAction(SearchResult, usize),
     RunPluginAction {
         render_location: UiRenderLocation,
@@ -128,7 +257,13 @@ pub enum AppMsg {
     PromptChanged(String),
     PromptSubmit,
     UpdateSearchResults,
-    SetSearchResults(Vec<SearchResult>),
+    SetSearchResults {
+        query: String,
+        results: Vec<SearchResult>,
+        recent_count: usize,
+        fallback: bool,
+        duration: Duration,
+    },
     RenderPluginUI {
         plugin_id: PluginId,
         plugin_name: String,
@@ -136,9 +271,12 @@ pub enum AppMsg {
         entrypoint_name: String,
         render_location: UiRenderLocation,
         top_level_view: bool,
+        breadcrumbs: Vec<String>,
+        key_handlers: Vec<PhysicalShortcut>,
         container: Arc<RootWidget>,
-        images: HashMap<UiWidgetId, Vec<u8>>,
+        images: HashMap<UiWidgetId, Handle>,
     },
+    RestorePluginView,
     HandleRenderPluginUI {
         top_level_view: bool,
         has_children: bool,
@@ -153,15 +291,22 @@ pub enum AppMsg {
     Noop,
     FontLoaded(Result<(), font::Error>),
     ShowWindow,
+    MainShortcutPressed,
     HideWindow,
+    ToggleWindow,
     ToggleActionPanel {
         keyboard: bool
     },
-    ShowPreferenceRequiredView {
+    ShowOnboardingView {
+        plugin_id: PluginId,
+        plugin_name: String,
+        entrypoint_id: EntrypointId,
+        description: Option<String>,
+        preferences: Vec<OnboardingPreference>,
+    },
+    CompletePluginOnboarding {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
-        plugin_preferences_required: bool,
-        entrypoint_preferences_required: bool
     },
     OpenSettingsPreferences {
         plugin_id: PluginId,
@@ -183,7 +328,52 @@ pub enum AppMsg {
         screenshot: Screenshot
     },
     ShowBackendError(BackendForFrontendApiError),
+    BackendReconnected,
+    TrayEvent(TrayEvent),
+    PluginsPausedChanged(bool),
+    OfflineModeChanged(bool),
+    FocusModeChanged(bool),
+    EntrypointDisabled(DisabledEntrypoint),
+    UndoDisableEntrypoint,
+    HideUndoDisableEntrypointToast(DisabledEntrypoint),
+    ToggleDebugHud,
+    ToggleShortcutHints,
+    TogglePin,
+    TogglePreviewPane,
+    SetResultPreview {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        preview: Option<SearchResultPreview>,
+    },
+    CloseQuicklook,
+    SetQuicklook {
+        path: String,
+        quicklook: Option<FileQuicklook>,
+    },
+    DetachPluginView,
+    WindowClosed(window::Id),
+    ShowSettingsWindow {
+        plugin_id: Option<PluginId>,
+        entrypoint_id: Option<EntrypointId>,
+    },
+    SettingsWindow(SettingsWindowMsg),
+    DebugHudFrame(Instant),
     ClosePluginView(PluginId),
+    OpenCommandPalette,
+    CloseCommandPalette,
+    CommandPaletteQueryChanged(String),
+    SetCommandPaletteResults {
+        query: String,
+        results: Vec<SearchResult>,
+    },
+    CommandPaletteFocus {
+        index: usize,
+    },
+    CommandPaletteSubmit,
+    RunCommandPaletteResult {
+        search_result: SearchResult,
+    },
+    PopPluginView(PluginId),
     OpenPluginView(PluginId, EntrypointId),
     InlineViewShortcuts {
         shortcuts: HashMap<PluginId, HashMap<String, PhysicalShortcut>>
@@ -193,6 +383,10 @@ pub enum AppMsg {
     },
     OnPrimaryActionMainViewNoPanelKeyboardWithoutFocus,
     OnPrimaryActionMainViewNoPanel { search_result: SearchResult },
+    OnHoverSearchResult { index: usize },
+    OnHoverShowAllRow { index: usize },
+    OnRightClickSearchResult { index: usize },
+    OnScrollSearchList { scroll_position: f32 },
     OnSecondaryActionMainViewNoPanelKeyboardWithFocus { search_result: SearchResult },
     OnSecondaryActionMainViewNoPanelKeyboardWithoutFocus,
     OnAnyActionMainViewSearchResultPanelKeyboardWithFocus { search_result: SearchResult, widget_id: UiWidgetId },
@@ -218,6 +412,13 @@ pub enum AppMsg {
     FocusPluginViewSearchBar {
         widget_id: UiWidgetId
     },
+    SelectPluginViewTab {
+        tab_id: String
+    },
+    StartDrag {
+        window_id: window::Id,
+        payload: DragPayload
+    },
     #[cfg(target_os = "linux")]
     LayerShell(layer_shell::LayerShellAppMsg),
     ClearInlineView {
@@ -229,6 +430,36 @@ pub enum AppMsg {
     SetWindowPositionMode {
         mode: WindowPositionMode
     },
+    SetWindowOverlay {
+        enabled: bool
+    },
+    SetDensity {
+        density: Density
+    },
+    SetUiScale {
+        scale: UiScale
+    },
+    MainWindowMonitorDetected {
+        monitor_size: Option<Size>,
+        system_scale_factor: f32,
+    },
+    RendererDetected {
+        renderer: UiRendererInfo
+    },
+    SetSearchResultsSort {
+        sort: SearchResultsSort
+    },
+    SetTrayTooltip {
+        text: Option<String>
+    },
+    TogglePluginGroup {
+        plugin_id: PluginId
+    },
+    ShowAllPluginResults {
+        plugin_id: PluginId,
+        plugin_name: String,
+    },
+    ExitPluginScope,
 }
 
 #[cfg(target_os = "linux")]
@@ -244,6 +475,7 @@ impl TryInto<iced_layershell::actions::LayershellCustomActionsWithId> for AppMsg
 
 const WINDOW_WIDTH: f32 = 750.0;
 const WINDOW_HEIGHT: f32 = 450.0;
+const UNDO_TOAST_DURATION: std::time::Duration = std::time::Duration::from_secs(5);
 
 #[cfg(not(target_os = "macos"))]
 fn window_settings(visible: bool, position: Position) -> window::Settings {
@@ -283,6 +515,42 @@ fn window_settings(visible: bool, position: Position) -> window::Settings {
 }
 
 
+// regular window which user can move, resize and close
+fn detached_view_window_settings() -> window::Settings {
+    window::Settings {
+        size: Size::new(WINDOW_WIDTH, WINDOW_HEIGHT),
+        resizable: true,
+        decorations: true,
+        exit_on_close_request: false,
+        ..Default::default()
+    }
+}
+
+fn settings_window_settings() -> window::Settings {
+    window::Settings {
+        size: SETTINGS_WINDOW_SIZE,
+        exit_on_close_request: false,
+        ..Default::default()
+    }
+}
+
+fn spawn_settings_process(settings_env_data: Option<SettingsEnvData>) {
+    let current_exe = std::env::current_exe()
+        .expect("unable to get current_exe");
+
+    let mut command = std::process::Command::new(current_exe);
+
+    command.args(["settings"]);
+
+    if let Some(settings_env_data) = settings_env_data {
+        command.env("GAUNTLET_INTERNAL_SETTINGS", settings_env_data_to_string(settings_env_data));
+    }
+
+    if let Err(err) = command.spawn() {
+        tracing::error!("Unable to start settings process: {:?}", err)
+    }
+}
+
 #[cfg(target_os = "linux")]
 fn layer_shell_settings() -> iced_layershell::reexport::NewLayerShellSettings {
     iced_layershell::reexport::NewLayerShellSettings {
@@ -334,6 +602,14 @@ pub fn run(
     frontend_receiver: RequestReceiver<UiRequestData, UiResponseData>,
     backend_sender: RequestSender<BackendRequestData, BackendResponseData>,
 ) {
+    let mut backend_api = BackendForFrontendApi::new(backend_sender);
+
+    // requested before application is started, because text size can only be specified when it starts
+    let setup_data = futures::executor::block_on(backend_api.setup_data())
+        .expect("Unable to setup frontend");
+
+    configure_renderer(setup_data.renderer, &setup_data.wgpu_backend);
+
     #[cfg(target_os = "linux")]
     let result = {
         let wayland = std::env::var("WAYLAND_DISPLAY")
@@ -341,14 +617,14 @@ pub fn run(
             .is_ok();
 
         if wayland {
-            run_wayland(minimized, frontend_receiver, backend_sender)
+            run_wayland(minimized, frontend_receiver, backend_api, setup_data)
         } else {
-            run_non_wayland(minimized, frontend_receiver, backend_sender)
+            run_non_wayland(minimized, frontend_receiver, backend_api, setup_data)
         }
     };
 
     #[cfg(not(target_os = "linux"))]
-    let result = run_non_wayland(minimized, frontend_receiver, backend_sender);
+    let result = run_non_wayland(minimized, frontend_receiver, backend_api, setup_data);
 
     result.expect("Unable to start application")
 }
@@ -356,8 +632,13 @@ pub fn run(
 fn run_non_wayland(
     minimized: bool,
     frontend_receiver: RequestReceiver<UiRequestData, UiResponseData>,
-    backend_sender: RequestSender<BackendRequestData, BackendResponseData>,
+    backend_api: BackendForFrontendApi,
+    setup_data: UiSetupData,
 ) -> anyhow::Result<()> {
+    let text_size = setup_data.font.size.unwrap_or_else(|| density_text_size(setup_data.density));
+
+    fonts::init_fonts(&setup_data.font);
+
     iced::daemon::<AppModel, AppMsg, GauntletComplexTheme, Renderer>(title, update, view)
         .settings(Settings {
             #[cfg(target_os = "macos")]
@@ -365,11 +646,14 @@ fn run_non_wayland(
                 activation_policy: iced::settings::ActivationPolicy::Accessory,
                 activate_ignoring_other_apps: true,
             },
+            default_font: fonts::default_font(),
+            default_text_size: Pixels(text_size),
             ..Default::default()
         })
         .subscription(subscription)
         .theme(|state, _| state.theme.clone())
-        .run_with(move || new(frontend_receiver, backend_sender, false, minimized))?;
+        .scale_factor(|state, _| state.ui_scale.scale_factor())
+        .run_with(move || new(frontend_receiver, backend_api, setup_data, text_size, false, minimized))?;
 
     Ok(())
 }
@@ -378,9 +662,16 @@ fn run_non_wayland(
 fn run_wayland(
     minimized: bool,
     frontend_receiver: RequestReceiver<UiRequestData, UiResponseData>,
-    backend_sender: RequestSender<BackendRequestData, BackendResponseData>,
+    backend_api: BackendForFrontendApi,
+    setup_data: UiSetupData,
 ) -> anyhow::Result<()> {
+    let text_size = setup_data.font.size.unwrap_or_else(|| density_text_size(setup_data.density));
+
+    fonts::init_fonts(&setup_data.font);
+
     iced_layershell::build_pattern::daemon("Gauntlet", update, view, wayland_remove_id_info)
+        .default_font(fonts::default_font())
+        .default_text_size(Pixels(text_size))
         .layer_settings(iced_layershell::settings::LayerShellSettings {
             start_mode: iced_layershell::settings::StartMode::Background,
             events_transparent: true,
@@ -390,7 +681,8 @@ fn run_wayland(
         })
         .subscription(subscription)
         .theme(|state| state.theme.clone())
-        .run_with(move || new(frontend_receiver, backend_sender, true, minimized))?;
+        .scale_factor(|state, _| state.ui_scale.scale_factor())
+        .run_with(move || new(frontend_receiver, backend_api, setup_data, text_size, true, minimized))?;
 
     Ok(())
 }
@@ -401,19 +693,18 @@ fn wayland_remove_id_info(_state: &mut AppModel, _id: window::Id) {
 
 fn new(
     frontend_receiver: RequestReceiver<UiRequestData, UiResponseData>,
-    backend_sender: RequestSender<BackendRequestData, BackendResponseData>,
+    mut backend_api: BackendForFrontendApi,
+    setup_data: UiSetupData,
+    text_size: f32,
     wayland: bool,
     minimized: bool,
 ) -> (AppModel, Task<AppMsg>) {
-    let mut backend_api = BackendForFrontendApi::new(backend_sender);
-
-    let setup_data = futures::executor::block_on(backend_api.setup_data())
-        .expect("Unable to setup frontend");
-
-    let theme = GauntletComplexTheme::new(setup_data.theme);
+    let theme = GauntletComplexTheme::new(setup_data.theme.clone(), setup_data.density, text_size);
 
     GauntletComplexTheme::set_global(theme.clone());
 
+    loading_bar::set_reduced_motion(setup_data.reduced_motion);
+
     let current_hotkey = Arc::new(StdMutex::new(None));
 
     let global_hotkey_manager = GlobalHotKeyManager::new()
@@ -428,6 +719,8 @@ fn new(
         font::load(BOOTSTRAP_FONT_BYTES).map(AppMsg::FontLoaded),
     ];
 
+    tasks.extend(fonts::load_font_files(&setup_data.font));
+
     #[cfg(target_os = "linux")]
     let (main_window_id, open_task) =  if wayland {
         let id = window::Id::unique();
@@ -446,11 +739,27 @@ fn new(
 
     tasks.push(open_task);
 
+    tasks.push(apply_window_overlay(main_window_id, setup_data.window_overlay));
+
+    tasks.push(detect_renderer());
+
+    if !wayland && !minimized {
+        tasks.push(detect_monitor(main_window_id));
+    }
+
+    let (tray_sender, tray_receiver) = tokio::sync::mpsc::unbounded_channel();
+
+    let tray = if setup_data.tray_icon && !cfg!(feature = "scenario_runner") {
+        Some(SysTray::new(tray_sender, setup_data.offline_mode))
+    } else {
+        None
+    };
+
     let global_state = if cfg!(feature = "scenario_runner") {
         let gen_in = std::env::var("GAUNTLET_SCREENSHOT_GEN_IN")
             .expect("Unable to read GAUNTLET_SCREENSHOT_GEN_IN");
 
-       println!("Reading scenario file at: {}", gen_in);
+        tracing::info!("Reading scenario file at: {}", gen_in);
 
         let gen_in = fs::read_to_string(gen_in)
             .expect("Unable to read file at GAUNTLET_SCREENSHOT_GEN_IN");
@@ -487,8 +796,12 @@ fn new(
                     entrypoint_name: "Screenshot Entrypoint".to_string(),
                     render_location,
                     top_level_view,
+                    breadcrumbs: vec![],
+                    key_handlers: vec![],
                     container: Arc::new(container),
-                    images
+                    images: images.into_iter()
+                        .map(|(widget_id, bytes)| (widget_id, decode_image(&bytes).expect("unable to decode screenshot image")))
+                        .collect()
                 };
 
                 tasks.push(Task::done(msg));
@@ -507,12 +820,22 @@ fn new(
                     )
                 }
             }
-            ScenarioFrontendEvent::ShowPreferenceRequiredView { entrypoint_id, plugin_preferences_required, entrypoint_preferences_required } => {
-                let error_view = ErrorViewData::PreferenceRequired {
+            ScenarioFrontendEvent::ShowOnboardingView { entrypoint_id, description, missing_preferences } => {
+                let preferences = missing_preferences.into_iter()
+                    .map(|name| OnboardingPreference {
+                        entrypoint_id: None,
+                        name,
+                        description: "".to_string(),
+                        filled: false,
+                    })
+                    .collect();
+
+                let error_view = ErrorViewData::Onboarding {
                     plugin_id: PluginId::from_string("__SCREENSHOT_GEN___"),
+                    plugin_name: "Screenshot Gen".to_string(),
                     entrypoint_id: EntrypointId::from_string(entrypoint_id),
-                    plugin_preferences_required,
-                    entrypoint_preferences_required,
+                    description,
+                    preferences,
                 };
 
                 GlobalState::new_error(error_view)
@@ -534,55 +857,107 @@ fn new(
         AppModel {
             // logic
             backend_api,
+            settings_backend_api: BackendApi::new_lazy(),
             global_hotkey_manager: Arc::new(StdRwLock::new(global_hotkey_manager)),
             current_hotkey,
             frontend_receiver: Arc::new(TokioRwLock::new(frontend_receiver)),
-            main_window_id,
+            image_cache: ImageCache::new(),
+            tray_receiver: Arc::new(TokioRwLock::new(tray_receiver)),
+            windows: AppWindows::new(main_window_id),
+            settings_window: None,
             focused: false,
             opened: !minimized,
             wayland,
-            #[cfg(any(target_os = "macos", target_os = "windows"))]
-            tray_icon: sys_tray::create_tray(),
+            tray,
             theme,
             window_position_mode: setup_data.window_position_mode,
+            window_overlay: setup_data.window_overlay,
+            ui_theme: setup_data.theme,
+            density: setup_data.density,
+            ui_scale: UiScaleState::new(setup_data.ui_scale, setup_data.monitor_ui_scale),
+            text_size,
+            search_results_sort: setup_data.search_results_sort,
+            results_per_plugin: setup_data.results_per_plugin,
             close_on_unfocus: setup_data.close_on_unfocus,
+            pinned: false,
+            escape_behavior: setup_data.escape_behavior,
+            reopen_last_view: setup_data.reopen_last_view,
             window_position_file: setup_data.window_position_file,
+            keymap: setup_data.keymap,
 
             // ephemeral state
             prompt: "".to_string(),
+            argument_prompt: None,
+            deep_link_confirmation: None,
+            last_plugin_view: None,
+            saved_search_position: None,
+            search_results_query: "".to_string(),
+            shortcut_hints: false,
+            preview_pane: false,
+            result_preview: None,
+            quicklook: None,
+            navigated_results: false,
+            command_palette: None,
+            accessible_view: None,
+            suspended_plugin_views: vec![],
 
             // state
             global_state,
-            client_context: ClientContext::new(),
+            client_context: ClientContext::new(setup_data.inline_view_order),
             search_results: vec![],
+            all_search_results: vec![],
+            recent_search_results_count: 0,
+            fallback_search_results: false,
+            collapsed_plugin_groups: HashSet::new(),
             loading_bar_state: HashMap::new(),
-            hud_display: None,
+            backend_reconnecting: false,
+            plugins_paused: false,
+            offline_mode: setup_data.offline_mode,
+            focus_mode: false,
+            undo_disable_entrypoint: None,
+            debug_hud: None,
         },
         Task::batch(tasks),
     )
 }
 
 fn title(state: &AppModel, window: window::Id) -> String {
-    if window == state.main_window_id {
-        "Gauntlet".to_owned()
-    } else {
-        "Gauntlet HUD".to_owned()
+    match state.windows.get(window) {
+        Some(AppWindow::DetachedPluginView) => {
+            match state.client_context.get_detached_view_container(window) {
+                Some(container) => container.get_entrypoint_name(),
+                None => "Gauntlet".to_owned(),
+            }
+        }
+        Some(AppWindow::Hud { .. }) => "Gauntlet HUD".to_owned(),
+        Some(AppWindow::Settings) => "Gauntlet Settings".to_owned(),
+        Some(AppWindow::Main) | None => "Gauntlet".to_owned(),
     }
 }
 
+// focused search result is changed by many messages, so preview, quicklook and accessibility tree are updated after any of them
 fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
+    let task = update_state(state, message);
+
+    Task::batch([task, state.request_result_preview(), state.follow_quicklook(), state.update_accessibility_tree()])
+}
+
+fn update_state(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
     match message {
         AppMsg::OpenView { plugin_id, plugin_name, entrypoint_id, entrypoint_name } => {
             match &mut state.global_state {
                 GlobalState::MainView { pending_plugin_view_data, .. } => {
-                    *pending_plugin_view_data = Some(PluginViewData {
+                    let plugin_view_data = PluginViewData {
                         top_level_view: true,
                         plugin_id: plugin_id.clone(),
                         plugin_name,
                         entrypoint_id: entrypoint_id.clone(),
                         entrypoint_name,
                         action_shortcuts: HashMap::new(),
-                    });
+                    };
+
+                    state.last_plugin_view = Some(plugin_view_data.clone());
+                    *pending_plugin_view_data = Some(plugin_view_data);
 
                     Task::batch([
                         state.open_plugin_view(plugin_id, entrypoint_id),
@@ -598,6 +973,9 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             }
         }
         AppMsg::OpenGeneratedView { plugin_id, plugin_name, entrypoint_id, entrypoint_name, action_index } => {
+            // generated entrypoints can't be reopened without running their action
+            state.last_plugin_view = None;
+
             match &mut state.global_state {
                 GlobalState::MainView { pending_plugin_view_data, .. } => {
                     *pending_plugin_view_data = Some(PluginViewData {
@@ -622,12 +1000,85 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                 }
             }
         }
-        AppMsg::RunCommand { plugin_id, entrypoint_id } => {
+        AppMsg::OpenViewFromDeepLink { plugin_id, plugin_name, entrypoint_id, entrypoint_name } => {
+            Task::batch([
+                state.show_window(false),
+                Task::done(AppMsg::OpenView {
+                    plugin_id,
+                    plugin_name,
+                    entrypoint_id,
+                    entrypoint_name,
+                })
+            ])
+        }
+        AppMsg::ShowWindowWithSearchQuery { query } => {
+            Task::batch([
+                state.show_window(false),
+                Task::done(AppMsg::PromptChanged(query)),
+            ])
+        }
+        AppMsg::ConfirmDeepLink { uri, plugin_name, entrypoint_name } => {
+            // opening window resets its state, so confirmation is set after it
+            let show_window = state.show_window(false);
+
+            state.deep_link_confirmation = Some(DeepLinkConfirmation::new(uri, plugin_name, entrypoint_name));
+
+            show_window
+        }
+        AppMsg::DeepLinkConfirmed => {
+            match state.deep_link_confirmation.take() {
+                Some(confirmation) => state.run_confirmed_deep_link(confirmation.uri),
+                None => Task::none(),
+            }
+        }
+        AppMsg::DeepLinkCancelled => {
+            state.deep_link_confirmation = None;
+
+            Task::none()
+        }
+        AppMsg::RunCommand { plugin_id, entrypoint_id, arguments } => {
             Task::batch([
                 state.hide_window(),
-                state.run_command(plugin_id, entrypoint_id),
+                state.run_command(plugin_id, entrypoint_id, arguments),
             ])
         }
+        AppMsg::ArgumentPromptChanged { id, value } => {
+            if let Some(argument_prompt) = &mut state.argument_prompt {
+                argument_prompt.set_value(id, value);
+            }
+
+            Task::none()
+        }
+        AppMsg::ArgumentPromptPickFile { id } => {
+            match &mut state.argument_prompt {
+                Some(argument_prompt) => argument_prompt.pick_file(id),
+                None => Task::none(),
+            }
+        }
+        AppMsg::ArgumentPromptFilePicked { id, path } => {
+            if let Some(argument_prompt) = &mut state.argument_prompt {
+                argument_prompt.file_picked(id, path);
+            }
+
+            Task::none()
+        }
+        AppMsg::ArgumentPromptSubmit => {
+            let Some(argument_prompt) = &mut state.argument_prompt else {
+                return Task::none()
+            };
+
+            match argument_prompt.submit() {
+                None => Task::none(),
+                Some(arguments) => {
+                    let plugin_id = argument_prompt.plugin_id.clone();
+                    let entrypoint_id = argument_prompt.entrypoint_id.clone();
+
+                    state.argument_prompt = None;
+
+                    Task::done(AppMsg::RunCommand { plugin_id, entrypoint_id, arguments })
+                }
+            }
+        }
         AppMsg::RunGeneratedEntrypoint { plugin_id, entrypoint_id, action_index } => {
             Task::batch([
                 state.hide_window(),
@@ -653,13 +1104,46 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             }
         }
         AppMsg::RunSearchItemAction(search_result, action_index) => {
+            if let Some(builtin_action) = builtin_action_at(&search_result, action_index) {
+                if let BuiltinSearchResultAction::DisableEntrypoint = builtin_action {
+                    return state.disable_entrypoint(&search_result)
+                }
+
+                if let Some(text) = builtin_action.clipboard_text(&search_result) {
+                    return Task::batch([
+                        iced::clipboard::write(text),
+                        state.hide_window(),
+                        Task::done(AppMsg::ShowHud { display: tr!("hud-copied-to-clipboard") }),
+                    ])
+                }
+
+                return Task::none()
+            }
+
             match search_result.entrypoint_type {
                 SearchResultEntrypointType::Command => {
                     if action_index == 0 {
-                        Task::done(AppMsg::RunCommand {
-                            entrypoint_id: search_result.entrypoint_id.clone(),
-                            plugin_id: search_result.plugin_id.clone(),
-                        })
+                        if search_result.entrypoint_arguments.is_empty() {
+                            Task::done(AppMsg::RunCommand {
+                                entrypoint_id: search_result.entrypoint_id.clone(),
+                                plugin_id: search_result.plugin_id.clone(),
+                                arguments: HashMap::new(),
+                            })
+                        } else {
+                            let prompt_shown = state.argument_prompt
+                                .as_ref()
+                                .is_some_and(|prompt| prompt.plugin_id == search_result.plugin_id && prompt.entrypoint_id == search_result.entrypoint_id);
+
+                            if prompt_shown {
+                                Task::done(AppMsg::ArgumentPromptSubmit)
+                            } else {
+                                let (argument_prompt, focus_task) = ArgumentPrompt::new(&search_result);
+
+                                state.argument_prompt = Some(argument_prompt);
+
+                                focus_task
+                            }
+                        }
                     } else {
                         Task::none()
                     }
@@ -702,12 +1186,25 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
         AppMsg::PromptChanged(mut new_prompt) => {
             if cfg!(feature = "scenario_runner") {
                 Task::none()
+            } else if state.quicklook.is_some() && new_prompt == format!("{} ", state.prompt) {
+                // space bar closes quicklook, focused result stays where it was
+                state.quicklook = None;
+
+                Task::none()
+            } else if state.navigated_results && new_prompt == format!("{} ", state.prompt) && state.focused_search_result_file().is_some() {
+                state.open_quicklook()
+            } else if state.prompt.is_empty() && new_prompt == "?" {
+                // search field is focused most of the time, so "?" typed into empty search field shows shortcut hints
+                Task::done(AppMsg::ToggleShortcutHints)
             } else {
                 match &mut state.global_state {
                     GlobalState::MainView { focused_search_result, sub_state, ..} => {
                         new_prompt.truncate(100); // search query uses regex so just to be safe truncate the prompt
 
                         state.prompt = new_prompt.clone();
+                        state.navigated_results = false;
+                        state.quicklook = None;
+                        state.argument_prompt = None;
 
                         focused_search_result.reset(true);
 
@@ -731,10 +1228,66 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
         AppMsg::PromptSubmit => {
             state.global_state.primary(&state.client_context, &state.search_results)
         },
-        AppMsg::SetSearchResults(new_search_results) => {
-            state.search_results = new_search_results;
+        AppMsg::SetSearchResults { query, results, recent_count, fallback, duration } => {
+            if let Some(debug_hud) = &mut state.debug_hud {
+                debug_hud.search(duration);
+            }
 
-            Task::none()
+            let same_query = state.search_results_query == query;
+
+            state.search_results_query = query;
+
+            let prev_focused = match &state.global_state {
+                GlobalState::MainView { focused_search_result, .. } if same_query => {
+                    focused_search_result.get(&state.search_results)
+                        .map(|search_result| (search_result.plugin_id.clone(), search_result.entrypoint_id.clone()))
+                }
+                _ => None
+            };
+
+            state.all_search_results = results;
+            state.recent_search_results_count = recent_count;
+            state.fallback_search_results = fallback;
+
+            state.update_visible_search_results();
+
+            match &mut state.global_state {
+                GlobalState::MainView { focused_search_result, .. } => {
+                    let saved_search_position = state.saved_search_position
+                        .take()
+                        .filter(|saved| saved.query == state.prompt && saved.results == search_result_keys(&state.search_results));
+
+                    match saved_search_position {
+                        Some(saved) => {
+                            *focused_search_result = saved.focused_search_result;
+
+                            focused_search_result.restore_scroll()
+                        }
+                        None if !same_query => {
+                            focused_search_result.reset(true);
+
+                            Task::none()
+                        }
+                        None => {
+                            // results are refreshed while main view is open, keep focus on the same item if it is still present
+                            if let Some((plugin_id, entrypoint_id)) = prev_focused {
+                                let new_index = state.search_results
+                                    .iter()
+                                    .position(|search_result| search_result.plugin_id == plugin_id && search_result.entrypoint_id == entrypoint_id);
+
+                                if let Some(new_index) = new_index {
+                                    if focused_search_result.index != Some(new_index) {
+                                        focused_search_result.focus_index(new_index);
+                                    }
+                                }
+                            }
+
+                            Task::none()
+                        }
+                    }
+                }
+                _ => Task::none()
+            }
         }
         AppMsg::RenderPluginUI {
             plugin_id,
@@ -743,12 +1296,66 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             entrypoint_name,
             render_location,
             top_level_view,
+            breadcrumbs,
+            key_handlers,
             container,
             images
         } => {
             let has_children = container.content.is_some();
 
+            let mut close_detached_window = Task::none();
+
+            if let UiRenderLocation::View = render_location {
+                if let Some(window_id) = state.client_context.get_detached_view_window(&plugin_id) {
+                    if state.is_plugin_view_in_main_window(&plugin_id) {
+                        // plugin has only one view, so it moves back to main window when it is opened there again
+                        state.client_context.remove_detached_view(window_id);
+                        state.windows.remove(window_id);
+
+                        close_detached_window = window::close(window_id);
+                    } else {
+                        // main window state is not affected by view rendered in separate window
+                        return Task::done(state.client_context.render_ui(
+                            render_location,
+                            container,
+                            images,
+                            &plugin_id,
+                            &plugin_name,
+                            &entrypoint_id,
+                            &entrypoint_name,
+                            breadcrumbs,
+                            key_handlers,
+                        ))
+                    }
+                }
+            }
+
+            if let UiRenderLocation::View = render_location {
+                if state.client_context.is_view_suspended(&plugin_id) {
+                    // main window state is not affected by view which is hidden under the one opened from command palette
+                    return Task::done(state.client_context.render_ui(
+                        render_location,
+                        container,
+                        images,
+                        &plugin_id,
+                        &plugin_name,
+                        &entrypoint_id,
+                        &entrypoint_name,
+                        breadcrumbs,
+                        key_handlers,
+                    ))
+                }
+
+                if let GlobalState::PluginView { plugin_view_data, .. } = &state.global_state {
+                    if plugin_view_data.plugin_id != plugin_id {
+                        // late render of view which was already closed, e.g. the one opened from command palette
+                        return Task::none()
+                    }
+                }
+            }
+
             Task::batch([
+                close_detached_window,
                 Task::done(state.client_context.render_ui(
                     render_location,
                     container,
@@ -757,6 +1364,8 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                     &plugin_name,
                     &entrypoint_id,
                     &entrypoint_name,
+                    breadcrumbs,
+                    key_handlers,
                 )),
                 Task::done(AppMsg::HandleRenderPluginUI {
                     top_level_view,
@@ -787,6 +1396,13 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                         None => Task::none(),
                         Some(pending_plugin_view_data) => {
                             let pending_plugin_view_data = pending_plugin_view_data.clone();
+
+                            state.saved_search_position = Some(SavedSearchPosition {
+                                query: state.prompt.clone(),
+                                results: search_result_keys(&state.search_results),
+                                focused_search_result: focused_search_result.clone(),
+                            });
+
                             GlobalState::plugin(
                                 &mut state.global_state,
                                 PluginViewData {
@@ -815,21 +1431,125 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             }
         }
         AppMsg::IcedEvent(window_id, Event::Keyboard(event)) => {
-            if window_id != state.main_window_id {
-                return Task::none()
+            match state.windows.get(window_id) {
+                Some(AppWindow::Main) => {}
+                Some(AppWindow::DetachedPluginView) => {
+                    let Some(container) = state.client_context.get_detached_view_container(window_id) else {
+                        return Task::none()
+                    };
+
+                    // only navigation back is handled in separate window, everything else is done with mouse
+                    return match event {
+                        keyboard::Event::KeyPressed { key: Key::Named(Named::Escape), .. } if container.get_view_depth() > 1 => {
+                            Task::done(AppMsg::PopPluginView(container.get_plugin_id()))
+                        }
+                        _ => Task::none()
+                    }
+                }
+                Some(AppWindow::Hud { .. }) | Some(AppWindow::Settings) | None => return Task::none(),
             }
 
             match event {
                 keyboard::Event::KeyPressed { key, modifiers, physical_key, text, .. } => {
                     tracing::debug!("Key pressed: {:?}. shift: {:?} control: {:?} alt: {:?} meta: {:?}", key, modifiers.shift(), modifiers.control(), modifiers.alt(), modifiers.logo());
+
+                    if state.undo_disable_entrypoint.is_some() && state.is_undo_shortcut(physical_key, modifiers) {
+                        return Task::done(AppMsg::UndoDisableEntrypoint)
+                    }
+
+                    if state.is_debug_hud_shortcut(physical_key, modifiers) {
+                        return Task::done(AppMsg::ToggleDebugHud)
+                    }
+
+                    if state.is_pin_shortcut(physical_key, modifiers) {
+                        return Task::done(AppMsg::TogglePin)
+                    }
+
+                    if let GlobalState::MainView { .. } = state.global_state {
+                        if state.is_toggle_preview_shortcut(physical_key, modifiers) {
+                            return Task::done(AppMsg::TogglePreviewPane)
+                        }
+                    }
+
+                    if let GlobalState::PluginView { .. } = state.global_state {
+                        if state.is_detach_view_shortcut(physical_key, modifiers) {
+                            return Task::done(AppMsg::DetachPluginView)
+                        }
+                    }
+
+                    if state.quicklook.is_some() {
+                        if let Key::Named(Named::Escape | Named::Space) = key {
+                            return Task::done(AppMsg::CloseQuicklook)
+                        }
+                    }
+
+                    // deep link confirmation is modal, it has to be either confirmed or cancelled
+                    if state.deep_link_confirmation.is_some() {
+                        return match key {
+                            Key::Named(Named::Enter) => Task::done(AppMsg::DeepLinkConfirmed),
+                            Key::Named(Named::Escape) => Task::done(AppMsg::DeepLinkCancelled),
+                            _ => Task::none(),
+                        }
+                    }
+
+                    // command palette is modal, text is typed into its search field
+                    if let Some(command_palette) = &mut state.command_palette {
+                        return match key {
+                            Key::Named(Named::ArrowUp) => command_palette.focused_result.focus_previous().unwrap_or_else(Task::none),
+                            Key::Named(Named::ArrowDown) => {
+                                let total = command_palette.results.len();
+
+                                command_palette.focused_result.focus_next(total).unwrap_or_else(Task::none)
+                            },
+                            Key::Named(Named::Escape) => state.back(),
+                            _ => Task::none(),
+                        }
+                    }
+
+                    // shortcut hints are modal, any key closes them
+                    if state.shortcut_hints {
+                        return match key {
+                            Key::Named(Named::Shift | Named::Control | Named::Alt | Named::Super) => Task::none(),
+                            _ => Task::done(AppMsg::ToggleShortcutHints),
+                        }
+                    }
+
+                    // only received when no text field is focused, otherwise "?" is typed into it
+                    if text.as_deref() == Some("?") && !modifiers.control() && !modifiers.alt() && !modifiers.logo() {
+                        return Task::done(AppMsg::ToggleShortcutHints)
+                    }
+
+                    if !state.is_typing_into_text_field(text.as_deref(), modifiers) {
+                        if let Some(action) = state.find_keymap_action(physical_key, modifiers) {
+                            return state.run_keymap_action(action)
+                        }
+                    }
+
+                    // key handlers registered by plugin view take precedence over focus navigation and typing into search bar
+                    if let GlobalState::PluginView { sub_state: PluginViewState::None, .. } = &state.global_state {
+                        if let Physical::Code(code) = physical_key {
+                            if let Some(shortcut) = physical_key_model(code, modifiers) {
+                                if state.client_context.view_has_key_handler(&shortcut) {
+                                    let PhysicalShortcut { physical_key, modifier_shift, modifier_control, modifier_alt, modifier_meta } = shortcut;
+
+                                    return state.handle_plugin_view_keyboard_event(physical_key, modifier_shift, modifier_control, modifier_alt, modifier_meta)
+                                }
+                            }
+                        }
+                    }
+
+                    if let (GlobalState::MainView { .. }, Key::Named(Named::ArrowUp | Named::ArrowDown)) = (&state.global_state, &key) {
+                        state.navigated_results = true;
+                    }
+
                     match key {
                         Key::Named(Named::ArrowUp) => state.global_state.up(&mut state.client_context, &state.search_results),
                         Key::Named(Named::ArrowDown) => state.global_state.down(&mut state.client_context, &state.search_results),
                         Key::Named(Named::ArrowLeft) => state.global_state.left(&mut state.client_context, &state.search_results),
                         Key::Named(Named::ArrowRight) => state.global_state.right(&mut state.client_context, &state.search_results),
-                        Key::Named(Named::Escape) => state.global_state.back(&state.client_context),
-                        Key::Named(Named::Tab) if !modifiers.shift() => state.global_state.next(&state.client_context),
-                        Key::Named(Named::Tab) if modifiers.shift() => state.global_state.previous(&state.client_context),
+                        Key::Named(Named::Escape) => state.back(),
+                        Key::Named(Named::Tab) if !modifiers.shift() => state.global_state.next(&mut state.client_context),
+                        Key::Named(Named::Tab) if modifiers.shift() => state.global_state.previous(&mut state.client_context),
                         Key::Named(Named::Enter) => {
                             if modifiers.logo() || modifiers.alt() || modifiers.control() {
                                 Task::none() // to avoid not wanted "enter" presses
@@ -842,6 +1562,9 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                                 }
                             }
                         },
+                        // dead keys and keys consumed by input method during composition don't produce text by themselves,
+                        // composed text arrives with following key press, so they are not treated as shortcuts
+                        Key::Unidentified => Task::none(),
                         Key::Named(Named::Backspace) => {
                             match &mut state.global_state {
                                 GlobalState::MainView { sub_state, search_field_id, .. } => {
@@ -968,6 +1691,15 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                                             Task::perform(async {}, |_| AppMsg::ToggleActionPanel { keyboard: true })
                                         }
                                         Some(PhysicalShortcut { physical_key, modifier_shift, modifier_control, modifier_alt, modifier_meta }) => {
+                                            if modifier_control && !modifier_shift && !modifier_alt && !modifier_meta {
+                                                let select_tab = tab_index(&physical_key)
+                                                    .and_then(|index| state.client_context.select_tab_by_index(index));
+
+                                                if let Some(task) = select_tab {
+                                                    return task
+                                                }
+                                            }
+
                                             if modifier_shift || modifier_control || modifier_alt || modifier_meta {
                                                 state.handle_plugin_view_keyboard_event(physical_key, modifier_shift, modifier_control, modifier_alt, modifier_meta)
                                             } else {
@@ -995,22 +1727,34 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             }
         }
         AppMsg::IcedEvent(window_id, Event::Window(window::Event::Focused)) => {
-            if !state.close_on_unfocus {
+            if window_id == state.windows.main_window_id() {
+                accessibility::update_window_focus(true);
+            }
+
+            if !state.close_on_unfocus || state.pinned {
                 return Task::none()
             }
 
-            if window_id != state.main_window_id {
+            if window_id != state.windows.main_window_id() {
                 return Task::none()
             }
 
             state.on_focused()
         }
         AppMsg::IcedEvent(window_id, Event::Window(window::Event::Unfocused)) => {
-            if !state.close_on_unfocus {
+            if window_id == state.windows.main_window_id() {
+                accessibility::update_window_focus(false);
+            }
+
+            if !state.close_on_unfocus || state.pinned {
+                return Task::none()
+            }
+
+            if state.argument_prompt.as_ref().is_some_and(|argument_prompt| argument_prompt.is_picking_file()) {
                 return Task::none()
             }
 
-            if window_id != state.main_window_id {
+            if window_id != state.windows.main_window_id() {
                 return Task::none()
             }
 
@@ -1021,7 +1765,7 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             }
         }
         AppMsg::IcedEvent(window_id, Event::Window(window::Event::Moved(point))) => {
-            if window_id != state.main_window_id {
+            if window_id != state.windows.main_window_id() {
                 return Task::none()
             }
 
@@ -1030,6 +1774,30 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
 
             Task::none()
         }
+        AppMsg::IcedEvent(window_id, Event::Window(window::Event::CloseRequested)) => {
+            match state.windows.get(window_id) {
+                Some(AppWindow::DetachedPluginView) => {}
+                Some(AppWindow::Settings) => {
+                    state.windows.remove(window_id);
+                    state.settings_window = None;
+
+                    return window::close(window_id)
+                }
+                Some(AppWindow::Main) | Some(AppWindow::Hud { .. }) | None => return Task::none(),
+            }
+
+            state.windows.remove(window_id);
+
+            match state.client_context.remove_detached_view(window_id) {
+                Some(plugin_id) => {
+                    Task::batch([
+                        state.close_plugin_view(plugin_id),
+                        window::close(window_id),
+                    ])
+                }
+                None => window::close(window_id)
+            }
+        }
         AppMsg::IcedEvent(_, _) => Task::none(),
         AppMsg::WidgetEvent { widget_event: ComponentWidgetEvent::Noop, .. } => Task::none(),
         AppMsg::WidgetEvent { widget_event: ComponentWidgetEvent::PreviousView, .. } => state.global_state.back(&state.client_context),
@@ -1041,24 +1809,48 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             result.expect("unable to load font");
             Task::none()
         }
-        AppMsg::ShowWindow => state.show_window(),
+        AppMsg::ShowWindow => state.show_window(true),
+        AppMsg::MainShortcutPressed => {
+            // while plugin view is open, main shortcut opens command palette on top of it instead
+            match &state.global_state {
+                GlobalState::PluginView { .. } if state.opened => Task::done(AppMsg::OpenCommandPalette),
+                _ => Task::done(AppMsg::ShowWindow),
+            }
+        }
         AppMsg::HideWindow => state.hide_window(),
-        AppMsg::ShowPreferenceRequiredView {
+        AppMsg::ToggleWindow => {
+            if state.opened {
+                state.hide_window()
+            } else {
+                Task::done(AppMsg::ShowWindow)
+            }
+        }
+        AppMsg::ShowOnboardingView {
             plugin_id,
+            plugin_name,
             entrypoint_id,
-            plugin_preferences_required,
-            entrypoint_preferences_required
+            description,
+            preferences
         } => {
             GlobalState::error(
                 &mut state.global_state,
-                ErrorViewData::PreferenceRequired {
+                ErrorViewData::Onboarding {
                     plugin_id,
+                    plugin_name,
                     entrypoint_id,
-                    plugin_preferences_required,
-                    entrypoint_preferences_required,
+                    description,
+                    preferences,
                 },
             )
         }
+        AppMsg::CompletePluginOnboarding { plugin_id, entrypoint_id } => {
+            // main view is restored before entrypoint is run again,
+            // so that opened plugin view or another onboarding view is not replaced by it
+            Task::batch([
+                GlobalState::initial(&mut state.global_state),
+                state.complete_plugin_onboarding(plugin_id, entrypoint_id),
+            ])
+        }
         AppMsg::ShowPluginErrorView { plugin_id, entrypoint_id, .. } => {
             GlobalState::error(
                 &mut state.global_state,
@@ -1069,13 +1861,182 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             )
         }
         AppMsg::ShowBackendError(err) => {
-            GlobalState::error(
-                &mut state.global_state,
-                match err {
-                    BackendForFrontendApiError::TimeoutError => ErrorViewData::BackendTimeout,
-                    BackendForFrontendApiError::Internal { display } => ErrorViewData::UnknownError { display }
+            match err {
+                BackendForFrontendApiError::TimeoutError | BackendForFrontendApiError::Disconnected => {
+                    state.reconnect_to_backend()
                 }
-            )
+                BackendForFrontendApiError::Internal { display } => {
+                    GlobalState::error(
+                        &mut state.global_state,
+                        ErrorViewData::UnknownError { display }
+                    )
+                }
+            }
+        }
+        AppMsg::BackendReconnected => {
+            state.backend_reconnecting = false;
+            state.update_tray_status();
+
+            // resume whatever was shown before connection was lost
+            match &state.global_state {
+                GlobalState::MainView { .. } => state.search(state.prompt.clone(), true),
+                GlobalState::PluginView { plugin_view_data, .. } => {
+                    state.open_plugin_view(plugin_view_data.plugin_id.clone(), plugin_view_data.entrypoint_id.clone())
+                }
+                GlobalState::ErrorView { .. } => Task::none(),
+            }
+        }
+        AppMsg::TrayEvent(event) => {
+            match event {
+                TrayEvent::OpenMainWindow => Task::done(AppMsg::ShowWindow),
+                TrayEvent::OpenSettings => state.open_settings_window(),
+                TrayEvent::TogglePausePlugins => state.set_plugins_paused(!state.plugins_paused),
+                TrayEvent::ToggleOfflineMode => state.set_offline_mode(!state.offline_mode),
+                TrayEvent::ToggleFocusMode => state.set_focus_mode(!state.focus_mode),
+                TrayEvent::Quit => iced::exit(),
+            }
+        }
+        AppMsg::PluginsPausedChanged(paused) => {
+            state.plugins_paused = paused;
+            state.update_tray_status();
+
+            match &state.global_state {
+                GlobalState::MainView { .. } => state.search(state.prompt.clone(), true),
+                _ => Task::none(),
+            }
+        }
+        AppMsg::OfflineModeChanged(offline_mode) => {
+            state.offline_mode = offline_mode;
+
+            if let Some(tray) = &state.tray {
+                tray.set_offline_mode(offline_mode);
+            }
+
+            match &state.global_state {
+                GlobalState::MainView { .. } => state.search(state.prompt.clone(), true),
+                _ => Task::none(),
+            }
+        }
+        AppMsg::FocusModeChanged(focus_mode) => {
+            state.focus_mode = focus_mode;
+
+            if let Some(tray) = &state.tray {
+                tray.set_focus_mode(focus_mode);
+            }
+
+            match &state.global_state {
+                GlobalState::MainView { .. } => state.search(state.prompt.clone(), true),
+                _ => Task::none(),
+            }
+        }
+        AppMsg::EntrypointDisabled(disabled_entrypoint) => {
+            state.undo_disable_entrypoint = Some(disabled_entrypoint.clone());
+
+            Task::batch([
+                Task::done(AppMsg::UpdateSearchResults),
+                Task::perform(async move {
+                    tokio::time::sleep(UNDO_TOAST_DURATION).await;
+                }, move |_| AppMsg::HideUndoDisableEntrypointToast(disabled_entrypoint)),
+            ])
+        }
+        AppMsg::UndoDisableEntrypoint => {
+            match state.undo_disable_entrypoint.take() {
+                Some(disabled_entrypoint) => state.enable_entrypoint(disabled_entrypoint),
+                None => Task::none()
+            }
+        }
+        AppMsg::HideUndoDisableEntrypointToast(disabled_entrypoint) => {
+            // toast could have been replaced by toast for other entrypoint in the meantime
+            if state.undo_disable_entrypoint.as_ref() == Some(&disabled_entrypoint) {
+                state.undo_disable_entrypoint = None;
+            }
+
+            Task::none()
+        }
+        AppMsg::ToggleShortcutHints => {
+            state.shortcut_hints = !state.shortcut_hints;
+
+            Task::none()
+        }
+        AppMsg::TogglePin => {
+            state.pinned = !state.pinned;
+
+            Task::none()
+        }
+        AppMsg::TogglePreviewPane => {
+            state.preview_pane = !state.preview_pane;
+
+            if !state.preview_pane {
+                state.result_preview = None;
+            }
+
+            Task::none()
+        }
+        AppMsg::CloseQuicklook => {
+            state.quicklook = None;
+
+            Task::none()
+        }
+        AppMsg::SetQuicklook { path, quicklook } => {
+            // response may arrive after quicklook was closed or moved to another file
+            if let Some(current) = &mut state.quicklook {
+                if current.path == path {
+                    current.set_loaded(quicklook);
+                }
+            }
+
+            Task::none()
+        }
+        AppMsg::SetResultPreview { plugin_id, entrypoint_id, preview } => {
+            // response for result which is not focused anymore is ignored
+            if let Some(result_preview) = &mut state.result_preview {
+                if result_preview.is_for(&plugin_id, &entrypoint_id) {
+                    result_preview.content = match preview {
+                        Some(preview) => ResultPreviewContent::Loaded(preview),
+                        None => ResultPreviewContent::Empty,
+                    };
+                }
+            }
+
+            Task::none()
+        }
+        AppMsg::DetachPluginView => {
+            // layer shell surfaces cannot be used as regular windows
+            if state.wayland {
+                return Task::none()
+            }
+
+            let GlobalState::PluginView { sub_state: PluginViewState::None, .. } = &state.global_state else {
+                return Task::none()
+            };
+
+            let (window_id, open_window) = window::open(detached_view_window_settings());
+
+            state.client_context.detach_view(window_id);
+            state.windows.insert(window_id, AppWindow::DetachedPluginView);
+
+            // view now belongs to separate window, so it is not reopened in main window
+            state.last_plugin_view = None;
+
+            Task::batch([
+                open_window.map(|_| AppMsg::Noop),
+                GlobalState::initial(&mut state.global_state),
+            ])
+        }
+        AppMsg::ToggleDebugHud => {
+            state.debug_hud = match state.debug_hud {
+                None => Some(DebugHud::new()),
+                Some(_) => None,
+            };
+
+            Task::none()
+        }
+        AppMsg::DebugHudFrame(now) => {
+            if let Some(debug_hud) = &mut state.debug_hud {
+                debug_hud.frame(now);
+            }
+
+            Task::none()
         }
         AppMsg::OpenSettingsPreferences { plugin_id, entrypoint_id, } => {
             state.open_settings_window_preferences(plugin_id, entrypoint_id)
@@ -1099,19 +2060,19 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             Task::none()
         }
         AppMsg::Screenshot { save_path } => {
-            println!("Creating screenshot at: {}", save_path);
+            tracing::info!("Creating screenshot at: {}", save_path);
 
             fs::create_dir_all(Path::new(&save_path).parent().expect("no parent?"))
                 .expect("unable to create scenario out directories");
 
-            window::screenshot(state.main_window_id)
+            window::screenshot(state.windows.main_window_id())
                 .map(move |screenshot| AppMsg::ScreenshotDone {
                     save_path: save_path.clone(),
                     screenshot,
                 })
         }
         AppMsg::ScreenshotDone { save_path, screenshot } => {
-            println!("Saving screenshot at: {}", save_path);
+            tracing::info!("Saving screenshot at: {}", save_path);
 
             Task::perform(
                 async move {
@@ -1144,12 +2105,11 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                 GlobalState::MainView { sub_state, focused_search_result, .. } => {
                     match sub_state {
                         MainViewState::None => {
-                            if let Some(search_item) = focused_search_result.get(&state.search_results) {
-                                if search_item.entrypoint_actions.len() > 0 {
-                                    MainViewState::search_result_action_panel(sub_state, keyboard);
-                                }
+                            if let Some(_) = focused_search_result.get(&state.search_results) {
+                                // there are always builtin actions available
+                                MainViewState::search_result_action_panel(sub_state, keyboard);
                             } else {
-                                if let Some(_) = state.client_context.get_first_inline_view_container() {
+                                if let Some(_) = state.client_context.get_focused_inline_view_container() {
                                     MainViewState::inline_result_action_panel(sub_state, keyboard);
                                 }
                             }
@@ -1182,6 +2142,60 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
         AppMsg::OnPrimaryActionMainViewNoPanelKeyboardWithoutFocus => {
             Task::done(AppMsg::OnAnyActionMainViewNoPanelKeyboardAtIndex { index: 0 })
         }
+        AppMsg::OnHoverSearchResult { index } => {
+            match &mut state.global_state {
+                GlobalState::MainView { focused_search_result, sub_state, show_all_focused, .. } => {
+                    // focus shouldn't change under opened action panel
+                    if matches!(sub_state, MainViewState::None) && (focused_search_result.index != Some(index) || *show_all_focused) {
+                        focused_search_result.focus_index(index);
+                        *show_all_focused = false;
+                    }
+                }
+                GlobalState::ErrorView { .. } => {}
+                GlobalState::PluginView { .. } => {}
+            }
+
+            Task::none()
+        }
+        AppMsg::OnHoverShowAllRow { index } => {
+            match &mut state.global_state {
+                GlobalState::MainView { focused_search_result, sub_state, show_all_focused, .. } => {
+                    if matches!(sub_state, MainViewState::None) && (focused_search_result.index != Some(index) || !*show_all_focused) {
+                        focused_search_result.focus_index(index);
+                        *show_all_focused = true;
+                    }
+                }
+                GlobalState::ErrorView { .. } => {}
+                GlobalState::PluginView { .. } => {}
+            }
+
+            Task::none()
+        }
+        AppMsg::OnRightClickSearchResult { index } => {
+            match &mut state.global_state {
+                GlobalState::MainView { focused_search_result, sub_state, .. } => {
+                    focused_search_result.focus_index(index);
+
+                    // same action panel as the one opened by keyboard, but without focused item
+                    MainViewState::search_result_action_panel(sub_state, false);
+                }
+                GlobalState::ErrorView { .. } => {}
+                GlobalState::PluginView { .. } => {}
+            }
+
+            Task::none()
+        }
+        AppMsg::OnScrollSearchList { scroll_position } => {
+            match &mut state.global_state {
+                GlobalState::MainView { focused_search_result, .. } => {
+                    focused_search_result.on_scroll(scroll_position);
+                }
+                GlobalState::ErrorView { .. } => {}
+                GlobalState::PluginView { .. } => {}
+            }
+
+            Task::none()
+        }
         AppMsg::OnPrimaryActionMainViewNoPanel { search_result } => {
             Task::done(AppMsg::RunSearchItemAction(search_result, 0))
         }
@@ -1200,7 +2214,7 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             ])
         }
         AppMsg::OnAnyActionMainViewInlineViewPanelKeyboardWithFocus { widget_id } => {
-            match state.client_context.get_first_inline_view_container() {
+            match state.client_context.get_focused_inline_view_container() {
                 Some(container) => {
                     let plugin_id = container.get_plugin_id();
 
@@ -1247,7 +2261,7 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             }
         }
         AppMsg::OnAnyActionMainViewNoPanelKeyboardAtIndex { index } => {
-            if let Some(container) = state.client_context.get_first_inline_view_container() {
+            if let Some(container) = state.client_context.get_focused_inline_view_container() {
                 let plugin_id = container.get_plugin_id();
                 let action_ids = container.get_action_ids();
 
@@ -1304,18 +2318,178 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
         AppMsg::ClosePluginView(plugin_id) => {
             state.close_plugin_view(plugin_id)
         }
+        AppMsg::OpenCommandPalette => {
+            if state.command_palette.is_some() {
+                return Task::none()
+            }
+
+            let (command_palette, focus_task) = CommandPalette::new();
+
+            state.command_palette = Some(command_palette);
+
+            Task::batch([
+                focus_task,
+                state.search_command_palette("".to_string()),
+            ])
+        }
+        AppMsg::CloseCommandPalette => {
+            state.command_palette = None;
+
+            Task::none()
+        }
+        AppMsg::CommandPaletteQueryChanged(query) => {
+            let Some(command_palette) = &mut state.command_palette else {
+                return Task::none()
+            };
+
+            command_palette.query = query.clone();
+
+            state.search_command_palette(query)
+        }
+        AppMsg::SetCommandPaletteResults { query, results } => {
+            // plugin can show only one view at a time, so views of plugins which already have one open are not listed
+            let mut open_view_plugin_ids: Vec<_> = state.suspended_plugin_views.iter()
+                .map(|plugin_view_data| plugin_view_data.plugin_id.clone())
+                .collect();
+
+            if let GlobalState::PluginView { plugin_view_data, .. } = &state.global_state {
+                open_view_plugin_ids.push(plugin_view_data.plugin_id.clone());
+            }
+
+            if let Some(command_palette) = &mut state.command_palette {
+                command_palette.set_results(query, results, &open_view_plugin_ids);
+            }
+
+            Task::none()
+        }
+        AppMsg::CommandPaletteFocus { index } => {
+            if let Some(command_palette) = &mut state.command_palette {
+                command_palette.focused_result.focus_index(index);
+            }
+
+            Task::none()
+        }
+        AppMsg::CommandPaletteSubmit => {
+            match state.command_palette.as_ref().and_then(|command_palette| command_palette.focused()) {
+                Some(search_result) => Task::done(AppMsg::RunCommandPaletteResult { search_result }),
+                None => Task::none(),
+            }
+        }
+        AppMsg::RunCommandPaletteResult { search_result } => {
+            state.command_palette = None;
+
+            match search_result.entrypoint_type {
+                SearchResultEntrypointType::Command => {
+                    state.run_command(search_result.plugin_id, search_result.entrypoint_id, HashMap::new())
+                }
+                SearchResultEntrypointType::View => {
+                    let GlobalState::PluginView { plugin_view_data, .. } = &state.global_state else {
+                        return Task::none()
+                    };
+
+                    let current_plugin_view_data = plugin_view_data.clone();
+
+                    let plugin_view_data = PluginViewData {
+                        top_level_view: true,
+                        plugin_id: search_result.plugin_id,
+                        plugin_name: search_result.plugin_name,
+                        entrypoint_id: search_result.entrypoint_id,
+                        entrypoint_name: search_result.entrypoint_name,
+                        action_shortcuts: HashMap::new(),
+                    };
+
+                    // current view is not closed, its plugin keeps it rendered until it is shown again
+                    let task = state.open_plugin_view(plugin_view_data.plugin_id.clone(), plugin_view_data.entrypoint_id.clone());
+
+                    state.client_context.suspend_view(current_plugin_view_data.plugin_id.clone());
+                    state.suspended_plugin_views.push(current_plugin_view_data);
+                    state.last_plugin_view = Some(plugin_view_data.clone());
+
+                    Task::batch([
+                        GlobalState::plugin(&mut state.global_state, plugin_view_data),
+                        task,
+                    ])
+                }
+                SearchResultEntrypointType::Generated => Task::none(),
+            }
+        }
+        AppMsg::PopPluginView(plugin_id) => {
+            state.pop_plugin_view(plugin_id)
+        }
+        AppMsg::RestorePluginView => {
+            state.client_context.get_view_container().restore_view()
+        }
         AppMsg::InlineViewShortcuts { shortcuts } => {
             state.client_context.set_inline_view_shortcuts(shortcuts);
 
             Task::none()
         }
         AppMsg::ShowHud { display } => {
-            state.hud_display = Some(display);
-
-            show_hud_window(
+            let (window_id, task) = show_hud_window(
                 #[cfg(target_os = "linux")]
                 state.wayland,
-            )
+            );
+
+            state.windows.insert(window_id, AppWindow::Hud { display });
+
+            task
+        }
+        AppMsg::WindowClosed(window_id) => {
+            state.windows.remove(window_id);
+
+            Task::none()
+        }
+        AppMsg::ShowSettingsWindow { plugin_id, entrypoint_id } => {
+            let settings_env_data = plugin_id.map(|plugin_id| {
+                match entrypoint_id {
+                    None => SettingsEnvData::OpenPluginPreferences {
+                        plugin_id: plugin_id.to_string(),
+                    },
+                    Some(entrypoint_id) => SettingsEnvData::OpenEntrypointPreferences {
+                        plugin_id: plugin_id.to_string(),
+                        entrypoint_id: entrypoint_id.to_string(),
+                    },
+                }
+            });
+
+            // layer shell surfaces cannot be used as regular windows, so settings are still a separate process
+            if state.wayland {
+                spawn_settings_process(settings_env_data);
+
+                return Task::none()
+            }
+
+            let settings_window_id = state.windows.find(|window| matches!(window, AppWindow::Settings));
+
+            if let (Some(window_id), Some(settings_window)) = (settings_window_id, &mut state.settings_window) {
+                let open_preferences = match settings_env_data {
+                    None => Task::none(),
+                    Some(settings_env_data) => settings_window.open_preferences(settings_env_data).map(AppMsg::SettingsWindow),
+                };
+
+                return Task::batch([
+                    window::gain_focus(window_id),
+                    open_preferences,
+                ])
+            }
+
+            let (settings_window, settings_task) = SettingsWindow::new(state.settings_backend_api.clone(), settings_env_data);
+
+            let (window_id, open_window) = window::open(settings_window_settings());
+
+            state.settings_window = Some(settings_window);
+            state.windows.insert(window_id, AppWindow::Settings);
+
+            Task::batch([
+                open_window.map(|_| AppMsg::Noop),
+                settings_task.map(AppMsg::SettingsWindow),
+            ])
+        }
+        AppMsg::SettingsWindow(message) => {
+            match &mut state.settings_window {
+                Some(settings_window) => settings_window.update(message).map(AppMsg::SettingsWindow),
+                None => Task::none(),
+            }
         }
         AppMsg::ResetMainViewState => {
             match &mut state.global_state {
@@ -1394,6 +2568,12 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
         AppMsg::FocusPluginViewSearchBar { widget_id } => {
             state.client_context.focus_search_bar(widget_id)
         }
+        AppMsg::SelectPluginViewTab { tab_id } => {
+            state.client_context.select_tab(tab_id)
+        }
+        AppMsg::StartDrag { window_id, payload } => {
+            start_drag(window_id, payload)
+        }
         #[cfg(target_os = "linux")]
         AppMsg::LayerShell(_) => {
             // handled by library
@@ -1405,7 +2585,8 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             Task::none()
         }
         AppMsg::SetTheme { theme } => {
-            state.theme = GauntletComplexTheme::new(theme);
+            state.theme = GauntletComplexTheme::new(theme.clone(), state.density, state.text_size);
+            state.ui_theme = theme;
 
             GauntletComplexTheme::update_global(state.theme.clone());
 
@@ -1416,120 +2597,273 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
 
             Task::none()
         }
-    }
-}
+        AppMsg::SetDensity { density } => {
+            state.density = density;
+            state.theme = GauntletComplexTheme::new(state.ui_theme.clone(), density, state.text_size);
 
-fn view(state: &AppModel, window: window::Id) -> Element<'_, AppMsg> {
-    if window != state.main_window_id {
-        view_hud(state)
-    } else {
-        view_main(state)
-    }
-}
+            GauntletComplexTheme::update_global(state.theme.clone());
 
-fn view_hud(state: &AppModel) -> Element<'_, AppMsg> {
-    match &state.hud_display {
-        Some(hud_display) => {
-            let hud: Element<_> = text(hud_display.to_string())
-                .shaping(Shaping::Advanced)
-                .into();
+            Task::none()
+        }
+        AppMsg::SetUiScale { scale } => {
+            state.ui_scale.set_scale(scale);
 
-            let hud = container(hud)
-                .align_x(Horizontal::Center)
-                .align_y(Vertical::Center)
-                .height(Length::Fill)
-                .themed(ContainerStyle::HudInner);
+            Task::none()
+        }
+        AppMsg::MainWindowMonitorDetected { monitor_size, system_scale_factor } => {
+            state.ui_scale.set_monitor(monitor_size, system_scale_factor);
 
-            let hud = container(hud)
-                .align_x(Horizontal::Center)
-                .align_y(Vertical::Center)
-                .height(Length::Fill)
-                .themed(ContainerStyle::Hud);
+            Task::none()
+        }
+        AppMsg::RendererDetected { renderer } => {
+            let mut backend_api = state.backend_api.clone();
 
-            let hud = container(hud)
-                .height(Length::Fill)
-                .width(Length::Fill)
-                .align_x(Horizontal::Center)
-                .align_y(Vertical::Center)
-                .class(ContainerStyleInner::Transparent)
-                .into();
+            Task::perform(async move {
+                backend_api.report_renderer(renderer)
+                    .await?;
 
-            hud
+                Ok(())
+            }, |result| handle_backend_error(result, |()| AppMsg::Noop))
         }
-        None => {
-            // this should never be shown, but in case it does, do not make it fully transparent
-            container(horizontal_space())
-                .themed(ContainerStyle::Hud)
+        AppMsg::SetWindowOverlay { enabled } => {
+            state.window_overlay = enabled;
+
+            apply_window_overlay(state.windows.main_window_id(), enabled)
         }
-    }
-}
+        AppMsg::SetSearchResultsSort { sort } => {
+            state.search_results_sort = sort;
 
-fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
-    match &state.global_state {
-        GlobalState::ErrorView { error_view } => {
-            match error_view {
-                ErrorViewData::PreferenceRequired { plugin_id, entrypoint_id, plugin_preferences_required, entrypoint_preferences_required } => {
-
-                    let (description_text, msg) = match (plugin_preferences_required, entrypoint_preferences_required) {
-                        (true, true) => {
-                            // TODO do not show "entrypoint" name to user
-                            let description_text = "Before using, plugin and entrypoint preferences need to be specified";
-                            // note:
-                            // we open plugin view and not entrypoint even though both need to be specified
-                            let msg = AppMsg::OpenSettingsPreferences { plugin_id: plugin_id.clone(), entrypoint_id: None };
-                            (description_text, msg)
-                        }
-                        (false, true) => {
-                            // TODO do not show "entrypoint" name to user
-                            let description_text = "Before using, entrypoint preferences need to be specified";
-                            let msg = AppMsg::OpenSettingsPreferences { plugin_id: plugin_id.clone(), entrypoint_id: Some(entrypoint_id.clone()) };
-                            (description_text, msg)
-                        }
-                        (true, false) => {
-                            let description_text = "Before using, plugin preferences need to be specified";
-                            let msg = AppMsg::OpenSettingsPreferences { plugin_id: plugin_id.clone(), entrypoint_id: None };
-                            (description_text, msg)
-                        }
-                        (false, false) => unreachable!()
-                    };
+            // order comes from server, so new search is needed
+            Task::done(AppMsg::UpdateSearchResults)
+        }
+        AppMsg::SetTrayTooltip { text } => {
+            if let Some(tray) = &state.tray {
+                tray.set_tooltip(text);
+            }
 
-                    let description: Element<_> = text(description_text)
-                        .shaping(Shaping::Advanced)
-                        .into();
+            Task::none()
+        }
+        AppMsg::TogglePluginGroup { plugin_id } => {
+            let focused = match &state.global_state {
+                GlobalState::MainView { focused_search_result, .. } => {
+                    focused_search_result.get(&state.search_results)
+                        .map(|search_result| (search_result.plugin_id.clone(), search_result.entrypoint_id.clone()))
+                }
+                _ => None
+            };
 
-                    let description = container(description)
-                        .width(Length::Fill)
-                        .align_x(Horizontal::Center)
-                        .themed(ContainerStyle::PreferenceRequiredViewDescription);
+            if !state.collapsed_plugin_groups.remove(&plugin_id) {
+                state.collapsed_plugin_groups.insert(plugin_id);
+            }
 
-                    let button_label: Element<_> = text("Open Settings")
-                        .into();
+            state.update_visible_search_results();
 
-                    let button: Element<_> = button(button_label)
-                        .on_press(msg)
-                        .into();
+            // keep focus on the same item, unless it was hidden by collapsing its group
+            let focused_index = focused.and_then(|(plugin_id, entrypoint_id)| {
+                state.search_results
+                    .iter()
+                    .position(|search_result| search_result.plugin_id == plugin_id && search_result.entrypoint_id == entrypoint_id)
+            });
 
-                    let button = container(button)
-                        .width(Length::Fill)
-                        .align_x(Horizontal::Center)
-                        .into();
+            if let GlobalState::MainView { focused_search_result, .. } = &mut state.global_state {
+                match focused_index {
+                    Some(index) => focused_search_result.focus_index(index),
+                    None => focused_search_result.reset(!state.search_results.is_empty()),
+                }
+            }
 
-                    let content: Element<_> = column([
-                        description,
-                        button
-                    ]).into();
+            Task::none()
+        }
+        AppMsg::ShowAllPluginResults { plugin_id, plugin_name } => {
+            match &mut state.global_state {
+                GlobalState::MainView { focused_search_result, show_all_focused, sub_state, plugin_scope, .. } => {
+                    *plugin_scope = Some(PluginScope {
+                        plugin_id,
+                        plugin_name,
+                    });
 
-                    let content: Element<_> = container(content)
-                        .align_x(Horizontal::Center)
-                        .align_y(Vertical::Center)
-                        .width(Length::Fill)
-                        .height(Length::Fill)
-                        .themed(ContainerStyle::Main);
+                    focused_search_result.reset(true);
+                    *show_all_focused = false;
 
-                    content
+                    MainViewState::initial(sub_state);
+
+                    // query is kept, only results of selected plugin are requested
+                    state.search(state.prompt.clone(), false)
+                }
+                _ => Task::none()
+            }
+        }
+        AppMsg::ExitPluginScope => {
+            match &mut state.global_state {
+                GlobalState::MainView { focused_search_result, sub_state, plugin_scope, .. } => {
+                    *plugin_scope = None;
+
+                    focused_search_result.reset(true);
+
+                    MainViewState::initial(sub_state);
+
+                    state.search(state.prompt.clone(), false)
+                }
+                _ => Task::none()
+            }
+        }
+    }
+}
+
+fn view(state: &AppModel, window: window::Id) -> Element<'_, AppMsg> {
+    match state.windows.get(window) {
+        Some(AppWindow::Main) => with_drag_window(view_main(state), window),
+        Some(AppWindow::Hud { display }) => view_hud(display),
+        Some(AppWindow::Settings) => {
+            match &state.settings_window {
+                Some(settings_window) => {
+                    ThemeAdapter::new(settings_window.view().map(AppMsg::SettingsWindow), GauntletSettingsTheme::default())
+                        .into()
+                }
+                None => view_closed_window(),
+            }
+        }
+        Some(AppWindow::DetachedPluginView) => {
+            match state.client_context.get_detached_view_container(window) {
+                Some(container) => with_drag_window(view_detached_plugin_view(container), window),
+                None => view_closed_window(),
+            }
+        }
+        None => view_closed_window(),
+    }
+}
+
+// native drag is started from the window in which item was dragged, plugin views can be shown in detached windows
+fn with_drag_window(element: Element<'_, AppMsg>, window_id: window::Id) -> Element<'_, AppMsg> {
+    element.map(move |msg| match msg {
+        AppMsg::WidgetEvent { widget_event: ComponentWidgetEvent::StartDrag { payload, .. }, .. } => AppMsg::StartDrag { window_id, payload },
+        msg => msg,
+    })
+}
+
+// window can be drawn for the last time after it was removed from state but before it is closed
+fn view_closed_window<'a>() -> Element<'a, AppMsg> {
+    container(horizontal_space())
+        .themed(ContainerStyle::Root)
+}
+
+fn view_detached_plugin_view(view_container: &PluginWidgetContainer) -> Element<'_, AppMsg> {
+    let plugin_id = view_container.get_plugin_id();
+
+    let container_element = view_container
+        .render_root_widget(&PluginViewState::None, &HashMap::new())
+        .map(move |widget_event| AppMsg::WidgetEvent {
+            plugin_id: plugin_id.clone(),
+            render_location: UiRenderLocation::View,
+            widget_event,
+        });
+
+    container(container_element)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .themed(ContainerStyle::Root)
+}
+
+// filled when window is pinned
+fn pin_button<'a>(pinned: bool) -> Element<'a, AppMsg> {
+    let icon = if pinned { Bootstrap::PinAngleFill } else { Bootstrap::PinAngle };
+
+    let icon: Element<_> = value(icon)
+        .font(BOOTSTRAP_FONT)
+        .size(text_size(14.0))
+        .themed(TextStyle::ListSectionTitle);
+
+    let icon: Element<_> = container(icon)
+        .padding(8.0)
+        .into();
+
+    mouse_area(icon)
+        .on_press(AppMsg::TogglePin)
+        .into()
+}
+
+fn view_hud(display: &str) -> Element<'_, AppMsg> {
+    let hud: Element<_> = text(display)
+        .shaping(Shaping::Advanced)
+        .into();
+
+    let hud = container(hud)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+        .height(Length::Fill)
+        .themed(ContainerStyle::HudInner);
+
+    let hud = container(hud)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+        .height(Length::Fill)
+        .themed(ContainerStyle::Hud);
+
+    container(hud)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+        .class(ContainerStyleInner::Transparent)
+        .into()
+}
+
+fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
+    let content = view_main_content(state);
+
+    let content = match &state.command_palette {
+        Some(command_palette) => stack([content, command_palette.view()]).into(),
+        None => content,
+    };
+
+    let content = match &state.deep_link_confirmation {
+        Some(deep_link_confirmation) => stack([content, deep_link_confirmation.view()]).into(),
+        None => content,
+    };
+
+    let content = match &state.quicklook {
+        Some(quicklook) => stack([content, quicklook.view()]).into(),
+        None => content,
+    };
+
+    let content = if state.shortcut_hints {
+        stack([content, shortcut_hints_view(&state.shortcut_hints_context(), &state.keymap)]).into()
+    } else {
+        content
+    };
+
+    let content = match &state.debug_hud {
+        Some(debug_hud) => column([debug_hud.view(), content]).into(),
+        None => content,
+    };
+
+    if !state.backend_reconnecting {
+        return content
+    }
+
+    let banner: Element<_> = text(tr!("backend-reconnecting"))
+        .shaping(Shaping::Advanced)
+        .into();
+
+    let banner: Element<_> = container(banner)
+        .width(Length::Fill)
+        .align_x(Horizontal::Center)
+        .padding(4.0)
+        .themed(ContainerStyle::RootBottomPanel);
+
+    column([banner, content])
+        .into()
+}
+
+fn view_main_content(state: &AppModel) -> Element<'_, AppMsg> {
+    match &state.global_state {
+        GlobalState::ErrorView { error_view } => {
+            match error_view {
+                ErrorViewData::Onboarding { plugin_id, plugin_name, entrypoint_id, description, preferences } => {
+                    onboarding_view(plugin_id, plugin_name, entrypoint_id, description, preferences)
                 }
                 ErrorViewData::PluginError { .. } => {
-                    let description: Element<_> = text("Error occurred in plugin when trying to show the view")
+                    let description: Element<_> = text(tr!("plugin-view-error"))
                         .into();
 
                     let description = container(description)
@@ -1537,7 +2871,7 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                         .align_x(Horizontal::Center)
                         .themed(ContainerStyle::PluginErrorViewTitle);
 
-                    let sub_description: Element<_> = text("Please report this to plugin author")
+                    let sub_description: Element<_> = text(tr!("plugin-view-error-report"))
                         .into();
 
                     let sub_description = container(sub_description)
@@ -1545,7 +2879,7 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                         .align_x(Horizontal::Center)
                         .themed(ContainerStyle::PluginErrorViewDescription);
 
-                    let button_label: Element<_> = text("Close")
+                    let button_label: Element<_> = text(tr!("close"))
                         .into();
 
                     let button: Element<_> = button(button_label)
@@ -1573,7 +2907,7 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                     content
                 }
                 ErrorViewData::UnknownError { display } => {
-                    let description: Element<_> = text("Unknown error occurred")
+                    let description: Element<_> = text(tr!("error-unknown"))
                         .into();
 
                     let description = container(description)
@@ -1581,7 +2915,7 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                         .align_x(Horizontal::Center)
                         .themed(ContainerStyle::PluginErrorViewTitle);
 
-                    let sub_description: Element<_> = text("Please report") // TODO link
+                    let sub_description: Element<_> = text(tr!("error-please-report")) // TODO link
                         .into();
 
                     let sub_description = container(sub_description)
@@ -1601,7 +2935,7 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                         .width(Length::Fill)
                         .into();
 
-                    let button_label: Element<_> = text("Close")
+                    let button_label: Element<_> = text(tr!("close"))
                         .into();
 
                     let button: Element<_> = button(button_label)
@@ -1620,50 +2954,6 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                         button
                     ]).into();
 
-                    let content: Element<_> = container(content)
-                        .align_x(Horizontal::Center)
-                        .align_y(Vertical::Center)
-                        .width(Length::Fill)
-                        .height(Length::Fill)
-                        .themed(ContainerStyle::Main);
-
-                    content
-                }
-                ErrorViewData::BackendTimeout => {
-                    let description: Element<_> = text("Error occurred")
-                        .into();
-
-                    let description = container(description)
-                        .width(Length::Fill)
-                        .align_x(Horizontal::Center)
-                        .themed(ContainerStyle::PluginErrorViewTitle);
-
-                    let sub_description: Element<_> = text("Backend was unable to process message in a timely manner")
-                        .into();
-
-                    let sub_description = container(sub_description)
-                        .width(Length::Fill)
-                        .align_x(Horizontal::Center)
-                        .themed(ContainerStyle::PluginErrorViewDescription);
-
-                    let button_label: Element<_> = text("Close")
-                        .into();
-
-                    let button: Element<_> = button(button_label)
-                        .on_press(AppMsg::HideWindow)
-                        .into();
-
-                    let button = container(button)
-                        .width(Length::Fill)
-                        .align_x(Horizontal::Center)
-                        .into();
-
-                    let content: Element<_> = column([
-                        description,
-                        sub_description,
-                        button
-                    ]).into();
-
                     let content: Element<_> = container(content)
                         .align_x(Horizontal::Center)
                         .align_y(Vertical::Center)
@@ -1675,17 +2965,41 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                 }
             }
         }
-        GlobalState::MainView { focused_search_result, sub_state, search_field_id, pending_plugin_view_loading_bar, .. } => {
-            let input: Element<_> = text_input("Search...", &state.prompt)
+        GlobalState::MainView { pending_plugin_view_data: Some(_), pending_plugin_view_loading_bar: LoadingBarState::On, .. } => {
+            let element: Element<_> = container(skeleton_view())
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .themed(ContainerStyle::Root);
+
+            element
+        }
+        GlobalState::MainView { focused_search_result, show_all_focused, sub_state, search_field_id, pending_plugin_view_loading_bar, plugin_scope, .. } => {
+            let input: Element<_> = text_input(&tr!("search-placeholder"), &state.prompt)
                 .on_input(AppMsg::PromptChanged)
                 .on_submit(AppMsg::PromptSubmit)
                 .ignore_with_modifiers(true)
                 .id(search_field_id.clone())
                 .width(Length::Fill)
+                .align_x(text_alignment())
                 .themed(TextInputStyle::MainSearch);
 
-            let search_list = search_list(&state.search_results, &focused_search_result)
-                .map(|search_result| AppMsg::OnPrimaryActionMainViewNoPanel { search_result });
+            let plugin_groups = match state.search_results_sort {
+                SearchResultsSort::Plugin if !state.fallback_search_results => Some(PluginGroups { collapsed: &state.collapsed_plugin_groups }),
+                SearchResultsSort::Plugin | SearchResultsSort::Frecency | SearchResultsSort::Relevance => None,
+            };
+
+            let map_search_list_event = |event: SearchListEvent| match event {
+                SearchListEvent::Primary(search_result) => AppMsg::OnPrimaryActionMainViewNoPanel { search_result },
+                SearchListEvent::Focus(index) => AppMsg::OnHoverSearchResult { index },
+                SearchListEvent::FocusShowAll(index) => AppMsg::OnHoverShowAllRow { index },
+                SearchListEvent::ActionPanel(index) => AppMsg::OnRightClickSearchResult { index },
+                SearchListEvent::TogglePluginGroup(plugin_id) => AppMsg::TogglePluginGroup { plugin_id },
+                SearchListEvent::ShowAllPluginResults(plugin_id, plugin_name) => AppMsg::ShowAllPluginResults { plugin_id, plugin_name },
+                SearchListEvent::ExitPluginScope => AppMsg::ExitPluginScope,
+            };
+
+            let search_list = search_list(&state.all_search_results, state.recent_search_results_count, state.fallback_search_results, plugin_groups, state.results_per_plugin_limit(), &focused_search_result, *show_all_focused)
+                .map(map_search_list_event);
 
             let search_list = container(search_list)
                 .width(Length::Fill)
@@ -1693,6 +3007,7 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
 
             let list: Element<_> = scrollable(search_list)
                 .id(focused_search_result.scrollable_id.clone())
+                .on_scroll(|viewport| AppMsg::OnScrollSearchList { scroll_position: viewport.absolute_offset().y })
                 .width(Length::Fill)
                 .into();
 
@@ -1701,10 +3016,29 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                 .height(Length::Fill)
                 .themed(ContainerStyle::MainList);
 
-            let input = container(input)
+            let input: Element<_> = row(directional(vec![input, pin_button(state.pinned)]))
+                .align_y(Alignment::Center)
+                .into();
+
+            let input: Element<_> = container(input)
                 .width(Length::Fill)
                 .themed(ContainerStyle::MainSearchBar);
 
+            let input: Element<_> = match &state.argument_prompt {
+                None => input,
+                Some(argument_prompt) => column([input, argument_prompt.view()]).into(),
+            };
+
+            let input: Element<_> = match plugin_scope {
+                None => input,
+                Some(plugin_scope) => {
+                    let title = plugin_scope_title(&plugin_scope.plugin_name)
+                        .map(map_search_list_event);
+
+                    column([input, title]).into()
+                },
+            };
+
             let separator = if matches!(pending_plugin_view_loading_bar, LoadingBarState::On) || !state.loading_bar_state.is_empty() {
                 LoadingBar::new()
                     .into()
@@ -1713,10 +3047,18 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                     .into()
             };
 
-            let inline_view = match state.client_context.get_all_inline_view_containers().first() {
-                Some((plugin_id, container)) => {
+            let inline_views = state.client_context.get_all_inline_view_containers();
+
+            // focus is only shown when there is more than one inline view and search results are not focused
+            let show_inline_view_focus = inline_views.len() > 1 && focused_search_result.index.is_none();
+
+            let mut content: Vec<Element<_>> = inline_views
+                .iter()
+                .map(|(plugin_id, container)| {
+                    let focused = show_inline_view_focus && state.client_context.is_inline_view_focused(plugin_id);
+
                     let plugin_id = plugin_id.clone();
-                    container.render_inline_root_widget()
+                    container.render_inline_root_widget(focused)
                         .map(move |widget_event| {
                             AppMsg::WidgetEvent {
                                 plugin_id: plugin_id.clone(),
@@ -1724,17 +3066,30 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                                 widget_event,
                             }
                         })
-                }
-                None => {
-                    horizontal_space()
+                })
+                .collect();
+
+            let list = match (state.preview_pane, &state.result_preview) {
+                (true, Some(result_preview)) => {
+                    let list: Element<_> = container(list)
+                        .width(Length::FillPortion(1))
+                        .into();
+
+                    let preview: Element<_> = container(result_preview.view())
+                        .width(Length::FillPortion(1))
+                        .into();
+
+                    row(directional(vec![list, vertical_rule(1).into(), preview]))
+                        .height(Length::Fill)
                         .into()
                 }
+                _ => list,
             };
 
-            let content: Element<_> = column(vec![
-                inline_view,
-                list,
-            ]).into();
+            content.push(list);
+
+            let content: Element<_> = column(content)
+                .into();
 
             let (primary_action, action_panel) = if let Some(search_item) = focused_search_result.get(&state.search_results) {
                 let primary_shortcut = PhysicalShortcut {
@@ -1754,7 +3109,17 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                 };
 
                 let create_static = |label: &str, primary_shortcut: PhysicalShortcut, secondary_shortcut: PhysicalShortcut| {
-                    let mut actions: Vec<_> = search_item.entrypoint_actions
+                    let label = label.to_string();
+
+                    let primary_action_widget_id = 0;
+
+                    let primary_action = ActionPanelItem::Action {
+                        label: label.clone(),
+                        widget_id: primary_action_widget_id,
+                        physical_shortcut: Some(primary_shortcut.clone()),
+                    };
+
+                    let plugin_actions = search_item.entrypoint_actions
                         .iter()
                         .enumerate()
                         .map(|(index, action)| {
@@ -1766,34 +3131,22 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
 
                             ActionPanelItem::Action {
                                 label: action.label.clone(),
-                                widget_id: index,
+                                widget_id: index + 1,
                                 physical_shortcut,
                             }
-                        })
-                        .collect();
+                        });
 
-                    let primary_action_widget_id = 0;
-
-                    if actions.len() == 0 {
-                        (Some((label.to_string(), primary_action_widget_id, primary_shortcut)), None)
-                    } else {
-                        let label = label.to_string();
-
-                        let primary_action = ActionPanelItem::Action {
-                            label: label.clone(),
-                            widget_id: primary_action_widget_id,
-                            physical_shortcut: Some(primary_shortcut.clone()),
-                        };
-
-                        actions.insert(0, primary_action);
+                    let actions: Vec<_> = std::iter::once(primary_action)
+                        .chain(plugin_actions)
+                        .chain(builtin_action_items(search_item))
+                        .collect();
 
-                        let action_panel = ActionPanel {
-                            title: Some(search_item.entrypoint_name.clone()),
-                            items: actions,
-                        };
+                    let action_panel = ActionPanel {
+                        title: Some(search_item.entrypoint_name.clone()),
+                        items: actions,
+                    };
 
-                        (Some((label, primary_action_widget_id, primary_shortcut)), Some(action_panel))
-                    }
+                    (Some((label, primary_action_widget_id, primary_shortcut)), Some(action_panel))
                 };
 
                 let create_generated = |label: &str, primary_shortcut: PhysicalShortcut, secondary_shortcut: PhysicalShortcut| {
@@ -1802,7 +3155,7 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                         .map(|action| action.label.clone())
                         .unwrap_or_else(|| label.to_string()); // should never happen, because there is always at least one action
 
-                    let mut actions: Vec<_> = search_item.entrypoint_actions
+                    let actions: Vec<_> = search_item.entrypoint_actions
                         .iter()
                         .enumerate()
                         .map(|(index, action)| {
@@ -1818,6 +3171,7 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                                 physical_shortcut,
                             }
                         })
+                        .chain(builtin_action_items(search_item))
                         .collect();
 
                     let primary_action_widget_id = 0;
@@ -1831,12 +3185,12 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                 };
 
                 match search_item.entrypoint_type {
-                    SearchResultEntrypointType::Command => create_static("Run Command", primary_shortcut, secondary_shortcut),
-                    SearchResultEntrypointType::View => create_static("Open View", primary_shortcut, secondary_shortcut),
-                    SearchResultEntrypointType::Generated => create_generated("Run Command", primary_shortcut, secondary_shortcut),
+                    SearchResultEntrypointType::Command => create_static(&tr!("action-run-command"), primary_shortcut, secondary_shortcut),
+                    SearchResultEntrypointType::View => create_static(&tr!("action-open-view"), primary_shortcut, secondary_shortcut),
+                    SearchResultEntrypointType::Generated => create_generated(&tr!("action-run-command"), primary_shortcut, secondary_shortcut),
                 }
             } else {
-                match state.client_context.get_first_inline_view_action_panel() {
+                match state.client_context.get_focused_inline_view_action_panel() {
                     None => (None, None),
                     Some(action_panel) => {
                         match action_panel.find_first() {
@@ -1857,8 +3211,10 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                 }
             };
 
-            let toast_text = if !state.loading_bar_state.is_empty() {
-                Some("Indexing...")
+            let toast_text = if let Some(disabled_entrypoint) = &state.undo_disable_entrypoint {
+                Some(tr!("toast-entrypoint-disabled", name = disabled_entrypoint.entrypoint_name.clone(), shortcut = undo_shortcut_label()))
+            } else if !state.loading_bar_state.is_empty() {
+                Some(tr!("search-indexing"))
             } else {
                 None
             };
@@ -1869,7 +3225,7 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                         false,
                         input,
                         separator,
-                        toast_text,
+                        toast_text.as_deref(),
                         content,
                         primary_action,
                         action_panel,
@@ -1886,7 +3242,7 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                         true,
                         input,
                         separator,
-                        toast_text,
+                        toast_text.as_deref(),
                         content,
                         primary_action,
                         action_panel,
@@ -1903,7 +3259,7 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                         true,
                         input,
                         separator,
-                        toast_text,
+                        toast_text.as_deref(),
                         content,
                         primary_action,
                         action_panel,
@@ -1951,9 +3307,13 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
 
 fn subscription(state: &AppModel) -> Subscription<AppMsg> {
     let frontend_receiver = state.frontend_receiver.clone();
+    let backend_api = state.backend_api.clone();
+    let image_cache = state.image_cache.clone();
+    let tray_receiver = state.tray_receiver.clone();
 
     struct RequestLoop;
     struct GlobalShortcutListener;
+    struct TrayListener;
 
     let events_subscription = event::listen_with(|event, status, window_id| match status {
         event::Status::Ignored => Some(AppMsg::IcedEvent(window_id, event)),
@@ -1963,7 +3323,20 @@ fn subscription(state: &AppModel) -> Subscription<AppMsg> {
         }
     });
 
+    // frames are only listened to while debug hud is shown, because listening to them causes constant redraws
+    let debug_hud_subscription = match &state.debug_hud {
+        Some(_) => window::frames().map(AppMsg::DebugHudFrame),
+        None => Subscription::none(),
+    };
+
+    let settings_window_subscription = match &state.settings_window {
+        Some(settings_window) => settings_window.subscription().map(AppMsg::SettingsWindow),
+        None => Subscription::none(),
+    };
+
     Subscription::batch([
+        debug_hud_subscription,
+        settings_window_subscription,
         Subscription::run_with_id(
             std::any::TypeId::of::<GlobalShortcutListener>(),
             stream::channel(
@@ -1978,12 +3351,32 @@ fn subscription(state: &AppModel) -> Subscription<AppMsg> {
             )
         ),
         events_subscription,
+        Subscription::run_with_id(
+            std::any::TypeId::of::<TrayListener>(),
+            stream::channel(
+                10,
+                |mut sender| async move {
+                    let mut tray_receiver = tray_receiver.write().await;
+
+                    while let Some(event) = tray_receiver.recv().await {
+                        if let Err(err) = sender.send(AppMsg::TrayEvent(event)).await {
+                            tracing::warn!("error occurred when receiving tray event {:?}", err)
+                        }
+                    }
+
+                    // tray is disabled, sender was dropped right away
+                    std::future::pending::<()>().await;
+
+                    unreachable!()
+                },
+            )
+        ),
         Subscription::run_with_id(
             std::any::TypeId::of::<RequestLoop>(),
             stream::channel(
                 100,
                 |sender| async move {
-                    request_loop(frontend_receiver, sender).await;
+                    request_loop(frontend_receiver, backend_api, image_cache, sender).await;
 
                     panic!("request_rx was unexpectedly closed")
                 },
@@ -2035,23 +3428,24 @@ impl AppModel {
     fn hide_window(&mut self) -> Task<AppMsg> {
         self.focused = false;
         self.opened = false;
+        self.command_palette = None;
 
         let mut commands = vec![];
 
         #[cfg(target_os = "linux")]
         if self.wayland {
             commands.push(
-                Task::done(AppMsg::LayerShell(layer_shell::LayerShellAppMsg::RemoveWindow(self.main_window_id)))
+                Task::done(AppMsg::LayerShell(layer_shell::LayerShellAppMsg::RemoveWindow(self.windows.main_window_id())))
             );
         } else {
             commands.push(
-                window::change_mode(self.main_window_id, Mode::Hidden)
+                window::change_mode(self.windows.main_window_id(), Mode::Hidden)
             );
         };
 
         #[cfg(not(target_os = "linux"))]
         commands.push(
-            window::change_mode(self.main_window_id, Mode::Hidden)
+            window::change_mode(self.windows.main_window_id(), Mode::Hidden)
         );
 
         #[cfg(target_os = "macos")]
@@ -2066,18 +3460,44 @@ impl AppModel {
             }
         }
 
+        commands.push(self.close_suspended_plugin_views());
+
         match &self.global_state {
             GlobalState::PluginView { plugin_view_data: PluginViewData { plugin_id, .. }, .. } => {
                 commands.push(self.close_plugin_view(plugin_id.clone()));
             }
-            GlobalState::MainView { .. } => {}
-            GlobalState::ErrorView { .. } => {}
+            GlobalState::MainView { .. } => {
+                self.last_plugin_view = None;
+            }
+            GlobalState::ErrorView { .. } => {
+                self.last_plugin_view = None;
+            }
         }
 
         Task::batch(commands)
     }
 
-    fn show_window(&mut self) -> Task<AppMsg> {
+    // window state is reset every time window is shown,
+    // if enabled in config, plugin view which was open when window was hidden is opened again
+    fn reopen_last_plugin_view(&self) -> Task<AppMsg> {
+        if !self.reopen_last_view {
+            return Task::none()
+        }
+
+        match &self.last_plugin_view {
+            None => Task::none(),
+            Some(plugin_view_data) => {
+                Task::done(AppMsg::OpenView {
+                    plugin_id: plugin_view_data.plugin_id.clone(),
+                    plugin_name: plugin_view_data.plugin_name.clone(),
+                    entrypoint_id: plugin_view_data.entrypoint_id.clone(),
+                    entrypoint_name: plugin_view_data.entrypoint_name.clone(),
+                })
+            }
+        }
+    }
+
+    fn show_window(&mut self, reopen_last_view: bool) -> Task<AppMsg> {
         if self.opened {
             return Task::none()
         }
@@ -2086,53 +3506,508 @@ impl AppModel {
 
         #[cfg(target_os = "linux")]
         let open_task =  if self.wayland {
-            let (_, open_task) = open_main_window_wayland(self.main_window_id);
+            let (_, open_task) = open_main_window_wayland(self.windows.main_window_id());
             open_task
         } else {
             Task::batch([
-                window::gain_focus(self.main_window_id),
-                window::change_mode(self.main_window_id, Mode::Windowed)
-            ])
+                window::gain_focus(self.windows.main_window_id()),
+                window::change_mode(self.windows.main_window_id(), Mode::Windowed)
+            ]).chain(detect_monitor(self.windows.main_window_id()))
         };
 
         #[cfg(not(target_os = "linux"))]
         let open_task = Task::batch([
-            window::gain_focus(self.main_window_id),
+            window::gain_focus(self.windows.main_window_id()),
             #[cfg(target_os = "macos")]
             match self.window_position_mode {
                 WindowPositionMode::Static => Task::none(),
-                WindowPositionMode::ActiveMonitor => window::move_to_active_monitor(self.main_window_id),
+                WindowPositionMode::ActiveMonitor => window::move_to_active_monitor(self.windows.main_window_id()),
             },
-            window::change_mode(self.main_window_id, Mode::Windowed)
-        ]);
+            window::change_mode(self.windows.main_window_id(), Mode::Windowed),
+            // level is set again after window is shown, so it is placed above fullscreen application which is currently active
+            apply_window_overlay(self.windows.main_window_id(), self.window_overlay),
+        ]).chain(detect_monitor(self.windows.main_window_id()));
+
+        let reopen_task = if reopen_last_view {
+            self.reopen_last_plugin_view()
+        } else {
+            Task::none()
+        };
 
         Task::batch([
             open_task,
-            self.reset_window_state()
+            self.reset_window_state(),
+            reopen_task,
         ])
     }
 
-    fn reset_window_state(&mut self) -> Task<AppMsg> {
-        self.prompt = "".to_string();
-
-        self.client_context.clear_all_inline_views();
+    fn back(&mut self) -> Task<AppMsg> {
+        // argument prompt is closed first, before going back in navigation
+        if self.argument_prompt.take().is_some() {
+            return match &self.global_state {
+                GlobalState::MainView { search_field_id, .. } => focus(search_field_id.clone()),
+                GlobalState::ErrorView { .. } => Task::none(),
+                GlobalState::PluginView { .. } => Task::none(),
+            }
+        }
 
-        GlobalState::initial(&mut self.global_state)
-    }
+        if self.command_palette.take().is_some() {
+            return Task::none()
+        }
 
-    fn open_plugin_view(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Task<AppMsg> {
-        let mut backend_client = self.backend_api.clone();
+        // view opened from command palette is closed by going back to the view that was open before it
+        if let GlobalState::PluginView { plugin_view_data, sub_state: PluginViewState::None } = &self.global_state {
+            if plugin_view_data.top_level_view {
+                if let Some(previous_plugin_view_data) = self.suspended_plugin_views.pop() {
+                    let task = self.close_plugin_view(plugin_view_data.plugin_id.clone());
 
-        Task::perform(async move {
-            let result = backend_client.request_view_render(plugin_id, entrypoint_id)
-                .await?;
+                    self.client_context.resume_view();
+                    self.last_plugin_view = Some(previous_plugin_view_data.clone());
 
-            Ok(result)
-        }, |result| handle_backend_error(result, |action_shortcuts| AppMsg::OnOpenView { action_shortcuts }))
-    }
+                    return Task::batch([
+                        GlobalState::plugin(&mut self.global_state, previous_plugin_view_data),
+                        task,
+                    ])
+                }
+            }
+        }
 
-    fn close_plugin_view(&self, plugin_id: PluginId) -> Task<AppMsg> {
-        let mut backend_client = self.backend_api.clone();
+        if let GlobalState::MainView { sub_state: MainViewState::None, plugin_scope, .. } = &self.global_state {
+            match self.escape_behavior {
+                UiEscapeBehavior::ClearThenHide if !self.prompt.is_empty() => {
+                    return Task::done(AppMsg::PromptChanged(String::new()))
+                }
+                UiEscapeBehavior::NeverHide if plugin_scope.is_none() => {
+                    return Task::none()
+                }
+                _ => {}
+            }
+        }
+
+        self.global_state.back(&self.client_context)
+    }
+
+    fn shortcut_hints_context(&self) -> ShortcutHintsContext {
+        match &self.global_state {
+            GlobalState::MainView { sub_state, plugin_scope, .. } => {
+                match sub_state {
+                    MainViewState::None => ShortcutHintsContext::MainView {
+                        inline_views: !self.client_context.get_all_inline_view_containers().is_empty(),
+                        plugin_scope: plugin_scope.is_some(),
+                        undo: self.undo_disable_entrypoint.is_some(),
+                    },
+                    MainViewState::SearchResultActionPanel { .. } | MainViewState::InlineViewActionPanel { .. } => ShortcutHintsContext::ActionPanel,
+                }
+            }
+            GlobalState::PluginView { plugin_view_data, sub_state } => {
+                match sub_state {
+                    PluginViewState::None => ShortcutHintsContext::PluginView {
+                        // going back from view opened using command palette returns to previous view, not to search
+                        top_level_view: plugin_view_data.top_level_view && self.suspended_plugin_views.is_empty(),
+                        tab_count: self.client_context.view_tab_count(),
+                    },
+                    PluginViewState::ActionPanel { .. } => ShortcutHintsContext::ActionPanel,
+                }
+            }
+            GlobalState::ErrorView { .. } => ShortcutHintsContext::ErrorView,
+        }
+    }
+
+    // keys which type text are not used as keymap shortcuts while the text goes into search bar or text field,
+    // text fields focused by user capture key presses themselves, so they are not received here
+    fn is_typing_into_text_field(&self, text: Option<&str>, modifiers: Modifiers) -> bool {
+        if modifiers.control() || modifiers.alt() || modifiers.logo() {
+            return false
+        }
+
+        let types_text = text.is_some_and(|text| text.chars().any(|char| !char.is_control()));
+
+        if !types_text {
+            return false
+        }
+
+        match &self.global_state {
+            GlobalState::MainView { sub_state: MainViewState::None, .. } => true,
+            GlobalState::PluginView { sub_state: PluginViewState::None, .. } => self.client_context.view_accepts_text(),
+            _ => false,
+        }
+    }
+
+    fn find_keymap_action(&self, physical_key: Physical, modifiers: Modifiers) -> Option<KeymapAction> {
+        let Physical::Code(physical_key) = physical_key else {
+            return None
+        };
+
+        let shortcut = physical_key_model(physical_key, modifiers)?;
+
+        self.keymap
+            .iter()
+            .find(|binding| binding.shortcut == shortcut)
+            .map(|binding| binding.action)
+    }
+
+    fn run_keymap_action(&mut self, action: KeymapAction) -> Task<AppMsg> {
+        match action {
+            KeymapAction::Next => self.global_state.down(&mut self.client_context, &self.search_results),
+            KeymapAction::Previous => self.global_state.up(&mut self.client_context, &self.search_results),
+            KeymapAction::Primary => self.global_state.primary(&self.client_context, &self.search_results),
+            KeymapAction::Secondary => self.global_state.secondary(&self.client_context, &self.search_results),
+            KeymapAction::ToggleActionPanel => Task::done(AppMsg::ToggleActionPanel { keyboard: true }),
+            KeymapAction::Back => self.back(),
+            KeymapAction::ToggleShortcutHints => Task::done(AppMsg::ToggleShortcutHints),
+            KeymapAction::TogglePin => Task::done(AppMsg::TogglePin),
+            KeymapAction::TogglePreview => Task::done(AppMsg::TogglePreviewPane),
+            KeymapAction::DetachView => Task::done(AppMsg::DetachPluginView),
+        }
+    }
+
+    fn reset_window_state(&mut self) -> Task<AppMsg> {
+        self.prompt = "".to_string();
+        self.argument_prompt = None;
+        self.deep_link_confirmation = None;
+        self.shortcut_hints = false;
+        self.result_preview = None;
+        self.quicklook = None;
+        self.navigated_results = false;
+        self.command_palette = None;
+
+        let close_suspended_views = self.close_suspended_plugin_views();
+
+        self.client_context.clear_all_inline_views();
+
+        Task::batch([
+            close_suspended_views,
+            GlobalState::initial(&mut self.global_state),
+        ])
+    }
+
+    fn close_suspended_plugin_views(&mut self) -> Task<AppMsg> {
+        self.suspended_plugin_views.clear();
+
+        let tasks: Vec<_> = self.client_context.clear_suspended_views()
+            .into_iter()
+            .map(|plugin_id| self.close_plugin_view(plugin_id))
+            .collect();
+
+        Task::batch(tasks)
+    }
+
+    // main view stays usable, banner is shown until backend responds again
+    fn reconnect_to_backend(&mut self) -> Task<AppMsg> {
+        if self.backend_reconnecting {
+            return Task::none()
+        }
+
+        tracing::warn!("Lost connection to backend, reconnecting");
+
+        self.backend_reconnecting = true;
+        self.update_tray_status();
+
+        let mut backend_client = self.backend_api.clone();
+
+        Task::perform(async move {
+            backend_client.reconnect()
+                .await;
+        }, |_| AppMsg::BackendReconnected)
+    }
+
+    fn update_tray_status(&self) {
+        let Some(tray) = &self.tray else {
+            return
+        };
+
+        let status = if self.backend_reconnecting {
+            TrayStatus::Reconnecting
+        } else if self.plugins_paused {
+            TrayStatus::PluginsPaused
+        } else {
+            TrayStatus::Running
+        };
+
+        tray.set_status(status);
+    }
+
+    fn disable_entrypoint(&mut self, search_result: &SearchResult) -> Task<AppMsg> {
+        let mut backend_api = self.backend_api.clone();
+
+        let disabled_entrypoint = DisabledEntrypoint {
+            plugin_id: search_result.plugin_id.clone(),
+            entrypoint_id: search_result.entrypoint_id.clone(),
+            entrypoint_name: search_result.entrypoint_name.clone(),
+        };
+
+        let close_action_panel = match &mut self.global_state {
+            GlobalState::MainView { sub_state, .. } => {
+                MainViewState::initial(sub_state);
+
+                Task::none()
+            }
+            GlobalState::ErrorView { .. } => Task::none(),
+            GlobalState::PluginView { .. } => Task::none(),
+        };
+
+        Task::batch([
+            close_action_panel,
+            Task::perform(async move {
+                backend_api.set_entrypoint_state(disabled_entrypoint.plugin_id.clone(), disabled_entrypoint.entrypoint_id.clone(), false)
+                    .await?;
+
+                Ok(disabled_entrypoint)
+            }, |result| handle_backend_error(result, |disabled_entrypoint| AppMsg::EntrypointDisabled(disabled_entrypoint))),
+        ])
+    }
+
+    fn enable_entrypoint(&self, disabled_entrypoint: DisabledEntrypoint) -> Task<AppMsg> {
+        let mut backend_api = self.backend_api.clone();
+
+        Task::perform(async move {
+            backend_api.set_entrypoint_state(disabled_entrypoint.plugin_id, disabled_entrypoint.entrypoint_id, true)
+                .await?;
+
+            Ok(())
+        }, |result| handle_backend_error(result, |()| AppMsg::UpdateSearchResults))
+    }
+
+    fn is_undo_shortcut(&self, physical_key: Physical, modifiers: Modifiers) -> bool {
+        let Physical::Code(physical_key) = physical_key else {
+            return false
+        };
+
+        physical_key_model(physical_key, modifiers) == Some(undo_shortcut())
+    }
+
+    fn is_debug_hud_shortcut(&self, physical_key: Physical, modifiers: Modifiers) -> bool {
+        let Physical::Code(physical_key) = physical_key else {
+            return false
+        };
+
+        physical_key_model(physical_key, modifiers) == Some(debug_hud_shortcut())
+    }
+
+    fn is_pin_shortcut(&self, physical_key: Physical, modifiers: Modifiers) -> bool {
+        let Physical::Code(physical_key) = physical_key else {
+            return false
+        };
+
+        physical_key_model(physical_key, modifiers) == Some(pin_shortcut())
+    }
+
+    fn is_toggle_preview_shortcut(&self, physical_key: Physical, modifiers: Modifiers) -> bool {
+        let Physical::Code(physical_key) = physical_key else {
+            return false
+        };
+
+        physical_key_model(physical_key, modifiers) == Some(toggle_preview_shortcut())
+    }
+
+    // preview failing to load is not shown as error, because it is not something user asked for directly
+    fn request_result_preview(&mut self) -> Task<AppMsg> {
+        if !self.preview_pane {
+            return Task::none()
+        }
+
+        let GlobalState::MainView { focused_search_result, .. } = &self.global_state else {
+            return Task::none()
+        };
+
+        let Some(search_result) = focused_search_result.get(&self.search_results) else {
+            self.result_preview = None;
+
+            return Task::none()
+        };
+
+        let already_requested = self.result_preview.as_ref()
+            .is_some_and(|preview| preview.is_for(&search_result.plugin_id, &search_result.entrypoint_id));
+
+        if already_requested {
+            return Task::none()
+        }
+
+        let plugin_id = search_result.plugin_id.clone();
+        let entrypoint_id = search_result.entrypoint_id.clone();
+
+        self.result_preview = Some(ResultPreview::loading(plugin_id.clone(), entrypoint_id.clone()));
+
+        let mut backend_api = self.backend_api.clone();
+
+        Task::perform(async move {
+            let preview = backend_api.result_preview(plugin_id.clone(), entrypoint_id.clone())
+                .await
+                .unwrap_or_else(|err| {
+                    tracing::warn!("Unable to load preview of search result: {:?}", err);
+
+                    None
+                });
+
+            AppMsg::SetResultPreview { plugin_id, entrypoint_id, preview }
+        }, std::convert::identity)
+    }
+
+    fn update_accessibility_tree(&mut self) -> Task<AppMsg> {
+        let view = match &self.global_state {
+            GlobalState::MainView { focused_search_result, sub_state, .. } => {
+                let results = self.search_results
+                    .iter()
+                    .map(|search_result| AccessibleItem {
+                        label: search_result.entrypoint_name.clone(),
+                        description: Some(search_result.plugin_name.clone()),
+                    })
+                    .collect();
+
+                let action_panel = match (sub_state, focused_search_result.get(&self.search_results)) {
+                    (MainViewState::SearchResultActionPanel { focused_action_item }, Some(search_result)) => {
+                        Some(AccessibleActionPanel {
+                            title: search_result.entrypoint_name.clone(),
+                            actions: action_labels(search_result),
+                            focused_action: focused_action_item.index,
+                        })
+                    }
+                    _ => None
+                };
+
+                AccessibleView::Main {
+                    prompt: self.prompt.clone(),
+                    results,
+                    focused_result: focused_search_result.index,
+                    action_panel,
+                }
+            }
+            GlobalState::ErrorView { .. } => AccessibleView::Other { title: tr!("error-occurred") },
+            GlobalState::PluginView { plugin_view_data, .. } => AccessibleView::Other { title: plugin_view_data.entrypoint_name.clone() },
+        };
+
+        if self.accessible_view.as_ref() == Some(&view) {
+            return Task::none()
+        }
+
+        let tree = accessibility::build_tree(&view);
+
+        self.accessible_view = Some(view);
+
+        accessibility::update_tree(self.windows.main_window_id(), tree)
+    }
+
+    fn focused_search_result_file(&self) -> Option<String> {
+        let GlobalState::MainView { focused_search_result, sub_state: MainViewState::None, .. } = &self.global_state else {
+            return None
+        };
+
+        focused_search_result.get(&self.search_results)?
+            .entrypoint_file
+            .clone()
+    }
+
+    fn open_quicklook(&mut self) -> Task<AppMsg> {
+        let Some(path) = self.focused_search_result_file() else {
+            return Task::none()
+        };
+
+        self.quicklook = Some(Quicklook::loading(path.clone()));
+
+        let dark = matches!(self.ui_theme.mode, UiThemeMode::Dark);
+
+        let mut backend_api = self.backend_api.clone();
+
+        Task::perform(async move {
+            let quicklook = backend_api.file_quicklook(path.clone(), dark)
+                .await
+                .map_err(|err| tracing::warn!("Unable to load quicklook of file {}: {:?}", path, err))
+                .ok();
+
+            AppMsg::SetQuicklook { path, quicklook }
+        }, std::convert::identity)
+    }
+
+    // quicklook is closed when focused result doesn't represent a file
+    fn follow_quicklook(&mut self) -> Task<AppMsg> {
+        let Some(quicklook) = &self.quicklook else {
+            return Task::none()
+        };
+
+        match self.focused_search_result_file() {
+            Some(path) if path == quicklook.path => Task::none(),
+            Some(_) => self.open_quicklook(),
+            None => {
+                self.quicklook = None;
+
+                Task::none()
+            }
+        }
+    }
+
+    fn is_detach_view_shortcut(&self, physical_key: Physical, modifiers: Modifiers) -> bool {
+        let Physical::Code(physical_key) = physical_key else {
+            return false
+        };
+
+        physical_key_model(physical_key, modifiers) == Some(detach_view_shortcut())
+    }
+
+    fn is_plugin_view_in_main_window(&self, plugin_id: &PluginId) -> bool {
+        match &self.global_state {
+            GlobalState::MainView { pending_plugin_view_data: Some(plugin_view_data), .. } => &plugin_view_data.plugin_id == plugin_id,
+            GlobalState::PluginView { plugin_view_data, .. } => &plugin_view_data.plugin_id == plugin_id,
+            _ => false,
+        }
+    }
+
+    fn set_plugins_paused(&self, paused: bool) -> Task<AppMsg> {
+        let mut backend_api = self.backend_api.clone();
+
+        Task::perform(async move {
+            backend_api.set_plugins_paused(paused)
+                .await?;
+
+            Ok(paused)
+        }, |result| handle_backend_error(result, |paused| AppMsg::PluginsPausedChanged(paused)))
+    }
+
+    fn set_offline_mode(&self, offline_mode: bool) -> Task<AppMsg> {
+        let mut backend_api = self.backend_api.clone();
+
+        Task::perform(async move {
+            backend_api.set_offline_mode(offline_mode)
+                .await?;
+
+            Ok(offline_mode)
+        }, |result| handle_backend_error(result, |offline_mode| AppMsg::OfflineModeChanged(offline_mode)))
+    }
+
+    fn set_focus_mode(&self, focus_mode: bool) -> Task<AppMsg> {
+        let mut backend_api = self.backend_api.clone();
+
+        Task::perform(async move {
+            backend_api.set_focus_mode(focus_mode)
+                .await?;
+
+            Ok(focus_mode)
+        }, |result| handle_backend_error(result, |focus_mode| AppMsg::FocusModeChanged(focus_mode)))
+    }
+
+    fn open_plugin_view(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Task<AppMsg> {
+        let mut backend_client = self.backend_api.clone();
+
+        Task::perform(async move {
+            let result = backend_client.request_view_render(plugin_id, entrypoint_id)
+                .await?;
+
+            Ok(result)
+        }, |result| handle_backend_error(result, |action_shortcuts| AppMsg::OnOpenView { action_shortcuts }))
+    }
+
+    fn pop_plugin_view(&self, plugin_id: PluginId) -> Task<AppMsg> {
+        let mut backend_client = self.backend_api.clone();
+
+        Task::perform(async move {
+            backend_client.request_view_pop(plugin_id)
+                .await?;
+
+            Ok(())
+        }, |result| handle_backend_error(result, |()| AppMsg::Noop))
+    }
+
+    fn close_plugin_view(&self, plugin_id: PluginId) -> Task<AppMsg> {
+        let mut backend_client = self.backend_api.clone();
 
         Task::perform(async move {
             backend_client.request_view_close(plugin_id)
@@ -2142,11 +4017,11 @@ impl AppModel {
         }, |result| handle_backend_error(result, |()| AppMsg::Noop))
     }
 
-    fn run_command(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Task<AppMsg> {
+    fn run_command(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, arguments: HashMap<String, String>) -> Task<AppMsg> {
         let mut backend_client = self.backend_api.clone();
 
         Task::perform(async move {
-            backend_client.request_run_command(plugin_id, entrypoint_id)
+            backend_client.request_run_command(plugin_id, entrypoint_id, arguments)
                 .await?;
 
             Ok(())
@@ -2233,7 +4108,7 @@ impl AppModel {
         let mut backend_client = self.backend_api.clone();
 
         let (plugin_id, entrypoint_id) = {
-            match self.client_context.get_first_inline_view_container() {
+            match self.client_context.get_focused_inline_view_container() {
                 None => {
                     return Task::none()
                 },
@@ -2252,15 +4127,118 @@ impl AppModel {
         )
     }
 
+    // items of collapsed plugin groups and items over per plugin limit are skipped by focus navigation,
+    // recent section and fallbacks are never grouped or limited
+    fn update_visible_search_results(&mut self) {
+        let grouped = self.search_results_sort == SearchResultsSort::Plugin && !self.fallback_search_results;
+
+        let limit = self.results_per_plugin_limit();
+
+        let (positions, totals) = plugin_result_positions(&self.all_search_results, self.recent_search_results_count);
+
+        let visible_search_results: Vec<_> = self.all_search_results
+            .iter()
+            .enumerate()
+            .filter(|(index, search_result)| !grouped || *index < self.recent_search_results_count || !self.collapsed_plugin_groups.contains(&search_result.plugin_id))
+            .filter(|(index, _)| match (limit, positions[*index]) {
+                (Some(limit), Some(position)) => position < limit,
+                _ => true
+            })
+            .collect();
+
+        // "Show all results" row is shown under the last shown result of plugin which has more results
+        let new_show_all_rows: HashMap<_, _> = visible_search_results
+            .iter()
+            .enumerate()
+            .filter_map(|(visible_index, (index, search_result))| match (limit, positions[*index]) {
+                (Some(limit), Some(position)) if position + 1 == limit && totals[&search_result.plugin_id] > limit => {
+                    let plugin_scope = PluginScope {
+                        plugin_id: search_result.plugin_id.clone(),
+                        plugin_name: search_result.plugin_name.clone(),
+                    };
+
+                    Some((visible_index, plugin_scope))
+                }
+                _ => None
+            })
+            .collect();
+
+        self.search_results = visible_search_results
+            .into_iter()
+            .map(|(_, search_result)| search_result.clone())
+            .collect();
+
+        if let GlobalState::MainView { focused_search_result, show_all_focused, show_all_rows, .. } = &mut self.global_state {
+            *show_all_focused = *show_all_focused && focused_search_result.index.is_some_and(|index| new_show_all_rows.contains_key(&index));
+            *show_all_rows = new_show_all_rows;
+        }
+    }
+
+    // results are not limited when all entrypoints are listed, when results are already restricted to one plugin or when they are fallbacks
+    fn results_per_plugin_limit(&self) -> Option<usize> {
+        let plugin_scope = matches!(self.global_state, GlobalState::MainView { plugin_scope: Some(_), .. });
+
+        let (text, filter) = parse_search_query(&self.prompt);
+
+        if plugin_scope || filter.plugin_name.is_some() || text.trim().is_empty() || self.fallback_search_results {
+            None
+        } else {
+            self.results_per_plugin
+        }
+    }
+
     fn search(&self, new_prompt: String, render_inline_view: bool) -> Task<AppMsg> {
         let mut backend_api = self.backend_api.clone();
 
+        let (text, mut filter) = parse_search_query(&new_prompt);
+
+        if let GlobalState::MainView { plugin_scope: Some(plugin_scope), .. } = &self.global_state {
+            filter.plugin_id = Some(plugin_scope.plugin_id.clone());
+        }
+
+        Task::perform(async move {
+            let start = Instant::now();
+
+            let (results, recent_count, fallback) = backend_api.search(text, filter, render_inline_view)
+                .await?;
+
+            Ok((results, recent_count, fallback, start.elapsed()))
+        }, move |result| handle_backend_error(result, |(results, recent_count, fallback, duration)| AppMsg::SetSearchResults { query: new_prompt, results, recent_count, fallback, duration }))
+    }
+
+    fn search_command_palette(&self, query: String) -> Task<AppMsg> {
+        let mut backend_api = self.backend_api.clone();
+
+        let (text, filter) = parse_search_query(&query);
+
+        Task::perform(async move {
+            let (results, _, _) = backend_api.search(text, filter, false)
+                .await?;
+
+            Ok(results)
+        }, |result| handle_backend_error(result, |results| AppMsg::SetCommandPaletteResults { query, results }))
+    }
+
+    fn open_settings_window(&self) -> Task<AppMsg> {
+        let mut backend_api = self.backend_api.clone();
+
         Task::perform(async move {
-            let search_results = backend_api.search(new_prompt, render_inline_view)
+            backend_api.open_settings_window()
                 .await?;
 
-            Ok(search_results)
-        }, |result| handle_backend_error(result, |search_results| AppMsg::SetSearchResults(search_results)))
+            Ok(())
+        }, |result| handle_backend_error(result, |()| AppMsg::Noop))
+    }
+
+    fn run_confirmed_deep_link(&self, uri: String) -> Task<AppMsg> {
+        let mut backend_api = self.backend_api.clone();
+
+        Task::perform(async move {
+            backend_api.run_confirmed_deep_link(uri)
+                .await?;
+
+            Ok(())
+        }, |result| handle_backend_error(result, |()| AppMsg::Noop))
     }
 
     fn open_settings_window_preferences(&self, plugin_id: PluginId, entrypoint_id: Option<EntrypointId>) -> Task<AppMsg> {
@@ -2274,6 +4252,17 @@ impl AppModel {
         }, |result| handle_backend_error(result, |()| AppMsg::Noop))
     }
 
+    fn complete_plugin_onboarding(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Task<AppMsg> {
+        let mut backend_api = self.backend_api.clone();
+
+        Task::perform(async move {
+            backend_api.complete_plugin_onboarding(plugin_id, entrypoint_id)
+                .await?;
+
+            Ok(())
+        }, |result| handle_backend_error(result, |()| AppMsg::Noop))
+    }
+
     fn inline_view_shortcuts(&self) -> Task<AppMsg> {
         let mut backend_api = self.backend_api.clone();
 
@@ -2292,8 +4281,14 @@ impl AppModel {
         } else {
             match value {
                 Some(value) => {
-                    if let Some(value) = value.chars().next().filter(|c| !c.is_control()) {
-                        *prompt = format!("{}{}", prompt, value);
+                    // committed text can contain multiple characters,
+                    // e.g. when dead key is followed by key it cannot be combined with
+                    let value: String = value.chars()
+                        .filter(|c| !c.is_control())
+                        .collect();
+
+                    if !value.is_empty() {
+                        prompt.push_str(&value);
                         focus(search_field_id.clone())
                     } else {
                         Task::none()
@@ -2320,8 +4315,32 @@ fn handle_backend_error<T>(result: Result<T, BackendForFrontendApiError>, conver
     }
 }
 
+// ctrl+1..9 switch tabs of plugin view
+fn tab_index(physical_key: &PhysicalKey) -> Option<usize> {
+    match physical_key {
+        PhysicalKey::Digit1 => Some(0),
+        PhysicalKey::Digit2 => Some(1),
+        PhysicalKey::Digit3 => Some(2),
+        PhysicalKey::Digit4 => Some(3),
+        PhysicalKey::Digit5 => Some(4),
+        PhysicalKey::Digit6 => Some(5),
+        PhysicalKey::Digit7 => Some(6),
+        PhysicalKey::Digit8 => Some(7),
+        PhysicalKey::Digit9 => Some(8),
+        _ => None
+    }
+}
+
+fn search_result_keys(search_results: &[SearchResult]) -> Vec<(PluginId, EntrypointId)> {
+    search_results.iter()
+        .map(|search_result| (search_result.plugin_id.clone(), search_result.entrypoint_id.clone()))
+        .collect()
+}
+
 async fn request_loop(
     frontend_receiver: Arc<TokioRwLock<RequestReceiver<UiRequestData, UiResponseData>>>,
+    backend_api: BackendForFrontendApi,
+    image_cache: ImageCache,
     mut sender: Sender<AppMsg>,
 ) {
     let mut frontend_receiver = frontend_receiver.write().await;
@@ -2337,11 +4356,16 @@ async fn request_loop(
                     entrypoint_name,
                     render_location,
                     top_level_view,
+                    breadcrumbs,
+                    key_handlers,
                     container,
                     images
                 } => {
                     responder.respond(UiResponseData::Nothing);
 
+                    // requests are processed one by one, so view is not shown until its images are available
+                    let images = image_cache.resolve(&backend_api, images).await;
+
                     AppMsg::RenderPluginUI {
                         plugin_id,
                         plugin_name,
@@ -2349,6 +4373,8 @@ async fn request_loop(
                         entrypoint_name,
                         render_location,
                         top_level_view,
+                        breadcrumbs,
+                        key_handlers,
                         container: Arc::new(container),
                         images
                     }
@@ -2360,29 +4386,70 @@ async fn request_loop(
                         plugin_id
                     }
                 }
+                UiRequestData::OpenPluginView { plugin_id, plugin_name, entrypoint_id, entrypoint_name } => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::OpenViewFromDeepLink {
+                        plugin_id,
+                        plugin_name,
+                        entrypoint_id,
+                        entrypoint_name,
+                    }
+                }
+                UiRequestData::ShowWindowWithSearchQuery { query } => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::ShowWindowWithSearchQuery {
+                        query
+                    }
+                }
+                UiRequestData::ConfirmDeepLink { uri, plugin_name, entrypoint_name } => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::ConfirmDeepLink {
+                        uri,
+                        plugin_name,
+                        entrypoint_name,
+                    }
+                }
                 UiRequestData::ShowWindow => {
                     responder.respond(UiResponseData::Nothing);
 
                     AppMsg::ShowWindow
                 }
+                UiRequestData::ShowSettingsWindow { plugin_id, entrypoint_id } => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::ShowSettingsWindow {
+                        plugin_id,
+                        entrypoint_id,
+                    }
+                }
                 UiRequestData::HideWindow => {
                     responder.respond(UiResponseData::Nothing);
 
                     AppMsg::HideWindow
                 }
-                UiRequestData::ShowPreferenceRequiredView {
+                UiRequestData::ToggleWindow => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::ToggleWindow
+                }
+                UiRequestData::ShowOnboardingView {
                     plugin_id,
+                    plugin_name,
                     entrypoint_id,
-                    plugin_preferences_required,
-                    entrypoint_preferences_required
+                    description,
+                    preferences
                 } => {
                     responder.respond(UiResponseData::Nothing);
 
-                    AppMsg::ShowPreferenceRequiredView {
+                    AppMsg::ShowOnboardingView {
                         plugin_id,
+                        plugin_name,
                         entrypoint_id,
-                        plugin_preferences_required,
-                        entrypoint_preferences_required
+                        description,
+                        preferences
                     }
                 }
                 UiRequestData::ShowPluginErrorView { plugin_id, entrypoint_id, render_location } => {
@@ -2435,6 +4502,41 @@ async fn request_loop(
                         mode,
                     }
                 }
+                UiRequestData::SetDensity { density } => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::SetDensity {
+                        density,
+                    }
+                }
+                UiRequestData::SetUiScale { scale } => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::SetUiScale {
+                        scale,
+                    }
+                }
+                UiRequestData::SetWindowOverlay { enabled } => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::SetWindowOverlay {
+                        enabled,
+                    }
+                }
+                UiRequestData::SetSearchResultsSort { sort } => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::SetSearchResultsSort {
+                        sort,
+                    }
+                }
+                UiRequestData::SetTrayTooltip { text } => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::SetTrayTooltip {
+                        text,
+                    }
+                }
             }
         };
 
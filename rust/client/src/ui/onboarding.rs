@@ -0,0 +1,220 @@
+use gauntlet_common::model::{EntrypointId, OnboardingPreference, PluginId};
+use gauntlet_common::tr;
+use iced::font::Weight;
+use iced::widget::text::Shaping;
+use iced::widget::{button, column, container, horizontal_space, row, scrollable, text, value, Space};
+use iced::{Alignment, Font, Length};
+use iced_fonts::{Bootstrap, BOOTSTRAP_FONT};
+
+use crate::ui::direction::directional;
+use crate::ui::fonts::default_font;
+use crate::ui::theme::button::ButtonStyle;
+use crate::ui::theme::container::ContainerStyle;
+use crate::ui::theme::text::TextStyle;
+use crate::ui::theme::{Element, ThemableWidget};
+use crate::ui::AppMsg;
+
+// shown instead of plugin view or command the first time plugin is used,
+// and every time any of required preferences is missing
+pub fn onboarding_view<'a>(
+    plugin_id: &PluginId,
+    plugin_name: &str,
+    entrypoint_id: &EntrypointId,
+    description: &Option<String>,
+    preferences: &[OnboardingPreference],
+) -> Element<'a, AppMsg> {
+    let title: Element<_> = text(tr!("onboarding-title", name = plugin_name.to_string()))
+        .shaping(Shaping::Advanced)
+        .size(20)
+        .font(Font {
+            weight: Weight::Bold,
+            ..default_font()
+        })
+        .into();
+
+    let mut content = vec![title];
+
+    if let Some(description) = description {
+        content.extend(description_view(description));
+    }
+
+    if !preferences.is_empty() {
+        let section_title: Element<_> = text(tr!("onboarding-required-preferences"))
+            .shaping(Shaping::Advanced)
+            .size(15)
+            .themed(TextStyle::ListSectionTitle);
+
+        content.push(section_title);
+
+        for preference in preferences {
+            content.push(preference_item(plugin_id, preference));
+        }
+    }
+
+    let content: Element<_> = column(content)
+        .spacing(12.0)
+        .into();
+
+    let content: Element<_> = scrollable(content)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into();
+
+    let first_missing = preferences.iter()
+        .find(|preference| !preference.filled);
+
+    let open_settings_msg = AppMsg::OpenSettingsPreferences {
+        plugin_id: plugin_id.clone(),
+        entrypoint_id: first_missing.and_then(|preference| preference.entrypoint_id.clone()),
+    };
+
+    let open_settings_label: Element<_> = text(tr!("preferences-open-settings"))
+        .into();
+
+    let open_settings: Element<_> = button(open_settings_label)
+        .on_press(open_settings_msg)
+        .into();
+
+    let continue_label: Element<_> = text(tr!("onboarding-continue"))
+        .into();
+
+    // entrypoint can't be run until all required preferences are set
+    let continue_msg = first_missing
+        .is_none()
+        .then(|| AppMsg::CompletePluginOnboarding {
+            plugin_id: plugin_id.clone(),
+            entrypoint_id: entrypoint_id.clone(),
+        });
+
+    let continue_button: Element<_> = button(continue_label)
+        .on_press_maybe(continue_msg)
+        .into();
+
+    let mut buttons = vec![horizontal_space().into()];
+
+    if !preferences.is_empty() {
+        buttons.push(open_settings);
+    }
+
+    buttons.push(continue_button);
+
+    let buttons: Element<_> = row(directional(buttons))
+        .spacing(8.0)
+        .into();
+
+    let content: Element<_> = column([content, buttons])
+        .spacing(12.0)
+        .into();
+
+    let content: Element<_> = container(content)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .themed(ContainerStyle::PreferenceRequiredViewDescription);
+
+    container(content)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .themed(ContainerStyle::Main)
+}
+
+fn preference_item<'a>(plugin_id: &PluginId, preference: &OnboardingPreference) -> Element<'a, AppMsg> {
+    let icon = if preference.filled {
+        Bootstrap::CheckCircleFill
+    } else {
+        Bootstrap::Circle
+    };
+
+    let icon: Element<_> = value(icon)
+        .font(BOOTSTRAP_FONT)
+        .into();
+
+    let name: Element<_> = text(preference.name.clone())
+        .shaping(Shaping::Advanced)
+        .into();
+
+    let mut label = vec![name];
+
+    if !preference.description.is_empty() {
+        let description: Element<_> = text(preference.description.clone())
+            .shaping(Shaping::Advanced)
+            .size(12)
+            .themed(TextStyle::MainListItemSubtext);
+
+        label.push(description);
+    }
+
+    let label: Element<_> = column(label)
+        .into();
+
+    let content: Element<_> = row(directional(vec![icon, label]))
+        .spacing(12.0)
+        .align_y(Alignment::Center)
+        .into();
+
+    button(content)
+        .width(Length::Fill)
+        .on_press(AppMsg::OpenSettingsPreferences {
+            plugin_id: plugin_id.clone(),
+            entrypoint_id: preference.entrypoint_id.clone(),
+        })
+        .themed(ButtonStyle::MainListItem)
+}
+
+// only subset of markdown is supported: headings, list items and paragraphs, also used by preview pane
+pub fn description_view<'a>(description: &str) -> Vec<Element<'a, AppMsg>> {
+    let mut result = vec![];
+    let mut paragraph = vec![];
+
+    for line in description.lines() {
+        let line = line.trim();
+
+        if line.is_empty() {
+            flush(&mut paragraph, &mut result);
+        } else if let Some(heading) = line.strip_prefix('#') {
+            flush(&mut paragraph, &mut result);
+
+            let heading: Element<_> = text(heading.trim_start_matches('#').trim().to_string())
+                .shaping(Shaping::Advanced)
+                .size(16)
+                .font(Font {
+                    weight: Weight::Bold,
+                    ..default_font()
+                })
+                .into();
+
+            result.push(heading);
+        } else if let Some(item) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+            flush(&mut paragraph, &mut result);
+
+            let bullet: Element<_> = text("•")
+                .into();
+
+            let item: Element<_> = text(item.to_string())
+                .shaping(Shaping::Advanced)
+                .into();
+
+            let item: Element<_> = row(directional(vec![Space::with_width(8.0).into(), bullet, item]))
+                .spacing(8.0)
+                .into();
+
+            result.push(item);
+        } else {
+            paragraph.push(line);
+        }
+    }
+
+    flush(&mut paragraph, &mut result);
+
+    result
+}
+
+fn flush<'a>(paragraph: &mut Vec<&str>, result: &mut Vec<Element<'a, AppMsg>>) {
+    if !paragraph.is_empty() {
+        let content: Element<_> = text(paragraph.join(" "))
+            .shaping(Shaping::Advanced)
+            .into();
+
+        result.push(content);
+        paragraph.clear();
+    }
+}
@@ -0,0 +1,147 @@
+use gauntlet_common::model::{FileQuicklook, FileQuicklookSpan};
+use gauntlet_common::tr;
+use iced::font::Weight;
+use iced::widget::image::Handle;
+use iced::widget::text::{Shaping, Span};
+use iced::widget::{column, container, image, mouse_area, rich_text, scrollable, span, text, Space};
+use iced::{Color, Font, Length};
+
+use crate::ui::fonts::{default_font, monospace_font};
+use crate::ui::theme::container::ContainerStyle;
+use crate::ui::theme::text::TextStyle;
+use crate::ui::theme::{Element, ThemableWidget};
+use crate::ui::AppMsg;
+
+// file of focused search result shown on top of main view, opened and closed with space bar.
+// arrow keys still move focus in the list and quicklook follows it
+pub struct Quicklook {
+    pub path: String,
+    content: QuicklookContent,
+}
+
+enum QuicklookContent {
+    Loading,
+    Failed,
+    Image(Handle),
+    Text {
+        lines: Vec<Vec<FileQuicklookSpan>>,
+        truncated: bool,
+    },
+    TooLarge {
+        size: u64,
+    },
+    Unsupported,
+}
+
+impl Quicklook {
+    pub fn loading(path: String) -> Self {
+        Self {
+            path,
+            content: QuicklookContent::Loading,
+        }
+    }
+
+    pub fn set_loaded(&mut self, quicklook: Option<FileQuicklook>) {
+        self.content = match quicklook {
+            None => QuicklookContent::Failed,
+            Some(FileQuicklook::Image { data, .. }) => QuicklookContent::Image(Handle::from_bytes(data)),
+            Some(FileQuicklook::Text { lines, truncated, .. }) => QuicklookContent::Text { lines, truncated },
+            Some(FileQuicklook::TooLarge { size, .. }) => QuicklookContent::TooLarge { size },
+            Some(FileQuicklook::Unsupported { .. }) => QuicklookContent::Unsupported,
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, AppMsg> {
+        let name = std::path::Path::new(&self.path)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| self.path.clone());
+
+        let title: Element<_> = text(name)
+            .shaping(Shaping::Advanced)
+            .font(Font {
+                weight: Weight::Bold,
+                ..default_font()
+            })
+            .into();
+
+        let path: Element<_> = text(&self.path)
+            .shaping(Shaping::Advanced)
+            .size(12)
+            .themed(TextStyle::MainListItemSubtext);
+
+        let content: Element<_> = match &self.content {
+            QuicklookContent::Loading => Space::with_height(Length::Fill).into(),
+            QuicklookContent::Failed => message(tr!("quicklook-failed")),
+            QuicklookContent::Unsupported => message(tr!("quicklook-unsupported")),
+            QuicklookContent::TooLarge { size } => message(tr!("quicklook-too-large", size = size / (1024 * 1024))),
+            QuicklookContent::Image(handle) => {
+                let image: Element<_> = image(handle.clone())
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .into();
+
+                image
+            }
+            QuicklookContent::Text { lines, truncated } => {
+                let mut content: Vec<Element<_>> = lines.iter()
+                    .map(|line| {
+                        let line: Element<_> = rich_text(line.iter().map(line_span).collect::<Vec<_>>())
+                            .font(monospace_font())
+                            .size(12)
+                            .into();
+
+                        line
+                    })
+                    .collect();
+
+                if *truncated {
+                    content.push(message(tr!("quicklook-truncated")));
+                }
+
+                let content: Element<_> = column(content)
+                    .width(Length::Fill)
+                    .into();
+
+                scrollable(content)
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .into()
+            }
+        };
+
+        let content: Element<_> = column([title, path, content])
+            .spacing(4.0)
+            .into();
+
+        let panel: Element<_> = container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .themed(ContainerStyle::ActionPanel);
+
+        let panel: Element<_> = container(panel)
+            .padding(24.0)
+            .center(Length::Fill)
+            .into();
+
+        // clicking anywhere closes it, same as space bar
+        mouse_area(panel)
+            .on_press(AppMsg::CloseQuicklook)
+            .into()
+    }
+}
+
+fn line_span(value: &FileQuicklookSpan) -> Span<'_> {
+    let result = span(&value.text);
+
+    match value.color {
+        Some((r, g, b)) => result.color(Color::from_rgb8(r, g, b)),
+        None => result,
+    }
+}
+
+fn message<'a>(value: String) -> Element<'a, AppMsg> {
+    text(value)
+        .shaping(Shaping::Advanced)
+        .themed(TextStyle::MainListItemSubtext)
+}
@@ -0,0 +1,38 @@
+use iced::system;
+use iced::Task;
+
+use gauntlet_common::model::{UiRenderer, UiRendererInfo};
+
+use crate::ui::AppMsg;
+
+// iced selects renderer and wgpu backend using environment variables,
+// variables set by user take priority over config
+pub fn configure_renderer(renderer: UiRenderer, wgpu_backend: &Option<String>) {
+    if std::env::var_os("ICED_BACKEND").is_none() {
+        match renderer {
+            // iced tries gpu renderer first and falls back to software renderer if surface cannot be created
+            UiRenderer::Auto => {}
+            UiRenderer::Wgpu => std::env::set_var("ICED_BACKEND", "wgpu,tiny-skia"),
+            UiRenderer::TinySkia => std::env::set_var("ICED_BACKEND", "tiny-skia"),
+        }
+    }
+
+    if std::env::var_os("WGPU_BACKEND").is_none() {
+        if let Some(wgpu_backend) = wgpu_backend {
+            std::env::set_var("WGPU_BACKEND", wgpu_backend);
+        }
+    }
+}
+
+// renderer is only known after it is created, so it is reported to server when application has started
+pub fn detect_renderer() -> Task<AppMsg> {
+    system::fetch_information()
+        .map(|information| {
+            AppMsg::RendererDetected {
+                renderer: UiRendererInfo {
+                    backend: information.graphics_backend,
+                    adapter: information.graphics_adapter,
+                }
+            }
+        })
+}
@@ -0,0 +1,196 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use gauntlet_common::model::{EntrypointId, PhysicalKey, PhysicalShortcut, PluginId, SearchResultPreview};
+use gauntlet_common::tr;
+use iced::font::Weight;
+use iced::widget::text::Shaping;
+use iced::widget::{column, container, scrollable, text, Space};
+use iced::{Font, Length};
+
+use crate::ui::fonts::{default_font, monospace_font};
+use crate::ui::onboarding::description_view;
+use crate::ui::theme::container::ContainerStyle;
+use crate::ui::theme::text::TextStyle;
+use crate::ui::theme::{Element, ThemableWidget};
+use crate::ui::AppMsg;
+
+// preview of focused search result, requested again when focus moves to another result
+pub struct ResultPreview {
+    pub plugin_id: PluginId,
+    pub entrypoint_id: EntrypointId,
+    pub content: ResultPreviewContent,
+}
+
+pub enum ResultPreviewContent {
+    Loading,
+    Empty,
+    Loaded(SearchResultPreview),
+}
+
+impl ResultPreview {
+    pub fn loading(plugin_id: PluginId, entrypoint_id: EntrypointId) -> Self {
+        Self {
+            plugin_id,
+            entrypoint_id,
+            content: ResultPreviewContent::Loading,
+        }
+    }
+
+    pub fn is_for(&self, plugin_id: &PluginId, entrypoint_id: &EntrypointId) -> bool {
+        &self.plugin_id == plugin_id && &self.entrypoint_id == entrypoint_id
+    }
+
+    pub fn view(&self) -> Element<'_, AppMsg> {
+        let content: Vec<Element<_>> = match &self.content {
+            // nothing is shown while loading, so that pane doesn't flicker when moving through results quickly
+            ResultPreviewContent::Loading => vec![],
+            ResultPreviewContent::Empty => {
+                let label: Element<_> = text(tr!("preview-empty"))
+                    .shaping(Shaping::Advanced)
+                    .themed(TextStyle::MainListItemSubtext);
+
+                vec![label]
+            }
+            ResultPreviewContent::Loaded(SearchResultPreview::File { path, size, modified, content }) => {
+                let mut result = vec![
+                    title(file_name(path)),
+                    detail(path.clone()),
+                    detail(tr!("preview-file-size", size = format_size(*size))),
+                ];
+
+                if let Some(modified) = modified {
+                    result.push(detail(tr!("preview-file-modified", age = format_age(*modified))));
+                }
+
+                result.push(Space::with_height(8.0).into());
+
+                match content {
+                    Some(content) => {
+                        let content: Element<_> = text(content.clone())
+                            .shaping(Shaping::Advanced)
+                            .font(monospace_font())
+                            .size(12)
+                            .into();
+
+                        result.push(content);
+                    }
+                    None => result.push(detail(tr!("preview-file-binary"))),
+                }
+
+                result
+            }
+            ResultPreviewContent::Loaded(SearchResultPreview::Folder { path, entries, entry_count }) => {
+                let mut result = vec![
+                    title(file_name(path)),
+                    detail(path.clone()),
+                    detail(tr!("preview-folder-items", count = *entry_count)),
+                    Space::with_height(8.0).into(),
+                ];
+
+                for entry in entries {
+                    let entry: Element<_> = text(entry.clone())
+                        .shaping(Shaping::Advanced)
+                        .into();
+
+                    result.push(entry);
+                }
+
+                if *entry_count > entries.len() {
+                    result.push(detail(tr!("preview-folder-more", count = *entry_count - entries.len())));
+                }
+
+                result
+            }
+            ResultPreviewContent::Loaded(SearchResultPreview::Markdown { content }) => {
+                description_view(content)
+            }
+        };
+
+        let content: Element<_> = column(content)
+            .spacing(4.0)
+            .width(Length::Fill)
+            .into();
+
+        let content: Element<_> = scrollable(content)
+            .width(Length::Fill)
+            .into();
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .themed(ContainerStyle::DetailContent)
+    }
+}
+
+pub fn toggle_preview_shortcut() -> PhysicalShortcut {
+    PhysicalShortcut {
+        physical_key: PhysicalKey::KeyI,
+        modifier_shift: false,
+        modifier_control: false,
+        modifier_alt: true,
+        modifier_meta: false,
+    }
+}
+
+fn title<'a>(value: String) -> Element<'a, AppMsg> {
+    text(value)
+        .shaping(Shaping::Advanced)
+        .size(16)
+        .font(Font {
+            weight: Weight::Bold,
+            ..default_font()
+        })
+        .into()
+}
+
+fn detail<'a>(value: String) -> Element<'a, AppMsg> {
+    text(value)
+        .shaping(Shaping::Advanced)
+        .size(12)
+        .themed(TextStyle::MainListItemSubtext)
+}
+
+fn file_name(path: &str) -> String {
+    std::path::Path::new(path)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string())
+}
+
+fn format_size(size: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+
+    if size < 1024 {
+        return format!("{} B", size);
+    }
+
+    let mut value = size as f64;
+    let mut unit = "B";
+
+    for next_unit in UNITS {
+        if value < 1024.0 {
+            break;
+        }
+
+        value /= 1024.0;
+        unit = next_unit;
+    }
+
+    format!("{:.1} {}", value, unit)
+}
+
+fn format_age(modified: u64) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|now| now.as_secs())
+        .unwrap_or(0);
+
+    let age = now.saturating_sub(modified);
+
+    match age {
+        0..=59 => tr!("preview-age-just-now"),
+        60..=3599 => tr!("preview-age-minutes", count = age / 60),
+        3600..=86399 => tr!("preview-age-hours", count = age / 3600),
+        _ => tr!("preview-age-days", count = age / 86400),
+    }
+}
@@ -2,9 +2,16 @@ use std::marker::PhantomData;
 use iced::Task;
 use iced::widget::scrollable::{scroll_to, AbsoluteOffset, Id};
 use crate::ui::AppMsg;
+use crate::ui::theme;
 
-pub const ESTIMATED_MAIN_LIST_ITEM_HEIGHT: f32 = 38.8;
-pub const ESTIMATED_ACTION_ITEM_HEIGHT: f32 = 38.8; // TODO
+// depends on density, so it is calculated from current theme every time new handle is created
+pub fn estimated_main_list_item_height() -> f32 {
+    theme::estimated_main_list_item_height()
+}
+
+pub fn estimated_action_item_height() -> f32 {
+    theme::estimated_main_list_item_height() // TODO
+}
 
 #[derive(Clone, Debug)]
 pub struct ScrollHandle {
@@ -13,6 +20,8 @@ pub struct ScrollHandle {
     offset: usize,
     rows_per_view: usize,
     item_height: f32,
+    // current scroll position of the scrollable, updated on every scroll including mouse wheel
+    scroll_position: f32,
 }
 
 impl ScrollHandle {
@@ -23,9 +32,15 @@ impl ScrollHandle {
             offset: 0,
             rows_per_view,
             item_height,
+            scroll_position: 0.0,
         }
     }
 
+    // handle for other content shown in place of the current one, nothing focused and scrolled to the top
+    pub fn fresh(&self) -> ScrollHandle {
+        ScrollHandle::new(false, self.item_height, self.rows_per_view)
+    }
+
     pub fn reset(&mut self, first_focused: bool) {
         self.index = if first_focused { Some(0) } else { None };
         self.offset = 0;
@@ -35,6 +50,28 @@ impl ScrollHandle {
         self.index = None;
     }
 
+    // used for mouse, item is already visible so no scrolling is needed
+    pub fn focus_index(&mut self, index: usize) {
+        self.index = Some(index);
+        self.offset = self.visible_offset(index);
+    }
+
+    pub fn on_scroll(&mut self, scroll_position: f32) {
+        self.scroll_position = scroll_position;
+
+        // keep keyboard navigation consistent with position of focused item after scrolling with mouse wheel
+        if let Some(index) = self.index {
+            self.offset = self.visible_offset(index);
+        }
+    }
+
+    fn visible_offset(&self, index: usize) -> usize {
+        let first_visible_row = (self.scroll_position / self.item_height).round() as usize;
+
+        index.saturating_sub(first_visible_row)
+            .min(self.rows_per_view)
+    }
+
     pub fn get<'a, T>(&self, search_results: &'a [T]) -> Option<&'a T> {
         match self.index {
             None => None,
@@ -110,6 +147,11 @@ impl ScrollHandle {
         }
     }
 
+    // scrollable loses its position when view is rebuilt, used when going back to previous view
+    pub fn restore_scroll<Message: 'static>(&self) -> Task<Message> {
+        scroll_to(self.scrollable_id.clone(), AbsoluteOffset { x: 0.0, y: self.scroll_position })
+    }
+
     pub fn scroll_to<Message: 'static>(&self, row_index: usize) -> Task<Message> {
         let pos_y = row_index as f32 * self.item_height - (self.offset as f32 * self.item_height);
 
@@ -1,32 +1,152 @@
 use crate::ui::scroll_handle::ScrollHandle;
+use crate::ui::direction::{directional, text_alignment};
+use crate::ui::fonts::default_font;
 use crate::ui::theme::button::ButtonStyle;
 use crate::ui::theme::container::ContainerStyle;
 use crate::ui::theme::image::ImageStyle;
+use crate::ui::theme::row::RowStyle;
 use crate::ui::theme::space::ThemeKindSpace;
 use crate::ui::theme::text::TextStyle;
-use crate::ui::theme::{Element, ThemableWidget};
+use crate::ui::theme::{text_size, Element, ThemableWidget};
 use crate::ui::widget::{render_icon_accessory, render_text_accessory};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use gauntlet_common::model::{IconAccessoryWidget, ImageLike, SearchResult, SearchResultAccessory, TextAccessoryWidget};
+use gauntlet_common::model::{IconAccessoryWidget, ImageLike, PluginId, SearchResult, SearchResultAccessory, SearchResultHighlight, TextAccessoryWidget};
+use gauntlet_common::tr;
 use iced::advanced::image::Handle;
 use iced::widget::button;
+use iced::widget::mouse_area;
+use iced::font::Weight;
 use iced::widget::row;
 use iced::widget::text;
-use iced::widget::text::Shaping;
-use iced::widget::{column, container, horizontal_space};
-use iced::{Alignment, Length};
+use iced::widget::text::{Shaping, Span};
+use iced::widget::value;
+use iced::widget::{column, container, horizontal_space, rich_text, span};
+use iced::{Alignment, Font, Length};
+use iced_fonts::{Bootstrap, BOOTSTRAP_FONT};
 
+#[derive(Debug, Clone)]
+pub enum SearchListEvent {
+    Primary(SearchResult),
+    Focus(usize),
+    FocusShowAll(usize),
+    ActionPanel(usize),
+    TogglePluginGroup(PluginId),
+    ShowAllPluginResults(PluginId, String),
+    ExitPluginScope,
+}
+
+// plugin groups are only shown when results are sorted by plugin
+pub struct PluginGroups<'a> {
+    pub collapsed: &'a HashSet<PluginId>,
+}
+
+// position of every result among results of the same plugin and amount of results of every plugin,
+// results in recent section are not counted
+pub fn plugin_result_positions(search_results: &[SearchResult], recent_count: usize) -> (Vec<Option<usize>>, HashMap<PluginId, usize>) {
+    let mut totals = HashMap::new();
+
+    let positions = search_results
+        .iter()
+        .enumerate()
+        .map(|(index, search_result)| {
+            if index < recent_count {
+                return None
+            }
+
+            let count = totals.entry(search_result.plugin_id.clone())
+                .or_insert(0);
+
+            let position = *count;
+
+            *count += 1;
+
+            Some(position)
+        })
+        .collect();
+
+    (positions, totals)
+}
+
+// first "recent_count" search results are shown in separate "Recent" section,
+// fallback results are shown in their own section, because they are not matches of the query,
+// "search_results" contains items of collapsed plugin groups and items over "results_per_plugin" limit too,
+// but they are not rendered and not counted in focus index
 pub fn search_list<'a>(
     search_results: &'a [SearchResult],
+    recent_count: usize,
+    fallback: bool,
+    plugin_groups: Option<PluginGroups<'a>>,
+    results_per_plugin: Option<usize>,
     focused_search_result: &ScrollHandle,
-) -> Element<'a, SearchResult> {
+    show_all_focused: bool,
+) -> Element<'a, SearchListEvent> {
+    let mut visible_index = 0;
+
+    let (positions, totals) = plugin_result_positions(search_results, recent_count);
+
     let items: Vec<Element<_>> = search_results
         .iter()
         .enumerate()
-        .map(|(index, search_result)| {
-            let main_text: Element<_> = text(&search_result.entrypoint_name)
-                .shaping(Shaping::Advanced)
+        .flat_map(|(result_index, search_result)| {
+            let group_title = match &plugin_groups {
+                Some(plugin_groups) if result_index >= recent_count => {
+                    let group_start = result_index == recent_count || search_results[result_index - 1].plugin_id != search_result.plugin_id;
+
+                    let collapsed = plugin_groups.collapsed.contains(&search_result.plugin_id);
+
+                    let title = if group_start {
+                        let count = search_results[result_index..]
+                            .iter()
+                            .take_while(|item| item.plugin_id == search_result.plugin_id)
+                            .count();
+
+                        let style = if result_index == 0 {
+                            RowStyle::ListFirstSectionTitle
+                        } else {
+                            RowStyle::ListSectionTitle
+                        };
+
+                        Some(plugin_group_title(search_result, count, collapsed, style))
+                    } else {
+                        None
+                    };
+
+                    if collapsed {
+                        return title.into_iter().collect::<Vec<_>>()
+                    }
+
+                    Some(title)
+                }
+                _ => None,
+            };
+
+            // the first result of plugin is always shown, so group title is never skipped here
+            if let (Some(limit), Some(position)) = (results_per_plugin, positions[result_index]) {
+                if position >= limit {
+                    return vec![]
+                }
+            }
+
+            let index = visible_index;
+            visible_index += 1;
+
+            let focused = focused_search_result.index == Some(index);
+
+            let show_all = match (results_per_plugin, positions[result_index]) {
+                (Some(limit), Some(position)) => {
+                    let total = totals[&search_result.plugin_id];
+
+                    if position + 1 == limit && total > limit {
+                        Some(show_all_row(search_result, total, index, focused && show_all_focused))
+                    } else {
+                        None
+                    }
+                }
+                _ => None
+            };
+
+            let main_text: Element<_> = rich_text(highlighted_spans(&search_result.entrypoint_name, &search_result.entrypoint_name_highlights))
                 .into();
             let main_text: Element<_> = container(main_text)
                 .themed(ContainerStyle::MainListItemText);
@@ -68,6 +188,46 @@ pub fn search_list<'a>(
             }
 
             button_content.push(main_text);
+
+            if let Some(subtitle) = &search_result.entrypoint_subtitle {
+                let subtitle: Element<_> = text(subtitle)
+                    .shaping(Shaping::Advanced)
+                    .themed(TextStyle::MainListItemSubtext);
+
+                let subtitle: Element<_> = container(subtitle)
+                    .themed(ContainerStyle::MainListItemText);
+
+                button_content.push(subtitle);
+            }
+
+            // show why the item was found if it's not obvious from its name
+            if !search_result.entrypoint_matched_keywords.is_empty() {
+                let keywords = search_result.entrypoint_matched_keywords
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(index, keyword)| {
+                        let separator = if index == 0 {
+                            None
+                        } else {
+                            Some(span(", "))
+                        };
+
+                        separator.into_iter()
+                            .chain(highlighted_spans(&keyword.keyword, &keyword.highlights))
+                    })
+                    .collect::<Vec<_>>();
+
+                let keywords: Element<_> = rich_text(keywords)
+                    .size(text_size(12.0))
+                    .class(TextStyle::MainListItemSubtext)
+                    .into();
+
+                let keywords: Element<_> = container(keywords)
+                    .themed(ContainerStyle::MainListItemText);
+
+                button_content.push(keywords);
+            }
+
             button_content.push(spacer);
 
             if search_result.entrypoint_accessories.len() > 0 {
@@ -102,27 +262,188 @@ pub fn search_list<'a>(
 
             button_content.push(sub_text);
 
-            let button_content: Element<_> = row(button_content)
+            let button_content: Element<_> = row(directional(button_content))
                 .align_y(Alignment::Center)
                 .into();
 
-            let style = match focused_search_result.index {
-                None => ButtonStyle::MainListItem,
-                Some(focused_index) => {
-                    if focused_index == index {
-                        ButtonStyle::MainListItemFocused
-                    } else {
-                        ButtonStyle::MainListItem
-                    }
-                }
+            let style = if focused && !show_all_focused {
+                ButtonStyle::MainListItemFocused
+            } else {
+                ButtonStyle::MainListItem
             };
 
-            button(button_content)
+            let button: Element<_> = button(button_content)
                 .width(Length::Fill)
-                .on_press(search_result.clone())
-                .themed(style)
+                .on_press(SearchListEvent::Primary(search_result.clone()))
+                .themed(style);
+
+            // on_move instead of on_enter, so that items moving under stationary cursor
+            // when list is scrolled using keyboard don't steal focus
+            let item: Element<_> = mouse_area(button)
+                .on_move(move |_| SearchListEvent::Focus(index))
+                .on_right_press(SearchListEvent::ActionPanel(index))
+                .into();
+
+            let title = match group_title {
+                Some(group_title) => group_title,
+                None if fallback => match index {
+                    0 => Some(section_title(tr!("search-section-fallback"), RowStyle::ListFirstSectionTitle)),
+                    _ => None
+                },
+                None => match (recent_count, index) {
+                    (0, _) => None,
+                    (_, 0) => Some(section_title(tr!("search-section-recent"), RowStyle::ListFirstSectionTitle)),
+                    (recent_count, index) if recent_count == index => Some(section_title(tr!("search-section-all"), RowStyle::ListSectionTitle)),
+                    _ => None
+                }
+            };
+
+            title.into_iter()
+                .chain([item])
+                .chain(show_all)
+                .collect::<Vec<_>>()
         })
         .collect();
 
     column(items).into()
 }
+
+// parts of the text which matched search query are shown in bold
+fn highlighted_spans<'a>(value: &'a str, highlights: &[SearchResultHighlight]) -> Vec<Span<'a>> {
+    let bold = Font {
+        weight: Weight::Bold,
+        ..default_font()
+    };
+
+    let mut spans = vec![];
+    let mut position = 0;
+
+    for highlight in highlights {
+        // ranges are produced by server, don't trust them to be valid for this text
+        if highlight.start < position || highlight.end > value.len() || !value.is_char_boundary(highlight.start) || !value.is_char_boundary(highlight.end) {
+            continue
+        }
+
+        if highlight.start > position {
+            spans.push(span(&value[position..highlight.start]));
+        }
+
+        spans.push(span(&value[highlight.start..highlight.end]).font(bold));
+
+        position = highlight.end;
+    }
+
+    if position < value.len() || spans.is_empty() {
+        spans.push(span(&value[position..]));
+    }
+
+    spans
+}
+
+fn section_title<'a>(title: String, style: RowStyle) -> Element<'a, SearchListEvent> {
+    let title: Element<_> = text(title)
+        .shaping(Shaping::Advanced)
+        .size(text_size(15.0))
+        .themed(TextStyle::ListSectionTitle);
+
+    let title: Element<_> = container(title)
+        .width(Length::Fill)
+        .align_x(text_alignment())
+        .into();
+
+    row([title])
+        .themed(style)
+}
+
+// focused together with search result it is shown under, so it can be reached with keyboard
+fn show_all_row<'a>(search_result: &SearchResult, count: usize, index: usize, focused: bool) -> Element<'a, SearchListEvent> {
+    let label: Element<_> = text(tr!("search-show-all-plugin-results", name = search_result.plugin_name.clone(), count = count))
+        .shaping(Shaping::Advanced)
+        .themed(TextStyle::MainListItemSubtext);
+
+    let label: Element<_> = container(label)
+        .width(Length::Fill)
+        .align_x(text_alignment())
+        .themed(ContainerStyle::MainListItemText);
+
+    let style = if focused {
+        ButtonStyle::MainListItemFocused
+    } else {
+        ButtonStyle::MainListItem
+    };
+
+    let button: Element<_> = button(label)
+        .width(Length::Fill)
+        .on_press(SearchListEvent::ShowAllPluginResults(search_result.plugin_id.clone(), search_result.plugin_name.clone()))
+        .themed(style);
+
+    mouse_area(button)
+        .on_move(move |_| SearchListEvent::FocusShowAll(index))
+        .into()
+}
+
+// shown under search bar while only results of one plugin are shown, clicking it shows results of all plugins again
+pub fn plugin_scope_title<'a>(plugin_name: &str) -> Element<'a, SearchListEvent> {
+    let icon: Element<_> = value(Bootstrap::XLg)
+        .font(BOOTSTRAP_FONT)
+        .size(text_size(12.0))
+        .themed(TextStyle::ListSectionTitle);
+
+    let title: Element<_> = text(tr!("search-plugin-scope", name = plugin_name.to_string()))
+        .shaping(Shaping::Advanced)
+        .size(text_size(15.0))
+        .themed(TextStyle::ListSectionTitle);
+
+    let content: Element<_> = row(directional(vec![title, icon]))
+        .spacing(8.0)
+        .align_y(Alignment::Center)
+        .into();
+
+    let content: Element<_> = container(content)
+        .width(Length::Fill)
+        .align_x(text_alignment())
+        .into();
+
+    let content: Element<_> = mouse_area(content)
+        .on_press(SearchListEvent::ExitPluginScope)
+        .into();
+
+    row([content])
+        .themed(RowStyle::ListFirstSectionTitle)
+}
+
+fn plugin_group_title<'a>(search_result: &SearchResult, count: usize, collapsed: bool, style: RowStyle) -> Element<'a, SearchListEvent> {
+    let icon = if collapsed {
+        Bootstrap::ChevronRight
+    } else {
+        Bootstrap::ChevronDown
+    };
+
+    let icon: Element<_> = value(icon)
+        .font(BOOTSTRAP_FONT)
+        .size(text_size(12.0))
+        .themed(TextStyle::ListSectionTitle);
+
+    let title: Element<_> = text(tr!("search-section-plugin", name = search_result.plugin_name.clone(), count = count))
+        .shaping(Shaping::Advanced)
+        .size(text_size(15.0))
+        .themed(TextStyle::ListSectionTitle);
+
+    let content: Element<_> = row(directional(vec![icon, title]))
+        .spacing(8.0)
+        .align_y(Alignment::Center)
+        .into();
+
+    let content: Element<_> = container(content)
+        .width(Length::Fill)
+        .align_x(text_alignment())
+        .into();
+
+    // headers are not focusable, keyboard navigation goes only through search results
+    let content: Element<_> = mouse_area(content)
+        .on_press(SearchListEvent::TogglePluginGroup(search_result.plugin_id.clone()))
+        .into();
+
+    row([content])
+        .themed(style)
+}
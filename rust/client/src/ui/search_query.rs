@@ -0,0 +1,63 @@
+use gauntlet_common::model::{SearchFilter, SearchResultEntrypointType};
+
+// "@clipboard #view history" -> ("history", plugin "clipboard", entrypoint type "view")
+// tokens which are not recognized as filters are kept in search text
+pub fn parse_search_query(prompt: &str) -> (String, SearchFilter) {
+    let mut filter = SearchFilter::default();
+
+    let text = prompt
+        .split_whitespace()
+        .filter(|token| {
+            if let Some(plugin_name) = token.strip_prefix('@') {
+                if !plugin_name.is_empty() {
+                    filter.plugin_name = Some(plugin_name.to_string());
+                    return false
+                }
+            }
+
+            if let Some(entrypoint_type) = token.strip_prefix('#') {
+                if let Some(entrypoint_type) = parse_entrypoint_type(entrypoint_type) {
+                    filter.entrypoint_type = Some(entrypoint_type);
+                    return false
+                }
+            }
+
+            true
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    (text, filter)
+}
+
+fn parse_entrypoint_type(value: &str) -> Option<SearchResultEntrypointType> {
+    match value.to_lowercase().as_str() {
+        "command" => Some(SearchResultEntrypointType::Command),
+        "view" => Some(SearchResultEntrypointType::View),
+        "generated" => Some(SearchResultEntrypointType::Generated),
+        _ => None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_filter_tokens() {
+        let (text, filter) = parse_search_query("@clipboard #View history");
+
+        assert_eq!(text, "history");
+        assert_eq!(filter.plugin_name.as_deref(), Some("clipboard"));
+        assert_eq!(filter.entrypoint_type, Some(SearchResultEntrypointType::View));
+    }
+
+    #[test]
+    fn keep_unknown_tokens() {
+        let (text, filter) = parse_search_query("c# @ #tag");
+
+        assert_eq!(text, "c# @ #tag");
+        assert!(filter.plugin_name.is_none());
+        assert!(filter.entrypoint_type.is_none());
+    }
+}
@@ -0,0 +1,140 @@
+use gauntlet_common::deeplink::DeepLink;
+use gauntlet_common::model::{EntrypointId, PhysicalKey, PhysicalShortcut, PluginId, SearchResult, SearchResultEntrypointType};
+use gauntlet_common::tr;
+
+use crate::ui::widget::ActionPanelItem;
+
+// actions provided by gauntlet itself, shown after plugin actions in action panel of every search result
+#[derive(Debug, Clone, Copy)]
+pub enum BuiltinSearchResultAction {
+    CopyEntrypointName,
+    CopyPluginId,
+    CopyDeepLink,
+    DisableEntrypoint,
+}
+
+impl BuiltinSearchResultAction {
+    pub fn for_search_result(search_result: &SearchResult) -> Vec<BuiltinSearchResultAction> {
+        let mut actions = vec![
+            BuiltinSearchResultAction::CopyEntrypointName,
+            BuiltinSearchResultAction::CopyPluginId,
+        ];
+
+        // deep links and enabled state are only supported for entrypoints stored in plugin manifest
+        let manifest_entrypoint = matches!(search_result.entrypoint_type, SearchResultEntrypointType::Command | SearchResultEntrypointType::View)
+            && !search_result.plugin_id.to_string().starts_with("builtin://");
+
+        if manifest_entrypoint {
+            actions.push(BuiltinSearchResultAction::CopyDeepLink);
+        }
+
+        // only commands can be disabled from search results
+        if manifest_entrypoint && search_result.entrypoint_type == SearchResultEntrypointType::Command {
+            actions.push(BuiltinSearchResultAction::DisableEntrypoint);
+        }
+
+        actions
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            BuiltinSearchResultAction::CopyEntrypointName => tr!("action-copy-entrypoint-name"),
+            BuiltinSearchResultAction::CopyPluginId => tr!("action-copy-plugin-id"),
+            BuiltinSearchResultAction::CopyDeepLink => tr!("action-copy-deep-link"),
+            BuiltinSearchResultAction::DisableEntrypoint => tr!("action-disable-entrypoint"),
+        }
+    }
+
+    // None if action doesn't copy anything
+    pub fn clipboard_text(&self, search_result: &SearchResult) -> Option<String> {
+        match self {
+            BuiltinSearchResultAction::CopyEntrypointName => Some(search_result.entrypoint_name.clone()),
+            BuiltinSearchResultAction::CopyPluginId => Some(search_result.plugin_id.to_string()),
+            BuiltinSearchResultAction::CopyDeepLink => {
+                let deep_link = DeepLink::Open {
+                    plugin_id: search_result.plugin_id.clone(),
+                    entrypoint_id: search_result.entrypoint_id.clone(),
+                };
+
+                Some(deep_link.to_uri())
+            }
+            BuiltinSearchResultAction::DisableEntrypoint => None,
+        }
+    }
+}
+
+// entrypoint disabled from main view, can be re-enabled while undo toast is shown
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisabledEntrypoint {
+    pub plugin_id: PluginId,
+    pub entrypoint_id: EntrypointId,
+    pub entrypoint_name: String,
+}
+
+pub fn undo_shortcut() -> PhysicalShortcut {
+    PhysicalShortcut {
+        physical_key: PhysicalKey::KeyZ,
+        modifier_shift: false,
+        modifier_control: cfg!(not(target_os = "macos")),
+        modifier_alt: false,
+        modifier_meta: cfg!(target_os = "macos"),
+    }
+}
+
+pub fn undo_shortcut_label() -> &'static str {
+    if cfg!(target_os = "macos") {
+        "⌘Z"
+    } else {
+        "Ctrl+Z"
+    }
+}
+
+// number of actions in action panel which are provided by plugin, including primary action for static entrypoints
+pub fn plugin_action_count(search_result: &SearchResult) -> usize {
+    match search_result.entrypoint_type {
+        SearchResultEntrypointType::Command | SearchResultEntrypointType::View => search_result.entrypoint_actions.len() + 1,
+        SearchResultEntrypointType::Generated => search_result.entrypoint_actions.len(),
+    }
+}
+
+pub fn action_count(search_result: &SearchResult) -> usize {
+    plugin_action_count(search_result) + BuiltinSearchResultAction::for_search_result(search_result).len()
+}
+
+pub fn builtin_action_at(search_result: &SearchResult, action_index: usize) -> Option<BuiltinSearchResultAction> {
+    let index = action_index.checked_sub(plugin_action_count(search_result))?;
+
+    BuiltinSearchResultAction::for_search_result(search_result)
+        .get(index)
+        .copied()
+}
+
+// labels of all actions in action panel of search result, in the order they are shown
+pub fn action_labels(search_result: &SearchResult) -> Vec<String> {
+    let primary_label = match search_result.entrypoint_type {
+        SearchResultEntrypointType::Command => Some(tr!("action-run-command")),
+        SearchResultEntrypointType::View => Some(tr!("action-open-view")),
+        SearchResultEntrypointType::Generated => None,
+    };
+
+    primary_label.into_iter()
+        .chain(search_result.entrypoint_actions.iter().map(|action| action.label.clone()))
+        .chain(BuiltinSearchResultAction::for_search_result(search_result).iter().map(|action| action.label()))
+        .collect()
+}
+
+pub fn builtin_action_items(search_result: &SearchResult) -> Vec<ActionPanelItem> {
+    let plugin_action_count = plugin_action_count(search_result);
+
+    BuiltinSearchResultAction::for_search_result(search_result)
+        .into_iter()
+        .enumerate()
+        .map(|(index, action)| {
+            ActionPanelItem::Action {
+                label: action.label(),
+                widget_id: plugin_action_count + index,
+                physical_shortcut: None,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,301 @@
+use gauntlet_common::model::{KeymapAction, KeymapBinding, PhysicalKey, PhysicalShortcut};
+use gauntlet_common::tr;
+use iced::widget::text::Shaping;
+use iced::widget::{column, container, horizontal_space, mouse_area, row, scrollable, text};
+use iced::{Alignment, Length};
+
+use crate::ui::direction::directional;
+use crate::ui::result_preview::toggle_preview_shortcut;
+use crate::ui::search_result_actions::undo_shortcut;
+use crate::ui::theme::container::ContainerStyle;
+use crate::ui::theme::row::RowStyle;
+use crate::ui::theme::text::TextStyle;
+use crate::ui::theme::{Element, ThemableWidget};
+use crate::ui::widget::render_shortcut;
+use crate::ui::AppMsg;
+
+// state of the window which decides which shortcuts are currently active
+pub enum ShortcutHintsContext {
+    MainView {
+        inline_views: bool,
+        plugin_scope: bool,
+        undo: bool,
+    },
+    ActionPanel,
+    PluginView {
+        top_level_view: bool,
+        tab_count: usize,
+    },
+    ErrorView,
+}
+
+struct ShortcutHint {
+    label: String,
+    shortcuts: Vec<PhysicalShortcut>,
+}
+
+// shortcuts which are always bound to keymap actions in current state, keymap from config adds more shortcuts on top of them
+fn builtin_keymap(context: &ShortcutHintsContext) -> Vec<KeymapBinding> {
+    let mut bindings = vec![
+        binding(KeymapAction::Primary, key(PhysicalKey::Enter)),
+        binding(KeymapAction::Next, key(PhysicalKey::ArrowDown)),
+        binding(KeymapAction::Previous, key(PhysicalKey::ArrowUp)),
+        binding(KeymapAction::ToggleActionPanel, alt(PhysicalKey::KeyK)),
+        binding(KeymapAction::TogglePin, pin_shortcut()),
+        binding(KeymapAction::ToggleShortcutHints, shift(PhysicalKey::Slash)),
+    ];
+
+    match context {
+        ShortcutHintsContext::MainView { .. } => {
+            bindings.push(binding(KeymapAction::Secondary, shift(PhysicalKey::Enter)));
+            bindings.push(binding(KeymapAction::TogglePreview, toggle_preview_shortcut()));
+            bindings.push(binding(KeymapAction::Back, key(PhysicalKey::Escape)));
+        }
+        ShortcutHintsContext::ActionPanel => {
+            // escape closes action panel instead of going back
+            bindings.push(binding(KeymapAction::ToggleActionPanel, key(PhysicalKey::Escape)));
+        }
+        ShortcutHintsContext::PluginView { .. } => {
+            bindings.push(binding(KeymapAction::Secondary, shift(PhysicalKey::Enter)));
+            bindings.push(binding(KeymapAction::DetachView, detach_view_shortcut()));
+            bindings.push(binding(KeymapAction::Back, key(PhysicalKey::Escape)));
+        }
+        ShortcutHintsContext::ErrorView => {
+            bindings.push(binding(KeymapAction::Back, key(PhysicalKey::Escape)));
+        }
+    }
+
+    bindings
+}
+
+// keymap actions which do something in current state, in order they are listed
+fn hinted_actions(context: &ShortcutHintsContext) -> Vec<(KeymapAction, String)> {
+    let mut actions = match context {
+        ShortcutHintsContext::MainView { plugin_scope, .. } => {
+            let back = if *plugin_scope {
+                tr!("shortcut-hint-show-all-plugins")
+            } else {
+                tr!("shortcut-hint-close-window")
+            };
+
+            vec![
+                (KeymapAction::Primary, tr!("shortcut-hint-open")),
+                (KeymapAction::Secondary, tr!("shortcut-hint-secondary-action")),
+                (KeymapAction::Next, tr!("shortcut-hint-next")),
+                (KeymapAction::Previous, tr!("shortcut-hint-previous")),
+                (KeymapAction::ToggleActionPanel, tr!("shortcut-hint-toggle-action-panel")),
+                (KeymapAction::TogglePreview, tr!("shortcut-hint-toggle-preview")),
+                (KeymapAction::Back, back),
+            ]
+        }
+        ShortcutHintsContext::ActionPanel => {
+            vec![
+                (KeymapAction::Primary, tr!("shortcut-hint-run-action")),
+                (KeymapAction::Next, tr!("shortcut-hint-next")),
+                (KeymapAction::Previous, tr!("shortcut-hint-previous")),
+                (KeymapAction::ToggleActionPanel, tr!("shortcut-hint-close-action-panel")),
+            ]
+        }
+        ShortcutHintsContext::PluginView { top_level_view, .. } => {
+            let back = if *top_level_view {
+                tr!("shortcut-hint-back-to-search")
+            } else {
+                tr!("shortcut-hint-back")
+            };
+
+            vec![
+                (KeymapAction::Primary, tr!("shortcut-hint-primary-action")),
+                (KeymapAction::Secondary, tr!("shortcut-hint-secondary-action")),
+                (KeymapAction::Next, tr!("shortcut-hint-next")),
+                (KeymapAction::Previous, tr!("shortcut-hint-previous")),
+                (KeymapAction::ToggleActionPanel, tr!("shortcut-hint-toggle-action-panel")),
+                (KeymapAction::DetachView, tr!("shortcut-hint-detach-view")),
+                (KeymapAction::Back, back),
+            ]
+        }
+        ShortcutHintsContext::ErrorView => {
+            vec![
+                (KeymapAction::Back, tr!("shortcut-hint-close-window")),
+            ]
+        }
+    };
+
+    actions.push((KeymapAction::TogglePin, tr!("shortcut-hint-toggle-pin")));
+    actions.push((KeymapAction::ToggleShortcutHints, tr!("shortcut-hint-toggle-shortcut-hints")));
+
+    actions
+}
+
+// hints for keymap actions are generated from active keymap, the rest are shortcuts which can't be rebound
+fn shortcut_hints(context: &ShortcutHintsContext, keymap: &[KeymapBinding]) -> Vec<ShortcutHint> {
+    let active_keymap: Vec<_> = builtin_keymap(context)
+        .into_iter()
+        .chain(keymap.iter().cloned())
+        .collect();
+
+    let mut hints: Vec<_> = hinted_actions(context)
+        .into_iter()
+        .map(|(action, label)| {
+            let shortcuts = active_keymap.iter()
+                .filter(|binding| binding.action == action)
+                .map(|binding| binding.shortcut.clone())
+                .collect();
+
+            ShortcutHint {
+                label,
+                shortcuts,
+            }
+        })
+        .filter(|hint| !hint.shortcuts.is_empty())
+        .collect();
+
+    let mut push = |label: String, shortcuts: Vec<PhysicalShortcut>| {
+        hints.push(ShortcutHint {
+            label,
+            shortcuts,
+        })
+    };
+
+    match context {
+        ShortcutHintsContext::MainView { inline_views, undo, .. } => {
+            push(tr!("shortcut-hint-quicklook"), vec![key(PhysicalKey::Space)]);
+
+            if *inline_views {
+                push(tr!("shortcut-hint-focus-inline-view"), vec![key(PhysicalKey::Tab), shift(PhysicalKey::Tab)]);
+            }
+
+            if *undo {
+                push(tr!("shortcut-hint-undo"), vec![undo_shortcut()]);
+            }
+        }
+        ShortcutHintsContext::PluginView { tab_count, .. } => {
+            push(tr!("shortcut-hint-navigate"), vec![key(PhysicalKey::ArrowLeft), key(PhysicalKey::ArrowRight)]);
+
+            // only first 9 tabs have shortcut
+            let tab_shortcuts: Vec<_> = TAB_KEYS.iter()
+                .take(*tab_count)
+                .map(|physical_key| control(physical_key.clone()))
+                .collect();
+
+            match tab_shortcuts.as_slice() {
+                [] => {}
+                [only] => push(tr!("shortcut-hint-select-tab"), vec![only.clone()]),
+                [first, .., last] => push(tr!("shortcut-hint-select-tab"), vec![first.clone(), last.clone()]),
+            }
+        }
+        ShortcutHintsContext::ActionPanel | ShortcutHintsContext::ErrorView => {}
+    }
+
+    hints
+}
+
+// modal layer on top of the window, closed by any key press or click
+pub fn shortcut_hints_view<'a>(context: &ShortcutHintsContext, keymap: &[KeymapBinding]) -> Element<'a, AppMsg> {
+    let title: Element<_> = text(tr!("shortcut-hints-title"))
+        .shaping(Shaping::Advanced)
+        .size(15)
+        .themed(TextStyle::ListSectionTitle);
+
+    let title: Element<_> = row([title])
+        .themed(RowStyle::ListFirstSectionTitle);
+
+    let mut items = vec![title];
+
+    for hint in shortcut_hints(context, keymap) {
+        let label: Element<_> = text(hint.label)
+            .shaping(Shaping::Advanced)
+            .into();
+
+        let mut content = vec![label, horizontal_space().into()];
+
+        content.extend(hint.shortcuts.iter().map(|shortcut| render_shortcut(shortcut)));
+
+        let item: Element<_> = row(directional(content))
+            .spacing(4.0)
+            .align_y(Alignment::Center)
+            .into();
+
+        let item: Element<_> = container(item)
+            .width(Length::Fill)
+            .padding(4.0)
+            .into();
+
+        items.push(item);
+    }
+
+    let items: Element<_> = column(items)
+        .into();
+
+    let items: Element<_> = scrollable(items)
+        .width(Length::Fill)
+        .into();
+
+    let panel: Element<_> = container(items)
+        .themed(ContainerStyle::ActionPanel);
+
+    let panel: Element<_> = container(panel)
+        .center(Length::Fill)
+        .into();
+
+    mouse_area(panel)
+        .on_press(AppMsg::ToggleShortcutHints)
+        .into()
+}
+
+pub fn pin_shortcut() -> PhysicalShortcut {
+    alt(PhysicalKey::KeyP)
+}
+
+pub fn detach_view_shortcut() -> PhysicalShortcut {
+    alt(PhysicalKey::KeyD)
+}
+
+const TAB_KEYS: [PhysicalKey; 9] = [
+    PhysicalKey::Digit1,
+    PhysicalKey::Digit2,
+    PhysicalKey::Digit3,
+    PhysicalKey::Digit4,
+    PhysicalKey::Digit5,
+    PhysicalKey::Digit6,
+    PhysicalKey::Digit7,
+    PhysicalKey::Digit8,
+    PhysicalKey::Digit9,
+];
+
+fn binding(action: KeymapAction, shortcut: PhysicalShortcut) -> KeymapBinding {
+    KeymapBinding {
+        action,
+        shortcut,
+    }
+}
+
+fn key(physical_key: PhysicalKey) -> PhysicalShortcut {
+    PhysicalShortcut {
+        physical_key,
+        modifier_shift: false,
+        modifier_control: false,
+        modifier_alt: false,
+        modifier_meta: false,
+    }
+}
+
+fn shift(physical_key: PhysicalKey) -> PhysicalShortcut {
+    PhysicalShortcut {
+        modifier_shift: true,
+        ..key(physical_key)
+    }
+}
+
+fn alt(physical_key: PhysicalKey) -> PhysicalShortcut {
+    PhysicalShortcut {
+        modifier_alt: true,
+        ..key(physical_key)
+    }
+}
+
+fn control(physical_key: PhysicalKey) -> PhysicalShortcut {
+    PhysicalShortcut {
+        modifier_control: true,
+        ..key(physical_key)
+    }
+}
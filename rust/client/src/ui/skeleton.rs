@@ -0,0 +1,94 @@
+use iced::widget::{column, container, horizontal_rule, row, Space};
+use iced::{Alignment, Length};
+
+use crate::ui::direction::directional;
+use crate::ui::theme::container::ContainerStyle;
+use crate::ui::theme::{Element, ThemableWidget};
+
+const LINE_HEIGHT: f32 = 12.0;
+const RECT_HEIGHT: f32 = 64.0;
+const CIRCLE_SIZE: f32 = 32.0;
+
+// amount of placeholder rows shown while plugin view is loading
+const VIEW_ROWS: usize = 8;
+
+pub enum SkeletonShape {
+    Line,
+    Circle,
+    Rect,
+}
+
+pub fn skeleton<'a, Message: 'a>(shape: SkeletonShape, width: Option<f32>, height: Option<f32>) -> Element<'a, Message> {
+    let (width, height, style) = match shape {
+        SkeletonShape::Line => {
+            let width = width.map(Length::Fixed).unwrap_or(Length::Fill);
+            let height = height.unwrap_or(LINE_HEIGHT);
+
+            (width, height, ContainerStyle::Skeleton)
+        }
+        SkeletonShape::Circle => {
+            let size = width.or(height).unwrap_or(CIRCLE_SIZE);
+
+            (Length::Fixed(size), size, ContainerStyle::SkeletonCircle)
+        }
+        SkeletonShape::Rect => {
+            let width = width.map(Length::Fixed).unwrap_or(Length::Fill);
+            let height = height.unwrap_or(RECT_HEIGHT);
+
+            (width, height, ContainerStyle::Skeleton)
+        }
+    };
+
+    container(Space::new(width, Length::Fixed(height)))
+        .themed(style)
+}
+
+// shown instead of plugin view until plugin renders it for the first time
+pub fn skeleton_view<'a, Message: 'a>() -> Element<'a, Message> {
+    let top_panel: Element<_> = row(directional(vec![
+        skeleton(SkeletonShape::Rect, Some(28.0), Some(28.0)),
+        skeleton(SkeletonShape::Line, Some(200.0), Some(16.0)),
+    ]))
+        .align_y(Alignment::Center)
+        .spacing(12)
+        .into();
+
+    let top_panel: Element<_> = container(top_panel)
+        .width(Length::Fill)
+        .themed(ContainerStyle::RootTopPanel);
+
+    let rows: Vec<Element<_>> = (0..VIEW_ROWS)
+        .map(|index| {
+            // vary width of rows, so placeholder looks closer to actual list
+            let width = match index % 3 {
+                0 => 260.0,
+                1 => 180.0,
+                _ => 220.0,
+            };
+
+            let item: Element<_> = row(directional(vec![
+                skeleton(SkeletonShape::Circle, Some(20.0), None),
+                skeleton(SkeletonShape::Line, Some(width), None),
+            ]))
+                .align_y(Alignment::Center)
+                .spacing(12)
+                .into();
+
+            container(item)
+                .width(Length::Fill)
+                .themed(ContainerStyle::SkeletonItem)
+        })
+        .collect();
+
+    let rows: Element<_> = column(rows)
+        .height(Length::Fill)
+        .into();
+
+    let rows: Element<_> = container(rows)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .themed(ContainerStyle::List);
+
+    column([top_panel, horizontal_rule(1).into(), rows])
+        .into()
+}
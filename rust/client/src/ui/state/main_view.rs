@@ -1,4 +1,4 @@
-use crate::ui::scroll_handle::{ScrollHandle, ESTIMATED_ACTION_ITEM_HEIGHT};
+use crate::ui::scroll_handle::{ScrollHandle, estimated_action_item_height};
 
 pub enum MainViewState {
     None,
@@ -23,13 +23,13 @@ impl MainViewState {
 
     pub fn search_result_action_panel(prev_state: &mut MainViewState, focus_first: bool) {
         *prev_state = Self::SearchResultActionPanel {
-            focused_action_item: ScrollHandle::new(focus_first, ESTIMATED_ACTION_ITEM_HEIGHT, 7),
+            focused_action_item: ScrollHandle::new(focus_first, estimated_action_item_height(), 7),
         }
     }
 
     pub fn inline_result_action_panel(prev_state: &mut MainViewState, focus_first: bool) {
         *prev_state = Self::InlineViewActionPanel {
-            focused_action_item: ScrollHandle::new(focus_first, ESTIMATED_ACTION_ITEM_HEIGHT, 7),
+            focused_action_item: ScrollHandle::new(focus_first, estimated_action_item_height(), 7),
         }
     }
 }
@@ -2,11 +2,14 @@ mod main_view;
 mod plugin_view;
 
 use crate::ui::client_context::ClientContext;
-use crate::ui::scroll_handle::{ScrollHandle, ESTIMATED_MAIN_LIST_ITEM_HEIGHT};
+use crate::ui::scroll_handle::{ScrollHandle, estimated_main_list_item_height};
+use crate::ui::search_result_actions::action_count;
+use crate::ui::widget::ComponentWidgetEvent;
 pub use crate::ui::state::main_view::MainViewState;
 pub use crate::ui::state::plugin_view::PluginViewState;
 use crate::ui::AppMsg;
-use gauntlet_common::model::{EntrypointId, PhysicalShortcut, PluginId, SearchResult};
+use gauntlet_common::i18n::is_rtl;
+use gauntlet_common::model::{EntrypointId, OnboardingPreference, PhysicalShortcut, PluginId, SearchResult, UiRenderLocation};
 use iced::widget::text_input;
 use iced::widget::text_input::focus;
 use iced::Task;
@@ -19,9 +22,14 @@ pub enum GlobalState {
 
         // ephemeral state
         focused_search_result: ScrollHandle,
+        // "Show all results" row under focused search result is focused instead of the result itself
+        show_all_focused: bool,
 
         // state
         sub_state: MainViewState,
+        // visible index of search result which has "Show all results" row shown under it
+        show_all_rows: HashMap<usize, PluginScope>,
+        plugin_scope: Option<PluginScope>,
         pending_plugin_view_data: Option<PluginViewData>,
         pending_plugin_view_loading_bar: LoadingBarState,
     },
@@ -35,6 +43,13 @@ pub enum GlobalState {
     },
 }
 
+// only results of one plugin are shown, selected using "Show all results" row
+#[derive(Clone)]
+pub struct PluginScope {
+    pub plugin_id: PluginId,
+    pub plugin_name: String,
+}
+
 #[derive(Clone)]
 pub struct PluginViewData {
     pub top_level_view: bool,
@@ -46,17 +61,17 @@ pub struct PluginViewData {
 }
 
 pub enum ErrorViewData {
-    PreferenceRequired {
+    Onboarding {
         plugin_id: PluginId,
+        plugin_name: String,
         entrypoint_id: EntrypointId,
-        plugin_preferences_required: bool,
-        entrypoint_preferences_required: bool,
+        description: Option<String>,
+        preferences: Vec<OnboardingPreference>,
     },
     PluginError {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
     },
-    BackendTimeout,
     UnknownError {
         display: String
     },
@@ -74,8 +89,11 @@ impl GlobalState {
     pub fn new(search_field_id: text_input::Id) -> GlobalState {
         GlobalState::MainView {
             search_field_id,
-            focused_search_result: ScrollHandle::new(true, ESTIMATED_MAIN_LIST_ITEM_HEIGHT, 7),
+            focused_search_result: ScrollHandle::new(true, estimated_main_list_item_height(), 7),
+            show_all_focused: false,
             sub_state: MainViewState::new(),
+            show_all_rows: HashMap::new(),
+            plugin_scope: None,
             pending_plugin_view_data: None,
             pending_plugin_view_loading_bar: LoadingBarState::Off,
         }
@@ -122,8 +140,8 @@ pub trait Focus<T> {
     fn primary(&mut self, client_context: &ClientContext, focus_list: &[T]) -> Task<AppMsg>;
     fn secondary(&mut self, client_context: &ClientContext, focus_list: &[T]) -> Task<AppMsg>;
     fn back(&mut self, client_context: &ClientContext) -> Task<AppMsg>;
-    fn next(&mut self, client_context: &ClientContext) -> Task<AppMsg>;
-    fn previous(&mut self, client_context: &ClientContext) -> Task<AppMsg>;
+    fn next(&mut self, client_context: &mut ClientContext) -> Task<AppMsg>;
+    fn previous(&mut self, client_context: &mut ClientContext) -> Task<AppMsg>;
     fn up(&mut self, client_context: &mut ClientContext, focus_list: &[T]) -> Task<AppMsg>;
     fn down(&mut self, client_context: &mut ClientContext, focus_list: &[T]) -> Task<AppMsg>;
     fn left(&mut self, client_context: &mut ClientContext, focus_list: &[T]) -> Task<AppMsg>;
@@ -133,10 +151,19 @@ pub trait Focus<T> {
 impl Focus<SearchResult> for GlobalState {
     fn primary(&mut self, client_context: &ClientContext, focus_list: &[SearchResult]) -> Task<AppMsg> {
         match self {
-            GlobalState::MainView { focused_search_result, sub_state, .. } => {
+            GlobalState::MainView { focused_search_result, sub_state, show_all_focused, show_all_rows, .. } => {
                 match sub_state {
                     MainViewState::None => {
-                        if let Some(search_result) = focused_search_result.get(focus_list) {
+                        let show_all_row = focused_search_result.index
+                            .filter(|_| *show_all_focused)
+                            .and_then(|index| show_all_rows.get(&index));
+
+                        if let Some(plugin_scope) = show_all_row {
+                            Task::done(AppMsg::ShowAllPluginResults {
+                                plugin_id: plugin_scope.plugin_id.clone(),
+                                plugin_name: plugin_scope.plugin_name.clone(),
+                            })
+                        } else if let Some(search_result) = focused_search_result.get(focus_list) {
                             let search_result = search_result.clone();
                             Task::done(AppMsg::OnPrimaryActionMainViewNoPanel { search_result })
                         } else {
@@ -234,12 +261,19 @@ impl Focus<SearchResult> for GlobalState {
         }
     }
 
-    fn back(&mut self, _client_context: &ClientContext) -> Task<AppMsg> {
+    fn back(&mut self, client_context: &ClientContext) -> Task<AppMsg> {
         match self {
-            GlobalState::MainView { sub_state, .. } => {
+            GlobalState::MainView { sub_state, plugin_scope, focused_search_result, .. } => {
                 match sub_state {
                     MainViewState::None => {
-                        Task::done(AppMsg::HideWindow)
+                        // query is kept, so results of all plugins are shown for it again
+                        if plugin_scope.take().is_some() {
+                            focused_search_result.reset(true);
+
+                            Task::done(AppMsg::UpdateSearchResults)
+                        } else {
+                            Task::done(AppMsg::HideWindow)
+                        }
                     }
                     MainViewState::SearchResultActionPanel { .. } => {
                         MainViewState::initial(sub_state);
@@ -255,7 +289,6 @@ impl Focus<SearchResult> for GlobalState {
                 plugin_view_data: PluginViewData {
                     top_level_view,
                     plugin_id,
-                    entrypoint_id,
                     ..
                 },
                 sub_state,
@@ -263,6 +296,15 @@ impl Focus<SearchResult> for GlobalState {
             } => {
                 match sub_state {
                     PluginViewState::None => {
+                        // search bar text is cleared first, the same way as in main view
+                        if let Some(widget_id) = client_context.get_view_search_bar_with_text() {
+                            return Task::done(AppMsg::WidgetEvent {
+                                widget_event: ComponentWidgetEvent::OnChangeSearchBar { widget_id, value: String::new() },
+                                plugin_id: plugin_id.clone(),
+                                render_location: UiRenderLocation::View,
+                            })
+                        }
+
                         if *top_level_view {
                             let plugin_id = plugin_id.clone();
 
@@ -272,8 +314,7 @@ impl Focus<SearchResult> for GlobalState {
                             ])
                         } else {
                             let plugin_id = plugin_id.clone();
-                            let entrypoint_id = entrypoint_id.clone();
-                            Task::done(AppMsg::OpenPluginView(plugin_id, entrypoint_id))
+                            Task::done(AppMsg::PopPluginView(plugin_id))
                         }
                     }
                     PluginViewState::ActionPanel { .. } => {
@@ -286,27 +327,59 @@ impl Focus<SearchResult> for GlobalState {
             }
         }
     }
-    fn next(&mut self, _client_context: &ClientContext) -> Task<AppMsg> {
+    fn next(&mut self, client_context: &mut ClientContext) -> Task<AppMsg> {
         match self {
-            GlobalState::MainView { .. } => Task::none(),
+            GlobalState::MainView { focused_search_result, sub_state, .. } => {
+                match sub_state {
+                    MainViewState::None => {
+                        focus_inline_view(focused_search_result, client_context, |client_context| client_context.focus_next_inline_view());
+
+                        Task::none()
+                    }
+                    MainViewState::SearchResultActionPanel { .. } | MainViewState::InlineViewActionPanel { .. } => Task::none()
+                }
+            },
             GlobalState::PluginView { .. } => Task::none(),
             GlobalState::ErrorView { .. } => Task::none(),
         }
     }
-    fn previous(&mut self, _client_context: &ClientContext) -> Task<AppMsg> {
+    fn previous(&mut self, client_context: &mut ClientContext) -> Task<AppMsg> {
         match self {
-            GlobalState::MainView { .. } => Task::none(),
+            GlobalState::MainView { focused_search_result, sub_state, .. } => {
+                match sub_state {
+                    MainViewState::None => {
+                        focus_inline_view(focused_search_result, client_context, |client_context| client_context.focus_previous_inline_view());
+
+                        Task::none()
+                    }
+                    MainViewState::SearchResultActionPanel { .. } | MainViewState::InlineViewActionPanel { .. } => Task::none()
+                }
+            },
             GlobalState::PluginView { .. } => Task::none(),
             GlobalState::ErrorView { .. } => Task::none(),
         }
     }
     fn up(&mut self, client_context: &mut ClientContext, _focus_list: &[SearchResult]) -> Task<AppMsg> {
         match self {
-            GlobalState::MainView { focused_search_result, sub_state, .. } => {
+            GlobalState::MainView { focused_search_result, sub_state, show_all_focused, show_all_rows, .. } => {
                 match sub_state {
                     MainViewState::None => {
-                        focused_search_result.focus_previous()
-                            .unwrap_or_else(|| Task::none())
+                        // "Show all results" row is between the result it is shown under and the next one
+                        if *show_all_focused {
+                            *show_all_focused = false;
+
+                            return Task::none()
+                        }
+
+                        match focused_search_result.focus_previous() {
+                            Some(task) => {
+                                *show_all_focused = focused_search_result.index
+                                    .is_some_and(|index| show_all_rows.contains_key(&index));
+
+                                task
+                            }
+                            None => Task::none()
+                        }
                     }
                     MainViewState::SearchResultActionPanel { focused_action_item } => {
                         focused_action_item.focus_previous()
@@ -334,20 +407,36 @@ impl Focus<SearchResult> for GlobalState {
     }
     fn down(&mut self, client_context: &mut ClientContext, focus_list: &[SearchResult]) -> Task<AppMsg> {
         match self {
-            GlobalState::MainView { focused_search_result, sub_state, .. } => {
+            GlobalState::MainView { focused_search_result, sub_state, show_all_focused, show_all_rows, .. } => {
                 match sub_state {
                     MainViewState::None => {
+                        let show_all_row = focused_search_result.index
+                            .is_some_and(|index| show_all_rows.contains_key(&index));
+
+                        if show_all_row && !*show_all_focused {
+                            *show_all_focused = true;
+
+                            return Task::none()
+                        }
+
                         if focus_list.len() != 0 {
-                            focused_search_result.focus_next(focus_list.len())
-                                .unwrap_or_else(|| Task::none())
+                            match focused_search_result.focus_next(focus_list.len()) {
+                                Some(task) => {
+                                    *show_all_focused = false;
+
+                                    task
+                                }
+                                None => Task::none()
+                            }
                         } else {
                             Task::none()
                         }
                     }
                     MainViewState::SearchResultActionPanel { focused_action_item } => {
                         if let Some(search_item) = focused_search_result.get(focus_list) {
-                            if search_item.entrypoint_actions.len() != 0 {
-                                focused_action_item.focus_next(search_item.entrypoint_actions.len())
+                            let action_count = action_count(search_item);
+                            if action_count != 0 {
+                                focused_action_item.focus_next(action_count)
                                     .unwrap_or_else(|| Task::none())
                             } else {
                                 Task::none()
@@ -357,7 +446,7 @@ impl Focus<SearchResult> for GlobalState {
                         }
                     }
                     MainViewState::InlineViewActionPanel { focused_action_item } => {
-                        match client_context.get_first_inline_view_action_panel() {
+                        match client_context.get_focused_inline_view_action_panel() {
                             Some(action_panel) => {
                                 if action_panel.action_count() != 0 {
                                     focused_action_item.focus_next(action_panel.action_count())
@@ -395,6 +484,10 @@ impl Focus<SearchResult> for GlobalState {
         match self {
             GlobalState::PluginView { sub_state, .. } => {
                 match sub_state {
+                    // grid items are laid out from right to left in right-to-left locales
+                    PluginViewState::None if is_rtl() => {
+                        client_context.focus_right()
+                    }
                     PluginViewState::None => {
                         client_context.focus_left()
                     }
@@ -409,6 +502,9 @@ impl Focus<SearchResult> for GlobalState {
         match self {
             GlobalState::PluginView { sub_state, .. } => {
                 match sub_state {
+                    PluginViewState::None if is_rtl() => {
+                        client_context.focus_left()
+                    }
                     PluginViewState::None => {
                         client_context.focus_right()
                     }
@@ -420,3 +516,16 @@ impl Focus<SearchResult> for GlobalState {
         }
     }
 }
+
+// first press moves focus from search results to inline views, following presses cycle between inline views
+fn focus_inline_view(focused_search_result: &mut ScrollHandle, client_context: &mut ClientContext, cycle: impl FnOnce(&mut ClientContext)) {
+    if client_context.get_focused_inline_view_container().is_none() {
+        return
+    }
+
+    if focused_search_result.index.is_some() {
+        focused_search_result.unfocus();
+    } else {
+        cycle(client_context);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::ui::scroll_handle::{ScrollHandle, ESTIMATED_ACTION_ITEM_HEIGHT};
+use crate::ui::scroll_handle::{ScrollHandle, estimated_action_item_height};
 
 #[derive(Debug, Clone)]
 pub enum PluginViewState {
@@ -20,7 +20,7 @@ impl PluginViewState {
 
     pub fn action_panel(prev_state: &mut PluginViewState, focus_first: bool) {
         *prev_state = Self::ActionPanel {
-            focused_action_item: ScrollHandle::new(focus_first, ESTIMATED_ACTION_ITEM_HEIGHT, 7),
+            focused_action_item: ScrollHandle::new(focus_first, estimated_action_item_height(), 7),
         }
     }
 }
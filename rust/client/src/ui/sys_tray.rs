@@ -1,64 +1,330 @@
 use image::ImageFormat;
+use tokio::sync::mpsc::UnboundedSender;
+use gauntlet_common::tr;
 
-pub fn create_tray() -> tray_icon::TrayIcon {
-    use tray_icon::TrayIconBuilder;
-    use tray_icon::menu::{MenuEvent, Menu, MenuItem, PredefinedMenuItem, AboutMetadataBuilder};
+#[derive(Debug, Clone)]
+pub enum TrayEvent {
+    OpenMainWindow,
+    OpenSettings,
+    TogglePausePlugins,
+    ToggleOfflineMode,
+    ToggleFocusMode,
+    Quit,
+}
 
-    MenuEvent::set_event_handler(Some(|event: MenuEvent| {
-        match event.id().as_ref() {
-            "GAUNTLET_OPEN_MAIN_WINDOW" => {
-                crate::open_window()
-            }
-            "GAUNTLET_OPEN_SETTING_WINDOW" => {
-                crate::open_settings_window()
-            }
-            _ => {}
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrayStatus {
+    Running,
+    PluginsPaused,
+    Reconnecting,
+}
+
+impl TrayStatus {
+    fn label(&self) -> String {
+        match self {
+            TrayStatus::Running => tr!("tray-status-running"),
+            TrayStatus::PluginsPaused => tr!("tray-status-plugins-paused"),
+            TrayStatus::Reconnecting => tr!("tray-status-reconnecting"),
+        }
+    }
+
+    fn pause_label(&self) -> String {
+        match self {
+            TrayStatus::PluginsPaused => tr!("tray-resume-plugins"),
+            TrayStatus::Running | TrayStatus::Reconnecting => tr!("tray-pause-plugins"),
+        }
+    }
+}
+
+fn offline_mode_label(offline_mode: bool) -> String {
+    if offline_mode {
+        tr!("tray-go-online")
+    } else {
+        tr!("tray-go-offline")
+    }
+}
+
+fn focus_mode_label(focus_mode: bool) -> String {
+    if focus_mode {
+        tr!("tray-disable-focus-mode")
+    } else {
+        tr!("tray-enable-focus-mode")
+    }
+}
+
+// rgba
+fn icon_image() -> (Vec<u8>, u32, u32) {
+    let bytes = include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../assets/linux/icon_256.png"));
+
+    let image = image::load_from_memory_with_format(bytes, ImageFormat::Png)
+        .expect("Failed to open icon path")
+        .into_rgba8();
+
+    let (width, height) = image.dimensions();
+
+    (image.into_raw(), width, height)
+}
+
+#[cfg(target_os = "linux")]
+pub struct SysTray {
+    handle: ksni::Handle<StatusNotifierTray>,
+}
+
+#[cfg(target_os = "linux")]
+impl SysTray {
+    pub fn new(event_sender: UnboundedSender<TrayEvent>, offline_mode: bool) -> Self {
+        let tray = StatusNotifierTray {
+            event_sender,
+            status: TrayStatus::Running,
+            offline_mode,
+            focus_mode: false,
+            tooltip: None,
+        };
+
+        let service = ksni::TrayService::new(tray);
+        let handle = service.handle();
+        service.spawn();
+
+        Self {
+            handle
+        }
+    }
+
+    pub fn set_status(&self, status: TrayStatus) {
+        self.handle.update(|tray| tray.status = status);
+    }
+
+    pub fn set_offline_mode(&self, offline_mode: bool) {
+        self.handle.update(|tray| tray.offline_mode = offline_mode);
+    }
+
+    pub fn set_focus_mode(&self, focus_mode: bool) {
+        self.handle.update(|tray| tray.focus_mode = focus_mode);
+    }
+
+    pub fn set_tooltip(&self, tooltip: Option<String>) {
+        self.handle.update(|tray| tray.tooltip = tooltip);
+    }
+}
+
+#[cfg(target_os = "linux")]
+struct StatusNotifierTray {
+    event_sender: UnboundedSender<TrayEvent>,
+    status: TrayStatus,
+    offline_mode: bool,
+    focus_mode: bool,
+    tooltip: Option<String>,
+}
+
+#[cfg(target_os = "linux")]
+impl StatusNotifierTray {
+    fn send(&self, event: TrayEvent) {
+        if let Err(err) = self.event_sender.send(event) {
+            tracing::warn!("error occurred when sending tray event {:?}", err)
         }
-    }));
-
-    let (tray_icon, muda_icon) = {
-        let bytes = include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../assets/linux/icon_256.png"));
-
-        let image = image::load_from_memory_with_format(bytes, ImageFormat::Png)
-            .expect("Failed to open icon path")
-            .into_rgba8();
-
-        let (width, height) = image.dimensions();
-        let rgba = image.into_raw();
-
-        let tray_icon = tray_icon::Icon::from_rgba(rgba.clone(), width, height)
-            .expect("Failed to open icon");
-
-        let muda_icon = tray_icon::menu::Icon::from_rgba(rgba, width, height)
-            .expect("Failed to open icon");
-
-        (tray_icon, muda_icon)
-    };
-
-    let about_metadata = AboutMetadataBuilder::new()
-        .name(Some("Gauntlet"))
-        .version(Some(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../VERSION"))))
-        .authors(Some(vec!["Exidex".to_string()]))
-        .credits(Some("Exidex".to_string()))
-        .license(Some("MPL-2.0"))
-        .website(Some("https://github.com/project-gauntlet/gauntlet"))
-        .icon(Some(muda_icon))
-        .build();
-
-    let menu = Menu::with_items(
-        &[
-            &MenuItem::new("Gauntlet", false, None),
-            &MenuItem::with_id("GAUNTLET_OPEN_MAIN_WINDOW", "Open", true, None),
-            &MenuItem::with_id("GAUNTLET_OPEN_SETTING_WINDOW", "Open Settings", true, None),
-            &PredefinedMenuItem::separator(),
-            &PredefinedMenuItem::about(Some("About..."), Some(about_metadata)),
-            &PredefinedMenuItem::quit(Some("Quit Gauntlet")),
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl ksni::Tray for StatusNotifierTray {
+    fn id(&self) -> String {
+        "gauntlet".to_string()
+    }
+
+    fn title(&self) -> String {
+        "Gauntlet".to_string()
+    }
+
+    fn icon_pixmap(&self) -> Vec<ksni::Icon> {
+        let (rgba, width, height) = icon_image();
+
+        // status notifier expects argb in network byte order
+        let data = rgba.chunks_exact(4)
+            .flat_map(|pixel| [pixel[3], pixel[0], pixel[1], pixel[2]])
+            .collect();
+
+        vec![ksni::Icon { width: width as i32, height: height as i32, data }]
+    }
+
+    fn tool_tip(&self) -> ksni::ToolTip {
+        let description = match &self.tooltip {
+            Some(tooltip) => format!("{}\n{}", self.status.label(), tooltip),
+            None => self.status.label(),
+        };
+
+        ksni::ToolTip {
+            title: "Gauntlet".to_string(),
+            description,
+            ..Default::default()
+        }
+    }
+
+    fn activate(&mut self, _x: i32, _y: i32) {
+        self.send(TrayEvent::OpenMainWindow)
+    }
+
+    fn menu(&self) -> Vec<ksni::MenuItem<Self>> {
+        use ksni::menu::StandardItem;
+
+        vec![
+            StandardItem {
+                label: self.status.label(),
+                enabled: false,
+                ..Default::default()
+            }.into(),
+            ksni::MenuItem::Separator,
+            StandardItem {
+                label: tr!("tray-open"),
+                activate: Box::new(|tray: &mut Self| tray.send(TrayEvent::OpenMainWindow)),
+                ..Default::default()
+            }.into(),
+            StandardItem {
+                label: tr!("tray-open-settings"),
+                activate: Box::new(|tray: &mut Self| tray.send(TrayEvent::OpenSettings)),
+                ..Default::default()
+            }.into(),
+            StandardItem {
+                label: self.status.pause_label(),
+                enabled: self.status != TrayStatus::Reconnecting,
+                activate: Box::new(|tray: &mut Self| tray.send(TrayEvent::TogglePausePlugins)),
+                ..Default::default()
+            }.into(),
+            StandardItem {
+                label: offline_mode_label(self.offline_mode),
+                enabled: self.status != TrayStatus::Reconnecting,
+                activate: Box::new(|tray: &mut Self| tray.send(TrayEvent::ToggleOfflineMode)),
+                ..Default::default()
+            }.into(),
+            StandardItem {
+                label: focus_mode_label(self.focus_mode),
+                enabled: self.status != TrayStatus::Reconnecting,
+                activate: Box::new(|tray: &mut Self| tray.send(TrayEvent::ToggleFocusMode)),
+                ..Default::default()
+            }.into(),
+            ksni::MenuItem::Separator,
+            StandardItem {
+                label: tr!("tray-quit"),
+                activate: Box::new(|tray: &mut Self| tray.send(TrayEvent::Quit)),
+                ..Default::default()
+            }.into(),
         ]
-    ).expect("unable to create tray menu");
-
-    TrayIconBuilder::new()
-        .with_menu(Box::new(menu))
-        .with_icon(tray_icon)
-        .build()
-        .expect("unable to create tray")
-}
\ No newline at end of file
+    }
+}
+
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+pub struct SysTray {
+    tray_icon: tray_icon::TrayIcon,
+    status_item: tray_icon::menu::MenuItem,
+    pause_item: tray_icon::menu::MenuItem,
+    offline_item: tray_icon::menu::MenuItem,
+    focus_item: tray_icon::menu::MenuItem,
+}
+
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+impl SysTray {
+    pub fn new(event_sender: UnboundedSender<TrayEvent>, offline_mode: bool) -> Self {
+        use tray_icon::TrayIconBuilder;
+        use tray_icon::menu::{MenuEvent, Menu, MenuItem, PredefinedMenuItem, AboutMetadataBuilder};
+
+        MenuEvent::set_event_handler(Some(move |event: MenuEvent| {
+            let event = match event.id().as_ref() {
+                "GAUNTLET_OPEN_MAIN_WINDOW" => TrayEvent::OpenMainWindow,
+                "GAUNTLET_OPEN_SETTING_WINDOW" => TrayEvent::OpenSettings,
+                "GAUNTLET_TOGGLE_PAUSE_PLUGINS" => TrayEvent::TogglePausePlugins,
+                "GAUNTLET_TOGGLE_OFFLINE_MODE" => TrayEvent::ToggleOfflineMode,
+                "GAUNTLET_TOGGLE_FOCUS_MODE" => TrayEvent::ToggleFocusMode,
+                "GAUNTLET_QUIT" => TrayEvent::Quit,
+                _ => return
+            };
+
+            if let Err(err) = event_sender.send(event) {
+                tracing::warn!("error occurred when sending tray event {:?}", err)
+            }
+        }));
+
+        let (tray_icon, muda_icon) = {
+            let (rgba, width, height) = icon_image();
+
+            let tray_icon = tray_icon::Icon::from_rgba(rgba.clone(), width, height)
+                .expect("Failed to open icon");
+
+            let muda_icon = tray_icon::menu::Icon::from_rgba(rgba, width, height)
+                .expect("Failed to open icon");
+
+            (tray_icon, muda_icon)
+        };
+
+        let about_metadata = AboutMetadataBuilder::new()
+            .name(Some("Gauntlet"))
+            .version(Some(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../VERSION"))))
+            .authors(Some(vec!["Exidex".to_string()]))
+            .credits(Some("Exidex".to_string()))
+            .license(Some("MPL-2.0"))
+            .website(Some("https://github.com/project-gauntlet/gauntlet"))
+            .icon(Some(muda_icon))
+            .build();
+
+        let status_item = MenuItem::new(TrayStatus::Running.label(), false, None);
+        let pause_item = MenuItem::with_id("GAUNTLET_TOGGLE_PAUSE_PLUGINS", TrayStatus::Running.pause_label(), true, None);
+        let offline_item = MenuItem::with_id("GAUNTLET_TOGGLE_OFFLINE_MODE", offline_mode_label(offline_mode), true, None);
+        let focus_item = MenuItem::with_id("GAUNTLET_TOGGLE_FOCUS_MODE", focus_mode_label(false), true, None);
+
+        let menu = Menu::with_items(
+            &[
+                &MenuItem::new("Gauntlet", false, None),
+                &status_item,
+                &PredefinedMenuItem::separator(),
+                &MenuItem::with_id("GAUNTLET_OPEN_MAIN_WINDOW", tr!("tray-open"), true, None),
+                &MenuItem::with_id("GAUNTLET_OPEN_SETTING_WINDOW", tr!("tray-open-settings"), true, None),
+                &pause_item,
+                &offline_item,
+                &focus_item,
+                &PredefinedMenuItem::separator(),
+                &PredefinedMenuItem::about(Some(&tr!("tray-about")), Some(about_metadata)),
+                &MenuItem::with_id("GAUNTLET_QUIT", tr!("tray-quit"), true, None),
+            ]
+        ).expect("unable to create tray menu");
+
+        let tray_icon = TrayIconBuilder::new()
+            .with_menu(Box::new(menu))
+            .with_icon(tray_icon)
+            .with_tooltip("Gauntlet")
+            .build()
+            .expect("unable to create tray");
+
+        Self {
+            tray_icon,
+            status_item,
+            pause_item,
+            offline_item,
+            focus_item,
+        }
+    }
+
+    pub fn set_status(&self, status: TrayStatus) {
+        self.status_item.set_text(status.label());
+        self.pause_item.set_text(status.pause_label());
+        self.pause_item.set_enabled(status != TrayStatus::Reconnecting);
+        self.offline_item.set_enabled(status != TrayStatus::Reconnecting);
+        self.focus_item.set_enabled(status != TrayStatus::Reconnecting);
+    }
+
+    pub fn set_offline_mode(&self, offline_mode: bool) {
+        self.offline_item.set_text(offline_mode_label(offline_mode));
+    }
+
+    pub fn set_focus_mode(&self, focus_mode: bool) {
+        self.focus_item.set_text(focus_mode_label(focus_mode));
+    }
+
+    pub fn set_tooltip(&self, tooltip: Option<String>) {
+        let tooltip = match tooltip {
+            Some(tooltip) => format!("Gauntlet\n{}", tooltip),
+            None => "Gauntlet".to_string(),
+        };
+
+        if let Err(err) = self.tray_icon.set_tooltip(Some(tooltip)) {
+            tracing::warn!("error occurred when setting tray tooltip {:?}", err)
+        }
+    }
+}
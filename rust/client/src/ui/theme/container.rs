@@ -52,14 +52,22 @@ pub enum ContainerStyle {
     GridInner,
     List,
     ListInner,
+    ListColumns,
     TextAccessory,
     TextAccessoryIcon,
     IconAccessory,
     InlineInner,
+    InlineInnerFocused,
     InlineName,
     HudInner,
     Hud,
     RootBottomPanelPrimaryActionButton,
+    Skeleton,
+    SkeletonCircle,
+    SkeletonItem,
+    MediaItemArtwork,
+    MediaItemProgress,
+    MediaItemProgressTrack,
 }
 
 pub enum ContainerStyleInner {
@@ -75,7 +83,12 @@ pub enum ContainerStyleInner {
     ContentImage,
     RootBottomPanel,
     InlineInner,
+    InlineInnerFocused,
     Hud,
+    Skeleton,
+    SkeletonCircle,
+    MediaItemProgress,
+    MediaItemProgressTrack,
 }
 
 
@@ -229,6 +242,19 @@ impl container::Catalog for GauntletComplexTheme {
                     ..Style::default()
                 }
             }
+            ContainerStyleInner::InlineInnerFocused => {
+                let theme = &self.inline_inner;
+
+                Style {
+                    background: Some(theme.background_color_focused.into()),
+                    border: Border {
+                        radius: theme.border_radius.into(),
+                        width: theme.border_width,
+                        color: theme.border_color,
+                    },
+                    ..Style::default()
+                }
+            }
             ContainerStyleInner::Hud => {
                 let theme = &self.hud;
                 let background_color = &theme.background_color;
@@ -244,6 +270,59 @@ impl container::Catalog for GauntletComplexTheme {
                     shadow: Default::default(),
                 }
             }
+            ContainerStyleInner::Skeleton => {
+                let theme = &self.skeleton;
+
+                Style {
+                    background: Some(theme.background_color.into()),
+                    border: Border {
+                        radius: theme.border_radius.into(),
+                        width: 0.0,
+                        color: Color::TRANSPARENT,
+                    },
+                    ..Style::default()
+                }
+            }
+            ContainerStyleInner::SkeletonCircle => {
+                let theme = &self.skeleton;
+
+                Style {
+                    background: Some(theme.background_color.into()),
+                    border: Border {
+                        // radius is clamped to half of the size when drawn
+                        radius: 1000.0f32.into(),
+                        width: 0.0,
+                        color: Color::TRANSPARENT,
+                    },
+                    ..Style::default()
+                }
+            }
+            ContainerStyleInner::MediaItemProgress => {
+                let theme = &self.media_item_progress;
+
+                Style {
+                    background: Some(theme.progress_color.into()),
+                    border: Border {
+                        radius: theme.border_radius.into(),
+                        width: 0.0,
+                        color: Color::TRANSPARENT,
+                    },
+                    ..Style::default()
+                }
+            }
+            ContainerStyleInner::MediaItemProgressTrack => {
+                let theme = &self.media_item_progress;
+
+                Style {
+                    background: Some(theme.background_color.into()),
+                    border: Border {
+                        radius: theme.border_radius.into(),
+                        width: 0.0,
+                        color: Color::TRANSPARENT,
+                    },
+                    ..Style::default()
+                }
+            }
         }
     }
 }
@@ -354,6 +433,13 @@ impl<'a, Message: 'a> ThemableWidget<'a, Message> for Container<'a, Message, Gau
                     .padding(theme.inline_inner.padding.to_iced())
                     .class(ContainerStyleInner::InlineInner)
             }
+            ContainerStyle::InlineInnerFocused => {
+                self
+                    .height(120)
+                    .max_height(120)
+                    .padding(theme.inline_inner.padding.to_iced())
+                    .class(ContainerStyleInner::InlineInnerFocused)
+            }
             ContainerStyle::InlineName => {
                 self.padding(theme.inline_name.padding.to_iced())
             }
@@ -410,9 +496,32 @@ impl<'a, Message: 'a> ThemableWidget<'a, Message> for Container<'a, Message, Gau
             ContainerStyle::List => {
                 self.padding(theme.list.padding.to_iced())
             }
+            ContainerStyle::Skeleton => {
+                self.class(ContainerStyleInner::Skeleton)
+            }
+            ContainerStyle::SkeletonCircle => {
+                self.class(ContainerStyleInner::SkeletonCircle)
+            }
+            ContainerStyle::SkeletonItem => {
+                self.padding(theme.list_item.padding.to_iced())
+            }
+            ContainerStyle::MediaItemArtwork => {
+                self.padding(theme.media_item_artwork.padding.to_iced())
+                    .max_width(theme.media_item_artwork.size.width)
+                    .max_height(theme.media_item_artwork.size.height)
+            }
+            ContainerStyle::MediaItemProgress => {
+                self.class(ContainerStyleInner::MediaItemProgress)
+            }
+            ContainerStyle::MediaItemProgressTrack => {
+                self.class(ContainerStyleInner::MediaItemProgressTrack)
+            }
             ContainerStyle::ListInner => {
                 self.padding(theme.list_inner.padding.to_iced())
             }
+            ContainerStyle::ListColumns => {
+                self.padding(theme.list_item.padding.to_iced())
+            }
             ContainerStyle::RootBottomPanelActionToggleText => {
                 self.padding(theme.root_bottom_panel_action_toggle_text.padding.to_iced())
             }
@@ -1,11 +1,12 @@
 use arc_swap::{ArcSwap, Guard};
-use gauntlet_common::model::{UiTheme, UiThemeColor, UiThemeMode};
+use gauntlet_common::model::{Density, UiTheme, UiThemeColor, UiThemeMode};
 use iced::application::DefaultStyle;
 use iced::{application, Color, Padding};
 use std::sync::Arc;
 
 pub mod button;
 pub mod text_input;
+pub mod text_editor;
 pub mod row;
 pub mod container;
 pub mod text;
@@ -24,6 +25,7 @@ pub type Element<'a, Message> = iced::Element<'a, Message, GauntletComplexTheme>
 
 #[derive(Debug, Clone)]
 pub struct GauntletComplexTheme {
+    text_size: f32,
     text: Color,
     root: ThemeRoot,
     popup: ThemeRoot,
@@ -104,7 +106,10 @@ pub struct GauntletComplexTheme {
     text_accessory: ThemePaddingTextColorSpacing,
     icon_accessory: ThemeIconAccessory,
     hud: ThemeRoot,
-    hud_content: ThemePaddingOnly
+    hud_content: ThemePaddingOnly,
+    skeleton: ThemeSkeleton,
+    media_item_artwork: ThemePaddingSize,
+    media_item_progress: ThemeMediaItemProgress,
 }
 
 impl Default for GauntletComplexTheme {
@@ -124,7 +129,8 @@ impl GauntletComplexTheme {
         set_theme(theme);
     }
 
-    pub fn new(simple_theme: UiTheme) -> GauntletComplexTheme {
+    // text size can only be set when application is started, so it is passed separately from density
+    pub fn new(simple_theme: UiTheme, density: Density, text_size: f32) -> GauntletComplexTheme {
         let UiTheme {
             mode,
             background,
@@ -153,7 +159,14 @@ impl GauntletComplexTheme {
             to_iced(&text_400)
         ];
 
+        let scale = density_scale(density);
+
+        let padding = |top: f32, right: f32, bottom: f32, left: f32| self::padding(top * scale, right * scale, bottom * scale, left * scale);
+        let padding_all = |value: f32| self::padding_all(value * scale);
+        let padding_axis = |vertical: f32, horizontal: f32| self::padding_axis(vertical * scale, horizontal * scale);
+
         GauntletComplexTheme {
+            text_size,
             text: text_100,
             root: ThemeRoot {
                 background_color: background_400,
@@ -280,6 +293,7 @@ impl GauntletComplexTheme {
             inline_inner: ThemeInline {
                 padding: padding_all(8.0),
                 background_color: background_200,
+                background_color_focused: background_300,
                 border_radius: content.border.radius,
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
@@ -581,10 +595,67 @@ impl GauntletComplexTheme {
             hud_content: ThemePaddingOnly {
                 padding: padding_axis(8.0, 16.0),
             },
+            skeleton: ThemeSkeleton {
+                background_color: match mode {
+                    UiThemeMode::Light => background_300,
+                    UiThemeMode::Dark => background_200
+                },
+                border_radius: content.border.radius,
+            },
+            media_item_artwork: ThemePaddingSize {
+                padding: padding_all(0.0),
+                size: ExternalThemeSize {
+                    width: 64.0,
+                    height: 64.0,
+                },
+            },
+            media_item_progress: ThemeMediaItemProgress {
+                progress_color: text_200,
+                background_color: match mode {
+                    UiThemeMode::Light => background_300,
+                    UiThemeMode::Dark => background_200
+                },
+                border_radius: 2.0,
+            },
         }
     }
 }
 
+// height of main list item with current density and text size, used to calculate scroll position
+pub fn estimated_main_list_item_height() -> f32 {
+    let theme = get_theme();
+
+    let item_padding = theme.main_list_item.padding.to_iced();
+    let text_padding = theme.main_list_item_text.padding.to_iced();
+
+    item_padding.vertical() + text_padding.vertical() + theme.text_size * LINE_HEIGHT
+}
+
+// explicitly specified text sizes are scaled relative to default text size
+pub fn text_size(size: f32) -> f32 {
+    size * get_theme().text_size / DEFAULT_TEXT_SIZE
+}
+
+pub fn density_text_size(density: Density) -> f32 {
+    match density {
+        Density::Compact => 14.0,
+        Density::Comfortable => DEFAULT_TEXT_SIZE,
+        Density::Large => 18.0,
+    }
+}
+
+fn density_scale(density: Density) -> f32 {
+    match density {
+        Density::Compact => 0.6,
+        Density::Comfortable => 1.0,
+        Density::Large => 1.3,
+    }
+}
+
+const DEFAULT_TEXT_SIZE: f32 = 16.0;
+// iced default line height
+const LINE_HEIGHT: f32 = 1.3;
+
 fn init_theme(theme: GauntletComplexTheme) {
     THEME.set(ArcSwap::new(Arc::new(theme))).expect("already set");
 }
@@ -748,10 +819,24 @@ pub struct ThemeCode {
     border_color: Color,
 }
 
+#[derive(Debug, Clone)]
+pub struct ThemeSkeleton {
+    background_color: Color,
+    border_radius: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct ThemeMediaItemProgress {
+    progress_color: Color,
+    background_color: Color,
+    border_radius: f32,
+}
+
 #[derive(Debug, Clone)]
 pub struct ThemeInline {
     padding: ThemePadding,
     background_color: Color,
+    background_color_focused: Color,
     border_radius: f32,
     border_width: f32,
     border_color: Color,
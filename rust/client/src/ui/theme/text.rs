@@ -1,7 +1,7 @@
 use iced::Renderer;
 use iced::widget::{Text, text};
 use iced::widget::text::Style;
-use crate::ui::theme::{Element, GauntletComplexTheme, get_theme, ThemableWidget};
+use crate::ui::theme::{Element, GauntletComplexTheme, get_theme, text_size, ThemableWidget};
 
 #[derive(Clone, Default)]
 pub enum TextStyle {
@@ -36,11 +36,11 @@ impl<'a, Message: 'a> ThemableWidget<'a, Message> for Text<'a, GauntletComplexTh
                 let theme = get_theme();
 
                 self.class(kind)
-                    .size(theme.metadata_item_label.text_size)
+                    .size(text_size(theme.metadata_item_label.text_size))
                     .into()
             }
             TextStyle::InlineName => {
-                self.size(15)
+                self.size(text_size(15.0))
                     .class(kind)
                     .into()
             }
@@ -0,0 +1,71 @@
+use iced::advanced::text::highlighter::PlainText;
+use iced::widget::text_editor::{Status, Style};
+use iced::widget::{text_editor, TextEditor};
+use iced::{Border, Renderer};
+
+use crate::ui::theme::{Element, GauntletComplexTheme, ThemableWidget, NOT_INTENDED_TO_BE_USED};
+
+pub enum TextEditorStyle {
+    FormInput,
+}
+
+impl text_editor::Catalog for GauntletComplexTheme {
+    type Class<'a> = TextEditorStyle;
+
+    fn default<'a>() -> Self::Class<'a> {
+        TextEditorStyle::FormInput
+    }
+
+    fn style(&self, _class: &Self::Class<'_>, status: Status) -> Style {
+        match status {
+            Status::Active => active(self),
+            Status::Hovered => focused(self), // TODO proper style
+            Status::Focused => focused(self),
+            Status::Disabled => active(self),
+        }
+    }
+}
+
+// text area in form looks the same as text field
+fn active(theme: &GauntletComplexTheme) -> Style {
+    let theme = &theme.form_input_text_field;
+
+    Style {
+        background: theme.background_color.into(),
+        border: Border {
+            radius: theme.border_radius.into(),
+            width: theme.border_width,
+            color: theme.border_color.into(),
+        },
+        icon: NOT_INTENDED_TO_BE_USED,
+        placeholder: theme.text_color_placeholder,
+        value: theme.text_color,
+        selection: theme.selection_color,
+    }
+}
+
+fn focused(theme: &GauntletComplexTheme) -> Style {
+    let theme = &theme.form_input_text_field;
+
+    Style {
+        background: theme.background_color_hovered.into(),
+        border: Border {
+            radius: theme.border_radius.into(),
+            width: theme.border_width,
+            color: theme.border_color_hovered.into(),
+        },
+        icon: NOT_INTENDED_TO_BE_USED,
+        placeholder: theme.text_color_placeholder,
+        value: theme.text_color,
+        selection: theme.selection_color,
+    }
+}
+
+impl<'a, Message: 'a + Clone> ThemableWidget<'a, Message> for TextEditor<'a, PlainText, Message, GauntletComplexTheme, Renderer> {
+    type Kind = TextEditorStyle;
+
+    fn themed(self, kind: TextEditorStyle) -> Element<'a, Message> {
+        self.class(kind)
+            .into()
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+
+use iced::window;
+use iced::Size;
+use iced::Task;
+
+use gauntlet_common::model::UiScale;
+
+use crate::ui::AppMsg;
+
+// iced multiplies scale factor returned by application with the one reported by operating system,
+// so to override it, manual scale is divided by system scale factor of the monitor the window is currently on
+pub struct UiScaleState {
+    scale: UiScale,
+    monitor_scale: HashMap<String, u16>,
+    current_monitor: Option<String>,
+    system_scale_factor: f64,
+}
+
+impl UiScaleState {
+    pub fn new(scale: UiScale, monitor_scale: HashMap<String, u16>) -> Self {
+        Self {
+            scale,
+            monitor_scale,
+            current_monitor: None,
+            system_scale_factor: 1.0,
+        }
+    }
+
+    pub fn set_scale(&mut self, scale: UiScale) {
+        self.scale = scale;
+    }
+
+    pub fn set_monitor(&mut self, monitor_size: Option<Size>, system_scale_factor: f32) {
+        let current_monitor = monitor_size.map(|size| monitor_key(size));
+
+        if current_monitor != self.current_monitor {
+            if let Some(current_monitor) = &current_monitor {
+                tracing::info!("Main window is shown on monitor {} with system scale factor {}", current_monitor, system_scale_factor);
+            }
+        }
+
+        self.current_monitor = current_monitor;
+        self.system_scale_factor = system_scale_factor as f64;
+    }
+
+    pub fn scale_factor(&self) -> f64 {
+        let monitor_percent = self.current_monitor
+            .as_ref()
+            .and_then(|monitor| self.monitor_scale.get(monitor))
+            .copied();
+
+        let percent = match (monitor_percent, self.scale) {
+            (Some(percent), _) => percent,
+            (None, UiScale::Manual { percent }) => percent,
+            (None, UiScale::Auto) => return 1.0,
+        };
+
+        if self.system_scale_factor <= 0.0 {
+            return 1.0
+        }
+
+        percent as f64 / 100.0 / self.system_scale_factor
+    }
+}
+
+// monitor is identified by its size, e.g. "3840x2160", because iced doesn't expose monitor names
+fn monitor_key(size: Size) -> String {
+    format!("{}x{}", size.width.round() as u32, size.height.round() as u32)
+}
+
+// not available on wayland, where main window is a layer shell surface,
+// so on wayland manual scale is applied on top of scale set by compositor
+pub fn detect_monitor(window_id: window::Id) -> Task<AppMsg> {
+    window::get_scale_factor(window_id)
+        .then(move |system_scale_factor| {
+            window::monitor_size(window_id)
+                .map(move |monitor_size| AppMsg::MainWindowMonitorDetected { monitor_size, system_scale_factor })
+        })
+}
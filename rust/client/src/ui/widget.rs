@@ -1,7 +1,13 @@
 use crate::model::UiViewEvent;
+use crate::ui::custom_widgets::drag_source::DragSource;
+use crate::ui::custom_widgets::focus_tracker::FocusTracker;
 use crate::ui::custom_widgets::loading_bar::LoadingBar;
+use crate::ui::direction::{directional, text_alignment};
+use crate::ui::drag_out::DragPayload;
+use crate::ui::fonts::{default_font, monospace_font};
+use crate::ui::skeleton::{skeleton, SkeletonShape};
 use crate::ui::grid_navigation::{grid_down_offset, grid_up_offset, GridSectionData};
-use crate::ui::scroll_handle::{ScrollHandle, ESTIMATED_MAIN_LIST_ITEM_HEIGHT};
+use crate::ui::scroll_handle::{ScrollHandle, estimated_main_list_item_height};
 use crate::ui::state::PluginViewState;
 use crate::ui::theme::button::ButtonStyle;
 use crate::ui::theme::container::ContainerStyle;
@@ -11,18 +17,23 @@ use crate::ui::theme::pick_list::PickListStyle;
 use crate::ui::theme::row::RowStyle;
 use crate::ui::theme::rule::RuleStyle;
 use crate::ui::theme::text::TextStyle;
+use crate::ui::theme::text_editor::TextEditorStyle;
 use crate::ui::theme::text_input::TextInputStyle;
 use crate::ui::theme::tooltip::TooltipStyle;
-use crate::ui::theme::{Element, ThemableWidget};
+use crate::ui::theme::{text_size, Element, ThemableWidget};
 use crate::ui::AppMsg;
-use gauntlet_common::model::{ActionPanelSectionWidget, ActionPanelSectionWidgetOrderedMembers, ActionPanelWidget, ActionPanelWidgetOrderedMembers, ActionWidget, CheckboxWidget, CodeBlockWidget, ContentWidget, ContentWidgetOrderedMembers, DatePickerWidget, DetailWidget, EmptyViewWidget, FormWidget, FormWidgetOrderedMembers, GridItemWidget, GridSectionWidget, GridSectionWidgetOrderedMembers, GridWidget, GridWidgetOrderedMembers, H1Widget, H2Widget, H3Widget, H4Widget, H5Widget, H6Widget, HorizontalBreakWidget, IconAccessoryWidget, Icons, ImageLike, ImageWidget, InlineSeparatorWidget, InlineWidget, InlineWidgetOrderedMembers, ListItemAccessories, ListItemWidget, ListSectionWidget, ListSectionWidgetOrderedMembers, ListWidget, ListWidgetOrderedMembers, MetadataIconWidget, MetadataLinkWidget, MetadataSeparatorWidget, MetadataTagItemWidget, MetadataTagListWidget, MetadataTagListWidgetOrderedMembers, MetadataValueWidget, MetadataWidget, MetadataWidgetOrderedMembers, ParagraphWidget, PasswordFieldWidget, PhysicalKey, PhysicalShortcut, PluginId, RootWidget, RootWidgetMembers, SearchBarWidget, SelectWidget, SelectWidgetOrderedMembers, SeparatorWidget, TextAccessoryWidget, TextFieldWidget, UiRenderLocation, UiWidgetId};
+use gauntlet_common::model::{ActionPanelSectionWidget, ActionPanelSectionWidgetOrderedMembers, ActionPanelWidget, ActionPanelWidgetOrderedMembers, ActionWidget, CheckboxWidget, CodeBlockWidget, ContentWidget, ContentWidgetOrderedMembers, DatePickerWidget, DetailWidget, EmptyViewWidget, FormWidget, FormWidgetOrderedMembers, GridItemWidget, GridSectionWidget, GridSectionWidgetOrderedMembers, GridWidget, GridWidgetOrderedMembers, H1Widget, H2Widget, H3Widget, H4Widget, H5Widget, H6Widget, HorizontalBreakWidget, IconAccessoryWidget, Icons, ImageLike, ImageWidget, InlineSeparatorWidget, InlineWidget, InlineWidgetOrderedMembers, ListColumnsWidget, ListColumnsWidgetOrderedMembers, ListItemAccessories, ListItemWidget, ListSectionWidget, ListSectionWidgetOrderedMembers, ListWidget, ListWidgetOrderedMembers, MediaItemWidget, MetadataIconWidget, MetadataLinkWidget, MetadataSeparatorWidget, MetadataTagItemWidget, MetadataTagListWidget, MetadataTagListWidgetOrderedMembers, MetadataValueWidget, MetadataWidget, MetadataWidgetOrderedMembers, ParagraphWidget, PasswordFieldWidget, PhysicalKey, PhysicalShortcut, PluginId, RootWidget, RootWidgetMembers, SearchBarWidget, SelectWidget, SelectWidgetOrderedMembers, SeparatorWidget, SkeletonVariant, SkeletonWidget, TabsWidget, TabsWidgetOrderedMembers, TextAccessoryWidget, TextAreaWidget, TextFieldWidget, UiRenderLocation, UiWidgetId};
 use gauntlet_common_ui::shortcut_to_text;
+use gauntlet_common::i18n::is_rtl;
+use gauntlet_common::tr;
 use iced::alignment::{Horizontal, Vertical};
 use iced::font::Weight;
 use iced::widget::image::Handle;
 use iced::widget::text::Shaping;
 use iced::widget::tooltip::Position;
-use iced::widget::{button, checkbox, column, container, horizontal_rule, horizontal_space, image, mouse_area, pick_list, row, scrollable, stack, text, text_input, tooltip, value, vertical_rule, Space};
+use iced::keyboard::key::Named;
+use iced::keyboard::Key;
+use iced::widget::{button, checkbox, column, container, horizontal_rule, horizontal_space, image, mouse_area, pick_list, row, scrollable, stack, text, text_editor, text_input, tooltip, value, vertical_rule, Space};
 use iced::{Alignment, Font, Length, Task};
 use iced_aw::date_picker::Date;
 use iced_aw::helpers::{date_picker, grid, grid_row};
@@ -32,14 +43,22 @@ use itertools::Itertools;
 use std::cell::Cell;
 use std::collections::HashMap;
 use std::fmt::{Debug, Display};
+use std::mem;
+use std::path::PathBuf;
 use std::sync::Arc;
 
+// how close to the end of the list, in rows, user needs to get before more items are requested from plugin
+const LOAD_MORE_THRESHOLD_ROWS: usize = 5;
+const TEXT_AREA_HEIGHT: f32 = 120.0;
+const MEDIA_ITEM_PROGRESS_HEIGHT: f32 = 4.0;
+const LIST_COLUMN_WIDTH: f32 = 100.0;
+
 #[derive(Debug)]
 pub struct ComponentWidgets<'b> {
     root_widget: &'b Option<Arc<RootWidget>>,
     state: &'b HashMap<UiWidgetId, ComponentWidgetState>,
     plugin_id: PluginId,
-    images: &'b HashMap<UiWidgetId, Vec<u8>>,
+    images: &'b HashMap<UiWidgetId, Handle>,
 }
 
 impl<'b> ComponentWidgets<'b> {
@@ -47,7 +66,7 @@ impl<'b> ComponentWidgets<'b> {
         root_widget: &'b Option<Arc<RootWidget>>,
         state: &'b HashMap<UiWidgetId, ComponentWidgetState>,
         plugin_id: PluginId,
-        images: &'b HashMap<UiWidgetId, Vec<u8>>
+        images: &'b HashMap<UiWidgetId, Handle>
     ) -> ComponentWidgets<'b> {
         Self {
             root_widget,
@@ -93,6 +112,15 @@ impl<'b> ComponentWidgets<'b> {
         }
     }
 
+    fn tabs_state(&self, widget_id: UiWidgetId) -> &TabsState {
+        let state = self.state.get(&widget_id).expect(&format!("requested state should always be present for id: {}", widget_id));
+
+        match state {
+            ComponentWidgetState::Tabs(state) => state,
+            _ => panic!("TabsState expected, {:?} found", state)
+        }
+    }
+
     fn root_state(&self, widget_id: UiWidgetId) -> &RootState {
         let state = self.state.get(&widget_id).expect(&format!("requested state should always be present for id: {}", widget_id));
 
@@ -101,6 +129,17 @@ impl<'b> ComponentWidgets<'b> {
             _ => panic!("TextFieldState expected, {:?} found", state)
         }
     }
+
+    // text editor borrows its content, so returned state lives as long as the state map itself
+    fn text_area_state(&self, widget_id: UiWidgetId) -> &'b TextAreaState {
+        let state: &'b HashMap<UiWidgetId, ComponentWidgetState> = self.state;
+        let state = state.get(&widget_id).expect(&format!("requested state should always be present for id: {}", widget_id));
+
+        match state {
+            ComponentWidgetState::TextArea(state) => state,
+            _ => panic!("TextAreaState expected, {:?} found", state)
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -108,7 +147,7 @@ pub struct ComponentWidgetsMut<'b> {
     root_widget: &'b mut Option<Arc<RootWidget>>,
     state: &'b mut HashMap<UiWidgetId, ComponentWidgetState>,
     plugin_id: PluginId,
-    images: &'b HashMap<UiWidgetId, Vec<u8>>,
+    images: &'b HashMap<UiWidgetId, Handle>,
 }
 
 impl<'b> ComponentWidgetsMut<'b> {
@@ -116,7 +155,7 @@ impl<'b> ComponentWidgetsMut<'b> {
         root_widget: &'b mut Option<Arc<RootWidget>>,
         state: &'b mut HashMap<UiWidgetId, ComponentWidgetState>,
         plugin_id: PluginId,
-        images: &'b HashMap<UiWidgetId, Vec<u8>>
+        images: &'b HashMap<UiWidgetId, Handle>
     ) -> ComponentWidgetsMut<'b> {
         Self {
             root_widget,
@@ -139,6 +178,15 @@ impl<'b> ComponentWidgetsMut<'b> {
         }
     }
 
+    fn tabs_state_mut_on_state(state: &mut HashMap<UiWidgetId, ComponentWidgetState>, widget_id: UiWidgetId) -> &mut TabsState {
+        let state = state.get_mut(&widget_id).expect(&format!("requested state should always be present for id: {}", widget_id));
+
+        match state {
+            ComponentWidgetState::Tabs(state) => state,
+            _ => panic!("TabsState expected, {:?} found", state)
+        }
+    }
+
     fn root_state_mut(&mut self, widget_id: UiWidgetId) -> &mut RootState {
         Self::root_state_mut_on_field(&mut self.state, widget_id)
     }
@@ -174,6 +222,9 @@ pub fn create_state(root_widget: &RootWidget) -> HashMap<UiWidgetId, ComponentWi
                             FormWidgetOrderedMembers::PasswordField(widget) => {
                                 result.insert(widget.__id__, ComponentWidgetState::text_field(&widget.value));
                             }
+                            FormWidgetOrderedMembers::TextArea(widget) => {
+                                result.insert(widget.__id__, ComponentWidgetState::text_area(&widget.value));
+                            }
                             FormWidgetOrderedMembers::Checkbox(widget) => {
                                 result.insert(widget.__id__, ComponentWidgetState::checkbox(&widget.value));
                             }
@@ -188,11 +239,15 @@ pub fn create_state(root_widget: &RootWidget) -> HashMap<UiWidgetId, ComponentWi
                     }
                 }
                 RootWidgetMembers::List(widget) => {
-                    result.insert(widget.__id__, ComponentWidgetState::root(ESTIMATED_MAIN_LIST_ITEM_HEIGHT, 7));
+                    result.insert(widget.__id__, ComponentWidgetState::root(estimated_main_list_item_height(), 7));
 
                     if let Some(widget) = &widget.content.search_bar {
                         result.insert(widget.__id__, ComponentWidgetState::text_field(&widget.value));
                     }
+
+                    if let Some(widget) = &widget.content.tabs {
+                        result.insert(widget.__id__, ComponentWidgetState::tabs(widget));
+                    }
                 }
                 RootWidgetMembers::Grid(widget) => {
                     // cursed heuristic
@@ -229,6 +284,10 @@ pub fn create_state(root_widget: &RootWidget) -> HashMap<UiWidgetId, ComponentWi
                     if let Some(widget) = &widget.content.search_bar {
                         result.insert(widget.__id__, ComponentWidgetState::text_field(&widget.value));
                     }
+
+                    if let Some(widget) = &widget.content.tabs {
+                        result.insert(widget.__id__, ComponentWidgetState::tabs(widget));
+                    }
                 }
                 RootWidgetMembers::Inline(_) => {}
             }
@@ -241,16 +300,63 @@ pub fn create_state(root_widget: &RootWidget) -> HashMap<UiWidgetId, ComponentWi
 #[derive(Debug, Clone)]
 pub enum ComponentWidgetState {
     TextField(TextFieldState),
+    TextArea(TextAreaState),
     Checkbox(CheckboxState),
     DatePicker(DatePickerState),
     Select(SelectState),
+    Tabs(TabsState),
     Root(RootState),
 }
 
 #[derive(Debug, Clone)]
 struct TextFieldState {
     text_input_id: text_input::Id,
-    state_value: String
+    state_value: String,
+    // value last rendered by plugin, none if plugin doesn't control the value
+    plugin_value: Option<String>,
+    // values sent to plugin which were not yet rendered back by it
+    sent_values: Vec<String>,
+}
+
+impl TextFieldState {
+    fn changed(&mut self, value: &str) {
+        self.state_value = value.to_string();
+
+        if self.plugin_value.is_some() {
+            self.sent_values.push(value.to_string());
+        }
+    }
+
+    // plugin renders values sent to it with a delay, so value rendered by plugin is only applied
+    // if it was set by plugin itself, otherwise text typed in the meantime, or being composed using IME, would be lost
+    fn rendered(&mut self, plugin_value: Option<&String>) {
+        let Some(plugin_value) = plugin_value else {
+            return;
+        };
+
+        if let Some(index) = self.sent_values.iter().position(|value| value == plugin_value) {
+            self.sent_values.drain(..=index);
+        } else if self.plugin_value.as_ref() != Some(plugin_value) {
+            self.state_value = plugin_value.clone();
+            self.sent_values.clear();
+        }
+
+        self.plugin_value = Some(plugin_value.clone());
+    }
+}
+
+#[derive(Debug)]
+struct TextAreaState {
+    content: text_editor::Content,
+}
+
+// editor content is not cloneable, cursor position and selection are lost when state is cloned
+impl Clone for TextAreaState {
+    fn clone(&self) -> Self {
+        TextAreaState {
+            content: text_editor::Content::with_text(&self.content.text())
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -269,10 +375,32 @@ struct SelectState {
     state_value: Option<String>
 }
 
+#[derive(Debug, Clone)]
+struct TabsState {
+    selected: Option<String>
+}
+
 #[derive(Debug, Clone)]
 struct RootState {
     show_action_panel: bool,
     focused_item: ScrollHandle,
+    // amount of items list had when more items were last requested, so the request is not repeated until they arrive
+    load_more_requested_at: Option<usize>,
+    // focus and scroll position of tabs which are not selected at the moment
+    tab_focused_items: HashMap<String, ScrollHandle>,
+}
+
+impl RootState {
+    // returns false if more items were already requested for the current amount of items
+    fn request_load_more(&mut self, item_count: usize) -> bool {
+        if self.load_more_requested_at == Some(item_count) {
+            return false;
+        }
+
+        self.load_more_requested_at = Some(item_count);
+
+        true
+    }
 }
 
 impl ComponentWidgetState {
@@ -280,13 +408,32 @@ impl ComponentWidgetState {
         ComponentWidgetState::Root(RootState {
             show_action_panel: false,
             focused_item: ScrollHandle::new(false, item_height, rows_per_view),
+            load_more_requested_at: None,
+            tab_focused_items: HashMap::new(),
         })
     }
 
     fn text_field(value: &Option<String>) -> ComponentWidgetState {
         ComponentWidgetState::TextField(TextFieldState {
             text_input_id: text_input::Id::unique(),
-            state_value: value.to_owned().unwrap_or_default()
+            state_value: value.to_owned().unwrap_or_default(),
+            plugin_value: value.to_owned(),
+            sent_values: vec![],
+        })
+    }
+
+    // state of widget which is rendered again is kept, only values controlled by plugin are updated
+    pub fn rerendered(mut self, rendered: &ComponentWidgetState) -> ComponentWidgetState {
+        if let (ComponentWidgetState::TextField(state), ComponentWidgetState::TextField(rendered)) = (&mut self, rendered) {
+            state.rendered(rendered.plugin_value.as_ref());
+        }
+
+        self
+    }
+
+    fn text_area(value: &Option<String>) -> ComponentWidgetState {
+        ComponentWidgetState::TextArea(TextAreaState {
+            content: text_editor::Content::with_text(value.as_deref().unwrap_or_default())
         })
     }
 
@@ -315,11 +462,22 @@ impl ComponentWidgetState {
             state_value: value.to_owned()
         })
     }
+
+    fn tabs(widget: &TabsWidget) -> ComponentWidgetState {
+        let selected = widget.value
+            .clone()
+            .or_else(|| ComponentWidgets::tab_ids(widget).into_iter().next());
+
+        ComponentWidgetState::Tabs(TabsState {
+            selected
+        })
+    }
 }
 
 #[derive(Debug, Clone)]
 pub enum TextRenderType {
     None,
+    Code,
     H1,
     H2,
     H3,
@@ -428,6 +586,82 @@ impl<'b> ComponentWidgets<'b> {
         text_input::focus(text_input_id.clone())
     }
 
+    pub fn search_bar_with_text(&self) -> Option<UiWidgetId> {
+        let Some(root_widget) = &self.root_widget else {
+            return None;
+        };
+
+        let Some(content) = &root_widget.content else {
+            return None;
+        };
+
+        let search_bar = match content {
+            RootWidgetMembers::List(widget) => widget.content.search_bar.as_ref(),
+            RootWidgetMembers::Grid(widget) => widget.content.search_bar.as_ref(),
+            _ => None
+        };
+
+        let widget_id = search_bar?.__id__;
+
+        let TextFieldState { state_value, .. } = self.text_field_state(widget_id);
+
+        if state_value.is_empty() {
+            None
+        } else {
+            Some(widget_id)
+        }
+    }
+
+    // whether typed text is put into search bar or text area of the view, see append_text
+    pub fn accepts_text(&self) -> bool {
+        let Some(root_widget) = &self.root_widget else {
+            return false;
+        };
+
+        let Some(content) = &root_widget.content else {
+            return false;
+        };
+
+        match content {
+            RootWidgetMembers::Form(widget) => {
+                widget.content.ordered_members
+                    .iter()
+                    .any(|members| matches!(members, FormWidgetOrderedMembers::TextArea(_)))
+            }
+            RootWidgetMembers::List(widget) => widget.content.search_bar.is_some(),
+            RootWidgetMembers::Grid(widget) => widget.content.search_bar.is_some(),
+            _ => false
+        }
+    }
+
+    // tabs which can be selected using ctrl+1..9, see select_tab_by_index
+    pub fn tab_count(&self) -> usize {
+        let Some(root_widget) = &self.root_widget else {
+            return 0;
+        };
+
+        let tabs_widget = match &root_widget.content {
+            Some(RootWidgetMembers::List(widget)) => widget.content.tabs.as_ref(),
+            Some(RootWidgetMembers::Grid(widget)) => widget.content.tabs.as_ref(),
+            _ => None
+        };
+
+        tabs_widget
+            .map(|tabs_widget| ComponentWidgets::tab_ids(tabs_widget).len())
+            .unwrap_or(0)
+    }
+
+    fn tab_ids(widget: &TabsWidget) -> Vec<String> {
+        widget.content.ordered_members
+            .iter()
+            .map(|members| {
+                match members {
+                    TabsWidgetOrderedMembers::Tab(tab) => tab.id.to_string()
+                }
+            })
+            .collect()
+    }
+
     fn grid_section_sizes(grid_widget: &GridWidget) -> Vec<GridSectionData> {
         let mut amount_per_section: Vec<GridSectionData> = vec![];
         let mut pending_section_size = 0;
@@ -510,6 +744,17 @@ impl<'b> ComponentWidgetsMut<'b> {
         };
 
         let widget_id = match content {
+            RootWidgetMembers::Form(widget) => {
+                let text: String = text.chars()
+                    .filter(|c| !c.is_control())
+                    .collect();
+
+                if text.is_empty() {
+                    return Task::none()
+                }
+
+                return self.edit_form_text_area(widget, text_editor::Edit::Paste(Arc::new(text)))
+            }
             RootWidgetMembers::List(widget) => {
                 match &widget.content.search_bar {
                     None => {
@@ -529,17 +774,33 @@ impl<'b> ComponentWidgetsMut<'b> {
             _ => return Task::none()
         };
 
-        let TextFieldState { text_input_id, state_value } = ComponentWidgetsMut::text_field_state_mut_on_state(&mut self.state, widget_id);
+        let TextFieldState { text_input_id, state_value, .. } = ComponentWidgetsMut::text_field_state_mut_on_state(&mut self.state, widget_id);
+
+        let text: String = text.chars()
+            .filter(|c| !c.is_control())
+            .collect();
 
-        if let Some(value) = text.chars().next().filter(|c| !c.is_control()) {
-            *state_value = format!("{}{}", state_value, value);
+        if !text.is_empty() {
+            let value = format!("{}{}", state_value, text);
 
-            text_input::focus(text_input_id.clone())
+            Task::batch([
+                text_input::focus(text_input_id.clone()),
+                self.change_search_bar(widget_id, value),
+            ])
         } else {
             Task::none()
         }
     }
 
+    // goes through the same path as text typed into focused search bar, so plugin is notified about the change
+    fn change_search_bar(&self, widget_id: UiWidgetId, value: String) -> Task<AppMsg> {
+        Task::done(AppMsg::WidgetEvent {
+            widget_event: ComponentWidgetEvent::OnChangeSearchBar { widget_id, value },
+            plugin_id: self.plugin_id.clone(),
+            render_location: UiRenderLocation::View,
+        })
+    }
+
     pub fn backspace_text(&mut self) -> Task<AppMsg> {
         let Some(root_widget) = &self.root_widget else {
             return Task::none();
@@ -550,6 +811,9 @@ impl<'b> ComponentWidgetsMut<'b> {
         };
 
         let widget_id = match content {
+            RootWidgetMembers::Form(widget) => {
+                return self.edit_form_text_area(widget, text_editor::Edit::Backspace)
+            }
             RootWidgetMembers::List(widget) => {
                 match &widget.content.search_bar {
                     None => {
@@ -569,13 +833,100 @@ impl<'b> ComponentWidgetsMut<'b> {
             _ => return Task::none()
         };
 
-        let TextFieldState { text_input_id, state_value } = ComponentWidgetsMut::text_field_state_mut_on_state(&mut self.state, widget_id);
+        let TextFieldState { text_input_id, state_value, .. } = ComponentWidgetsMut::text_field_state_mut_on_state(&mut self.state, widget_id);
 
         let mut chars = state_value.chars();
         chars.next_back();
-        *state_value = chars.as_str().to_owned();
+        let value = chars.as_str().to_owned();
 
-        text_input::focus(text_input_id.clone())
+        Task::batch([
+            text_input::focus(text_input_id.clone()),
+            self.change_search_bar(widget_id, value),
+        ])
+    }
+
+    // fields of the form are not focused when it is opened, so key presses
+    // that were not handled by any field are sent to the first text area of the form
+    fn edit_form_text_area(&self, widget: &FormWidget, edit: text_editor::Edit) -> Task<AppMsg> {
+        let text_area = widget.content.ordered_members
+            .iter()
+            .find_map(|members| match members {
+                FormWidgetOrderedMembers::TextArea(widget) => Some(widget),
+                _ => None
+            });
+
+        let Some(text_area) = text_area else {
+            return Task::none();
+        };
+
+        Task::done(AppMsg::WidgetEvent {
+            plugin_id: self.plugin_id.clone(),
+            render_location: UiRenderLocation::View,
+            widget_event: ComponentWidgetEvent::TextAreaAction {
+                widget_id: text_area.__id__,
+                action: text_editor::Action::Edit(edit),
+                max_length: text_area.max_length.map(|max_length| max_length as usize),
+            },
+        })
+    }
+
+    // returns None if view doesn't have tabs or there is no tab with this index, so shortcut can be handled by plugin instead
+    pub fn select_tab_by_index(&mut self, index: usize) -> Option<Task<AppMsg>> {
+        let Some((_, tabs_widget)) = self.tabs_widget() else {
+            return None;
+        };
+
+        let tab_id = ComponentWidgets::tab_ids(tabs_widget)
+            .into_iter()
+            .nth(index)?;
+
+        Some(self.select_tab(tab_id))
+    }
+
+    pub fn select_tab(&mut self, tab_id: String) -> Task<AppMsg> {
+        let Some((root_widget_id, tabs_widget)) = self.tabs_widget() else {
+            return Task::none();
+        };
+
+        let tabs_widget_id = tabs_widget.__id__;
+
+        let TabsState { selected } = ComponentWidgetsMut::tabs_state_mut_on_state(&mut self.state, tabs_widget_id);
+
+        if selected.as_ref() == Some(&tab_id) {
+            return Task::none();
+        }
+
+        let prev_tab_id = selected.replace(tab_id.clone());
+
+        let RootState { focused_item, tab_focused_items, .. } = ComponentWidgetsMut::root_state_mut_on_field(&mut self.state, root_widget_id);
+
+        let tab_focused_item = tab_focused_items.remove(&tab_id)
+            .unwrap_or_else(|| focused_item.fresh());
+
+        let prev_focused_item = mem::replace(focused_item, tab_focused_item);
+
+        if let Some(prev_tab_id) = prev_tab_id {
+            tab_focused_items.insert(prev_tab_id, prev_focused_item);
+        }
+
+        Task::batch([
+            focused_item.restore_scroll(),
+            Task::done(AppMsg::WidgetEvent {
+                plugin_id: self.plugin_id.clone(),
+                render_location: UiRenderLocation::View,
+                widget_event: ComponentWidgetEvent::TabChanged { widget_id: tabs_widget_id, tab_id },
+            })
+        ])
+    }
+
+    fn tabs_widget(&self) -> Option<(UiWidgetId, &TabsWidget)> {
+        let root_widget = self.root_widget.as_ref()?;
+
+        match root_widget.content.as_ref()? {
+            RootWidgetMembers::List(widget) => widget.content.tabs.as_ref().map(|tabs| (widget.__id__, tabs)),
+            RootWidgetMembers::Grid(widget) => widget.content.tabs.as_ref().map(|tabs| (widget.__id__, tabs)),
+            _ => None
+        }
     }
 
     pub fn focus_up(&mut self) -> Task<AppMsg> {
@@ -673,9 +1024,24 @@ impl<'b> ComponentWidgetsMut<'b> {
 
                 let item_focus_event = ComponentWidgets::list_item_focus_event(self.plugin_id.clone(), focused_item, widget);
 
+                let near_end = focused_item.index
+                    .map(|index| index + LOAD_MORE_THRESHOLD_ROWS >= total)
+                    .unwrap_or(false);
+
+                let load_more_event = if widget.has_more.unwrap_or(false) && near_end {
+                    Task::done(AppMsg::WidgetEvent {
+                        plugin_id: self.plugin_id.clone(),
+                        render_location: UiRenderLocation::View,
+                        widget_event: ComponentWidgetEvent::LoadMoreListItems { list_widget_id: widget.__id__, item_count: total },
+                    })
+                } else {
+                    Task::none()
+                };
+
                 Task::batch([
                     item_focus_event,
-                    focus_task
+                    focus_task,
+                    load_more_event,
                 ])
             }
             RootWidgetMembers::Grid(grid_widget) => {
@@ -802,6 +1168,17 @@ impl<'b> ComponentWidgetsMut<'b> {
 
 impl<'b> ComponentWidgets<'b> {
 
+    pub fn restore_scroll(&self) -> Task<AppMsg> {
+        let tasks = self.state.values()
+            .filter_map(|state| match state {
+                ComponentWidgetState::Root(RootState { focused_item, .. }) => Some(focused_item.restore_scroll()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        Task::batch(tasks)
+    }
+
     pub fn first_open(&self) -> AppMsg {
         let Some(root_widget) = &self.root_widget else {
             return AppMsg::Noop;
@@ -943,6 +1320,7 @@ impl<'b> ComponentWidgets<'b> {
     fn render_text<'a>(&self, value: &[String], context: TextRenderType) -> Element<'a, ComponentWidgetEvent> {
         let header = match context {
             TextRenderType::None => None,
+            TextRenderType::Code => None,
             TextRenderType::H1 => Some(34),
             TextRenderType::H2 => Some(30),
             TextRenderType::H3 => Some(24),
@@ -956,22 +1334,27 @@ impl<'b> ComponentWidgets<'b> {
 
         if let Some(size) = header {
             text = text
-                .size(size)
+                .size(text_size(size as f32))
                 .font(Font {
                     weight: Weight::Bold,
-                    ..Font::DEFAULT
+                    ..default_font()
                 })
         }
 
+        if let TextRenderType::Code = context {
+            text = text.font(monospace_font())
+        }
+
         text.into()
     }
 
-    pub fn render_root_widget<'a>(
+    pub fn render_root_widget(
         &self,
         plugin_view_state: &PluginViewState,
         entrypoint_name: Option<&String>,
+        breadcrumbs: &[String],
         action_shortcuts: &HashMap<String, PhysicalShortcut>,
-    ) -> Element<'a, ComponentWidgetEvent> {
+    ) -> Element<'b, ComponentWidgetEvent> {
         match &self.root_widget {
             None => {
                 horizontal_space()
@@ -997,17 +1380,19 @@ impl<'b> ComponentWidgets<'b> {
                                     widget.__id__,
                                     None,
                                     &None,
+                                    &None,
                                     &widget.content.actions,
                                     content,
                                     widget.is_loading.unwrap_or(false),
                                     plugin_view_state,
                                     entrypoint_name,
+                                    breadcrumbs,
                                     action_shortcuts,
                                 )
                             },
-                            RootWidgetMembers::Form(widget) => self.render_form_widget(widget, plugin_view_state, entrypoint_name, action_shortcuts),
-                            RootWidgetMembers::List(widget) => self.render_list_widget(widget, plugin_view_state, entrypoint_name, action_shortcuts),
-                            RootWidgetMembers::Grid(widget) => self.render_grid_widget(widget, plugin_view_state, entrypoint_name, action_shortcuts),
+                            RootWidgetMembers::Form(widget) => self.render_form_widget(widget, plugin_view_state, entrypoint_name, breadcrumbs, action_shortcuts),
+                            RootWidgetMembers::List(widget) => self.render_list_widget(widget, plugin_view_state, entrypoint_name, breadcrumbs, action_shortcuts),
+                            RootWidgetMembers::Grid(widget) => self.render_grid_widget(widget, plugin_view_state, entrypoint_name, breadcrumbs, action_shortcuts),
                             _ => {
                                 panic!("used inline widget in non-inline place")
                             }
@@ -1018,7 +1403,7 @@ impl<'b> ComponentWidgets<'b> {
         }
     }
 
-    pub fn render_root_inline_widget<'a>(&self, plugin_name: Option<&String>, entrypoint_name: Option<&String>) -> Element<'a, ComponentWidgetEvent> {
+    pub fn render_root_inline_widget<'a>(&self, plugin_name: Option<&String>, entrypoint_name: Option<&String>, focused: bool) -> Element<'a, ComponentWidgetEvent> {
         match &self.root_widget {
             None => {
                 horizontal_space()
@@ -1036,7 +1421,7 @@ impl<'b> ComponentWidgets<'b> {
                                 let entrypoint_name = entrypoint_name.expect("entrypoint name should always exist after render");
                                 let plugin_name = plugin_name.expect("entrypoint name should always exist after render");
 
-                                self.render_inline_widget(widget, plugin_name, entrypoint_name)
+                                self.render_inline_widget(widget, plugin_name, entrypoint_name, focused)
                             },
                             _ => {
                                 panic!("used non-inline widget in inline place")
@@ -1082,7 +1467,7 @@ impl<'b> ComponentWidgets<'b> {
 
         let icon: Element<_> = value(Bootstrap::BoxArrowUpRight)
             .font(BOOTSTRAP_FONT)
-            .size(16)
+            .size(text_size(16.0))
             .into();
 
         let icon = container(icon)
@@ -1121,7 +1506,7 @@ impl<'b> ComponentWidgets<'b> {
     fn render_metadata_icon_widget<'a>(&self, widget: &MetadataIconWidget, is_in_list: bool) -> Element<'a, ComponentWidgetEvent> {
         let value = value(icon_to_bootstrap(&widget.icon))
             .font(BOOTSTRAP_FONT)
-            .size(26)
+            .size(text_size(26.0))
             .into();
 
         render_metadata_item(&widget.label, value, is_in_list)
@@ -1222,8 +1607,92 @@ impl<'b> ComponentWidgets<'b> {
             .themed(ContainerStyle::ContentHorizontalBreak)
     }
 
+    fn render_skeleton_widget<'a>(&self, widget: &SkeletonWidget) -> Element<'a, ComponentWidgetEvent> {
+        let shape = match widget.variant {
+            None | Some(SkeletonVariant::Line) => SkeletonShape::Line,
+            Some(SkeletonVariant::Circle) => SkeletonShape::Circle,
+            Some(SkeletonVariant::Rect) => SkeletonShape::Rect,
+        };
+
+        let content = skeleton(shape, widget.width.map(|width| width as f32), widget.height.map(|height| height as f32));
+
+        container(content)
+            .width(Length::Fill)
+            .themed(ContainerStyle::ContentParagraph)
+    }
+
+    fn render_media_item_widget<'a>(&self, widget: &MediaItemWidget) -> Element<'a, ComponentWidgetEvent> {
+        let title: Element<_> = text(widget.title.to_string())
+            .shaping(Shaping::Advanced)
+            .into();
+
+        let mut info = vec![title];
+
+        if let Some(subtitle) = &widget.subtitle {
+            let subtitle: Element<_> = text(subtitle.to_string())
+                .shaping(Shaping::Advanced)
+                .themed(TextStyle::ListItemSubtitle);
+
+            info.push(subtitle)
+        }
+
+        if let Some(duration) = widget.duration.filter(|duration| *duration > 0.0) {
+            let position = widget.position.unwrap_or(0.0).clamp(0.0, duration);
+
+            // in permille, so it can be used as fill portion
+            let played = ((position / duration) * 1000.0).round() as u16;
+
+            let played_bar: Element<_> = container(Space::with_height(MEDIA_ITEM_PROGRESS_HEIGHT))
+                .width(Length::FillPortion(played))
+                .themed(ContainerStyle::MediaItemProgress);
+
+            let remaining_bar: Element<_> = container(Space::with_height(MEDIA_ITEM_PROGRESS_HEIGHT))
+                .width(Length::FillPortion(1000 - played))
+                .themed(ContainerStyle::MediaItemProgressTrack);
+
+            let bar: Element<_> = row(directional(vec![played_bar, remaining_bar]))
+                .width(Length::Fill)
+                .into();
+
+            let time: Element<_> = text(format!("{} / {}", format_media_time(position), format_media_time(duration)))
+                .themed(TextStyle::ListItemSubtitle);
+
+            let progress: Element<_> = row(directional(vec![bar, time]))
+                .align_y(Alignment::Center)
+                .spacing(8)
+                .into();
+
+            info.push(progress)
+        }
+
+        let info: Element<_> = column(info)
+            .width(Length::Fill)
+            .spacing(4)
+            .into();
+
+        let mut content = vec![info];
+
+        if let Some(artwork) = &widget.artwork {
+            let artwork = render_image(self.images, widget.__id__, artwork, None);
+
+            let artwork: Element<_> = container(artwork)
+                .themed(ContainerStyle::MediaItemArtwork);
+
+            content.insert(0, artwork)
+        }
+
+        let content: Element<_> = row(directional(content))
+            .align_y(Alignment::Center)
+            .spacing(12)
+            .into();
+
+        container(content)
+            .width(Length::Fill)
+            .themed(ContainerStyle::ContentParagraph)
+    }
+
     fn render_code_block_widget<'a>(&self, widget: &CodeBlockWidget) -> Element<'a, ComponentWidgetEvent> {
-        let content: Element<_> = self.render_text(&widget.content.text, TextRenderType::None);
+        let content: Element<_> = self.render_text(&widget.content.text, TextRenderType::Code);
 
         let content = container(content)
             .width(Length::Fill)
@@ -1249,6 +1718,8 @@ impl<'b> ComponentWidgets<'b> {
                     ContentWidgetOrderedMembers::H6(widget) => self.render_h6_widget(widget),
                     ContentWidgetOrderedMembers::HorizontalBreak(widget) => self.render_horizontal_break_widget(widget),
                     ContentWidgetOrderedMembers::CodeBlock(widget) => self.render_code_block_widget(widget),
+                    ContentWidgetOrderedMembers::Skeleton(widget) => self.render_skeleton_widget(widget),
+                    ContentWidgetOrderedMembers::MediaItem(widget) => self.render_media_item_widget(widget),
                 }
             })
             .collect();
@@ -1339,9 +1810,15 @@ impl<'b> ComponentWidgets<'b> {
         let widget_id = widget.__id__;
         let TextFieldState { state_value, .. } = self.text_field_state(widget.__id__);
 
-        text_input("", state_value)
+        let text_input = text_input(widget.placeholder.as_deref().unwrap_or_default(), state_value)
             .on_input(move |value| ComponentWidgetEvent::OnChangeTextField { widget_id, value })
-            .themed(TextInputStyle::FormInput)
+            .on_submit(ComponentWidgetEvent::SubmitTextField { widget_id })
+            .themed(TextInputStyle::FormInput);
+
+        FocusTracker::new(text_input)
+            .on_focus(ComponentWidgetEvent::FocusTextField { widget_id })
+            .on_blur(ComponentWidgetEvent::BlurTextField { widget_id })
+            .into()
     }
 
     fn render_password_field_widget<'a>(&self, widget: &PasswordFieldWidget) -> Element<'a, ComponentWidgetEvent> {
@@ -1354,6 +1831,27 @@ impl<'b> ComponentWidgets<'b> {
             .themed(TextInputStyle::FormInput)
     }
 
+    fn render_text_area_widget(&self, widget: &TextAreaWidget) -> Element<'b, ComponentWidgetEvent> {
+        let widget_id = widget.__id__;
+        let TextAreaState { content } = self.text_area_state(widget_id);
+
+        let max_length = widget.max_length.map(|max_length| max_length as usize);
+
+        text_editor(content)
+            .placeholder(widget.placeholder.clone().unwrap_or_default())
+            .height(TEXT_AREA_HEIGHT)
+            .on_action(move |action| ComponentWidgetEvent::TextAreaAction { widget_id, action, max_length })
+            .key_binding(move |key_press| {
+                match key_press.key.as_ref() {
+                    Key::Named(Named::Enter) if key_press.modifiers.command() => {
+                        Some(text_editor::Binding::Custom(ComponentWidgetEvent::SubmitTextArea { widget_id }))
+                    }
+                    _ => text_editor::Binding::from_key_press(key_press)
+                }
+            })
+            .themed(TextEditorStyle::FormInput)
+    }
+
     fn render_checkbox_widget<'a>(&self, widget: &CheckboxWidget) -> Element<'a, ComponentWidgetEvent> {
         let widget_id = widget.__id__;
         let CheckboxState { state_value } = self.checkbox_state(widget_id);
@@ -1426,13 +1924,14 @@ impl<'b> ComponentWidgets<'b> {
             .into()
     }
 
-    fn render_form_widget<'a>(
+    fn render_form_widget(
         &self,
         widget: &FormWidget,
         plugin_view_state: &PluginViewState,
         entrypoint_name: &str,
+        breadcrumbs: &[String],
         action_shortcuts: &HashMap<String, PhysicalShortcut>,
-    ) -> Element<'a, ComponentWidgetEvent> {
+    ) -> Element<'b, ComponentWidgetEvent> {
         let widget_id = widget.__id__;
         let RootState { show_action_panel, .. } = self.root_state(widget_id);
 
@@ -1446,9 +1945,12 @@ impl<'b> ComponentWidgets<'b> {
                                 .into()
                         }
                         Some(label) => {
+                            // label is aligned towards the input
+                            let label_alignment = if is_rtl() { Horizontal::Left } else { Horizontal::Right };
+
                             let label: Element<_> = text(label.to_string())
                                 .shaping(Shaping::Advanced)
-                                .align_x(Horizontal::Right)
+                                .align_x(label_alignment)
                                 .width(Length::Fill)
                                 .into();
 
@@ -1471,7 +1973,7 @@ impl<'b> ComponentWidgets<'b> {
                         after,
                     ];
 
-                    let row: Element<_> = row(content)
+                    let row: Element<_> = row(directional(content))
                         .align_y(Alignment::Center)
                         .themed(RowStyle::FormInput);
 
@@ -1482,6 +1984,7 @@ impl<'b> ComponentWidgets<'b> {
                     FormWidgetOrderedMembers::Separator(widget) => self.render_separator_widget(widget),
                     FormWidgetOrderedMembers::TextField(widget) => render_field(self.render_text_field_widget(widget), &widget.label),
                     FormWidgetOrderedMembers::PasswordField(widget) => render_field(self.render_password_field_widget(widget), &widget.label),
+                    FormWidgetOrderedMembers::TextArea(widget) => render_field(self.render_text_area_widget(widget), &widget.label),
                     FormWidgetOrderedMembers::Checkbox(widget) => render_field(self.render_checkbox_widget(widget), &widget.label),
                     FormWidgetOrderedMembers::DatePicker(widget) => render_field(self.render_date_picker_widget(widget), &widget.label),
                     FormWidgetOrderedMembers::Select(widget) => render_field(self.render_select_widget(widget), &widget.label)
@@ -1509,11 +2012,13 @@ impl<'b> ComponentWidgets<'b> {
             widget_id,
             None,
             &None,
+            &None,
             &widget.content.actions,
             content,
             widget.is_loading.unwrap_or(false),
             plugin_view_state,
             entrypoint_name,
+            breadcrumbs,
             action_shortcuts
         )
     }
@@ -1531,7 +2036,7 @@ impl<'b> ComponentWidgets<'b> {
 
                 let icon = value(icon_to_bootstrap(icon))
                     .font(BOOTSTRAP_FONT)
-                    .size(45)
+                    .size(text_size(45.0))
                     .themed(TextStyle::InlineSeparator);
 
                 let bot_rule: Element<_> = vertical_rule(1)
@@ -1548,7 +2053,7 @@ impl<'b> ComponentWidgets<'b> {
         }
     }
 
-    fn render_inline_widget<'a>(&self, widget: &InlineWidget, plugin_name: &str, entrypoint_name: &str) -> Element<'a, ComponentWidgetEvent> {
+    fn render_inline_widget<'a>(&self, widget: &InlineWidget, plugin_name: &str, entrypoint_name: &str, focused: bool) -> Element<'a, ComponentWidgetEvent> {
         let name: Element<_> = text(format!("{} - {}", plugin_name, entrypoint_name))
             .shaping(Shaping::Advanced)
             .themed(TextStyle::InlineName);
@@ -1571,11 +2076,17 @@ impl<'b> ComponentWidgets<'b> {
             })
             .collect();
 
-        let content: Element<_> = row(content)
+        let content: Element<_> = row(directional(content))
             .into();
 
+        let style = if focused {
+            ContainerStyle::InlineInnerFocused
+        } else {
+            ContainerStyle::InlineInner
+        };
+
         let content: Element<_> = container(content)
-            .themed(ContainerStyle::InlineInner);
+            .themed(style);
 
         let content: Element<_> = column(vec![name, content])
             .width(Length::Fill)
@@ -1614,6 +2125,32 @@ impl<'b> ComponentWidgets<'b> {
             content.insert(0, image)
         }
 
+        let actions: Vec<Element<_>> = widget.content.ordered_members
+            .iter()
+            .map(|members| {
+                match members {
+                    EmptyViewWidgetOrderedMembers::Action(action) => {
+                        let title: Element<_> = text(action.label.to_string())
+                            .shaping(Shaping::Advanced)
+                            .into();
+
+                        button(title)
+                            .on_press(ComponentWidgetEvent::ActionClick { widget_id: action.__id__, id: None })
+                            .themed(ButtonStyle::Action)
+                    }
+                }
+            })
+            .collect();
+
+        if !actions.is_empty() {
+            let actions: Element<_> = row(directional(actions))
+                .spacing(8)
+                .into();
+
+            content.push(Space::with_height(16).into());
+            content.push(actions)
+        }
+
         let content: Element<_> = column(content)
             .align_x(Alignment::Center)
             .into();
@@ -1629,12 +2166,13 @@ impl<'b> ComponentWidgets<'b> {
 
     fn render_search_bar_widget<'a>(&self, widget: &SearchBarWidget) -> Element<'a, ComponentWidgetEvent> {
         let widget_id = widget.__id__;
-        let TextFieldState { state_value, text_input_id } = self.text_field_state(widget_id);
+        let TextFieldState { state_value, text_input_id, .. } = self.text_field_state(widget_id);
 
         text_input(widget.placeholder.as_deref().unwrap_or_default(), state_value)
             .id(text_input_id.clone())
             .ignore_with_modifiers(true)
             .on_input(move |value| ComponentWidgetEvent::OnChangeSearchBar { widget_id, value })
+            .align_x(text_alignment())
             .themed(TextInputStyle::PluginSearchBar)
     }
 
@@ -1643,10 +2181,13 @@ impl<'b> ComponentWidgets<'b> {
         list_widget: &ListWidget,
         plugin_view_state: &PluginViewState,
         entrypoint_name: &str,
+        breadcrumbs: &[String],
         action_shortcuts: &HashMap<String, PhysicalShortcut>,
     ) -> Element<'a, ComponentWidgetEvent> {
         let widget_id = list_widget.__id__;
-        let RootState { show_action_panel, focused_item } = self.root_state(widget_id);
+        let RootState { show_action_panel, focused_item, .. } = self.root_state(widget_id);
+
+        let table = list_widget.content.list_columns.is_some();
 
         let mut pending: Vec<&ListItemWidget> = vec![];
         let mut items: Vec<Element<_>> = vec![];
@@ -1663,7 +2204,7 @@ impl<'b> ComponentWidgets<'b> {
                     if !pending.is_empty() {
                         let content: Vec<_> = pending
                             .iter()
-                            .map(|widget| self.render_list_item_widget(widget, focused_item.index, index_counter))
+                            .map(|widget| self.render_list_item_widget(widget, focused_item.index, index_counter, table))
                             .collect();
 
                         let content: Element<_> = column(content)
@@ -1674,7 +2215,7 @@ impl<'b> ComponentWidgets<'b> {
                         pending = vec![];
                     }
 
-                    items.push(self.render_list_section_widget(widget, focused_item.index, index_counter, first_section));
+                    items.push(self.render_list_section_widget(widget, focused_item.index, index_counter, first_section, table));
 
                     first_section = false;
                 },
@@ -1684,7 +2225,7 @@ impl<'b> ComponentWidgets<'b> {
         if !pending.is_empty() {
             let content: Vec<_> = pending
                 .iter()
-                .map(|widget| self.render_list_item_widget(widget, focused_item.index, index_counter))
+                .map(|widget| self.render_list_item_widget(widget, focused_item.index, index_counter, table))
                 .collect();
 
             let content: Element<_> = column(content)
@@ -1707,11 +2248,36 @@ impl<'b> ComponentWidgets<'b> {
                 .width(Length::Fill)
                 .themed(ContainerStyle::ListInner);
 
+            let has_more = list_widget.has_more.unwrap_or(false);
+            let item_count = index_counter.get();
+
             let content: Element<_> = scrollable(content)
                 .id(focused_item.scrollable_id.clone())
+                .on_scroll(move |viewport| {
+                    let scroll_position = viewport.absolute_offset().y;
+                    let distance_to_end = viewport.content_bounds().height - viewport.bounds().height - scroll_position;
+                    let near_end = distance_to_end < LOAD_MORE_THRESHOLD_ROWS as f32 * estimated_main_list_item_height();
+
+                    ComponentWidgetEvent::ScrollList {
+                        list_widget_id: widget_id,
+                        scroll_position,
+                        load_more_item_count: if has_more && near_end { Some(item_count) } else { None },
+                    }
+                })
                 .width(Length::Fill)
                 .into();
 
+            let content = match &list_widget.content.list_columns {
+                Some(widget) => {
+                    let header = self.render_list_columns_widget(widget);
+
+                    column([header, content])
+                        .width(Length::Fill)
+                        .into()
+                }
+                None => content,
+            };
+
             let content: Element<_> = container(content)
                 .width(Length::FillPortion(3))
                 .themed(ContainerStyle::List);
@@ -1747,11 +2313,13 @@ impl<'b> ComponentWidgets<'b> {
             widget_id,
             focused_item_id,
             &list_widget.content.search_bar,
+            &list_widget.content.tabs,
             &list_widget.content.actions,
             content,
             list_widget.is_loading.unwrap_or(false),
             plugin_view_state,
             entrypoint_name,
+            breadcrumbs,
             action_shortcuts
         )
     }
@@ -1762,12 +2330,13 @@ impl<'b> ComponentWidgets<'b> {
         item_focus_index: Option<usize>,
         index_counter: &Cell<usize>,
         first_section: bool,
+        table: bool,
     ) -> Element<'a, ComponentWidgetEvent> {
         let content: Vec<_> = widget.content.ordered_members
             .iter()
             .map(|members| {
                 match members {
-                    ListSectionWidgetOrderedMembers::ListItem(widget) => self.render_list_item_widget(widget, item_focus_index, index_counter)
+                    ListSectionWidgetOrderedMembers::ListItem(widget) => self.render_list_item_widget(widget, item_focus_index, index_counter, table)
                 }
             })
             .collect();
@@ -1780,11 +2349,49 @@ impl<'b> ComponentWidgets<'b> {
         render_section(content, Some(&widget.title), &widget.subtitle, section_title_style, TextStyle::ListSectionTitle, TextStyle::ListSectionSubtitle)
     }
 
+    // header row of list shown as a table, first column is above item titles, the rest are above accessories
+    fn render_list_columns_widget<'a>(&self, widget: &ListColumnsWidget) -> Element<'a, ComponentWidgetEvent> {
+        let mut content: Vec<Element<_>> = vec![];
+
+        for (index, members) in widget.content.ordered_members.iter().enumerate() {
+            match members {
+                ListColumnsWidgetOrderedMembers::ListColumn(widget) => {
+                    let title: Element<_> = text(widget.title.to_string())
+                        .shaping(Shaping::Advanced)
+                        .themed(TextStyle::ListSectionTitle);
+
+                    if index == 0 {
+                        let title: Element<_> = container(title)
+                            .width(Length::Fill)
+                            .themed(ContainerStyle::ListItemTitle);
+
+                        content.push(title);
+                    } else {
+                        content.push(render_list_column_cell(title));
+                    }
+                }
+            }
+        }
+
+        let content: Element<_> = row(directional(content))
+            .align_y(Alignment::Center)
+            .into();
+
+        let content: Element<_> = container(content)
+            .width(Length::Fill)
+            .themed(ContainerStyle::ListColumns);
+
+        container(content)
+            .width(Length::Fill)
+            .themed(ContainerStyle::ListInner)
+    }
+
     fn render_list_item_widget<'a>(
         &self,
         widget: &ListItemWidget,
         item_focus_index: Option<usize>,
-        index_counter: &Cell<usize>
+        index_counter: &Cell<usize>,
+        table: bool,
     ) -> Element<'a, ComponentWidgetEvent> {
         let icon: Option<Element<_>> = widget.icon
             .as_ref()
@@ -1819,9 +2426,15 @@ impl<'b> ComponentWidgets<'b> {
             let accessories: Vec<Element<_>> = widget.content.accessories
                 .iter()
                 .map(|accessory| {
-                    match accessory {
+                    let accessory = match accessory {
                         ListItemAccessories::_0(widget) => render_text_accessory(self.images, widget),
                         ListItemAccessories::_1(widget) => render_icon_accessory(self.images, widget)
+                    };
+
+                    if table {
+                        render_list_column_cell(accessory)
+                    } else {
+                        accessory
                     }
                 })
                 .collect();
@@ -1836,7 +2449,7 @@ impl<'b> ComponentWidgets<'b> {
             content.push(accessories);
         }
 
-        let content: Element<_> = row(content)
+        let content: Element<_> = row(directional(content))
             .align_y(Alignment::Center)
             .into();
 
@@ -1861,10 +2474,12 @@ impl<'b> ComponentWidgets<'b> {
             Some(widget_id) => ComponentWidgetEvent::RunPrimaryAction { widget_id: *widget_id, id: Some(widget.id.clone()) }
         };
 
-        button(content)
+        let content: Element<_> = button(content)
             .on_press(on_press_msg)
             .width(Length::Fill)
-            .themed(style)
+            .themed(style);
+
+        render_drag_source(content, widget.__id__, &widget.drag_file, &widget.drag_text)
     }
 
     fn render_grid_widget<'a>(
@@ -1872,9 +2487,10 @@ impl<'b> ComponentWidgets<'b> {
         grid_widget: &GridWidget,
         plugin_view_state: &PluginViewState,
         entrypoint_name: &str,
+        breadcrumbs: &[String],
         action_shortcuts: &HashMap<String, PhysicalShortcut>,
     ) -> Element<'a, ComponentWidgetEvent> {
-        let RootState { show_action_panel, focused_item } = self.root_state(grid_widget.__id__);
+        let RootState { show_action_panel, focused_item, .. } = self.root_state(grid_widget.__id__);
 
         let content = if grid_widget.content.ordered_members.is_empty() {
             match &grid_widget.content.empty_view {
@@ -1941,11 +2557,13 @@ impl<'b> ComponentWidgets<'b> {
             grid_widget.__id__,
             focused_item_id,
             &grid_widget.content.search_bar,
+            &grid_widget.content.tabs,
             &grid_widget.content.actions,
             content,
             grid_widget.is_loading.unwrap_or(false),
             plugin_view_state,
             entrypoint_name,
+            breadcrumbs,
             action_shortcuts
         )
     }
@@ -2020,6 +2638,8 @@ impl<'b> ComponentWidgets<'b> {
             .width(Length::Fill)
             .themed(style);
 
+        let content = render_drag_source(content, widget.__id__, &widget.drag_file, &widget.drag_text);
+
         let mut sub_content_left = vec![];
 
         if let Some(title) = &widget.title {
@@ -2052,7 +2672,7 @@ impl<'b> ComponentWidgets<'b> {
             .width(Length::Shrink)
             .into();
 
-        let sub_content: Element<_> = row(vec![sub_content_left, sub_content_right])
+        let sub_content: Element<_> = row(directional(vec![sub_content_left, sub_content_right]))
             .themed(RowStyle::GridItemTitle);
 
         let content: Element<_> = column(vec![content, sub_content])
@@ -2094,7 +2714,7 @@ impl<'b> ComponentWidgets<'b> {
                 let mut row_items: Vec<_> = row_items.collect();
                 row_items.resize_with(grid_width, || horizontal_space().into());
 
-                grid_row(row_items).into()
+                grid_row(directional(row_items)).into()
             })
             .collect();
 
@@ -2106,8 +2726,46 @@ impl<'b> ComponentWidgets<'b> {
         grid
     }
 
-    fn render_top_panel<'a>(&self, search_bar: &Option<SearchBarWidget>) -> Element<'a, ComponentWidgetEvent> {
-        let icon = value(Bootstrap::ArrowLeft)
+    fn render_tabs_widget<'a>(&self, widget: &TabsWidget) -> Element<'a, ComponentWidgetEvent> {
+        let widget_id = widget.__id__;
+        let TabsState { selected } = self.tabs_state(widget_id);
+
+        let tabs: Vec<Element<_>> = widget.content.ordered_members
+            .iter()
+            .map(|members| {
+                match members {
+                    TabsWidgetOrderedMembers::Tab(tab) => {
+                        let style = if selected.as_ref() == Some(&tab.id) {
+                            ButtonStyle::ActionFocused
+                        } else {
+                            ButtonStyle::Action
+                        };
+
+                        let title: Element<_> = text(tab.title.to_string())
+                            .shaping(Shaping::Advanced)
+                            .into();
+
+                        button(title)
+                            .on_press(ComponentWidgetEvent::SelectTab { widget_id, tab_id: tab.id.to_string() })
+                            .themed(style)
+                    }
+                }
+            })
+            .collect();
+
+        let tabs: Element<_> = row(directional(tabs))
+            .spacing(4)
+            .into();
+
+        container(tabs)
+            .width(Length::Fill)
+            .themed(ContainerStyle::RootTopPanel)
+    }
+
+    fn render_top_panel<'a>(&self, search_bar: &Option<SearchBarWidget>, breadcrumbs: &[String]) -> Element<'a, ComponentWidgetEvent> {
+        let icon = if is_rtl() { Bootstrap::ArrowRight } else { Bootstrap::ArrowLeft };
+
+        let icon = value(icon)
             .font(BOOTSTRAP_FONT);
 
         let back_button: Element<_> = button(icon)
@@ -2119,7 +2777,20 @@ impl<'b> ComponentWidgets<'b> {
             .map(|widget| self.render_search_bar_widget(widget))
             .unwrap_or_else(|| Space::with_width(Length::FillPortion(3)).into());
 
-        let top_panel: Element<_> = row(vec![back_button, search_bar_element])
+        let mut top_panel_content = vec![back_button];
+
+        // names of all views in navigation stack, shown only when plugin has pushed a view
+        if breadcrumbs.len() > 1 {
+            let breadcrumbs: Element<_> = text(breadcrumbs.join(" › "))
+                .shaping(Shaping::Advanced)
+                .into();
+
+            top_panel_content.push(breadcrumbs);
+        }
+
+        top_panel_content.push(search_bar_element);
+
+        let top_panel: Element<_> = row(directional(top_panel_content))
             .align_y(Alignment::Center)
             .themed(RowStyle::RootTopPanel);
 
@@ -2136,15 +2807,25 @@ impl<'b> ComponentWidgets<'b> {
         root_widget_id: UiWidgetId,
         focused_item_id: Option<String>,
         search_bar: &Option<SearchBarWidget>,
+        tabs: &Option<TabsWidget>,
         action_panel: &Option<ActionPanelWidget>,
         content: Element<'a, ComponentWidgetEvent>,
         is_loading: bool,
         plugin_view_state: &PluginViewState,
         entrypoint_name: &str,
+        breadcrumbs: &[String],
         action_shortcuts: &HashMap<String, PhysicalShortcut>,
     ) -> Element<'a, ComponentWidgetEvent>  {
 
-        let top_panel = self.render_top_panel(search_bar);
+        let top_panel = self.render_top_panel(search_bar, breadcrumbs);
+
+        let top_panel = match tabs {
+            None => top_panel,
+            Some(tabs) => {
+                column([top_panel, self.render_tabs_widget(tabs)])
+                    .into()
+            }
+        };
 
         let top_separator = if is_loading {
             LoadingBar::new()
@@ -2239,7 +2920,7 @@ fn render_metadata_item<'a>(label: &str, value: Element<'a, ComponentWidgetEvent
         let value = container(value)
             .themed(ContainerStyle::MetadataItemValueInList);
 
-        row(vec![label, space, value])
+        row(directional(vec![label, space, value]))
             .width(Length::Fill)
             .into()
     } else {
@@ -2262,7 +2943,7 @@ fn render_section<'a>(content: Element<'a, ComponentWidgetEvent>, title: Option<
     if let Some(title) = title {
         let title: Element<_> = text(title.to_string())
             .shaping(Shaping::Advanced)
-            .size(15)
+            .size(text_size(15.0))
             .themed(theme_kind_title_text);
 
         title_content.push(title)
@@ -2271,7 +2952,7 @@ fn render_section<'a>(content: Element<'a, ComponentWidgetEvent>, title: Option<
     if let Some(subtitle) = subtitle {
         let subtitle: Element<_> = text(subtitle.to_string())
             .shaping(Shaping::Advanced)
-            .size(15)
+            .size(text_size(15.0))
             .themed(theme_kind_subtitle_text);
 
         title_content.push(subtitle)
@@ -2415,7 +3096,7 @@ fn render_action_panel_items<'a, T: 'a + Clone>(
             .shaping(Shaping::Advanced)
             .font(Font {
                 weight: Weight::Bold,
-                ..Font::DEFAULT
+                ..default_font()
             })
             .themed(TextStyle::ActionSectionTitle);
 
@@ -2465,7 +3146,7 @@ fn render_action_panel_items<'a, T: 'a + Clone>(
                     let space: Element<_> = horizontal_space()
                         .into();
 
-                    row([text, space, shortcut_element])
+                    row(directional(vec![text, space, shortcut_element]))
                         .align_y(Alignment::Center)
                         .into()
                 } else {
@@ -2558,7 +3239,7 @@ pub fn render_root<'a, T: 'a + Clone>(
 
             let shortcut = render_shortcut(&shortcut);
 
-            let content: Element<_> = row(vec![label, shortcut])
+            let content: Element<_> = row(directional(vec![label, shortcut]))
                 .into();
 
             let content: Element<_> = button(content)
@@ -2575,7 +3256,7 @@ pub fn render_root<'a, T: 'a + Clone>(
 
     let (hide_action_panel, action_panel, bottom_panel) = match action_panel {
         Some(action_panel) => {
-            let actions_text: Element<_> = text("Actions")
+            let actions_text: Element<_> = text(tr!("action-panel-title"))
                 .themed(TextStyle::RootBottomPanelActionToggleText);
 
             let actions_text: Element<_> = container(actions_text)
@@ -2619,7 +3300,7 @@ pub fn render_root<'a, T: 'a + Clone>(
                 bottom_panel_content.push(rule);
             }
 
-            let action_panel_toggle_content: Element<_> = row(vec![actions_text, shortcut])
+            let action_panel_toggle_content: Element<_> = row(directional(vec![actions_text, shortcut]))
                 .into();
 
             let action_panel_toggle: Element<_> = button(action_panel_toggle_content)
@@ -2628,7 +3309,7 @@ pub fn render_root<'a, T: 'a + Clone>(
 
             bottom_panel_content.push(action_panel_toggle);
 
-            let bottom_panel: Element<_> = row(bottom_panel_content)
+            let bottom_panel: Element<_> = row(directional(bottom_panel_content))
                 .align_y(Alignment::Center)
                 .themed(RowStyle::RootBottomPanel);
 
@@ -2655,7 +3336,7 @@ pub fn render_root<'a, T: 'a + Clone>(
                 bottom_panel_content.push(primary_action);
             }
 
-            let bottom_panel: Element<_> = row(bottom_panel_content)
+            let bottom_panel: Element<_> = row(directional(bottom_panel_content))
                 .align_y(Alignment::Center)
                 .themed(RowStyle::RootBottomPanel);
 
@@ -2685,11 +3366,20 @@ pub fn render_root<'a, T: 'a + Clone>(
         if !hide_action_panel {
             let action_panel = render_action_panel(action_panel, on_action_click, action_panel_scroll_handle);
 
-            let action_panel: Element<_>= container(action_panel)
-                .padding(gauntlet_common_ui::padding(0.0, 8.0, 48.0, 0.0))
-                .align_right(Length::Fill)
-                .align_bottom(Length::Fill)
-                .into();
+            // action panel is anchored to the same side as action panel toggle
+            let action_panel: Element<_> = if is_rtl() {
+                container(action_panel)
+                    .padding(gauntlet_common_ui::padding(0.0, 0.0, 48.0, 8.0))
+                    .align_left(Length::Fill)
+                    .align_bottom(Length::Fill)
+                    .into()
+            } else {
+                container(action_panel)
+                    .padding(gauntlet_common_ui::padding(0.0, 8.0, 48.0, 0.0))
+                    .align_right(Length::Fill)
+                    .align_bottom(Length::Fill)
+                    .into()
+            };
 
             content.push(action_panel);
         }
@@ -2700,7 +3390,7 @@ pub fn render_root<'a, T: 'a + Clone>(
 }
 
 
-fn render_shortcut<'a, T: 'a>(shortcut: &PhysicalShortcut) -> Element<'a, T> {
+pub fn render_shortcut<'a, T: 'a>(shortcut: &PhysicalShortcut) -> Element<'a, T> {
     let mut result = vec![];
 
     let (
@@ -2740,12 +3430,26 @@ fn render_shortcut<'a, T: 'a>(shortcut: &PhysicalShortcut) -> Element<'a, T> {
         .themed(RowStyle::ActionShortcut)
 }
 
-fn render_image<'a, T: 'a + Clone>(images: &HashMap<UiWidgetId, Vec<u8>>, widget_id: UiWidgetId, image_data: &ImageLike, icon_style: Option<TextStyle>) -> Element<'a, T> {
+fn format_media_time(seconds: f64) -> String {
+    let seconds = seconds.max(0.0) as u64;
+
+    let hours = seconds / 3600;
+    let minutes = seconds / 60 % 60;
+    let seconds = seconds % 60;
+
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:02}", minutes, seconds)
+    }
+}
+
+fn render_image<'a, T: 'a + Clone>(images: &HashMap<UiWidgetId, Handle>, widget_id: UiWidgetId, image_data: &ImageLike, icon_style: Option<TextStyle>) -> Element<'a, T> {
     match image_data {
         ImageLike::ImageSource(_) => {
             match images.get(&widget_id) {
-                Some(bytes) => {
-                    image(Handle::from_bytes(bytes.clone()))
+                Some(handle) => {
+                    image(handle.clone())
                         .into()
                 }
                 None => {
@@ -2771,7 +3475,7 @@ fn render_image<'a, T: 'a + Clone>(images: &HashMap<UiWidgetId, Vec<u8>>, widget
     }
 }
 
-pub fn render_icon_accessory<'a, T: 'a + Clone>(images: &HashMap<UiWidgetId, Vec<u8>>, widget: &IconAccessoryWidget) -> Element<'a, T> {
+pub fn render_icon_accessory<'a, T: 'a + Clone>(images: &HashMap<UiWidgetId, Handle>, widget: &IconAccessoryWidget) -> Element<'a, T> {
     let icon = render_image(images, widget.__id__, &widget.icon, Some(TextStyle::IconAccessory));
 
     let content = container(icon)
@@ -2792,7 +3496,17 @@ pub fn render_icon_accessory<'a, T: 'a + Clone>(images: &HashMap<UiWidgetId, Vec
     }
 }
 
-pub fn render_text_accessory<'a, T: 'a + Clone>(images: &HashMap<UiWidgetId, Vec<u8>>, widget: &TextAccessoryWidget) -> Element<'a, T> {
+// cells have the same width in every row, so that they are aligned under column header
+fn render_list_column_cell<'a, T: 'a>(content: Element<'a, T>) -> Element<'a, T> {
+    let alignment = if is_rtl() { Horizontal::Left } else { Horizontal::Right };
+
+    container(content)
+        .width(Length::Fixed(LIST_COLUMN_WIDTH))
+        .align_x(alignment)
+        .into()
+}
+
+pub fn render_text_accessory<'a, T: 'a + Clone>(images: &HashMap<UiWidgetId, Handle>, widget: &TextAccessoryWidget) -> Element<'a, T> {
     let icon: Option<Element<_>> = widget.icon
         .as_ref()
         .map(|icon| render_image(images, widget.__id__, icon, Some(TextStyle::TextAccessory)));
@@ -2859,6 +3573,15 @@ pub enum ComponentWidgetEvent {
         widget_id: UiWidgetId,
         value: String
     },
+    SubmitTextField {
+        widget_id: UiWidgetId,
+    },
+    FocusTextField {
+        widget_id: UiWidgetId,
+    },
+    BlurTextField {
+        widget_id: UiWidgetId,
+    },
     OnChangePasswordField {
         widget_id: UiWidgetId,
         value: String
@@ -2867,6 +3590,14 @@ pub enum ComponentWidgetEvent {
         widget_id: UiWidgetId,
         value: String
     },
+    TextAreaAction {
+        widget_id: UiWidgetId,
+        action: text_editor::Action,
+        max_length: Option<usize>,
+    },
+    SubmitTextArea {
+        widget_id: UiWidgetId,
+    },
     SubmitDatePicker {
         widget_id: UiWidgetId,
         value: String
@@ -2893,11 +3624,33 @@ pub enum ComponentWidgetEvent {
         grid_widget_id: UiWidgetId,
         item_id: Option<String>,
     },
+    SelectTab {
+        widget_id: UiWidgetId,
+        tab_id: String,
+    },
+    TabChanged {
+        widget_id: UiWidgetId,
+        tab_id: String,
+    },
+    ScrollList {
+        list_widget_id: UiWidgetId,
+        scroll_position: f32,
+        // set if list has more items and it is scrolled close to the end
+        load_more_item_count: Option<usize>,
+    },
+    LoadMoreListItems {
+        list_widget_id: UiWidgetId,
+        item_count: usize,
+    },
     PreviousView,
     RunPrimaryAction {
         widget_id: UiWidgetId,
         id: Option<String>,
     },
+    StartDrag {
+        widget_id: UiWidgetId,
+        payload: DragPayload,
+    },
     Noop,
 }
 
@@ -2980,24 +3733,39 @@ impl ComponentWidgetEvent {
                 let state = state.expect("state should always exist for ");
 
                 {
-                    let ComponentWidgetState::TextField(TextFieldState { state_value, .. }) = state else {
+                    let ComponentWidgetState::TextField(state) = state else {
                         panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
                     };
 
-                    *state_value = value.clone();
+                    state.changed(&value);
                 }
 
                 Some(create_text_field_on_change_event(widget_id, Some(value)))
             }
+            ComponentWidgetEvent::SubmitTextField { widget_id } => {
+                let state = state.expect("state should always exist for ");
+
+                let ComponentWidgetState::TextField(TextFieldState { state_value, .. }) = state else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+                };
+
+                Some(create_text_field_on_submit_event(widget_id, Some(state_value.clone())))
+            }
+            ComponentWidgetEvent::FocusTextField { widget_id } => {
+                Some(create_text_field_on_focus_event(widget_id))
+            }
+            ComponentWidgetEvent::BlurTextField { widget_id } => {
+                Some(create_text_field_on_blur_event(widget_id))
+            }
             ComponentWidgetEvent::OnChangePasswordField { widget_id, value } => {
                 let state = state.expect("state should always exist for ");
 
                 {
-                    let ComponentWidgetState::TextField(TextFieldState { state_value, .. }) = state else {
+                    let ComponentWidgetState::TextField(state) = state else {
                         panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
                     };
 
-                    *state_value = value.clone();
+                    state.changed(&value);
                 }
 
                 Some(create_password_field_on_change_event(widget_id, Some(value)))
@@ -3006,15 +3774,58 @@ impl ComponentWidgetEvent {
                 let state = state.expect("state should always exist for ");
 
                 {
-                    let ComponentWidgetState::TextField(TextFieldState { state_value, .. }) = state else {
+                    let ComponentWidgetState::TextField(state) = state else {
                         panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
                     };
 
-                    *state_value = value.clone();
+                    state.changed(&value);
                 }
 
                 Some(create_search_bar_on_change_event(widget_id, Some(value)))
             }
+            ComponentWidgetEvent::TextAreaAction { widget_id, action, max_length } => {
+                let state = state.expect("state should always exist for ");
+
+                let ComponentWidgetState::TextArea(TextAreaState { content }) = state else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+                };
+
+                let is_edit = action.is_edit();
+
+                let action = match (action, max_length) {
+                    (text_editor::Action::Edit(edit), Some(max_length)) => {
+                        let remaining = max_length.saturating_sub(content.text().trim_end_matches('\n').chars().count());
+
+                        match edit {
+                            text_editor::Edit::Insert(_) | text_editor::Edit::Enter if remaining == 0 => return None,
+                            text_editor::Edit::Paste(text) => {
+                                let text: String = text.chars().take(remaining).collect();
+
+                                text_editor::Action::Edit(text_editor::Edit::Paste(Arc::new(text)))
+                            }
+                            edit => text_editor::Action::Edit(edit)
+                        }
+                    }
+                    (action, _) => action
+                };
+
+                content.perform(action);
+
+                if is_edit {
+                    Some(create_text_area_on_change_event(widget_id, Some(content.text())))
+                } else {
+                    None
+                }
+            }
+            ComponentWidgetEvent::SubmitTextArea { widget_id } => {
+                let state = state.expect("state should always exist for ");
+
+                let ComponentWidgetState::TextArea(TextAreaState { content }) = state else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+                };
+
+                Some(create_text_area_on_submit_event(widget_id, Some(content.text())))
+            }
             ComponentWidgetEvent::ToggleActionPanel { .. } => {
                 Some(UiViewEvent::AppEvent {
                     event: AppMsg::ToggleActionPanel { keyboard: false }
@@ -3026,6 +3837,47 @@ impl ComponentWidgetEvent {
             ComponentWidgetEvent::FocusGridItem { grid_widget_id, item_id } => {
                 Some(create_grid_on_item_focus_change_event(grid_widget_id, item_id))
             }
+            ComponentWidgetEvent::SelectTab { widget_id: _, tab_id } => {
+                Some(UiViewEvent::AppEvent {
+                    event: AppMsg::SelectPluginViewTab { tab_id }
+                })
+            }
+            ComponentWidgetEvent::StartDrag { .. } => {
+                // replaced with AppMsg::StartDrag by the window which rendered the item
+                None
+            }
+            ComponentWidgetEvent::TabChanged { widget_id, tab_id } => {
+                Some(create_tabs_on_change_event(widget_id, tab_id))
+            }
+            ComponentWidgetEvent::ScrollList { list_widget_id, scroll_position, load_more_item_count } => {
+                let state = state.expect("state should always exist for ");
+
+                let ComponentWidgetState::Root(root_state) = state else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", list_widget_id, state)
+                };
+
+                root_state.focused_item.on_scroll(scroll_position);
+
+                match load_more_item_count {
+                    Some(item_count) if root_state.request_load_more(item_count) => {
+                        Some(create_list_on_load_more_event(list_widget_id))
+                    }
+                    _ => None
+                }
+            }
+            ComponentWidgetEvent::LoadMoreListItems { list_widget_id, item_count } => {
+                let state = state.expect("state should always exist for ");
+
+                let ComponentWidgetState::Root(root_state) = state else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", list_widget_id, state)
+                };
+
+                if root_state.request_load_more(item_count) {
+                    Some(create_list_on_load_more_event(list_widget_id))
+                } else {
+                    None
+                }
+            }
             ComponentWidgetEvent::Noop | ComponentWidgetEvent::PreviousView => {
                 panic!("widget_id on these events is not supposed to be called")
             }
@@ -3049,17 +3901,40 @@ impl ComponentWidgetEvent {
             ComponentWidgetEvent::ToggleCheckbox { widget_id, .. } => widget_id,
             ComponentWidgetEvent::SelectPickList { widget_id, .. } => widget_id,
             ComponentWidgetEvent::OnChangeTextField { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::SubmitTextField { widget_id } => widget_id,
+            ComponentWidgetEvent::FocusTextField { widget_id } => widget_id,
+            ComponentWidgetEvent::BlurTextField { widget_id } => widget_id,
             ComponentWidgetEvent::OnChangePasswordField { widget_id, .. } => widget_id,
             ComponentWidgetEvent::OnChangeSearchBar { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::TextAreaAction { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::SubmitTextArea { widget_id } => widget_id,
             ComponentWidgetEvent::ToggleActionPanel { widget_id } => widget_id,
             ComponentWidgetEvent::FocusListItem { list_widget_id, .. } => list_widget_id,
             ComponentWidgetEvent::FocusGridItem { grid_widget_id, .. } => grid_widget_id,
+            ComponentWidgetEvent::SelectTab { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::TabChanged { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::ScrollList { list_widget_id, .. } => list_widget_id,
+            ComponentWidgetEvent::LoadMoreListItems { list_widget_id, .. } => list_widget_id,
             ComponentWidgetEvent::RunPrimaryAction { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::StartDrag { widget_id, .. } => widget_id,
             ComponentWidgetEvent::Noop | ComponentWidgetEvent::PreviousView => panic!("widget_id on these events is not supposed to be called"),
         }.to_owned()
     }
 }
 
+// file takes precedence if item specifies both
+fn render_drag_source<'a>(content: Element<'a, ComponentWidgetEvent>, widget_id: UiWidgetId, drag_file: &Option<String>, drag_text: &Option<String>) -> Element<'a, ComponentWidgetEvent> {
+    let payload = match (drag_file, drag_text) {
+        (Some(path), _) => DragPayload::File(PathBuf::from(path)),
+        (None, Some(text)) => DragPayload::Text(text.clone()),
+        (None, None) => return content,
+    };
+
+    DragSource::new(content)
+        .on_drag(ComponentWidgetEvent::StartDrag { widget_id, payload })
+        .into()
+}
+
 pub fn parse_date(value: &str) -> Option<(i32, u32, u32)> {
     let ymd: Vec<_> = value.split("-")
         .collect();
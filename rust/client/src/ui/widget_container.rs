@@ -8,17 +8,23 @@ use std::collections::HashMap;
 use std::mem;
 use std::ops::DerefMut;
 use std::sync::{Arc, Mutex};
+use iced::widget::image::Handle;
 use iced::Task;
 use crate::ui::AppMsg;
 
 pub struct PluginWidgetContainer {
     root_widget: Option<Arc<RootWidget>>,
     state: HashMap<UiWidgetId, ComponentWidgetState>,
-    images: HashMap<UiWidgetId, Vec<u8>>,
+    images: HashMap<UiWidgetId, Handle>,
     plugin_id: Option<PluginId>,
     plugin_name: Option<String>,
     entrypoint_id: Option<EntrypointId>,
-    entrypoint_name: Option<String>
+    entrypoint_name: Option<String>,
+    breadcrumbs: Vec<String>,
+    key_handlers: Vec<PhysicalShortcut>,
+    // state of views below the topmost one in navigation stack,
+    // restored when plugin goes back, so scroll position and focus are preserved
+    saved_states: Vec<HashMap<UiWidgetId, ComponentWidgetState>>,
 }
 
 impl PluginWidgetContainer {
@@ -31,6 +37,9 @@ impl PluginWidgetContainer {
             plugin_name: None,
             entrypoint_id: None,
             entrypoint_name: None,
+            breadcrumbs: vec![],
+            key_handlers: vec![],
+            saved_states: vec![],
         }
     }
 
@@ -42,21 +51,64 @@ impl PluginWidgetContainer {
         self.entrypoint_id.clone().expect("entrypoint id should always exist after render")
     }
 
+    pub fn get_entrypoint_name(&self) -> String {
+        self.entrypoint_name.clone().expect("entrypoint name should always exist after render")
+    }
+
+    // number of views in plugin navigation stack
+    pub fn get_view_depth(&self) -> usize {
+        self.breadcrumbs.len()
+    }
+
+    pub fn has_key_handler(&self, shortcut: &PhysicalShortcut) -> bool {
+        self.key_handlers.contains(shortcut)
+    }
+
     pub fn replace_view(
         &mut self,
         container: Arc<RootWidget>,
-        images: HashMap<UiWidgetId, Vec<u8>>,
+        images: HashMap<UiWidgetId, Handle>,
         plugin_id: &PluginId,
         plugin_name: &str,
         entrypoint_id: &EntrypointId,
-        entrypoint_name: &str
+        entrypoint_name: &str,
+        breadcrumbs: Vec<String>,
+        key_handlers: Vec<PhysicalShortcut>,
     ) -> AppMsg {
         tracing::trace!("replace_view is called. container: {:?}", container);
 
+        let first_open = match self.root_widget.as_ref() {
+            None => true,
+            Some(root_widget) => root_widget.content.is_none()
+        };
+
+        let current_depth = if first_open { 0 } else { self.breadcrumbs.len() };
+        let new_depth = breadcrumbs.len();
+
+        if first_open {
+            self.saved_states.clear();
+        }
+
+        let mut restored = false;
+        let pushed = current_depth > 0 && new_depth > current_depth;
+
+        if pushed {
+            self.saved_states.push(mem::take(&mut self.state));
+        } else if new_depth < current_depth {
+            self.saved_states.truncate(new_depth);
+
+            if let Some(state) = self.saved_states.pop() {
+                self.state = state;
+                restored = true;
+            }
+        }
+
         self.plugin_id = Some(plugin_id.clone());
         self.plugin_name = Some(plugin_name.to_string());
         self.entrypoint_id = Some(entrypoint_id.clone());
         self.entrypoint_name = Some(entrypoint_name.to_string());
+        self.breadcrumbs = breadcrumbs;
+        self.key_handlers = key_handlers;
         self.images = images;
 
         // use new state with values from old state but only widget ids which exists in new state
@@ -66,27 +118,35 @@ impl PluginWidgetContainer {
         for (key, value) in old_state.into_iter() {
             match self.state.entry(key) {
                 Entry::Occupied(mut entry) => {
+                    let value = value.rerendered(entry.get());
                     entry.insert(value);
                 }
                 Entry::Vacant(_) => {}
             }
         }
 
-        let first_open = match self.root_widget.as_ref() {
-            None => true,
-            Some(root_widget) => root_widget.content.is_none()
-        };
-
         self.root_widget = Some(container);
 
-        if first_open {
+        // search bar of pushed view is focused the same way as the one of the first view
+        if first_open || pushed {
             ComponentWidgets::new(&mut self.root_widget, &mut self.state, plugin_id.clone(), &self.images)
                 .first_open()
+        } else if restored {
+            AppMsg::RestorePluginView
         } else {
             AppMsg::Noop
         }
     }
 
+    pub fn restore_view(&self) -> Task<AppMsg> {
+        let widgets = ComponentWidgets::new(&self.root_widget, &self.state, self.get_plugin_id(), &self.images);
+
+        Task::batch([
+            widgets.restore_scroll(),
+            Task::done(widgets.first_open()),
+        ])
+    }
+
     pub fn handle_event(&mut self, plugin_id: PluginId, event: ComponentWidgetEvent) -> Option<UiViewEvent> {
         let widget_id = event.widget_id();
 
@@ -94,17 +154,17 @@ impl PluginWidgetContainer {
     }
 
     pub fn render_root_widget<'a>(
-        &self,
+        &'a self,
         plugin_view_state: &PluginViewState,
         action_shortcuts: &HashMap<String, PhysicalShortcut>,
     ) -> Element<'a, ComponentWidgetEvent> {
         ComponentWidgets::new(&self.root_widget, &self.state, self.get_plugin_id(), &self.images)
-            .render_root_widget(plugin_view_state, self.entrypoint_name.as_ref(), action_shortcuts)
+            .render_root_widget(plugin_view_state, self.entrypoint_name.as_ref(), &self.breadcrumbs, action_shortcuts)
     }
 
-    pub fn render_inline_root_widget<'a>(&self) -> Element<'a, ComponentWidgetEvent> {
+    pub fn render_inline_root_widget<'a>(&self, focused: bool) -> Element<'a, ComponentWidgetEvent> {
         ComponentWidgets::new(&self.root_widget, &self.state, self.get_plugin_id(), &self.images)
-            .render_root_inline_widget(self.plugin_name.as_ref(), self.entrypoint_name.as_ref())
+            .render_root_inline_widget(self.plugin_name.as_ref(), self.entrypoint_name.as_ref(), focused)
     }
 
     pub fn append_text(&mut self, text: &str) -> Task<AppMsg> {
@@ -131,6 +191,18 @@ impl PluginWidgetContainer {
             .toggle_action_panel()
     }
 
+    pub fn select_tab(&mut self, tab_id: String) -> Task<AppMsg> {
+        let plugin_id = self.get_plugin_id();
+        ComponentWidgetsMut::new(&mut self.root_widget, &mut self.state, plugin_id, &self.images)
+            .select_tab(tab_id)
+    }
+
+    pub fn select_tab_by_index(&mut self, index: usize) -> Option<Task<AppMsg>> {
+        let plugin_id = self.get_plugin_id();
+        ComponentWidgetsMut::new(&mut self.root_widget, &mut self.state, plugin_id, &self.images)
+            .select_tab_by_index(index)
+    }
+
     pub fn get_action_ids(&self) -> Vec<UiWidgetId> {
         ComponentWidgets::new(&self.root_widget, &self.state, self.get_plugin_id(), &self.images)
             .get_action_ids()
@@ -141,6 +213,21 @@ impl PluginWidgetContainer {
             .get_focused_item_id()
     }
 
+    pub fn get_search_bar_with_text(&self) -> Option<UiWidgetId> {
+        ComponentWidgets::new(&self.root_widget, &self.state, self.get_plugin_id(), &self.images)
+            .search_bar_with_text()
+    }
+
+    pub fn accepts_text(&self) -> bool {
+        ComponentWidgets::new(&self.root_widget, &self.state, self.get_plugin_id(), &self.images)
+            .accepts_text()
+    }
+
+    pub fn tab_count(&self) -> usize {
+        ComponentWidgets::new(&self.root_widget, &self.state, self.get_plugin_id(), &self.images)
+            .tab_count()
+    }
+
     pub fn get_action_panel(&self, action_shortcuts: &HashMap<String, PhysicalShortcut>) -> Option<ActionPanel> {
         ComponentWidgets::new(&self.root_widget, &self.state, self.get_plugin_id(), &self.images)
             .get_action_panel(action_shortcuts)
@@ -0,0 +1,95 @@
+use iced::window;
+use iced::Task;
+
+use crate::ui::AppMsg;
+
+// main window is always above other windows, but some platforms need additional hints for it to be shown above fullscreen applications.
+// on wayland main window is already placed on overlay layer, which compositors show above fullscreen surfaces
+pub fn apply_window_overlay(window_id: window::Id, enabled: bool) -> Task<AppMsg> {
+    #[cfg(target_os = "macos")]
+    {
+        window::run_with_handle(window_id, move |handle| macos::set_window_level(handle, enabled))
+            .map(|_| AppMsg::Noop)
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        if enabled {
+            window::run_with_handle(window_id, |handle| win32::bring_to_front(handle))
+                .map(|_| AppMsg::Noop)
+        } else {
+            Task::none()
+        }
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    {
+        let _ = (window_id, enabled);
+        Task::none()
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod macos {
+    use objc2_app_kit::{NSFloatingWindowLevel, NSScreenSaverWindowLevel, NSView, NSWindowCollectionBehavior};
+    use raw_window_handle::{RawWindowHandle, WindowHandle};
+
+    pub fn set_window_level(handle: WindowHandle<'_>, enabled: bool) {
+        let RawWindowHandle::AppKit(handle) = handle.as_raw() else {
+            return;
+        };
+
+        unsafe {
+            let ns_view: &NSView = handle.ns_view.cast().as_ref();
+
+            let Some(ns_window) = ns_view.window() else {
+                return;
+            };
+
+            if enabled {
+                // screen saver level is above fullscreen windows, full screen auxiliary allows window to be shown in fullscreen space
+                ns_window.setLevel(NSScreenSaverWindowLevel);
+                ns_window.setCollectionBehavior(NSWindowCollectionBehavior::CanJoinAllSpaces | NSWindowCollectionBehavior::FullScreenAuxiliary);
+            } else {
+                ns_window.setLevel(NSFloatingWindowLevel);
+                ns_window.setCollectionBehavior(NSWindowCollectionBehavior::Default);
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod win32 {
+    use raw_window_handle::{RawWindowHandle, WindowHandle};
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::System::Threading::{AttachThreadInput, GetCurrentThreadId};
+    use windows::Win32::UI::WindowsAndMessaging::{GetForegroundWindow, GetWindowThreadProcessId, SetForegroundWindow, SetWindowPos, HWND_TOPMOST, SWP_NOMOVE, SWP_NOSIZE, SWP_SHOWWINDOW};
+
+    pub fn bring_to_front(handle: WindowHandle<'_>) {
+        let RawWindowHandle::Win32(handle) = handle.as_raw() else {
+            return;
+        };
+
+        let hwnd = HWND(handle.hwnd.get() as _);
+
+        unsafe {
+            // fullscreen applications are often topmost themselves, so window is moved to the top of topmost windows every time it is shown
+            if let Err(err) = SetWindowPos(hwnd, HWND_TOPMOST, 0, 0, 0, 0, SWP_NOMOVE | SWP_NOSIZE | SWP_SHOWWINDOW) {
+                tracing::warn!("Unable to move window to the top: {:?}", err);
+            }
+
+            // only process which received last input event is allowed to take focus from foreground window,
+            // attaching to input of foreground window thread works around that
+            let foreground_thread = GetWindowThreadProcessId(GetForegroundWindow(), None);
+            let current_thread = GetCurrentThreadId();
+
+            if foreground_thread != 0 && foreground_thread != current_thread {
+                let _ = AttachThreadInput(current_thread, foreground_thread, true);
+                let _ = SetForegroundWindow(hwnd);
+                let _ = AttachThreadInput(current_thread, foreground_thread, false);
+            } else {
+                let _ = SetForegroundWindow(hwnd);
+            }
+        }
+    }
+}
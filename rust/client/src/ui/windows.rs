@@ -0,0 +1,56 @@
+use std::collections::HashMap;
+
+use iced::window;
+
+// what is shown in native window, all windows are driven by the same application loop
+pub enum AppWindow {
+    Main,
+    Hud {
+        display: String,
+    },
+    // view container is kept in client context, so plugin can render into it the same way as into main window
+    DetachedPluginView,
+    // settings state is kept in app model, only one settings window can be open
+    Settings,
+}
+
+pub struct AppWindows {
+    main_window_id: window::Id,
+    windows: HashMap<window::Id, AppWindow>,
+}
+
+impl AppWindows {
+    pub fn new(main_window_id: window::Id) -> Self {
+        Self {
+            main_window_id,
+            windows: HashMap::from([(main_window_id, AppWindow::Main)]),
+        }
+    }
+
+    pub fn main_window_id(&self) -> window::Id {
+        self.main_window_id
+    }
+
+    pub fn get(&self, window_id: window::Id) -> Option<&AppWindow> {
+        self.windows.get(&window_id)
+    }
+
+    pub fn find(&self, predicate: impl Fn(&AppWindow) -> bool) -> Option<window::Id> {
+        self.windows.iter()
+            .find(|(_, window)| predicate(window))
+            .map(|(window_id, _)| *window_id)
+    }
+
+    pub fn insert(&mut self, window_id: window::Id, window: AppWindow) {
+        self.windows.insert(window_id, window);
+    }
+
+    // main window is only hidden, never closed, so it is never removed
+    pub fn remove(&mut self, window_id: window::Id) -> Option<AppWindow> {
+        if window_id == self.main_window_id {
+            return None
+        }
+
+        self.windows.remove(&window_id)
+    }
+}
@@ -0,0 +1,60 @@
+use objc2::rc::Retained;
+use objc2::runtime::NSObject;
+use objc2::{declare_class, msg_send_id, mutability, sel, ClassType, DeclaredClass};
+use objc2_foundation::{NSAppleEventDescriptor, NSAppleEventManager};
+
+use crate::handle_deep_link;
+
+// four char codes from Carbon AppleEvents.h: kInternetEventClass, kAEGetURL and keyDirectObject
+const INTERNET_EVENT_CLASS: u32 = u32::from_be_bytes(*b"GURL");
+const GET_URL_EVENT_ID: u32 = u32::from_be_bytes(*b"GURL");
+const DIRECT_OBJECT_KEYWORD: u32 = u32::from_be_bytes(*b"----");
+
+declare_class!(
+    struct UrlEventHandler;
+
+    unsafe impl ClassType for UrlEventHandler {
+        type Super = NSObject;
+        type Mutability = mutability::InteriorMutable;
+        const NAME: &'static str = "GauntletUrlEventHandler";
+    }
+
+    impl DeclaredClass for UrlEventHandler {}
+
+    unsafe impl UrlEventHandler {
+        #[method(handleGetUrlEvent:withReplyEvent:)]
+        fn handle_get_url_event(&self, event: &NSAppleEventDescriptor, _reply_event: &NSAppleEventDescriptor) {
+            let uri = unsafe { event.paramDescriptorForKeyword(DIRECT_OBJECT_KEYWORD) }
+                .and_then(|descriptor| unsafe { descriptor.stringValue() });
+
+            match uri {
+                Some(uri) => {
+                    let uri = uri.to_string();
+
+                    // handled the same way as "gauntlet deeplink" cli command, event loop is not blocked by the request
+                    std::thread::spawn(move || handle_deep_link(uri));
+                }
+                None => tracing::warn!("Received url event without url"),
+            }
+        }
+    }
+);
+
+// macOS doesn't pass opened url as command line argument, instead it is sent as apple event
+// to the running application, which is registered as handler of "gauntlet" scheme in Info.plist.
+// handler is registered before event loop starts, so that url which launched the application is also received
+pub fn register_url_event_handler() {
+    let handler: Retained<UrlEventHandler> = unsafe { msg_send_id![UrlEventHandler::alloc(), init] };
+
+    unsafe {
+        NSAppleEventManager::sharedAppleEventManager().setEventHandler_andSelector_forEventClass_andEventID(
+            &handler,
+            sel!(handleGetUrlEvent:withReplyEvent:),
+            INTERNET_EVENT_CLASS,
+            GET_URL_EVENT_ID,
+        );
+    }
+
+    // event manager doesn't retain handler, it is needed until the process exits
+    std::mem::forget(handler);
+}
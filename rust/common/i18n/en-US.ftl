@@ -0,0 +1,241 @@
+## Shared
+
+close = Close
+loading = Loading...
+error-occurred = Error occurred
+error-unknown = Unknown error occurred
+error-please-report = Please report
+error-backend-timeout = Backend was unable to process message in a timely manner
+
+theme-auto-detect = Auto-detect
+theme-theme-file = Theme file present
+theme-config = Config setting present
+theme-macos-light = macOS Light
+theme-macos-dark = macOS Dark
+theme-legacy = Legacy
+theme-high-contrast = High Contrast
+
+window-position-mode-static = Static
+window-position-mode-active-monitor = Active Monitor
+search-results-sort-frecency = Most Used
+search-results-sort-relevance = Relevance
+search-results-sort-plugin = Plugin
+density-compact = Compact
+density-comfortable = Comfortable
+density-large = Large
+ui-scale-auto = Auto
+
+## Main window
+
+search-placeholder = Search...
+search-indexing = Indexing...
+toast-entrypoint-disabled = "{ $name }" disabled, press { $shortcut } to undo
+search-section-recent = Recent
+search-section-all = All
+search-section-fallback = No results, use query with
+search-section-plugin = { $name } ({ $count })
+search-show-all-plugin-results = Show all { $count } results from { $name }
+search-plugin-scope = Results from { $name }
+accessibility-search-field = Search
+accessibility-search-results = Search results
+preview-empty = No preview available
+preview-file-size = Size: { $size }
+preview-file-modified = Modified { $age }
+preview-file-binary = Binary file
+preview-folder-items = { $count ->
+    [one] { $count } item
+   *[other] { $count } items
+}
+preview-folder-more = and { $count } more
+preview-age-just-now = just now
+preview-age-minutes = { $count ->
+    [one] { $count } minute ago
+   *[other] { $count } minutes ago
+}
+preview-age-hours = { $count ->
+    [one] { $count } hour ago
+   *[other] { $count } hours ago
+}
+preview-age-days = { $count ->
+    [one] { $count } day ago
+   *[other] { $count } days ago
+}
+quicklook-failed = Unable to load file
+quicklook-unsupported = Quicklook is not available for this file
+quicklook-too-large = File is too large to show ({ $size } MB)
+quicklook-truncated = Only beginning of the file is shown
+
+shortcut-hints-title = Keyboard Shortcuts
+shortcut-hint-open = Open
+shortcut-hint-primary-action = Run primary action
+shortcut-hint-secondary-action = Run secondary action
+shortcut-hint-run-action = Run focused action
+shortcut-hint-next = Next item
+shortcut-hint-previous = Previous item
+shortcut-hint-navigate = Move focus
+shortcut-hint-toggle-action-panel = Show actions
+shortcut-hint-close-action-panel = Close actions
+shortcut-hint-focus-inline-view = Focus inline view
+shortcut-hint-undo = Undo
+shortcut-hint-select-tab = Select tab by number
+shortcut-hint-show-all-plugins = Show results of all plugins
+shortcut-hint-close-window = Close window
+shortcut-hint-back-to-search = Back to search
+shortcut-hint-back = Back
+shortcut-hint-toggle-shortcut-hints = Show or hide this list
+shortcut-hint-toggle-pin = Keep window open when it loses focus
+shortcut-hint-detach-view = Open in separate window
+shortcut-hint-toggle-preview = Show or hide preview of focused result
+shortcut-hint-quicklook = Quicklook focused file (after moving with arrow keys)
+
+action-panel-title = Actions
+action-run-command = Run Command
+action-open-view = Open View
+action-copy-entrypoint-name = Copy Entrypoint Name
+action-copy-plugin-id = Copy Plugin ID
+action-copy-deep-link = Copy Deep Link
+action-disable-entrypoint = Disable This Command
+
+hud-copied-to-clipboard = Copied to clipboard
+backend-reconnecting = Connection to server was lost, reconnecting...
+
+argument-optional = { $name } (optional)
+argument-required = "{ $name }" is required
+argument-file-not-found = File "{ $path }" doesn't exist
+
+deep-link-confirmation-title = Deep link wants to run "{ $entrypoint }" from "{ $plugin }"
+deep-link-confirmation-run = Run
+deep-link-confirmation-cancel = Cancel
+
+onboarding-title = Set up { $name }
+onboarding-required-preferences = Required preferences
+onboarding-continue = Continue
+preferences-open-settings = Open Settings
+
+plugin-view-error = Error occurred in plugin when trying to show the view
+plugin-view-error-report = Please report this to plugin author
+
+command-palette-placeholder = Search commands and views...
+
+tray-open = Open
+tray-open-settings = Open Settings
+tray-about = About...
+tray-quit = Quit Gauntlet
+tray-pause-plugins = Pause All Plugins
+tray-resume-plugins = Resume All Plugins
+tray-go-offline = Go Offline
+tray-go-online = Go Online
+tray-enable-focus-mode = Enable Focus Mode
+tray-disable-focus-mode = Disable Focus Mode
+tray-status-running = Status: Running
+tray-status-plugins-paused = Status: Plugins Paused
+tray-status-reconnecting = Status: Reconnecting to Server
+
+## Settings
+
+settings-unable-to-connect = Unable to connect to server. Please check if you have Gauntlet running on your PC
+
+settings-tab-general = General
+settings-tab-plugins = Plugins
+settings-tab-quicklinks = Quicklinks
+settings-tab-crash-reports = Crash Reports
+settings-tab-usage-stats = Usage Stats
+settings-tab-troubleshooting = Troubleshooting
+settings-tab-dev-console = Dev Console
+
+settings-download-in-progress = Download in progress
+settings-download-failed = Download failed
+settings-download-successful = Download successful
+
+settings-global-shortcut = Global Shortcut
+settings-shortcut-unset-hint = Backspace - Unset Shortcut
+settings-shortcut-stop-capturing-hint = Escape - Stop Capturing
+settings-theme = Theme
+settings-theme-file-present = Unable to change because theme config file is present
+settings-theme-defined-in-config = Unable to change because value is defined in config
+settings-window-position-mode = Window Position Mode
+settings-window-overlay = Overlay Mode
+settings-window-overlay-enabled = Show above fullscreen applications
+settings-density = Density
+settings-density-restart-hint = Text size is changed after restart
+settings-ui-scale = UI Scale
+settings-ui-scale-hint = Overrides scale detected from display
+settings-search-results-sort = Sort Search Results By
+settings-autostart = Autostart
+settings-autostart-enabled = Start Gauntlet on login
+
+settings-plugins-select-item = Select item from the list on the left
+settings-plugins-or = or
+settings-plugins-add-hint = Click '+' to add new plugin
+settings-plugins-description = Description
+settings-plugins-priority = Search priority
+settings-plugins-priority-hint = Results of this plugin are ranked higher when priority is above 1.0 and lower when it is below
+settings-plugins-notifications = Allow desktop notifications
+settings-plugins-notifications-hint = Notifications are also not shown while focus mode is enabled
+settings-plugins-environment = Environment variables
+settings-plugins-environment-name = Name
+settings-plugins-environment-value = Value
+settings-plugins-environment-add = Add variable
+settings-plugins-environment-hint = Press Enter to save. Variables are available to plugin using Environment.variables and to processes it starts, running plugin is restarted when they are saved
+settings-plugins-filesystem-roots = Approved directories
+settings-plugins-filesystem-roots-path = Absolute path to directory
+settings-plugins-filesystem-roots-add = Add directory
+settings-plugins-filesystem-roots-hint = Press Enter to save. Plugin can read and write files in these directories using FS api, in addition to ones declared in its manifest. Running plugin is restarted when they are saved
+settings-plugins-storage = Storage
+settings-plugins-storage-usage = Data: { $data }, cache: { $cache }, logs: { $logs }
+settings-plugins-storage-clear-cache = Clear cache
+settings-plugins-storage-clear-data = Clear data
+settings-plugins-storage-hint = Data directory of plugin can be resolved using Environment.pluginDataDir. Clearing data removes files and databases stored by plugin, preferences are kept. Running plugin is restarted
+settings-plugins-check-for-updates = Check for updates
+settings-plugins-remove = Remove plugin
+settings-plugins-repository-url = Enter Git Repository URL
+settings-plugins-supported-protocols = Supported protocols:
+settings-plugins-download = Download plugin
+
+settings-table-name = Name
+settings-table-type = Type
+settings-table-enabled = Enabled
+
+settings-entrypoint-type-command = Command
+settings-entrypoint-type-view = View
+settings-entrypoint-type-inline-view = Inline View
+settings-entrypoint-type-generator = Entrypoint Generator
+settings-entrypoint-type-search-provider = Search Provider
+
+settings-preferences-enter-value = Enter value...
+settings-preferences-select-value = Select value...
+
+settings-quicklinks-empty = No quicklinks yet. Use { "{query}" } in url to insert the rest of search query, set bang to search with "!bang query"
+settings-quicklinks-name = Name
+settings-quicklinks-bang = Bang
+settings-quicklinks-fallback = Fallback
+settings-quicklinks-save = Save
+settings-quicklinks-add = Add
+settings-quicklinks-cancel = Cancel
+
+settings-crash-reports-enabled = Save crash reports
+settings-crash-reports-description = When enabled, crash details are saved to { $path }. Reports are never sent automatically, submitting opens prefilled GitHub issue which can be reviewed before sending
+settings-crash-reports-empty = No crash reports
+settings-crash-reports-submit = Submit
+
+settings-usage-stats-enabled = Collect usage stats
+settings-usage-stats-description = Launch counts and search-to-launch times are stored only on this machine and are never sent anywhere
+settings-usage-stats-clear = Clear
+settings-usage-stats-empty = No usage stats yet
+settings-usage-stats-unknown = unknown
+settings-usage-stats-search-to-launch = Average search-to-launch time: { $duration }
+settings-usage-stats-most-used = Most used commands
+settings-usage-stats-launch-count = { $count ->
+    [one] { $count } launch
+   *[other] { $count } launches
+}
+settings-usage-stats-unused-plugins = Plugins never used
+
+settings-troubleshooting-log-dir = Log files are stored in { $path }
+settings-troubleshooting-refresh = Refresh
+settings-troubleshooting-no-logs = No log lines available
+
+settings-dev-console-select-plugin = Select plugin
+settings-dev-console-clear = Clear
+settings-dev-console-no-plugin = Select plugin to see its console output, renders and view events
+settings-dev-console-no-events = Waiting for events...
@@ -0,0 +1,371 @@
+use std::backtrace::Backtrace;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Context};
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+use crate::dirs::Dirs;
+use crate::model::SettingsCrashReport;
+
+const VERSION: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../VERSION"));
+const ISSUES_URL: &str = "https://github.com/project-gauntlet/gauntlet/issues/new";
+
+pub const CRASH_MONITOR_ENV: &str = "GAUNTLET_INTERNAL_CRASH_MONITOR";
+const CRASH_MONITOR_PROCESS_ENV: &str = "GAUNTLET_INTERNAL_CRASH_MONITOR_PROCESS";
+
+// urls that are too long are rejected by browsers and github, body is roughly 3 times longer after percent-encoding
+const MAX_SUBMITTED_REPORT_LEN: usize = 2000;
+
+// crash reports are opt-in, the value is set from settings after database is available.
+// settings are only available to server, so the value is also mirrored to a file
+// which is read by other processes, e.g. remote client or settings app
+static CRASH_REPORTS_ENABLED: AtomicBool = AtomicBool::new(false);
+
+static CRASH_PROCESS: Mutex<Option<&'static str>> = Mutex::new(None);
+
+// native crashes, e.g. segfault in native library, don't run panic hook.
+// minidump of crashed process can't be reliably written from inside of it, so it is written by separate monitor process
+static MINIDUMP_HANDLER: Mutex<Option<crash_handler::CrashHandler>> = Mutex::new(None);
+
+pub fn set_crash_reports_enabled(enabled: bool) {
+    CRASH_REPORTS_ENABLED.store(enabled, Ordering::SeqCst);
+
+    if let Err(err) = write_crash_reports_enabled_file(enabled) {
+        tracing::warn!("Unable to save crash reports setting: {:?}", err);
+    }
+
+    update_minidump_handler(enabled);
+}
+
+fn crash_reports_enabled_file() -> PathBuf {
+    Dirs::new().crash_dir().join("enabled")
+}
+
+fn write_crash_reports_enabled_file(enabled: bool) -> anyhow::Result<()> {
+    let path = crash_reports_enabled_file();
+
+    if enabled {
+        fs::create_dir_all(Dirs::new().crash_dir())?;
+        fs::write(&path, "")?;
+    } else if path.exists() {
+        fs::remove_file(&path)?;
+    }
+
+    Ok(())
+}
+
+// installs panic hook which saves crash report to crash directory before running default hook
+// and, if crash reports are enabled, starts monitor process which writes minidump on native crash
+pub fn init_crash_handler(process: &'static str) {
+    *CRASH_PROCESS.lock().unwrap() = Some(process);
+
+    let enabled = crash_reports_enabled_file().exists();
+
+    CRASH_REPORTS_ENABLED.store(enabled, Ordering::SeqCst);
+
+    update_minidump_handler(enabled);
+
+    let default_hook = std::panic::take_hook();
+
+    std::panic::set_hook(Box::new(move |info| {
+        if CRASH_REPORTS_ENABLED.load(Ordering::SeqCst) {
+            let message = match info.payload().downcast_ref::<&str>() {
+                Some(message) => message.to_string(),
+                None => match info.payload().downcast_ref::<String>() {
+                    Some(message) => message.clone(),
+                    None => "Box<dyn Any>".to_string(),
+                }
+            };
+
+            let location = info.location()
+                .map(|location| location.to_string())
+                .unwrap_or_else(|| "unknown".to_string());
+
+            let thread = std::thread::current();
+            let thread = thread.name().unwrap_or("unnamed");
+
+            let details = format!(
+                "Thread: {}\nLocation: {}\nMessage: {}\n\nBacktrace:\n{}\n",
+                thread,
+                location,
+                message,
+                Backtrace::force_capture()
+            );
+
+            if let Err(err) = write_crash_report(process, crash_timestamp(), &details) {
+                eprintln!("Unable to write crash report: {:?}", err);
+            }
+        }
+
+        default_hook(info)
+    }));
+}
+
+fn crash_timestamp() -> u128 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis())
+        .unwrap_or_default()
+}
+
+fn write_crash_report(process: &str, timestamp: u128, details: &str) -> anyhow::Result<()> {
+    let crash_dir = Dirs::new().crash_dir();
+
+    fs::create_dir_all(&crash_dir)
+        .context("Unable to create crash directory")?;
+
+    let report = format!(
+        "Version: {}\nPlatform: {} {}\nProcess: {}\nTimestamp: {}\n{}",
+        VERSION.trim(),
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        process,
+        timestamp,
+        details
+    );
+
+    let mut file = fs::File::create(crash_dir.join(format!("crash-{}.txt", timestamp)))
+        .context("Unable to create crash report file")?;
+
+    file.write_all(report.as_bytes())
+        .context("Unable to write crash report file")?;
+
+    Ok(())
+}
+
+// monitor process is started lazily, only when crash reports are enabled,
+// and it exits by itself when crashed process disconnects, e.g. after handler is dropped
+fn update_minidump_handler(enabled: bool) {
+    let Some(process) = *CRASH_PROCESS.lock().unwrap() else {
+        return;
+    };
+
+    let mut handler = MINIDUMP_HANDLER.lock().unwrap();
+
+    if !enabled {
+        *handler = None;
+        return;
+    }
+
+    if handler.is_some() {
+        return;
+    }
+
+    match attach_minidump_handler(process) {
+        Ok(new_handler) => *handler = Some(new_handler),
+        Err(err) => tracing::warn!("Unable to start crash monitor, minidumps will not be written: {:?}", err),
+    }
+}
+
+fn crash_monitor_socket(pid: u32) -> PathBuf {
+    std::env::temp_dir().join(format!("gauntlet-crash-monitor-{}", pid))
+}
+
+fn attach_minidump_handler(process: &str) -> anyhow::Result<crash_handler::CrashHandler> {
+    let socket = crash_monitor_socket(std::process::id());
+
+    let monitor = std::process::Command::new(std::env::current_exe()?)
+        .env(CRASH_MONITOR_ENV, &socket)
+        .env(CRASH_MONITOR_PROCESS_ENV, process)
+        .spawn()
+        .context("Unable to start crash monitor process")?;
+
+    // socket is created by monitor process after it is started
+    let mut attempts = 0;
+    let client = loop {
+        match minidumper::Client::with_name(minidumper::SocketName::Path(&socket)) {
+            Ok(client) => break client,
+            Err(_) if attempts < 50 => {
+                attempts += 1;
+                std::thread::sleep(Duration::from_millis(20));
+            }
+            Err(err) => return Err(anyhow!("Unable to connect to crash monitor: {:?}", err)),
+        }
+    };
+
+    let handler = crash_handler::CrashHandler::attach(unsafe {
+        crash_handler::make_crash_event(move |crash_context: &crash_handler::CrashContext| {
+            crash_handler::CrashEventResult::Handled(client.request_dump(crash_context).is_ok())
+        })
+    })?;
+
+    // monitor needs to be allowed to ptrace crashed process to read its memory
+    #[cfg(target_os = "linux")]
+    handler.set_ptracer(Some(monitor.id()));
+
+    #[cfg(not(target_os = "linux"))]
+    let _ = monitor;
+
+    Ok(handler)
+}
+
+// entry point of monitor process, minidump is written next to report with the same timestamp
+pub fn run_crash_monitor(socket: String) {
+    let process = std::env::var(CRASH_MONITOR_PROCESS_ENV)
+        .unwrap_or_else(|_| "unknown".to_string());
+
+    let socket = PathBuf::from(socket);
+
+    // leftover from previous process with the same pid
+    let _ = fs::remove_file(&socket);
+
+    let mut server = match minidumper::Server::with_name(minidumper::SocketName::Path(&socket)) {
+        Ok(server) => server,
+        Err(err) => {
+            tracing::error!("Unable to start crash monitor: {:?}", err);
+            return;
+        }
+    };
+
+    let handler = MinidumpServerHandler {
+        process,
+        timestamp: Mutex::new(0),
+    };
+
+    let shutdown = AtomicBool::new(false);
+
+    if let Err(err) = server.run(Box::new(handler), &shutdown, None) {
+        tracing::error!("Crash monitor stopped with error: {:?}", err);
+    }
+
+    let _ = fs::remove_file(&socket);
+}
+
+struct MinidumpServerHandler {
+    process: String,
+    timestamp: Mutex<u128>,
+}
+
+impl minidumper::ServerHandler for MinidumpServerHandler {
+    fn create_minidump_file(&self) -> Result<(fs::File, PathBuf), std::io::Error> {
+        let crash_dir = Dirs::new().crash_dir();
+
+        fs::create_dir_all(&crash_dir)?;
+
+        let timestamp = crash_timestamp();
+
+        *self.timestamp.lock().unwrap() = timestamp;
+
+        let path = crash_dir.join(format!("crash-{}.dmp", timestamp));
+
+        let file = fs::File::create(&path)?;
+
+        Ok((file, path))
+    }
+
+    fn on_minidump_created(&self, result: Result<minidumper::MinidumpBinary, minidumper::Error>) -> minidumper::LoopAction {
+        let timestamp = *self.timestamp.lock().unwrap();
+
+        let details = match result {
+            Ok(minidump) => format!("Message: Native crash\nMinidump: {}\n", minidump.path.display()),
+            Err(err) => format!("Message: Native crash\nMinidump: unable to write minidump: {:?}\n", err),
+        };
+
+        if let Err(err) = write_crash_report(&self.process, timestamp, &details) {
+            tracing::error!("Unable to write crash report: {:?}", err);
+        }
+
+        // crashed process is not going to request anything else
+        minidumper::LoopAction::Exit
+    }
+
+    fn on_message(&self, _kind: u32, _buffer: Vec<u8>) {}
+
+    fn on_client_disconnected(&self, _num_clients: usize) -> minidumper::LoopAction {
+        minidumper::LoopAction::Exit
+    }
+}
+
+// newest first
+pub fn crash_reports() -> anyhow::Result<Vec<SettingsCrashReport>> {
+    let crash_dir = Dirs::new().crash_dir();
+
+    if !crash_dir.exists() {
+        return Ok(vec![])
+    }
+
+    let mut reports = fs::read_dir(&crash_dir)
+        .context("Unable to read crash directory")?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let id = entry.file_name().to_string_lossy().to_string();
+
+            if !id.starts_with("crash-") || !id.ends_with(".txt") {
+                return None
+            }
+
+            let content = fs::read_to_string(entry.path())
+                .ok()?;
+
+            Some(SettingsCrashReport { id, content })
+        })
+        .collect::<Vec<_>>();
+
+    reports.sort_by(|a, b| crash_report_timestamp(&b.id).cmp(&crash_report_timestamp(&a.id)));
+
+    Ok(reports)
+}
+
+fn crash_report_timestamp(id: &str) -> u128 {
+    id.trim_start_matches("crash-")
+        .trim_end_matches(".txt")
+        .parse()
+        .unwrap_or_default()
+}
+
+fn crash_report_path(id: &str) -> anyhow::Result<PathBuf> {
+    // id comes from rpc, make sure it doesn't point outside of crash directory
+    if id.contains(['/', '\\']) || id.contains("..") {
+        return Err(anyhow!("Invalid crash report id: {}", id))
+    }
+
+    Ok(Dirs::new().crash_dir().join(id))
+}
+
+pub fn remove_crash_report(id: &str) -> anyhow::Result<()> {
+    let path = crash_report_path(id)?;
+
+    fs::remove_file(&path)
+        .context("Unable to remove crash report")?;
+
+    // only reports of native crashes have minidump
+    let minidump = path.with_extension("dmp");
+
+    if minidump.exists() {
+        fs::remove_file(minidump)
+            .context("Unable to remove crash minidump")?;
+    }
+
+    Ok(())
+}
+
+// reports are not sent anywhere automatically,
+// submitting opens prefilled github issue so that user can review it before sending
+pub fn crash_report_submit_url(id: &str) -> anyhow::Result<String> {
+    let content = fs::read_to_string(crash_report_path(id)?)
+        .context("Unable to read crash report")?;
+
+    let content = match content.char_indices().nth(MAX_SUBMITTED_REPORT_LEN) {
+        Some((index, _)) => format!("{}\n...", &content[..index]),
+        None => content
+    };
+
+    let title = content.lines()
+        .find_map(|line| line.strip_prefix("Message: "))
+        .map(|message| format!("Crash: {}", message))
+        .unwrap_or_else(|| "Crash".to_string());
+
+    let body = format!("```\n{}\n```", content);
+
+    Ok(format!(
+        "{}?title={}&body={}",
+        ISSUES_URL,
+        utf8_percent_encode(&title, NON_ALPHANUMERIC),
+        utf8_percent_encode(&body, NON_ALPHANUMERIC)
+    ))
+}
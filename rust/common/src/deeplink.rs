@@ -0,0 +1,161 @@
+use anyhow::anyhow;
+use percent_encoding::percent_decode_str;
+use url::Url;
+
+use crate::model::{EntrypointId, PluginId};
+
+pub const DEEP_LINK_SCHEME: &str = "gauntlet";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeepLink {
+    // gauntlet://open/<plugin-id>/<entrypoint-id>
+    Open {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+    },
+    // gauntlet://run/<plugin-id>/<entrypoint-id>?arg=<value>&arg=<value>
+    Run {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        arguments: Vec<String>,
+    },
+    // gauntlet://search?query=<value>
+    Search {
+        query: String,
+    },
+}
+
+impl DeepLink {
+    pub fn parse(value: &str) -> anyhow::Result<DeepLink> {
+        let url = Url::parse(value)?;
+
+        if url.scheme() != DEEP_LINK_SCHEME {
+            return Err(anyhow!("Unsupported deep link scheme: {}", url.scheme()))
+        }
+
+        let segments = url.path_segments()
+            .map(|segments| {
+                segments
+                    .filter(|segment| !segment.is_empty())
+                    .map(|segment| percent_decode_str(segment).decode_utf8().map(|segment| segment.to_string()))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?
+            .unwrap_or_default();
+
+        let query_values = |name: &str| {
+            url.query_pairs()
+                .filter(|(key, _)| key == name)
+                .map(|(_, value)| value.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        match (url.host_str(), segments.as_slice()) {
+            (Some("open"), [plugin_id, entrypoint_id]) => {
+                Ok(DeepLink::Open {
+                    plugin_id: PluginId::from_string(plugin_id),
+                    entrypoint_id: EntrypointId::from_string(entrypoint_id),
+                })
+            }
+            (Some("run"), [plugin_id, entrypoint_id]) => {
+                Ok(DeepLink::Run {
+                    plugin_id: PluginId::from_string(plugin_id),
+                    entrypoint_id: EntrypointId::from_string(entrypoint_id),
+                    arguments: query_values("arg"),
+                })
+            }
+            (Some("search"), []) => {
+                Ok(DeepLink::Search {
+                    query: query_values("query").join(" "),
+                })
+            }
+            _ => Err(anyhow!("Unsupported deep link: {}", value))
+        }
+    }
+
+    pub fn to_uri(&self) -> String {
+        let (action, segments, query) = match self {
+            DeepLink::Open { plugin_id, entrypoint_id } => {
+                ("open", vec![plugin_id.to_string(), entrypoint_id.to_string()], vec![])
+            }
+            DeepLink::Run { plugin_id, entrypoint_id, arguments } => {
+                let query = arguments.iter()
+                    .map(|argument| ("arg", argument.as_str()))
+                    .collect();
+
+                ("run", vec![plugin_id.to_string(), entrypoint_id.to_string()], query)
+            }
+            DeepLink::Search { query } => {
+                ("search", vec![], vec![("query", query.as_str())])
+            }
+        };
+
+        let mut url = Url::parse(&format!("{}://{}", DEEP_LINK_SCHEME, action))
+            .expect("deep link base url should always be valid");
+
+        if !segments.is_empty() {
+            url.path_segments_mut()
+                .expect("deep link url should always have authority")
+                .extend(segments);
+        }
+
+        if !query.is_empty() {
+            url.query_pairs_mut()
+                .extend_pairs(query);
+        }
+
+        url.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn open_deep_link_roundtrip() {
+        let deep_link = DeepLink::Open {
+            plugin_id: PluginId::from_string("file:///home/user/my plugin"),
+            entrypoint_id: EntrypointId::from_string("main-view"),
+        };
+
+        let uri = deep_link.to_uri();
+
+        assert_eq!(uri, "gauntlet://open/file:%2F%2F%2Fhome%2Fuser%2Fmy%20plugin/main-view");
+        assert_eq!(DeepLink::parse(&uri).unwrap(), deep_link);
+    }
+
+    #[test]
+    fn run_deep_link_roundtrip() {
+        let deep_link = DeepLink::Run {
+            plugin_id: PluginId::from_string("builtin://quicklinks"),
+            entrypoint_id: EntrypointId::from_string("google"),
+            arguments: vec!["rust lang".to_string(), "a&b".to_string()],
+        };
+
+        let uri = deep_link.to_uri();
+
+        assert_eq!(uri, "gauntlet://run/builtin:%2F%2Fquicklinks/google?arg=rust+lang&arg=a%26b");
+        assert_eq!(DeepLink::parse(&uri).unwrap(), deep_link);
+    }
+
+    #[test]
+    fn search_deep_link() {
+        assert_eq!(
+            DeepLink::parse("gauntlet://search?query=hello%20world").unwrap(),
+            DeepLink::Search { query: "hello world".to_string() }
+        );
+        assert_eq!(
+            DeepLink::parse("gauntlet://search").unwrap(),
+            DeepLink::Search { query: "".to_string() }
+        );
+    }
+
+    #[test]
+    fn unsupported_deep_link() {
+        assert!(DeepLink::parse("https://open/a/b").is_err());
+        assert!(DeepLink::parse("gauntlet://unknown/a/b").is_err());
+        assert!(DeepLink::parse("gauntlet://open/a").is_err());
+        assert!(DeepLink::parse("gauntlet://search/a").is_err());
+    }
+}
@@ -24,6 +24,16 @@ impl Dirs {
         path
     }
 
+    // config directory of the os, not of gauntlet. used to find files of other applications
+    pub fn system_config_dir(&self) -> PathBuf {
+        let path = BaseDirs::new()
+            .expect("System didn't report any home directory")
+            .config_dir()
+            .to_path_buf();
+
+        path
+    }
+
     pub fn data_db_file(&self) -> anyhow::Result<PathBuf> {
         let path = self.data_dir()?.join("data.db");
 
@@ -68,12 +78,21 @@ impl Dirs {
         self.cache_dir().join("icons")
     }
 
+    pub fn search_index_file(&self) -> PathBuf {
+        self.cache_dir().join("search_index.bin")
+    }
+
     pub fn plugin_cache(&self, plugin_uuid: &str) -> anyhow::Result<PathBuf> {
         let plugin_cache_dir = self.cache_dir().join("plugins").join(&plugin_uuid);
 
         Ok(plugin_cache_dir)
     }
 
+    // kept outside of plugin cache dir, so that plugins are not able to tamper with compiled code
+    pub fn plugin_code_cache(&self, plugin_uuid: &str) -> PathBuf {
+        self.cache_dir().join("code_cache").join(&plugin_uuid)
+    }
+
     pub fn cache_dir(&self) -> PathBuf {
         let cache_dir = if cfg!(feature = "release") || cfg!(feature = "scenario_runner") {
             self.inner.cache_dir().to_path_buf()
@@ -84,8 +103,20 @@ impl Dirs {
         cache_dir
     }
 
+    pub fn log_dir(&self) -> PathBuf {
+        self.state_dir().join("logs")
+    }
+
+    pub fn instance_lock_file(&self) -> PathBuf {
+        self.state_dir().join("instance.lock")
+    }
+
+    pub fn crash_dir(&self) -> PathBuf {
+        self.state_dir().join("crashes")
+    }
+
     pub fn plugin_log_files(&self, plugin_uuid: &str) -> (PathBuf, PathBuf) {
-        let plugin_dir = self.state_dir().join("logs").join(&plugin_uuid);
+        let plugin_dir = self.log_dir().join(&plugin_uuid);
 
         let out_log_file = plugin_dir.join("stdout.txt");
         let err_log_file = plugin_dir.join("stderr.txt");
@@ -127,4 +158,12 @@ impl Dirs {
     pub fn window_position(&self) -> PathBuf {
         self.state_dir().join("window_position")
     }
+
+    pub fn http_api_token_file(&self) -> PathBuf {
+        self.state_dir().join("http_api_token")
+    }
+
+    pub fn remote_frontend_token_file(&self) -> PathBuf {
+        self.state_dir().join("remote_frontend_token")
+    }
 }
\ No newline at end of file
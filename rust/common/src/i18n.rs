@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::FluentResource;
+use once_cell::sync::Lazy;
+use unic_langid::{CharacterDirection, LanguageIdentifier};
+
+pub use fluent_bundle::FluentArgs;
+
+const FALLBACK_LOCALE: &str = "en-US";
+
+// to add new translation, create "<locale>.ftl" file in "i18n" directory and add it here
+const TRANSLATIONS: [(&str, &str); 1] = [
+    ("en-US", include_str!("../i18n/en-US.ftl")),
+];
+
+static LOCALE: Lazy<LanguageIdentifier> = Lazy::new(|| detect_locale(None));
+
+// locale used to pick plugin provided translations. unlike ui translations, which require restart,
+// it follows changes of system locale, see refresh_translation_locale
+static TRANSLATION_LOCALE: Lazy<RwLock<LanguageIdentifier>> = Lazy::new(|| RwLock::new(LOCALE.clone()));
+
+// best matching locale first, fallback locale last
+static BUNDLES: Lazy<Vec<FluentBundle<FluentResource>>> = Lazy::new(|| {
+    let available = TRANSLATIONS.iter()
+        .map(|(locale, _)| *locale)
+        .collect::<Vec<_>>();
+
+    let best_match = best_matching_locale(&LOCALE, &available);
+
+    let mut locales = vec![];
+    if let Some(best_match) = best_match {
+        if best_match != FALLBACK_LOCALE {
+            locales.push(best_match);
+        }
+    }
+    locales.push(FALLBACK_LOCALE);
+
+    locales.into_iter()
+        .map(|locale| {
+            let (_, source) = TRANSLATIONS.iter()
+                .find(|(id, _)| *id == locale)
+                .expect("locale should be present in translations");
+
+            create_bundle(locale, source)
+        })
+        .collect()
+});
+
+fn create_bundle(locale: &str, source: &str) -> FluentBundle<FluentResource> {
+    let locale: LanguageIdentifier = locale.parse()
+        .expect("translation locale should be valid");
+
+    let resource = FluentResource::try_new(source.to_string())
+        .unwrap_or_else(|(_, errors)| panic!("unable to parse translation for {}: {:?}", locale, errors));
+
+    let mut bundle = FluentBundle::new_concurrent(vec![locale]);
+
+    // iced doesn't render unicode isolation marks
+    bundle.set_use_isolating(false);
+
+    bundle.add_resource(resource)
+        .expect("translation should not contain duplicate messages");
+
+    bundle
+}
+
+// GAUNTLET_LOCALE environment variable takes precedence over system locale.
+// system locale can be provided by caller when it is known better than what process environment says
+fn detect_locale(system_locale: Option<String>) -> LanguageIdentifier {
+    std::env::var("GAUNTLET_LOCALE")
+        .ok()
+        .or(system_locale)
+        .or_else(|| sys_locale::get_locale())
+        .and_then(|locale| parse_locale(&locale))
+        .unwrap_or_else(|| FALLBACK_LOCALE.parse().expect("fallback locale should be valid"))
+}
+
+// accepts both BCP 47 "de-DE" and POSIX "de_DE.UTF-8" formats
+pub fn parse_locale(locale: &str) -> Option<LanguageIdentifier> {
+    let locale = locale.split(['.', '@'])
+        .next()?
+        .replace('_', "-");
+
+    locale.parse().ok()
+}
+
+// exact match first, then match only by language, so that "de-AT" picks "de" or "de-DE"
+fn best_matching_locale<'a>(locale: &LanguageIdentifier, available: &[&'a str]) -> Option<&'a str> {
+    let available = available.iter()
+        .filter_map(|id| parse_locale(id).map(|parsed| (*id, parsed)))
+        .collect::<Vec<_>>();
+
+    available.iter()
+        .find(|(_, parsed)| parsed == locale)
+        .or_else(|| available.iter().find(|(_, parsed)| parsed.language == locale.language))
+        .map(|(id, _)| *id)
+}
+
+pub fn current_locale() -> &'static LanguageIdentifier {
+    &LOCALE
+}
+
+// layout is mirrored for right-to-left locales like arabic or hebrew
+pub fn is_rtl() -> bool {
+    matches!(LOCALE.character_direction(), CharacterDirection::RTL)
+}
+
+// picks value for current locale from map keyed by locale, e.g. plugin provided translations
+pub fn select_translation<T>(translations: &HashMap<String, T>) -> Option<&T> {
+    let available = translations.keys()
+        .map(|locale| locale.as_str())
+        .collect::<Vec<_>>();
+
+    let locale = TRANSLATION_LOCALE.read().expect("lock is poisoned");
+
+    best_matching_locale(&locale, &available)
+        .and_then(|locale| translations.get(locale))
+}
+
+// detects locale again, returns true if it has changed since last check.
+// system locale is passed when it comes with change notification, e.g. on linux
+// environment of running process still contains the old one
+pub fn refresh_translation_locale(system_locale: Option<String>) -> bool {
+    let detected = detect_locale(system_locale);
+
+    let mut locale = TRANSLATION_LOCALE.write().expect("lock is poisoned");
+
+    if *locale == detected {
+        return false;
+    }
+
+    *locale = detected;
+
+    true
+}
+
+pub fn translate(id: &str, args: Option<&FluentArgs>) -> String {
+    for bundle in BUNDLES.iter() {
+        let Some(pattern) = bundle.get_message(id).and_then(|message| message.value()) else {
+            continue;
+        };
+
+        let mut errors = vec![];
+        let value = bundle.format_pattern(pattern, args, &mut errors);
+
+        return value.into_owned();
+    }
+
+    // missing message is shown as is, so that it is easy to spot
+    id.to_string()
+}
+
+// tr!("message-id") or tr!("message-id", name = value)
+#[macro_export]
+macro_rules! tr {
+    ($id:literal) => {
+        $crate::i18n::translate($id, None)
+    };
+    ($id:literal, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = $crate::i18n::FluentArgs::new();
+        $(args.set(stringify!($name), $value);)+
+        $crate::i18n::translate($id, Some(&args))
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn translations_are_valid() {
+        for (locale, source) in TRANSLATIONS {
+            create_bundle(locale, source);
+        }
+    }
+
+    #[test]
+    fn locale_matching() {
+        let available = ["en-US", "de", "pt-BR"];
+
+        let matching = |locale: &str| best_matching_locale(&parse_locale(locale).unwrap(), &available);
+
+        assert_eq!(matching("de_AT.UTF-8"), Some("de"));
+        assert_eq!(matching("pt-BR"), Some("pt-BR"));
+        assert_eq!(matching("pt-PT"), Some("pt-BR"));
+        assert_eq!(matching("en-GB"), Some("en-US"));
+        assert_eq!(matching("fr-FR"), None);
+    }
+}
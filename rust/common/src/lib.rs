@@ -5,6 +5,10 @@ pub mod rpc;
 pub mod scenario_convert;
 pub mod scenario_model;
 pub mod dirs;
+pub mod deeplink;
+pub mod i18n;
+pub mod logging;
+pub mod crash;
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(tag = "type")]
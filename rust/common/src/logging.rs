@@ -0,0 +1,113 @@
+use std::fs;
+
+use anyhow::Context;
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt, EnvFilter, Layer};
+
+use crate::dirs::Dirs;
+
+const DEFAULT_LOG_LEVEL: &str = "info";
+const MAX_LOG_FILES: usize = 7;
+
+pub const SERVER_LOG_FILE_PREFIX: &str = "server";
+pub const SETTINGS_LOG_FILE_PREFIX: &str = "settings";
+pub const REMOTE_CLIENT_LOG_FILE_PREFIX: &str = "remote_client";
+
+// needs to be held until the end of the process, otherwise buffered log lines are not written to file
+pub struct LogGuard(#[allow(unused)] Option<WorkerGuard>);
+
+// log level uses the same syntax as RUST_LOG env variable, so it can be set per target,
+// e.g. "info,gauntlet_server=debug,plugin=trace"
+// priority: log level argument, RUST_LOG env variable, default level
+//
+// logs are always written to stderr and, if file prefix is specified, to daily rotated files in log directory
+pub fn init_logging(log_level: Option<&str>, log_file_prefix: Option<&str>) -> LogGuard {
+    let create_filter = || {
+        match log_level {
+            Some(log_level) => EnvFilter::try_new(log_level)
+                .unwrap_or_else(|err| {
+                    eprintln!("Invalid log level '{}', falling back to default: {}", log_level, err);
+                    EnvFilter::new(DEFAULT_LOG_LEVEL)
+                }),
+            None => EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| EnvFilter::new(DEFAULT_LOG_LEVEL))
+        }
+    };
+
+    let stderr_layer = fmt::layer()
+        .with_writer(std::io::stderr)
+        .with_filter(create_filter());
+
+    let (file_layer, guard) = match log_file_prefix.map(|prefix| create_file_appender(prefix)) {
+        Some(Ok(appender)) => {
+            let (writer, guard) = tracing_appender::non_blocking(appender);
+
+            let layer = fmt::layer()
+                .with_writer(writer)
+                .with_ansi(false)
+                .with_filter(create_filter());
+
+            (Some(layer), Some(guard))
+        }
+        Some(Err(err)) => {
+            eprintln!("Unable to create log file, logging only to stderr: {:?}", err);
+            (None, None)
+        }
+        None => (None, None)
+    };
+
+    tracing_subscriber::registry()
+        .with(stderr_layer)
+        .with(file_layer)
+        .init();
+
+    LogGuard(guard)
+}
+
+fn create_file_appender(prefix: &str) -> anyhow::Result<RollingFileAppender> {
+    let appender = RollingFileAppender::builder()
+        .rotation(Rotation::DAILY)
+        .filename_prefix(prefix)
+        .filename_suffix("log")
+        .max_log_files(MAX_LOG_FILES)
+        .build(Dirs::new().log_dir())
+        .context("Unable to create rolling file appender")?;
+
+    Ok(appender)
+}
+
+// last lines of the most recent log file with specified prefix
+pub fn read_recent_log_lines(log_file_prefix: &str, limit: usize) -> anyhow::Result<Vec<String>> {
+    let log_dir = Dirs::new().log_dir();
+
+    let latest_log_file = fs::read_dir(&log_dir)
+        .context("Unable to read log directory")?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            let file_name = entry.file_name();
+            let file_name = file_name.to_string_lossy();
+
+            file_name.starts_with(&format!("{}.", log_file_prefix)) && file_name.ends_with(".log")
+        })
+        // file names contain date in "yyyy-MM-dd" format, so the latest file is the last one alphabetically
+        .max_by_key(|entry| entry.file_name());
+
+    let Some(latest_log_file) = latest_log_file else {
+        return Ok(vec![])
+    };
+
+    let content = fs::read(latest_log_file.path())
+        .context("Unable to read log file")?;
+
+    let content = String::from_utf8_lossy(&content);
+
+    let lines = content.lines()
+        .collect::<Vec<_>>();
+
+    let start = lines.len().saturating_sub(limit);
+
+    Ok(lines[start..].iter().map(|line| line.to_string()).collect())
+}
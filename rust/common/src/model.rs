@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::anyhow;
 use bincode::{Decode, Encode};
@@ -10,6 +11,8 @@ use gix_url::Url;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde::de::Error;
 
+use crate::tr;
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Encode, Decode)]
 pub struct PluginId(Arc<str>);
 
@@ -75,6 +78,22 @@ impl Display for EntrypointId {
     }
 }
 
+// hex encoded sha256 of image bytes, same image is transferred to frontend only once
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Encode, Decode)]
+pub struct ImageHash(Arc<str>);
+
+impl ImageHash {
+    pub fn from_string(hash: impl ToString) -> Self {
+        ImageHash(hash.to_string().into())
+    }
+}
+
+impl Display for ImageHash {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum DownloadStatus {
     InProgress,
@@ -84,13 +103,13 @@ pub enum DownloadStatus {
     },
 }
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Encode, Decode)]
 pub enum UiRenderLocation {
     InlineView,
     View
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
 pub struct PhysicalShortcut {
     pub physical_key: PhysicalKey,
     pub modifier_shift: bool,
@@ -106,19 +125,133 @@ pub struct LocalSaveData {
 }
 
 #[derive(Debug, Clone)]
+pub struct PluginManifestDiagnostic {
+    // path to the field in manifest, e.g. "permissions.filesystem.read[0]"
+    pub field: String,
+    pub reason: String,
+    pub suggestion: Option<String>,
+}
+
+impl Display for PluginManifestDiagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.reason)?;
+
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, " (suggestion: {})", suggestion)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DevConsoleEvent {
+    pub plugin_id: PluginId,
+    pub kind: DevConsoleEventKind,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DevConsoleEventKind {
+    Stdout,
+    Stderr,
+    Render,
+    ViewEvent,
+}
+
+#[derive(Debug, Clone)]
+pub struct ServerMetrics {
+    pub search: MetricsTiming,
+    pub requests: HashMap<String, u64>,
+    pub plugins: Vec<PluginMetrics>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PluginMetrics {
+    pub plugin_id: PluginId,
+    pub render: MetricsTiming,
+    // memory of plugin runtime process, which runs single isolate
+    pub memory_bytes: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MetricsTiming {
+    pub count: u64,
+    pub total: Duration,
+    pub last: Duration,
+    pub max: Duration,
+}
+
+impl MetricsTiming {
+    pub fn record(&mut self, duration: Duration) {
+        self.count += 1;
+        self.total += duration;
+        self.last = duration;
+        self.max = self.max.max(duration);
+    }
+
+    pub fn average(&self) -> Duration {
+        if self.count == 0 {
+            Duration::ZERO
+        } else {
+            self.total.div_f64(self.count as f64)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Encode, Decode)]
 pub struct SearchResult {
     pub plugin_id: PluginId,
     pub plugin_name: String,
     pub entrypoint_id: EntrypointId,
     pub entrypoint_name: String,
+    // parts of the name which matched search query, shown in bold
+    pub entrypoint_name_highlights: Vec<SearchResultHighlight>,
+    pub entrypoint_subtitle: Option<String>,
+    // keywords of entrypoint which matched search query, shown next to the name
+    pub entrypoint_matched_keywords: Vec<SearchResultMatchedKeyword>,
     pub entrypoint_generator_name: Option<String>,
+    #[bincode(with_serde)]
     pub entrypoint_icon: Option<bytes::Bytes>,
     pub entrypoint_type: SearchResultEntrypointType,
     pub entrypoint_actions: Vec<SearchResultEntrypointAction>,
     pub entrypoint_accessories: Vec<SearchResultAccessory>,
+    pub entrypoint_arguments: Vec<EntrypointArgument>,
+    // local file which result represents, it can be previewed using space bar
+    pub entrypoint_file: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+// byte offsets in highlighted text
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct SearchResultHighlight {
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct SearchResultMatchedKeyword {
+    pub keyword: String,
+    pub highlights: Vec<SearchResultHighlight>,
+}
+
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct EntrypointArgument {
+    pub id: String,
+    pub name: String,
+    pub required: bool,
+    pub argument_type: EntrypointArgumentType,
+}
+
+#[derive(Debug, Clone, Encode, Decode)]
+pub enum EntrypointArgumentType {
+    String,
+    Enum {
+        enum_values: Vec<PreferenceEnumValue>,
+    },
+    File,
+}
+
+#[derive(Debug, Clone, Encode, Decode)]
 pub enum SearchResultAccessory {
     TextAccessory {
         text: String,
@@ -131,33 +264,94 @@ pub enum SearchResultAccessory {
     },
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Encode, Decode)]
 pub struct SearchResultEntrypointAction {
     pub action_type: SearchResultEntrypointActionType,
     pub label: String,
     pub shortcut: Option<PhysicalShortcut>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Encode, Decode)]
 pub enum SearchResultEntrypointActionType {
     Command,
     View,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
 pub enum SearchResultEntrypointType {
     Command,
     View,
     Generated,
 }
 
-#[derive(Debug, Clone)]
+// details of focused search result shown in preview pane, requested separately so that search results stay small
+#[derive(Debug, Clone, Encode, Decode)]
+pub enum SearchResultPreview {
+    File {
+        path: String,
+        size: u64,
+        // seconds since unix epoch
+        modified: Option<u64>,
+        // beginning of file if it is text
+        content: Option<String>,
+    },
+    Folder {
+        path: String,
+        // first entries, folders have trailing slash
+        entries: Vec<String>,
+        entry_count: usize,
+    },
+    Markdown {
+        content: String,
+    },
+}
+
+// shown in overlay on top of main view, files over size limit are not loaded
+#[derive(Debug, Clone, Encode, Decode)]
+pub enum FileQuicklook {
+    // first page of pdf is rendered to image
+    Image {
+        path: String,
+        data: Vec<u8>,
+    },
+    Text {
+        path: String,
+        lines: Vec<Vec<FileQuicklookSpan>>,
+        // only beginning of file is shown
+        truncated: bool,
+    },
+    TooLarge {
+        path: String,
+        size: u64,
+    },
+    Unsupported {
+        path: String,
+    },
+}
+
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct FileQuicklookSpan {
+    pub text: String,
+    // rgb, none for default text color
+    pub color: Option<(u8, u8, u8)>,
+}
+
+// restricts search results, parsed from "@plugin-name" and "#view" tokens in search query
+#[derive(Debug, Clone, Default, Encode, Decode)]
+pub struct SearchFilter {
+    pub plugin_name: Option<String>,
+    pub entrypoint_type: Option<SearchResultEntrypointType>,
+    // set when all results of one plugin are shown
+    pub plugin_id: Option<PluginId>,
+}
+
+#[derive(Debug, Clone, Encode, Decode)]
 pub enum UiThemeMode {
     Light,
     Dark
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode)]
 pub enum WindowPositionMode {
     Static,
     ActiveMonitor
@@ -166,16 +360,76 @@ pub enum WindowPositionMode {
 impl Display for WindowPositionMode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let label = match self {
-            WindowPositionMode::Static => "Static",
-            WindowPositionMode::ActiveMonitor => "Active Monitor",
+            WindowPositionMode::Static => tr!("window-position-mode-static"),
+            WindowPositionMode::ActiveMonitor => tr!("window-position-mode-active-monitor"),
         };
 
         write!(f, "{}", label)
     }
 }
 
+// spacing and text size of main window
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Encode, Decode)]
+pub enum Density {
+    Compact,
+    Comfortable,
+    Large,
+}
 
-#[derive(Debug, Clone)]
+impl Display for Density {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            Density::Compact => tr!("density-compact"),
+            Density::Comfortable => tr!("density-comfortable"),
+            Density::Large => tr!("density-large"),
+        };
+
+        write!(f, "{}", label)
+    }
+}
+
+// scale of main window relative to the one reported by operating system,
+// in addition to global override scale can be overridden for specific monitors in config
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Encode, Decode)]
+pub enum UiScale {
+    Auto,
+    Manual {
+        percent: u16
+    },
+}
+
+impl Display for UiScale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UiScale::Auto => write!(f, "{}", tr!("ui-scale-auto")),
+            UiScale::Manual { percent } => write!(f, "{}%", percent),
+        }
+    }
+}
+
+// order of search results in main view, recently used entrypoints are always shown first
+#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode)]
+pub enum SearchResultsSort {
+    Frecency,
+    Relevance,
+    // groups results under collapsible plugin headers
+    Plugin,
+}
+
+impl Display for SearchResultsSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            SearchResultsSort::Frecency => tr!("search-results-sort-frecency"),
+            SearchResultsSort::Relevance => tr!("search-results-sort-relevance"),
+            SearchResultsSort::Plugin => tr!("search-results-sort-plugin"),
+        };
+
+        write!(f, "{}", label)
+    }
+}
+
+
+#[derive(Debug, Clone, Encode, Decode)]
 pub struct UiThemeColor {
     pub r: f32,
     pub g: f32,
@@ -185,29 +439,29 @@ pub struct UiThemeColor {
 
 pub type UiThemeColorPalette = [UiThemeColor; 4];
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Encode, Decode)]
 pub struct UiThemeWindow {
     pub border: UiThemeWindowBorder,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Encode, Decode)]
 pub struct UiThemeWindowBorder {
     pub radius: f32,
     pub width: f32,
     pub color: UiThemeColor,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Encode, Decode)]
 pub struct UiThemeContent {
     pub border: UiThemeContentBorder,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Encode, Decode)]
 pub struct UiThemeContentBorder {
     pub radius: f32,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Encode, Decode)]
 pub struct UiTheme {
     pub mode: UiThemeMode,
     pub background: UiThemeColorPalette,
@@ -216,13 +470,86 @@ pub struct UiTheme {
     pub content: UiThemeContent,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Default, Encode, Decode)]
+pub struct UiFontConfig {
+    pub family: Option<String>,
+    pub monospace_family: Option<String>,
+    // overrides text size of selected density
+    pub size: Option<f32>,
+    pub files: Vec<PathBuf>,
+}
+
+// renderer used by main window, only read on start
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Encode, Decode)]
+pub enum UiRenderer {
+    // gpu renderer with fallback to software renderer if gpu renderer cannot be created
+    Auto,
+    Wgpu,
+    TinySkia,
+}
+
+// what escape does in main view when neither action panel nor plugin scope is open
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Encode, Decode)]
+pub enum UiEscapeBehavior {
+    // search text is cleared first, window is hidden on next press
+    ClearThenHide,
+    AlwaysHide,
+    // window is only hidden by global shortcut or when it loses focus
+    NeverHide,
+}
+
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct UiRendererInfo {
+    pub backend: String,
+    pub adapter: String,
+}
+
+#[derive(Debug, Encode, Decode)]
 pub struct UiSetupData {
     pub window_position_file: PathBuf,
     pub theme: UiTheme,
     pub global_shortcut: Option<PhysicalShortcut>,
     pub close_on_unfocus: bool,
+    pub escape_behavior: UiEscapeBehavior,
+    pub reopen_last_view: bool,
+    pub reduced_motion: bool,
+    pub tray_icon: bool,
     pub window_position_mode: WindowPositionMode,
+    pub window_overlay: bool,
+    pub density: Density,
+    pub font: UiFontConfig,
+    pub ui_scale: UiScale,
+    // monitor size, e.g. "3840x2160", to scale in percent
+    pub monitor_ui_scale: HashMap<String, u16>,
+    pub renderer: UiRenderer,
+    // e.g. "vulkan", "metal", "dx12" or "gl"
+    pub wgpu_backend: Option<String>,
+    pub search_results_sort: SearchResultsSort,
+    pub inline_view_order: Vec<PluginId>,
+    pub results_per_plugin: Option<usize>,
+    pub keymap: Vec<KeymapBinding>,
+    pub offline_mode: bool,
+}
+
+// in-app navigation actions which can be bound to additional shortcuts
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode)]
+pub enum KeymapAction {
+    Next,
+    Previous,
+    Primary,
+    Secondary,
+    ToggleActionPanel,
+    Back,
+    ToggleShortcutHints,
+    TogglePin,
+    DetachView,
+    TogglePreview,
+}
+
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct KeymapBinding {
+    pub action: KeymapAction,
+    pub shortcut: PhysicalShortcut,
 }
 
 #[derive(Debug)]
@@ -236,10 +563,40 @@ pub enum UiResponseData {
     Err(anyhow::Error),
 }
 
-#[derive(Debug)]
+// preference which has to be set before plugin can be used
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct OnboardingPreference {
+    // none for plugin preferences
+    pub entrypoint_id: Option<EntrypointId>,
+    pub name: String,
+    pub description: String,
+    pub filled: bool,
+}
+
+#[derive(Debug, Encode, Decode)]
 pub enum UiRequestData {
     ShowWindow,
     HideWindow,
+    ToggleWindow,
+    // plugin is selected in plugins view when plugin id is specified
+    ShowSettingsWindow {
+        plugin_id: Option<PluginId>,
+        entrypoint_id: Option<EntrypointId>,
+    },
+    OpenPluginView {
+        plugin_id: PluginId,
+        plugin_name: String,
+        entrypoint_id: EntrypointId,
+        entrypoint_name: String,
+    },
+    ShowWindowWithSearchQuery {
+        query: String,
+    },
+    ConfirmDeepLink {
+        uri: String,
+        plugin_name: String,
+        entrypoint_name: String,
+    },
     ClearInlineView {
         plugin_id: PluginId
     },
@@ -250,14 +607,20 @@ pub enum UiRequestData {
         entrypoint_name: String,
         render_location: UiRenderLocation,
         top_level_view: bool,
+        // names of views in plugin view navigation stack, from bottommost to topmost
+        breadcrumbs: Vec<String>,
+        // shortcuts registered by the view, their key presses are sent to plugin instead of being handled by frontend
+        key_handlers: Vec<PhysicalShortcut>,
         container: RootWidget,
-        images: HashMap<UiWidgetId, Vec<u8>>,
+        images: HashMap<UiWidgetId, ImageHash>,
     },
-    ShowPreferenceRequiredView {
+    ShowOnboardingView {
         plugin_id: PluginId,
+        plugin_name: String,
         entrypoint_id: EntrypointId,
-        plugin_preferences_required: bool,
-        entrypoint_preferences_required: bool,
+        // markdown from plugin manifest
+        description: Option<String>,
+        preferences: Vec<OnboardingPreference>,
     },
     ShowPluginErrorView {
         plugin_id: PluginId,
@@ -282,16 +645,35 @@ pub enum UiRequestData {
     SetWindowPositionMode {
         mode: WindowPositionMode
     },
+    SetWindowOverlay {
+        enabled: bool
+    },
+    SetDensity {
+        density: Density
+    },
+    SetUiScale {
+        scale: UiScale
+    },
+    SetSearchResultsSort {
+        sort: SearchResultsSort
+    },
+    // extra line shown in tray icon tooltip, e.g. countdown of running timers
+    SetTrayTooltip {
+        text: Option<String>
+    },
 }
 
-#[derive(Debug)]
+#[derive(Debug, Encode, Decode)]
 pub enum BackendResponseData {
     Nothing,
     SetupData {
         data: UiSetupData
     },
     Search {
-        results: Vec<SearchResult>
+        results: Vec<SearchResult>,
+        recent_count: usize,
+        // results are fallbacks shown because nothing matched the query
+        fallback: bool,
     },
     RequestViewRender {
         shortcuts: HashMap<String, PhysicalShortcut>
@@ -299,13 +681,24 @@ pub enum BackendResponseData {
     InlineViewShortcuts {
         shortcuts: HashMap<PluginId, HashMap<String, PhysicalShortcut>>
     },
+    Images {
+        images: HashMap<ImageHash, Vec<u8>>
+    },
+    ResultPreview {
+        preview: Option<SearchResultPreview>
+    },
+    FileQuicklook {
+        quicklook: FileQuicklook
+    },
 }
 
-#[derive(Debug)]
+#[derive(Debug, Encode, Decode)]
 pub enum BackendRequestData {
+    Ping,
     Setup,
     Search {
         text: String,
+        filter: SearchFilter,
         render_inline_view: bool
     },
     RequestViewRender {
@@ -315,9 +708,13 @@ pub enum BackendRequestData {
     RequestViewClose {
         plugin_id: PluginId,
     },
+    RequestViewPop {
+        plugin_id: PluginId,
+    },
     RequestRunCommand {
         plugin_id: PluginId,
-        entrypoint_id: EntrypointId
+        entrypoint_id: EntrypointId,
+        arguments: HashMap<String, String>,
     },
     RequestRunGeneratedEntrypoint {
         plugin_id: PluginId,
@@ -345,17 +742,53 @@ pub enum BackendRequestData {
         href: String
     },
     OpenSettingsWindow,
+    RunConfirmedDeepLink {
+        uri: String,
+    },
     OpenSettingsWindowPreferences {
         plugin_id: PluginId,
         entrypoint_id: Option<EntrypointId>
     },
     InlineViewShortcuts,
+    SetPluginsPaused {
+        paused: bool
+    },
+    SetOfflineMode {
+        offline: bool
+    },
+    SetFocusMode {
+        enabled: bool
+    },
+    SetEntrypointState {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        enabled: bool,
+    },
+    CompletePluginOnboarding {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+    },
     SetupResponse {
         global_shortcut_error: Option<String>
     },
+    GetImages {
+        hashes: Vec<ImageHash>
+    },
+    GetResultPreview {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+    },
+    GetFileQuicklook {
+        path: String,
+        // syntax highlighting colors depend on theme
+        dark: bool,
+    },
+    ReportRenderer {
+        renderer: UiRendererInfo
+    },
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Encode, Decode)]
 pub enum KeyboardEventOrigin {
     MainView,
     PluginView,
@@ -454,6 +887,12 @@ pub trait WidgetVisitor {
     async fn h6_widget(&mut self, _widget: &H6Widget) {}
     async fn horizontal_break_widget(&mut self, _widget: &HorizontalBreakWidget) {}
     async fn code_block_widget(&mut self, _widget: &CodeBlockWidget) {}
+    async fn skeleton_widget(&mut self, _widget: &SkeletonWidget) {}
+    async fn media_item_widget(&mut self, widget: &MediaItemWidget) {
+        if let Some(artwork) = &widget.artwork {
+            self.image(widget.__id__, artwork).await
+        }
+    }
     async fn paragraph_widget(&mut self, _widget: &ParagraphWidget) {}
     async fn content_widget(&mut self, widget: &ContentWidget) {
         for members in &widget.content.ordered_members {
@@ -468,6 +907,8 @@ pub trait WidgetVisitor {
                 ContentWidgetOrderedMembers::H6(widget) => self.h6_widget(widget).await,
                 ContentWidgetOrderedMembers::HorizontalBreak(widget) => self.horizontal_break_widget(widget).await,
                 ContentWidgetOrderedMembers::CodeBlock(widget) => self.code_block_widget(widget).await,
+                ContentWidgetOrderedMembers::Skeleton(widget) => self.skeleton_widget(widget).await,
+                ContentWidgetOrderedMembers::MediaItem(widget) => self.media_item_widget(widget).await,
             }
         }
     }
@@ -486,6 +927,7 @@ pub trait WidgetVisitor {
 
     async fn text_field_widget(&mut self, _widget: &TextFieldWidget) {}
     async fn password_field_widget(&mut self, _widget: &PasswordFieldWidget) {}
+    async fn text_area_widget(&mut self, _widget: &TextAreaWidget) {}
     async fn checkbox_widget(&mut self, _widget: &CheckboxWidget) {}
     async fn date_picker_widget(&mut self, _widget: &DatePickerWidget) {}
     async fn select_item_widget(&mut self, _widget: &SelectItemWidget) {}
@@ -505,6 +947,7 @@ pub trait WidgetVisitor {
             match members {
                 FormWidgetOrderedMembers::TextField(widget) => self.text_field_widget(widget).await,
                 FormWidgetOrderedMembers::PasswordField(widget) => self.password_field_widget(widget).await,
+                FormWidgetOrderedMembers::TextArea(widget) => self.text_area_widget(widget).await,
                 FormWidgetOrderedMembers::Checkbox(widget) => self.checkbox_widget(widget).await,
                 FormWidgetOrderedMembers::DatePicker(widget) => self.date_picker_widget(widget).await,
                 FormWidgetOrderedMembers::Select(widget) => self.select_widget(widget).await,
@@ -531,6 +974,11 @@ pub trait WidgetVisitor {
         if let Some(image) = &widget.image {
             self.image(widget.__id__, image).await
         }
+        for members in &widget.content.ordered_members {
+            match members {
+                EmptyViewWidgetOrderedMembers::Action(widget) => self.action_widget(widget).await,
+            }
+        }
     }
 
     async fn icon_accessory_widget(&mut self, widget: &IconAccessoryWidget) {
@@ -544,6 +992,24 @@ pub trait WidgetVisitor {
 
     async fn search_bar_widget(&mut self, _widget: &SearchBarWidget) {}
 
+    async fn tab_widget(&mut self, _widget: &TabWidget) {}
+    async fn tabs_widget(&mut self, widget: &TabsWidget) {
+        for members in &widget.content.ordered_members {
+            match members {
+                TabsWidgetOrderedMembers::Tab(widget) => self.tab_widget(widget).await,
+            }
+        }
+    }
+
+    async fn list_column_widget(&mut self, _widget: &ListColumnWidget) {}
+    async fn list_columns_widget(&mut self, widget: &ListColumnsWidget) {
+        for members in &widget.content.ordered_members {
+            match members {
+                ListColumnsWidgetOrderedMembers::ListColumn(widget) => self.list_column_widget(widget).await,
+            }
+        }
+    }
+
     async fn list_item_widget(&mut self, widget: &ListItemWidget) {
         if let Some(image) = &widget.icon {
             self.image(widget.__id__, image).await
@@ -571,6 +1037,12 @@ pub trait WidgetVisitor {
         if let Some(widget) = &widget.content.search_bar {
             self.search_bar_widget(widget).await
         }
+        if let Some(widget) = &widget.content.tabs {
+            self.tabs_widget(widget).await
+        }
+        if let Some(widget) = &widget.content.list_columns {
+            self.list_columns_widget(widget).await
+        }
         if let Some(widget) = &widget.content.empty_view {
             self.empty_view_widget(widget).await
         }
@@ -600,6 +1072,8 @@ pub trait WidgetVisitor {
                 ContentWidgetOrderedMembers::H6(widget) => self.h6_widget(widget).await,
                 ContentWidgetOrderedMembers::HorizontalBreak(widget) => self.horizontal_break_widget(widget).await,
                 ContentWidgetOrderedMembers::CodeBlock(widget) => self.code_block_widget(widget).await,
+                ContentWidgetOrderedMembers::Skeleton(widget) => self.skeleton_widget(widget).await,
+                ContentWidgetOrderedMembers::MediaItem(widget) => self.media_item_widget(widget).await,
             }
         }
     }
@@ -617,6 +1091,9 @@ pub trait WidgetVisitor {
         if let Some(widget) = &widget.content.search_bar {
             self.search_bar_widget(widget).await
         }
+        if let Some(widget) = &widget.content.tabs {
+            self.tabs_widget(widget).await
+        }
         if let Some(widget) = &widget.content.empty_view {
             self.empty_view_widget(widget).await
         }
@@ -641,17 +1118,34 @@ pub trait WidgetVisitor {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Encode, Decode)]
 pub enum UiPropertyValue {
     String(String),
     Number(f64),
     Bool(bool),
-    Bytes(bytes::Bytes),
+    Bytes(#[bincode(with_serde)] bytes::Bytes),
     Array(Vec<UiPropertyValue>),
     Object(HashMap<String, UiPropertyValue>),
+    Color(UiColor),
+    // name of enum item, e.g. "Top" for position or "Small" for size
+    Enum(String),
     Undefined,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode)]
+pub struct UiColor {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl UiColor {
+    pub fn to_hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}{:02x}", self.r, self.g, self.b, self.a)
+    }
+}
+
 pub type UiWidgetId = usize;
 
 #[derive(Debug, Clone)]
@@ -661,6 +1155,10 @@ pub struct SettingsEntrypoint {
     pub entrypoint_description: String,
     pub entrypoint_type: SettingsEntrypointType,
     pub enabled: bool,
+    // not shown in search results, but can still be run, e.g. using shortcut or deep link
+    pub hidden: bool,
+    // order set in settings, None if entrypoint was never moved
+    pub position: Option<u32>,
     pub preferences: HashMap<String, PluginPreference>,
     pub preferences_user_data: HashMap<String, PluginPreferenceUserData>,
 }
@@ -671,6 +1169,14 @@ pub struct SettingsPlugin {
     pub plugin_name: String,
     pub plugin_description: String,
     pub enabled: bool,
+    // multiplier of search scores of plugin results, 1.0 by default
+    pub priority: f64,
+    // whether plugin is allowed to show desktop notifications
+    pub notifications_enabled: bool,
+    // environment variables set by user, passed to plugin runtime process and everything it starts
+    pub environment: HashMap<String, String>,
+    // directories approved by user, accessible by plugin in addition to ones declared in manifest
+    pub filesystem_roots: Vec<String>,
     pub entrypoints: HashMap<EntrypointId, SettingsEntrypoint>,
     pub preferences: HashMap<String, PluginPreference>,
     pub preferences_user_data: HashMap<String, PluginPreferenceUserData>,
@@ -682,6 +1188,7 @@ pub enum SettingsEntrypointType {
     View,
     InlineView,
     EntrypointGenerator,
+    SearchProvider,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -692,24 +1199,78 @@ pub enum SettingsTheme {
     // Custom, TODO specify file path or drag and drop via settings ui
     MacOSLight,
     MacOSDark,
-    Legacy
+    Legacy,
+    HighContrast,
 }
 
 impl Display for SettingsTheme {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let label = match self {
-            SettingsTheme::AutoDetect => "Auto-detect",
-            SettingsTheme::ThemeFile => "Theme file present",
-            SettingsTheme::Config => "Config setting present",
-            SettingsTheme::MacOSLight => "macOS Light",
-            SettingsTheme::MacOSDark => "macOS Dark",
-            SettingsTheme::Legacy => "Legacy",
+            SettingsTheme::AutoDetect => tr!("theme-auto-detect"),
+            SettingsTheme::ThemeFile => tr!("theme-theme-file"),
+            SettingsTheme::Config => tr!("theme-config"),
+            SettingsTheme::MacOSLight => tr!("theme-macos-light"),
+            SettingsTheme::MacOSDark => tr!("theme-macos-dark"),
+            SettingsTheme::Legacy => tr!("theme-legacy"),
+            SettingsTheme::HighContrast => tr!("theme-high-contrast"),
         };
 
         write!(f, "{}", label)
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct SettingsCrashReport {
+    // file name in crash directory
+    pub id: String,
+    pub content: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct SettingsQuicklink {
+    pub id: String,
+    pub name: String,
+    // may contain {query} placeholder which is replaced by the rest of search query
+    pub url: String,
+    // without leading "!", e.g. "g" for "!g rust"
+    pub bang: Option<String>,
+    // shown as "Search ... for ..." when nothing else matches the query
+    pub fallback: bool,
+}
+
+// sizes in bytes of plugin directories
+#[derive(Debug, Clone)]
+pub struct SettingsPluginDiskUsage {
+    pub data: u64,
+    pub cache: u64,
+    pub logs: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct SettingsUsageStats {
+    // ordered by launch count, most used first
+    pub entrypoints: Vec<SettingsEntrypointUsage>,
+    pub unused_plugins: Vec<SettingsUnusedPlugin>,
+    pub average_search_to_launch: Option<Duration>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SettingsEntrypointUsage {
+    pub plugin_id: PluginId,
+    pub plugin_name: String,
+    pub entrypoint_id: EntrypointId,
+    pub entrypoint_name: String,
+    pub launch_count: u64,
+    // none if entrypoint was only launched without searching, e.g. using deep link
+    pub average_search_to_launch: Option<Duration>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SettingsUnusedPlugin {
+    pub plugin_id: PluginId,
+    pub plugin_name: String,
+}
+
 #[derive(Debug, Clone)]
 pub enum PluginPreferenceUserData {
     Number {
@@ -777,7 +1338,7 @@ pub enum PluginPreference {
     },
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Encode, Decode)]
 pub struct PreferenceEnumValue {
     pub label: String,
     pub value: String,
@@ -785,7 +1346,7 @@ pub struct PreferenceEnumValue {
 
 
 // copy of iced (currently fork) PhysicalKey but without modifiers
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
 pub enum PhysicalKey {
     Backquote,
     Backslash,
@@ -974,193 +1535,199 @@ pub enum PhysicalKey {
 
 impl PhysicalKey {
     pub fn from_value(key: String) -> PhysicalKey {
-        match key.as_str() {
-            "Backquote" => PhysicalKey::Backquote,
-            "Backslash" => PhysicalKey::Backslash,
-            "BracketLeft" => PhysicalKey::BracketLeft,
-            "BracketRight" => PhysicalKey::BracketRight,
-            "Comma" => PhysicalKey::Comma,
-            "Digit0" => PhysicalKey::Digit0,
-            "Digit1" => PhysicalKey::Digit1,
-            "Digit2" => PhysicalKey::Digit2,
-            "Digit3" => PhysicalKey::Digit3,
-            "Digit4" => PhysicalKey::Digit4,
-            "Digit5" => PhysicalKey::Digit5,
-            "Digit6" => PhysicalKey::Digit6,
-            "Digit7" => PhysicalKey::Digit7,
-            "Digit8" => PhysicalKey::Digit8,
-            "Digit9" => PhysicalKey::Digit9,
-            "Equal" => PhysicalKey::Equal,
-            "IntlBackslash" => PhysicalKey::IntlBackslash,
-            "IntlRo" => PhysicalKey::IntlRo,
-            "IntlYen" => PhysicalKey::IntlYen,
-            "KeyA" => PhysicalKey::KeyA,
-            "KeyB" => PhysicalKey::KeyB,
-            "KeyC" => PhysicalKey::KeyC,
-            "KeyD" => PhysicalKey::KeyD,
-            "KeyE" => PhysicalKey::KeyE,
-            "KeyF" => PhysicalKey::KeyF,
-            "KeyG" => PhysicalKey::KeyG,
-            "KeyH" => PhysicalKey::KeyH,
-            "KeyI" => PhysicalKey::KeyI,
-            "KeyJ" => PhysicalKey::KeyJ,
-            "KeyK" => PhysicalKey::KeyK,
-            "KeyL" => PhysicalKey::KeyL,
-            "KeyM" => PhysicalKey::KeyM,
-            "KeyN" => PhysicalKey::KeyN,
-            "KeyO" => PhysicalKey::KeyO,
-            "KeyP" => PhysicalKey::KeyP,
-            "KeyQ" => PhysicalKey::KeyQ,
-            "KeyR" => PhysicalKey::KeyR,
-            "KeyS" => PhysicalKey::KeyS,
-            "KeyT" => PhysicalKey::KeyT,
-            "KeyU" => PhysicalKey::KeyU,
-            "KeyV" => PhysicalKey::KeyV,
-            "KeyW" => PhysicalKey::KeyW,
-            "KeyX" => PhysicalKey::KeyX,
-            "KeyY" => PhysicalKey::KeyY,
-            "KeyZ" => PhysicalKey::KeyZ,
-            "Minus" => PhysicalKey::Minus,
-            "Period" => PhysicalKey::Period,
-            "Quote" => PhysicalKey::Quote,
-            "Semicolon" => PhysicalKey::Semicolon,
-            "Slash" => PhysicalKey::Slash,
-            "Backspace" => PhysicalKey::Backspace,
-            "CapsLock" => PhysicalKey::CapsLock,
-            "ContextMenu" => PhysicalKey::ContextMenu,
-            "Enter" => PhysicalKey::Enter,
-            "Space" => PhysicalKey::Space,
-            "Tab" => PhysicalKey::Tab,
-            "Convert" => PhysicalKey::Convert,
-            "KanaMode" => PhysicalKey::KanaMode,
-            "Lang1" => PhysicalKey::Lang1,
-            "Lang2" => PhysicalKey::Lang2,
-            "Lang3" => PhysicalKey::Lang3,
-            "Lang4" => PhysicalKey::Lang4,
-            "Lang5" => PhysicalKey::Lang5,
-            "NonConvert" => PhysicalKey::NonConvert,
-            "Delete" => PhysicalKey::Delete,
-            "End" => PhysicalKey::End,
-            "Help" => PhysicalKey::Help,
-            "Home" => PhysicalKey::Home,
-            "Insert" => PhysicalKey::Insert,
-            "PageDown" => PhysicalKey::PageDown,
-            "PageUp" => PhysicalKey::PageUp,
-            "ArrowDown" => PhysicalKey::ArrowDown,
-            "ArrowLeft" => PhysicalKey::ArrowLeft,
-            "ArrowRight" => PhysicalKey::ArrowRight,
-            "ArrowUp" => PhysicalKey::ArrowUp,
-            "NumLock" => PhysicalKey::NumLock,
-            "Numpad0" => PhysicalKey::Numpad0,
-            "Numpad1" => PhysicalKey::Numpad1,
-            "Numpad2" => PhysicalKey::Numpad2,
-            "Numpad3" => PhysicalKey::Numpad3,
-            "Numpad4" => PhysicalKey::Numpad4,
-            "Numpad5" => PhysicalKey::Numpad5,
-            "Numpad6" => PhysicalKey::Numpad6,
-            "Numpad7" => PhysicalKey::Numpad7,
-            "Numpad8" => PhysicalKey::Numpad8,
-            "Numpad9" => PhysicalKey::Numpad9,
-            "NumpadAdd" => PhysicalKey::NumpadAdd,
-            "NumpadBackspace" => PhysicalKey::NumpadBackspace,
-            "NumpadClear" => PhysicalKey::NumpadClear,
-            "NumpadClearEntry" => PhysicalKey::NumpadClearEntry,
-            "NumpadComma" => PhysicalKey::NumpadComma,
-            "NumpadDecimal" => PhysicalKey::NumpadDecimal,
-            "NumpadDivide" => PhysicalKey::NumpadDivide,
-            "NumpadEnter" => PhysicalKey::NumpadEnter,
-            "NumpadEqual" => PhysicalKey::NumpadEqual,
-            "NumpadHash" => PhysicalKey::NumpadHash,
-            "NumpadMemoryAdd" => PhysicalKey::NumpadMemoryAdd,
-            "NumpadMemoryClear" => PhysicalKey::NumpadMemoryClear,
-            "NumpadMemoryRecall" => PhysicalKey::NumpadMemoryRecall,
-            "NumpadMemoryStore" => PhysicalKey::NumpadMemoryStore,
-            "NumpadMemorySubtract" => PhysicalKey::NumpadMemorySubtract,
-            "NumpadMultiply" => PhysicalKey::NumpadMultiply,
-            "NumpadParenLeft" => PhysicalKey::NumpadParenLeft,
-            "NumpadParenRight" => PhysicalKey::NumpadParenRight,
-            "NumpadStar" => PhysicalKey::NumpadStar,
-            "NumpadSubtract" => PhysicalKey::NumpadSubtract,
-            "Escape" => PhysicalKey::Escape,
-            "Fn" => PhysicalKey::Fn,
-            "FnLock" => PhysicalKey::FnLock,
-            "PrintScreen" => PhysicalKey::PrintScreen,
-            "ScrollLock" => PhysicalKey::ScrollLock,
-            "Pause" => PhysicalKey::Pause,
-            "BrowserBack" => PhysicalKey::BrowserBack,
-            "BrowserFavorites" => PhysicalKey::BrowserFavorites,
-            "BrowserForward" => PhysicalKey::BrowserForward,
-            "BrowserHome" => PhysicalKey::BrowserHome,
-            "BrowserRefresh" => PhysicalKey::BrowserRefresh,
-            "BrowserSearch" => PhysicalKey::BrowserSearch,
-            "BrowserStop" => PhysicalKey::BrowserStop,
-            "Eject" => PhysicalKey::Eject,
-            "LaunchApp1" => PhysicalKey::LaunchApp1,
-            "LaunchApp2" => PhysicalKey::LaunchApp2,
-            "LaunchMail" => PhysicalKey::LaunchMail,
-            "MediaPlayPause" => PhysicalKey::MediaPlayPause,
-            "MediaSelect" => PhysicalKey::MediaSelect,
-            "MediaStop" => PhysicalKey::MediaStop,
-            "MediaTrackNext" => PhysicalKey::MediaTrackNext,
-            "MediaTrackPrevious" => PhysicalKey::MediaTrackPrevious,
-            "Power" => PhysicalKey::Power,
-            "Sleep" => PhysicalKey::Sleep,
-            "AudioVolumeDown" => PhysicalKey::AudioVolumeDown,
-            "AudioVolumeMute" => PhysicalKey::AudioVolumeMute,
-            "AudioVolumeUp" => PhysicalKey::AudioVolumeUp,
-            "WakeUp" => PhysicalKey::WakeUp,
-            "Abort" => PhysicalKey::Abort,
-            "Resume" => PhysicalKey::Resume,
-            "Suspend" => PhysicalKey::Suspend,
-            "Again" => PhysicalKey::Again,
-            "Copy" => PhysicalKey::Copy,
-            "Cut" => PhysicalKey::Cut,
-            "Find" => PhysicalKey::Find,
-            "Open" => PhysicalKey::Open,
-            "Paste" => PhysicalKey::Paste,
-            "Props" => PhysicalKey::Props,
-            "Select" => PhysicalKey::Select,
-            "Undo" => PhysicalKey::Undo,
-            "Hiragana" => PhysicalKey::Hiragana,
-            "Katakana" => PhysicalKey::Katakana,
-            "F1" => PhysicalKey::F1,
-            "F2" => PhysicalKey::F2,
-            "F3" => PhysicalKey::F3,
-            "F4" => PhysicalKey::F4,
-            "F5" => PhysicalKey::F5,
-            "F6" => PhysicalKey::F6,
-            "F7" => PhysicalKey::F7,
-            "F8" => PhysicalKey::F8,
-            "F9" => PhysicalKey::F9,
-            "F10" => PhysicalKey::F10,
-            "F11" => PhysicalKey::F11,
-            "F12" => PhysicalKey::F12,
-            "F13" => PhysicalKey::F13,
-            "F14" => PhysicalKey::F14,
-            "F15" => PhysicalKey::F15,
-            "F16" => PhysicalKey::F16,
-            "F17" => PhysicalKey::F17,
-            "F18" => PhysicalKey::F18,
-            "F19" => PhysicalKey::F19,
-            "F20" => PhysicalKey::F20,
-            "F21" => PhysicalKey::F21,
-            "F22" => PhysicalKey::F22,
-            "F23" => PhysicalKey::F23,
-            "F24" => PhysicalKey::F24,
-            "F25" => PhysicalKey::F25,
-            "F26" => PhysicalKey::F26,
-            "F27" => PhysicalKey::F27,
-            "F28" => PhysicalKey::F28,
-            "F29" => PhysicalKey::F29,
-            "F30" => PhysicalKey::F30,
-            "F31" => PhysicalKey::F31,
-            "F32" => PhysicalKey::F32,
-            "F33" => PhysicalKey::F33,
-            "F34" => PhysicalKey::F34,
-            "F35" => PhysicalKey::F35,
-            _ => {
-                panic!("unknown key: {}", key)
-            }
+        match PhysicalKey::try_from_value(&key) {
+            Some(key) => key,
+            None => panic!("unknown key: {}", key)
+        }
+    }
+
+    // for keys coming from plugins, which are not guaranteed to be valid
+    pub fn try_from_value(key: &str) -> Option<PhysicalKey> {
+        match key {
+            "Backquote" => Some(PhysicalKey::Backquote),
+            "Backslash" => Some(PhysicalKey::Backslash),
+            "BracketLeft" => Some(PhysicalKey::BracketLeft),
+            "BracketRight" => Some(PhysicalKey::BracketRight),
+            "Comma" => Some(PhysicalKey::Comma),
+            "Digit0" => Some(PhysicalKey::Digit0),
+            "Digit1" => Some(PhysicalKey::Digit1),
+            "Digit2" => Some(PhysicalKey::Digit2),
+            "Digit3" => Some(PhysicalKey::Digit3),
+            "Digit4" => Some(PhysicalKey::Digit4),
+            "Digit5" => Some(PhysicalKey::Digit5),
+            "Digit6" => Some(PhysicalKey::Digit6),
+            "Digit7" => Some(PhysicalKey::Digit7),
+            "Digit8" => Some(PhysicalKey::Digit8),
+            "Digit9" => Some(PhysicalKey::Digit9),
+            "Equal" => Some(PhysicalKey::Equal),
+            "IntlBackslash" => Some(PhysicalKey::IntlBackslash),
+            "IntlRo" => Some(PhysicalKey::IntlRo),
+            "IntlYen" => Some(PhysicalKey::IntlYen),
+            "KeyA" => Some(PhysicalKey::KeyA),
+            "KeyB" => Some(PhysicalKey::KeyB),
+            "KeyC" => Some(PhysicalKey::KeyC),
+            "KeyD" => Some(PhysicalKey::KeyD),
+            "KeyE" => Some(PhysicalKey::KeyE),
+            "KeyF" => Some(PhysicalKey::KeyF),
+            "KeyG" => Some(PhysicalKey::KeyG),
+            "KeyH" => Some(PhysicalKey::KeyH),
+            "KeyI" => Some(PhysicalKey::KeyI),
+            "KeyJ" => Some(PhysicalKey::KeyJ),
+            "KeyK" => Some(PhysicalKey::KeyK),
+            "KeyL" => Some(PhysicalKey::KeyL),
+            "KeyM" => Some(PhysicalKey::KeyM),
+            "KeyN" => Some(PhysicalKey::KeyN),
+            "KeyO" => Some(PhysicalKey::KeyO),
+            "KeyP" => Some(PhysicalKey::KeyP),
+            "KeyQ" => Some(PhysicalKey::KeyQ),
+            "KeyR" => Some(PhysicalKey::KeyR),
+            "KeyS" => Some(PhysicalKey::KeyS),
+            "KeyT" => Some(PhysicalKey::KeyT),
+            "KeyU" => Some(PhysicalKey::KeyU),
+            "KeyV" => Some(PhysicalKey::KeyV),
+            "KeyW" => Some(PhysicalKey::KeyW),
+            "KeyX" => Some(PhysicalKey::KeyX),
+            "KeyY" => Some(PhysicalKey::KeyY),
+            "KeyZ" => Some(PhysicalKey::KeyZ),
+            "Minus" => Some(PhysicalKey::Minus),
+            "Period" => Some(PhysicalKey::Period),
+            "Quote" => Some(PhysicalKey::Quote),
+            "Semicolon" => Some(PhysicalKey::Semicolon),
+            "Slash" => Some(PhysicalKey::Slash),
+            "Backspace" => Some(PhysicalKey::Backspace),
+            "CapsLock" => Some(PhysicalKey::CapsLock),
+            "ContextMenu" => Some(PhysicalKey::ContextMenu),
+            "Enter" => Some(PhysicalKey::Enter),
+            "Space" => Some(PhysicalKey::Space),
+            "Tab" => Some(PhysicalKey::Tab),
+            "Convert" => Some(PhysicalKey::Convert),
+            "KanaMode" => Some(PhysicalKey::KanaMode),
+            "Lang1" => Some(PhysicalKey::Lang1),
+            "Lang2" => Some(PhysicalKey::Lang2),
+            "Lang3" => Some(PhysicalKey::Lang3),
+            "Lang4" => Some(PhysicalKey::Lang4),
+            "Lang5" => Some(PhysicalKey::Lang5),
+            "NonConvert" => Some(PhysicalKey::NonConvert),
+            "Delete" => Some(PhysicalKey::Delete),
+            "End" => Some(PhysicalKey::End),
+            "Help" => Some(PhysicalKey::Help),
+            "Home" => Some(PhysicalKey::Home),
+            "Insert" => Some(PhysicalKey::Insert),
+            "PageDown" => Some(PhysicalKey::PageDown),
+            "PageUp" => Some(PhysicalKey::PageUp),
+            "ArrowDown" => Some(PhysicalKey::ArrowDown),
+            "ArrowLeft" => Some(PhysicalKey::ArrowLeft),
+            "ArrowRight" => Some(PhysicalKey::ArrowRight),
+            "ArrowUp" => Some(PhysicalKey::ArrowUp),
+            "NumLock" => Some(PhysicalKey::NumLock),
+            "Numpad0" => Some(PhysicalKey::Numpad0),
+            "Numpad1" => Some(PhysicalKey::Numpad1),
+            "Numpad2" => Some(PhysicalKey::Numpad2),
+            "Numpad3" => Some(PhysicalKey::Numpad3),
+            "Numpad4" => Some(PhysicalKey::Numpad4),
+            "Numpad5" => Some(PhysicalKey::Numpad5),
+            "Numpad6" => Some(PhysicalKey::Numpad6),
+            "Numpad7" => Some(PhysicalKey::Numpad7),
+            "Numpad8" => Some(PhysicalKey::Numpad8),
+            "Numpad9" => Some(PhysicalKey::Numpad9),
+            "NumpadAdd" => Some(PhysicalKey::NumpadAdd),
+            "NumpadBackspace" => Some(PhysicalKey::NumpadBackspace),
+            "NumpadClear" => Some(PhysicalKey::NumpadClear),
+            "NumpadClearEntry" => Some(PhysicalKey::NumpadClearEntry),
+            "NumpadComma" => Some(PhysicalKey::NumpadComma),
+            "NumpadDecimal" => Some(PhysicalKey::NumpadDecimal),
+            "NumpadDivide" => Some(PhysicalKey::NumpadDivide),
+            "NumpadEnter" => Some(PhysicalKey::NumpadEnter),
+            "NumpadEqual" => Some(PhysicalKey::NumpadEqual),
+            "NumpadHash" => Some(PhysicalKey::NumpadHash),
+            "NumpadMemoryAdd" => Some(PhysicalKey::NumpadMemoryAdd),
+            "NumpadMemoryClear" => Some(PhysicalKey::NumpadMemoryClear),
+            "NumpadMemoryRecall" => Some(PhysicalKey::NumpadMemoryRecall),
+            "NumpadMemoryStore" => Some(PhysicalKey::NumpadMemoryStore),
+            "NumpadMemorySubtract" => Some(PhysicalKey::NumpadMemorySubtract),
+            "NumpadMultiply" => Some(PhysicalKey::NumpadMultiply),
+            "NumpadParenLeft" => Some(PhysicalKey::NumpadParenLeft),
+            "NumpadParenRight" => Some(PhysicalKey::NumpadParenRight),
+            "NumpadStar" => Some(PhysicalKey::NumpadStar),
+            "NumpadSubtract" => Some(PhysicalKey::NumpadSubtract),
+            "Escape" => Some(PhysicalKey::Escape),
+            "Fn" => Some(PhysicalKey::Fn),
+            "FnLock" => Some(PhysicalKey::FnLock),
+            "PrintScreen" => Some(PhysicalKey::PrintScreen),
+            "ScrollLock" => Some(PhysicalKey::ScrollLock),
+            "Pause" => Some(PhysicalKey::Pause),
+            "BrowserBack" => Some(PhysicalKey::BrowserBack),
+            "BrowserFavorites" => Some(PhysicalKey::BrowserFavorites),
+            "BrowserForward" => Some(PhysicalKey::BrowserForward),
+            "BrowserHome" => Some(PhysicalKey::BrowserHome),
+            "BrowserRefresh" => Some(PhysicalKey::BrowserRefresh),
+            "BrowserSearch" => Some(PhysicalKey::BrowserSearch),
+            "BrowserStop" => Some(PhysicalKey::BrowserStop),
+            "Eject" => Some(PhysicalKey::Eject),
+            "LaunchApp1" => Some(PhysicalKey::LaunchApp1),
+            "LaunchApp2" => Some(PhysicalKey::LaunchApp2),
+            "LaunchMail" => Some(PhysicalKey::LaunchMail),
+            "MediaPlayPause" => Some(PhysicalKey::MediaPlayPause),
+            "MediaSelect" => Some(PhysicalKey::MediaSelect),
+            "MediaStop" => Some(PhysicalKey::MediaStop),
+            "MediaTrackNext" => Some(PhysicalKey::MediaTrackNext),
+            "MediaTrackPrevious" => Some(PhysicalKey::MediaTrackPrevious),
+            "Power" => Some(PhysicalKey::Power),
+            "Sleep" => Some(PhysicalKey::Sleep),
+            "AudioVolumeDown" => Some(PhysicalKey::AudioVolumeDown),
+            "AudioVolumeMute" => Some(PhysicalKey::AudioVolumeMute),
+            "AudioVolumeUp" => Some(PhysicalKey::AudioVolumeUp),
+            "WakeUp" => Some(PhysicalKey::WakeUp),
+            "Abort" => Some(PhysicalKey::Abort),
+            "Resume" => Some(PhysicalKey::Resume),
+            "Suspend" => Some(PhysicalKey::Suspend),
+            "Again" => Some(PhysicalKey::Again),
+            "Copy" => Some(PhysicalKey::Copy),
+            "Cut" => Some(PhysicalKey::Cut),
+            "Find" => Some(PhysicalKey::Find),
+            "Open" => Some(PhysicalKey::Open),
+            "Paste" => Some(PhysicalKey::Paste),
+            "Props" => Some(PhysicalKey::Props),
+            "Select" => Some(PhysicalKey::Select),
+            "Undo" => Some(PhysicalKey::Undo),
+            "Hiragana" => Some(PhysicalKey::Hiragana),
+            "Katakana" => Some(PhysicalKey::Katakana),
+            "F1" => Some(PhysicalKey::F1),
+            "F2" => Some(PhysicalKey::F2),
+            "F3" => Some(PhysicalKey::F3),
+            "F4" => Some(PhysicalKey::F4),
+            "F5" => Some(PhysicalKey::F5),
+            "F6" => Some(PhysicalKey::F6),
+            "F7" => Some(PhysicalKey::F7),
+            "F8" => Some(PhysicalKey::F8),
+            "F9" => Some(PhysicalKey::F9),
+            "F10" => Some(PhysicalKey::F10),
+            "F11" => Some(PhysicalKey::F11),
+            "F12" => Some(PhysicalKey::F12),
+            "F13" => Some(PhysicalKey::F13),
+            "F14" => Some(PhysicalKey::F14),
+            "F15" => Some(PhysicalKey::F15),
+            "F16" => Some(PhysicalKey::F16),
+            "F17" => Some(PhysicalKey::F17),
+            "F18" => Some(PhysicalKey::F18),
+            "F19" => Some(PhysicalKey::F19),
+            "F20" => Some(PhysicalKey::F20),
+            "F21" => Some(PhysicalKey::F21),
+            "F22" => Some(PhysicalKey::F22),
+            "F23" => Some(PhysicalKey::F23),
+            "F24" => Some(PhysicalKey::F24),
+            "F25" => Some(PhysicalKey::F25),
+            "F26" => Some(PhysicalKey::F26),
+            "F27" => Some(PhysicalKey::F27),
+            "F28" => Some(PhysicalKey::F28),
+            "F29" => Some(PhysicalKey::F29),
+            "F30" => Some(PhysicalKey::F30),
+            "F31" => Some(PhysicalKey::F31),
+            "F32" => Some(PhysicalKey::F32),
+            "F33" => Some(PhysicalKey::F33),
+            "F34" => Some(PhysicalKey::F34),
+            "F35" => Some(PhysicalKey::F35),
+            _ => None
         }
     }
 
@@ -1,19 +1,26 @@
 use std::collections::HashMap;
+use std::time::Duration;
 use thiserror::Error;
 use tonic::{Code, Request};
-use tonic::transport::Channel;
+use tonic::transport::{Channel, Endpoint};
 
 use gauntlet_utils::channel::{RequestError, RequestSender};
 
-use crate::model::{BackendRequestData, BackendResponseData, DownloadStatus, EntrypointId, KeyboardEventOrigin, LocalSaveData, PhysicalKey, PhysicalShortcut, PluginId, PluginPreferenceUserData, SearchResult, SettingsEntrypoint, SettingsEntrypointType, SettingsPlugin, SettingsTheme, UiPropertyValue, UiSetupData, UiWidgetId, WindowPositionMode};
-use crate::rpc::grpc::{RpcDownloadPluginRequest, RpcDownloadStatus, RpcDownloadStatusRequest, RpcEntrypointTypeSettings, RpcGetGlobalShortcutRequest, RpcGetThemeRequest, RpcGetWindowPositionModeRequest, RpcPingRequest, RpcPluginsRequest, RpcRemovePluginRequest, RpcSaveLocalPluginRequest, RpcSetEntrypointStateRequest, RpcSetGlobalShortcutRequest, RpcSetPluginStateRequest, RpcSetPreferenceValueRequest, RpcSetThemeRequest, RpcSetWindowPositionModeRequest, RpcShortcut, RpcShowSettingsWindowRequest, RpcShowWindowRequest};
+use crate::model::{BackendRequestData, BackendResponseData, Density, DevConsoleEvent, DevConsoleEventKind, DownloadStatus, EntrypointId, FileQuicklook, ImageHash, KeyboardEventOrigin, LocalSaveData, PhysicalKey, PhysicalShortcut, PluginId, PluginMetrics, PluginPreferenceUserData, SearchFilter, SearchResult, SearchResultPreview, SearchResultsSort, ServerMetrics, SettingsCrashReport, SettingsEntrypoint, SettingsEntrypointType, SettingsEntrypointUsage, SettingsPlugin, SettingsPluginDiskUsage, SettingsQuicklink, SettingsTheme, SettingsUnusedPlugin, SettingsUsageStats, UiPropertyValue, UiRendererInfo, UiScale, UiSetupData, UiWidgetId, WindowPositionMode};
+use crate::rpc::grpc::{RpcClearPluginCacheRequest, RpcClearPluginDataRequest, RpcClearUsageStatsRequest, RpcCrashReportsRequest, RpcDevConsoleEvent, RpcDevConsoleEventKind, RpcDevConsoleRequest, RpcDownloadPluginRequest, RpcDownloadStatus, RpcDownloadStatusRequest, RpcEntrypointTypeSettings, RpcGetAutostartEnabledRequest, RpcGetCrashReportsEnabledRequest, RpcGetGlobalShortcutRequest, RpcGetSearchResultsSortRequest, RpcGetThemeRequest, RpcGetUsageStatsEnabledRequest, RpcGetDensityRequest, RpcGetUiScaleRequest, RpcGetWindowOverlayRequest, RpcGetWindowPositionModeRequest, RpcHandleDeepLinkRequest, RpcHideWindowRequest, RpcMetricsRequest, RpcPingRequest, RpcPluginDiskUsageRequest, RpcPluginsRequest, RpcProfilesRequest, RpcQuicklinksRequest, RpcRecentLogLinesRequest, RpcReindexRequest, RpcRemoveCrashReportRequest, RpcRemovePluginRequest, RpcRemoveProfileRequest, RpcRemoveQuicklinkRequest, RpcRendererInfoRequest, RpcSaveLocalPluginRequest, RpcSaveProfileRequest, RpcSaveQuicklinkRequest, RpcSetAutostartEnabledRequest, RpcSetCrashReportsEnabledRequest, RpcSetEntrypointHiddenRequest, RpcSetEntrypointOrderRequest, RpcSetEntrypointStateRequest, RpcSetGlobalShortcutRequest, RpcSetPluginEnvironmentRequest, RpcSetPluginFilesystemRootsRequest, RpcSetPluginNotificationsEnabledRequest, RpcSetPluginPriorityRequest, RpcSetPluginStateRequest, RpcSetPreferenceValueRequest, RpcSetSearchResultsSortRequest, RpcSetThemeRequest, RpcSetUsageStatsEnabledRequest, RpcSetDensityRequest, RpcSetUiScaleRequest, RpcSetWindowOverlayRequest, RpcSetWindowPositionModeRequest, RpcShortcut, RpcShowSettingsWindowRequest, RpcShowWindowRequest, RpcSubmitCrashReportRequest, RpcSwitchProfileRequest, RpcToggleWindowRequest, RpcUsageStatsRequest};
 use crate::rpc::grpc::rpc_backend_client::RpcBackendClient;
-use crate::rpc::grpc_convert::{plugin_preference_from_rpc, plugin_preference_user_data_from_rpc, plugin_preference_user_data_to_rpc};
+use crate::rpc::backend_server::BACKEND_SERVER_ADDRESS;
+use crate::rpc::grpc_convert::{metrics_timing_from_rpc, plugin_preference_from_rpc, plugin_preference_user_data_from_rpc, plugin_preference_user_data_to_rpc};
+
+const RECONNECT_INITIAL_DELAY: Duration = Duration::from_secs(1);
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(30);
 
 #[derive(Error, Debug, Clone)]
 pub enum BackendForFrontendApiError {
     #[error("Frontend wasn't able to process request in a timely manner")]
     TimeoutError,
+    #[error("Backend is not running")]
+    Disconnected,
     #[error("Internal Error: {display:?}")]
     Internal {
         display: String
@@ -24,7 +31,7 @@ impl From<RequestError> for BackendForFrontendApiError {
     fn from(error: RequestError) -> BackendForFrontendApiError {
         match error {
             RequestError::TimeoutError => BackendForFrontendApiError::TimeoutError,
-            RequestError::OtherSideWasDropped => BackendForFrontendApiError::Internal { display: "other side was dropped".to_string() }
+            RequestError::OtherSideWasDropped => BackendForFrontendApiError::Disconnected
         }
     }
 }
@@ -41,6 +48,31 @@ impl BackendForFrontendApi {
         }
     }
 
+    pub async fn ping(&mut self) -> Result<(), BackendForFrontendApiError> {
+        let request = BackendRequestData::Ping;
+
+        let BackendResponseData::Nothing = self.backend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    // pings backend with exponential backoff until it responds
+    pub async fn reconnect(&mut self) {
+        let mut delay = RECONNECT_INITIAL_DELAY;
+
+        loop {
+            tokio::time::sleep(delay).await;
+
+            if self.ping().await.is_ok() {
+                return;
+            }
+
+            delay = (delay * 2).min(RECONNECT_MAX_DELAY);
+        }
+    }
+
     pub async fn setup_data(&mut self) -> Result<UiSetupData, BackendForFrontendApiError> {
         let request = BackendRequestData::Setup;
 
@@ -51,6 +83,18 @@ impl BackendForFrontendApi {
         Ok(data)
     }
 
+    pub async fn report_renderer(&mut self, renderer: UiRendererInfo) -> Result<(), BackendForFrontendApiError> {
+        let request = BackendRequestData::ReportRenderer {
+            renderer
+        };
+
+        let BackendResponseData::Nothing = self.backend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
     pub async fn setup_response(&mut self, global_shortcut_error: Option<String>) -> Result<(), BackendForFrontendApiError> {
         let request = BackendRequestData::SetupResponse {
             global_shortcut_error
@@ -63,17 +107,19 @@ impl BackendForFrontendApi {
         Ok(())
     }
 
-    pub async fn search(&mut self, text: String, render_inline_view: bool) -> Result<Vec<SearchResult>, BackendForFrontendApiError> {
+    // returns search results, amount of recently used entrypoints at the start of them and whether results are fallbacks
+    pub async fn search(&mut self, text: String, filter: SearchFilter, render_inline_view: bool) -> Result<(Vec<SearchResult>, usize, bool), BackendForFrontendApiError> {
         let request = BackendRequestData::Search {
             text,
+            filter,
             render_inline_view,
         };
 
-        let BackendResponseData::Search { results } = self.backend_sender.send_receive(request).await? else {
+        let BackendResponseData::Search { results, recent_count, fallback } = self.backend_sender.send_receive(request).await? else {
             unreachable!()
         };
 
-        Ok(results)
+        Ok((results, recent_count, fallback))
     }
 
     pub async fn request_view_render(&mut self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Result<HashMap<String, PhysicalShortcut>, BackendForFrontendApiError> {
@@ -89,6 +135,18 @@ impl BackendForFrontendApi {
         Ok(shortcuts)
     }
 
+    pub async fn request_view_pop(&mut self, plugin_id: PluginId) -> Result<(), BackendForFrontendApiError> {
+        let request = BackendRequestData::RequestViewPop {
+            plugin_id,
+        };
+
+        let BackendResponseData::Nothing = self.backend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
     pub async fn request_view_close(&mut self, plugin_id: PluginId) -> Result<(), BackendForFrontendApiError> {
         let request = BackendRequestData::RequestViewClose {
             plugin_id,
@@ -101,10 +159,11 @@ impl BackendForFrontendApi {
         Ok(())
     }
 
-    pub async fn request_run_command(&mut self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Result<(), BackendForFrontendApiError> {
+    pub async fn request_run_command(&mut self, plugin_id: PluginId, entrypoint_id: EntrypointId, arguments: HashMap<String, String>) -> Result<(), BackendForFrontendApiError> {
         let request = BackendRequestData::RequestRunCommand {
             plugin_id,
             entrypoint_id,
+            arguments,
         };
 
         let BackendResponseData::Nothing = self.backend_sender.send_receive(request).await? else {
@@ -201,6 +260,81 @@ impl BackendForFrontendApi {
         Ok(())
     }
 
+    pub async fn run_confirmed_deep_link(&mut self, uri: String) -> Result<(), BackendForFrontendApiError> {
+        let request = BackendRequestData::RunConfirmedDeepLink {
+            uri
+        };
+
+        let BackendResponseData::Nothing = self.backend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    pub async fn set_plugins_paused(&mut self, paused: bool) -> Result<(), BackendForFrontendApiError> {
+        let request = BackendRequestData::SetPluginsPaused {
+            paused,
+        };
+
+        let BackendResponseData::Nothing = self.backend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    pub async fn set_offline_mode(&mut self, offline: bool) -> Result<(), BackendForFrontendApiError> {
+        let request = BackendRequestData::SetOfflineMode {
+            offline,
+        };
+
+        let BackendResponseData::Nothing = self.backend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    pub async fn set_focus_mode(&mut self, enabled: bool) -> Result<(), BackendForFrontendApiError> {
+        let request = BackendRequestData::SetFocusMode {
+            enabled,
+        };
+
+        let BackendResponseData::Nothing = self.backend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    pub async fn set_entrypoint_state(&mut self, plugin_id: PluginId, entrypoint_id: EntrypointId, enabled: bool) -> Result<(), BackendForFrontendApiError> {
+        let request = BackendRequestData::SetEntrypointState {
+            plugin_id,
+            entrypoint_id,
+            enabled,
+        };
+
+        let BackendResponseData::Nothing = self.backend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    pub async fn complete_plugin_onboarding(&mut self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Result<(), BackendForFrontendApiError> {
+        let request = BackendRequestData::CompletePluginOnboarding {
+            plugin_id,
+            entrypoint_id,
+        };
+
+        let BackendResponseData::Nothing = self.backend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
     pub async fn open_settings_window_preferences(&mut self, plugin_id: PluginId, entrypoint_id: Option<EntrypointId>) -> Result<(), BackendForFrontendApiError> {
         let request = BackendRequestData::OpenSettingsWindowPreferences {
             plugin_id,
@@ -223,6 +357,44 @@ impl BackendForFrontendApi {
 
         Ok(shortcuts)
     }
+
+    pub async fn images(&self, hashes: Vec<ImageHash>) -> Result<HashMap<ImageHash, Vec<u8>>, BackendForFrontendApiError> {
+        let request = BackendRequestData::GetImages {
+            hashes
+        };
+
+        let BackendResponseData::Images { images } = self.backend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(images)
+    }
+
+    pub async fn result_preview(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Result<Option<SearchResultPreview>, BackendForFrontendApiError> {
+        let request = BackendRequestData::GetResultPreview {
+            plugin_id,
+            entrypoint_id,
+        };
+
+        let BackendResponseData::ResultPreview { preview } = self.backend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(preview)
+    }
+
+    pub async fn file_quicklook(&self, path: String, dark: bool) -> Result<FileQuicklook, BackendForFrontendApiError> {
+        let request = BackendRequestData::GetFileQuicklook {
+            path,
+            dark,
+        };
+
+        let BackendResponseData::FileQuicklook { quicklook } = self.backend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(quicklook)
+    }
 }
 
 #[derive(Error, Debug, Clone)]
@@ -256,6 +428,10 @@ impl From<prost::UnknownEnumValue> for BackendApiError {
     }
 }
 
+fn backend_server_uri() -> String {
+    format!("http://{}", BACKEND_SERVER_ADDRESS)
+}
+
 #[derive(Debug, Clone)]
 pub struct BackendApi {
     client: RpcBackendClient<Channel>
@@ -264,10 +440,22 @@ pub struct BackendApi {
 impl BackendApi {
     pub async fn new() -> anyhow::Result<Self> {
         Ok(Self {
-            client: RpcBackendClient::connect("http://127.0.0.1:42320").await?
+            client: RpcBackendClient::connect(backend_server_uri()).await?
         })
     }
 
+    // connection is established on first request, for when server runs in the same process and may not be listening yet.
+    // channel is cloned together with api, so all clones share single connection
+    pub fn new_lazy() -> Self {
+        let channel = Endpoint::from_shared(backend_server_uri())
+            .expect("backend server address should be valid uri")
+            .connect_lazy();
+
+        Self {
+            client: RpcBackendClient::new(channel)
+        }
+    }
+
     pub async fn ping(&mut self) -> Result<(), BackendApiError> {
         let _ = self.client.ping(Request::new(RpcPingRequest::default()))
             .await?;
@@ -282,6 +470,29 @@ impl BackendApi {
         Ok(())
     }
 
+    pub async fn hide_window(&mut self) -> Result<(), BackendApiError> {
+        let _ = self.client.hide_window(Request::new(RpcHideWindowRequest::default()))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn toggle_window(&mut self) -> Result<(), BackendApiError> {
+        let _ = self.client.toggle_window(Request::new(RpcToggleWindowRequest::default()))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn handle_deep_link(&mut self, uri: String) -> Result<(), BackendApiError> {
+        let request = RpcHandleDeepLinkRequest { uri };
+
+        self.client.handle_deep_link(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn show_settings_window(&mut self) -> Result<(), BackendApiError> {
         let _ = self.client.show_settings_window(Request::new(RpcShowSettingsWindowRequest::default()))
             .await?;
@@ -307,11 +518,14 @@ impl BackendApi {
                             RpcEntrypointTypeSettings::SCommand => SettingsEntrypointType::Command,
                             RpcEntrypointTypeSettings::SView => SettingsEntrypointType::View,
                             RpcEntrypointTypeSettings::SInlineView => SettingsEntrypointType::InlineView,
-                            RpcEntrypointTypeSettings::SEntrypointGenerator => SettingsEntrypointType::EntrypointGenerator
+                            RpcEntrypointTypeSettings::SEntrypointGenerator => SettingsEntrypointType::EntrypointGenerator,
+                            RpcEntrypointTypeSettings::SSearchProvider => SettingsEntrypointType::SearchProvider,
                         };
 
                         let entrypoint = SettingsEntrypoint {
                             enabled: entrypoint.enabled,
+                            hidden: entrypoint.hidden,
+                            position: entrypoint.position,
                             entrypoint_id: id.clone(),
                             entrypoint_name: entrypoint.entrypoint_name.clone(),
                             entrypoint_description: entrypoint.entrypoint_description,
@@ -333,6 +547,10 @@ impl BackendApi {
                     plugin_name: plugin.plugin_name,
                     plugin_description: plugin.plugin_description,
                     enabled: plugin.enabled,
+                    priority: plugin.priority,
+                    notifications_enabled: plugin.notifications_enabled,
+                    environment: plugin.environment,
+                    filesystem_roots: plugin.filesystem_roots,
                     entrypoints,
                     preferences: plugin.preferences.into_iter()
                         .map(|(key, value)| (key, plugin_preference_from_rpc(value)))
@@ -361,6 +579,92 @@ impl BackendApi {
         Ok(())
     }
 
+    pub async fn set_plugin_priority(&mut self, plugin_id: PluginId, priority: f64) -> Result<(), BackendApiError> {
+        let request = RpcSetPluginPriorityRequest {
+            plugin_id: plugin_id.to_string(),
+            priority,
+        };
+
+        self.client.set_plugin_priority(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn set_plugin_notifications_enabled(&mut self, plugin_id: PluginId, enabled: bool) -> Result<(), BackendApiError> {
+        let request = RpcSetPluginNotificationsEnabledRequest {
+            plugin_id: plugin_id.to_string(),
+            enabled,
+        };
+
+        self.client.set_plugin_notifications_enabled(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn set_plugin_environment(&mut self, plugin_id: PluginId, environment: HashMap<String, String>) -> Result<(), BackendApiError> {
+        let request = RpcSetPluginEnvironmentRequest {
+            plugin_id: plugin_id.to_string(),
+            environment,
+        };
+
+        self.client.set_plugin_environment(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn set_plugin_filesystem_roots(&mut self, plugin_id: PluginId, filesystem_roots: Vec<String>) -> Result<(), BackendApiError> {
+        let request = RpcSetPluginFilesystemRootsRequest {
+            plugin_id: plugin_id.to_string(),
+            filesystem_roots,
+        };
+
+        self.client.set_plugin_filesystem_roots(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn plugin_disk_usage(&mut self, plugin_id: PluginId) -> Result<SettingsPluginDiskUsage, BackendApiError> {
+        let request = RpcPluginDiskUsageRequest {
+            plugin_id: plugin_id.to_string(),
+        };
+
+        let response = self.client.plugin_disk_usage(Request::new(request))
+            .await?
+            .into_inner();
+
+        Ok(SettingsPluginDiskUsage {
+            data: response.data,
+            cache: response.cache,
+            logs: response.logs,
+        })
+    }
+
+    pub async fn clear_plugin_cache(&mut self, plugin_id: PluginId) -> Result<(), BackendApiError> {
+        let request = RpcClearPluginCacheRequest {
+            plugin_id: plugin_id.to_string(),
+        };
+
+        self.client.clear_plugin_cache(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn clear_plugin_data(&mut self, plugin_id: PluginId) -> Result<(), BackendApiError> {
+        let request = RpcClearPluginDataRequest {
+            plugin_id: plugin_id.to_string(),
+        };
+
+        self.client.clear_plugin_data(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn set_entrypoint_state(&mut self, plugin_id: PluginId, entrypoint_id: EntrypointId, enabled: bool) -> Result<(), BackendApiError> {
         let request = RpcSetEntrypointStateRequest {
             plugin_id: plugin_id.to_string(),
@@ -374,6 +678,33 @@ impl BackendApi {
         Ok(())
     }
 
+    pub async fn set_entrypoint_hidden(&mut self, plugin_id: PluginId, entrypoint_id: EntrypointId, hidden: bool) -> Result<(), BackendApiError> {
+        let request = RpcSetEntrypointHiddenRequest {
+            plugin_id: plugin_id.to_string(),
+            entrypoint_id: entrypoint_id.to_string(),
+            hidden,
+        };
+
+        self.client.set_entrypoint_hidden(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn set_entrypoint_order(&mut self, plugin_id: PluginId, entrypoint_ids: Vec<EntrypointId>) -> Result<(), BackendApiError> {
+        let request = RpcSetEntrypointOrderRequest {
+            plugin_id: plugin_id.to_string(),
+            entrypoint_ids: entrypoint_ids.into_iter()
+                .map(|entrypoint_id| entrypoint_id.to_string())
+                .collect(),
+        };
+
+        self.client.set_entrypoint_order(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn set_global_shortcut(&mut self, shortcut: Option<PhysicalShortcut>) -> Result<(), BackendApiError> {
         let request = RpcSetGlobalShortcutRequest {
             shortcut: shortcut.map(|shortcut| {
@@ -422,6 +753,7 @@ impl BackendApi {
             SettingsTheme::MacOSLight => "MacOSLight",
             SettingsTheme::MacOSDark => "MacOSDark",
             SettingsTheme::Legacy => "Legacy",
+            SettingsTheme::HighContrast => "HighContrast",
         };
 
         let request = RpcSetThemeRequest {
@@ -447,6 +779,7 @@ impl BackendApi {
             "MacOSLight" => SettingsTheme::MacOSLight,
             "MacOSDark" => SettingsTheme::MacOSDark,
             "Legacy" => SettingsTheme::Legacy,
+            "HighContrast" => SettingsTheme::HighContrast,
             _ => unreachable!()
         };
 
@@ -484,6 +817,133 @@ impl BackendApi {
         Ok(mode)
     }
 
+    pub async fn set_window_overlay(&mut self, enabled: bool) -> Result<(), BackendApiError> {
+        let request = RpcSetWindowOverlayRequest { enabled };
+
+        self.client.set_window_overlay(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_window_overlay(&mut self) -> Result<bool, BackendApiError> {
+        let enabled = self.client.get_window_overlay(Request::new(RpcGetWindowOverlayRequest::default()))
+            .await?
+            .into_inner()
+            .enabled;
+
+        Ok(enabled)
+    }
+
+    pub async fn set_density(&mut self, density: Density) -> Result<(), BackendApiError> {
+        let density = match density {
+            Density::Compact => "Compact",
+            Density::Comfortable => "Comfortable",
+            Density::Large => "Large",
+        };
+
+        let request = RpcSetDensityRequest {
+            density: density.to_string()
+        };
+
+        self.client.set_density(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_density(&mut self) -> Result<Density, BackendApiError> {
+        let response = self.client.get_density(Request::new(RpcGetDensityRequest::default()))
+            .await?;
+
+        let density = response.into_inner().density;
+
+        let density = match density.as_str() {
+            "Compact" => Density::Compact,
+            "Comfortable" => Density::Comfortable,
+            "Large" => Density::Large,
+            _ => unreachable!()
+        };
+
+        Ok(density)
+    }
+
+    // none if main window hasn't reported renderer yet
+    pub async fn renderer_info(&mut self) -> Result<Option<UiRendererInfo>, BackendApiError> {
+        let response = self.client.renderer_info(Request::new(RpcRendererInfoRequest::default()))
+            .await?;
+
+        let response = response.into_inner();
+
+        let renderer = match (response.backend, response.adapter) {
+            (Some(backend), Some(adapter)) => Some(UiRendererInfo { backend, adapter }),
+            _ => None
+        };
+
+        Ok(renderer)
+    }
+
+    pub async fn set_ui_scale(&mut self, scale: UiScale) -> Result<(), BackendApiError> {
+        let percent = match scale {
+            UiScale::Auto => None,
+            UiScale::Manual { percent } => Some(percent as u32),
+        };
+
+        let request = RpcSetUiScaleRequest {
+            percent
+        };
+
+        self.client.set_ui_scale(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_ui_scale(&mut self) -> Result<UiScale, BackendApiError> {
+        let response = self.client.get_ui_scale(Request::new(RpcGetUiScaleRequest::default()))
+            .await?;
+
+        let scale = match response.into_inner().percent {
+            None => UiScale::Auto,
+            Some(percent) => UiScale::Manual { percent: percent as u16 },
+        };
+
+        Ok(scale)
+    }
+
+    pub async fn set_search_results_sort(&mut self, sort: SearchResultsSort) -> Result<(), BackendApiError> {
+        let sort = match sort {
+            SearchResultsSort::Frecency => "Frecency",
+            SearchResultsSort::Relevance => "Relevance",
+            SearchResultsSort::Plugin => "Plugin",
+        };
+
+        let request = RpcSetSearchResultsSortRequest {
+            sort: sort.to_string()
+        };
+
+        self.client.set_search_results_sort(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_search_results_sort(&mut self) -> Result<SearchResultsSort, BackendApiError> {
+        let response = self.client.get_search_results_sort(Request::new(RpcGetSearchResultsSortRequest::default()))
+            .await?;
+
+        let sort = response.into_inner().sort;
+
+        let sort = match sort.as_str() {
+            "Frecency" => SearchResultsSort::Frecency,
+            "Relevance" => SearchResultsSort::Relevance,
+            "Plugin" => SearchResultsSort::Plugin,
+            _ => unreachable!()
+        };
+
+        Ok(sort)
+    }
+
     pub async fn set_preference_value(&mut self, plugin_id: PluginId, entrypoint_id: Option<EntrypointId>, id: String, user_data: PluginPreferenceUserData) -> Result<(), BackendApiError> {
         let request = RpcSetPreferenceValueRequest {
             plugin_id: plugin_id.to_string(),
@@ -540,11 +1000,232 @@ impl BackendApi {
         Ok(())
     }
 
+    pub async fn quicklinks(&mut self) -> Result<Vec<SettingsQuicklink>, BackendApiError> {
+        let quicklinks = self.client.quicklinks(Request::new(RpcQuicklinksRequest::default()))
+            .await?
+            .into_inner()
+            .quicklinks
+            .into_iter()
+            .map(|quicklink| SettingsQuicklink {
+                id: quicklink.id,
+                name: quicklink.name,
+                url: quicklink.url,
+                bang: quicklink.bang,
+                fallback: quicklink.fallback,
+            })
+            .collect();
+
+        Ok(quicklinks)
+    }
+
+    pub async fn save_quicklink(&mut self, id: Option<String>, name: String, url: String, bang: Option<String>, fallback: bool) -> Result<(), BackendApiError> {
+        let request = RpcSaveQuicklinkRequest { id, name, url, bang, fallback };
+
+        self.client.save_quicklink(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_quicklink(&mut self, id: String) -> Result<(), BackendApiError> {
+        let request = RpcRemoveQuicklinkRequest { id };
+
+        self.client.remove_quicklink(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn profiles(&mut self) -> Result<Vec<String>, BackendApiError> {
+        let names = self.client.profiles(Request::new(RpcProfilesRequest::default()))
+            .await?
+            .into_inner()
+            .names;
+
+        Ok(names)
+    }
+
+    pub async fn save_profile(&mut self, name: String) -> Result<(), BackendApiError> {
+        let request = RpcSaveProfileRequest { name };
+
+        self.client.save_profile(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn switch_profile(&mut self, name: String) -> Result<(), BackendApiError> {
+        let request = RpcSwitchProfileRequest { name };
+
+        self.client.switch_profile(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_profile(&mut self, name: String) -> Result<(), BackendApiError> {
+        let request = RpcRemoveProfileRequest { name };
+
+        self.client.remove_profile(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn recent_log_lines(&mut self, limit: u32) -> Result<Vec<String>, BackendApiError> {
+        let request = RpcRecentLogLinesRequest { limit };
+
+        let lines = self.client.recent_log_lines(Request::new(request))
+            .await?
+            .into_inner()
+            .lines;
+
+        Ok(lines)
+    }
+
+    pub async fn reindex(&mut self) -> Result<(), BackendApiError> {
+        self.client.reindex(Request::new(RpcReindexRequest::default()))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn set_crash_reports_enabled(&mut self, enabled: bool) -> Result<(), BackendApiError> {
+        let request = RpcSetCrashReportsEnabledRequest { enabled };
+
+        self.client.set_crash_reports_enabled(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_crash_reports_enabled(&mut self) -> Result<bool, BackendApiError> {
+        let enabled = self.client.get_crash_reports_enabled(Request::new(RpcGetCrashReportsEnabledRequest::default()))
+            .await?
+            .into_inner()
+            .enabled;
+
+        Ok(enabled)
+    }
+
+    pub async fn set_usage_stats_enabled(&mut self, enabled: bool) -> Result<(), BackendApiError> {
+        let request = RpcSetUsageStatsEnabledRequest { enabled };
+
+        self.client.set_usage_stats_enabled(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_usage_stats_enabled(&mut self) -> Result<bool, BackendApiError> {
+        let enabled = self.client.get_usage_stats_enabled(Request::new(RpcGetUsageStatsEnabledRequest::default()))
+            .await?
+            .into_inner()
+            .enabled;
+
+        Ok(enabled)
+    }
+
+    pub async fn usage_stats(&mut self) -> Result<SettingsUsageStats, BackendApiError> {
+        let response = self.client.usage_stats(Request::new(RpcUsageStatsRequest::default()))
+            .await?
+            .into_inner();
+
+        let entrypoints = response.entrypoints
+            .into_iter()
+            .map(|entrypoint| SettingsEntrypointUsage {
+                plugin_id: PluginId::from_string(entrypoint.plugin_id),
+                plugin_name: entrypoint.plugin_name,
+                entrypoint_id: EntrypointId::from_string(entrypoint.entrypoint_id),
+                entrypoint_name: entrypoint.entrypoint_name,
+                launch_count: entrypoint.launch_count,
+                average_search_to_launch: entrypoint.average_search_to_launch_micros.map(Duration::from_micros),
+            })
+            .collect();
+
+        let unused_plugins = response.unused_plugins
+            .into_iter()
+            .map(|plugin| SettingsUnusedPlugin {
+                plugin_id: PluginId::from_string(plugin.plugin_id),
+                plugin_name: plugin.plugin_name,
+            })
+            .collect();
+
+        Ok(SettingsUsageStats {
+            entrypoints,
+            unused_plugins,
+            average_search_to_launch: response.average_search_to_launch_micros.map(Duration::from_micros),
+        })
+    }
+
+    pub async fn clear_usage_stats(&mut self) -> Result<(), BackendApiError> {
+        self.client.clear_usage_stats(Request::new(RpcClearUsageStatsRequest::default()))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn crash_reports(&mut self) -> Result<Vec<SettingsCrashReport>, BackendApiError> {
+        let crash_reports = self.client.crash_reports(Request::new(RpcCrashReportsRequest::default()))
+            .await?
+            .into_inner()
+            .crash_reports
+            .into_iter()
+            .map(|crash_report| SettingsCrashReport {
+                id: crash_report.id,
+                content: crash_report.content,
+            })
+            .collect();
+
+        Ok(crash_reports)
+    }
+
+    pub async fn remove_crash_report(&mut self, id: String) -> Result<(), BackendApiError> {
+        let request = RpcRemoveCrashReportRequest { id };
+
+        self.client.remove_crash_report(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn submit_crash_report(&mut self, id: String) -> Result<(), BackendApiError> {
+        let request = RpcSubmitCrashReportRequest { id };
+
+        self.client.submit_crash_report(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn set_autostart_enabled(&mut self, enabled: bool) -> Result<(), BackendApiError> {
+        let request = RpcSetAutostartEnabledRequest { enabled };
+
+        self.client.set_autostart_enabled(Request::new(request))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_autostart_enabled(&mut self) -> Result<bool, BackendApiError> {
+        let enabled = self.client.get_autostart_enabled(Request::new(RpcGetAutostartEnabledRequest::default()))
+            .await?
+            .into_inner()
+            .enabled;
+
+        Ok(enabled)
+    }
+
     pub async fn save_local_plugin(&mut self, path: String) -> Result<LocalSaveData, BackendApiError> {
         let request = RpcSaveLocalPluginRequest { path };
 
+        // error message contains all problems with plugin manifest, so it is passed without status details
         let response = self.client.save_local_plugin(Request::new(request))
-            .await?
+            .await
+            .map_err(|err| match err.code() {
+                Code::DeadlineExceeded => BackendApiError::Timeout,
+                _ => BackendApiError::Internal { display: err.message().to_string() },
+            })?
             .into_inner();
 
         Ok(LocalSaveData {
@@ -552,4 +1233,61 @@ impl BackendApi {
             stderr_file_path: response.stderr_file_path,
         })
     }
+
+    pub async fn dev_console(&mut self, plugin_id: PluginId) -> Result<DevConsoleStream, BackendApiError> {
+        let request = RpcDevConsoleRequest { plugin_id: plugin_id.to_string() };
+
+        let stream = self.client.dev_console(Request::new(request))
+            .await?
+            .into_inner();
+
+        Ok(DevConsoleStream { stream })
+    }
+
+    pub async fn metrics(&mut self) -> Result<ServerMetrics, BackendApiError> {
+        let response = self.client.metrics(Request::new(RpcMetricsRequest::default()))
+            .await?
+            .into_inner();
+
+        let plugins = response.plugins
+            .into_iter()
+            .map(|plugin| PluginMetrics {
+                plugin_id: PluginId::from_string(plugin.plugin_id),
+                render: plugin.render.map(metrics_timing_from_rpc).unwrap_or_default(),
+                memory_bytes: plugin.memory_bytes,
+            })
+            .collect();
+
+        Ok(ServerMetrics {
+            search: response.search.map(metrics_timing_from_rpc).unwrap_or_default(),
+            requests: response.requests,
+            plugins,
+        })
+    }
+}
+
+pub struct DevConsoleStream {
+    stream: tonic::Streaming<RpcDevConsoleEvent>,
+}
+
+impl DevConsoleStream {
+    // returns None when server closed the stream
+    pub async fn next(&mut self) -> Result<Option<DevConsoleEvent>, BackendApiError> {
+        let Some(event) = self.stream.message().await? else {
+            return Ok(None)
+        };
+
+        let kind = match event.kind.try_into()? {
+            RpcDevConsoleEventKind::DevConsoleStdout => DevConsoleEventKind::Stdout,
+            RpcDevConsoleEventKind::DevConsoleStderr => DevConsoleEventKind::Stderr,
+            RpcDevConsoleEventKind::DevConsoleRender => DevConsoleEventKind::Render,
+            RpcDevConsoleEventKind::DevConsoleViewEvent => DevConsoleEventKind::ViewEvent,
+        };
+
+        Ok(Some(DevConsoleEvent {
+            plugin_id: PluginId::from_string(event.plugin_id),
+            kind,
+            message: event.message,
+        }))
+    }
 }
@@ -1,21 +1,23 @@
 use std::collections::HashMap;
-use std::net::SocketAddr;
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
 use std::time::Duration;
 
 use tokio::net::TcpStream;
+use tokio_stream::wrappers::ReceiverStream;
 use tonic::{Request, Response, Status};
 use tonic::transport::Server;
 
-use crate::model::{DownloadStatus, EntrypointId, LocalSaveData, PhysicalKey, PhysicalShortcut, PluginId, PluginPreferenceUserData, SettingsEntrypointType, SettingsPlugin, SettingsTheme, WindowPositionMode};
-use crate::rpc::grpc::{RpcDownloadPluginRequest, RpcDownloadPluginResponse, RpcDownloadStatus, RpcDownloadStatusRequest, RpcDownloadStatusResponse, RpcDownloadStatusValue, RpcEntrypoint, RpcEntrypointTypeSettings, RpcGetGlobalShortcutRequest, RpcGetGlobalShortcutResponse, RpcGetThemeRequest, RpcGetThemeResponse, RpcGetWindowPositionModeRequest, RpcGetWindowPositionModeResponse, RpcPingRequest, RpcPingResponse, RpcPlugin, RpcPluginsRequest, RpcPluginsResponse, RpcRemovePluginRequest, RpcRemovePluginResponse, RpcSaveLocalPluginRequest, RpcSaveLocalPluginResponse, RpcSetEntrypointStateRequest, RpcSetEntrypointStateResponse, RpcSetGlobalShortcutRequest, RpcSetGlobalShortcutResponse, RpcSetPluginStateRequest, RpcSetPluginStateResponse, RpcSetPreferenceValueRequest, RpcSetPreferenceValueResponse, RpcSetThemeRequest, RpcSetThemeResponse, RpcSetWindowPositionModeRequest, RpcSetWindowPositionModeResponse, RpcShortcut, RpcShowSettingsWindowRequest, RpcShowSettingsWindowResponse, RpcShowWindowRequest, RpcShowWindowResponse};
+use crate::model::{Density, DevConsoleEvent, DevConsoleEventKind, DownloadStatus, EntrypointId, LocalSaveData, PhysicalKey, PhysicalShortcut, PluginId, PluginPreferenceUserData, SearchResultsSort, ServerMetrics, SettingsCrashReport, SettingsEntrypointType, SettingsPlugin, SettingsPluginDiskUsage, SettingsQuicklink, SettingsTheme, SettingsUsageStats, UiRendererInfo, UiScale, WindowPositionMode};
+use crate::rpc::grpc::{RpcClearPluginCacheRequest, RpcClearPluginCacheResponse, RpcClearPluginDataRequest, RpcClearPluginDataResponse, RpcClearUsageStatsRequest, RpcClearUsageStatsResponse, RpcCrashReport, RpcCrashReportsRequest, RpcCrashReportsResponse, RpcDevConsoleEvent, RpcDevConsoleEventKind, RpcDevConsoleRequest, RpcDownloadPluginRequest, RpcDownloadPluginResponse, RpcDownloadStatus, RpcDownloadStatusRequest, RpcDownloadStatusResponse, RpcDownloadStatusValue, RpcEntrypoint, RpcEntrypointUsage, RpcEntrypointTypeSettings, RpcGetAutostartEnabledRequest, RpcGetAutostartEnabledResponse, RpcGetDensityRequest, RpcGetDensityResponse, RpcGetUiScaleRequest, RpcGetUiScaleResponse, RpcGetCrashReportsEnabledRequest, RpcGetCrashReportsEnabledResponse, RpcGetGlobalShortcutRequest, RpcGetGlobalShortcutResponse, RpcGetSearchResultsSortRequest, RpcGetSearchResultsSortResponse, RpcGetThemeRequest, RpcGetThemeResponse, RpcGetUsageStatsEnabledRequest, RpcGetUsageStatsEnabledResponse, RpcGetWindowOverlayRequest, RpcGetWindowOverlayResponse, RpcGetWindowPositionModeRequest, RpcGetWindowPositionModeResponse, RpcHandleDeepLinkRequest, RpcHandleDeepLinkResponse, RpcHideWindowRequest, RpcHideWindowResponse, RpcMetricsRequest, RpcMetricsResponse, RpcPingRequest, RpcPingResponse, RpcPlugin, RpcPluginDiskUsageRequest, RpcPluginDiskUsageResponse, RpcPluginMetrics, RpcPluginsRequest, RpcPluginsResponse, RpcProfilesRequest, RpcProfilesResponse, RpcQuicklink, RpcQuicklinksRequest, RpcQuicklinksResponse, RpcRecentLogLinesRequest, RpcRecentLogLinesResponse, RpcReindexRequest, RpcReindexResponse, RpcRemoveCrashReportRequest, RpcRemoveCrashReportResponse, RpcRemovePluginRequest, RpcRemovePluginResponse, RpcRemoveProfileRequest, RpcRemoveProfileResponse, RpcRemoveQuicklinkRequest, RpcRemoveQuicklinkResponse, RpcRendererInfoRequest, RpcRendererInfoResponse, RpcSaveLocalPluginRequest, RpcSaveLocalPluginResponse, RpcSaveProfileRequest, RpcSaveProfileResponse, RpcSaveQuicklinkRequest, RpcSaveQuicklinkResponse, RpcSetAutostartEnabledRequest, RpcSetAutostartEnabledResponse, RpcSetCrashReportsEnabledRequest, RpcSetCrashReportsEnabledResponse, RpcSetDensityRequest, RpcSetDensityResponse, RpcSetUiScaleRequest, RpcSetUiScaleResponse, RpcSetEntrypointHiddenRequest, RpcSetEntrypointHiddenResponse, RpcSetEntrypointOrderRequest, RpcSetEntrypointOrderResponse, RpcSetEntrypointStateRequest, RpcSetEntrypointStateResponse, RpcSetGlobalShortcutRequest, RpcSetGlobalShortcutResponse, RpcSetPluginEnvironmentRequest, RpcSetPluginEnvironmentResponse, RpcSetPluginFilesystemRootsRequest, RpcSetPluginFilesystemRootsResponse, RpcSetPluginNotificationsEnabledRequest, RpcSetPluginNotificationsEnabledResponse, RpcSetPluginPriorityRequest, RpcSetPluginPriorityResponse, RpcSetPluginStateRequest, RpcSetPluginStateResponse, RpcSetPreferenceValueRequest, RpcSetPreferenceValueResponse, RpcSetSearchResultsSortRequest, RpcSetSearchResultsSortResponse, RpcSetThemeRequest, RpcSetThemeResponse, RpcSetUsageStatsEnabledRequest, RpcSetUsageStatsEnabledResponse, RpcSetWindowOverlayRequest, RpcSetWindowOverlayResponse, RpcSetWindowPositionModeRequest, RpcSetWindowPositionModeResponse, RpcShortcut, RpcShowSettingsWindowRequest, RpcShowSettingsWindowResponse, RpcShowWindowRequest, RpcShowWindowResponse, RpcSubmitCrashReportRequest, RpcSubmitCrashReportResponse, RpcSwitchProfileRequest, RpcSwitchProfileResponse, RpcToggleWindowRequest, RpcToggleWindowResponse, RpcUnusedPlugin, RpcUsageStatsRequest, RpcUsageStatsResponse};
 use crate::rpc::grpc::rpc_backend_server::{RpcBackend, RpcBackendServer};
-use crate::rpc::grpc_convert::{plugin_preference_to_rpc, plugin_preference_user_data_from_rpc, plugin_preference_user_data_to_rpc};
+use crate::rpc::grpc_convert::{metrics_timing_to_rpc, plugin_preference_to_rpc, plugin_preference_user_data_from_rpc, plugin_preference_user_data_to_rpc};
+
+// used by settings, cli and other local processes, not reachable from other machines
+pub const BACKEND_SERVER_ADDRESS: SocketAddr = SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 42320));
 
 pub async fn wait_for_backend_server() {
     loop {
-        let addr: SocketAddr = "127.0.0.1:42320".parse().unwrap();
-
-        if TcpStream::connect(addr).await.is_ok() {
+        if TcpStream::connect(BACKEND_SERVER_ADDRESS).await.is_ok() {
             return;
         }
 
@@ -24,11 +26,9 @@ pub async fn wait_for_backend_server() {
 }
 
 pub async fn start_backend_server(server: Box<dyn BackendServer + Sync + Send>) {
-    let addr = "127.0.0.1:42320".parse().unwrap();
-
     Server::builder()
         .add_service(RpcBackendServer::new(RpcBackendServerImpl::new(server)))
-        .serve(addr)
+        .serve(BACKEND_SERVER_ADDRESS)
         .await
         .expect("unable to start backend server");
 }
@@ -49,8 +49,14 @@ impl RpcBackendServerImpl {
 pub trait BackendServer {
     async fn show_window(&self) -> anyhow::Result<()>;
 
+    async fn hide_window(&self) -> anyhow::Result<()>;
+
+    async fn toggle_window(&self) -> anyhow::Result<()>;
+
     async fn show_settings_window(&self) -> anyhow::Result<()>;
 
+    async fn handle_deep_link(&self, uri: String) -> anyhow::Result<()>;
+
     async fn plugins(&self) -> anyhow::Result<Vec<SettingsPlugin>>;
 
     async fn set_plugin_state(
@@ -59,6 +65,36 @@ pub trait BackendServer {
         enabled: bool
     ) -> anyhow::Result<()>;
 
+    async fn set_plugin_priority(
+        &self,
+        plugin_id: PluginId,
+        priority: f64
+    ) -> anyhow::Result<()>;
+
+    async fn set_plugin_notifications_enabled(
+        &self,
+        plugin_id: PluginId,
+        enabled: bool
+    ) -> anyhow::Result<()>;
+
+    async fn set_plugin_environment(
+        &self,
+        plugin_id: PluginId,
+        environment: HashMap<String, String>
+    ) -> anyhow::Result<()>;
+
+    async fn set_plugin_filesystem_roots(
+        &self,
+        plugin_id: PluginId,
+        filesystem_roots: Vec<String>
+    ) -> anyhow::Result<()>;
+
+    async fn plugin_disk_usage(&self, plugin_id: PluginId) -> anyhow::Result<SettingsPluginDiskUsage>;
+
+    async fn clear_plugin_cache(&self, plugin_id: PluginId) -> anyhow::Result<()>;
+
+    async fn clear_plugin_data(&self, plugin_id: PluginId) -> anyhow::Result<()>;
+
     async fn set_entrypoint_state(
         &self,
         plugin_id: PluginId,
@@ -66,6 +102,19 @@ pub trait BackendServer {
         enabled: bool
     ) -> anyhow::Result<()>;
 
+    async fn set_entrypoint_hidden(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        hidden: bool
+    ) -> anyhow::Result<()>;
+
+    async fn set_entrypoint_order(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_ids: Vec<EntrypointId>
+    ) -> anyhow::Result<()>;
+
     async fn set_global_shortcut(
         &self,
         shortcut: Option<PhysicalShortcut>
@@ -93,6 +142,46 @@ pub trait BackendServer {
         &self,
     ) -> anyhow::Result<WindowPositionMode>;
 
+    async fn set_window_overlay(
+        &self,
+        enabled: bool
+    ) -> anyhow::Result<()>;
+
+    async fn get_window_overlay(
+        &self,
+    ) -> anyhow::Result<bool>;
+
+    async fn set_density(
+        &self,
+        density: Density
+    ) -> anyhow::Result<()>;
+
+    async fn get_density(
+        &self,
+    ) -> anyhow::Result<Density>;
+
+    async fn set_ui_scale(
+        &self,
+        scale: UiScale
+    ) -> anyhow::Result<()>;
+
+    async fn renderer_info(
+        &self,
+    ) -> anyhow::Result<Option<UiRendererInfo>>;
+
+    async fn get_ui_scale(
+        &self,
+    ) -> anyhow::Result<UiScale>;
+
+    async fn set_search_results_sort(
+        &self,
+        sort: SearchResultsSort
+    ) -> anyhow::Result<()>;
+
+    async fn get_search_results_sort(
+        &self,
+    ) -> anyhow::Result<SearchResultsSort>;
+
     async fn set_preference_value(
         &self,
         plugin_id: PluginId,
@@ -107,7 +196,51 @@ pub trait BackendServer {
 
     async fn remove_plugin(&self, plugin_id: PluginId) -> anyhow::Result<()>;
 
+    async fn quicklinks(&self) -> anyhow::Result<Vec<SettingsQuicklink>>;
+
+    async fn save_quicklink(&self, id: Option<String>, name: String, url: String, bang: Option<String>, fallback: bool) -> anyhow::Result<()>;
+
+    async fn remove_quicklink(&self, id: String) -> anyhow::Result<()>;
+
+    async fn profiles(&self) -> anyhow::Result<Vec<String>>;
+
+    async fn save_profile(&self, name: String) -> anyhow::Result<()>;
+
+    async fn switch_profile(&self, name: String) -> anyhow::Result<()>;
+
+    async fn remove_profile(&self, name: String) -> anyhow::Result<()>;
+
+    async fn recent_log_lines(&self, limit: u32) -> anyhow::Result<Vec<String>>;
+
+    async fn reindex(&self) -> anyhow::Result<()>;
+
+    async fn set_crash_reports_enabled(&self, enabled: bool) -> anyhow::Result<()>;
+
+    async fn get_crash_reports_enabled(&self) -> anyhow::Result<bool>;
+
+    async fn crash_reports(&self) -> anyhow::Result<Vec<SettingsCrashReport>>;
+
+    async fn remove_crash_report(&self, id: String) -> anyhow::Result<()>;
+
+    async fn submit_crash_report(&self, id: String) -> anyhow::Result<()>;
+
+    async fn set_usage_stats_enabled(&self, enabled: bool) -> anyhow::Result<()>;
+
+    async fn get_usage_stats_enabled(&self) -> anyhow::Result<bool>;
+
+    async fn usage_stats(&self) -> anyhow::Result<SettingsUsageStats>;
+
+    async fn clear_usage_stats(&self) -> anyhow::Result<()>;
+
+    async fn set_autostart_enabled(&self, enabled: bool) -> anyhow::Result<()>;
+
+    async fn get_autostart_enabled(&self) -> anyhow::Result<bool>;
+
     async fn save_local_plugin(&self, path: String) -> anyhow::Result<LocalSaveData>;
+
+    async fn dev_console(&self, plugin_id: PluginId) -> anyhow::Result<tokio::sync::mpsc::Receiver<DevConsoleEvent>>;
+
+    async fn metrics(&self) -> anyhow::Result<ServerMetrics>;
 }
 
 
@@ -125,6 +258,32 @@ impl RpcBackend for RpcBackendServerImpl {
         Ok(Response::new(RpcShowWindowResponse::default()))
     }
 
+    async fn hide_window(&self, _request: Request<RpcHideWindowRequest>) -> Result<Response<RpcHideWindowResponse>, Status> {
+        self.server.hide_window()
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcHideWindowResponse::default()))
+    }
+
+    async fn toggle_window(&self, _request: Request<RpcToggleWindowRequest>) -> Result<Response<RpcToggleWindowResponse>, Status> {
+        self.server.toggle_window()
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcToggleWindowResponse::default()))
+    }
+
+    async fn handle_deep_link(&self, request: Request<RpcHandleDeepLinkRequest>) -> Result<Response<RpcHandleDeepLinkResponse>, Status> {
+        let request = request.into_inner();
+
+        self.server.handle_deep_link(request.uri)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcHandleDeepLinkResponse::default()))
+    }
+
     async fn show_settings_window(&self, _request: Request<RpcShowSettingsWindowRequest>) -> Result<Response<RpcShowSettingsWindowResponse>, Status> {
         self.server.show_settings_window()
             .await
@@ -143,6 +302,8 @@ impl RpcBackend for RpcBackendServerImpl {
                     .into_iter()
                     .map(|(_, entrypoint)| RpcEntrypoint {
                         enabled: entrypoint.enabled,
+                        hidden: entrypoint.hidden,
+                        position: entrypoint.position,
                         entrypoint_id: entrypoint.entrypoint_id.to_string(),
                         entrypoint_name: entrypoint.entrypoint_name,
                         entrypoint_description: entrypoint.entrypoint_description,
@@ -151,6 +312,7 @@ impl RpcBackend for RpcBackendServerImpl {
                             SettingsEntrypointType::View => RpcEntrypointTypeSettings::SView,
                             SettingsEntrypointType::InlineView => RpcEntrypointTypeSettings::SInlineView,
                             SettingsEntrypointType::EntrypointGenerator => RpcEntrypointTypeSettings::SEntrypointGenerator,
+                            SettingsEntrypointType::SearchProvider => RpcEntrypointTypeSettings::SSearchProvider,
                         }.into(),
                         preferences: entrypoint.preferences.into_iter()
                             .map(|(key, value)| (key, plugin_preference_to_rpc(value)))
@@ -166,6 +328,10 @@ impl RpcBackend for RpcBackendServerImpl {
                     plugin_name: plugin.plugin_name,
                     plugin_description: plugin.plugin_description,
                     enabled: plugin.enabled,
+                    priority: plugin.priority,
+                    notifications_enabled: plugin.notifications_enabled,
+                    environment: plugin.environment,
+                    filesystem_roots: plugin.filesystem_roots,
                     entrypoints,
                     preferences: plugin.preferences.into_iter()
                         .map(|(key, value)| (key, plugin_preference_to_rpc(value)))
@@ -194,6 +360,99 @@ impl RpcBackend for RpcBackendServerImpl {
         Ok(Response::new(RpcSetPluginStateResponse::default()))
     }
 
+    async fn set_plugin_priority(&self, request: Request<RpcSetPluginPriorityRequest>) -> Result<Response<RpcSetPluginPriorityResponse>, Status> {
+        let request = request.into_inner();
+        let plugin_id = request.plugin_id;
+        let priority = request.priority;
+
+        let plugin_id = PluginId::from_string(plugin_id);
+
+        self.server.set_plugin_priority(plugin_id, priority)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcSetPluginPriorityResponse::default()))
+    }
+
+    async fn set_plugin_notifications_enabled(&self, request: Request<RpcSetPluginNotificationsEnabledRequest>) -> Result<Response<RpcSetPluginNotificationsEnabledResponse>, Status> {
+        let request = request.into_inner();
+        let plugin_id = request.plugin_id;
+        let enabled = request.enabled;
+
+        let plugin_id = PluginId::from_string(plugin_id);
+
+        self.server.set_plugin_notifications_enabled(plugin_id, enabled)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcSetPluginNotificationsEnabledResponse::default()))
+    }
+
+    async fn set_plugin_environment(&self, request: Request<RpcSetPluginEnvironmentRequest>) -> Result<Response<RpcSetPluginEnvironmentResponse>, Status> {
+        let request = request.into_inner();
+        let plugin_id = request.plugin_id;
+        let environment = request.environment;
+
+        let plugin_id = PluginId::from_string(plugin_id);
+
+        self.server.set_plugin_environment(plugin_id, environment)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcSetPluginEnvironmentResponse::default()))
+    }
+
+    async fn set_plugin_filesystem_roots(&self, request: Request<RpcSetPluginFilesystemRootsRequest>) -> Result<Response<RpcSetPluginFilesystemRootsResponse>, Status> {
+        let request = request.into_inner();
+        let plugin_id = request.plugin_id;
+        let filesystem_roots = request.filesystem_roots;
+
+        let plugin_id = PluginId::from_string(plugin_id);
+
+        self.server.set_plugin_filesystem_roots(plugin_id, filesystem_roots)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcSetPluginFilesystemRootsResponse::default()))
+    }
+
+    async fn plugin_disk_usage(&self, request: Request<RpcPluginDiskUsageRequest>) -> Result<Response<RpcPluginDiskUsageResponse>, Status> {
+        let request = request.into_inner();
+        let plugin_id = PluginId::from_string(request.plugin_id);
+
+        let usage = self.server.plugin_disk_usage(plugin_id)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcPluginDiskUsageResponse {
+            data: usage.data,
+            cache: usage.cache,
+            logs: usage.logs,
+        }))
+    }
+
+    async fn clear_plugin_cache(&self, request: Request<RpcClearPluginCacheRequest>) -> Result<Response<RpcClearPluginCacheResponse>, Status> {
+        let request = request.into_inner();
+        let plugin_id = PluginId::from_string(request.plugin_id);
+
+        self.server.clear_plugin_cache(plugin_id)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcClearPluginCacheResponse::default()))
+    }
+
+    async fn clear_plugin_data(&self, request: Request<RpcClearPluginDataRequest>) -> Result<Response<RpcClearPluginDataResponse>, Status> {
+        let request = request.into_inner();
+        let plugin_id = PluginId::from_string(request.plugin_id);
+
+        self.server.clear_plugin_data(plugin_id)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcClearPluginDataResponse::default()))
+    }
+
     async fn set_entrypoint_state(&self, request: Request<RpcSetEntrypointStateRequest>) -> Result<Response<RpcSetEntrypointStateResponse>, Status> {
         let request = request.into_inner();
         let plugin_id = request.plugin_id;
@@ -210,6 +469,38 @@ impl RpcBackend for RpcBackendServerImpl {
         Ok(Response::new(RpcSetEntrypointStateResponse::default()))
     }
 
+    async fn set_entrypoint_hidden(&self, request: Request<RpcSetEntrypointHiddenRequest>) -> Result<Response<RpcSetEntrypointHiddenResponse>, Status> {
+        let request = request.into_inner();
+        let plugin_id = request.plugin_id;
+        let entrypoint_id = request.entrypoint_id;
+        let hidden = request.hidden;
+
+        let plugin_id = PluginId::from_string(plugin_id);
+        let entrypoint_id = EntrypointId::from_string(entrypoint_id);
+
+        self.server.set_entrypoint_hidden(plugin_id, entrypoint_id, hidden)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcSetEntrypointHiddenResponse::default()))
+    }
+
+    async fn set_entrypoint_order(&self, request: Request<RpcSetEntrypointOrderRequest>) -> Result<Response<RpcSetEntrypointOrderResponse>, Status> {
+        let request = request.into_inner();
+
+        let plugin_id = PluginId::from_string(request.plugin_id);
+        let entrypoint_ids = request.entrypoint_ids
+            .into_iter()
+            .map(|entrypoint_id| EntrypointId::from_string(entrypoint_id))
+            .collect();
+
+        self.server.set_entrypoint_order(plugin_id, entrypoint_ids)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcSetEntrypointOrderResponse::default()))
+    }
+
     async fn set_preference_value(&self, request: Request<RpcSetPreferenceValueRequest>) -> Result<Response<RpcSetPreferenceValueResponse>, Status> {
         let request = request.into_inner();
         let plugin_id = request.plugin_id;
@@ -285,6 +576,7 @@ impl RpcBackend for RpcBackendServerImpl {
             "MacOSLight" => SettingsTheme::MacOSLight,
             "MacOSDark" => SettingsTheme::MacOSDark,
             "Legacy" => SettingsTheme::Legacy,
+            "HighContrast" => SettingsTheme::HighContrast,
             _ => unreachable!()
         };
 
@@ -307,6 +599,7 @@ impl RpcBackend for RpcBackendServerImpl {
             SettingsTheme::MacOSLight => "MacOSLight",
             SettingsTheme::MacOSDark => "MacOSDark",
             SettingsTheme::Legacy => "Legacy",
+            SettingsTheme::HighContrast => "HighContrast",
         };
 
         Ok(Response::new(RpcGetThemeResponse {
@@ -344,6 +637,134 @@ impl RpcBackend for RpcBackendServerImpl {
         }))
     }
 
+    async fn set_window_overlay(&self, request: Request<RpcSetWindowOverlayRequest>) -> Result<Response<RpcSetWindowOverlayResponse>, Status> {
+        let enabled = request.into_inner().enabled;
+
+        self.server.set_window_overlay(enabled)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcSetWindowOverlayResponse::default()))
+    }
+
+    async fn get_window_overlay(&self, _request: Request<RpcGetWindowOverlayRequest>) -> Result<Response<RpcGetWindowOverlayResponse>, Status> {
+        let enabled = self.server.get_window_overlay()
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcGetWindowOverlayResponse { enabled }))
+    }
+
+    async fn set_density(&self, request: Request<RpcSetDensityRequest>) -> Result<Response<RpcSetDensityResponse>, Status> {
+        let density = request.into_inner().density;
+
+        let density = match density.as_str() {
+            "Compact" => Density::Compact,
+            "Comfortable" => Density::Comfortable,
+            "Large" => Density::Large,
+            _ => unreachable!()
+        };
+
+        self.server.set_density(density)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcSetDensityResponse::default()))
+    }
+
+    async fn get_density(&self, _request: Request<RpcGetDensityRequest>) -> Result<Response<RpcGetDensityResponse>, Status> {
+        let density = self.server.get_density()
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        let density = match density {
+            Density::Compact => "Compact",
+            Density::Comfortable => "Comfortable",
+            Density::Large => "Large",
+        };
+
+        Ok(Response::new(RpcGetDensityResponse {
+            density: density.to_string(),
+        }))
+    }
+
+    async fn renderer_info(&self, _request: Request<RpcRendererInfoRequest>) -> Result<Response<RpcRendererInfoResponse>, Status> {
+        let renderer = self.server.renderer_info()
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        let response = match renderer {
+            None => RpcRendererInfoResponse::default(),
+            Some(renderer) => RpcRendererInfoResponse {
+                backend: Some(renderer.backend),
+                adapter: Some(renderer.adapter),
+            }
+        };
+
+        Ok(Response::new(response))
+    }
+
+    async fn set_ui_scale(&self, request: Request<RpcSetUiScaleRequest>) -> Result<Response<RpcSetUiScaleResponse>, Status> {
+        let scale = match request.into_inner().percent {
+            None => UiScale::Auto,
+            Some(percent) => UiScale::Manual { percent: percent as u16 },
+        };
+
+        self.server.set_ui_scale(scale)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcSetUiScaleResponse::default()))
+    }
+
+    async fn get_ui_scale(&self, _request: Request<RpcGetUiScaleRequest>) -> Result<Response<RpcGetUiScaleResponse>, Status> {
+        let scale = self.server.get_ui_scale()
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        let percent = match scale {
+            UiScale::Auto => None,
+            UiScale::Manual { percent } => Some(percent as u32),
+        };
+
+        Ok(Response::new(RpcGetUiScaleResponse {
+            percent,
+        }))
+    }
+
+    async fn set_search_results_sort(&self, request: Request<RpcSetSearchResultsSortRequest>) -> Result<Response<RpcSetSearchResultsSortResponse>, Status> {
+        let sort = request.into_inner().sort;
+
+        let sort = match sort.as_str() {
+            "Frecency" => SearchResultsSort::Frecency,
+            "Relevance" => SearchResultsSort::Relevance,
+            "Plugin" => SearchResultsSort::Plugin,
+            _ => unreachable!()
+        };
+
+        self.server.set_search_results_sort(sort)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcSetSearchResultsSortResponse::default()))
+    }
+
+    async fn get_search_results_sort(&self, _request: Request<RpcGetSearchResultsSortRequest>) -> Result<Response<RpcGetSearchResultsSortResponse>, Status> {
+        let sort = self.server.get_search_results_sort()
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        let sort = match sort {
+            SearchResultsSort::Frecency => "Frecency",
+            SearchResultsSort::Relevance => "Relevance",
+            SearchResultsSort::Plugin => "Plugin",
+        };
+
+        Ok(Response::new(RpcGetSearchResultsSortResponse {
+            sort: sort.to_string(),
+        }))
+    }
+
     async fn download_plugin(&self, request: Request<RpcDownloadPluginRequest>) -> Result<Response<RpcDownloadPluginResponse>, Status> {
         let request = request.into_inner();
         let plugin_id = request.plugin_id;
@@ -393,6 +814,225 @@ impl RpcBackend for RpcBackendServerImpl {
         Ok(Response::new(RpcRemovePluginResponse::default()))
     }
 
+    async fn quicklinks(&self, _: Request<RpcQuicklinksRequest>) -> Result<Response<RpcQuicklinksResponse>, Status> {
+        let quicklinks = self.server.quicklinks()
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?
+            .into_iter()
+            .map(|quicklink| RpcQuicklink {
+                id: quicklink.id,
+                name: quicklink.name,
+                url: quicklink.url,
+                bang: quicklink.bang,
+                fallback: quicklink.fallback,
+            })
+            .collect();
+
+        Ok(Response::new(RpcQuicklinksResponse { quicklinks }))
+    }
+
+    async fn save_quicklink(&self, request: Request<RpcSaveQuicklinkRequest>) -> Result<Response<RpcSaveQuicklinkResponse>, Status> {
+        let request = request.into_inner();
+
+        self.server.save_quicklink(request.id, request.name, request.url, request.bang, request.fallback)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcSaveQuicklinkResponse::default()))
+    }
+
+    async fn remove_quicklink(&self, request: Request<RpcRemoveQuicklinkRequest>) -> Result<Response<RpcRemoveQuicklinkResponse>, Status> {
+        let request = request.into_inner();
+
+        self.server.remove_quicklink(request.id)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcRemoveQuicklinkResponse::default()))
+    }
+
+    async fn profiles(&self, _: Request<RpcProfilesRequest>) -> Result<Response<RpcProfilesResponse>, Status> {
+        let names = self.server.profiles()
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcProfilesResponse { names }))
+    }
+
+    async fn save_profile(&self, request: Request<RpcSaveProfileRequest>) -> Result<Response<RpcSaveProfileResponse>, Status> {
+        let request = request.into_inner();
+
+        self.server.save_profile(request.name)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcSaveProfileResponse::default()))
+    }
+
+    async fn switch_profile(&self, request: Request<RpcSwitchProfileRequest>) -> Result<Response<RpcSwitchProfileResponse>, Status> {
+        let request = request.into_inner();
+
+        self.server.switch_profile(request.name)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcSwitchProfileResponse::default()))
+    }
+
+    async fn remove_profile(&self, request: Request<RpcRemoveProfileRequest>) -> Result<Response<RpcRemoveProfileResponse>, Status> {
+        let request = request.into_inner();
+
+        self.server.remove_profile(request.name)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcRemoveProfileResponse::default()))
+    }
+
+    async fn recent_log_lines(&self, request: Request<RpcRecentLogLinesRequest>) -> Result<Response<RpcRecentLogLinesResponse>, Status> {
+        let request = request.into_inner();
+
+        let lines = self.server.recent_log_lines(request.limit)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcRecentLogLinesResponse { lines }))
+    }
+
+    async fn reindex(&self, _: Request<RpcReindexRequest>) -> Result<Response<RpcReindexResponse>, Status> {
+        self.server.reindex()
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcReindexResponse::default()))
+    }
+
+    async fn set_crash_reports_enabled(&self, request: Request<RpcSetCrashReportsEnabledRequest>) -> Result<Response<RpcSetCrashReportsEnabledResponse>, Status> {
+        let request = request.into_inner();
+
+        self.server.set_crash_reports_enabled(request.enabled)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcSetCrashReportsEnabledResponse::default()))
+    }
+
+    async fn get_crash_reports_enabled(&self, _: Request<RpcGetCrashReportsEnabledRequest>) -> Result<Response<RpcGetCrashReportsEnabledResponse>, Status> {
+        let enabled = self.server.get_crash_reports_enabled()
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcGetCrashReportsEnabledResponse { enabled }))
+    }
+
+    async fn crash_reports(&self, _: Request<RpcCrashReportsRequest>) -> Result<Response<RpcCrashReportsResponse>, Status> {
+        let crash_reports = self.server.crash_reports()
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?
+            .into_iter()
+            .map(|crash_report| RpcCrashReport {
+                id: crash_report.id,
+                content: crash_report.content,
+            })
+            .collect();
+
+        Ok(Response::new(RpcCrashReportsResponse { crash_reports }))
+    }
+
+    async fn remove_crash_report(&self, request: Request<RpcRemoveCrashReportRequest>) -> Result<Response<RpcRemoveCrashReportResponse>, Status> {
+        let request = request.into_inner();
+
+        self.server.remove_crash_report(request.id)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcRemoveCrashReportResponse::default()))
+    }
+
+    async fn submit_crash_report(&self, request: Request<RpcSubmitCrashReportRequest>) -> Result<Response<RpcSubmitCrashReportResponse>, Status> {
+        let request = request.into_inner();
+
+        self.server.submit_crash_report(request.id)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcSubmitCrashReportResponse::default()))
+    }
+
+    async fn set_usage_stats_enabled(&self, request: Request<RpcSetUsageStatsEnabledRequest>) -> Result<Response<RpcSetUsageStatsEnabledResponse>, Status> {
+        let request = request.into_inner();
+
+        self.server.set_usage_stats_enabled(request.enabled)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcSetUsageStatsEnabledResponse::default()))
+    }
+
+    async fn get_usage_stats_enabled(&self, _: Request<RpcGetUsageStatsEnabledRequest>) -> Result<Response<RpcGetUsageStatsEnabledResponse>, Status> {
+        let enabled = self.server.get_usage_stats_enabled()
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcGetUsageStatsEnabledResponse { enabled }))
+    }
+
+    async fn usage_stats(&self, _: Request<RpcUsageStatsRequest>) -> Result<Response<RpcUsageStatsResponse>, Status> {
+        let SettingsUsageStats { entrypoints, unused_plugins, average_search_to_launch } = self.server.usage_stats()
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        let entrypoints = entrypoints.into_iter()
+            .map(|entrypoint| RpcEntrypointUsage {
+                plugin_id: entrypoint.plugin_id.to_string(),
+                plugin_name: entrypoint.plugin_name,
+                entrypoint_id: entrypoint.entrypoint_id.to_string(),
+                entrypoint_name: entrypoint.entrypoint_name,
+                launch_count: entrypoint.launch_count,
+                average_search_to_launch_micros: entrypoint.average_search_to_launch.map(|duration| duration.as_micros() as u64),
+            })
+            .collect();
+
+        let unused_plugins = unused_plugins.into_iter()
+            .map(|plugin| RpcUnusedPlugin {
+                plugin_id: plugin.plugin_id.to_string(),
+                plugin_name: plugin.plugin_name,
+            })
+            .collect();
+
+        Ok(Response::new(RpcUsageStatsResponse {
+            entrypoints,
+            unused_plugins,
+            average_search_to_launch_micros: average_search_to_launch.map(|duration| duration.as_micros() as u64),
+        }))
+    }
+
+    async fn clear_usage_stats(&self, _: Request<RpcClearUsageStatsRequest>) -> Result<Response<RpcClearUsageStatsResponse>, Status> {
+        self.server.clear_usage_stats()
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcClearUsageStatsResponse::default()))
+    }
+
+    async fn set_autostart_enabled(&self, request: Request<RpcSetAutostartEnabledRequest>) -> Result<Response<RpcSetAutostartEnabledResponse>, Status> {
+        let request = request.into_inner();
+
+        self.server.set_autostart_enabled(request.enabled)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcSetAutostartEnabledResponse::default()))
+    }
+
+    async fn get_autostart_enabled(&self, _: Request<RpcGetAutostartEnabledRequest>) -> Result<Response<RpcGetAutostartEnabledResponse>, Status> {
+        let enabled = self.server.get_autostart_enabled()
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcGetAutostartEnabledResponse { enabled }))
+    }
+
     async fn save_local_plugin(&self, request: Request<RpcSaveLocalPluginRequest>) -> Result<Response<RpcSaveLocalPluginResponse>, Status> {
         let request = request.into_inner();
         let path = request.path;
@@ -406,4 +1046,62 @@ impl RpcBackend for RpcBackendServerImpl {
             stderr_file_path: local_save_data.stderr_file_path,
         }))
     }
+
+    type DevConsoleStream = ReceiverStream<Result<RpcDevConsoleEvent, Status>>;
+
+    async fn dev_console(&self, request: Request<RpcDevConsoleRequest>) -> Result<Response<Self::DevConsoleStream>, Status> {
+        let request = request.into_inner();
+        let plugin_id = PluginId::from_string(request.plugin_id);
+
+        let mut events = self.server.dev_console(plugin_id)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        let (sender, receiver) = tokio::sync::mpsc::channel(100);
+
+        tokio::spawn(async move {
+            while let Some(event) = events.recv().await {
+                let kind = match event.kind {
+                    DevConsoleEventKind::Stdout => RpcDevConsoleEventKind::DevConsoleStdout,
+                    DevConsoleEventKind::Stderr => RpcDevConsoleEventKind::DevConsoleStderr,
+                    DevConsoleEventKind::Render => RpcDevConsoleEventKind::DevConsoleRender,
+                    DevConsoleEventKind::ViewEvent => RpcDevConsoleEventKind::DevConsoleViewEvent,
+                };
+
+                let event = RpcDevConsoleEvent {
+                    plugin_id: event.plugin_id.to_string(),
+                    kind: kind.into(),
+                    message: event.message,
+                };
+
+                // client disconnected
+                if sender.send(Ok(event)).await.is_err() {
+                    break
+                }
+            }
+        });
+
+        Ok(Response::new(ReceiverStream::new(receiver)))
+    }
+
+    async fn metrics(&self, _: Request<RpcMetricsRequest>) -> Result<Response<RpcMetricsResponse>, Status> {
+        let metrics = self.server.metrics()
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        let plugins = metrics.plugins
+            .into_iter()
+            .map(|plugin| RpcPluginMetrics {
+                plugin_id: plugin.plugin_id.to_string(),
+                render: Some(metrics_timing_to_rpc(plugin.render)),
+                memory_bytes: plugin.memory_bytes,
+            })
+            .collect();
+
+        Ok(Response::new(RpcMetricsResponse {
+            search: Some(metrics_timing_to_rpc(metrics.search)),
+            requests: metrics.requests,
+            plugins,
+        }))
+    }
 }
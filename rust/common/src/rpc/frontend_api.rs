@@ -3,7 +3,7 @@ use anyhow::anyhow;
 use thiserror::Error;
 use gauntlet_utils::channel::{RequestError, RequestSender};
 
-use crate::model::{EntrypointId, UiTheme, PhysicalShortcut, PluginId, RootWidget, UiRenderLocation, UiRequestData, UiResponseData, UiWidgetId, WindowPositionMode};
+use crate::model::{Density, EntrypointId, ImageHash, OnboardingPreference, UiTheme, PhysicalShortcut, PluginId, RootWidget, SearchResultsSort, UiRenderLocation, UiRequestData, UiResponseData, UiScale, UiWidgetId, WindowPositionMode};
 
 #[derive(Error, Debug)]
 pub enum FrontendApiError {
@@ -48,8 +48,10 @@ impl FrontendApi {
         entrypoint_name: String,
         render_location: UiRenderLocation,
         top_level_view: bool,
+        breadcrumbs: Vec<String>,
+        key_handlers: Vec<PhysicalShortcut>,
         container: RootWidget,
-        images: HashMap<UiWidgetId, Vec<u8>>,
+        images: HashMap<UiWidgetId, ImageHash>,
     ) -> Result<(), FrontendApiError> {
         let request = UiRequestData::ReplaceView {
             plugin_id,
@@ -58,6 +60,8 @@ impl FrontendApi {
             entrypoint_name,
             render_location,
             top_level_view,
+            breadcrumbs,
+            key_handlers,
             container,
             images,
         };
@@ -89,6 +93,26 @@ impl FrontendApi {
         Ok(())
     }
 
+    pub async fn show_settings_window(
+        &self,
+        plugin_id: Option<PluginId>,
+        entrypoint_id: Option<EntrypointId>,
+    ) -> anyhow::Result<()> {
+        let request = UiRequestData::ShowSettingsWindow {
+            plugin_id,
+            entrypoint_id,
+        };
+
+        let data = self.frontend_sender.send_receive(request)
+            .await
+            .map_err(|err| anyhow!("error: {:?}", err))?;
+
+        match data {
+            UiResponseData::Nothing => Ok(()),
+            UiResponseData::Err(err) => Err(err)
+        }
+    }
+
     pub async fn hide_window(&self) -> Result<(), FrontendApiError> {
         let UiResponseData::Nothing = self.frontend_sender.send_receive(UiRequestData::HideWindow).await? else {
             unreachable!()
@@ -97,18 +121,75 @@ impl FrontendApi {
         Ok(())
     }
 
-    pub async fn show_preference_required_view(
+    pub async fn toggle_window(&self) -> Result<(), FrontendApiError> {
+        let UiResponseData::Nothing = self.frontend_sender.send_receive(UiRequestData::ToggleWindow).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    pub async fn open_plugin_view(
         &self,
         plugin_id: PluginId,
+        plugin_name: String,
         entrypoint_id: EntrypointId,
-        plugin_preferences_required: bool,
-        entrypoint_preferences_required: bool,
+        entrypoint_name: String,
     ) -> Result<(), FrontendApiError> {
-        let request = UiRequestData::ShowPreferenceRequiredView {
+        let request = UiRequestData::OpenPluginView {
             plugin_id,
+            plugin_name,
             entrypoint_id,
-            plugin_preferences_required,
-            entrypoint_preferences_required,
+            entrypoint_name,
+        };
+
+        let UiResponseData::Nothing = self.frontend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    pub async fn show_window_with_search_query(&self, query: String) -> Result<(), FrontendApiError> {
+        let request = UiRequestData::ShowWindowWithSearchQuery {
+            query
+        };
+
+        let UiResponseData::Nothing = self.frontend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    pub async fn confirm_deep_link(&self, uri: String, plugin_name: String, entrypoint_name: String) -> Result<(), FrontendApiError> {
+        let request = UiRequestData::ConfirmDeepLink {
+            uri,
+            plugin_name,
+            entrypoint_name,
+        };
+
+        let UiResponseData::Nothing = self.frontend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    pub async fn show_onboarding_view(
+        &self,
+        plugin_id: PluginId,
+        plugin_name: String,
+        entrypoint_id: EntrypointId,
+        description: Option<String>,
+        preferences: Vec<OnboardingPreference>,
+    ) -> Result<(), FrontendApiError> {
+        let request = UiRequestData::ShowOnboardingView {
+            plugin_id,
+            plugin_name,
+            entrypoint_id,
+            description,
+            preferences,
         };
 
         let UiResponseData::Nothing = self.frontend_sender.send_receive(request).await? else {
@@ -224,4 +305,94 @@ impl FrontendApi {
             UiResponseData::Err(err) => Err(err)
         }
     }
-}
\ No newline at end of file
+
+    pub async fn set_window_overlay(
+        &self,
+        enabled: bool
+    ) -> anyhow::Result<()> {
+        let request = UiRequestData::SetWindowOverlay {
+            enabled,
+        };
+
+        let data = self.frontend_sender.send_receive(request)
+            .await
+            .map_err(|err| anyhow!("error: {:?}", err))?;
+
+        match data {
+            UiResponseData::Nothing => Ok(()),
+            UiResponseData::Err(err) => Err(err)
+        }
+    }
+
+    pub async fn set_density(
+        &self,
+        density: Density
+    ) -> anyhow::Result<()> {
+        let request = UiRequestData::SetDensity {
+            density,
+        };
+
+        let data = self.frontend_sender.send_receive(request)
+            .await
+            .map_err(|err| anyhow!("error: {:?}", err))?;
+
+        match data {
+            UiResponseData::Nothing => Ok(()),
+            UiResponseData::Err(err) => Err(err)
+        }
+    }
+
+    pub async fn set_ui_scale(
+        &self,
+        scale: UiScale
+    ) -> anyhow::Result<()> {
+        let request = UiRequestData::SetUiScale {
+            scale,
+        };
+
+        let data = self.frontend_sender.send_receive(request)
+            .await
+            .map_err(|err| anyhow!("error: {:?}", err))?;
+
+        match data {
+            UiResponseData::Nothing => Ok(()),
+            UiResponseData::Err(err) => Err(err)
+        }
+    }
+
+    pub async fn set_search_results_sort(
+        &self,
+        sort: SearchResultsSort
+    ) -> anyhow::Result<()> {
+        let request = UiRequestData::SetSearchResultsSort {
+            sort,
+        };
+
+        let data = self.frontend_sender.send_receive(request)
+            .await
+            .map_err(|err| anyhow!("error: {:?}", err))?;
+
+        match data {
+            UiResponseData::Nothing => Ok(()),
+            UiResponseData::Err(err) => Err(err)
+        }
+    }
+
+    pub async fn set_tray_tooltip(
+        &self,
+        text: Option<String>
+    ) -> anyhow::Result<()> {
+        let request = UiRequestData::SetTrayTooltip {
+            text,
+        };
+
+        let data = self.frontend_sender.send_receive(request)
+            .await
+            .map_err(|err| anyhow!("error: {:?}", err))?;
+
+        match data {
+            UiResponseData::Nothing => Ok(()),
+            UiResponseData::Err(err) => Err(err)
+        }
+    }
+}
@@ -0,0 +1,353 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, Mutex};
+
+use anyhow::anyhow;
+
+use gauntlet_utils::channel::{channel, RequestReceiver, RequestSender, ResponseReceiver, Responder};
+
+use crate::model::{BackendRequestData, BackendResponseData, PluginId, UiRenderLocation, UiRequestData, UiResponseData};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FrontendSessionId(u64);
+
+impl Display for FrontendSessionId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// allows more than one frontend to be attached to the same server, local frontend is attached the same way as remote ones.
+// server keeps talking to single frontend channel, router forwards view updates to every session which shows the view,
+// requests which change global state are sent to every frontend and requests to show or hide window go
+// to the frontend which was used last. plugin has one view per runtime, so all sessions which opened it show the same view.
+// inside of a session, window which shows the view, main or detached, is picked by client context of that session
+#[derive(Clone)]
+pub struct FrontendRouter {
+    inner: Arc<Mutex<FrontendRouterInner>>,
+    backend_sender: RequestSender<BackendRequestData, BackendResponseData>,
+}
+
+#[derive(Default)]
+struct FrontendRouterInner {
+    next_session_id: u64,
+    sessions: HashMap<FrontendSessionId, RequestSender<UiRequestData, UiResponseData>>,
+    view_owners: HashMap<PluginId, HashSet<FrontendSessionId>>,
+    inline_view_owner: Option<FrontendSessionId>,
+    last_active: Option<FrontendSessionId>,
+}
+
+enum Route {
+    Sessions(Vec<FrontendSessionId>),
+    Broadcast,
+}
+
+impl FrontendRouter {
+    pub fn new(
+        mut frontend_receiver: RequestReceiver<UiRequestData, UiResponseData>,
+        backend_sender: RequestSender<BackendRequestData, BackendResponseData>,
+    ) -> Self {
+        let router = Self {
+            inner: Arc::new(Mutex::new(FrontendRouterInner::default())),
+            backend_sender,
+        };
+
+        tokio::spawn({
+            let router = router.clone();
+
+            async move {
+                while let Some((data, responder)) = frontend_receiver.recv_checked().await {
+                    router.route_ui_request(data, responder);
+                }
+            }
+        });
+
+        router
+    }
+
+    // returns channels which are passed to frontend in place of the ones connected directly to server
+    pub fn attach(&self) -> (FrontendSessionId, RequestReceiver<UiRequestData, UiResponseData>, RequestSender<BackendRequestData, BackendResponseData>) {
+        let (frontend_sender, frontend_receiver) = channel::<UiRequestData, UiResponseData>();
+        let (backend_sender, mut backend_receiver) = channel::<BackendRequestData, BackendResponseData>();
+
+        let session_id = {
+            let mut inner = self.inner.lock().unwrap();
+
+            inner.next_session_id += 1;
+
+            let session_id = FrontendSessionId(inner.next_session_id);
+
+            inner.sessions.insert(session_id, frontend_sender);
+            inner.last_active = Some(session_id);
+
+            session_id
+        };
+
+        tracing::info!("Frontend session {} attached", session_id);
+
+        tokio::spawn({
+            let router = self.clone();
+
+            async move {
+                while let Some((data, responder)) = backend_receiver.recv_checked().await {
+                    router.route_backend_request(session_id, data, responder);
+                }
+            }
+        });
+
+        (session_id, frontend_receiver, backend_sender)
+    }
+
+    pub fn detach(&self, session_id: FrontendSessionId) {
+        let mut inner = self.inner.lock().unwrap();
+
+        inner.sessions.remove(&session_id);
+        inner.view_owners.retain(|_, owners| {
+            owners.remove(&session_id);
+
+            !owners.is_empty()
+        });
+
+        if inner.inline_view_owner == Some(session_id) {
+            inner.inline_view_owner = None;
+        }
+
+        if inner.last_active == Some(session_id) {
+            inner.last_active = inner.sessions.keys().max_by_key(|session_id| session_id.0).copied();
+        }
+
+        tracing::info!("Frontend session {} detached", session_id);
+    }
+
+    fn route_backend_request(&self, session_id: FrontendSessionId, data: BackendRequestData, responder: Responder<BackendResponseData>) {
+        {
+            let mut inner = self.inner.lock().unwrap();
+
+            // view is closed in plugin only when the last session which shows it closes it
+            if let BackendRequestData::RequestViewClose { plugin_id } = &data {
+                if let Some(owners) = inner.view_owners.get_mut(plugin_id) {
+                    owners.remove(&session_id);
+
+                    if !owners.is_empty() {
+                        responder.respond(BackendResponseData::Nothing);
+                        return;
+                    }
+
+                    inner.view_owners.remove(plugin_id);
+                }
+            }
+
+            inner.last_active = Some(session_id);
+
+            match &data {
+                BackendRequestData::Search { render_inline_view: true, .. } => {
+                    inner.inline_view_owner = Some(session_id);
+                }
+                BackendRequestData::RequestViewRender { plugin_id, .. }
+                | BackendRequestData::RequestViewPop { plugin_id, .. }
+                | BackendRequestData::RequestRunCommand { plugin_id, .. }
+                | BackendRequestData::RequestRunGeneratedEntrypoint { plugin_id, .. }
+                | BackendRequestData::SendViewEvent { plugin_id, .. }
+                | BackendRequestData::SendKeyboardEvent { plugin_id, .. }
+                | BackendRequestData::SendOpenEvent { plugin_id, .. } => {
+                    inner.view_owners.entry(plugin_id.clone())
+                        .or_default()
+                        .insert(session_id);
+                }
+                _ => {}
+            }
+        }
+
+        // sent before awaiting, so server receives requests in the same order frontends sent them
+        match self.backend_sender.send(data) {
+            Ok(response) => {
+                tokio::spawn(async move {
+                    if let Ok(data) = wait_for_response(response).await {
+                        responder.respond(data);
+                    }
+                });
+            }
+            Err(err) => {
+                tracing::error!("Unable to send request to server: {:?}", err);
+            }
+        }
+    }
+
+    fn route_ui_request(&self, data: UiRequestData, responder: Responder<UiResponseData>) {
+        let (route, sessions) = {
+            let inner = self.inner.lock().unwrap();
+
+            let route = match &data {
+                UiRequestData::ReplaceView { render_location: UiRenderLocation::InlineView, .. }
+                | UiRequestData::ClearInlineView { .. } => {
+                    inner.inline_view_owner.or(inner.last_active)
+                        .map(|session_id| Route::Sessions(vec![session_id]))
+                }
+                UiRequestData::ReplaceView { plugin_id, .. }
+                | UiRequestData::ShowOnboardingView { plugin_id, .. }
+                | UiRequestData::ShowPluginErrorView { plugin_id, .. }
+                | UiRequestData::UpdateLoadingBar { plugin_id, .. } => {
+                    match inner.view_owners.get(plugin_id) {
+                        Some(owners) => Some(Route::Sessions(owners.iter().copied().collect())),
+                        None => inner.last_active.map(|session_id| Route::Sessions(vec![session_id]))
+                    }
+                }
+                UiRequestData::OpenPluginView { .. }
+                | UiRequestData::ShowWindow
+                | UiRequestData::HideWindow
+                | UiRequestData::ToggleWindow
+                | UiRequestData::ShowSettingsWindow { .. }
+                | UiRequestData::ShowWindowWithSearchQuery { .. }
+                | UiRequestData::ConfirmDeepLink { .. }
+                | UiRequestData::ShowHud { .. } => {
+                    inner.last_active.map(|session_id| Route::Sessions(vec![session_id]))
+                }
+                UiRequestData::RequestSearchResultUpdate
+                | UiRequestData::SetGlobalShortcut { .. }
+                | UiRequestData::SetTheme { .. }
+                | UiRequestData::SetWindowPositionMode { .. }
+                | UiRequestData::SetWindowOverlay { .. }
+                | UiRequestData::SetDensity { .. }
+                | UiRequestData::SetUiScale { .. }
+                | UiRequestData::SetSearchResultsSort { .. }
+                | UiRequestData::SetTrayTooltip { .. } => Some(Route::Broadcast),
+            };
+
+            (route, inner.sessions.clone())
+        };
+
+        let senders: Vec<_> = match route {
+            None => vec![],
+            Some(Route::Sessions(session_ids)) => {
+                session_ids.iter()
+                    .filter_map(|session_id| sessions.get(session_id))
+                    .collect()
+            }
+            Some(Route::Broadcast) => sessions.values().collect()
+        };
+
+        let responses: Vec<_> = match senders.as_slice() {
+            [sender] => sender.send(data).ok().into_iter().collect(),
+            senders => {
+                senders.iter()
+                    .filter_map(|sender| sender.send(clone_request(&data)).ok())
+                    .collect()
+            }
+        };
+
+        if responses.is_empty() {
+            responder.respond(UiResponseData::Err(anyhow!("No frontend is attached")));
+            return;
+        }
+
+        tokio::spawn(async move {
+            let mut result = UiResponseData::Nothing;
+
+            for response in responses {
+                match wait_for_response(response).await {
+                    Ok(UiResponseData::Nothing) => {}
+                    Ok(err @ UiResponseData::Err(_)) => result = err,
+                    Err(err) => result = UiResponseData::Err(err),
+                }
+            }
+
+            responder.respond(result);
+        });
+    }
+}
+
+async fn wait_for_response<Res>(mut response: ResponseReceiver<Res>) -> anyhow::Result<Res> {
+    Ok(response.recv_checked().await?)
+}
+
+// widget tree is not Clone, so request is copied the same way it is sent to remote frontend
+fn clone_request(data: &UiRequestData) -> UiRequestData {
+    let config = bincode::config::standard();
+
+    let encoded = bincode::encode_to_vec(data, config)
+        .expect("ui request should be encodable");
+
+    let (data, _) = bincode::decode_from_slice(&encoded, config)
+        .expect("encoded ui request should be decodable");
+
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::EntrypointId;
+
+    use super::*;
+
+    fn loading_bar(plugin_id: &PluginId) -> UiRequestData {
+        UiRequestData::UpdateLoadingBar {
+            plugin_id: plugin_id.clone(),
+            entrypoint_id: EntrypointId::from_string("view"),
+            show: true,
+        }
+    }
+
+    async fn respond_to_loading_bar(receiver: &mut RequestReceiver<UiRequestData, UiResponseData>) {
+        let (data, responder) = receiver.recv().await;
+
+        assert!(matches!(data, UiRequestData::UpdateLoadingBar { .. }));
+
+        responder.respond(UiResponseData::Nothing);
+    }
+
+    #[tokio::test]
+    async fn two_sessions_opening_the_same_plugin_both_receive_its_view_updates() {
+        let (frontend_sender, frontend_receiver) = channel::<UiRequestData, UiResponseData>();
+        let (backend_sender, mut backend_receiver) = channel::<BackendRequestData, BackendResponseData>();
+
+        let router = FrontendRouter::new(frontend_receiver, backend_sender);
+
+        let (_, mut first_receiver, first_sender) = router.attach();
+        let (_, mut second_receiver, second_sender) = router.attach();
+        let (_, mut third_receiver, _third_sender) = router.attach();
+
+        tokio::spawn(async move {
+            while let Some((_, responder)) = backend_receiver.recv_checked().await {
+                responder.respond(BackendResponseData::Nothing);
+            }
+        });
+
+        let plugin_id = PluginId::from_string("test://plugin");
+
+        for sender in [&first_sender, &second_sender] {
+            let request = BackendRequestData::RequestViewRender {
+                plugin_id: plugin_id.clone(),
+                entrypoint_id: EntrypointId::from_string("view"),
+            };
+
+            sender.send_receive(request).await.unwrap();
+        }
+
+        let response = tokio::spawn({
+            let frontend_sender = frontend_sender.clone();
+            let request = loading_bar(&plugin_id);
+
+            async move { frontend_sender.send_receive(request).await }
+        });
+
+        respond_to_loading_bar(&mut first_receiver).await;
+        respond_to_loading_bar(&mut second_receiver).await;
+
+        assert!(matches!(response.await.unwrap(), Ok(UiResponseData::Nothing)));
+        assert!(third_receiver.try_recv().is_none());
+
+        // view stays open in second session after first one closes it
+        first_sender.send_receive(BackendRequestData::RequestViewClose { plugin_id: plugin_id.clone() }).await.unwrap();
+
+        let response = tokio::spawn({
+            let request = loading_bar(&plugin_id);
+
+            async move { frontend_sender.send_receive(request).await }
+        });
+
+        respond_to_loading_bar(&mut second_receiver).await;
+
+        assert!(matches!(response.await.unwrap(), Ok(UiResponseData::Nothing)));
+        assert!(first_receiver.try_recv().is_none());
+    }
+}
@@ -1,6 +1,8 @@
-use crate::model::{PluginPreference, PluginPreferenceUserData, PreferenceEnumValue};
+use std::time::Duration;
+
+use crate::model::{MetricsTiming, PluginPreference, PluginPreferenceUserData, PreferenceEnumValue, UiColor, UiPropertyValue};
 use crate::rpc::grpc::rpc_ui_property_value::Value;
-use crate::rpc::grpc::{RpcEnumValue, RpcPluginPreference, RpcPluginPreferenceUserData, RpcPluginPreferenceValueType, RpcUiPropertyValue};
+use crate::rpc::grpc::{RpcEnumValue, RpcMetricsTiming, RpcPluginPreference, RpcPluginPreferenceUserData, RpcPluginPreferenceValueType, RpcUiPropertyValue, RpcUiPropertyValueArray, RpcUiPropertyValueColor, RpcUiPropertyValueObject};
 
 pub fn plugin_preference_user_data_from_rpc(value: RpcPluginPreferenceUserData) -> PluginPreferenceUserData {
     let value_type: RpcPluginPreferenceValueType = value.r#type.try_into().unwrap();
@@ -404,3 +406,80 @@ pub fn plugin_preference_from_rpc(value: RpcPluginPreference) -> PluginPreferenc
     }
 }
 
+
+pub fn metrics_timing_to_rpc(value: MetricsTiming) -> RpcMetricsTiming {
+    RpcMetricsTiming {
+        count: value.count,
+        total_micros: value.total.as_micros() as u64,
+        last_micros: value.last.as_micros() as u64,
+        max_micros: value.max.as_micros() as u64,
+    }
+}
+
+pub fn metrics_timing_from_rpc(value: RpcMetricsTiming) -> MetricsTiming {
+    MetricsTiming {
+        count: value.count,
+        total: Duration::from_micros(value.total_micros),
+        last: Duration::from_micros(value.last_micros),
+        max: Duration::from_micros(value.max_micros),
+    }
+}
+
+pub fn ui_property_value_to_rpc(value: UiPropertyValue) -> RpcUiPropertyValue {
+    let value = match value {
+        UiPropertyValue::String(value) => Value::String(value),
+        UiPropertyValue::Number(value) => Value::Number(value),
+        UiPropertyValue::Bool(value) => Value::Bool(value),
+        UiPropertyValue::Bytes(value) => Value::Bytes(value.to_vec()),
+        UiPropertyValue::Array(value) => {
+            Value::Array(RpcUiPropertyValueArray {
+                value: value.into_iter().map(|value| ui_property_value_to_rpc(value)).collect()
+            })
+        }
+        UiPropertyValue::Object(value) => {
+            Value::Object(RpcUiPropertyValueObject {
+                value: value.into_iter().map(|(key, value)| (key, ui_property_value_to_rpc(value))).collect()
+            })
+        }
+        UiPropertyValue::Color(UiColor { r, g, b, a }) => {
+            Value::Color(RpcUiPropertyValueColor {
+                r: r as u32,
+                g: g as u32,
+                b: b as u32,
+                a: a as u32,
+            })
+        }
+        UiPropertyValue::Enum(value) => Value::Enum(value),
+        UiPropertyValue::Undefined => Value::Undefined(0),
+    };
+
+    RpcUiPropertyValue {
+        value: Some(value)
+    }
+}
+
+pub fn ui_property_value_from_rpc(value: RpcUiPropertyValue) -> UiPropertyValue {
+    match value.value {
+        None | Some(Value::Undefined(_)) => UiPropertyValue::Undefined,
+        Some(Value::String(value)) => UiPropertyValue::String(value),
+        Some(Value::Number(value)) => UiPropertyValue::Number(value),
+        Some(Value::Bool(value)) => UiPropertyValue::Bool(value),
+        Some(Value::Bytes(value)) => UiPropertyValue::Bytes(value.into()),
+        Some(Value::Array(value)) => {
+            UiPropertyValue::Array(value.value.into_iter().map(|value| ui_property_value_from_rpc(value)).collect())
+        }
+        Some(Value::Object(value)) => {
+            UiPropertyValue::Object(value.value.into_iter().map(|(key, value)| (key, ui_property_value_from_rpc(value))).collect())
+        }
+        Some(Value::Color(RpcUiPropertyValueColor { r, g, b, a })) => {
+            // values outside of u8 range are clamped
+            UiPropertyValue::Color(UiColor {
+                r: r.min(u8::MAX as u32) as u8,
+                g: g.min(u8::MAX as u32) as u8,
+                b: b.min(u8::MAX as u32) as u8,
+                a: a.min(u8::MAX as u32) as u8,
+            })
+        }
+        Some(Value::Enum(value)) => UiPropertyValue::Enum(value),
+    }
+}
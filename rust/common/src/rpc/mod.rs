@@ -1,5 +1,7 @@
 pub mod backend_api;
 pub mod frontend_api;
 pub mod backend_server;
+pub mod frontend_router;
+pub mod remote;
 mod grpc;
 mod grpc_convert;
@@ -0,0 +1,544 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, Context};
+use bincode::{Decode, Encode};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use tokio_rustls::rustls::crypto::ring::default_provider;
+use tokio_rustls::rustls::pki_types::{CertificateDer, PrivateKeyDer, ServerName};
+use tokio_rustls::rustls::{ClientConfig, RootCertStore, ServerConfig};
+use tokio_rustls::client::TlsStream;
+use tokio_rustls::{TlsAcceptor, TlsConnector};
+
+use gauntlet_utils::channel::{channel, RequestReceiver, RequestSender, Responder};
+
+use crate::dirs::Dirs;
+use crate::rpc::frontend_router::FrontendRouter;
+use crate::model::{BackendRequestData, BackendResponseData, UiRequestData, UiResponseData};
+
+// views are sent as a whole, but anything above this is not expected from well-behaved peer
+const MESSAGE_SIZE_LIMIT: u32 = 64 * 1024 * 1024;
+
+// before token is checked, only hello message is expected, so unauthenticated peer can't make server allocate much memory
+const HELLO_SIZE_LIMIT: u32 = 4 * 1024;
+
+// tls handshake and hello message, so that connections which never authenticate don't stay open
+const AUTHENTICATION_TIMEOUT: Duration = Duration::from_secs(10);
+
+const RECONNECT_INITIAL_DELAY: Duration = Duration::from_secs(1);
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(30);
+
+// messages exchanged between headless server and remote frontend over single tls connection.
+// both sides forward them into the same in-process channels which are used when running locally,
+// so neither server nor frontend know that the other side is remote
+#[derive(Debug, Encode, Decode)]
+enum RemoteMessage {
+    Hello {
+        token: String
+    },
+    UiRequest {
+        id: u64,
+        data: UiRequestData
+    },
+    UiResponse {
+        id: u64,
+        data: RemoteUiResponseData
+    },
+    BackendRequest {
+        id: u64,
+        data: BackendRequestData
+    },
+    BackendResponse {
+        id: u64,
+        data: BackendResponseData
+    },
+}
+
+#[derive(Debug, Encode, Decode)]
+enum RemoteUiResponseData {
+    Nothing,
+    Err(String),
+}
+
+impl From<UiResponseData> for RemoteUiResponseData {
+    fn from(value: UiResponseData) -> Self {
+        match value {
+            UiResponseData::Nothing => RemoteUiResponseData::Nothing,
+            UiResponseData::Err(err) => RemoteUiResponseData::Err(format!("{:#}", err)),
+        }
+    }
+}
+
+impl From<RemoteUiResponseData> for UiResponseData {
+    fn from(value: RemoteUiResponseData) -> Self {
+        match value {
+            RemoteUiResponseData::Nothing => UiResponseData::Nothing,
+            RemoteUiResponseData::Err(err) => UiResponseData::Err(anyhow!(err)),
+        }
+    }
+}
+
+// every connection is attached to router as separate frontend session
+pub async fn serve_remote_frontend(
+    listen: SocketAddr,
+    cert: &Path,
+    key: &Path,
+    token: String,
+    router: FrontendRouter,
+) -> anyhow::Result<()> {
+    let acceptor = tls_acceptor(cert, key)?;
+
+    let listener = TcpListener::bind(listen)
+        .await
+        .context("Unable to bind remote frontend listener")?;
+
+    tracing::info!("Waiting for remote frontends on {}", listen);
+
+    let token: Arc<str> = token.into();
+
+    loop {
+        let (stream, address) = match listener.accept().await {
+            Ok(accepted) => accepted,
+            Err(err) => {
+                tracing::warn!("Unable to accept remote frontend connection: {:?}", err);
+                continue;
+            }
+        };
+
+        let acceptor = acceptor.clone();
+        let token = token.clone();
+        let router = router.clone();
+
+        tokio::spawn(async move {
+            let result = async {
+                let authentication = async {
+                    let mut stream = acceptor.accept(stream)
+                        .await
+                        .context("Tls handshake failed")?;
+
+                    match recv_message(&mut stream, HELLO_SIZE_LIMIT).await? {
+                        RemoteMessage::Hello { token: received } if constant_time_eq(received.as_bytes(), token.as_bytes()) => {}
+                        _ => Err(anyhow!("Remote frontend didn't provide valid token"))?
+                    }
+
+                    anyhow::Ok(stream)
+                };
+
+                let stream = tokio::time::timeout(AUTHENTICATION_TIMEOUT, authentication)
+                    .await
+                    .map_err(|_| anyhow!("Remote frontend didn't authenticate in time"))??;
+
+                let (session_id, mut frontend_receiver, backend_sender) = router.attach();
+
+                tracing::info!("Remote frontend connected from {} as session {}", address, session_id);
+
+                let result = server_connection_loop(stream, &mut frontend_receiver, &backend_sender).await;
+
+                router.detach(session_id);
+
+                // requests which were routed to this frontend right before it disconnected
+                while let Some((_, responder)) = frontend_receiver.try_recv() {
+                    responder.respond(UiResponseData::Err(anyhow!("Remote frontend disconnected")));
+                }
+
+                result
+            }.await;
+
+            match result {
+                Ok(()) => tracing::info!("Remote frontend from {} disconnected", address),
+                Err(err) => tracing::warn!("Remote frontend connection from {} closed: {:?}", address, err),
+            }
+        });
+    }
+}
+
+async fn server_connection_loop<S: AsyncRead + AsyncWrite + Unpin + Send + 'static>(
+    stream: S,
+    frontend_receiver: &mut RequestReceiver<UiRequestData, UiResponseData>,
+    backend_sender: &RequestSender<BackendRequestData, BackendResponseData>,
+) -> anyhow::Result<()> {
+    let (read, write) = tokio::io::split(stream);
+
+    let (message_sender, mut writer_task) = spawn_writer(write);
+    let (mut message_receiver, reader_task) = spawn_reader(read);
+
+    let mut next_id = 0;
+    let mut pending: HashMap<u64, Responder<UiResponseData>> = HashMap::new();
+
+    let result = loop {
+        tokio::select! {
+            (data, responder) = frontend_receiver.recv() => {
+                next_id += 1;
+                pending.insert(next_id, responder);
+
+                let _ = message_sender.send(RemoteMessage::UiRequest { id: next_id, data });
+            }
+            message = message_receiver.recv() => {
+                let message = match message {
+                    Some(Ok(message)) => message,
+                    Some(Err(err)) => break Err(err),
+                    None => break Ok(())
+                };
+
+                match message {
+                    RemoteMessage::UiResponse { id, data } => {
+                        if let Some(responder) = pending.remove(&id) {
+                            responder.respond(data.into());
+                        }
+                    }
+                    RemoteMessage::BackendRequest { id, data } => {
+                        // sent before awaiting, so server receives requests in the same order frontend sent them
+                        let mut response = match backend_sender.send(data) {
+                            Ok(response) => response,
+                            Err(err) => break Err(err.into())
+                        };
+
+                        let message_sender = message_sender.clone();
+
+                        tokio::spawn(async move {
+                            if let Ok(data) = response.recv_checked().await {
+                                let _ = message_sender.send(RemoteMessage::BackendResponse { id, data });
+                            }
+                        });
+                    }
+                    message @ _ => break Err(anyhow!("Unexpected message from remote frontend: {:?}", message))
+                }
+            }
+            result = &mut writer_task => {
+                break result.map_err(anyhow::Error::from).and_then(|result| result)
+            }
+        }
+    };
+
+    writer_task.abort();
+    reader_task.abort();
+
+    for (_, responder) in pending {
+        responder.respond(UiResponseData::Err(anyhow!("Remote frontend disconnected")));
+    }
+
+    result
+}
+
+// returns channels which can be passed to frontend in place of the ones connected to local server.
+// first connection has to succeed, after that connection is re-established in background whenever it is lost
+pub async fn connect_remote_backend(
+    address: &str,
+    token: String,
+    cert: &Path,
+) -> anyhow::Result<(RequestReceiver<UiRequestData, UiResponseData>, RequestSender<BackendRequestData, BackendResponseData>)> {
+    let connector = tls_connector(cert)?;
+
+    let stream = connect(&connector, address, &token).await?;
+
+    tracing::info!("Connected to remote server {}", address);
+
+    let (frontend_sender, frontend_receiver) = channel::<UiRequestData, UiResponseData>();
+    let (backend_sender, mut backend_receiver) = channel::<BackendRequestData, BackendResponseData>();
+
+    let address = address.to_string();
+
+    tokio::spawn(async move {
+        let mut stream = stream;
+
+        loop {
+            if let Err(err) = client_connection_loop(stream, &frontend_sender, &mut backend_receiver).await {
+                tracing::error!("Connection to remote server closed: {:?}", err);
+            }
+
+            stream = match reconnect(&connector, &address, &token, &mut backend_receiver).await {
+                Some(stream) => stream,
+                None => return,
+            };
+
+            tracing::info!("Reconnected to remote server {}", address);
+        }
+    });
+
+    Ok((frontend_receiver, backend_sender))
+}
+
+async fn connect(connector: &TlsConnector, address: &str, token: &str) -> anyhow::Result<TlsStream<TcpStream>> {
+    let (host, _) = address.rsplit_once(':')
+        .ok_or_else(|| anyhow!("Remote address is expected to be in host:port format: {}", address))?;
+
+    let server_name = ServerName::try_from(host.trim_start_matches('[').trim_end_matches(']').to_string())
+        .context("Invalid remote host name")?;
+
+    let stream = TcpStream::connect(address)
+        .await
+        .context("Unable to connect to remote server")?;
+
+    let mut stream = connector.connect(server_name, stream)
+        .await
+        .context("Tls handshake failed")?;
+
+    send_message(&mut stream, &RemoteMessage::Hello { token: token.to_string() }).await?;
+
+    Ok(stream)
+}
+
+// retries with exponential backoff. requests sent by frontend in the meantime fail right away,
+// so that it shows that connection was lost instead of waiting for them to time out.
+// returns none when frontend is closed
+async fn reconnect(
+    connector: &TlsConnector,
+    address: &str,
+    token: &str,
+    backend_receiver: &mut RequestReceiver<BackendRequestData, BackendResponseData>,
+) -> Option<TlsStream<TcpStream>> {
+    let mut delay = RECONNECT_INITIAL_DELAY;
+
+    loop {
+        let sleep = tokio::time::sleep(delay);
+        tokio::pin!(sleep);
+
+        loop {
+            tokio::select! {
+                _ = &mut sleep => break,
+                request = backend_receiver.recv_checked() => {
+                    // responder is dropped without responding, so sender gets OtherSideWasDropped error
+                    request?;
+                }
+            }
+        }
+
+        match connect(connector, address, token).await {
+            Ok(stream) => return Some(stream),
+            Err(err) => tracing::debug!("Unable to reconnect to remote server: {:?}", err),
+        }
+
+        delay = (delay * 2).min(RECONNECT_MAX_DELAY);
+    }
+}
+
+async fn client_connection_loop<S: AsyncRead + AsyncWrite + Unpin + Send + 'static>(
+    stream: S,
+    frontend_sender: &RequestSender<UiRequestData, UiResponseData>,
+    backend_receiver: &mut RequestReceiver<BackendRequestData, BackendResponseData>,
+) -> anyhow::Result<()> {
+    let (read, write) = tokio::io::split(stream);
+
+    let (message_sender, mut writer_task) = spawn_writer(write);
+    let (mut message_receiver, reader_task) = spawn_reader(read);
+
+    let mut next_id = 0;
+    let mut pending: HashMap<u64, Responder<BackendResponseData>> = HashMap::new();
+
+    let result = loop {
+        tokio::select! {
+            (data, responder) = backend_receiver.recv() => {
+                next_id += 1;
+                pending.insert(next_id, responder);
+
+                let _ = message_sender.send(RemoteMessage::BackendRequest { id: next_id, data });
+            }
+            message = message_receiver.recv() => {
+                let message = match message {
+                    Some(Ok(message)) => message,
+                    Some(Err(err)) => break Err(err),
+                    None => break Ok(())
+                };
+
+                match message {
+                    RemoteMessage::BackendResponse { id, data } => {
+                        let data = match data {
+                            // window position is stored on the machine where window is shown
+                            BackendResponseData::SetupData { mut data } => {
+                                data.window_position_file = Dirs::new().window_position();
+
+                                BackendResponseData::SetupData { data }
+                            }
+                            data @ _ => data
+                        };
+
+                        if let Some(responder) = pending.remove(&id) {
+                            responder.respond(data);
+                        }
+                    }
+                    RemoteMessage::UiRequest { id, data } => {
+                        let mut response = match frontend_sender.send(data) {
+                            Ok(response) => response,
+                            Err(err) => break Err(err.into())
+                        };
+
+                        let message_sender = message_sender.clone();
+
+                        tokio::spawn(async move {
+                            if let Ok(data) = response.recv_checked().await {
+                                let _ = message_sender.send(RemoteMessage::UiResponse { id, data: data.into() });
+                            }
+                        });
+                    }
+                    message @ _ => break Err(anyhow!("Unexpected message from remote server: {:?}", message))
+                }
+            }
+            result = &mut writer_task => {
+                break result.map_err(anyhow::Error::from).and_then(|result| result)
+            }
+        }
+    };
+
+    writer_task.abort();
+    reader_task.abort();
+
+    result
+}
+
+fn spawn_writer<W: AsyncWrite + Unpin + Send + 'static>(mut write: W) -> (mpsc::UnboundedSender<RemoteMessage>, tokio::task::JoinHandle<anyhow::Result<()>>) {
+    let (message_sender, mut message_receiver) = mpsc::unbounded_channel::<RemoteMessage>();
+
+    let writer_task = tokio::spawn(async move {
+        while let Some(message) = message_receiver.recv().await {
+            send_message(&mut write, &message).await?;
+        }
+
+        anyhow::Ok(())
+    });
+
+    (message_sender, writer_task)
+}
+
+// reading is done in separate task, because partially read message would be lost if reading was cancelled by select
+fn spawn_reader<R: AsyncRead + Unpin + Send + 'static>(mut read: R) -> (mpsc::UnboundedReceiver<anyhow::Result<RemoteMessage>>, tokio::task::JoinHandle<()>) {
+    let (message_sender, message_receiver) = mpsc::unbounded_channel::<anyhow::Result<RemoteMessage>>();
+
+    let reader_task = tokio::spawn(async move {
+        loop {
+            let message = recv_message(&mut read, MESSAGE_SIZE_LIMIT).await;
+
+            let failed = message.is_err();
+
+            if message_sender.send(message).is_err() || failed {
+                break;
+            }
+        }
+    });
+
+    (message_receiver, reader_task)
+}
+
+async fn send_message<W: AsyncWrite + Unpin>(write: &mut W, message: &RemoteMessage) -> anyhow::Result<()> {
+    let encoded: Vec<u8> = bincode::encode_to_vec(message, bincode::config::standard())?;
+
+    write.write_u32(encoded.len() as u32).await?;
+
+    write.write_all(&encoded[..]).await?;
+
+    write.flush().await?;
+
+    Ok(())
+}
+
+async fn recv_message<R: AsyncRead + Unpin>(read: &mut R, size_limit: u32) -> anyhow::Result<RemoteMessage> {
+    let buf_size = read.read_u32().await?;
+
+    if buf_size > size_limit {
+        Err(anyhow!("Message of {} bytes exceeds size limit", buf_size))?
+    }
+
+    let mut buffer = vec![0; buf_size as usize];
+
+    read.read_exact(&mut buffer).await?;
+
+    let (decoded, _) = bincode::decode_from_slice(&buffer[..], bincode::config::standard())
+        .context("Unable to deserialize remote message")?;
+
+    Ok(decoded)
+}
+
+fn tls_acceptor(cert: &Path, key: &Path) -> anyhow::Result<TlsAcceptor> {
+    let certs = read_certs(cert)?;
+
+    let key = rustls_pemfile::private_key(&mut BufReader::new(File::open(key).context("Unable to open private key file")?))
+        .context("Unable to read private key file")?
+        .ok_or_else(|| anyhow!("Private key file doesn't contain private key"))?;
+
+    let config = ServerConfig::builder_with_provider(Arc::new(default_provider()))
+        .with_safe_default_protocol_versions()?
+        .with_no_client_auth()
+        .with_single_cert(certs, PrivateKeyDer::from(key))
+        .context("Invalid certificate or private key")?;
+
+    Ok(TlsAcceptor::from(Arc::new(config)))
+}
+
+// server certificate is usually self-signed, so instead of system roots
+// only the certificate provided by user is trusted
+fn tls_connector(cert: &Path) -> anyhow::Result<TlsConnector> {
+    let mut roots = RootCertStore::empty();
+
+    for cert in read_certs(cert)? {
+        roots.add(cert)
+            .context("Invalid server certificate")?;
+    }
+
+    let config = ClientConfig::builder_with_provider(Arc::new(default_provider()))
+        .with_safe_default_protocol_versions()?
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+
+    Ok(TlsConnector::from(Arc::new(config)))
+}
+
+fn read_certs(cert: &Path) -> anyhow::Result<Vec<CertificateDer<'static>>> {
+    let certs = rustls_pemfile::certs(&mut BufReader::new(File::open(cert).context("Unable to open certificate file")?))
+        .collect::<Result<Vec<_>, _>>()
+        .context("Unable to read certificate file")?;
+
+    if certs.is_empty() {
+        Err(anyhow!("Certificate file doesn't contain certificates"))?
+    }
+
+    Ok(certs)
+}
+
+fn constant_time_eq(left: &[u8], right: &[u8]) -> bool {
+    if left.len() != right.len() {
+        return false;
+    }
+
+    left.iter()
+        .zip(right.iter())
+        .fold(0, |acc, (left, right)| acc | (left ^ right)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use gauntlet_utils::channel::RequestError;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn request_in_flight_fails_when_connection_is_dropped() {
+        let (client, mut server) = tokio::io::duplex(1024);
+
+        let (frontend_sender, _frontend_receiver) = channel::<UiRequestData, UiResponseData>();
+        let (backend_sender, mut backend_receiver) = channel::<BackendRequestData, BackendResponseData>();
+
+        let connection = tokio::spawn(async move {
+            let _ = client_connection_loop(client, &frontend_sender, &mut backend_receiver).await;
+        });
+
+        let request = tokio::spawn(async move {
+            backend_sender.send_receive(BackendRequestData::Ping).await
+        });
+
+        // request has reached the server, but response never arrives
+        let message = recv_message(&mut server, MESSAGE_SIZE_LIMIT).await.unwrap();
+        assert!(matches!(message, RemoteMessage::BackendRequest { data: BackendRequestData::Ping, .. }));
+
+        drop(server);
+
+        connection.await.unwrap();
+
+        assert!(matches!(request.await.unwrap(), Err(RequestError::OtherSideWasDropped)));
+    }
+}
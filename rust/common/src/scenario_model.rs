@@ -19,10 +19,11 @@ pub enum ScenarioFrontendEvent {
         #[serde(with="base64")]
         images: HashMap<UiWidgetId, Vec<u8>>,
     },
-    ShowPreferenceRequiredView {
+    ShowOnboardingView {
         entrypoint_id: String,
-        plugin_preferences_required: bool,
-        entrypoint_preferences_required: bool,
+        description: Option<String>,
+        // names of required preferences which don't have value yet
+        missing_preferences: Vec<String>,
     },
     ShowPluginErrorView {
         entrypoint_id: String,
@@ -534,6 +534,13 @@ fn root(children: &[&Component]) -> Component {
 
                 ].into_iter().map(|s| s.to_string()).collect()
             }),
+            ("SkeletonVariant".to_owned(), SharedType::Enum {
+                items: [
+                    "Line",
+                    "Circle",
+                    "Rect",
+                ].into_iter().map(|s| s.to_string()).collect()
+            }),
             ("ImageSourceUrl".to_owned(), SharedType::Object {
                 items: {
                     let mut map = IndexMap::new();
@@ -809,6 +816,32 @@ pub fn create_component_model() -> Vec<Component> {
         children_string(mark_doc!("/code_block/props/children.md")),
     );
 
+    let skeleton_component = component(
+        "skeleton",
+        mark_doc!("/skeleton/description.md"),
+        "Skeleton",
+        [
+            property("variant", mark_doc!("/skeleton/props/variant.md"), true, PropertyType::SharedTypeRef { name: "SkeletonVariant".to_owned() }),
+            property("width", mark_doc!("/skeleton/props/width.md"), true, PropertyType::Number),
+            property("height", mark_doc!("/skeleton/props/height.md"), true, PropertyType::Number),
+        ],
+        children_none(),
+    );
+
+    let media_item_component = component(
+        "media_item",
+        mark_doc!("/media_item/description.md"),
+        "MediaItem",
+        [
+            property("title", mark_doc!("/media_item/props/title.md"), false, PropertyType::String),
+            property("subtitle", mark_doc!("/media_item/props/subtitle.md"), true, PropertyType::String),
+            property("artwork", mark_doc!("/media_item/props/artwork.md"), true, PropertyType::SharedTypeRef { name: "ImageLike".to_owned() }),
+            property("position", mark_doc!("/media_item/props/position.md"), true, PropertyType::Number),
+            property("duration", mark_doc!("/media_item/props/duration.md"), true, PropertyType::Number),
+        ],
+        children_none(),
+    );
+
     // let code_component = component(
     //     "code",
     //     "Code",
@@ -847,6 +880,8 @@ pub fn create_component_model() -> Vec<Component> {
                 member("H6", &h6_component, Arity::ZeroOrMore),
                 member("HorizontalBreak", &horizontal_break_component, Arity::ZeroOrMore),
                 member("CodeBlock", &code_block_component, Arity::ZeroOrMore),
+                member("Skeleton", &skeleton_component, Arity::ZeroOrMore),
+                member("MediaItem", &media_item_component, Arity::ZeroOrMore),
                 // member("Code", &code_component),
             ],
             []
@@ -878,9 +913,16 @@ pub fn create_component_model() -> Vec<Component> {
         [
             property("label", mark_doc!("/text_field/props/label.md"),true, PropertyType::String),
             property("value", mark_doc!("/text_field/props/value.md"),true, PropertyType::String),
+            property("placeholder", mark_doc!("/text_field/props/placeholder.md"),true, PropertyType::String),
+            property("debounce", mark_doc!("/text_field/props/debounce.md"),true, PropertyType::Number),
             event("onChange", mark_doc!("/text_field/props/onChange.md"),true, [
                 property("value", "".to_string(), true, PropertyType::String)
-            ])
+            ]),
+            event("onSubmit", mark_doc!("/text_field/props/onSubmit.md"),true, [
+                property("value", "".to_string(), true, PropertyType::String)
+            ]),
+            event("onFocus", mark_doc!("/text_field/props/onFocus.md"),true, []),
+            event("onBlur", mark_doc!("/text_field/props/onBlur.md"),true, [])
         ],
         children_none(),
     );
@@ -899,12 +941,24 @@ pub fn create_component_model() -> Vec<Component> {
         children_none(),
     );
 
-    // let text_area_component = component(
-    //     "text_area",
-    //     "TextArea",
-    //     [],
-    //     children_none(),
-    // );
+    let text_area_component = component(
+        "text_area",
+        mark_doc!("/text_area/description.md"),
+        "TextArea",
+        [
+            property("label", mark_doc!("/text_area/props/label.md"), true, PropertyType::String),
+            property("value", mark_doc!("/text_area/props/value.md"), true, PropertyType::String),
+            property("placeholder", mark_doc!("/text_area/props/placeholder.md"), true, PropertyType::String),
+            property("maxLength", mark_doc!("/text_area/props/maxLength.md"), true, PropertyType::Number),
+            event("onChange", mark_doc!("/text_area/props/onChange.md"), true, [
+                property("value", "".to_string(), true, PropertyType::String)
+            ]),
+            event("onSubmit", mark_doc!("/text_area/props/onSubmit.md"), true, [
+                property("value", "".to_string(), true, PropertyType::String)
+            ])
+        ],
+        children_none(),
+    );
 
     let checkbox_component = component(
         "checkbox",
@@ -991,7 +1045,7 @@ pub fn create_component_model() -> Vec<Component> {
             [
                 member("TextField", &text_field_component, Arity::ZeroOrMore),
                 member("PasswordField", &password_field_component, Arity::ZeroOrMore),
-                // member("TextArea", &text_area_component),
+                member("TextArea", &text_area_component, Arity::ZeroOrMore),
                 member("Checkbox", &checkbox_component, Arity::ZeroOrMore),
                 member("DatePicker", &date_picker_component, Arity::ZeroOrMore),
                 member("Select", &select_component, Arity::ZeroOrMore),
@@ -1039,7 +1093,12 @@ pub fn create_component_model() -> Vec<Component> {
             property("description", mark_doc!("/empty_view/props/description.md"),true, PropertyType::String),
             property("image", mark_doc!("/empty_view/props/image.md"),true, PropertyType::SharedTypeRef { name: "ImageLike".to_owned() }),
         ],
-        children_none(),
+        children_members(
+            [
+                member("Action", &action_component, Arity::ZeroOrMore),
+            ],
+            []
+        ),
     );
 
     let accessory_text_component = component(
@@ -1079,6 +1138,58 @@ pub fn create_component_model() -> Vec<Component> {
         children_none(),
     );
 
+    let tab_component = component(
+        "tab",
+        mark_doc!("/tab/description.md"),
+        "Tab",
+        [
+            property("id", mark_doc!("/tab/props/id.md"), false, PropertyType::String),
+            property("title", mark_doc!("/tab/props/title.md"), false, PropertyType::String),
+        ],
+        children_none(),
+    );
+
+    let tabs_component = component(
+        "tabs",
+        mark_doc!("/tabs/description.md"),
+        "Tabs",
+        [
+            property("value", mark_doc!("/tabs/props/value.md"), true, PropertyType::String),
+            event("onChange", mark_doc!("/tabs/props/onChange.md"), true, [
+                property("tabId", "".to_string(), false, PropertyType::String)
+            ])
+        ],
+        children_members(
+            [
+                member("Tab", &tab_component, Arity::ZeroOrMore),
+            ],
+            []
+        ),
+    );
+
+    let list_column_component = component(
+        "list_column",
+        mark_doc!("/list_column/description.md"),
+        "ListColumn",
+        [
+            property("title", mark_doc!("/list_column/props/title.md"), false, PropertyType::String),
+        ],
+        children_none(),
+    );
+
+    let list_columns_component = component(
+        "list_columns",
+        mark_doc!("/list_columns/description.md"),
+        "ListColumns",
+        [],
+        children_members(
+            [
+                member("Column", &list_column_component, Arity::ZeroOrMore),
+            ],
+            []
+        ),
+    );
+
     let list_item_component = component(
         "list_item",
         mark_doc!("/list_item/description.md"),
@@ -1089,6 +1200,8 @@ pub fn create_component_model() -> Vec<Component> {
             property("subtitle", mark_doc!("/list_item/props/subtitle.md"),true, PropertyType::String),
             property("icon", mark_doc!("/list_item/props/icon.md"),true, PropertyType::SharedTypeRef { name: "ImageLike".to_owned() }),
             property("accessories", mark_doc!("/list_item/props/accessories.md"),true, PropertyType::Array { item: Box::new(PropertyType::Union { items: vec![component_ref(&accessory_text_component, Arity::ZeroOrMore), component_ref(&accessory_icon_component, Arity::ZeroOrMore)]}) }),
+            property("dragFile", mark_doc!("/list_item/props/dragFile.md"), true, PropertyType::String),
+            property("dragText", mark_doc!("/list_item/props/dragText.md"), true, PropertyType::String),
         ],
         children_none(),
     );
@@ -1116,9 +1229,11 @@ pub fn create_component_model() -> Vec<Component> {
         [
             property("actions", mark_doc!("/list/props/actions.md"), true, component_ref(&action_panel_component, Arity::ZeroOrOne)),
             property("isLoading", mark_doc!("/list/props/isLoading.md"), true, PropertyType::Boolean),
+            property("hasMore", mark_doc!("/list/props/hasMore.md"), true, PropertyType::Boolean),
             event("onItemFocusChange", mark_doc!("/list/props/onItemFocusChange.md"), true, [
                 property("itemId", "".to_string(), true, PropertyType::String)
-            ])
+            ]),
+            event("onLoadMore", mark_doc!("/list/props/onLoadMore.md"), true, [])
         ],
         children_members(
             [
@@ -1127,6 +1242,8 @@ pub fn create_component_model() -> Vec<Component> {
             ],
             [
                 member("SearchBar", &search_bar_component, Arity::ZeroOrOne),
+                member("Tabs", &tabs_component, Arity::ZeroOrOne),
+                member("Columns", &list_columns_component, Arity::ZeroOrOne),
                 member("EmptyView", &empty_view_component, Arity::ZeroOrOne),
                 member("Detail", &detail_component, Arity::ZeroOrOne),
             ]
@@ -1142,6 +1259,8 @@ pub fn create_component_model() -> Vec<Component> {
             property("title", mark_doc!("/grid_item/props/title.md"), true, PropertyType::String),
             property("subtitle", mark_doc!("/grid_item/props/subtitle.md"), true, PropertyType::String),
             property("accessory", mark_doc!("/grid_item/props/accessory.md"),true, component_ref(&accessory_icon_component, Arity::ZeroOrOne)),
+            property("dragFile", mark_doc!("/grid_item/props/dragFile.md"), true, PropertyType::String),
+            property("dragText", mark_doc!("/grid_item/props/dragText.md"), true, PropertyType::String),
         ],
         children_members(
             [],
@@ -1193,6 +1312,7 @@ pub fn create_component_model() -> Vec<Component> {
             ],
             [
                 member("SearchBar", &search_bar_component, Arity::ZeroOrOne),
+                member("Tabs", &tabs_component, Arity::ZeroOrOne),
                 member("EmptyView", &empty_view_component, Arity::ZeroOrOne),
             ]
         ),
@@ -1292,6 +1412,8 @@ pub fn create_component_model() -> Vec<Component> {
         h6_component,
         horizontal_break_component,
         code_block_component,
+        skeleton_component,
+        media_item_component,
         // code_component,
         paragraph_component,
         content_component,
@@ -1300,7 +1422,7 @@ pub fn create_component_model() -> Vec<Component> {
 
         text_field_component,
         password_field_component,
-        // text_area_component,
+        text_area_component,
         checkbox_component,
         date_picker_component,
         select_item_component,
@@ -1319,6 +1441,12 @@ pub fn create_component_model() -> Vec<Component> {
 
         search_bar_component,
 
+        tab_component,
+        tabs_component,
+
+        list_column_component,
+        list_columns_component,
+
         list_item_component,
         list_section_component,
         list_component,
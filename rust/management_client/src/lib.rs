@@ -1,3 +1,9 @@
+use gauntlet_common::rpc::backend_api::BackendApi;
+use gauntlet_common::SettingsEnvData;
+use iced::{Size, Subscription, Task};
+
+pub use crate::theme::{Element as SettingsElement, GauntletSettingsTheme};
+
 mod ui;
 mod theme;
 mod views;
@@ -6,3 +12,38 @@ mod components;
 pub fn start_management_client() {
     ui::run();
 }
+
+pub const SETTINGS_WINDOW_SIZE: Size = ui::SETTINGS_WINDOW_SIZE;
+
+// settings shown in a window of another iced application, instead of being a separate process.
+// it uses connection to server of that application
+pub struct SettingsWindow {
+    model: ui::ManagementAppModel,
+}
+
+#[derive(Debug, Clone)]
+pub struct SettingsWindowMsg(ui::ManagementAppMsg);
+
+impl SettingsWindow {
+    pub fn new(backend_api: BackendApi, settings_env_data: Option<SettingsEnvData>) -> (Self, Task<SettingsWindowMsg>) {
+        let (model, task) = ui::new(Some(backend_api), settings_env_data);
+
+        (Self { model }, task.map(SettingsWindowMsg))
+    }
+
+    pub fn open_preferences(&mut self, settings_env_data: SettingsEnvData) -> Task<SettingsWindowMsg> {
+        ui::open_preferences(settings_env_data).map(SettingsWindowMsg)
+    }
+
+    pub fn update(&mut self, message: SettingsWindowMsg) -> Task<SettingsWindowMsg> {
+        ui::update(&mut self.model, message.0).map(SettingsWindowMsg)
+    }
+
+    pub fn view(&self) -> SettingsElement<'_, SettingsWindowMsg> {
+        ui::view(&self.model).map(SettingsWindowMsg)
+    }
+
+    pub fn subscription(&self) -> Subscription<SettingsWindowMsg> {
+        ui::subscription(&self.model).map(SettingsWindowMsg)
+    }
+}
@@ -1,5 +1,7 @@
+use gauntlet_common::logging::{init_logging, SETTINGS_LOG_FILE_PREFIX};
+
 fn main() {
-    tracing_subscriber::fmt::init();
+    let _log_guard = init_logging(None, Some(SETTINGS_LOG_FILE_PREFIX));
 
     gauntlet_management_client::start_management_client();
 }
@@ -11,6 +11,7 @@ pub mod checkbox;
 pub mod pick_list;
 pub mod scrollable;
 pub mod shortcut_selector;
+pub mod slider;
 
 pub type Element<'a, Message> = iced::Element<'a, Message, GauntletSettingsTheme>;
 
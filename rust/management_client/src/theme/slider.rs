@@ -0,0 +1,39 @@
+use crate::theme::{GauntletSettingsTheme, BACKGROUND_DARKEST, PRIMARY, PRIMARY_HOVERED};
+use iced::widget::slider;
+use iced::widget::slider::{Handle, HandleShape, Rail, Status, Style};
+use iced::{Border, Color};
+
+impl slider::Catalog for GauntletSettingsTheme {
+    type Class<'a> = ();
+
+    fn default<'a>() -> Self::Class<'a> {
+        ()
+    }
+
+    fn style(&self, _class: &Self::Class<'_>, status: Status) -> Style {
+        let handle_color = match status {
+            Status::Active => PRIMARY.to_iced(),
+            Status::Hovered | Status::Dragged => PRIMARY_HOVERED.to_iced(),
+        };
+
+        Style {
+            rail: Rail {
+                backgrounds: (PRIMARY.to_iced().into(), BACKGROUND_DARKEST.to_iced().into()),
+                width: 4.0,
+                border: Border {
+                    radius: 2.0.into(),
+                    width: 0.0,
+                    color: Color::TRANSPARENT,
+                },
+            },
+            handle: Handle {
+                shape: HandleShape::Circle {
+                    radius: 7.0,
+                },
+                background: handle_color.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+        }
+    }
+}
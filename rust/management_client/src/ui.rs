@@ -8,57 +8,96 @@ use iced_aw::Spinner;
 use iced_fonts::{Bootstrap, BOOTSTRAP_FONT, BOOTSTRAP_FONT_BYTES};
 use itertools::Itertools;
 
-use gauntlet_common::model::{DownloadStatus, PhysicalShortcut, PluginId, SettingsTheme, WindowPositionMode};
+use gauntlet_common::model::{Density, DownloadStatus, PhysicalShortcut, PluginId, SearchResultsSort, SettingsTheme, UiScale, WindowPositionMode};
 use gauntlet_common::rpc::backend_api::{BackendApi, BackendApiError};
+use gauntlet_common::{settings_env_data_from_string, tr, SettingsEnvData};
 use gauntlet_common_ui::padding;
 use crate::theme::{Element, GauntletSettingsTheme};
 use crate::theme::button::ButtonStyle;
 use crate::theme::container::ContainerStyle;
 use crate::theme::text::TextStyle;
+use crate::views::crash_reports::{ManagementAppCrashReportsMsgIn, ManagementAppCrashReportsMsgOut, ManagementAppCrashReportsState};
+use crate::views::dev_console::{ManagementAppDevConsoleMsgIn, ManagementAppDevConsoleMsgOut, ManagementAppDevConsoleState};
 use crate::views::general::{ManagementAppGeneralMsgIn, ManagementAppGeneralMsgOut, ManagementAppGeneralState};
-use crate::views::plugins::{ManagementAppPluginMsgIn, ManagementAppPluginMsgOut, ManagementAppPluginsState};
+use crate::views::plugins::{selected_item, ManagementAppPluginMsgIn, ManagementAppPluginMsgOut, ManagementAppPluginsState};
+use crate::views::quicklinks::{ManagementAppQuicklinksMsgIn, ManagementAppQuicklinksMsgOut, ManagementAppQuicklinksState};
+use crate::views::troubleshooting::{ManagementAppTroubleshootingMsgIn, ManagementAppTroubleshootingMsgOut, ManagementAppTroubleshootingState};
+use crate::views::usage_stats::{ManagementAppUsageStatsMsgIn, ManagementAppUsageStatsMsgOut, ManagementAppUsageStatsState};
+
+const SETTINGS_ENV: &'static str = "GAUNTLET_INTERNAL_SETTINGS";
+
+pub const SETTINGS_WINDOW_SIZE: Size = Size::new(1000.0, 600.0);
 
 pub fn run() {
+    let settings_env_data = std::env::var(SETTINGS_ENV)
+        .ok()
+        .filter(|value| !value.is_empty())
+        .map(|val| settings_env_data_from_string(val));
+
     iced::application::<ManagementAppModel, ManagementAppMsg, GauntletSettingsTheme, Renderer>("Gauntlet Settings", update, view)
         .window(window::Settings {
-            size: Size::new(1000.0, 600.0),
+            size: SETTINGS_WINDOW_SIZE,
             ..Default::default()
         })
         .subscription(subscription)
         .theme(|_| GauntletSettingsTheme::default())
-        .run_with(new)
+        .run_with(move || {
+            let backend_api = futures::executor::block_on(async {
+                anyhow::Ok(BackendApi::new().await?)
+            })
+                .inspect_err(|err| tracing::error!("Unable to connect to server: {:?}", err))
+                .ok();
+
+            new(backend_api, settings_env_data)
+        })
         .expect("Unable to start settings application");
 }
 
-struct ManagementAppModel {
+pub(crate) struct ManagementAppModel {
     backend_api: Option<BackendApi>,
     error_view: Option<ErrorView>,
     downloads_info: HashMap<PluginId, DownloadInfo>,
     download_info_shown: bool,
     current_settings_view: SettingsView,
     general_state: ManagementAppGeneralState,
-    plugins_state: ManagementAppPluginsState
+    plugins_state: ManagementAppPluginsState,
+    quicklinks_state: ManagementAppQuicklinksState,
+    crash_reports_state: ManagementAppCrashReportsState,
+    usage_stats_state: ManagementAppUsageStatsState,
+    troubleshooting_state: ManagementAppTroubleshootingState,
+    dev_console_state: ManagementAppDevConsoleState,
 }
 
 
 #[derive(Debug, Clone)]
-enum ManagementAppMsg {
+pub(crate) enum ManagementAppMsg {
     FontLoaded(Result<(), font::Error>),
     General(ManagementAppGeneralMsgIn),
     Plugin(ManagementAppPluginMsgIn),
+    Quicklinks(ManagementAppQuicklinksMsgIn),
+    CrashReports(ManagementAppCrashReportsMsgIn),
+    UsageStats(ManagementAppUsageStatsMsgIn),
+    Troubleshooting(ManagementAppTroubleshootingMsgIn),
+    DevConsole(ManagementAppDevConsoleMsgIn),
     SwitchView(SettingsView),
     DownloadStatus { plugins: HashMap<PluginId, DownloadStatus> },
     HandleBackendError(BackendApiError),
     CheckDownloadStatus,
     DownloadPlugin { plugin_id: PluginId },
+    LocalPluginSaved { plugin_id: PluginId, result: Result<(), BackendApiError> },
     Noop,
     ToggleDownloadInfo,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-enum SettingsView {
+pub(crate) enum SettingsView {
     General,
-    Plugins
+    Plugins,
+    Quicklinks,
+    CrashReports,
+    UsageStats,
+    Troubleshooting,
+    DevConsole,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -78,13 +117,7 @@ pub enum DownloadInfo {
     Successful,
 }
 
-fn new() -> (ManagementAppModel, Task<ManagementAppMsg>) {
-    let backend_api = futures::executor::block_on(async {
-        anyhow::Ok(BackendApi::new().await?)
-    })
-        .inspect_err(|err| tracing::error!("Unable to connect to server: {:?}", err))
-        .ok();
-
+pub(crate) fn new(backend_api: Option<BackendApi>, settings_env_data: Option<SettingsEnvData>) -> (ManagementAppModel, Task<ManagementAppMsg>) {
     (
         ManagementAppModel {
             backend_api: backend_api.clone(),
@@ -93,11 +126,17 @@ fn new() -> (ManagementAppModel, Task<ManagementAppMsg>) {
             download_info_shown: false,
             current_settings_view: SettingsView::Plugins,
             general_state: ManagementAppGeneralState::new(backend_api.clone()),
-            plugins_state: ManagementAppPluginsState::new(backend_api.clone()),
+            plugins_state: ManagementAppPluginsState::new(backend_api.clone(), settings_env_data),
+            quicklinks_state: ManagementAppQuicklinksState::new(backend_api.clone()),
+            crash_reports_state: ManagementAppCrashReportsState::new(backend_api.clone()),
+            usage_stats_state: ManagementAppUsageStatsState::new(backend_api.clone()),
+            troubleshooting_state: ManagementAppTroubleshootingState::new(backend_api.clone()),
+            dev_console_state: ManagementAppDevConsoleState::new(backend_api.clone()),
         },
         Task::batch([
             font::load(BOOTSTRAP_FONT_BYTES).map(ManagementAppMsg::FontLoaded),
             Task::done(ManagementAppMsg::Plugin(ManagementAppPluginMsgIn::FetchPlugins)),
+            Task::done(ManagementAppMsg::Quicklinks(ManagementAppQuicklinksMsgIn::FetchQuicklinks)),
             Task::perform(
                 async {
                     match backend_api {
@@ -114,8 +153,13 @@ fn new() -> (ManagementAppModel, Task<ManagementAppMsg>) {
                                     ManagementAppMsg::General(ManagementAppGeneralMsgIn::InitSetting {
                                         theme: init.theme,
                                         window_position_mode: init.window_position_mode,
+                                        window_overlay: init.window_overlay,
+                                        density: init.density,
+                                        ui_scale: init.ui_scale,
+                                        search_results_sort: init.search_results_sort,
                                         shortcut: init.global_shortcut,
-                                        shortcut_error: init.global_shortcut_error
+                                        shortcut_error: init.global_shortcut_error,
+                                        autostart_enabled: init.autostart_enabled,
                                     })
                                 },
                                 Err(err) => ManagementAppMsg::HandleBackendError(err)
@@ -132,7 +176,12 @@ struct InitSettingsData {
     global_shortcut: Option<PhysicalShortcut>,
     global_shortcut_error: Option<String>,
     theme: SettingsTheme,
-    window_position_mode: WindowPositionMode
+    window_position_mode: WindowPositionMode,
+    window_overlay: bool,
+    density: Density,
+    ui_scale: UiScale,
+    search_results_sort: SearchResultsSort,
+    autostart_enabled: bool,
 }
 
 async fn init_data(mut backend_api: BackendApi) -> Result<InitSettingsData, BackendApiError> {
@@ -145,15 +194,35 @@ async fn init_data(mut backend_api: BackendApi) -> Result<InitSettingsData, Back
     let window_position_mode = backend_api.get_window_position_mode()
         .await?;
 
+    let window_overlay = backend_api.get_window_overlay()
+        .await?;
+
+    let density = backend_api.get_density()
+        .await?;
+
+    let ui_scale = backend_api.get_ui_scale()
+        .await?;
+
+    let search_results_sort = backend_api.get_search_results_sort()
+        .await?;
+
+    let autostart_enabled = backend_api.get_autostart_enabled()
+        .await?;
+
     Ok(InitSettingsData {
         global_shortcut,
         global_shortcut_error,
         theme,
-        window_position_mode
+        window_position_mode,
+        window_overlay,
+        density,
+        ui_scale,
+        search_results_sort,
+        autostart_enabled,
     })
 }
 
-fn update(state: &mut ManagementAppModel, message: ManagementAppMsg) -> Task<ManagementAppMsg> {
+pub(crate) fn update(state: &mut ManagementAppModel, message: ManagementAppMsg) -> Task<ManagementAppMsg> {
     let backend_api = match &state.backend_api {
         Some(backend_api) => backend_api.clone(),
         None => {
@@ -178,6 +247,9 @@ fn update(state: &mut ManagementAppModel, message: ManagementAppMsg) -> Task<Man
                         ManagementAppPluginMsgOut::SelectedItem(selected_item) => {
                             ManagementAppMsg::Plugin(ManagementAppPluginMsgIn::SelectItem(selected_item))
                         }
+                        ManagementAppPluginMsgOut::DiskUsageReloaded { plugin_id, usage } => {
+                            ManagementAppMsg::Plugin(ManagementAppPluginMsgIn::DiskUsageFetched { plugin_id, usage })
+                        }
                         ManagementAppPluginMsgOut::HandleBackendError(err) => {
                             ManagementAppMsg::HandleBackendError(err)
                         }
@@ -191,20 +263,103 @@ fn update(state: &mut ManagementAppModel, message: ManagementAppMsg) -> Task<Man
                         ManagementAppGeneralMsgOut::Noop => {
                             ManagementAppMsg::General(ManagementAppGeneralMsgIn::Noop)
                         },
+                        ManagementAppGeneralMsgOut::AutostartReloaded(enabled) => {
+                            ManagementAppMsg::General(ManagementAppGeneralMsgIn::AutostartFetched(enabled))
+                        },
                         ManagementAppGeneralMsgOut::HandleBackendError(err) => {
                             ManagementAppMsg::HandleBackendError(err)
                         }
                     }
                 })
         }
+        ManagementAppMsg::Quicklinks(message) => {
+            state.quicklinks_state.update(message)
+                .map(|msg| {
+                    match msg {
+                        ManagementAppQuicklinksMsgOut::Noop => {
+                            ManagementAppMsg::Quicklinks(ManagementAppQuicklinksMsgIn::Noop)
+                        }
+                        ManagementAppQuicklinksMsgOut::QuicklinksReloaded(quicklinks) => {
+                            ManagementAppMsg::Quicklinks(ManagementAppQuicklinksMsgIn::QuicklinksFetched(quicklinks))
+                        }
+                        ManagementAppQuicklinksMsgOut::HandleBackendError(err) => {
+                            ManagementAppMsg::HandleBackendError(err)
+                        }
+                    }
+                })
+        }
+        ManagementAppMsg::CrashReports(message) => {
+            state.crash_reports_state.update(message)
+                .map(|msg| {
+                    match msg {
+                        ManagementAppCrashReportsMsgOut::Noop => {
+                            ManagementAppMsg::CrashReports(ManagementAppCrashReportsMsgIn::Noop)
+                        }
+                        ManagementAppCrashReportsMsgOut::CrashReportsReloaded { enabled, crash_reports } => {
+                            ManagementAppMsg::CrashReports(ManagementAppCrashReportsMsgIn::CrashReportsFetched { enabled, crash_reports })
+                        }
+                        ManagementAppCrashReportsMsgOut::HandleBackendError(err) => {
+                            ManagementAppMsg::HandleBackendError(err)
+                        }
+                    }
+                })
+        }
+        ManagementAppMsg::UsageStats(message) => {
+            state.usage_stats_state.update(message)
+                .map(|msg| {
+                    match msg {
+                        ManagementAppUsageStatsMsgOut::UsageStatsReloaded { enabled, stats } => {
+                            ManagementAppMsg::UsageStats(ManagementAppUsageStatsMsgIn::UsageStatsFetched { enabled, stats })
+                        }
+                        ManagementAppUsageStatsMsgOut::HandleBackendError(err) => {
+                            ManagementAppMsg::HandleBackendError(err)
+                        }
+                    }
+                })
+        }
+        ManagementAppMsg::Troubleshooting(message) => {
+            state.troubleshooting_state.update(message)
+                .map(|msg| {
+                    match msg {
+                        ManagementAppTroubleshootingMsgOut::LogsReloaded(log_lines) => {
+                            ManagementAppMsg::Troubleshooting(ManagementAppTroubleshootingMsgIn::LogsFetched(log_lines))
+                        }
+                        ManagementAppTroubleshootingMsgOut::HandleBackendError(err) => {
+                            ManagementAppMsg::HandleBackendError(err)
+                        }
+                    }
+                })
+        }
+        ManagementAppMsg::DevConsole(message) => {
+            state.dev_console_state.update(message)
+                .map(|msg| {
+                    match msg {
+                        ManagementAppDevConsoleMsgOut::PluginsReloaded(plugins) => {
+                            ManagementAppMsg::DevConsole(ManagementAppDevConsoleMsgIn::PluginsFetched(plugins))
+                        }
+                        ManagementAppDevConsoleMsgOut::HandleBackendError(err) => {
+                            ManagementAppMsg::HandleBackendError(err)
+                        }
+                    }
+                })
+        }
         ManagementAppMsg::FontLoaded(result) => {
             result.expect("unable to load font");
             Task::none()
         }
         ManagementAppMsg::SwitchView(view) => {
+            // logs, crash reports and usage stats are fetched every time the view is opened, so that they are up-to-date
+            let task = match view {
+                SettingsView::CrashReports => Task::done(ManagementAppMsg::CrashReports(ManagementAppCrashReportsMsgIn::FetchCrashReports)),
+                SettingsView::UsageStats => Task::done(ManagementAppMsg::UsageStats(ManagementAppUsageStatsMsgIn::FetchUsageStats)),
+                SettingsView::Troubleshooting => Task::done(ManagementAppMsg::Troubleshooting(ManagementAppTroubleshootingMsgIn::FetchLogs)),
+                SettingsView::DevConsole => Task::done(ManagementAppMsg::DevConsole(ManagementAppDevConsoleMsgIn::FetchPlugins)),
+                _ => Task::none()
+            };
+
             state.current_settings_view = view;
 
-            Task::none()
+            task
         }
         ManagementAppMsg::HandleBackendError(err) => {
             state.error_view = Some(match err {
@@ -266,6 +421,18 @@ fn update(state: &mut ManagementAppModel, message: ManagementAppMsg) -> Task<Man
 
             if already_downloading {
                 Task::none()
+            } else if let Ok(path) = plugin_id.try_to_path() {
+                // local plugins are saved right away, problems with plugin manifest are shown the same way as download errors
+                Task::perform(
+                    async move {
+                        let result = backend_client.save_local_plugin(path.display().to_string())
+                            .await
+                            .map(|_| ());
+
+                        (plugin_id, result)
+                    },
+                    |(plugin_id, result)| ManagementAppMsg::LocalPluginSaved { plugin_id, result }
+                )
             } else {
                 Task::perform(
                     async move {
@@ -278,6 +445,35 @@ fn update(state: &mut ManagementAppModel, message: ManagementAppMsg) -> Task<Man
                 )
             }
         }
+        ManagementAppMsg::LocalPluginSaved { plugin_id, result } => {
+            match result {
+                Ok(()) => {
+                    state.downloads_info.insert(plugin_id, DownloadInfo::Successful);
+
+                    let mut backend_api = backend_api.clone();
+
+                    Task::perform(
+                        async move {
+                            let plugins = backend_api.plugins()
+                                .await?;
+
+                            Ok(plugins)
+                        },
+                        |result| handle_backend_error(result, |plugins| ManagementAppMsg::Plugin(ManagementAppPluginMsgIn::PluginsFetched(plugins)))
+                    )
+                }
+                Err(err) => {
+                    let message = match err {
+                        BackendApiError::Timeout => tr!("error-backend-timeout"),
+                        BackendApiError::Internal { display } => display,
+                    };
+
+                    state.downloads_info.insert(plugin_id, DownloadInfo::Error { message });
+
+                    Task::none()
+                }
+            }
+        }
         ManagementAppMsg::Noop => Task::none(),
         ManagementAppMsg::ToggleDownloadInfo => {
             state.download_info_shown = !state.download_info_shown;
@@ -286,9 +482,9 @@ fn update(state: &mut ManagementAppModel, message: ManagementAppMsg) -> Task<Man
     }
 }
 
-fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
+pub(crate) fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
     if let None = &state.backend_api {
-        let description: Element<_> = text("Unable to connect to server. Please check if you have Gauntlet running on your PC")
+        let description: Element<_> = text(tr!("settings-unable-to-connect"))
             .into();
 
         let content: Element<_> = container(description)
@@ -304,7 +500,7 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
     if let Some(err) = &state.error_view {
         return match err {
             ErrorView::Timeout => {
-                let description: Element<_> = text("Error occurred")
+                let description: Element<_> = text(tr!("error-occurred"))
                     .into();
 
                 let description = container(description)
@@ -313,7 +509,7 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
                     .padding(12)
                     .into();
 
-                let sub_description: Element<_> = text("Backend was unable to process message in a timely manner")
+                let sub_description: Element<_> = text(tr!("error-backend-timeout"))
                     .into();
 
                 let sub_description = container(sub_description)
@@ -337,7 +533,7 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
                 content
             }
             ErrorView::UnknownError { display } => {
-                let description: Element<_> = text("Unknown error occurred")
+                let description: Element<_> = text(tr!("error-unknown"))
                     .into();
 
                 let description = container(description)
@@ -346,7 +542,7 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
                     .padding(12)
                     .into();
 
-                let sub_description: Element<_> = text("Please report")
+                let sub_description: Element<_> = text(tr!("error-please-report"))
                     .into();
 
                 let sub_description = container(sub_description)
@@ -393,6 +589,26 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
             state.plugins_state.view()
                 .map(|msg| ManagementAppMsg::Plugin(msg))
         }
+        SettingsView::Quicklinks => {
+            state.quicklinks_state.view()
+                .map(|msg| ManagementAppMsg::Quicklinks(msg))
+        }
+        SettingsView::CrashReports => {
+            state.crash_reports_state.view()
+                .map(|msg| ManagementAppMsg::CrashReports(msg))
+        }
+        SettingsView::UsageStats => {
+            state.usage_stats_state.view()
+                .map(|msg| ManagementAppMsg::UsageStats(msg))
+        }
+        SettingsView::Troubleshooting => {
+            state.troubleshooting_state.view()
+                .map(|msg| ManagementAppMsg::Troubleshooting(msg))
+        }
+        SettingsView::DevConsole => {
+            state.dev_console_state.view()
+                .map(|msg| ManagementAppMsg::DevConsole(msg))
+        }
     };
 
     let icon_general: Element<_> = value(Bootstrap::GearFill)
@@ -403,7 +619,7 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
         .align_x(alignment::Horizontal::Center)
         .into();
 
-    let text_general: Element<_> = text("General")
+    let text_general: Element<_> = text(tr!("settings-tab-general"))
         .height(Length::Fill)
         .align_y(alignment::Vertical::Center)
         .align_x(alignment::Horizontal::Center)
@@ -434,7 +650,7 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
         .align_x(alignment::Horizontal::Center)
         .into();
 
-    let text_plugins: Element<_> = text("Plugins")
+    let text_plugins: Element<_> = text(tr!("settings-tab-plugins"))
         .height(Length::Fill)
         .align_y(alignment::Vertical::Center)
         .align_x(alignment::Horizontal::Center)
@@ -457,7 +673,162 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
         .padding(8.0)
         .into();
 
-    let top_bar_buttons: Element<_> = row(vec![general_button, plugins_button])
+    let icon_quicklinks: Element<_> = value(Bootstrap::Link45deg)
+        .font(BOOTSTRAP_FONT)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .align_y(alignment::Vertical::Center)
+        .align_x(alignment::Horizontal::Center)
+        .into();
+
+    let text_quicklinks: Element<_> = text(tr!("settings-tab-quicklinks"))
+        .height(Length::Fill)
+        .align_y(alignment::Vertical::Center)
+        .align_x(alignment::Horizontal::Center)
+        .into();
+
+    let quicklinks_button: Element<_> = column(vec![icon_quicklinks, text_quicklinks])
+        .align_x(Alignment::Center)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .into();
+
+    let quicklinks_button: Element<_> = button(quicklinks_button)
+        .on_press(ManagementAppMsg::SwitchView(SettingsView::Quicklinks))
+        .height(Length::Fill)
+        .width(80)
+        .class(if state.current_settings_view == SettingsView::Quicklinks { ButtonStyle::ViewSwitcherSelected } else { ButtonStyle::ViewSwitcher })
+        .into();
+
+    let quicklinks_button: Element<_> = container(quicklinks_button)
+        .padding(8.0)
+        .into();
+
+    let icon_crash_reports: Element<_> = value(Bootstrap::ExclamationTriangleFill)
+        .font(BOOTSTRAP_FONT)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .align_y(alignment::Vertical::Center)
+        .align_x(alignment::Horizontal::Center)
+        .into();
+
+    let text_crash_reports: Element<_> = text(tr!("settings-tab-crash-reports"))
+        .height(Length::Fill)
+        .align_y(alignment::Vertical::Center)
+        .align_x(alignment::Horizontal::Center)
+        .into();
+
+    let crash_reports_button: Element<_> = column(vec![icon_crash_reports, text_crash_reports])
+        .align_x(Alignment::Center)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .into();
+
+    let crash_reports_button: Element<_> = button(crash_reports_button)
+        .on_press(ManagementAppMsg::SwitchView(SettingsView::CrashReports))
+        .height(Length::Fill)
+        .width(120)
+        .class(if state.current_settings_view == SettingsView::CrashReports { ButtonStyle::ViewSwitcherSelected } else { ButtonStyle::ViewSwitcher })
+        .into();
+
+    let crash_reports_button: Element<_> = container(crash_reports_button)
+        .padding(8.0)
+        .into();
+
+    let icon_usage_stats: Element<_> = value(Bootstrap::BarChartFill)
+        .font(BOOTSTRAP_FONT)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .align_y(alignment::Vertical::Center)
+        .align_x(alignment::Horizontal::Center)
+        .into();
+
+    let text_usage_stats: Element<_> = text(tr!("settings-tab-usage-stats"))
+        .height(Length::Fill)
+        .align_y(alignment::Vertical::Center)
+        .align_x(alignment::Horizontal::Center)
+        .into();
+
+    let usage_stats_button: Element<_> = column(vec![icon_usage_stats, text_usage_stats])
+        .align_x(Alignment::Center)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .into();
+
+    let usage_stats_button: Element<_> = button(usage_stats_button)
+        .on_press(ManagementAppMsg::SwitchView(SettingsView::UsageStats))
+        .height(Length::Fill)
+        .width(120)
+        .class(if state.current_settings_view == SettingsView::UsageStats { ButtonStyle::ViewSwitcherSelected } else { ButtonStyle::ViewSwitcher })
+        .into();
+
+    let usage_stats_button: Element<_> = container(usage_stats_button)
+        .padding(8.0)
+        .into();
+
+    let icon_troubleshooting: Element<_> = value(Bootstrap::BugFill)
+        .font(BOOTSTRAP_FONT)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .align_y(alignment::Vertical::Center)
+        .align_x(alignment::Horizontal::Center)
+        .into();
+
+    let text_troubleshooting: Element<_> = text(tr!("settings-tab-troubleshooting"))
+        .height(Length::Fill)
+        .align_y(alignment::Vertical::Center)
+        .align_x(alignment::Horizontal::Center)
+        .into();
+
+    let troubleshooting_button: Element<_> = column(vec![icon_troubleshooting, text_troubleshooting])
+        .align_x(Alignment::Center)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .into();
+
+    let troubleshooting_button: Element<_> = button(troubleshooting_button)
+        .on_press(ManagementAppMsg::SwitchView(SettingsView::Troubleshooting))
+        .height(Length::Fill)
+        .width(120)
+        .class(if state.current_settings_view == SettingsView::Troubleshooting { ButtonStyle::ViewSwitcherSelected } else { ButtonStyle::ViewSwitcher })
+        .into();
+
+    let troubleshooting_button: Element<_> = container(troubleshooting_button)
+        .padding(8.0)
+        .into();
+
+    let icon_dev_console: Element<_> = value(Bootstrap::TerminalFill)
+        .font(BOOTSTRAP_FONT)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .align_y(alignment::Vertical::Center)
+        .align_x(alignment::Horizontal::Center)
+        .into();
+
+    let text_dev_console: Element<_> = text(tr!("settings-tab-dev-console"))
+        .height(Length::Fill)
+        .align_y(alignment::Vertical::Center)
+        .align_x(alignment::Horizontal::Center)
+        .into();
+
+    let dev_console_button: Element<_> = column(vec![icon_dev_console, text_dev_console])
+        .align_x(Alignment::Center)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .into();
+
+    let dev_console_button: Element<_> = button(dev_console_button)
+        .on_press(ManagementAppMsg::SwitchView(SettingsView::DevConsole))
+        .height(Length::Fill)
+        .width(120)
+        .class(if state.current_settings_view == SettingsView::DevConsole { ButtonStyle::ViewSwitcherSelected } else { ButtonStyle::ViewSwitcher })
+        .into();
+
+    let dev_console_button: Element<_> = container(dev_console_button)
+        .padding(8.0)
+        .into();
+
+    let top_bar_buttons: Element<_> = row(vec![general_button, plugins_button, quicklinks_button, crash_reports_button, usage_stats_button, troubleshooting_button, dev_console_button])
         .into();
 
     let top_bar_buttons: Element<_> = container(top_bar_buttons)
@@ -618,7 +989,7 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
             .map(|(plugin_id, info)| {
                 match info {
                     DownloadInfo::InProgress => {
-                        let kind_text: Element<_> = text("Download in progress")
+                        let kind_text: Element<_> = text(tr!("settings-download-in-progress"))
                             .into();
 
                         let kind_text: Element<_> = container(kind_text)
@@ -654,7 +1025,7 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
                             .into()
                     }
                     DownloadInfo::Error { message } => {
-                        let kind_text: Element<_> = text("Download failed")
+                        let kind_text: Element<_> = text(tr!("settings-download-failed"))
                             .into();
 
                         let kind_text: Element<_> = container(kind_text)
@@ -697,7 +1068,7 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
                             .into()
                     }
                     DownloadInfo::Successful => {
-                        let kind_text: Element<_> = text("Download successful")
+                        let kind_text: Element<_> = text(tr!("settings-download-successful"))
                             .into();
 
                         let kind_text: Element<_> = container(kind_text)
@@ -775,12 +1146,29 @@ fn view(state: &ManagementAppModel) -> Element<'_, ManagementAppMsg> {
         .into()
 }
 
-fn subscription(_state: &ManagementAppModel) -> Subscription<ManagementAppMsg> {
-    time::every(Duration::from_millis(300))
-        .map(|_| ManagementAppMsg::CheckDownloadStatus)
+pub(crate) fn subscription(state: &ManagementAppModel) -> Subscription<ManagementAppMsg> {
+    // dev console stream is only kept open while the view is shown
+    let dev_console = match state.current_settings_view {
+        SettingsView::DevConsole => state.dev_console_state.subscription().map(ManagementAppMsg::DevConsole),
+        _ => Subscription::none()
+    };
+
+    Subscription::batch([
+        time::every(Duration::from_millis(300))
+            .map(|_| ManagementAppMsg::CheckDownloadStatus),
+        dev_console,
+    ])
 }
 
 
+// used when settings are already shown and another plugin or entrypoint is requested to be opened
+pub(crate) fn open_preferences(settings_env_data: SettingsEnvData) -> Task<ManagementAppMsg> {
+    Task::batch([
+        Task::done(ManagementAppMsg::SwitchView(SettingsView::Plugins)),
+        Task::done(ManagementAppMsg::Plugin(ManagementAppPluginMsgIn::SelectItem(selected_item(Some(settings_env_data))))),
+    ])
+}
+
 pub fn handle_backend_error<T>(result: Result<T, BackendApiError>, convert: impl FnOnce(T) -> ManagementAppMsg) -> ManagementAppMsg {
     match result {
         Ok(val) => convert(val),
@@ -0,0 +1,283 @@
+use crate::theme::button::ButtonStyle;
+use crate::theme::text::TextStyle;
+use crate::theme::Element;
+use gauntlet_common::dirs::Dirs;
+use gauntlet_common::model::SettingsCrashReport;
+use gauntlet_common::rpc::backend_api::{BackendApi, BackendApiError};
+use gauntlet_common::tr;
+use iced::widget::text::Shaping;
+use iced::widget::{button, checkbox, column, container, horizontal_rule, row, scrollable, text, value};
+use iced::{Alignment, Font, Length, Padding, Task};
+use iced_fonts::{Bootstrap, BOOTSTRAP_FONT};
+
+pub struct ManagementAppCrashReportsState {
+    backend_api: Option<BackendApi>,
+    enabled: bool,
+    crash_reports: Vec<SettingsCrashReport>,
+    selected_id: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum ManagementAppCrashReportsMsgIn {
+    FetchCrashReports,
+    CrashReportsFetched {
+        enabled: bool,
+        crash_reports: Vec<SettingsCrashReport>
+    },
+    ToggleEnabled(bool),
+    SelectCrashReport { id: String },
+    SubmitCrashReport { id: String },
+    RemoveCrashReport { id: String },
+    Noop
+}
+
+#[derive(Debug, Clone)]
+pub enum ManagementAppCrashReportsMsgOut {
+    Noop,
+    CrashReportsReloaded {
+        enabled: bool,
+        crash_reports: Vec<SettingsCrashReport>
+    },
+    HandleBackendError(BackendApiError)
+}
+
+impl ManagementAppCrashReportsState {
+    pub fn new(backend_api: Option<BackendApi>) -> Self {
+        Self {
+            backend_api,
+            enabled: false,
+            crash_reports: vec![],
+            selected_id: None,
+        }
+    }
+
+    pub fn update(&mut self, message: ManagementAppCrashReportsMsgIn) -> Task<ManagementAppCrashReportsMsgOut> {
+        let backend_api = match &self.backend_api {
+            Some(backend_api) => backend_api.clone(),
+            None => {
+                return Task::none()
+            }
+        };
+
+        match message {
+            ManagementAppCrashReportsMsgIn::FetchCrashReports => {
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    fetch_crash_reports(&mut backend_api).await
+                }, |result| handle_backend_error(result, |(enabled, crash_reports)| ManagementAppCrashReportsMsgOut::CrashReportsReloaded { enabled, crash_reports }))
+            }
+            ManagementAppCrashReportsMsgIn::CrashReportsFetched { enabled, crash_reports } => {
+                if let Some(selected_id) = &self.selected_id {
+                    if !crash_reports.iter().any(|crash_report| &crash_report.id == selected_id) {
+                        self.selected_id = None;
+                    }
+                }
+
+                self.enabled = enabled;
+                self.crash_reports = crash_reports;
+
+                Task::none()
+            }
+            ManagementAppCrashReportsMsgIn::ToggleEnabled(enabled) => {
+                self.enabled = enabled;
+
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.set_crash_reports_enabled(enabled)
+                        .await?;
+
+                    fetch_crash_reports(&mut backend_api).await
+                }, |result| handle_backend_error(result, |(enabled, crash_reports)| ManagementAppCrashReportsMsgOut::CrashReportsReloaded { enabled, crash_reports }))
+            }
+            ManagementAppCrashReportsMsgIn::SelectCrashReport { id } => {
+                if self.selected_id.as_ref() == Some(&id) {
+                    self.selected_id = None;
+                } else {
+                    self.selected_id = Some(id);
+                }
+
+                Task::none()
+            }
+            ManagementAppCrashReportsMsgIn::SubmitCrashReport { id } => {
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.submit_crash_report(id)
+                        .await?;
+
+                    Ok(())
+                }, |result| handle_backend_error(result, |()| ManagementAppCrashReportsMsgOut::Noop))
+            }
+            ManagementAppCrashReportsMsgIn::RemoveCrashReport { id } => {
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.remove_crash_report(id)
+                        .await?;
+
+                    fetch_crash_reports(&mut backend_api).await
+                }, |result| handle_backend_error(result, |(enabled, crash_reports)| ManagementAppCrashReportsMsgOut::CrashReportsReloaded { enabled, crash_reports }))
+            }
+            ManagementAppCrashReportsMsgIn::Noop => {
+                Task::none()
+            }
+        }
+    }
+
+    pub fn view(&self) -> Element<ManagementAppCrashReportsMsgIn> {
+        let enabled_checkbox: Element<_> = checkbox(tr!("settings-crash-reports-enabled"), self.enabled)
+            .on_toggle(ManagementAppCrashReportsMsgIn::ToggleEnabled)
+            .into();
+
+        let crash_dir = Dirs::new().crash_dir();
+
+        let description: Element<_> = text(tr!("settings-crash-reports-description", path = crash_dir.display().to_string()))
+            .shaping(Shaping::Advanced)
+            .class(TextStyle::Subtitle)
+            .into();
+
+        let header: Element<_> = column(vec![enabled_checkbox, description])
+            .spacing(8.0)
+            .padding(8.0)
+            .into();
+
+        let separator: Element<_> = horizontal_rule(1)
+            .into();
+
+        let list: Element<_> = if self.crash_reports.is_empty() {
+            let empty: Element<_> = text(tr!("settings-crash-reports-empty"))
+                .class(TextStyle::Subtitle)
+                .into();
+
+            container(empty)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .align_x(Alignment::Center)
+                .align_y(Alignment::Center)
+                .into()
+        } else {
+            let crash_reports: Vec<Element<_>> = self.crash_reports.iter()
+                .map(|crash_report| self.view_crash_report(crash_report))
+                .collect();
+
+            let list: Element<_> = column(crash_reports)
+                .into();
+
+            scrollable(list)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .into()
+        };
+
+        let content: Element<_> = column(vec![header, separator, list])
+            .into();
+
+        container(content)
+            .padding(Padding::new(4.0))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+
+    fn view_crash_report<'a>(&'a self, crash_report: &'a SettingsCrashReport) -> Element<'a, ManagementAppCrashReportsMsgIn> {
+        let selected = self.selected_id.as_ref() == Some(&crash_report.id);
+
+        let message = crash_report_field(&crash_report.content, "Message")
+            .unwrap_or_default();
+
+        let process = crash_report_field(&crash_report.content, "Process")
+            .unwrap_or_default();
+
+        let chevron: Element<_> = value(if selected { Bootstrap::ChevronDown } else { Bootstrap::ChevronRight })
+            .font(BOOTSTRAP_FONT)
+            .into();
+
+        let message: Element<_> = text(message)
+            .shaping(Shaping::Advanced)
+            .into();
+
+        let subtitle: Element<_> = text(format!("{} - {}", process, &crash_report.id))
+            .shaping(Shaping::Advanced)
+            .class(TextStyle::Subtitle)
+            .into();
+
+        let title: Element<_> = column(vec![message, subtitle])
+            .width(Length::Fill)
+            .into();
+
+        let title: Element<_> = row(vec![chevron, title])
+            .spacing(8.0)
+            .align_y(Alignment::Center)
+            .into();
+
+        let title: Element<_> = button(title)
+            .class(ButtonStyle::TableRow)
+            .width(Length::Fill)
+            .on_press(ManagementAppCrashReportsMsgIn::SelectCrashReport { id: crash_report.id.clone() })
+            .into();
+
+        let submit_button: Element<_> = button(text(tr!("settings-crash-reports-submit")))
+            .class(ButtonStyle::Primary)
+            .on_press(ManagementAppCrashReportsMsgIn::SubmitCrashReport { id: crash_report.id.clone() })
+            .into();
+
+        let remove_icon: Element<_> = value(Bootstrap::TrashFill)
+            .font(BOOTSTRAP_FONT)
+            .into();
+
+        let remove_button: Element<_> = button(remove_icon)
+            .class(ButtonStyle::Destructive)
+            .on_press(ManagementAppCrashReportsMsgIn::RemoveCrashReport { id: crash_report.id.clone() })
+            .into();
+
+        let header: Element<_> = row(vec![title, submit_button, remove_button])
+            .spacing(8.0)
+            .padding(8.0)
+            .align_y(Alignment::Center)
+            .into();
+
+        if selected {
+            let content: Element<_> = text(&crash_report.content)
+                .shaping(Shaping::Advanced)
+                .font(Font::MONOSPACE)
+                .size(12)
+                .into();
+
+            let content: Element<_> = container(content)
+                .padding(Padding::new(8.0).left(32.0))
+                .width(Length::Fill)
+                .into();
+
+            column(vec![header, content])
+                .into()
+        } else {
+            header
+        }
+    }
+}
+
+async fn fetch_crash_reports(backend_api: &mut BackendApi) -> Result<(bool, Vec<SettingsCrashReport>), BackendApiError> {
+    let enabled = backend_api.get_crash_reports_enabled()
+        .await?;
+
+    let crash_reports = backend_api.crash_reports()
+        .await?;
+
+    Ok((enabled, crash_reports))
+}
+
+// report starts with "Name: value" lines
+fn crash_report_field(content: &str, name: &str) -> Option<String> {
+    content.lines()
+        .find_map(|line| line.strip_prefix(&format!("{}: ", name)))
+        .map(|value| value.to_string())
+}
+
+pub fn handle_backend_error<T>(result: Result<T, BackendApiError>, convert: impl FnOnce(T) -> ManagementAppCrashReportsMsgOut) -> ManagementAppCrashReportsMsgOut {
+    match result {
+        Ok(val) => convert(val),
+        Err(err) => ManagementAppCrashReportsMsgOut::HandleBackendError(err)
+    }
+}
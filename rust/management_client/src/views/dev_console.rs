@@ -0,0 +1,283 @@
+use std::fmt::{Display, Formatter};
+
+use crate::theme::button::ButtonStyle;
+use crate::theme::text::TextStyle;
+use crate::theme::Element;
+use gauntlet_common::model::{DevConsoleEvent, DevConsoleEventKind, PluginId};
+use gauntlet_common::rpc::backend_api::{BackendApi, BackendApiError};
+use gauntlet_common::tr;
+use iced::futures::SinkExt;
+use iced::widget::text::Shaping;
+use iced::widget::{button, column, container, horizontal_rule, horizontal_space, pick_list, row, scrollable, text, value};
+use iced::{stream, Alignment, Font, Length, Padding, Subscription, Task};
+use iced_fonts::{Bootstrap, BOOTSTRAP_FONT};
+use itertools::Itertools;
+
+const EVENTS_LIMIT: usize = 1000;
+
+pub struct ManagementAppDevConsoleState {
+    backend_api: Option<BackendApi>,
+    plugins: Vec<DevConsolePlugin>,
+    selected_plugin: Option<DevConsolePlugin>,
+    events: Vec<DevConsoleEvent>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DevConsolePlugin {
+    plugin_id: PluginId,
+    plugin_name: String,
+}
+
+impl Display for DevConsolePlugin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.plugin_name)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ManagementAppDevConsoleMsgIn {
+    FetchPlugins,
+    PluginsFetched(Vec<DevConsolePlugin>),
+    SelectPlugin(DevConsolePlugin),
+    Event(DevConsoleEvent),
+    StreamError(BackendApiError),
+    ClearEvents,
+}
+
+#[derive(Debug, Clone)]
+pub enum ManagementAppDevConsoleMsgOut {
+    PluginsReloaded(Vec<DevConsolePlugin>),
+    HandleBackendError(BackendApiError)
+}
+
+impl ManagementAppDevConsoleState {
+    pub fn new(backend_api: Option<BackendApi>) -> Self {
+        Self {
+            backend_api,
+            plugins: vec![],
+            selected_plugin: None,
+            events: vec![],
+        }
+    }
+
+    pub fn update(&mut self, message: ManagementAppDevConsoleMsgIn) -> Task<ManagementAppDevConsoleMsgOut> {
+        let backend_api = match &self.backend_api {
+            Some(backend_api) => backend_api.clone(),
+            None => {
+                return Task::none()
+            }
+        };
+
+        match message {
+            ManagementAppDevConsoleMsgIn::FetchPlugins => {
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    let plugins = backend_api.plugins()
+                        .await?
+                        .into_values()
+                        .map(|plugin| DevConsolePlugin { plugin_id: plugin.plugin_id, plugin_name: plugin.plugin_name })
+                        .sorted_by(|a, b| a.plugin_name.cmp(&b.plugin_name))
+                        .collect();
+
+                    Ok(plugins)
+                }, |result| handle_backend_error(result, |plugins| ManagementAppDevConsoleMsgOut::PluginsReloaded(plugins)))
+            }
+            ManagementAppDevConsoleMsgIn::PluginsFetched(plugins) => {
+                if let Some(selected_plugin) = &self.selected_plugin {
+                    if !plugins.contains(selected_plugin) {
+                        self.selected_plugin = None;
+                    }
+                }
+
+                self.plugins = plugins;
+
+                Task::none()
+            }
+            ManagementAppDevConsoleMsgIn::SelectPlugin(plugin) => {
+                if self.selected_plugin.as_ref() != Some(&plugin) {
+                    self.selected_plugin = Some(plugin);
+                    self.events.clear();
+                }
+
+                Task::none()
+            }
+            ManagementAppDevConsoleMsgIn::Event(event) => {
+                if self.events.len() >= EVENTS_LIMIT {
+                    self.events.remove(0);
+                }
+
+                self.events.push(event);
+
+                Task::none()
+            }
+            ManagementAppDevConsoleMsgIn::StreamError(err) => {
+                Task::done(ManagementAppDevConsoleMsgOut::HandleBackendError(err))
+            }
+            ManagementAppDevConsoleMsgIn::ClearEvents => {
+                self.events.clear();
+
+                Task::none()
+            }
+        }
+    }
+
+    pub fn subscription(&self) -> Subscription<ManagementAppDevConsoleMsgIn> {
+        let (Some(backend_api), Some(selected_plugin)) = (&self.backend_api, &self.selected_plugin) else {
+            return Subscription::none()
+        };
+
+        struct DevConsoleListener;
+
+        let mut backend_api = backend_api.clone();
+        let plugin_id = selected_plugin.plugin_id.clone();
+
+        // new stream is opened every time selected plugin changes
+        Subscription::run_with_id(
+            (std::any::TypeId::of::<DevConsoleListener>(), plugin_id.to_string()),
+            stream::channel(
+                100,
+                |mut sender| async move {
+                    let error = match backend_api.dev_console(plugin_id).await {
+                        Ok(mut events) => {
+                            loop {
+                                match events.next().await {
+                                    Ok(Some(event)) => {
+                                        if let Err(err) = sender.send(ManagementAppDevConsoleMsgIn::Event(event)).await {
+                                            tracing::warn!("error occurred when receiving dev console event {:?}", err)
+                                        }
+                                    }
+                                    Ok(None) => break None,
+                                    Err(err) => break Some(err)
+                                }
+                            }
+                        }
+                        Err(err) => Some(err)
+                    };
+
+                    if let Some(error) = error {
+                        let _ = sender.send(ManagementAppDevConsoleMsgIn::StreamError(error)).await;
+                    }
+
+                    std::future::pending::<()>().await;
+
+                    unreachable!()
+                },
+            )
+        )
+    }
+
+    pub fn view(&self) -> Element<ManagementAppDevConsoleMsgIn> {
+        let plugin_field: Element<_> = pick_list(
+            self.plugins.as_slice(),
+            self.selected_plugin.clone(),
+            ManagementAppDevConsoleMsgIn::SelectPlugin,
+        )
+            .placeholder(tr!("settings-dev-console-select-plugin"))
+            .into();
+
+        let space: Element<_> = horizontal_space()
+            .into();
+
+        let clear_icon: Element<_> = value(Bootstrap::Trash)
+            .font(BOOTSTRAP_FONT)
+            .into();
+
+        let clear_text: Element<_> = text(tr!("settings-dev-console-clear"))
+            .into();
+
+        let clear_content: Element<_> = row(vec![clear_icon, clear_text])
+            .spacing(8.0)
+            .align_y(Alignment::Center)
+            .into();
+
+        let clear_button: Element<_> = button(clear_content)
+            .class(ButtonStyle::Primary)
+            .on_press(ManagementAppDevConsoleMsgIn::ClearEvents)
+            .into();
+
+        let header: Element<_> = row(vec![plugin_field, space, clear_button])
+            .spacing(8.0)
+            .padding(8.0)
+            .align_y(Alignment::Center)
+            .into();
+
+        let separator: Element<_> = horizontal_rule(1)
+            .into();
+
+        let events: Element<_> = if self.events.is_empty() {
+            let empty_text = match self.selected_plugin {
+                None => tr!("settings-dev-console-no-plugin"),
+                Some(_) => tr!("settings-dev-console-no-events"),
+            };
+
+            let empty: Element<_> = text(empty_text)
+                .class(TextStyle::Subtitle)
+                .into();
+
+            container(empty)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .align_x(Alignment::Center)
+                .align_y(Alignment::Center)
+                .into()
+        } else {
+            let events: Vec<Element<_>> = self.events.iter()
+                .map(|event| view_event(event))
+                .collect();
+
+            let events: Element<_> = column(events)
+                .padding(8.0)
+                .width(Length::Fill)
+                .into();
+
+            scrollable(events)
+                .anchor_bottom()
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .into()
+        };
+
+        let content: Element<_> = column(vec![header, separator, events])
+            .into();
+
+        container(content)
+            .padding(Padding::new(4.0))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+}
+
+fn view_event(event: &DevConsoleEvent) -> Element<ManagementAppDevConsoleMsgIn> {
+    let (kind, class) = match event.kind {
+        DevConsoleEventKind::Stdout => ("stdout", TextStyle::Default),
+        DevConsoleEventKind::Stderr => ("stderr", TextStyle::Destructive),
+        DevConsoleEventKind::Render => ("render", TextStyle::Positive),
+        DevConsoleEventKind::ViewEvent => ("event", TextStyle::Subtitle),
+    };
+
+    let kind: Element<_> = text(format!("[{}]", kind))
+        .font(Font::MONOSPACE)
+        .size(12)
+        .width(72)
+        .class(class)
+        .into();
+
+    let message: Element<_> = text(&event.message)
+        .shaping(Shaping::Advanced)
+        .font(Font::MONOSPACE)
+        .size(12)
+        .width(Length::Fill)
+        .into();
+
+    row(vec![kind, message])
+        .into()
+}
+
+pub fn handle_backend_error<T>(result: Result<T, BackendApiError>, convert: impl FnOnce(T) -> ManagementAppDevConsoleMsgOut) -> ManagementAppDevConsoleMsgOut {
+    match result {
+        Ok(val) => convert(val),
+        Err(err) => ManagementAppDevConsoleMsgOut::HandleBackendError(err)
+    }
+}
@@ -1,12 +1,13 @@
 use crate::components::shortcut_selector::ShortcutSelector;
 use crate::theme::text::TextStyle;
 use crate::theme::Element;
-use gauntlet_common::model::{PhysicalShortcut, SettingsTheme, WindowPositionMode};
+use gauntlet_common::model::{Density, PhysicalShortcut, SearchResultsSort, SettingsTheme, UiScale, WindowPositionMode};
 use gauntlet_common::rpc::backend_api::{BackendApi, BackendApiError};
+use gauntlet_common::tr;
 use iced::alignment::Horizontal;
 use iced::widget::text::Shaping;
 use iced::widget::tooltip::Position;
-use iced::widget::{column, container, pick_list, row, text, tooltip, value, Space};
+use iced::widget::{checkbox, column, container, pick_list, row, text, tooltip, value, Space};
 use iced::{alignment, Alignment, Length, Padding, Task};
 use iced_fonts::{Bootstrap, BOOTSTRAP_FONT};
 use crate::theme::container::ContainerStyle;
@@ -15,9 +16,14 @@ pub struct ManagementAppGeneralState {
     backend_api: Option<BackendApi>,
     theme: SettingsTheme,
     window_position_mode: WindowPositionMode,
+    window_overlay: bool,
+    density: Density,
+    ui_scale: UiScale,
+    search_results_sort: SearchResultsSort,
     current_shortcut: Option<PhysicalShortcut>,
     current_shortcut_error: Option<String>,
-    currently_capturing: bool
+    currently_capturing: bool,
+    autostart_enabled: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -26,11 +32,22 @@ pub enum ManagementAppGeneralMsgIn {
     CapturingChanged(bool),
     ThemeChanged(SettingsTheme),
     WindowPositionModeChanged(WindowPositionMode),
+    WindowOverlayChanged(bool),
+    DensityChanged(Density),
+    UiScaleChanged(UiScale),
+    SearchResultsSortChanged(SearchResultsSort),
+    AutostartChanged(bool),
+    AutostartFetched(bool),
     InitSetting {
         theme: SettingsTheme,
         window_position_mode: WindowPositionMode,
+        window_overlay: bool,
+        density: Density,
+        ui_scale: UiScale,
+        search_results_sort: SearchResultsSort,
         shortcut: Option<PhysicalShortcut>,
-        shortcut_error: Option<String>
+        shortcut_error: Option<String>,
+        autostart_enabled: bool,
     },
     Noop
 }
@@ -38,6 +55,7 @@ pub enum ManagementAppGeneralMsgIn {
 #[derive(Debug, Clone)]
 pub enum ManagementAppGeneralMsgOut {
     Noop,
+    AutostartReloaded(bool),
     HandleBackendError(BackendApiError)
 }
 
@@ -47,9 +65,14 @@ impl ManagementAppGeneralState {
             backend_api,
             theme: SettingsTheme::AutoDetect,
             window_position_mode: WindowPositionMode::Static,
+            window_overlay: false,
+            density: Density::Comfortable,
+            ui_scale: UiScale::Auto,
+            search_results_sort: SearchResultsSort::Frecency,
             current_shortcut: None,
             current_shortcut_error: None,
             currently_capturing: false,
+            autostart_enabled: false,
         }
     }
 
@@ -77,11 +100,16 @@ impl ManagementAppGeneralState {
             ManagementAppGeneralMsgIn::Noop => {
                 Task::none()
             }
-            ManagementAppGeneralMsgIn::InitSetting { theme, window_position_mode, shortcut, shortcut_error } => {
+            ManagementAppGeneralMsgIn::InitSetting { theme, window_position_mode, window_overlay, density, ui_scale, search_results_sort, shortcut, shortcut_error, autostart_enabled } => {
                 self.theme = theme;
                 self.window_position_mode = window_position_mode;
+                self.window_overlay = window_overlay;
+                self.density = density;
+                self.ui_scale = ui_scale;
+                self.search_results_sort = search_results_sort;
                 self.current_shortcut = shortcut;
                 self.current_shortcut_error = shortcut_error;
+                self.autostart_enabled = autostart_enabled;
 
                 Task::done(ManagementAppGeneralMsgOut::Noop)
             }
@@ -115,6 +143,75 @@ impl ManagementAppGeneralState {
                     Ok(())
                 }, |result| handle_backend_error(result, |()| ManagementAppGeneralMsgOut::Noop))
             }
+            ManagementAppGeneralMsgIn::WindowOverlayChanged(enabled) => {
+                self.window_overlay = enabled;
+
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.set_window_overlay(enabled)
+                        .await?;
+
+                    Ok(())
+                }, |result| handle_backend_error(result, |()| ManagementAppGeneralMsgOut::Noop))
+            }
+            ManagementAppGeneralMsgIn::DensityChanged(density) => {
+                self.density = density;
+
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.set_density(density)
+                        .await?;
+
+                    Ok(())
+                }, |result| handle_backend_error(result, |()| ManagementAppGeneralMsgOut::Noop))
+            }
+            ManagementAppGeneralMsgIn::UiScaleChanged(scale) => {
+                self.ui_scale = scale;
+
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.set_ui_scale(scale)
+                        .await?;
+
+                    Ok(())
+                }, |result| handle_backend_error(result, |()| ManagementAppGeneralMsgOut::Noop))
+            }
+            ManagementAppGeneralMsgIn::SearchResultsSortChanged(sort) => {
+                self.search_results_sort = sort.clone();
+
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.set_search_results_sort(sort)
+                        .await?;
+
+                    Ok(())
+                }, |result| handle_backend_error(result, |()| ManagementAppGeneralMsgOut::Noop))
+            }
+            ManagementAppGeneralMsgIn::AutostartChanged(enabled) => {
+                self.autostart_enabled = enabled;
+
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.set_autostart_enabled(enabled)
+                        .await?;
+
+                    // read back actual state in case entry could not be changed
+                    let enabled = backend_api.get_autostart_enabled()
+                        .await?;
+
+                    Ok(enabled)
+                }, |result| handle_backend_error(result, |enabled| ManagementAppGeneralMsgOut::AutostartReloaded(enabled)))
+            }
+            ManagementAppGeneralMsgIn::AutostartFetched(enabled) => {
+                self.autostart_enabled = enabled;
+
+                Task::none()
+            }
         }
     }
 
@@ -132,7 +229,7 @@ impl ManagementAppGeneralState {
             .into();
 
         let global_shortcut_field = self.view_field(
-            "Global Shortcut",
+            tr!("settings-global-shortcut"),
             global_shortcut_field,
             Some(self.shortcut_capture_after())
         );
@@ -146,6 +243,16 @@ impl ManagementAppGeneralState {
             content.push(self.window_position_mode_field())
         }
 
+        content.push(self.window_overlay_field());
+
+        content.push(self.density_field());
+
+        content.push(self.ui_scale_field());
+
+        content.push(self.search_results_sort_field());
+
+        content.push(self.autostart_field());
+
         let content: Element<_> = column(content)
             .into();
 
@@ -163,7 +270,7 @@ impl ManagementAppGeneralState {
     fn theme_field(&self) -> Element<ManagementAppGeneralMsgIn> {
         let theme_field = match &self.theme {
             SettingsTheme::ThemeFile => {
-                let theme_field: Element<_> = text("Unable to change because theme config file is present ")
+                let theme_field: Element<_> = text(tr!("settings-theme-file-present"))
                     .shaping(Shaping::Advanced)
                     .align_x(Horizontal::Center)
                     .width(Length::Fill)
@@ -172,7 +279,7 @@ impl ManagementAppGeneralState {
                 theme_field
             }
             SettingsTheme::Config => {
-                let theme_field: Element<_> = text("Unable to change because value is defined in config")
+                let theme_field: Element<_> = text(tr!("settings-theme-defined-in-config"))
                     .shaping(Shaping::Advanced)
                     .align_x(Horizontal::Center)
                     .width(Length::Fill)
@@ -186,6 +293,7 @@ impl ManagementAppGeneralState {
                     SettingsTheme::MacOSLight,
                     SettingsTheme::MacOSDark,
                     SettingsTheme::Legacy,
+                    SettingsTheme::HighContrast,
                 ];
 
                 let theme_field: Element<_> = pick_list(
@@ -203,7 +311,7 @@ impl ManagementAppGeneralState {
             .into();
 
         let theme_field = self.view_field(
-            "Theme",
+            tr!("settings-theme"),
             theme_field,
             None
         );
@@ -228,7 +336,139 @@ impl ManagementAppGeneralState {
             .into();
 
         let field = self.view_field(
-            "Window Position Mode",
+            tr!("settings-window-position-mode"),
+            field,
+            None
+        );
+
+        field
+    }
+
+    fn window_overlay_field(&self) -> Element<ManagementAppGeneralMsgIn> {
+        let field: Element<_> = checkbox(tr!("settings-window-overlay-enabled"), self.window_overlay)
+            .on_toggle(ManagementAppGeneralMsgIn::WindowOverlayChanged)
+            .into();
+
+        let field: Element<_> = container(field)
+            .width(Length::Fill)
+            .into();
+
+        let field = self.view_field(
+            tr!("settings-window-overlay"),
+            field,
+            None
+        );
+
+        field
+    }
+
+    fn density_field(&self) -> Element<ManagementAppGeneralMsgIn> {
+        let items = [
+            Density::Compact,
+            Density::Comfortable,
+            Density::Large,
+        ];
+
+        let field: Element<_> = pick_list(
+            items,
+            Some(self.density),
+            move |item| ManagementAppGeneralMsgIn::DensityChanged(item),
+        ).into();
+
+        let field: Element<_> = container(field)
+            .width(Length::Fill)
+            .into();
+
+        let hint: Element<_> = text(tr!("settings-density-restart-hint"))
+            .shaping(Shaping::Advanced)
+            .class(TextStyle::Subtitle)
+            .into();
+
+        let hint: Element<_> = container(hint)
+            .width(Length::FillPortion(3))
+            .padding(Padding::from([0.0, 8.0]))
+            .into();
+
+        let field = self.view_field(
+            tr!("settings-density"),
+            field,
+            Some(hint)
+        );
+
+        field
+    }
+
+    fn ui_scale_field(&self) -> Element<ManagementAppGeneralMsgIn> {
+        let mut items = vec![UiScale::Auto];
+
+        items.extend([75, 90, 100, 110, 125, 150, 175, 200, 250, 300].map(|percent| UiScale::Manual { percent }));
+
+        let field: Element<_> = pick_list(
+            items,
+            Some(self.ui_scale),
+            move |item| ManagementAppGeneralMsgIn::UiScaleChanged(item),
+        ).into();
+
+        let field: Element<_> = container(field)
+            .width(Length::Fill)
+            .into();
+
+        let hint: Element<_> = text(tr!("settings-ui-scale-hint"))
+            .shaping(Shaping::Advanced)
+            .class(TextStyle::Subtitle)
+            .into();
+
+        let hint: Element<_> = container(hint)
+            .width(Length::FillPortion(3))
+            .padding(Padding::from([0.0, 8.0]))
+            .into();
+
+        let field = self.view_field(
+            tr!("settings-ui-scale"),
+            field,
+            Some(hint)
+        );
+
+        field
+    }
+
+    fn search_results_sort_field(&self) -> Element<ManagementAppGeneralMsgIn> {
+        let items = [
+            SearchResultsSort::Frecency,
+            SearchResultsSort::Relevance,
+            SearchResultsSort::Plugin,
+        ];
+
+        let field: Element<_> = pick_list(
+            items,
+            Some(self.search_results_sort.clone()),
+            move |item| ManagementAppGeneralMsgIn::SearchResultsSortChanged(item),
+        ).into();
+
+        let field: Element<_> = container(field)
+            .width(Length::Fill)
+            .into();
+
+        let field = self.view_field(
+            tr!("settings-search-results-sort"),
+            field,
+            None
+        );
+
+        field
+    }
+
+    fn autostart_field(&self) -> Element<ManagementAppGeneralMsgIn> {
+        let field: Element<_> = checkbox(tr!("settings-autostart-enabled"), self.autostart_enabled)
+            .on_toggle(ManagementAppGeneralMsgIn::AutostartChanged)
+            .into();
+
+        let field: Element<_> = container(field)
+            .width(Length::Fill)
+            .into();
+
+        let field = self.view_field(
+            tr!("settings-autostart"),
             field,
             None
         );
@@ -236,7 +476,7 @@ impl ManagementAppGeneralState {
         field
     }
 
-    fn view_field<'a>(&'a self, label: &'a str, input: Element<'a, ManagementAppGeneralMsgIn>, after: Option<Element<'a, ManagementAppGeneralMsgIn>>) -> Element<'a, ManagementAppGeneralMsgIn> {
+    fn view_field<'a>(&'a self, label: String, input: Element<'a, ManagementAppGeneralMsgIn>, after: Option<Element<'a, ManagementAppGeneralMsgIn>>) -> Element<'a, ManagementAppGeneralMsgIn> {
         let label: Element<_> = text(label)
             .shaping(Shaping::Advanced)
             .align_x(Horizontal::Right)
@@ -274,12 +514,12 @@ impl ManagementAppGeneralState {
 
     fn shortcut_capture_after(&self) -> Element<ManagementAppGeneralMsgIn> {
         if self.currently_capturing {
-            let hint1: Element<_> = text("Backspace - Unset Shortcut")
+            let hint1: Element<_> = text(tr!("settings-shortcut-unset-hint"))
                 .width(Length::Fill)
                 .class(TextStyle::Subtitle)
                 .into();
 
-            let hint2: Element<_> = text("Escape - Stop Capturing")
+            let hint2: Element<_> = text(tr!("settings-shortcut-stop-capturing-hint"))
                 .width(Length::Fill)
                 .class(TextStyle::Subtitle)
                 .into();
@@ -1,2 +1,7 @@
+pub mod crash_reports;
+pub mod dev_console;
 pub mod general;
-pub mod plugins;
\ No newline at end of file
+pub mod plugins;
+pub mod quicklinks;
+pub mod troubleshooting;
+pub mod usage_stats;
@@ -3,18 +3,19 @@ use std::collections::HashMap;
 use std::rc::Rc;
 
 use iced::{padding, Alignment, Length, Padding, Task};
-use iced::widget::{button, column, container, row, scrollable, text, text_input, value, vertical_rule};
+use iced::widget::{button, checkbox, column, container, row, scrollable, slider, text, text_input, value, vertical_rule};
 use iced::widget::text::Shaping;
 use iced_fonts::{Bootstrap, BOOTSTRAP_FONT};
-use gauntlet_common::{settings_env_data_from_string, SettingsEnvData};
-use gauntlet_common::model::{EntrypointId, PluginId, PluginPreferenceUserData, SettingsPlugin};
+use gauntlet_common::SettingsEnvData;
+use gauntlet_common::model::{EntrypointId, PluginId, PluginPreferenceUserData, SettingsPlugin, SettingsPluginDiskUsage};
 use gauntlet_common::rpc::backend_api::{BackendApi, BackendApiError};
+use gauntlet_common::tr;
 
 use crate::theme::button::ButtonStyle;
 use crate::theme::Element;
 use crate::theme::text::TextStyle;
 use crate::views::plugins::preferences::{PluginPreferencesMsg, preferences_ui, SelectItem};
-use crate::views::plugins::table::{PluginTableMsgIn, PluginTableMsgOut, PluginTableState, PluginTableUpdateResult};
+use crate::views::plugins::table::{sorted_entrypoints, PluginTableMsgIn, PluginTableMsgOut, PluginTableState, PluginTableUpdateResult};
 
 mod preferences;
 mod table;
@@ -32,6 +33,65 @@ pub enum ManagementAppPluginMsgIn {
         plugin_id: PluginId,
     },
     SelectItem(SelectedItem),
+    PriorityChanged {
+        plugin_id: PluginId,
+        priority: f64,
+    },
+    // saved only when slider is released, not on every change
+    PriorityReleased {
+        plugin_id: PluginId,
+    },
+    NotificationsToggled {
+        plugin_id: PluginId,
+        enabled: bool,
+    },
+    EnvironmentNameChanged {
+        plugin_id: PluginId,
+        index: usize,
+        name: String,
+    },
+    EnvironmentValueChanged {
+        plugin_id: PluginId,
+        index: usize,
+        value: String,
+    },
+    EnvironmentVariableAdded {
+        plugin_id: PluginId,
+    },
+    EnvironmentVariableRemoved {
+        plugin_id: PluginId,
+        index: usize,
+    },
+    // saved when enter is pressed in one of the fields or variable is removed, plugin is restarted after that
+    SaveEnvironment {
+        plugin_id: PluginId,
+    },
+    FilesystemRootChanged {
+        plugin_id: PluginId,
+        index: usize,
+        path: String,
+    },
+    FilesystemRootAdded {
+        plugin_id: PluginId,
+    },
+    FilesystemRootRemoved {
+        plugin_id: PluginId,
+        index: usize,
+    },
+    // saved the same way as environment variables
+    SaveFilesystemRoots {
+        plugin_id: PluginId,
+    },
+    DiskUsageFetched {
+        plugin_id: PluginId,
+        usage: SettingsPluginDiskUsage,
+    },
+    ClearCache {
+        plugin_id: PluginId,
+    },
+    ClearData {
+        plugin_id: PluginId,
+    },
     Noop
 }
 
@@ -41,6 +101,10 @@ pub enum ManagementAppPluginMsgOut {
     DownloadPlugin {
         plugin_id: PluginId,
     },
+    DiskUsageReloaded {
+        plugin_id: PluginId,
+        usage: SettingsPluginDiskUsage,
+    },
     HandleBackendError(BackendApiError),
     Noop
 }
@@ -50,28 +114,31 @@ pub struct ManagementAppPluginsState {
     table_state: PluginTableState,
     plugin_data: Rc<RefCell<PluginDataContainer>>,
     preference_user_data: HashMap<(PluginId, Option<EntrypointId>, String), PluginPreferenceUserDataState>,
+    // name and value pairs being edited, rows with empty name are not saved
+    environment: HashMap<PluginId, Vec<(String, String)>>,
+    // directory paths being edited, empty rows are not saved
+    filesystem_roots: HashMap<PluginId, Vec<String>>,
+    // fetched when plugin is selected and after directory is cleared
+    disk_usage: HashMap<PluginId, SettingsPluginDiskUsage>,
     selected_item: SelectedItem,
 }
 
-const SETTINGS_ENV: &'static str = "GAUNTLET_INTERNAL_SETTINGS";
+pub fn selected_item(settings_env_data: Option<SettingsEnvData>) -> SelectedItem {
+    match settings_env_data {
+        None => SelectedItem::None,
+        Some(SettingsEnvData::OpenEntrypointPreferences { plugin_id, entrypoint_id }) => SelectedItem::Entrypoint {
+            plugin_id: PluginId::from_string(plugin_id),
+            entrypoint_id: EntrypointId::from_string(entrypoint_id),
+        },
+        Some(SettingsEnvData::OpenPluginPreferences { plugin_id }) => SelectedItem::Plugin {
+            plugin_id: PluginId::from_string(plugin_id),
+        },
+    }
+}
 
 impl ManagementAppPluginsState {
-    pub fn new(backend_api: Option<BackendApi>) -> Self {
-        let settings_env_data = std::env::var(SETTINGS_ENV)
-            .ok()
-            .filter(|value| !value.is_empty())
-            .map(|val| settings_env_data_from_string(val));
-
-        let select_item = match settings_env_data {
-            None => SelectedItem::None,
-            Some(SettingsEnvData::OpenEntrypointPreferences { plugin_id, entrypoint_id }) => SelectedItem::Entrypoint {
-                plugin_id: PluginId::from_string(plugin_id),
-                entrypoint_id: EntrypointId::from_string(entrypoint_id),
-            },
-            Some(SettingsEnvData::OpenPluginPreferences { plugin_id }) => SelectedItem::Plugin {
-                plugin_id: PluginId::from_string(plugin_id),
-            },
-        };
+    pub fn new(backend_api: Option<BackendApi>, settings_env_data: Option<SettingsEnvData>) -> Self {
+        let select_item = selected_item(settings_env_data);
 
         tracing::debug!("Opening selected item: {:?}", select_item);
 
@@ -79,6 +146,9 @@ impl ManagementAppPluginsState {
             backend_api,
             plugin_data: Rc::new(RefCell::new(PluginDataContainer::new())),
             preference_user_data: HashMap::new(),
+            environment: HashMap::new(),
+            filesystem_roots: HashMap::new(),
+            disk_usage: HashMap::new(),
             selected_item: select_item,
             table_state: PluginTableState::new(),
         }
@@ -130,6 +200,61 @@ impl ManagementAppPluginsState {
                                     |result| handle_backend_error(result, |plugins| ManagementAppPluginMsgOut::PluginsReloaded(plugins))
                                 )
                             }
+                            PluginTableMsgOut::SetEntrypointHidden { hidden, plugin_id, entrypoint_id } => {
+                                let mut backend_client = backend_api.clone();
+
+                                Task::perform(
+                                    async move {
+                                        backend_client.set_entrypoint_hidden(plugin_id, entrypoint_id, hidden)
+                                            .await?;
+
+                                        let plugins = backend_client.plugins()
+                                            .await?;
+
+                                        Ok(plugins)
+                                    },
+                                    |result| handle_backend_error(result, |plugins| ManagementAppPluginMsgOut::PluginsReloaded(plugins))
+                                )
+                            }
+                            PluginTableMsgOut::MoveEntrypoint { up, plugin_id, entrypoint_id } => {
+                                // whole order is saved, so that entrypoints which were never moved get position too
+                                let entrypoint_ids = {
+                                    let plugin_data = self.plugin_data.borrow();
+                                    let plugin = plugin_data.plugins.get(&plugin_id).unwrap();
+
+                                    let mut entrypoint_ids: Vec<_> = sorted_entrypoints(plugin)
+                                        .into_iter()
+                                        .map(|entrypoint| entrypoint.entrypoint_id.clone())
+                                        .collect();
+
+                                    let index = entrypoint_ids.iter()
+                                        .position(|id| id == &entrypoint_id)
+                                        .unwrap();
+
+                                    if up && index > 0 {
+                                        entrypoint_ids.swap(index, index - 1);
+                                    } else if !up && index + 1 < entrypoint_ids.len() {
+                                        entrypoint_ids.swap(index, index + 1);
+                                    }
+
+                                    entrypoint_ids
+                                };
+
+                                let mut backend_client = backend_api.clone();
+
+                                Task::perform(
+                                    async move {
+                                        backend_client.set_entrypoint_order(plugin_id, entrypoint_ids)
+                                            .await?;
+
+                                        let plugins = backend_client.plugins()
+                                            .await?;
+
+                                        Ok(plugins)
+                                    },
+                                    |result| handle_backend_error(result, |plugins| ManagementAppPluginMsgOut::PluginsReloaded(plugins))
+                                )
+                            }
                             PluginTableMsgOut::SelectItem(selected_item) => {
                                 Task::done(ManagementAppPluginMsgOut::SelectedItem(selected_item))
                             }
@@ -210,17 +335,245 @@ impl ManagementAppPluginsState {
                 Task::done(ManagementAppPluginMsgOut::DownloadPlugin { plugin_id })
             }
             ManagementAppPluginMsgIn::SelectItem(selected_item) => {
+                let task = match &selected_item {
+                    SelectedItem::Plugin { plugin_id } => fetch_disk_usage(backend_api, plugin_id.clone()),
+                    _ => Task::none(),
+                };
+
                 self.selected_item = selected_item;
 
+                task
+            }
+            ManagementAppPluginMsgIn::PriorityChanged { plugin_id, priority } => {
+                let mut plugin_data = self.plugin_data.borrow_mut();
+
+                if let Some(plugin) = plugin_data.plugins.get_mut(&plugin_id) {
+                    plugin.priority = priority;
+                }
+
+                Task::none()
+            }
+            ManagementAppPluginMsgIn::PriorityReleased { plugin_id } => {
+                let priority = {
+                    let plugin_data = self.plugin_data.borrow();
+
+                    match plugin_data.plugins.get(&plugin_id) {
+                        None => return Task::none(),
+                        Some(plugin) => plugin.priority
+                    }
+                };
+
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(
+                    async move {
+                        backend_api.set_plugin_priority(plugin_id, priority)
+                            .await?;
+
+                        Ok(())
+                    },
+                    |result| handle_backend_error(result, |()| ManagementAppPluginMsgOut::Noop)
+                )
+            }
+            ManagementAppPluginMsgIn::NotificationsToggled { plugin_id, enabled } => {
+                {
+                    let mut plugin_data = self.plugin_data.borrow_mut();
+
+                    if let Some(plugin) = plugin_data.plugins.get_mut(&plugin_id) {
+                        plugin.notifications_enabled = enabled;
+                    }
+                }
+
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(
+                    async move {
+                        backend_api.set_plugin_notifications_enabled(plugin_id, enabled)
+                            .await?;
+
+                        Ok(())
+                    },
+                    |result| handle_backend_error(result, |()| ManagementAppPluginMsgOut::Noop)
+                )
+            }
+            ManagementAppPluginMsgIn::EnvironmentNameChanged { plugin_id, index, name } => {
+                if let Some(variable) = self.environment.get_mut(&plugin_id).and_then(|variables| variables.get_mut(index)) {
+                    variable.0 = name;
+                }
+
+                Task::none()
+            }
+            ManagementAppPluginMsgIn::EnvironmentValueChanged { plugin_id, index, value } => {
+                if let Some(variable) = self.environment.get_mut(&plugin_id).and_then(|variables| variables.get_mut(index)) {
+                    variable.1 = value;
+                }
+
+                Task::none()
+            }
+            ManagementAppPluginMsgIn::EnvironmentVariableAdded { plugin_id } => {
+                self.environment.entry(plugin_id)
+                    .or_default()
+                    .push((String::new(), String::new()));
+
+                Task::none()
+            }
+            ManagementAppPluginMsgIn::EnvironmentVariableRemoved { plugin_id, index } => {
+                if let Some(variables) = self.environment.get_mut(&plugin_id) {
+                    if index < variables.len() {
+                        variables.remove(index);
+                    }
+                }
+
+                self.save_environment(backend_api, plugin_id)
+            }
+            ManagementAppPluginMsgIn::SaveEnvironment { plugin_id } => {
+                self.save_environment(backend_api, plugin_id)
+            }
+            ManagementAppPluginMsgIn::FilesystemRootChanged { plugin_id, index, path } => {
+                if let Some(root) = self.filesystem_roots.get_mut(&plugin_id).and_then(|roots| roots.get_mut(index)) {
+                    *root = path;
+                }
+
+                Task::none()
+            }
+            ManagementAppPluginMsgIn::FilesystemRootAdded { plugin_id } => {
+                self.filesystem_roots.entry(plugin_id)
+                    .or_default()
+                    .push(String::new());
+
+                Task::none()
+            }
+            ManagementAppPluginMsgIn::FilesystemRootRemoved { plugin_id, index } => {
+                if let Some(roots) = self.filesystem_roots.get_mut(&plugin_id) {
+                    if index < roots.len() {
+                        roots.remove(index);
+                    }
+                }
+
+                self.save_filesystem_roots(backend_api, plugin_id)
+            }
+            ManagementAppPluginMsgIn::SaveFilesystemRoots { plugin_id } => {
+                self.save_filesystem_roots(backend_api, plugin_id)
+            }
+            ManagementAppPluginMsgIn::DiskUsageFetched { plugin_id, usage } => {
+                self.disk_usage.insert(plugin_id, usage);
+
                 Task::none()
             }
+            ManagementAppPluginMsgIn::ClearCache { plugin_id } => {
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(
+                    async move {
+                        backend_api.clear_plugin_cache(plugin_id.clone())
+                            .await?;
+
+                        let usage = backend_api.plugin_disk_usage(plugin_id.clone())
+                            .await?;
+
+                        Ok((plugin_id, usage))
+                    },
+                    |result| handle_backend_error(result, |(plugin_id, usage)| ManagementAppPluginMsgOut::DiskUsageReloaded { plugin_id, usage })
+                )
+            }
+            ManagementAppPluginMsgIn::ClearData { plugin_id } => {
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(
+                    async move {
+                        backend_api.clear_plugin_data(plugin_id.clone())
+                            .await?;
+
+                        let usage = backend_api.plugin_disk_usage(plugin_id.clone())
+                            .await?;
+
+                        Ok((plugin_id, usage))
+                    },
+                    |result| handle_backend_error(result, |(plugin_id, usage)| ManagementAppPluginMsgOut::DiskUsageReloaded { plugin_id, usage })
+                )
+            }
             ManagementAppPluginMsgIn::Noop => {
                 Task::none()
             }
         }
     }
 
+    fn save_environment(&mut self, mut backend_api: BackendApi, plugin_id: PluginId) -> Task<ManagementAppPluginMsgOut> {
+        let environment: HashMap<String, String> = self.environment.get(&plugin_id)
+            .map(|variables| {
+                variables.iter()
+                    .filter(|(name, _)| !name.trim().is_empty())
+                    .map(|(name, value)| (name.trim().to_string(), value.clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        {
+            let mut plugin_data = self.plugin_data.borrow_mut();
+
+            if let Some(plugin) = plugin_data.plugins.get_mut(&plugin_id) {
+                plugin.environment = environment.clone();
+            }
+        }
+
+        Task::perform(
+            async move {
+                backend_api.set_plugin_environment(plugin_id, environment)
+                    .await?;
+
+                Ok(())
+            },
+            |result| handle_backend_error(result, |()| ManagementAppPluginMsgOut::Noop)
+        )
+    }
+
+    fn save_filesystem_roots(&mut self, mut backend_api: BackendApi, plugin_id: PluginId) -> Task<ManagementAppPluginMsgOut> {
+        let filesystem_roots: Vec<String> = self.filesystem_roots.get(&plugin_id)
+            .map(|roots| {
+                roots.iter()
+                    .map(|root| root.trim())
+                    .filter(|root| !root.is_empty())
+                    .map(|root| root.to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        {
+            let mut plugin_data = self.plugin_data.borrow_mut();
+
+            if let Some(plugin) = plugin_data.plugins.get_mut(&plugin_id) {
+                plugin.filesystem_roots = filesystem_roots.clone();
+            }
+        }
+
+        Task::perform(
+            async move {
+                backend_api.set_plugin_filesystem_roots(plugin_id, filesystem_roots)
+                    .await?;
+
+                Ok(())
+            },
+            |result| handle_backend_error(result, |()| ManagementAppPluginMsgOut::Noop)
+        )
+    }
+
     fn apply_plugin_fetch(&mut self, plugins: HashMap<PluginId, SettingsPlugin>) {
+        self.filesystem_roots = plugins.iter()
+            .map(|(plugin_id, plugin)| (plugin_id.clone(), plugin.filesystem_roots.clone()))
+            .collect();
+
+        self.environment = plugins.iter()
+            .map(|(plugin_id, plugin)| {
+                let mut variables: Vec<_> = plugin.environment.clone()
+                    .into_iter()
+                    .collect();
+
+                variables.sort();
+
+                (plugin_id.clone(), variables)
+            })
+            .collect();
+
         self.preference_user_data = plugins.iter()
             .map(|(plugin_id, plugin)| {
                 let mut result = vec![];
@@ -265,6 +618,214 @@ impl ManagementAppPluginsState {
         self.table_state.apply_plugin_reload(self.plugin_data.clone(), plugin_refs)
     }
 
+    fn view_environment(&self, plugin_id: &PluginId) -> Element<ManagementAppPluginMsgIn> {
+        let label: Element<_> = text(tr!("settings-plugins-environment"))
+            .size(14)
+            .class(TextStyle::Subtitle)
+            .into();
+
+        let label = container(label)
+            .padding(padding::bottom(8.0))
+            .into();
+
+        let variables = self.environment.get(plugin_id)
+            .map(|variables| variables.as_slice())
+            .unwrap_or_default();
+
+        let mut rows: Vec<Element<_>> = variables.iter()
+            .enumerate()
+            .map(|(index, (variable_name, variable_value))| {
+                let name_plugin_id = plugin_id.clone();
+                let value_plugin_id = plugin_id.clone();
+
+                let name_input: Element<_> = text_input(&tr!("settings-plugins-environment-name"), variable_name)
+                    .on_input(move |name| ManagementAppPluginMsgIn::EnvironmentNameChanged { plugin_id: name_plugin_id.clone(), index, name })
+                    .on_submit(ManagementAppPluginMsgIn::SaveEnvironment { plugin_id: plugin_id.clone() })
+                    .width(Length::FillPortion(1))
+                    .into();
+
+                let value_input: Element<_> = text_input(&tr!("settings-plugins-environment-value"), variable_value)
+                    .on_input(move |value| ManagementAppPluginMsgIn::EnvironmentValueChanged { plugin_id: value_plugin_id.clone(), index, value })
+                    .on_submit(ManagementAppPluginMsgIn::SaveEnvironment { plugin_id: plugin_id.clone() })
+                    .width(Length::FillPortion(2))
+                    .into();
+
+                let remove_icon: Element<_> = value(Bootstrap::TrashFill)
+                    .font(BOOTSTRAP_FONT)
+                    .into();
+
+                let remove_button: Element<_> = button(remove_icon)
+                    .class(ButtonStyle::Destructive)
+                    .on_press(ManagementAppPluginMsgIn::EnvironmentVariableRemoved { plugin_id: plugin_id.clone(), index })
+                    .into();
+
+                row(vec![name_input, value_input, remove_button])
+                    .spacing(8.0)
+                    .align_y(Alignment::Center)
+                    .into()
+            })
+            .collect();
+
+        let add_text: Element<_> = text(tr!("settings-plugins-environment-add"))
+            .into();
+
+        let add_button: Element<_> = button(add_text)
+            .class(ButtonStyle::Primary)
+            .on_press(ManagementAppPluginMsgIn::EnvironmentVariableAdded { plugin_id: plugin_id.clone() })
+            .into();
+
+        rows.push(add_button);
+
+        let hint: Element<_> = text(tr!("settings-plugins-environment-hint"))
+            .size(12)
+            .class(TextStyle::Subtitle)
+            .into();
+
+        rows.push(hint);
+
+        let content: Element<_> = column(rows)
+            .spacing(8.0)
+            .into();
+
+        let content = container(content)
+            .padding(Padding::new(8.0))
+            .into();
+
+        column(vec![label, content])
+            .into()
+    }
+
+    fn view_filesystem_roots(&self, plugin_id: &PluginId) -> Element<ManagementAppPluginMsgIn> {
+        let label: Element<_> = text(tr!("settings-plugins-filesystem-roots"))
+            .size(14)
+            .class(TextStyle::Subtitle)
+            .into();
+
+        let label = container(label)
+            .padding(padding::bottom(8.0))
+            .into();
+
+        let roots = self.filesystem_roots.get(plugin_id)
+            .map(|roots| roots.as_slice())
+            .unwrap_or_default();
+
+        let mut rows: Vec<Element<_>> = roots.iter()
+            .enumerate()
+            .map(|(index, root)| {
+                let path_plugin_id = plugin_id.clone();
+
+                let path_input: Element<_> = text_input(&tr!("settings-plugins-filesystem-roots-path"), root)
+                    .on_input(move |path| ManagementAppPluginMsgIn::FilesystemRootChanged { plugin_id: path_plugin_id.clone(), index, path })
+                    .on_submit(ManagementAppPluginMsgIn::SaveFilesystemRoots { plugin_id: plugin_id.clone() })
+                    .width(Length::Fill)
+                    .into();
+
+                let remove_icon: Element<_> = value(Bootstrap::TrashFill)
+                    .font(BOOTSTRAP_FONT)
+                    .into();
+
+                let remove_button: Element<_> = button(remove_icon)
+                    .class(ButtonStyle::Destructive)
+                    .on_press(ManagementAppPluginMsgIn::FilesystemRootRemoved { plugin_id: plugin_id.clone(), index })
+                    .into();
+
+                row(vec![path_input, remove_button])
+                    .spacing(8.0)
+                    .align_y(Alignment::Center)
+                    .into()
+            })
+            .collect();
+
+        let add_text: Element<_> = text(tr!("settings-plugins-filesystem-roots-add"))
+            .into();
+
+        let add_button: Element<_> = button(add_text)
+            .class(ButtonStyle::Primary)
+            .on_press(ManagementAppPluginMsgIn::FilesystemRootAdded { plugin_id: plugin_id.clone() })
+            .into();
+
+        rows.push(add_button);
+
+        let hint: Element<_> = text(tr!("settings-plugins-filesystem-roots-hint"))
+            .size(12)
+            .class(TextStyle::Subtitle)
+            .into();
+
+        rows.push(hint);
+
+        let content: Element<_> = column(rows)
+            .spacing(8.0)
+            .into();
+
+        let content = container(content)
+            .padding(Padding::new(8.0))
+            .into();
+
+        column(vec![label, content])
+            .into()
+    }
+
+    fn view_disk_usage(&self, plugin_id: &PluginId) -> Element<ManagementAppPluginMsgIn> {
+        let label: Element<_> = text(tr!("settings-plugins-storage"))
+            .size(14)
+            .class(TextStyle::Subtitle)
+            .into();
+
+        let label = container(label)
+            .padding(padding::bottom(8.0))
+            .into();
+
+        let usage: Element<_> = match self.disk_usage.get(plugin_id) {
+            None => text(tr!("loading")).into(),
+            Some(usage) => {
+                let usage = tr!(
+                    "settings-plugins-storage-usage",
+                    data = format_size(usage.data),
+                    cache = format_size(usage.cache),
+                    logs = format_size(usage.logs)
+                );
+
+                text(usage).into()
+            }
+        };
+
+        let clear_cache_text: Element<_> = text(tr!("settings-plugins-storage-clear-cache"))
+            .into();
+
+        let clear_cache_button: Element<_> = button(clear_cache_text)
+            .class(ButtonStyle::Primary)
+            .on_press(ManagementAppPluginMsgIn::ClearCache { plugin_id: plugin_id.clone() })
+            .into();
+
+        let clear_data_text: Element<_> = text(tr!("settings-plugins-storage-clear-data"))
+            .into();
+
+        let clear_data_button: Element<_> = button(clear_data_text)
+            .class(ButtonStyle::Destructive)
+            .on_press(ManagementAppPluginMsgIn::ClearData { plugin_id: plugin_id.clone() })
+            .into();
+
+        let buttons: Element<_> = row(vec![clear_cache_button, clear_data_button])
+            .spacing(8.0)
+            .into();
+
+        let hint: Element<_> = text(tr!("settings-plugins-storage-hint"))
+            .size(12)
+            .class(TextStyle::Subtitle)
+            .into();
+
+        let content: Element<_> = column(vec![usage, buttons, hint])
+            .spacing(8.0)
+            .into();
+
+        let content = container(content)
+            .padding(Padding::new(8.0))
+            .into();
+
+        column(vec![label, content])
+            .into()
+    }
+
     pub fn view(&self) -> Element<ManagementAppPluginMsgIn> {
         let table: Element<_> = self.table_state.view()
             .map(|msg| ManagementAppPluginMsgIn::PluginTableMsg(msg));
@@ -275,9 +836,9 @@ impl ManagementAppPluginsState {
 
         let sidebar_content: Element<_> = match &self.selected_item {
             SelectedItem::None => {
-                let text1: Element<_> = text("Select item from the list on the left").into();
-                let text2: Element<_> = text("or").into();
-                let text3: Element<_> = text("Click '+' to add new plugin").into();
+                let text1: Element<_> = text(tr!("settings-plugins-select-item")).into();
+                let text2: Element<_> = text(tr!("settings-plugins-or")).into();
+                let text3: Element<_> = text(tr!("settings-plugins-add-hint")).into();
 
                 let text_column = column(vec![text1, text2, text3])
                     .align_x(Alignment::Center);
@@ -296,7 +857,7 @@ impl ManagementAppPluginsState {
 
                 match plugin {
                     None => {
-                        let loading_text: Element<_> = text("Loading...").into();
+                        let loading_text: Element<_> = text(tr!("loading")).into();
 
                         container(loading_text)
                             .align_y(Alignment::Center)
@@ -328,7 +889,7 @@ impl ManagementAppPluginsState {
                         ];
 
                         if !plugin.plugin_description.is_empty() {
-                            let description_label: Element<_> = text("Description")
+                            let description_label: Element<_> = text(tr!("settings-plugins-description"))
                                 .size(14)
                                 .class(TextStyle::Subtitle)
                                 .into();
@@ -350,6 +911,77 @@ impl ManagementAppPluginsState {
                             column_content.push(content);
                         }
 
+                        let priority_label: Element<_> = text(tr!("settings-plugins-priority"))
+                            .size(14)
+                            .class(TextStyle::Subtitle)
+                            .into();
+
+                        let priority_label = container(priority_label)
+                            .padding(padding::bottom(8.0))
+                            .into();
+
+                        let priority_plugin_id = plugin.plugin_id.clone();
+
+                        let priority_slider: Element<_> = slider(0.1..=3.0, plugin.priority, move |priority| ManagementAppPluginMsgIn::PriorityChanged { plugin_id: priority_plugin_id.clone(), priority })
+                            .on_release(ManagementAppPluginMsgIn::PriorityReleased { plugin_id: plugin.plugin_id.clone() })
+                            .step(0.1)
+                            .into();
+
+                        let priority_value: Element<_> = text(format!("{:.1}", plugin.priority))
+                            .width(Length::Fixed(40.0))
+                            .align_x(Alignment::End)
+                            .into();
+
+                        let priority_row: Element<_> = row(vec![priority_slider, priority_value])
+                            .spacing(8.0)
+                            .align_y(Alignment::Center)
+                            .into();
+
+                        let priority_hint: Element<_> = text(tr!("settings-plugins-priority-hint"))
+                            .size(12)
+                            .class(TextStyle::Subtitle)
+                            .into();
+
+                        let priority_content: Element<_> = column(vec![priority_row, priority_hint])
+                            .spacing(4.0)
+                            .into();
+
+                        let priority_content = container(priority_content)
+                            .padding(Padding::new(8.0))
+                            .into();
+
+                        let content: Element<_> = column(vec![priority_label, priority_content])
+                            .into();
+
+                        column_content.push(content);
+
+                        let notifications_plugin_id = plugin.plugin_id.clone();
+
+                        let notifications_checkbox: Element<_> = checkbox(tr!("settings-plugins-notifications"), plugin.notifications_enabled)
+                            .on_toggle(move |enabled| ManagementAppPluginMsgIn::NotificationsToggled { plugin_id: notifications_plugin_id.clone(), enabled })
+                            .into();
+
+                        let notifications_hint: Element<_> = text(tr!("settings-plugins-notifications-hint"))
+                            .size(12)
+                            .class(TextStyle::Subtitle)
+                            .into();
+
+                        let notifications_content: Element<_> = column(vec![notifications_checkbox, notifications_hint])
+                            .spacing(4.0)
+                            .into();
+
+                        let notifications_content = container(notifications_content)
+                            .padding(Padding::new(8.0))
+                            .into();
+
+                        column_content.push(notifications_content);
+
+                        column_content.push(self.view_environment(&plugin.plugin_id));
+
+                        column_content.push(self.view_filesystem_roots(&plugin.plugin_id));
+
+                        column_content.push(self.view_disk_usage(&plugin.plugin_id));
+
                         column_content.push(
                             preferences_ui(plugin_id.clone(), None, &plugin.preferences, &self.preference_user_data)
                                 .map(|msg| ManagementAppPluginMsgIn::PluginPreferenceMsg(msg))
@@ -367,7 +999,7 @@ impl ManagementAppPluginsState {
                         let mut column_content = vec![content];
 
                         if !plugin.plugin_id.to_string().starts_with("bundled://") {
-                             let check_for_updates_text: Element<_> = text("Check for updates")
+                             let check_for_updates_text: Element<_> = text(tr!("settings-plugins-check-for-updates"))
                                 .into();
 
                             let check_for_updates_text_container: Element<_> = container(check_for_updates_text)
@@ -384,7 +1016,7 @@ impl ManagementAppPluginsState {
 
                             column_content.push(check_for_updates_button);
 
-                            let remove_text: Element<_> = text("Remove plugin")
+                            let remove_text: Element<_> = text(tr!("settings-plugins-remove"))
                                 .into();
 
                             let remove_button_text_container: Element<_> = container(remove_text)
@@ -423,7 +1055,7 @@ impl ManagementAppPluginsState {
 
                 match entrypoint {
                     None => {
-                        let loading_text: Element<_> = text("Loading...").into();
+                        let loading_text: Element<_> = text(tr!("loading")).into();
 
                         container(loading_text)
                             .align_y(Alignment::Center)
@@ -445,7 +1077,7 @@ impl ManagementAppPluginsState {
                         ];
 
                         if !entrypoint.entrypoint_description.is_empty() {
-                            let description_label: Element<_> = text("Description")
+                            let description_label: Element<_> = text(tr!("settings-plugins-description"))
                                 .size(14)
                                 .class(TextStyle::Subtitle)
                                 .into();
@@ -486,15 +1118,15 @@ impl ManagementAppPluginsState {
                 }
             }
             SelectedItem::NewPlugin { repository_url } => {
-                let url_input: Element<_> = text_input("Enter Git Repository URL", &repository_url)
+                let url_input: Element<_> = text_input(&tr!("settings-plugins-repository-url"), &repository_url)
                     .on_input(|value| ManagementAppPluginMsgIn::SelectItem(SelectedItem::NewPlugin { repository_url: value }))
                     .on_submit(ManagementAppPluginMsgIn::DownloadPlugin { plugin_id: PluginId::from_string(repository_url) })
                     .into();
 
                 let content: Element<_> = column(vec![
                     url_input,
-                    text("Supported protocols:").into(),
-                    text("http(s), ssh, git").into(),
+                    text(tr!("settings-plugins-supported-protocols")).into(),
+                    text("http(s), ssh, git, file").into(),
                 ]).into();
 
                 container(content)
@@ -518,7 +1150,7 @@ impl ManagementAppPluginsState {
         };
 
         let top_button_text = if plugin_url.is_some() {
-            text("Download plugin")
+            text(tr!("settings-plugins-download"))
         } else {
             value(Bootstrap::Plus)
                 .font(BOOTSTRAP_FONT)
@@ -659,6 +1291,40 @@ impl PluginPreferenceUserDataState {
     }
 }
 
+fn fetch_disk_usage(mut backend_api: BackendApi, plugin_id: PluginId) -> Task<ManagementAppPluginMsgOut> {
+    Task::perform(
+        async move {
+            let usage = backend_api.plugin_disk_usage(plugin_id.clone())
+                .await?;
+
+            Ok((plugin_id, usage))
+        },
+        |result| handle_backend_error(result, |(plugin_id, usage)| ManagementAppPluginMsgOut::DiskUsageReloaded { plugin_id, usage })
+    )
+}
+
+fn format_size(size: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+
+    if size < 1024 {
+        return format!("{} B", size);
+    }
+
+    let mut value = size as f64;
+    let mut unit = "B";
+
+    for next_unit in UNITS {
+        if value < 1024.0 {
+            break;
+        }
+
+        value /= 1024.0;
+        unit = next_unit;
+    }
+
+    format!("{:.1} {}", value, unit)
+}
+
 pub fn handle_backend_error<T>(result: Result<T, BackendApiError>, convert: impl FnOnce(T) -> ManagementAppPluginMsgOut) -> ManagementAppPluginMsgOut {
     match result {
         Ok(val) => convert(val),
@@ -4,6 +4,7 @@ use crate::theme::text::TextStyle;
 use crate::theme::Element;
 use crate::views::plugins::PluginPreferenceUserDataState;
 use gauntlet_common::model::{EntrypointId, PluginId, PluginPreference};
+use gauntlet_common::tr;
 use iced::widget::{button, checkbox, column, container, pick_list, row, text, text_input};
 use iced::{padding, widget, Length, Padding};
 use iced_aw::number_input;
@@ -324,7 +325,7 @@ pub fn preferences_ui<'a>(
                     .padding(padding::bottom(8.0))
                     .into();
 
-                let add_text_input: Element<_> = text_input("Enter value...", &new_value)
+                let add_text_input: Element<_> = text_input(&tr!("settings-preferences-enter-value"), &new_value)
                     .on_input(move |new_value| PluginPreferencesMsg::UpdatePreferenceValue {
                         plugin_id: plugin_id.clone(),
                         entrypoint_id: entrypoint_id.clone(),
@@ -593,7 +594,7 @@ pub fn preferences_ui<'a>(
                         },
                     }),
                 )
-                    .placeholder("Select value...")
+                    .placeholder(tr!("settings-preferences-select-value"))
                     .width(Length::Fill)
                     .into();
 
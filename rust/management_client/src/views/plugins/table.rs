@@ -8,7 +8,8 @@ use iced::widget::scrollable::Id;
 use iced_fonts::{Bootstrap, BOOTSTRAP_FONT};
 use iced_table::table;
 
-use gauntlet_common::model::{EntrypointId, PluginId, SettingsEntrypointType, SettingsPlugin};
+use gauntlet_common::model::{EntrypointId, PluginId, SettingsEntrypoint, SettingsEntrypointType, SettingsPlugin};
+use gauntlet_common::tr;
 
 use crate::theme::{Element, GauntletSettingsTheme};
 use crate::theme::button::ButtonStyle;
@@ -22,6 +23,16 @@ pub enum PluginTableMsgIn {
     ToggleShowEntrypoints {
         plugin_id: PluginId,
     },
+    SetEntrypointHidden {
+        hidden: bool,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+    },
+    MoveEntrypoint {
+        up: bool,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+    },
 }
 
 pub enum PluginTableMsgOut {
@@ -34,6 +45,16 @@ pub enum PluginTableMsgOut {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
     },
+    SetEntrypointHidden {
+        hidden: bool,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+    },
+    MoveEntrypoint {
+        up: bool,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+    },
     SelectItem(SelectedItem),
     ToggleShowEntrypoints {
         plugin_id: PluginId,
@@ -59,6 +80,7 @@ impl PluginTableState {
                 Column::new(ColumnKind::ShowEntrypointsToggle),
                 Column::new(ColumnKind::Name),
                 Column::new(ColumnKind::Type),
+                Column::new(ColumnKind::Organize),
                 Column::new(ColumnKind::EnableToggle),
             ],
             rows: vec![],
@@ -98,6 +120,16 @@ impl PluginTableState {
                     PluginTableMsgOut::ToggleShowEntrypoints { plugin_id }
                 )
             }
+            PluginTableMsgIn::SetEntrypointHidden { hidden, plugin_id, entrypoint_id } => {
+                PluginTableUpdateResult::Value(
+                    PluginTableMsgOut::SetEntrypointHidden { hidden, plugin_id, entrypoint_id }
+                )
+            }
+            PluginTableMsgIn::MoveEntrypoint { up, plugin_id, entrypoint_id } => {
+                PluginTableUpdateResult::Value(
+                    PluginTableMsgOut::MoveEntrypoint { up, plugin_id, entrypoint_id }
+                )
+            }
         }
     }
 
@@ -113,14 +145,7 @@ impl PluginTableState {
                 });
 
                 if plugin_state.show_entrypoints {
-                    let mut entrypoints: Vec<_> = plugin.entrypoints
-                        .iter()
-                        .map(|(_, entrypoint)| entrypoint)
-                        .collect();
-
-                    entrypoints.sort_by_key(|entrypoint| &entrypoint.entrypoint_name);
-
-                    let mut entrypoints: Vec<_> = entrypoints
+                    let mut entrypoints: Vec<_> = sorted_entrypoints(plugin)
                         .iter()
                         .map(|entrypoint| {
                             Row::Entrypoint {
@@ -146,6 +171,17 @@ impl PluginTableState {
     }
 }
 
+// entrypoints moved by user go first, the rest are sorted by name
+pub fn sorted_entrypoints(plugin: &SettingsPlugin) -> Vec<&SettingsEntrypoint> {
+    let mut entrypoints: Vec<_> = plugin.entrypoints
+        .iter()
+        .map(|(_, entrypoint)| entrypoint)
+        .collect();
+
+    entrypoints.sort_by_key(|entrypoint| (entrypoint.position.is_none(), entrypoint.position, &entrypoint.entrypoint_name));
+
+    entrypoints
+}
 
 #[derive(Debug, Clone)]
 enum EnabledItem {
@@ -176,6 +212,7 @@ enum ColumnKind {
     ShowEntrypointsToggle,
     Name,
     Type,
+    Organize,
     EnableToggle,
 }
 
@@ -201,19 +238,23 @@ impl<'a> table::Column<'a, PluginTableMsgIn, GauntletSettingsTheme, Renderer> fo
                     .into()
             }
             ColumnKind::Name => {
-                container(text("Name"))
+                container(text(tr!("settings-table-name")))
                     .height(Length::Fixed(30.0))
                     .align_y(Alignment::Center)
                     .into()
             }
             ColumnKind::Type => {
-                container(text("Type"))
+                container(text(tr!("settings-table-type")))
                     .height(Length::Fixed(30.0))
                     .align_y(Alignment::Center)
                     .into()
             }
+            ColumnKind::Organize => {
+                horizontal_space()
+                    .into()
+            }
             ColumnKind::EnableToggle => {
-                container(text("Enabled"))
+                container(text(tr!("settings-table-enabled")))
                     .height(Length::Fixed(30.0))
                     .align_y(Alignment::Center)
                     .into()
@@ -328,13 +369,14 @@ impl<'a> table::Column<'a, PluginTableMsgIn, GauntletSettingsTheme, Renderer> fo
                         let entrypoint = plugin.entrypoints.get(&entrypoint_id).unwrap();
 
                         let entrypoint_type = match entrypoint.entrypoint_type {
-                            SettingsEntrypointType::Command => "Command",
-                            SettingsEntrypointType::View => "View",
-                            SettingsEntrypointType::InlineView => "Inline View",
-                            SettingsEntrypointType::EntrypointGenerator => "Entrypoint Generator"
+                            SettingsEntrypointType::Command => tr!("settings-entrypoint-type-command"),
+                            SettingsEntrypointType::View => tr!("settings-entrypoint-type-view"),
+                            SettingsEntrypointType::InlineView => tr!("settings-entrypoint-type-inline-view"),
+                            SettingsEntrypointType::EntrypointGenerator => tr!("settings-entrypoint-type-generator"),
+                            SettingsEntrypointType::SearchProvider => tr!("settings-entrypoint-type-search-provider"),
                         };
 
-                        container(text(entrypoint_type.to_string()))
+                        container(text(entrypoint_type))
                             .align_y(Alignment::Center)
                             .into()
                     }
@@ -369,6 +411,63 @@ impl<'a> table::Column<'a, PluginTableMsgIn, GauntletSettingsTheme, Renderer> fo
                     .padding(8.0)
                     .into()
             }
+            ColumnKind::Organize => {
+                match row_entry {
+                    Row::Plugin { .. } => {
+                        horizontal_space()
+                            .into()
+                    }
+                    Row::Entrypoint { plugin_data, plugin_id, entrypoint_id } => {
+                        let plugin_data = plugin_data.borrow();
+                        let plugin = plugin_data.plugins.get(&plugin_id).unwrap();
+                        let entrypoint = plugin.entrypoints.get(&entrypoint_id).unwrap();
+
+                        let entrypoints = sorted_entrypoints(plugin);
+                        let index = entrypoints.iter()
+                            .position(|entrypoint| &entrypoint.entrypoint_id == entrypoint_id)
+                            .unwrap();
+
+                        let hidden_icon = if entrypoint.hidden { Bootstrap::EyeSlash } else { Bootstrap::Eye };
+
+                        let hidden_button: Element<_> = button(value(hidden_icon).font(BOOTSTRAP_FONT))
+                            .on_press(PluginTableMsgIn::SetEntrypointHidden {
+                                hidden: !entrypoint.hidden,
+                                plugin_id: plugin_id.clone(),
+                                entrypoint_id: entrypoint_id.clone(),
+                            })
+                            .padding(4.0)
+                            .class(ButtonStyle::TableRow)
+                            .into();
+
+                        let up_button: Element<_> = button(value(Bootstrap::ArrowUp).font(BOOTSTRAP_FONT))
+                            .on_press_maybe((index > 0).then(|| PluginTableMsgIn::MoveEntrypoint {
+                                up: true,
+                                plugin_id: plugin_id.clone(),
+                                entrypoint_id: entrypoint_id.clone(),
+                            }))
+                            .padding(4.0)
+                            .class(ButtonStyle::TableRow)
+                            .into();
+
+                        let down_button: Element<_> = button(value(Bootstrap::ArrowDown).font(BOOTSTRAP_FONT))
+                            .on_press_maybe((index + 1 < entrypoints.len()).then(|| PluginTableMsgIn::MoveEntrypoint {
+                                up: false,
+                                plugin_id: plugin_id.clone(),
+                                entrypoint_id: entrypoint_id.clone(),
+                            }))
+                            .padding(4.0)
+                            .class(ButtonStyle::TableRow)
+                            .into();
+
+                        container(row(vec![hidden_button, up_button, down_button]))
+                            .width(Length::Fill)
+                            .height(Length::Fixed(40.0))
+                            .align_y(Alignment::Center)
+                            .align_x(Alignment::Center)
+                            .into()
+                    }
+                }
+            }
             ColumnKind::EnableToggle => {
                 let (enabled, show_checkbox, plugin_id, entrypoint_id) = match &row_entry {
                     Row::Plugin { plugin_data, plugin_id } => {
@@ -434,6 +533,7 @@ impl<'a> table::Column<'a, PluginTableMsgIn, GauntletSettingsTheme, Renderer> fo
             ColumnKind::ShowEntrypointsToggle => 35.0,
             ColumnKind::Name => 350.0,
             ColumnKind::Type => 200.0,
+            ColumnKind::Organize => 100.0,
             ColumnKind::EnableToggle => 75.0
         }
     }
@@ -0,0 +1,313 @@
+use crate::theme::button::ButtonStyle;
+use crate::theme::text::TextStyle;
+use crate::theme::Element;
+use gauntlet_common::model::SettingsQuicklink;
+use gauntlet_common::rpc::backend_api::{BackendApi, BackendApiError};
+use gauntlet_common::tr;
+use iced::widget::text::Shaping;
+use iced::widget::{button, checkbox, column, container, horizontal_rule, row, scrollable, text, text_input, value};
+use iced::{Alignment, Length, Padding, Task};
+use iced_fonts::{Bootstrap, BOOTSTRAP_FONT};
+
+pub struct ManagementAppQuicklinksState {
+    backend_api: Option<BackendApi>,
+    quicklinks: Vec<SettingsQuicklink>,
+    editing_id: Option<String>,
+    name: String,
+    url: String,
+    bang: String,
+    fallback: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum ManagementAppQuicklinksMsgIn {
+    FetchQuicklinks,
+    QuicklinksFetched(Vec<SettingsQuicklink>),
+    NameChanged(String),
+    UrlChanged(String),
+    BangChanged(String),
+    FallbackToggled(bool),
+    EditQuicklink(SettingsQuicklink),
+    CancelEdit,
+    SaveQuicklink,
+    RemoveQuicklink { id: String },
+    Noop
+}
+
+#[derive(Debug, Clone)]
+pub enum ManagementAppQuicklinksMsgOut {
+    Noop,
+    QuicklinksReloaded(Vec<SettingsQuicklink>),
+    HandleBackendError(BackendApiError)
+}
+
+impl ManagementAppQuicklinksState {
+    pub fn new(backend_api: Option<BackendApi>) -> Self {
+        Self {
+            backend_api,
+            quicklinks: vec![],
+            editing_id: None,
+            name: "".to_string(),
+            url: "".to_string(),
+            bang: "".to_string(),
+            fallback: false,
+        }
+    }
+
+    pub fn update(&mut self, message: ManagementAppQuicklinksMsgIn) -> Task<ManagementAppQuicklinksMsgOut> {
+        let backend_api = match &self.backend_api {
+            Some(backend_api) => backend_api.clone(),
+            None => {
+                return Task::none()
+            }
+        };
+
+        match message {
+            ManagementAppQuicklinksMsgIn::FetchQuicklinks => {
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    let quicklinks = backend_api.quicklinks()
+                        .await?;
+
+                    Ok(quicklinks)
+                }, |result| handle_backend_error(result, |quicklinks| ManagementAppQuicklinksMsgOut::QuicklinksReloaded(quicklinks)))
+            }
+            ManagementAppQuicklinksMsgIn::QuicklinksFetched(quicklinks) => {
+                self.quicklinks = quicklinks;
+
+                Task::none()
+            }
+            ManagementAppQuicklinksMsgIn::NameChanged(name) => {
+                self.name = name;
+
+                Task::none()
+            }
+            ManagementAppQuicklinksMsgIn::UrlChanged(url) => {
+                self.url = url;
+
+                Task::none()
+            }
+            ManagementAppQuicklinksMsgIn::BangChanged(bang) => {
+                self.bang = bang;
+
+                Task::none()
+            }
+            ManagementAppQuicklinksMsgIn::FallbackToggled(fallback) => {
+                self.fallback = fallback;
+
+                Task::none()
+            }
+            ManagementAppQuicklinksMsgIn::EditQuicklink(quicklink) => {
+                self.editing_id = Some(quicklink.id);
+                self.name = quicklink.name;
+                self.url = quicklink.url;
+                self.bang = quicklink.bang.unwrap_or_default();
+                self.fallback = quicklink.fallback;
+
+                Task::none()
+            }
+            ManagementAppQuicklinksMsgIn::CancelEdit => {
+                self.editing_id = None;
+                self.name = "".to_string();
+                self.url = "".to_string();
+                self.bang = "".to_string();
+                self.fallback = false;
+
+                Task::none()
+            }
+            ManagementAppQuicklinksMsgIn::SaveQuicklink => {
+                if self.name.trim().is_empty() || self.url.trim().is_empty() {
+                    return Task::none()
+                }
+
+                let id = self.editing_id.take();
+                let name = std::mem::take(&mut self.name);
+                let url = std::mem::take(&mut self.url);
+                let bang = Some(std::mem::take(&mut self.bang))
+                    .filter(|bang| !bang.trim().is_empty());
+                let fallback = std::mem::take(&mut self.fallback);
+
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.save_quicklink(id, name, url, bang, fallback)
+                        .await?;
+
+                    let quicklinks = backend_api.quicklinks()
+                        .await?;
+
+                    Ok(quicklinks)
+                }, |result| handle_backend_error(result, |quicklinks| ManagementAppQuicklinksMsgOut::QuicklinksReloaded(quicklinks)))
+            }
+            ManagementAppQuicklinksMsgIn::RemoveQuicklink { id } => {
+                if self.editing_id.as_ref() == Some(&id) {
+                    self.editing_id = None;
+                }
+
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.remove_quicklink(id)
+                        .await?;
+
+                    let quicklinks = backend_api.quicklinks()
+                        .await?;
+
+                    Ok(quicklinks)
+                }, |result| handle_backend_error(result, |quicklinks| ManagementAppQuicklinksMsgOut::QuicklinksReloaded(quicklinks)))
+            }
+            ManagementAppQuicklinksMsgIn::Noop => {
+                Task::none()
+            }
+        }
+    }
+
+    pub fn view(&self) -> Element<ManagementAppQuicklinksMsgIn> {
+        let quicklinks: Vec<Element<_>> = self.quicklinks.iter()
+            .map(|quicklink| self.view_quicklink(quicklink))
+            .collect();
+
+        let list: Element<_> = if quicklinks.is_empty() {
+            let empty: Element<_> = text(tr!("settings-quicklinks-empty"))
+                .class(TextStyle::Subtitle)
+                .into();
+
+            container(empty)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .align_x(Alignment::Center)
+                .align_y(Alignment::Center)
+                .into()
+        } else {
+            let list: Element<_> = column(quicklinks)
+                .into();
+
+            scrollable(list)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .into()
+        };
+
+        let separator: Element<_> = horizontal_rule(1)
+            .into();
+
+        let content: Element<_> = column(vec![list, separator, self.view_form()])
+            .into();
+
+        container(content)
+            .padding(Padding::new(4.0))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+
+    fn view_quicklink<'a>(&'a self, quicklink: &'a SettingsQuicklink) -> Element<'a, ManagementAppQuicklinksMsgIn> {
+        let name: Element<_> = text(&quicklink.name)
+            .shaping(Shaping::Advanced)
+            .width(Length::FillPortion(2))
+            .into();
+
+        let url: Element<_> = text(&quicklink.url)
+            .shaping(Shaping::Advanced)
+            .class(TextStyle::Subtitle)
+            .width(Length::FillPortion(5))
+            .into();
+
+        let bang: Element<_> = text(quicklink.bang.as_ref().map(|bang| format!("!{}", bang)).unwrap_or_default())
+            .class(TextStyle::Subtitle)
+            .width(Length::FillPortion(1))
+            .into();
+
+        let fallback: Element<_> = if quicklink.fallback {
+            value(Bootstrap::Globe)
+                .font(BOOTSTRAP_FONT)
+                .into()
+        } else {
+            text("").into()
+        };
+
+        let edit_icon: Element<_> = value(Bootstrap::PencilFill)
+            .font(BOOTSTRAP_FONT)
+            .into();
+
+        let edit_button: Element<_> = button(edit_icon)
+            .class(ButtonStyle::Primary)
+            .on_press(ManagementAppQuicklinksMsgIn::EditQuicklink(quicklink.clone()))
+            .into();
+
+        let remove_icon: Element<_> = value(Bootstrap::TrashFill)
+            .font(BOOTSTRAP_FONT)
+            .into();
+
+        let remove_button: Element<_> = button(remove_icon)
+            .class(ButtonStyle::Destructive)
+            .on_press(ManagementAppQuicklinksMsgIn::RemoveQuicklink { id: quicklink.id.clone() })
+            .into();
+
+        row(vec![name, url, bang, fallback, edit_button, remove_button])
+            .spacing(8.0)
+            .padding(8.0)
+            .align_y(Alignment::Center)
+            .into()
+    }
+
+    fn view_form(&self) -> Element<ManagementAppQuicklinksMsgIn> {
+        let name_input: Element<_> = text_input(&tr!("settings-quicklinks-name"), &self.name)
+            .on_input(ManagementAppQuicklinksMsgIn::NameChanged)
+            .on_submit(ManagementAppQuicklinksMsgIn::SaveQuicklink)
+            .width(Length::FillPortion(2))
+            .into();
+
+        let url_input: Element<_> = text_input("https://github.com/search?q={query}", &self.url)
+            .on_input(ManagementAppQuicklinksMsgIn::UrlChanged)
+            .on_submit(ManagementAppQuicklinksMsgIn::SaveQuicklink)
+            .width(Length::FillPortion(5))
+            .into();
+
+        let bang_input: Element<_> = text_input(&tr!("settings-quicklinks-bang"), &self.bang)
+            .on_input(ManagementAppQuicklinksMsgIn::BangChanged)
+            .on_submit(ManagementAppQuicklinksMsgIn::SaveQuicklink)
+            .width(Length::FillPortion(1))
+            .into();
+
+        let fallback_checkbox: Element<_> = checkbox(tr!("settings-quicklinks-fallback"), self.fallback)
+            .on_toggle(ManagementAppQuicklinksMsgIn::FallbackToggled)
+            .into();
+
+        let save_text: Element<_> = if self.editing_id.is_some() {
+            text(tr!("settings-quicklinks-save")).into()
+        } else {
+            text(tr!("settings-quicklinks-add")).into()
+        };
+
+        let save_button: Element<_> = button(save_text)
+            .class(ButtonStyle::Positive)
+            .on_press(ManagementAppQuicklinksMsgIn::SaveQuicklink)
+            .into();
+
+        let mut content = vec![name_input, url_input, bang_input, fallback_checkbox, save_button];
+
+        if self.editing_id.is_some() {
+            let cancel_button: Element<_> = button(text(tr!("settings-quicklinks-cancel")))
+                .class(ButtonStyle::Primary)
+                .on_press(ManagementAppQuicklinksMsgIn::CancelEdit)
+                .into();
+
+            content.push(cancel_button);
+        }
+
+        row(content)
+            .spacing(8.0)
+            .padding(8.0)
+            .align_y(Alignment::Center)
+            .into()
+    }
+}
+
+pub fn handle_backend_error<T>(result: Result<T, BackendApiError>, convert: impl FnOnce(T) -> ManagementAppQuicklinksMsgOut) -> ManagementAppQuicklinksMsgOut {
+    match result {
+        Ok(val) => convert(val),
+        Err(err) => ManagementAppQuicklinksMsgOut::HandleBackendError(err)
+    }
+}
@@ -0,0 +1,153 @@
+use crate::theme::button::ButtonStyle;
+use crate::theme::text::TextStyle;
+use crate::theme::Element;
+use gauntlet_common::dirs::Dirs;
+use gauntlet_common::rpc::backend_api::{BackendApi, BackendApiError};
+use gauntlet_common::tr;
+use iced::widget::text::Shaping;
+use iced::widget::{button, column, container, horizontal_rule, horizontal_space, row, scrollable, text, value};
+use iced::{Alignment, Font, Length, Padding, Task};
+use iced_fonts::{Bootstrap, BOOTSTRAP_FONT};
+
+const RECENT_LOG_LINES_LIMIT: u32 = 500;
+
+pub struct ManagementAppTroubleshootingState {
+    backend_api: Option<BackendApi>,
+    log_lines: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum ManagementAppTroubleshootingMsgIn {
+    FetchLogs,
+    LogsFetched(Vec<String>),
+}
+
+#[derive(Debug, Clone)]
+pub enum ManagementAppTroubleshootingMsgOut {
+    LogsReloaded(Vec<String>),
+    HandleBackendError(BackendApiError)
+}
+
+impl ManagementAppTroubleshootingState {
+    pub fn new(backend_api: Option<BackendApi>) -> Self {
+        Self {
+            backend_api,
+            log_lines: vec![],
+        }
+    }
+
+    pub fn update(&mut self, message: ManagementAppTroubleshootingMsgIn) -> Task<ManagementAppTroubleshootingMsgOut> {
+        let backend_api = match &self.backend_api {
+            Some(backend_api) => backend_api.clone(),
+            None => {
+                return Task::none()
+            }
+        };
+
+        match message {
+            ManagementAppTroubleshootingMsgIn::FetchLogs => {
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    let log_lines = backend_api.recent_log_lines(RECENT_LOG_LINES_LIMIT)
+                        .await?;
+
+                    Ok(log_lines)
+                }, |result| handle_backend_error(result, |log_lines| ManagementAppTroubleshootingMsgOut::LogsReloaded(log_lines)))
+            }
+            ManagementAppTroubleshootingMsgIn::LogsFetched(log_lines) => {
+                self.log_lines = log_lines;
+
+                Task::none()
+            }
+        }
+    }
+
+    pub fn view(&self) -> Element<ManagementAppTroubleshootingMsgIn> {
+        let log_dir = Dirs::new().log_dir();
+
+        let log_dir: Element<_> = text(tr!("settings-troubleshooting-log-dir", path = log_dir.display().to_string()))
+            .shaping(Shaping::Advanced)
+            .class(TextStyle::Subtitle)
+            .into();
+
+        let space: Element<_> = horizontal_space()
+            .into();
+
+        let refresh_icon: Element<_> = value(Bootstrap::ArrowClockwise)
+            .font(BOOTSTRAP_FONT)
+            .into();
+
+        let refresh_text: Element<_> = text(tr!("settings-troubleshooting-refresh"))
+            .into();
+
+        let refresh_content: Element<_> = row(vec![refresh_icon, refresh_text])
+            .spacing(8.0)
+            .align_y(Alignment::Center)
+            .into();
+
+        let refresh_button: Element<_> = button(refresh_content)
+            .class(ButtonStyle::Primary)
+            .on_press(ManagementAppTroubleshootingMsgIn::FetchLogs)
+            .into();
+
+        let header: Element<_> = row(vec![log_dir, space, refresh_button])
+            .spacing(8.0)
+            .padding(8.0)
+            .align_y(Alignment::Center)
+            .into();
+
+        let separator: Element<_> = horizontal_rule(1)
+            .into();
+
+        let logs: Element<_> = if self.log_lines.is_empty() {
+            let empty: Element<_> = text(tr!("settings-troubleshooting-no-logs"))
+                .class(TextStyle::Subtitle)
+                .into();
+
+            container(empty)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .align_x(Alignment::Center)
+                .align_y(Alignment::Center)
+                .into()
+        } else {
+            let lines: Vec<Element<_>> = self.log_lines.iter()
+                .map(|line| {
+                    text(line)
+                        .shaping(Shaping::Advanced)
+                        .font(Font::MONOSPACE)
+                        .size(12)
+                        .into()
+                })
+                .collect();
+
+            let lines: Element<_> = column(lines)
+                .padding(8.0)
+                .width(Length::Fill)
+                .into();
+
+            scrollable(lines)
+                .anchor_bottom()
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .into()
+        };
+
+        let content: Element<_> = column(vec![header, separator, logs])
+            .into();
+
+        container(content)
+            .padding(Padding::new(4.0))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+}
+
+pub fn handle_backend_error<T>(result: Result<T, BackendApiError>, convert: impl FnOnce(T) -> ManagementAppTroubleshootingMsgOut) -> ManagementAppTroubleshootingMsgOut {
+    match result {
+        Ok(val) => convert(val),
+        Err(err) => ManagementAppTroubleshootingMsgOut::HandleBackendError(err)
+    }
+}
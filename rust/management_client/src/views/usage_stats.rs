@@ -0,0 +1,247 @@
+use crate::theme::button::ButtonStyle;
+use crate::theme::text::TextStyle;
+use crate::theme::Element;
+use gauntlet_common::model::SettingsUsageStats;
+use gauntlet_common::rpc::backend_api::{BackendApi, BackendApiError};
+use gauntlet_common::tr;
+use iced::widget::text::Shaping;
+use iced::widget::{button, checkbox, column, container, horizontal_rule, horizontal_space, row, scrollable, text, value};
+use iced::{Alignment, Length, Padding, Task};
+use iced_fonts::{Bootstrap, BOOTSTRAP_FONT};
+
+const MOST_USED_LIMIT: usize = 20;
+
+pub struct ManagementAppUsageStatsState {
+    backend_api: Option<BackendApi>,
+    enabled: bool,
+    stats: Option<SettingsUsageStats>,
+}
+
+#[derive(Debug, Clone)]
+pub enum ManagementAppUsageStatsMsgIn {
+    FetchUsageStats,
+    UsageStatsFetched {
+        enabled: bool,
+        stats: SettingsUsageStats
+    },
+    ToggleEnabled(bool),
+    ClearUsageStats,
+}
+
+#[derive(Debug, Clone)]
+pub enum ManagementAppUsageStatsMsgOut {
+    UsageStatsReloaded {
+        enabled: bool,
+        stats: SettingsUsageStats
+    },
+    HandleBackendError(BackendApiError)
+}
+
+impl ManagementAppUsageStatsState {
+    pub fn new(backend_api: Option<BackendApi>) -> Self {
+        Self {
+            backend_api,
+            enabled: false,
+            stats: None,
+        }
+    }
+
+    pub fn update(&mut self, message: ManagementAppUsageStatsMsgIn) -> Task<ManagementAppUsageStatsMsgOut> {
+        let backend_api = match &self.backend_api {
+            Some(backend_api) => backend_api.clone(),
+            None => {
+                return Task::none()
+            }
+        };
+
+        match message {
+            ManagementAppUsageStatsMsgIn::FetchUsageStats => {
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    fetch_usage_stats(&mut backend_api).await
+                }, |result| handle_backend_error(result, |(enabled, stats)| ManagementAppUsageStatsMsgOut::UsageStatsReloaded { enabled, stats }))
+            }
+            ManagementAppUsageStatsMsgIn::UsageStatsFetched { enabled, stats } => {
+                self.enabled = enabled;
+                self.stats = Some(stats);
+
+                Task::none()
+            }
+            ManagementAppUsageStatsMsgIn::ToggleEnabled(enabled) => {
+                self.enabled = enabled;
+
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.set_usage_stats_enabled(enabled)
+                        .await?;
+
+                    fetch_usage_stats(&mut backend_api).await
+                }, |result| handle_backend_error(result, |(enabled, stats)| ManagementAppUsageStatsMsgOut::UsageStatsReloaded { enabled, stats }))
+            }
+            ManagementAppUsageStatsMsgIn::ClearUsageStats => {
+                let mut backend_api = backend_api.clone();
+
+                Task::perform(async move {
+                    backend_api.clear_usage_stats()
+                        .await?;
+
+                    fetch_usage_stats(&mut backend_api).await
+                }, |result| handle_backend_error(result, |(enabled, stats)| ManagementAppUsageStatsMsgOut::UsageStatsReloaded { enabled, stats }))
+            }
+        }
+    }
+
+    pub fn view(&self) -> Element<ManagementAppUsageStatsMsgIn> {
+        let enabled_checkbox: Element<_> = checkbox(tr!("settings-usage-stats-enabled"), self.enabled)
+            .on_toggle(ManagementAppUsageStatsMsgIn::ToggleEnabled)
+            .into();
+
+        let description: Element<_> = text(tr!("settings-usage-stats-description"))
+            .shaping(Shaping::Advanced)
+            .class(TextStyle::Subtitle)
+            .into();
+
+        let header_text: Element<_> = column(vec![enabled_checkbox, description])
+            .spacing(8.0)
+            .into();
+
+        let space: Element<_> = horizontal_space()
+            .into();
+
+        let clear_icon: Element<_> = value(Bootstrap::TrashFill)
+            .font(BOOTSTRAP_FONT)
+            .into();
+
+        let clear_text: Element<_> = text(tr!("settings-usage-stats-clear"))
+            .into();
+
+        let clear_content: Element<_> = row(vec![clear_icon, clear_text])
+            .spacing(8.0)
+            .align_y(Alignment::Center)
+            .into();
+
+        let clear_button: Element<_> = button(clear_content)
+            .class(ButtonStyle::Destructive)
+            .on_press(ManagementAppUsageStatsMsgIn::ClearUsageStats)
+            .into();
+
+        let header: Element<_> = row(vec![header_text, space, clear_button])
+            .spacing(8.0)
+            .padding(8.0)
+            .align_y(Alignment::Center)
+            .into();
+
+        let separator: Element<_> = horizontal_rule(1)
+            .into();
+
+        let content: Element<_> = match &self.stats {
+            Some(stats) if !stats.entrypoints.is_empty() || !stats.unused_plugins.is_empty() => {
+                scrollable(self.view_stats(stats))
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .into()
+            }
+            _ => {
+                let empty: Element<_> = text(tr!("settings-usage-stats-empty"))
+                    .class(TextStyle::Subtitle)
+                    .into();
+
+                container(empty)
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .align_x(Alignment::Center)
+                    .align_y(Alignment::Center)
+                    .into()
+            }
+        };
+
+        let content: Element<_> = column(vec![header, separator, content])
+            .into();
+
+        container(content)
+            .padding(Padding::new(4.0))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+
+    fn view_stats<'a>(&'a self, stats: &'a SettingsUsageStats) -> Element<'a, ManagementAppUsageStatsMsgIn> {
+        let mut content = vec![];
+
+        let average = match stats.average_search_to_launch {
+            None => tr!("settings-usage-stats-unknown"),
+            Some(duration) => format!("{:.1}s", duration.as_secs_f64()),
+        };
+
+        let average: Element<_> = text(tr!("settings-usage-stats-search-to-launch", duration = average))
+            .into();
+
+        content.push(average);
+
+        let most_used_title: Element<_> = text(tr!("settings-usage-stats-most-used"))
+            .class(TextStyle::Subtitle)
+            .into();
+
+        content.push(most_used_title);
+
+        for entrypoint in stats.entrypoints.iter().take(MOST_USED_LIMIT) {
+            let name: Element<_> = text(format!("{} - {}", entrypoint.entrypoint_name, entrypoint.plugin_name))
+                .shaping(Shaping::Advanced)
+                .width(Length::Fill)
+                .into();
+
+            let launch_count: Element<_> = text(tr!("settings-usage-stats-launch-count", count = entrypoint.launch_count))
+                .class(TextStyle::Subtitle)
+                .into();
+
+            let entrypoint: Element<_> = row(vec![name, launch_count])
+                .spacing(8.0)
+                .padding(Padding::new(0.0).left(16.0))
+                .into();
+
+            content.push(entrypoint);
+        }
+
+        let unused_title: Element<_> = text(tr!("settings-usage-stats-unused-plugins"))
+            .class(TextStyle::Subtitle)
+            .into();
+
+        content.push(unused_title);
+
+        for plugin in &stats.unused_plugins {
+            let plugin: Element<_> = text(&plugin.plugin_name)
+                .shaping(Shaping::Advanced)
+                .into();
+
+            let plugin: Element<_> = container(plugin)
+                .padding(Padding::new(0.0).left(16.0))
+                .into();
+
+            content.push(plugin);
+        }
+
+        column(content)
+            .spacing(8.0)
+            .padding(8.0)
+            .into()
+    }
+}
+
+async fn fetch_usage_stats(backend_api: &mut BackendApi) -> Result<(bool, SettingsUsageStats), BackendApiError> {
+    let enabled = backend_api.get_usage_stats_enabled()
+        .await?;
+
+    let stats = backend_api.usage_stats()
+        .await?;
+
+    Ok((enabled, stats))
+}
+
+pub fn handle_backend_error<T>(result: Result<T, BackendApiError>, convert: impl FnOnce(T) -> ManagementAppUsageStatsMsgOut) -> ManagementAppUsageStatsMsgOut {
+    match result {
+        Ok(val) => convert(val),
+        Err(err) => ManagementAppUsageStatsMsgOut::HandleBackendError(err)
+    }
+}
@@ -1,6 +1,6 @@
-use crate::model::{JsGeneratedSearchItem, JsClipboardData, JsPreferenceUserData};
+use crate::model::{JsGeneratedSearchItem, JsClipboardData, JsOAuthConfig, JsOAuthTokens, JsPreferenceUserData};
 use crate::{JsRequest, JsResponse, JsUiRenderLocation};
-use gauntlet_common::model::{EntrypointId, RootWidget, UiRenderLocation};
+use gauntlet_common::model::{EntrypointId, PhysicalShortcut, PluginId, RootWidget, UiRenderLocation};
 use std::collections::HashMap;
 use anyhow::anyhow;
 use gauntlet_utils::channel::{RequestError, RequestSender};
@@ -10,18 +10,30 @@ pub trait BackendForPluginRuntimeApi {
     async fn reload_search_index(&self, generated_entrypoints: Vec<JsGeneratedSearchItem>, refresh_search_list: bool) -> anyhow::Result<()> ;
     async fn get_asset_data(&self, path: &str) -> anyhow::Result<Vec<u8>>;
     async fn get_entrypoint_generator_entrypoint_ids(&self) -> anyhow::Result<Vec<String>>;
+    async fn get_search_provider_entrypoint_ids(&self) -> anyhow::Result<Vec<String>>;
+    async fn search_provider_results(&self, search_id: u32, entrypoint_id: EntrypointId, results: Vec<JsGeneratedSearchItem>) -> anyhow::Result<()>;
     async fn get_plugin_preferences(&self) -> anyhow::Result<HashMap<String, JsPreferenceUserData>>;
     async fn get_entrypoint_preferences(&self, entrypoint_id: EntrypointId) -> anyhow::Result<HashMap<String, JsPreferenceUserData>>;
     async fn plugin_preferences_required(&self) -> anyhow::Result<bool>;
     async fn entrypoint_preferences_required(&self, entrypoint_id: EntrypointId) -> anyhow::Result<bool>;
+    async fn plugin_onboarding_required(&self) -> anyhow::Result<bool>;
     async fn clipboard_read(&self) -> anyhow::Result<JsClipboardData>;
     async fn clipboard_read_text(&self) -> anyhow::Result<Option<String>>;
     async fn clipboard_write(&self, data: JsClipboardData) -> anyhow::Result<()>;
     async fn clipboard_write_text(&self, data: String) -> anyhow::Result<()>;
+    async fn clipboard_write_sensitive_text(&self, data: String) -> anyhow::Result<()>;
+    async fn clipboard_paste_text(&self, data: String, sensitive: bool) -> anyhow::Result<()>;
     async fn clipboard_clear(&self) -> anyhow::Result<()>;
+    async fn run_in_terminal(&self, command: Vec<String>, cwd: Option<String>) -> anyhow::Result<()>;
+    async fn run_command(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, arguments: Vec<String>) -> anyhow::Result<()>;
+    async fn oauth_authorize(&self, config: JsOAuthConfig) -> anyhow::Result<Option<JsOAuthTokens>>;
+    async fn oauth_remove_tokens(&self, config: JsOAuthConfig) -> anyhow::Result<()>;
     async fn ui_update_loading_bar(&self, entrypoint_id: EntrypointId, show: bool) -> anyhow::Result<()>;
     async fn ui_show_hud(&self, display: String) -> anyhow::Result<()>;
+    async fn ui_show_notification(&self, title: String, body: Option<String>, actions: Vec<String>) -> anyhow::Result<u32>;
     async fn ui_hide_window(&self) -> anyhow::Result<()>;
+    async fn ui_open_settings(&self) -> anyhow::Result<()>;
+    async fn ui_show_window_with_search_query(&self, query: String) -> anyhow::Result<()>;
     async fn ui_get_action_id_for_shortcut(
         &self,
         entrypoint_id: EntrypointId,
@@ -37,6 +49,8 @@ pub trait BackendForPluginRuntimeApi {
         entrypoint_name: String,
         render_location: UiRenderLocation,
         top_level_view: bool,
+        breadcrumbs: Vec<String>,
+        key_handlers: Vec<PhysicalShortcut>,
         container: RootWidget,
     ) -> anyhow::Result<()>;
     async fn ui_show_plugin_error_view(
@@ -44,12 +58,7 @@ pub trait BackendForPluginRuntimeApi {
         entrypoint_id: EntrypointId,
         render_location: UiRenderLocation
     ) -> anyhow::Result<()>;
-    async fn ui_show_preferences_required_view(
-        &self,
-        entrypoint_id: EntrypointId,
-        plugin_preferences_required: bool,
-        entrypoint_preferences_required: bool
-    ) -> anyhow::Result<()>;
+    async fn ui_show_onboarding_view(&self, entrypoint_id: EntrypointId) -> anyhow::Result<()>;
     async fn ui_clear_inline_view(&self) -> anyhow::Result<()>;
 }
 
@@ -111,6 +120,28 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiProxy {
         }
     }
 
+    async fn get_search_provider_entrypoint_ids(&self) -> anyhow::Result<Vec<String>> {
+        let request = JsRequest::GetSearchProviderEntrypointIds;
+
+        match self.request(request).await? {
+            JsResponse::SearchProviderEntrypointIds { data } => Ok(data),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
+    async fn search_provider_results(&self, search_id: u32, entrypoint_id: EntrypointId, results: Vec<JsGeneratedSearchItem>) -> anyhow::Result<()> {
+        let request = JsRequest::SearchProviderResults {
+            search_id,
+            entrypoint_id,
+            results,
+        };
+
+        match self.request(request).await? {
+            JsResponse::Nothing => Ok(()),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
     async fn get_plugin_preferences(&self) -> anyhow::Result<HashMap<String, JsPreferenceUserData>> {
         let request = JsRequest::GetPluginPreferences;
 
@@ -151,6 +182,15 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiProxy {
         }
     }
 
+    async fn plugin_onboarding_required(&self) -> anyhow::Result<bool> {
+        let request = JsRequest::PluginOnboardingRequired;
+
+        match self.request(request).await? {
+            JsResponse::PluginOnboardingRequired { data } => Ok(data),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
     async fn clipboard_read(&self) -> anyhow::Result<JsClipboardData> {
         let request = JsRequest::ClipboardRead;
 
@@ -191,6 +231,29 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiProxy {
         }
     }
 
+    async fn clipboard_write_sensitive_text(&self, data: String) -> anyhow::Result<()> {
+        let request = JsRequest::ClipboardWriteSensitiveText {
+            data,
+        };
+
+        match self.request(request).await? {
+            JsResponse::Nothing => Ok(()),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
+    async fn clipboard_paste_text(&self, data: String, sensitive: bool) -> anyhow::Result<()> {
+        let request = JsRequest::ClipboardPasteText {
+            data,
+            sensitive,
+        };
+
+        match self.request(request).await? {
+            JsResponse::Nothing => Ok(()),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
     async fn clipboard_clear(&self) -> anyhow::Result<()> {
         let request = JsRequest::ClipboardClear;
 
@@ -200,6 +263,53 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiProxy {
         }
     }
 
+    async fn run_in_terminal(&self, command: Vec<String>, cwd: Option<String>) -> anyhow::Result<()> {
+        let request = JsRequest::RunInTerminal {
+            command,
+            cwd,
+        };
+
+        match self.request(request).await? {
+            JsResponse::Nothing => Ok(()),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
+    async fn run_command(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, arguments: Vec<String>) -> anyhow::Result<()> {
+        let request = JsRequest::RunCommand {
+            plugin_id,
+            entrypoint_id,
+            arguments,
+        };
+
+        match self.request(request).await? {
+            JsResponse::Nothing => Ok(()),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
+    async fn oauth_authorize(&self, config: JsOAuthConfig) -> anyhow::Result<Option<JsOAuthTokens>> {
+        let request = JsRequest::OAuthAuthorize {
+            config,
+        };
+
+        match self.request(request).await? {
+            JsResponse::OAuthAuthorize { data } => Ok(data),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
+    async fn oauth_remove_tokens(&self, config: JsOAuthConfig) -> anyhow::Result<()> {
+        let request = JsRequest::OAuthRemoveTokens {
+            config,
+        };
+
+        match self.request(request).await? {
+            JsResponse::Nothing => Ok(()),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
     async fn ui_update_loading_bar(&self, entrypoint_id: EntrypointId, show: bool) -> anyhow::Result<()> {
         let request = JsRequest::UpdateLoadingBar {
             entrypoint_id,
@@ -223,6 +333,19 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiProxy {
         }
     }
 
+    async fn ui_show_notification(&self, title: String, body: Option<String>, actions: Vec<String>) -> anyhow::Result<u32> {
+        let request = JsRequest::ShowNotification {
+            title,
+            body,
+            actions,
+        };
+
+        match self.request(request).await? {
+            JsResponse::NotificationId { data } => Ok(data),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
     async fn ui_hide_window(&self) -> anyhow::Result<()> {
         let request = JsRequest::HideWindow;
 
@@ -232,6 +355,26 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiProxy {
         }
     }
 
+    async fn ui_open_settings(&self) -> anyhow::Result<()> {
+        let request = JsRequest::OpenSettings;
+
+        match self.request(request).await? {
+            JsResponse::Nothing => Ok(()),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
+    async fn ui_show_window_with_search_query(&self, query: String) -> anyhow::Result<()> {
+        let request = JsRequest::ShowWindowWithSearchQuery {
+            query,
+        };
+
+        match self.request(request).await? {
+            JsResponse::Nothing => Ok(()),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value)
+        }
+    }
+
     async fn ui_get_action_id_for_shortcut(&self, entrypoint_id: EntrypointId, key: String, modifier_shift: bool, modifier_control: bool, modifier_alt: bool, modifier_meta: bool) -> anyhow::Result<Option<String>> {
         let request = JsRequest::GetActionIdForShortcut {
             entrypoint_id,
@@ -254,6 +397,8 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiProxy {
         entrypoint_name: String,
         render_location: UiRenderLocation,
         top_level_view: bool,
+        breadcrumbs: Vec<String>,
+        key_handlers: Vec<PhysicalShortcut>,
         container: RootWidget,
     ) -> anyhow::Result<()> {
         let request = JsRequest::Render {
@@ -264,6 +409,8 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiProxy {
                 UiRenderLocation::View => JsUiRenderLocation::View
             },
             top_level_view,
+            breadcrumbs,
+            key_handlers,
             container,
         };
 
@@ -288,11 +435,9 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiProxy {
         }
     }
 
-    async fn ui_show_preferences_required_view(&self, entrypoint_id: EntrypointId, plugin_preferences_required: bool, entrypoint_preferences_required: bool) -> anyhow::Result<()> {
-        let request = JsRequest::ShowPreferenceRequiredView {
+    async fn ui_show_onboarding_view(&self, entrypoint_id: EntrypointId) -> anyhow::Result<()> {
+        let request = JsRequest::ShowOnboardingView {
             entrypoint_id,
-            plugin_preferences_required,
-            entrypoint_preferences_required,
         };
 
         match self.request(request).await? {
@@ -84,6 +84,36 @@ pub async fn clipboard_write_text(state: Rc<RefCell<OpState>>, #[string] data: S
     api.clipboard_write_text(data).await
 }
 
+#[op2(async)]
+pub async fn clipboard_write_sensitive_text(state: Rc<RefCell<OpState>>, #[string] data: String) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state
+            .borrow::<BackendForPluginRuntimeApiProxy>()
+            .clone();
+
+        api
+    };
+
+    api.clipboard_write_sensitive_text(data).await
+}
+
+#[op2(async)]
+pub async fn clipboard_paste_text(state: Rc<RefCell<OpState>>, #[string] data: String, sensitive: bool) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state
+            .borrow::<BackendForPluginRuntimeApiProxy>()
+            .clone();
+
+        api
+    };
+
+    api.clipboard_paste_text(data, sensitive).await
+}
+
 #[op2(async)]
 pub async fn clipboard_clear(state: Rc<RefCell<OpState>>) -> anyhow::Result<()> {
     let api = {
@@ -0,0 +1,239 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+use anyhow::{anyhow, Context};
+use deno_core::{op2, OpState, ToJsBuffer};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rusqlite::hooks::{AuthAction, AuthContext, Authorization};
+use rusqlite::limits::Limit;
+use rusqlite::types::{Value, ValueRef};
+use rusqlite::{params_from_iter, Connection};
+use serde::{Deserialize, Serialize};
+
+static DATABASE_NAME_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[a-zA-Z0-9_-]+$").expect("invalid regex"));
+
+// sqlite databases opened by plugin using Database.open, stored in "databases" directory inside plugin data directory,
+// so they are removed together with it when plugin is uninstalled. queries run on blocking thread pool
+#[derive(Clone)]
+pub struct PluginDatabases(Rc<RefCell<PluginDatabasesInner>>);
+
+struct PluginDatabasesInner {
+    dir: PathBuf,
+    next_id: u32,
+    connections: HashMap<u32, Arc<Mutex<Connection>>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum DatabaseParam {
+    Bool(bool),
+    Number(f64),
+    Text(String),
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+pub enum DatabaseValue {
+    Null,
+    Number(f64),
+    Text(String),
+    Blob(ToJsBuffer),
+}
+
+#[derive(Serialize)]
+pub struct DatabaseQueryResult {
+    columns: Vec<String>,
+    rows: Vec<Vec<DatabaseValue>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DatabaseExecuteResult {
+    changes: usize,
+    #[serde(rename = "lastInsertRowId")]
+    last_insert_row_id: f64,
+}
+
+impl PluginDatabases {
+    pub fn new(dir: PathBuf) -> Self {
+        Self(Rc::new(RefCell::new(PluginDatabasesInner {
+            dir,
+            next_id: 0,
+            connections: HashMap::new(),
+        })))
+    }
+}
+
+#[op2(async)]
+pub async fn database_open(state: Rc<RefCell<OpState>>, #[string] name: String) -> anyhow::Result<u32> {
+    if !DATABASE_NAME_PATTERN.is_match(&name) {
+        return Err(anyhow!("Invalid database name, only letters, numbers, '-' and '_' are allowed: {:?}", name));
+    }
+
+    let databases = plugin_databases(&state);
+
+    let dir = databases.0.borrow().dir.clone();
+
+    let connection = tokio::task::spawn_blocking(move || {
+        std::fs::create_dir_all(&dir)
+            .context("Unable to create databases directory")?;
+
+        let path = dir.join(format!("{}.sqlite", name));
+
+        tracing::debug!("Opening database: {:?}", path);
+
+        open_connection(&path)
+            .with_context(|| format!("Unable to open database: {}", name))
+    }).await??;
+
+    let mut databases = databases.0.borrow_mut();
+
+    let id = databases.next_id;
+    databases.next_id += 1;
+
+    databases.connections.insert(id, Arc::new(Mutex::new(connection)));
+
+    Ok(id)
+}
+
+// other database files can't be attached, otherwise plugin would be able to read and write any file,
+// e.g. using "ATTACH DATABASE" or "VACUUM INTO". VACUUM attaches temporary database internally, so it is not available either
+fn open_connection(path: &Path) -> anyhow::Result<Connection> {
+    let connection = Connection::open(path)?;
+
+    connection.set_limit(Limit::SQLITE_LIMIT_ATTACHED, 0);
+
+    connection.authorizer(Some(|context: AuthContext<'_>| {
+        match context.action {
+            AuthAction::Attach { .. } => Authorization::Deny,
+            _ => Authorization::Allow,
+        }
+    }));
+
+    Ok(connection)
+}
+
+#[op2(async)]
+#[serde]
+pub async fn database_query(
+    state: Rc<RefCell<OpState>>,
+    id: u32,
+    #[string] sql: String,
+    #[serde] params: Vec<Option<DatabaseParam>>,
+) -> anyhow::Result<DatabaseQueryResult> {
+    let connection = connection(&state, id)?;
+
+    tokio::task::spawn_blocking(move || -> anyhow::Result<_> {
+        let connection = connection.lock().unwrap();
+
+        let mut statement = connection.prepare(&sql)?;
+
+        let columns: Vec<String> = statement.column_names()
+            .into_iter()
+            .map(|name| name.to_string())
+            .collect();
+
+        let mut rows = statement.query(params_from_iter(params.into_iter().map(param_to_value)))?;
+
+        let mut result = vec![];
+
+        while let Some(row) = rows.next()? {
+            let row = (0..columns.len())
+                .map(|index| Ok(value_from_ref(row.get_ref(index)?)))
+                .collect::<anyhow::Result<Vec<_>>>()?;
+
+            result.push(row);
+        }
+
+        Ok(DatabaseQueryResult { columns, rows: result })
+    }).await?
+}
+
+#[op2(async)]
+#[serde]
+pub async fn database_execute(
+    state: Rc<RefCell<OpState>>,
+    id: u32,
+    #[string] sql: String,
+    #[serde] params: Vec<Option<DatabaseParam>>,
+) -> anyhow::Result<DatabaseExecuteResult> {
+    let connection = connection(&state, id)?;
+
+    tokio::task::spawn_blocking(move || -> anyhow::Result<_> {
+        let connection = connection.lock().unwrap();
+
+        let changes = connection.prepare(&sql)?
+            .execute(params_from_iter(params.into_iter().map(param_to_value)))?;
+
+        Ok(DatabaseExecuteResult {
+            changes,
+            last_insert_row_id: connection.last_insert_rowid() as f64,
+        })
+    }).await?
+}
+
+// connection is closed when the last running query finishes
+#[op2(fast)]
+pub fn database_close(state: Rc<RefCell<OpState>>, id: u32) {
+    plugin_databases(&state).0.borrow_mut().connections.remove(&id);
+}
+
+fn plugin_databases(state: &Rc<RefCell<OpState>>) -> PluginDatabases {
+    let state = state.borrow();
+
+    state
+        .borrow::<PluginDatabases>()
+        .clone()
+}
+
+fn connection(state: &Rc<RefCell<OpState>>, id: u32) -> anyhow::Result<Arc<Mutex<Connection>>> {
+    plugin_databases(state).0.borrow()
+        .connections
+        .get(&id)
+        .cloned()
+        .ok_or(anyhow!("Database is closed"))
+}
+
+// js numbers without fractional part are bound as integers
+fn param_to_value(param: Option<DatabaseParam>) -> Value {
+    match param {
+        None => Value::Null,
+        Some(DatabaseParam::Bool(value)) => Value::Integer(value as i64),
+        Some(DatabaseParam::Number(value)) if value.fract() == 0.0 && value.abs() < i64::MAX as f64 => Value::Integer(value as i64),
+        Some(DatabaseParam::Number(value)) => Value::Real(value),
+        Some(DatabaseParam::Text(value)) => Value::Text(value),
+    }
+}
+
+fn value_from_ref(value: ValueRef) -> DatabaseValue {
+    match value {
+        ValueRef::Null => DatabaseValue::Null,
+        ValueRef::Integer(value) => DatabaseValue::Number(value as f64),
+        ValueRef::Real(value) => DatabaseValue::Number(value),
+        ValueRef::Text(value) => DatabaseValue::Text(String::from_utf8_lossy(value).to_string()),
+        ValueRef::Blob(value) => DatabaseValue::Blob(value.to_vec().into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attach_is_denied() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let connection = open_connection(&dir.path().join("plugin.sqlite")).unwrap();
+
+        connection.execute("CREATE TABLE item (name TEXT)", []).unwrap();
+
+        let other = dir.path().join("other.sqlite");
+
+        assert!(connection.execute(&format!("ATTACH DATABASE '{}' AS other", other.display()), []).is_err());
+        assert!(connection.execute(&format!("VACUUM INTO '{}'", other.display()), []).is_err());
+        assert!(!other.exists());
+    }
+}
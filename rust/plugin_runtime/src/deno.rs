@@ -1,11 +1,14 @@
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fs::File;
+use std::future::Future;
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::path::{Path, PathBuf};
 use std::pin::Pin;
 use std::rc::Rc;
 use std::sync::Arc;
 use anyhow::{anyhow, Context};
-use deno_core::{FastString, ModuleLoadResponse, ModuleLoader, ModuleSource, ModuleSourceCode, ModuleSpecifier, ModuleType, RequestedModuleType, ResolutionKind, StaticModuleLoader};
+use deno_core::{FastString, ModuleLoadResponse, ModuleLoader, ModuleSource, ModuleSourceCode, ModuleSpecifier, ModuleType, RequestedModuleType, ResolutionKind, SourceCodeCacheInfo, StaticModuleLoader};
 use deno_core::futures::Stream;
 use deno_core::url::Url;
 use deno_runtime::BootstrapOptions;
@@ -19,22 +22,34 @@ use tokio::sync::mpsc::Receiver;
 use gauntlet_common::model::PluginId;
 use crate::api::BackendForPluginRuntimeApiProxy;
 use crate::assets::{asset_data, asset_data_blocking};
-use crate::clipboard::{clipboard_clear, clipboard_read, clipboard_read_text, clipboard_write, clipboard_write_text};
+use crate::clipboard::{clipboard_clear, clipboard_paste_text, clipboard_read, clipboard_read_text, clipboard_write, clipboard_write_sensitive_text, clipboard_write_text};
+use crate::oauth::{oauth_authorize, oauth_remove_tokens};
+use crate::terminal::run_in_terminal;
+use crate::subprocess::{subprocess_kill, subprocess_read, subprocess_spawn, Subprocesses};
+use crate::database::{database_close, database_execute, database_open, database_query, PluginDatabases};
+use crate::fs::{fs_read_dir, fs_read_file, fs_read_text_file, fs_stat, fs_unwatch, fs_watch, fs_watch_next, fs_write_file, fs_write_text_file, PluginFileSystem};
+use crate::http_cache::{op_http_cache_config, op_http_cache_get, op_http_cache_put, op_http_cache_refresh, HttpCache};
 use crate::entrypoint_generators::get_entrypoint_generator_entrypoint_ids;
 use crate::component_model::ComponentModel;
-use crate::environment::{environment_gauntlet_version, environment_is_development, environment_plugin_cache_dir, environment_plugin_data_dir};
+use crate::environment::{environment_gauntlet_version, environment_is_development, environment_plugin_cache_dir, environment_plugin_data_dir, environment_variables};
 use crate::events::{op_plugin_get_pending_event, EventReceiver, JsEvent};
 use crate::JsPluginCode;
 use crate::logs::{op_log_debug, op_log_error, op_log_info, op_log_trace, op_log_warn};
 use crate::model::JsInit;
-use crate::permissions::{permissions_to_deno};
+use crate::permissions::{exec_allowed_programs, filesystem_roots, permissions_to_deno};
 use crate::plugin_data::PluginData;
 use crate::plugins::applications::{current_os, wayland, ApplicationContext};
 use crate::plugins::numbat::{run_numbat, NumbatContext};
 use crate::plugins::settings::open_settings;
-use crate::preferences::{entrypoint_preferences_required, get_entrypoint_preferences, get_plugin_preferences, plugin_preferences_required};
-use crate::search::reload_search_index;
-use crate::ui::{clear_inline_view, fetch_action_id_for_shortcut, hide_window, op_component_model, op_entrypoint_names, op_inline_view_entrypoint_id, op_react_replace_view, show_hud, show_plugin_error_view, show_preferences_required_view, update_loading_bar};
+use crate::plugins::processes::{process_kill, process_list, process_terminate, ProcessContext};
+use crate::plugins::shell::{shell_command_kill, shell_command_poll, shell_command_run, ShellContext};
+use crate::plugins::system::run_system_command;
+use crate::plugins::media::{media_control, media_now_playing};
+use crate::plugins::quick_note::{quick_note_append, quick_note_run_hook};
+use crate::plugins::color::pick_color_from_screen;
+use crate::preferences::{entrypoint_preferences_required, get_entrypoint_preferences, get_plugin_preferences, plugin_onboarding_required, plugin_preferences_required};
+use crate::search::{get_search_provider_entrypoint_ids, reload_search_index, search_provider_results};
+use crate::ui::{clear_inline_view, fetch_action_id_for_shortcut, hide_window, op_component_model, op_entrypoint_names, op_inline_view_entrypoint_id, op_preload_entrypoint_ids, op_react_replace_view, show_hud, show_notification, show_onboarding_view, show_plugin_error_view, show_window_with_search_query, update_loading_bar};
 
 
 
@@ -42,19 +57,71 @@ pub struct CustomModuleLoader {
     code: JsPluginCode,
     static_loader: StaticModuleLoader,
     dev_plugin: bool,
+    code_cache_dir: Option<PathBuf>,
 }
 
 impl CustomModuleLoader {
-    fn new(code: JsPluginCode, dev_plugin: bool) -> Self {
+    fn new(code: JsPluginCode, dev_plugin: bool, code_cache_dir: Option<PathBuf>) -> Self {
         let module_map: HashMap<_, _> = MODULES.iter()
             .map(|(key, value)| (key.parse().expect("provided key is not valid url"), FastString::from_static(value)))
             .collect();
         Self {
             code,
             static_loader: StaticModuleLoader::new(module_map),
-            dev_plugin
+            dev_plugin,
+            code_cache_dir
         }
     }
+
+    fn module_source(&self, module_specifier: &ModuleSpecifier, code: FastString) -> ModuleSource {
+        let code_cache = self.code_cache_dir
+            .as_ref()
+            .map(|code_cache_dir| read_code_cache(code_cache_dir, module_specifier, code.as_str()));
+
+        ModuleSource::new(ModuleType::JavaScript, ModuleSourceCode::String(code), module_specifier, code_cache)
+    }
+}
+
+// v8 code cache of plugin and bridge modules, saves parsing and compiling them on every runtime start.
+// file starts with hash of the source, v8 additionally rejects cache that doesn't match the source
+fn code_cache_file(code_cache_dir: &Path, module_specifier: &ModuleSpecifier) -> PathBuf {
+    let file_name = module_specifier.as_str()
+        .chars()
+        .map(|char| if char.is_ascii_alphanumeric() || char == '-' { char } else { '_' })
+        .collect::<String>();
+
+    code_cache_dir.join(file_name)
+}
+
+fn read_code_cache(code_cache_dir: &Path, module_specifier: &ModuleSpecifier, code: &str) -> SourceCodeCacheInfo {
+    let mut hasher = DefaultHasher::new();
+    code.hash(&mut hasher);
+    let hash = hasher.finish();
+
+    let data = std::fs::read(code_cache_file(code_cache_dir, module_specifier))
+        .ok()
+        .filter(|data| data.len() > 8 && data[..8] == hash.to_le_bytes())
+        .map(|data| Cow::Owned(data[8..].to_vec()));
+
+    SourceCodeCacheInfo {
+        hash,
+        data,
+    }
+}
+
+fn write_code_cache(code_cache_dir: &Path, module_specifier: &ModuleSpecifier, hash: u64, code_cache: &[u8]) -> anyhow::Result<()> {
+    std::fs::create_dir_all(code_cache_dir)
+        .context("Unable to create code cache directory")?;
+
+    let data = hash.to_le_bytes()
+        .into_iter()
+        .chain(code_cache.iter().copied())
+        .collect::<Vec<_>>();
+
+    std::fs::write(code_cache_file(code_cache_dir, module_specifier), data)
+        .context("Unable to write code cache file")?;
+
+    Ok(())
 }
 
 const MODULES: [(&str, &str); 11] = [
@@ -71,6 +138,27 @@ const MODULES: [(&str, &str); 11] = [
     ("gauntlet:bridge/internal-windows", include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../js/bridge_build/dist/bridge-internal-windows.js"))),
 ];
 
+// WebAssembly modules shipped by plugins are limited to 32 MiB of code and 512 MiB of linear memory
+const WASM_MAX_MODULE_SIZE: usize = 32 * 1024 * 1024;
+const WASM_MAX_MEMORY_PAGES: usize = 512 * 1024 * 1024 / (64 * 1024);
+
+// subset of node built-in modules commonly needed by npm packages, only the ones that don't need filesystem,
+// process or network access, so that they don't bypass plugin permissions
+pub const NODE_COMPAT_MODULES: [&str; 12] = [
+    "assert",
+    "buffer",
+    "crypto",
+    "events",
+    "path",
+    "path/posix",
+    "path/win32",
+    "punycode",
+    "querystring",
+    "string_decoder",
+    "url",
+    "util",
+];
+
 impl ModuleLoader for CustomModuleLoader {
     fn resolve(
         &self,
@@ -92,6 +180,21 @@ impl ModuleLoader for CustomModuleLoader {
             }
         }
 
+        // polyfills are registered by deno_node extension of the worker under the same "node:" specifiers,
+        // so module map already contains them and loader doesn't need to load them
+        if let Some(node_module) = specifier.strip_prefix("node:") {
+            if NODE_COMPAT_MODULES.contains(&node_module) {
+                return Ok(Url::parse(specifier)?);
+            }
+
+            return Err(anyhow!(
+                "Node module '{}' is not supported in plugins, imported from '{}'. Supported modules: {}",
+                node_module,
+                referrer,
+                NODE_COMPAT_MODULES.iter().map(|module| format!("node:{}", module)).collect::<Vec<_>>().join(", ")
+            ))
+        }
+
         let specifier = match (specifier, referrer) {
             ("gauntlet:init", _) => "gauntlet:init",
             ("gauntlet:core", _) => "gauntlet:bridge/core",
@@ -136,22 +239,42 @@ impl ModuleLoader for CustomModuleLoader {
                         let result = self.code.js
                             .get(entrypoint_id)
                             .ok_or(anyhow!("Cannot find JS code path: {:?}", entrypoint_id))
-                            .map(|js| ModuleSourceCode::String(js.clone().into()))
-                            .map(|js| ModuleSource::new(ModuleType::JavaScript, js, module_specifier, None));
+                            .map(|js| self.module_source(module_specifier, js.clone().into()));
 
                         ModuleLoadResponse::Sync(result)
                     }
                 }
             }
+            _ if specifier.scheme() == "node" => {
+                ModuleLoadResponse::Sync(Err(anyhow!("Node module polyfill is not registered in runtime: '{}'", specifier)))
+            }
             _ => {
-                if specifier.as_str().starts_with("gauntlet:bridge/"){
-                    self.static_loader.load(module_specifier, maybe_referrer, is_dyn_import, requested_module_type)
+                let bridge_module = MODULES.iter()
+                    .find(|(key, _)| *key == specifier.as_str() && key.starts_with("gauntlet:bridge/"));
+
+                if let Some((_, code)) = bridge_module {
+                    ModuleLoadResponse::Sync(Ok(self.module_source(module_specifier, FastString::from_static(code))))
                 } else {
                     ModuleLoadResponse::Sync(Err(anyhow!("Module not found: specifier '{}' and referrer '{:?}'", specifier, maybe_referrer.map(|url| url.as_str()))))
                 }
             }
         }
     }
+
+    fn code_cache_ready(
+        &self,
+        module_specifier: ModuleSpecifier,
+        hash: u64,
+        code_cache: &[u8],
+    ) -> Pin<Box<dyn Future<Output = ()>>> {
+        if let Some(code_cache_dir) = &self.code_cache_dir {
+            if let Err(err) = write_code_cache(code_cache_dir, &module_specifier, hash, code_cache) {
+                tracing::warn!("Unable to save code cache for module {}: {:?}", module_specifier, err);
+            }
+        }
+
+        Box::pin(std::future::ready(()))
+    }
 }
 
 deno_core::extension!(
@@ -178,12 +301,14 @@ deno_core::extension!(
         op_react_replace_view,
         op_inline_view_entrypoint_id,
         op_entrypoint_names,
+        op_preload_entrypoint_ids,
         show_plugin_error_view,
         clear_inline_view,
-        show_preferences_required_view,
+        show_onboarding_view,
         op_component_model,
         fetch_action_id_for_shortcut,
         show_hud,
+        show_notification,
         hide_window,
         update_loading_bar,
 
@@ -192,36 +317,68 @@ deno_core::extension!(
         get_entrypoint_preferences,
         plugin_preferences_required,
         entrypoint_preferences_required,
+        plugin_onboarding_required,
 
         // search
         reload_search_index,
+        get_search_provider_entrypoint_ids,
+        search_provider_results,
 
         // clipboard
         clipboard_read_text,
         clipboard_read,
         clipboard_write,
         clipboard_write_text,
+        clipboard_write_sensitive_text,
+        clipboard_paste_text,
         clipboard_clear,
 
+        // oauth
+        oauth_authorize,
+        oauth_remove_tokens,
+
+        // terminal
+        run_in_terminal,
+
+        // subprocess
+        subprocess_spawn,
+        subprocess_read,
+        subprocess_kill,
+
+        // file system
+        fs_read_dir,
+        fs_read_file,
+        fs_read_text_file,
+        fs_write_file,
+        fs_write_text_file,
+        fs_stat,
+        fs_watch,
+        fs_watch_next,
+        fs_unwatch,
+
+        // database
+        database_open,
+        database_query,
+        database_execute,
+        database_close,
+
+        // http cache
+        op_http_cache_config,
+        op_http_cache_get,
+        op_http_cache_put,
+        op_http_cache_refresh,
+
         // plugin environment
         environment_gauntlet_version,
         environment_is_development,
         environment_plugin_data_dir,
         environment_plugin_cache_dir,
+        environment_variables,
     ],
-    options = {
-        event_receiver: EventReceiver,
-        plugin_data: PluginData,
-        component_model: ComponentModel,
-        backend_api: BackendForPluginRuntimeApiProxy,
-        outer_handle: Handle
-    },
-    state = |state, options| {
-        state.put(options.event_receiver);
-        state.put(options.plugin_data);
-        state.put(options.component_model);
-        state.put(options.backend_api);
-        state.put(options.outer_handle);
+    // only state needed while shared code is evaluated is set here, so that extension can be included in snapshot.
+    // state which is specific to plugin is added after runtime is created
+    state = |state| {
+        state.put(ComponentModel::new());
     },
 );
 
@@ -272,6 +429,31 @@ deno_core::extension!(
 
         // plugins settings
         open_settings,
+
+        // plugins shell
+        shell_command_run,
+        shell_command_poll,
+        shell_command_kill,
+
+        // plugins system
+        run_system_command,
+
+        // plugins processes
+        process_list,
+        process_terminate,
+        process_kill,
+
+        // plugins media
+        media_now_playing,
+        media_control,
+
+        // plugins quick note
+        quick_note_append,
+        quick_note_run_hook,
+
+        // plugins color
+        pick_color_from_screen,
+        show_window_with_search_query,
     ],
     esm_entry_point = "ext:gauntlet/internal-all/bootstrap.js",
     esm = [
@@ -281,10 +463,14 @@ deno_core::extension!(
     options = {
         numbat_context: NumbatContext,
         application_context: ApplicationContext,
+        shell_context: ShellContext,
+        process_context: ProcessContext,
     },
     state = |state, options| {
         state.put(options.numbat_context);
         state.put(options.application_context);
+        state.put(options.shell_context);
+        state.put(options.process_context);
     },
 );
 
@@ -312,13 +498,38 @@ deno_core::extension!(
 );
 
 
+// bridge, react and renderer code is evaluated once at build time, so that it doesn't have to be done every time plugin starts.
+// extensions which are not included in snapshot can't add ops to it, so bundled plugin with its internal ops doesn't use it
+pub fn create_runtime_snapshot(snapshot_path: PathBuf) {
+    deno_runtime::snapshot::create_runtime_snapshot(
+        snapshot_path,
+        Default::default(),
+        vec![
+            gauntlet::init_ops(),
+            prod::gauntlet_esm::init_ops_and_esm(),
+        ],
+    )
+}
+
 pub async fn start_js_runtime(
     outer_handle: Handle,
     init: JsInit,
     event_stream: Receiver<JsEvent>,
     api: BackendForPluginRuntimeApiProxy,
+    startup_snapshot: Option<&'static [u8]>,
 ) -> anyhow::Result<()> {
 
+    // flags are set once per process, each plugin runs in its own runtime process
+    let unrecognized_flags = deno_core::v8_set_flags(vec![
+        "".to_string(),
+        format!("--wasm-max-module-size={}", WASM_MAX_MODULE_SIZE),
+        format!("--wasm-max-mem-pages={}", WASM_MAX_MEMORY_PAGES),
+    ]);
+
+    if unrecognized_flags.len() > 1 {
+        tracing::warn!("Unrecognized v8 flags: {:?}", &unrecognized_flags[1..]);
+    }
+
     let stdout = if let Some(stdout_file) = init.stdout_file {
         let stdout_file = PathBuf::from(stdout_file);
 
@@ -359,35 +570,50 @@ pub async fn start_js_runtime(
         Path::new(&init.plugin_cache_dir),
     )?;
 
-    let gauntlet_esm = if cfg!(feature = "release") && !init.dev_plugin {
+    let http_cache = HttpCache::new(&init.plugin_cache_dir, init.http_cache);
+
+    let subprocesses = Subprocesses::new(exec_allowed_programs(
+        &init.permissions.exec,
+        &home_dir,
+        Path::new(&init.plugin_data_dir),
+        Path::new(&init.plugin_cache_dir),
+    )?);
+
+    // directories approved by user are not added to deno permissions, so they are only accessible using FS api
+    let approved_roots = filesystem_roots(&init.filesystem_roots, &home_dir, Path::new(&init.plugin_data_dir), Path::new(&init.plugin_cache_dir))?;
+
+    let file_system = PluginFileSystem::new(
+        filesystem_roots(&init.permissions.filesystem.read, &home_dir, Path::new(&init.plugin_data_dir), Path::new(&init.plugin_cache_dir))?,
+        filesystem_roots(&init.permissions.filesystem.write, &home_dir, Path::new(&init.plugin_data_dir), Path::new(&init.plugin_cache_dir))?,
+        approved_roots,
+    );
+
+    let databases = PluginDatabases::new(Path::new(&init.plugin_data_dir).join("databases"));
+
+    let bundled_plugin = init.plugin_id.to_string() == "bundled://gauntlet";
+
+    // snapshot contains production build of shared code, development plugins use development build of react for better error messages
+    let startup_snapshot = startup_snapshot.filter(|_| !init.dev_plugin && !bundled_plugin);
+
+    let gauntlet_esm = if startup_snapshot.is_some() {
+        prod::gauntlet_esm::init_ops()
+    } else if cfg!(feature = "release") && !init.dev_plugin {
         prod::gauntlet_esm::init_ops_and_esm()
     } else {
         dev::gauntlet_esm::init_ops_and_esm()
     };
 
     let mut extensions = vec![
-        gauntlet::init_ops(
-            EventReceiver::new(event_stream),
-            PluginData::new(
-                init.plugin_id.clone(),
-                init.plugin_uuid.clone(),
-                init.plugin_cache_dir,
-                init.plugin_data_dir,
-                init.inline_view_entrypoint_id,
-                init.entrypoint_names,
-                home_dir
-            ),
-            ComponentModel::new(),
-            api,
-            outer_handle
-        ),
+        gauntlet::init_ops(),
         gauntlet_esm,
     ];
 
-    if init.plugin_id.to_string() == "bundled://gauntlet" {
+    if bundled_plugin {
         extensions.push(gauntlet_internal_all::init_ops_and_esm(
             NumbatContext::new(),
-            ApplicationContext::new()?
+            ApplicationContext::new()?,
+            ShellContext::new(),
+            ProcessContext::new(),
         ));
 
         #[cfg(target_os = "macos")]
@@ -407,7 +633,7 @@ pub async fn start_js_runtime(
             broadcast_channel: Default::default(),
             feature_checker: Arc::new(Default::default()),
             fs,
-            module_loader: Rc::new(CustomModuleLoader::new(init.code, init.dev_plugin)),
+            module_loader: Rc::new(CustomModuleLoader::new(init.code, init.dev_plugin, init.code_cache_dir.map(PathBuf::from))),
             node_services: None,
             npm_process_state_provider: None,
             permissions: permissions_container,
@@ -418,6 +644,7 @@ pub async fn start_js_runtime(
             v8_code_cache: None,
         },
         WorkerOptions {
+            startup_snapshot,
             bootstrap: BootstrapOptions {
                 is_stderr_tty: false,
                 is_stdout_tty: false,
@@ -437,9 +664,64 @@ pub async fn start_js_runtime(
         },
     );
 
+    {
+        let op_state = worker.js_runtime.op_state();
+        let mut op_state = op_state.borrow_mut();
+
+        op_state.put(EventReceiver::new(event_stream));
+        op_state.put(PluginData::new(
+            init.plugin_id.clone(),
+            init.plugin_uuid.clone(),
+            init.plugin_cache_dir,
+            init.plugin_data_dir,
+            init.inline_view_entrypoint_id,
+            init.entrypoint_names,
+            init.preload_entrypoint_ids,
+            home_dir,
+            init.environment,
+        ));
+        op_state.put(api);
+        op_state.put(http_cache);
+        op_state.put(subprocesses);
+        op_state.put(file_system);
+        op_state.put(databases);
+        op_state.put(outer_handle);
+    }
+
     worker.execute_main_module(&init_url).await?;
     worker.run_event_loop(false).await?;
 
     Ok(())
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolve(specifier: &str) -> anyhow::Result<ModuleSpecifier> {
+        let loader = CustomModuleLoader::new(JsPluginCode { js: HashMap::new() }, false, None);
+
+        loader.resolve(specifier, "gauntlet:entrypoint?view", ResolutionKind::Import)
+    }
+
+    #[test]
+    fn plugin_can_import_node_path() {
+        assert_eq!(resolve("node:path").unwrap().as_str(), "node:path");
+        assert_eq!(resolve("node:path/posix").unwrap().as_str(), "node:path/posix");
+    }
+
+    #[test]
+    fn unsupported_node_modules_are_rejected() {
+        let err = resolve("node:fs").unwrap_err().to_string();
+
+        assert!(err.starts_with("Node module 'fs' is not supported in plugins"));
+        assert!(err.contains("node:path"));
+    }
+
+    // bare specifiers are bundled by plugins themselves, they are not mapped to node modules
+    #[test]
+    fn bare_specifiers_are_not_node_modules() {
+        assert!(resolve("path").is_err());
+    }
+}
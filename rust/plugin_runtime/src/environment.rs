@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use deno_core::{op2, OpState};
 use crate::plugin_data::PluginData;
 
@@ -35,4 +37,14 @@ pub fn environment_plugin_cache_dir(state: &mut OpState) -> String {
         .borrow::<PluginData>()
         .plugin_cache_dir()
         .to_string()
-}
\ No newline at end of file
+}
+
+// variables configured by user in settings, same ones are present in process environment
+#[op2]
+#[serde]
+pub fn environment_variables(state: &mut OpState) -> HashMap<String, String> {
+    state
+        .borrow::<PluginData>()
+        .environment()
+        .clone()
+}
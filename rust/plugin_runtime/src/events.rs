@@ -1,4 +1,5 @@
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::pin::Pin;
 use std::rc::Rc;
 use anyhow::anyhow;
@@ -8,6 +9,7 @@ use deno_core::futures::{Stream, StreamExt};
 use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc::Receiver;
 use gauntlet_common::model::UiWidgetId;
+use crate::model::JsUiRenderLocation;
 
 #[derive(Debug, Deserialize, Serialize, Encode, Decode)]
 #[serde(tag = "type")]
@@ -17,9 +19,11 @@ pub enum JsEvent {
         entrypoint_id: String
     },
     CloseView,
+    PopView,
     RunCommand {
         #[serde(rename = "entrypointId")]
-        entrypoint_id: String
+        entrypoint_id: String,
+        arguments: HashMap<String, String>,
     },
     RunGeneratedEntrypoint {
         #[serde(rename = "entrypointId")]
@@ -54,6 +58,26 @@ pub enum JsEvent {
         text: String,
     },
     RefreshSearchIndex,
+    Search {
+        text: String,
+        #[serde(rename = "searchId")]
+        search_id: u32,
+    },
+    NotificationClosed {
+        #[serde(rename = "notificationId")]
+        notification_id: u32,
+        // not set if notification was dismissed without clicking any action
+        #[serde(rename = "actionIndex")]
+        action_index: Option<usize>,
+    },
+    // renders are sent to frontend in background, so failure to show the view is reported back as event
+    ViewRenderFailed {
+        #[serde(rename = "entrypointId")]
+        entrypoint_id: String,
+        #[serde(rename = "renderLocation")]
+        render_location: JsUiRenderLocation,
+        message: String,
+    },
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Encode, Decode)]
@@ -75,6 +99,22 @@ pub enum JsUiPropertyValue {
     Bool {
         value: bool
     },
+    // hex color in #rrggbbaa format
+    Color {
+        value: String
+    },
+    Enum {
+        value: String
+    },
+    Bytes {
+        value: Vec<u8>
+    },
+    Array {
+        value: Vec<JsUiPropertyValue>
+    },
+    Object {
+        value: HashMap<String, JsUiPropertyValue>
+    },
     Undefined,
 }
 
@@ -0,0 +1,435 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::path::{Component, Path, PathBuf};
+use std::rc::Rc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Context};
+use deno_core::{op2, JsBuffer, OpState};
+use futures::executor::block_on;
+use gauntlet_common::model::{GridItemWidget, ListItemWidget, RootWidget, WidgetVisitor};
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Serialize;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
+
+// file system access using FS api, limited to directories listed in "filesystem" permission
+// and directories approved by user in settings. watchers are stopped when plugin runtime stops
+#[derive(Clone)]
+pub struct PluginFileSystem(Rc<RefCell<PluginFileSystemInner>>);
+
+struct PluginFileSystemInner {
+    read_roots: Vec<PathBuf>,
+    write_roots: Vec<PathBuf>,
+    next_watcher_id: u32,
+    watchers: HashMap<u32, FileWatcher>,
+}
+
+struct FileWatcher {
+    // events stop when watcher is dropped
+    _watcher: RecommendedWatcher,
+    events: Rc<tokio::sync::Mutex<UnboundedReceiver<FsWatchEvent>>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FsDirEntry {
+    name: String,
+    path: String,
+    #[serde(rename = "isFile")]
+    is_file: bool,
+    #[serde(rename = "isDirectory")]
+    is_directory: bool,
+    #[serde(rename = "isSymlink")]
+    is_symlink: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FsStat {
+    #[serde(rename = "isFile")]
+    is_file: bool,
+    #[serde(rename = "isDirectory")]
+    is_directory: bool,
+    #[serde(rename = "isSymlink")]
+    is_symlink: bool,
+    size: u64,
+    // milliseconds since unix epoch
+    modified: Option<f64>,
+    created: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FsWatchEvent {
+    kind: &'static str,
+    paths: Vec<String>,
+}
+
+impl PluginFileSystem {
+    // paths in write roots can also be read, directories approved by user can be both read and written
+    pub fn new(read_roots: Vec<PathBuf>, write_roots: Vec<PathBuf>, approved_roots: Vec<PathBuf>) -> Self {
+        let write_roots: Vec<_> = write_roots.into_iter()
+            .chain(approved_roots)
+            .collect();
+
+        Self(Rc::new(RefCell::new(PluginFileSystemInner {
+            read_roots: read_roots.into_iter().chain(write_roots.iter().cloned()).collect(),
+            write_roots,
+            next_watcher_id: 0,
+            watchers: HashMap::new(),
+        })))
+    }
+
+    fn resolve_read(&self, path: &str) -> anyhow::Result<PathBuf> {
+        resolve_path(&self.0.borrow().read_roots, path, false)
+    }
+
+    fn resolve_write(&self, path: &str) -> anyhow::Result<PathBuf> {
+        resolve_path(&self.0.borrow().write_roots, path, true)
+    }
+}
+
+#[op2(async)]
+#[serde]
+pub async fn fs_read_dir(state: Rc<RefCell<OpState>>, #[string] path: String) -> anyhow::Result<Vec<FsDirEntry>> {
+    let path = file_system(&state).resolve_read(&path)?;
+
+    let mut entries = tokio::fs::read_dir(&path)
+        .await
+        .with_context(|| format!("Unable to read directory: {}", path.display()))?;
+
+    let mut result = vec![];
+
+    while let Some(entry) = entries.next_entry().await? {
+        let file_type = entry.file_type().await?;
+
+        result.push(FsDirEntry {
+            name: entry.file_name().to_string_lossy().to_string(),
+            path: entry.path().display().to_string(),
+            is_file: file_type.is_file(),
+            is_directory: file_type.is_dir(),
+            is_symlink: file_type.is_symlink(),
+        })
+    }
+
+    Ok(result)
+}
+
+#[op2(async)]
+#[buffer]
+pub async fn fs_read_file(state: Rc<RefCell<OpState>>, #[string] path: String) -> anyhow::Result<Vec<u8>> {
+    let path = file_system(&state).resolve_read(&path)?;
+
+    tokio::fs::read(&path)
+        .await
+        .with_context(|| format!("Unable to read file: {}", path.display()))
+}
+
+#[op2(async)]
+#[string]
+pub async fn fs_read_text_file(state: Rc<RefCell<OpState>>, #[string] path: String) -> anyhow::Result<String> {
+    let path = file_system(&state).resolve_read(&path)?;
+
+    tokio::fs::read_to_string(&path)
+        .await
+        .with_context(|| format!("Unable to read file: {}", path.display()))
+}
+
+#[op2(async)]
+pub async fn fs_write_file(state: Rc<RefCell<OpState>>, #[string] path: String, #[buffer] data: JsBuffer) -> anyhow::Result<()> {
+    let path = file_system(&state).resolve_write(&path)?;
+
+    tokio::fs::write(&path, data)
+        .await
+        .with_context(|| format!("Unable to write file: {}", path.display()))
+}
+
+#[op2(async)]
+pub async fn fs_write_text_file(state: Rc<RefCell<OpState>>, #[string] path: String, #[string] data: String) -> anyhow::Result<()> {
+    let path = file_system(&state).resolve_write(&path)?;
+
+    tokio::fs::write(&path, data)
+        .await
+        .with_context(|| format!("Unable to write file: {}", path.display()))
+}
+
+// symlinks are not followed, so that it is possible to tell them apart
+#[op2(async)]
+#[serde]
+pub async fn fs_stat(state: Rc<RefCell<OpState>>, #[string] path: String) -> anyhow::Result<FsStat> {
+    let path = file_system(&state).resolve_read(&path)?;
+
+    let metadata = tokio::fs::symlink_metadata(&path)
+        .await
+        .with_context(|| format!("Unable to read metadata of file: {}", path.display()))?;
+
+    Ok(FsStat {
+        is_file: metadata.is_file(),
+        is_directory: metadata.is_dir(),
+        is_symlink: metadata.is_symlink(),
+        size: metadata.len(),
+        modified: timestamp(metadata.modified()),
+        created: timestamp(metadata.created()),
+    })
+}
+
+#[op2]
+pub fn fs_watch(state: Rc<RefCell<OpState>>, #[string] path: String, recursive: bool) -> anyhow::Result<u32> {
+    let file_system = file_system(&state);
+
+    let path = file_system.resolve_read(&path)?;
+
+    let (sender, receiver) = unbounded_channel();
+
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        let event = match event {
+            Ok(event) => event,
+            Err(err) => {
+                tracing::warn!("Error while watching file system: {:?}", err);
+                return;
+            }
+        };
+
+        let kind = match event.kind {
+            EventKind::Access(_) => return,
+            EventKind::Create(_) => "create",
+            EventKind::Modify(_) => "modify",
+            EventKind::Remove(_) => "remove",
+            EventKind::Any | EventKind::Other => "other",
+        };
+
+        let paths = event.paths
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect();
+
+        let _ = sender.send(FsWatchEvent { kind, paths });
+    })?;
+
+    let mode = if recursive {
+        RecursiveMode::Recursive
+    } else {
+        RecursiveMode::NonRecursive
+    };
+
+    watcher.watch(&path, mode)
+        .with_context(|| format!("Unable to watch path: {}", path.display()))?;
+
+    let mut file_system = file_system.0.borrow_mut();
+
+    let id = file_system.next_watcher_id;
+    file_system.next_watcher_id += 1;
+
+    file_system.watchers.insert(id, FileWatcher {
+        _watcher: watcher,
+        events: Rc::new(tokio::sync::Mutex::new(receiver)),
+    });
+
+    Ok(id)
+}
+
+// returns none after watcher was closed
+#[op2(async)]
+#[serde]
+pub async fn fs_watch_next(state: Rc<RefCell<OpState>>, id: u32) -> anyhow::Result<Option<FsWatchEvent>> {
+    let events = file_system(&state).0.borrow()
+        .watchers
+        .get(&id)
+        .map(|watcher| watcher.events.clone());
+
+    let Some(events) = events else {
+        return Ok(None)
+    };
+
+    let event = events.lock()
+        .await
+        .recv()
+        .await;
+
+    Ok(event)
+}
+
+#[op2(fast)]
+pub fn fs_unwatch(state: Rc<RefCell<OpState>>, id: u32) {
+    file_system(&state).0.borrow_mut().watchers.remove(&id);
+}
+
+// dragged file is read by application it is dropped into, so plugin is only allowed to drag files it can read itself
+pub fn validate_drag_files(file_system: &PluginFileSystem, root_widget: &RootWidget) -> anyhow::Result<()> {
+    let mut validator = DragFileValidator {
+        file_system,
+        result: Ok(()),
+    };
+
+    block_on(validator.root_widget(root_widget));
+
+    validator.result
+}
+
+struct DragFileValidator<'a> {
+    file_system: &'a PluginFileSystem,
+    result: anyhow::Result<()>,
+}
+
+impl WidgetVisitor for DragFileValidator<'_> {
+    async fn list_item_widget(&mut self, widget: &ListItemWidget) {
+        self.drag_file(&widget.drag_file)
+    }
+
+    async fn grid_item_widget(&mut self, widget: &GridItemWidget) {
+        self.drag_file(&widget.drag_file)
+    }
+}
+
+impl DragFileValidator<'_> {
+    // first invalid path is reported
+    fn drag_file(&mut self, drag_file: &Option<String>) {
+        if let (Some(path), Ok(())) = (drag_file, &self.result) {
+            self.result = self.file_system.resolve_read(path)
+                .map(|_| ())
+                .with_context(|| format!("Invalid dragFile: {}", path));
+        }
+    }
+}
+
+fn file_system(state: &Rc<RefCell<OpState>>) -> PluginFileSystem {
+    let state = state.borrow();
+
+    state
+        .borrow::<PluginFileSystem>()
+        .clone()
+}
+
+// path is canonicalized before comparing with roots, so that symlinks and ".." can't be used to escape them.
+// file which is about to be created doesn't exist yet, so its nearest existing ancestor is canonicalized instead
+fn resolve_path(roots: &[PathBuf], path: &str, allow_missing: bool) -> anyhow::Result<PathBuf> {
+    let original = Path::new(path);
+
+    if !original.is_absolute() {
+        return Err(anyhow!("Path is not absolute: {}", path));
+    }
+
+    let resolved = match std::fs::canonicalize(original) {
+        Ok(resolved) => resolved,
+        Err(err) if allow_missing && err.kind() == ErrorKind::NotFound => {
+            let mut ancestor = original;
+            let mut missing = vec![];
+
+            loop {
+                let (Some(parent), Some(file_name)) = (ancestor.parent(), ancestor.file_name()) else {
+                    return Err(anyhow!("Invalid path: {}", path));
+                };
+
+                missing.push(file_name);
+                ancestor = parent;
+
+                match std::fs::canonicalize(ancestor) {
+                    Ok(resolved) => break missing.into_iter().rev().fold(resolved, |path, name| path.join(name)),
+                    Err(err) if err.kind() == ErrorKind::NotFound => continue,
+                    Err(err) => Err(err).with_context(|| format!("Unable to resolve path: {}", path))?,
+                }
+            }
+        }
+        Err(err) => Err(err).with_context(|| format!("Unable to resolve path: {}", path))?,
+    };
+
+    // ".." in part of the path which doesn't exist yet is not resolved by canonicalize
+    if resolved.components().any(|component| matches!(component, Component::ParentDir | Component::CurDir)) {
+        return Err(anyhow!("Invalid path: {}", path));
+    }
+
+    if roots.iter().any(|root| resolved.starts_with(root)) {
+        Ok(resolved)
+    } else {
+        Err(anyhow!("Plugin doesn't have permission to access '{}'", path))
+    }
+}
+
+fn timestamp(time: std::io::Result<SystemTime>) -> Option<f64> {
+    time.ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map(|duration| duration.as_millis() as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roots(dir: &Path) -> (PathBuf, Vec<PathBuf>) {
+        let dir = std::fs::canonicalize(dir).unwrap();
+        let root = dir.join("root");
+
+        std::fs::create_dir_all(root.join("nested")).unwrap();
+        std::fs::write(root.join("nested").join("file.txt"), "inside").unwrap();
+        std::fs::write(dir.join("secret.txt"), "outside").unwrap();
+
+        (dir, vec![root])
+    }
+
+    fn path(path: PathBuf) -> String {
+        path.to_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn paths_inside_root_are_allowed() {
+        let dir = tempfile::tempdir().unwrap();
+        let (dir, roots) = roots(dir.path());
+
+        let file = dir.join("root").join("nested").join("file.txt");
+
+        assert_eq!(resolve_path(&roots, &path(file.clone()), false).unwrap(), file);
+        assert!(resolve_path(&roots, &path(dir.join("root").join("new").join("file.txt")), true).is_ok());
+    }
+
+    #[test]
+    fn drag_files_outside_of_read_roots_are_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let (dir, roots) = roots(dir.path());
+
+        let file_system = PluginFileSystem::new(roots, vec![], vec![]);
+
+        let mut validator = DragFileValidator { file_system: &file_system, result: Ok(()) };
+        validator.drag_file(&Some(path(dir.join("root").join("nested").join("file.txt"))));
+        assert!(validator.result.is_ok());
+
+        validator.drag_file(&Some(path(dir.join("secret.txt"))));
+        assert!(validator.result.is_err());
+    }
+
+    #[test]
+    fn relative_paths_are_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let (_, roots) = roots(dir.path());
+
+        assert!(resolve_path(&roots, "root/nested/file.txt", false).is_err());
+    }
+
+    #[test]
+    fn parent_dir_escape_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let (dir, roots) = roots(dir.path());
+
+        let existing = dir.join("root").join("nested").join("..").join("..").join("secret.txt");
+        let missing = dir.join("root").join("missing").join("..").join("..").join("created.txt");
+
+        assert!(resolve_path(&roots, &path(existing.clone()), false).is_err());
+        assert!(resolve_path(&roots, &path(existing), true).is_err());
+        assert!(resolve_path(&roots, &path(missing), true).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlink_escape_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let (dir, roots) = roots(dir.path());
+
+        let file_link = dir.join("root").join("secret-link");
+        std::os::unix::fs::symlink(dir.join("secret.txt"), &file_link).unwrap();
+
+        let dir_link = dir.join("root").join("outside-link");
+        std::os::unix::fs::symlink(&dir, &dir_link).unwrap();
+
+        assert!(resolve_path(&roots, &path(file_link.clone()), false).is_err());
+        assert!(resolve_path(&roots, &path(file_link), true).is_err());
+        assert!(resolve_path(&roots, &path(dir_link.join("secret.txt")), false).is_err());
+        assert!(resolve_path(&roots, &path(dir_link.join("new").join("created.txt")), true).is_err());
+    }
+}
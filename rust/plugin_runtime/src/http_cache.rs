@@ -0,0 +1,380 @@
+use std::cell::RefCell;
+use std::fs::File;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use anyhow::Context;
+use bincode::{Decode, Encode};
+use deno_core::{op2, JsBuffer, OpState, ToJsBuffer};
+use serde::Serialize;
+use crate::model::JsPluginHttpCache;
+
+// responses to fetch requests of plugins which opted in are stored in plugin cache directory, one file per url.
+// only one variant of response is stored, it is used only if request headers listed in Vary are the same.
+// least recently used entries are removed when directory grows over size configured in plugin manifest
+#[derive(Clone)]
+pub struct HttpCache {
+    config: Option<HttpCacheConfig>,
+}
+
+#[derive(Clone)]
+struct HttpCacheConfig {
+    dir: PathBuf,
+    max_size: u64,
+    stale_while_revalidate: bool,
+}
+
+#[derive(Encode, Decode)]
+struct HttpCacheEntry {
+    url: String,
+    status: u16,
+    status_text: String,
+    headers: Vec<(String, String)>,
+    // values of request headers listed in Vary response header at the time response was stored
+    vary: Vec<(String, Option<String>)>,
+    body: Vec<u8>,
+    // milliseconds since unix epoch
+    expires_at: u64,
+}
+
+#[derive(Serialize)]
+pub struct JsHttpCacheConfig {
+    stale_while_revalidate: bool,
+}
+
+#[derive(Serialize)]
+pub struct JsHttpCacheEntry {
+    status: u16,
+    status_text: String,
+    headers: Vec<(String, String)>,
+    body: ToJsBuffer,
+    fresh: bool,
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+impl HttpCache {
+    pub fn new(plugin_cache_dir: &str, http_cache: Option<JsPluginHttpCache>) -> Self {
+        let config = http_cache.map(|http_cache| HttpCacheConfig {
+            dir: Path::new(plugin_cache_dir).join("http_cache"),
+            max_size: http_cache.max_size,
+            stale_while_revalidate: http_cache.stale_while_revalidate,
+        });
+
+        Self {
+            config
+        }
+    }
+}
+
+impl HttpCacheConfig {
+    fn entry_file(&self, url: &str) -> PathBuf {
+        let mut hasher = DefaultHasher::new();
+        url.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        self.dir.join(format!("{:016x}", hash))
+    }
+
+    fn read(&self, url: &str, request_headers: &[(String, String)]) -> anyhow::Result<Option<HttpCacheEntry>> {
+        let path = self.entry_file(url);
+
+        let data = match std::fs::read(&path) {
+            Ok(data) => data,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err).context("Unable to read http cache entry"),
+        };
+
+        let entry = match bincode::decode_from_slice::<HttpCacheEntry, _>(&data, bincode::config::standard()) {
+            Ok((entry, _)) => entry,
+            Err(err) => {
+                tracing::warn!("Unable to decode http cache entry, removing: {:?}", err);
+                let _ = std::fs::remove_file(&path);
+                return Ok(None)
+            }
+        };
+
+        // different url with the same hash
+        if entry.url != url {
+            return Ok(None)
+        }
+
+        // stored response was returned for a request with different headers, e.g. different Accept-Language
+        let vary_matches = entry.vary
+            .iter()
+            .all(|(name, value)| header(request_headers, name) == value.as_deref());
+
+        if !vary_matches {
+            return Ok(None)
+        }
+
+        // modification time is used to find least recently used entries
+        let _ = File::options()
+            .write(true)
+            .open(&path)
+            .and_then(|file| file.set_modified(SystemTime::now()));
+
+        Ok(Some(entry))
+    }
+
+    fn write(&self, entry: HttpCacheEntry) -> anyhow::Result<()> {
+        std::fs::create_dir_all(&self.dir)
+            .context("Unable to create http cache directory")?;
+
+        let data = bincode::encode_to_vec(&entry, bincode::config::standard())?;
+
+        std::fs::write(self.entry_file(&entry.url), data)
+            .context("Unable to write http cache entry")?;
+
+        self.evict()
+    }
+
+    fn evict(&self) -> anyhow::Result<()> {
+        let mut files = vec![];
+        let mut total_size = 0;
+
+        for dir_entry in std::fs::read_dir(&self.dir)? {
+            let dir_entry = dir_entry?;
+            let metadata = dir_entry.metadata()?;
+
+            total_size += metadata.len();
+            files.push((dir_entry.path(), metadata.len(), metadata.modified()?));
+        }
+
+        files.sort_by_key(|(_, _, modified)| *modified);
+
+        for (path, size, _) in files {
+            if total_size <= self.max_size {
+                break;
+            }
+
+            std::fs::remove_file(path)
+                .context("Unable to remove http cache entry")?;
+
+            total_size -= size;
+        }
+
+        Ok(())
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+fn header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers.iter()
+        .find(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+// values of request headers which response depends on, returns None if response depends on something other than headers
+fn vary(headers: &[(String, String)], request_headers: &[(String, String)]) -> Option<Vec<(String, Option<String>)>> {
+    let Some(vary) = header(headers, "vary") else {
+        return Some(vec![])
+    };
+
+    vary.split(',')
+        .map(|name| name.trim().to_ascii_lowercase())
+        .filter(|name| !name.is_empty())
+        .map(|name| {
+            if name == "*" {
+                return None
+            }
+
+            let value = header(request_headers, &name).map(|value| value.to_string());
+
+            Some((name, value))
+        })
+        .collect()
+}
+
+// only cache-control max-age is taken into account, responses without it are revalidated on every request.
+// returns None if response is not allowed to be stored
+fn expires_at(headers: &[(String, String)]) -> Option<u64> {
+    let now = now();
+
+    let cache_control = header(headers, "cache-control")
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+
+    let mut expires_at = now;
+
+    for directive in cache_control.split(',').map(|directive| directive.trim()) {
+        match directive {
+            "no-store" => return None,
+            "no-cache" => return Some(now),
+            _ => {
+                if let Some(max_age) = directive.strip_prefix("max-age=").and_then(|max_age| max_age.parse::<u64>().ok()) {
+                    expires_at = now + max_age * 1000;
+                }
+            }
+        }
+    }
+
+    Some(expires_at)
+}
+
+fn http_cache_config(state: &Rc<RefCell<OpState>>) -> anyhow::Result<HttpCacheConfig> {
+    state.borrow()
+        .borrow::<HttpCache>()
+        .config
+        .clone()
+        .context("Http cache is not enabled for plugin")
+}
+
+#[op2]
+#[serde]
+pub fn op_http_cache_config(state: &mut OpState) -> Option<JsHttpCacheConfig> {
+    state.borrow::<HttpCache>()
+        .config
+        .as_ref()
+        .map(|config| JsHttpCacheConfig {
+            stale_while_revalidate: config.stale_while_revalidate,
+        })
+}
+
+#[op2(async)]
+#[serde]
+pub async fn op_http_cache_get(
+    state: Rc<RefCell<OpState>>,
+    #[string] url: String,
+    #[serde] request_headers: Vec<(String, String)>,
+) -> anyhow::Result<Option<JsHttpCacheEntry>> {
+    let config = http_cache_config(&state)?;
+
+    let entry = tokio::task::spawn_blocking(move || config.read(&url, &request_headers)).await??;
+
+    Ok(entry.map(|entry| JsHttpCacheEntry {
+        fresh: entry.expires_at > now(),
+        etag: header(&entry.headers, "etag").map(|value| value.to_string()),
+        last_modified: header(&entry.headers, "last-modified").map(|value| value.to_string()),
+        status: entry.status,
+        status_text: entry.status_text,
+        headers: entry.headers,
+        body: entry.body.into(),
+    }))
+}
+
+#[op2(async)]
+pub async fn op_http_cache_put(
+    state: Rc<RefCell<OpState>>,
+    #[string] url: String,
+    #[serde] request_headers: Vec<(String, String)>,
+    status: u16,
+    #[string] status_text: String,
+    #[serde] headers: Vec<(String, String)>,
+    #[buffer] body: JsBuffer,
+) -> anyhow::Result<()> {
+    let config = http_cache_config(&state)?;
+
+    let Some(expires_at) = expires_at(&headers) else {
+        return Ok(())
+    };
+
+    let Some(vary) = vary(&headers, &request_headers) else {
+        return Ok(())
+    };
+
+    let entry = HttpCacheEntry {
+        url,
+        status,
+        status_text,
+        headers,
+        vary,
+        body: body.to_vec(),
+        expires_at,
+    };
+
+    tokio::task::spawn_blocking(move || config.write(entry)).await?
+}
+
+// called when server responded with 304 Not Modified, headers of the response replace the stored ones
+#[op2(async)]
+pub async fn op_http_cache_refresh(
+    state: Rc<RefCell<OpState>>,
+    #[string] url: String,
+    #[serde] request_headers: Vec<(String, String)>,
+    #[serde] headers: Vec<(String, String)>,
+) -> anyhow::Result<()> {
+    let config = http_cache_config(&state)?;
+
+    tokio::task::spawn_blocking(move || {
+        let Some(mut entry) = config.read(&url, &request_headers)? else {
+            return Ok(())
+        };
+
+        for (name, value) in headers {
+            entry.headers.retain(|(header_name, _)| !header_name.eq_ignore_ascii_case(&name));
+            entry.headers.push((name, value));
+        }
+
+        match expires_at(&entry.headers) {
+            None => std::fs::remove_file(config.entry_file(&url)).context("Unable to remove http cache entry"),
+            Some(expires_at) => {
+                entry.expires_at = expires_at;
+
+                config.write(entry)
+            }
+        }
+    }).await?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(headers: &[(&str, &str)]) -> Vec<(String, String)> {
+        headers.iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn vary_stores_request_header_values() {
+        let vary = vary(
+            &headers(&[("Vary", "Accept-Language, Authorization")]),
+            &headers(&[("accept-language", "en-US")]),
+        );
+
+        assert_eq!(vary, Some(vec![
+            ("accept-language".to_string(), Some("en-US".to_string())),
+            ("authorization".to_string(), None),
+        ]));
+    }
+
+    #[test]
+    fn vary_star_is_not_stored() {
+        assert_eq!(vary(&headers(&[("vary", "*")]), &[]), None);
+    }
+
+    #[test]
+    fn entry_is_not_used_for_different_vary_header() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let config = HttpCacheConfig {
+            dir: dir.path().to_path_buf(),
+            max_size: u64::MAX,
+            stale_while_revalidate: false,
+        };
+
+        let response_headers = headers(&[("vary", "accept-language")]);
+
+        config.write(HttpCacheEntry {
+            url: "https://example.com".to_string(),
+            status: 200,
+            status_text: "OK".to_string(),
+            vary: vary(&response_headers, &headers(&[("Accept-Language", "en-US")])).unwrap(),
+            headers: response_headers,
+            body: vec![],
+            expires_at: u64::MAX,
+        }).unwrap();
+
+        assert!(config.read("https://example.com", &headers(&[("Accept-Language", "en-US")])).unwrap().is_some());
+        assert!(config.read("https://example.com", &headers(&[("Accept-Language", "de-DE")])).unwrap().is_none());
+    }
+}
@@ -3,16 +3,22 @@ mod assets;
 mod clipboard;
 mod entrypoint_generators;
 mod component_model;
+mod database;
 mod deno;
 mod environment;
 mod events;
+mod fs;
+mod http_cache;
 mod logs;
 mod model;
+mod oauth;
 mod permissions;
 mod plugin_data;
 mod plugins;
 mod preferences;
 mod search;
+mod subprocess;
+mod terminal;
 mod ui;
 
 use crate::api::BackendForPluginRuntimeApiProxy;
@@ -42,22 +48,25 @@ use tokio_util::sync::CancellationToken;
 use gauntlet_utils::channel::{Payload, RequestReceiver};
 
 pub use api::BackendForPluginRuntimeApi;
+pub use deno::create_runtime_snapshot;
 pub use events::JsEvent;
 pub use events::JsKeyboardEventOrigin;
 pub use events::JsUiPropertyValue;
 pub use model::*;
 pub use permissions::PERMISSIONS_VARIABLE_PATTERN;
+pub use deno::NODE_COMPAT_MODULES;
 
-pub fn run_plugin_runtime(socket_name: String) {
+// startup snapshot is created by server build script using create_runtime_snapshot
+pub fn run_plugin_runtime(socket_name: String, startup_snapshot: Option<&'static [u8]>) {
     tokio::runtime::Builder::new_current_thread()
         .enable_all()
         .build()
         .expect("unable to start tokio runtime for plugin")
-        .block_on(run_outer(socket_name))
+        .block_on(run_outer(socket_name, startup_snapshot))
         .expect("plugin runtime crashed");
 }
 
-async fn run_outer(socket_name: String) -> anyhow::Result<()> {
+async fn run_outer(socket_name: String, startup_snapshot: Option<&'static [u8]>) -> anyhow::Result<()> {
     tracing::info!("Starting plugin runtime at socket: {}", &socket_name);
 
     let stop_token = CancellationToken::new();
@@ -114,7 +123,7 @@ async fn run_outer(socket_name: String) -> anyhow::Result<()> {
             tracing::error!("Request loop has unexpectedly stopped {:?}", plugin_id)
         }
         result @ _ = {
-            run_new_tokio(handle, stop_token.clone(), init, event_receiver, api)
+            run_new_tokio(handle, stop_token.clone(), init, event_receiver, api, startup_snapshot)
         } => {
             tracing::error!("Request loop has unexpectedly stopped {:?}", plugin_id)
         }
@@ -129,19 +138,19 @@ async fn run_outer(socket_name: String) -> anyhow::Result<()> {
     Ok(())
 }
 
-async fn run_new_tokio(outer_handle: Handle, stop_token: CancellationToken, init: JsInit, event_receiver: Receiver<JsEvent>, api: BackendForPluginRuntimeApiProxy) -> anyhow::Result<()> {
-    tokio::task::spawn_blocking(|| {
+async fn run_new_tokio(outer_handle: Handle, stop_token: CancellationToken, init: JsInit, event_receiver: Receiver<JsEvent>, api: BackendForPluginRuntimeApiProxy, startup_snapshot: Option<&'static [u8]>) -> anyhow::Result<()> {
+    tokio::task::spawn_blocking(move || {
         tokio::runtime::Builder::new_current_thread()
             .enable_all()
             .build()
             .expect("unable to start tokio runtime for plugin")
-            .block_on(run(outer_handle, stop_token, init, event_receiver, api))
+            .block_on(run(outer_handle, stop_token, init, event_receiver, api, startup_snapshot))
     }).await??;
 
     Ok(())
 }
 
-async fn run(outer_handle: Handle, stop_token: CancellationToken, init: JsInit, event_receiver: Receiver<JsEvent>, api: BackendForPluginRuntimeApiProxy) -> anyhow::Result<()> {
+async fn run(outer_handle: Handle, stop_token: CancellationToken, init: JsInit, event_receiver: Receiver<JsEvent>, api: BackendForPluginRuntimeApiProxy, startup_snapshot: Option<&'static [u8]>) -> anyhow::Result<()> {
     let plugin_id = init.plugin_id.clone();
 
     tokio::select! {
@@ -150,7 +159,7 @@ async fn run(outer_handle: Handle, stop_token: CancellationToken, init: JsInit,
         }
         result @ _ = {
             tokio::task::unconstrained(async {
-                 start_js_runtime(outer_handle, init, event_receiver, api).await
+                 start_js_runtime(outer_handle, init, event_receiver, api, startup_snapshot).await
             })
         } => {
             if let Err(err) = result {
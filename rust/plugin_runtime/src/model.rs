@@ -1,5 +1,5 @@
 use crate::JsEvent;
-use gauntlet_common::model::{EntrypointId, Icons, PluginId, RootWidget};
+use gauntlet_common::model::{EntrypointId, Icons, PhysicalShortcut, PluginId, RootWidget};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
@@ -18,6 +18,20 @@ pub enum JsUiRenderLocation {
     View
 }
 
+// key handler registered by plugin view using useKeyHandler hook, key uses the same names as keyboard events
+#[derive(Debug, Deserialize)]
+pub struct JsKeyHandler {
+    pub key: String,
+    #[serde(rename = "modifierShift")]
+    pub modifier_shift: bool,
+    #[serde(rename = "modifierControl")]
+    pub modifier_control: bool,
+    #[serde(rename = "modifierAlt")]
+    pub modifier_alt: bool,
+    #[serde(rename = "modifierMeta")]
+    pub modifier_meta: bool,
+}
+
 #[derive(Debug, Encode, Decode)]
 pub struct JsPluginCode {
     pub js: HashMap<String, String>,
@@ -31,15 +45,29 @@ pub struct JsInit {
     pub permissions: JsPluginPermissions,
     pub inline_view_entrypoint_id: Option<String>,
     pub entrypoint_names: HashMap<EntrypointId, String>,
+    pub preload_entrypoint_ids: Vec<EntrypointId>,
     pub dev_plugin: bool,
     pub home_dir: String,
     pub local_storage_dir: String,
     pub plugin_cache_dir: String,
     pub plugin_data_dir: String,
+    pub code_cache_dir: Option<String>,
+    pub http_cache: Option<JsPluginHttpCache>,
+    // user defined, also set on runtime process
+    pub environment: HashMap<String, String>,
+    // approved by user in settings, only accessible using FS api
+    pub filesystem_roots: Vec<String>,
     pub stdout_file: Option<String>,
     pub stderr_file: Option<String>,
 }
 
+#[derive(Debug, Encode, Decode)]
+pub struct JsPluginHttpCache {
+    // in bytes
+    pub max_size: u64,
+    pub stale_while_revalidate: bool,
+}
+
 #[derive(Debug, Encode, Decode)]
 pub struct JsPluginPermissions {
     pub environment: Vec<String>,
@@ -56,7 +84,7 @@ pub struct JsPluginPermissionsFileSystem {
     pub write: Vec<String>,
 }
 
-#[derive(Debug, Encode, Decode)]
+#[derive(Clone, Debug, Encode, Decode)]
 pub struct JsPluginPermissionsExec {
     pub command: Vec<String>,
     pub executable: Vec<String>,
@@ -82,6 +110,9 @@ pub enum JsResponse {
     EntrypointGeneratorEntrypointIds {
         data: Vec<String>
     },
+    SearchProviderEntrypointIds {
+        data: Vec<String>
+    },
     PluginPreferences {
         data: HashMap<String, JsPreferenceUserData>
     },
@@ -94,6 +125,9 @@ pub enum JsResponse {
     EntrypointPreferencesRequired {
         data: bool
     },
+    PluginOnboardingRequired {
+        data: bool
+    },
     ClipboardRead {
         data: JsClipboardData
     },
@@ -103,6 +137,12 @@ pub enum JsResponse {
     ActionIdForShortcut {
         data: Option<String>
     },
+    OAuthAuthorize {
+        data: Option<JsOAuthTokens>
+    },
+    NotificationId {
+        data: u32
+    },
 }
 
 #[derive(Debug, Encode, Decode)]
@@ -112,6 +152,8 @@ pub enum JsRequest {
         entrypoint_name: String,
         render_location: JsUiRenderLocation,
         top_level_view: bool,
+        breadcrumbs: Vec<String>,
+        key_handlers: Vec<PhysicalShortcut>,
         container: RootWidget,
     },
     ClearInlineView,
@@ -119,15 +161,23 @@ pub enum JsRequest {
         entrypoint_id: EntrypointId,
         render_location: JsUiRenderLocation,
     },
-    ShowPreferenceRequiredView {
+    ShowOnboardingView {
         entrypoint_id: EntrypointId,
-        plugin_preferences_required: bool,
-        entrypoint_preferences_required: bool
     },
     ShowHud {
         display: String
     },
+    ShowNotification {
+        title: String,
+        body: Option<String>,
+        // labels of action buttons
+        actions: Vec<String>,
+    },
     HideWindow,
+    OpenSettings,
+    ShowWindowWithSearchQuery {
+        query: String
+    },
     UpdateLoadingBar {
         entrypoint_id: EntrypointId,
         show: bool
@@ -140,6 +190,12 @@ pub enum JsRequest {
         path: String,
     },
     GetEntrypointGeneratorEntrypointIds,
+    GetSearchProviderEntrypointIds,
+    SearchProviderResults {
+        search_id: u32,
+        entrypoint_id: EntrypointId,
+        results: Vec<JsGeneratedSearchItem>,
+    },
     GetPluginPreferences,
     GetEntrypointPreferences {
         entrypoint_id: EntrypointId,
@@ -148,6 +204,7 @@ pub enum JsRequest {
     EntrypointPreferencesRequired {
         entrypoint_id: EntrypointId,
     },
+    PluginOnboardingRequired,
     ClipboardRead,
     ClipboardReadText,
     ClipboardWrite {
@@ -156,7 +213,29 @@ pub enum JsRequest {
     ClipboardWriteText {
         data: String
     },
+    ClipboardWriteSensitiveText {
+        data: String
+    },
+    ClipboardPasteText {
+        data: String,
+        sensitive: bool,
+    },
     ClipboardClear,
+    RunInTerminal {
+        command: Vec<String>,
+        cwd: Option<String>,
+    },
+    RunCommand {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        arguments: Vec<String>,
+    },
+    OAuthAuthorize {
+        config: JsOAuthConfig
+    },
+    OAuthRemoveTokens {
+        config: JsOAuthConfig
+    },
     GetActionIdForShortcut {
         entrypoint_id: EntrypointId,
         key: String,
@@ -170,12 +249,19 @@ pub enum JsRequest {
 #[derive(Deserialize, Serialize, Encode, Decode)]
 pub struct JsGeneratedSearchItem {
     pub entrypoint_name: String,
+    pub entrypoint_subtitle: Option<String>,
+    #[serde(default)]
+    pub entrypoint_keywords: Vec<String>,
     pub generator_entrypoint_id: String,
     pub entrypoint_id: String,
     pub entrypoint_uuid: String,
     pub entrypoint_icon: Option<Vec<u8>>,
     pub entrypoint_actions: Vec<JsGeneratedSearchItemAction>,
     pub entrypoint_accessories: Vec<JsGeneratedSearchItemAccessory>,
+    #[serde(default)]
+    pub entrypoint_preview: Option<String>,
+    #[serde(default)]
+    pub entrypoint_file: Option<String>,
 }
 
 impl fmt::Debug for JsGeneratedSearchItem {
@@ -183,11 +269,15 @@ impl fmt::Debug for JsGeneratedSearchItem {
         // exclude entrypoint_icon
         fmt.debug_struct("JsGeneratedSearchItem")
             .field("entrypoint_name", &self.entrypoint_name)
+            .field("entrypoint_subtitle", &self.entrypoint_subtitle)
+            .field("entrypoint_keywords", &self.entrypoint_keywords)
             .field("generator_entrypoint_id", &self.generator_entrypoint_id)
             .field("entrypoint_id", &self.entrypoint_id)
             .field("entrypoint_uuid", &self.entrypoint_uuid)
             .field("entrypoint_actions", &self.entrypoint_actions)
             .field("entrypoint_accessories", &self.entrypoint_accessories)
+            .field("entrypoint_preview", &self.entrypoint_preview)
+            .field("entrypoint_file", &self.entrypoint_file)
             .finish()
     }
 }
@@ -234,4 +324,24 @@ pub enum JsGeneratedSearchItemAccessory {
 pub struct JsClipboardData {
     pub text_data: Option<String>,
     pub png_data: Option<Vec<u8>>
+}
+
+#[derive(Debug, Serialize, Deserialize, Encode, Decode)]
+pub struct JsOAuthConfig {
+    pub client_id: String,
+    pub client_secret: Option<String>,
+    pub authorize_url: String,
+    pub token_url: String,
+    pub scopes: Vec<String>,
+    pub redirect_port: Option<u16>,
+    pub extra_authorize_params: HashMap<String, String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Encode, Decode)]
+pub struct JsOAuthTokens {
+    pub access_token: String,
+    pub token_type: String,
+    // milliseconds since unix epoch
+    pub expires_at: Option<f64>,
+    pub scopes: Vec<String>,
 }
\ No newline at end of file
@@ -0,0 +1,37 @@
+use deno_core::{op2, OpState};
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::api::{BackendForPluginRuntimeApi, BackendForPluginRuntimeApiProxy};
+use crate::model::{JsOAuthConfig, JsOAuthTokens};
+
+// returns null while user is authorizing in browser, js side polls until tokens are returned
+#[op2(async)]
+#[serde]
+pub async fn oauth_authorize(state: Rc<RefCell<OpState>>, #[serde] config: JsOAuthConfig) -> anyhow::Result<Option<JsOAuthTokens>> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state
+            .borrow::<BackendForPluginRuntimeApiProxy>()
+            .clone();
+
+        api
+    };
+
+    api.oauth_authorize(config).await
+}
+
+#[op2(async)]
+pub async fn oauth_remove_tokens(state: Rc<RefCell<OpState>>, #[serde] config: JsOAuthConfig) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state
+            .borrow::<BackendForPluginRuntimeApiProxy>()
+            .clone();
+
+        api
+    };
+
+    api.oauth_remove_tokens(config).await
+}
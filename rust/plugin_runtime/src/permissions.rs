@@ -147,6 +147,23 @@ fn run_permission(
     Ok(Permissions::new_unary(allow_list, None, false))
 }
 
+// programs which can be started using Process.run, commands are matched by name and executables by full path
+pub fn exec_allowed_programs(
+    permissions: &JsPluginPermissionsExec,
+    home_dir: &Path,
+    plugin_data_dir: &Path,
+    plugin_cache_dir: &Path,
+) -> anyhow::Result<HashSet<String>> {
+    let executables = permissions.executable
+        .iter()
+        .map(|path| augment_path(path, home_dir, plugin_data_dir, plugin_cache_dir))
+        .collect::<anyhow::Result<Vec<_>>>()?
+        .into_iter()
+        .filter_map(|path| path.map(|path| path.display().to_string()));
+
+    Ok(permissions.command.iter().cloned().chain(executables).collect())
+}
+
 fn augment_path(path: &String, home_dir: &Path, plugin_data_dir: &Path, plugin_cache_dir: &Path) -> anyhow::Result<Option<PathBuf>> {
     if let Some(matches) = PERMISSIONS_VARIABLE_PATTERN.captures(path) {
         let namespace = &matches["namespace"];
@@ -208,4 +225,24 @@ fn augment_path(path: &String, home_dir: &Path, plugin_data_dir: &Path, plugin_c
             }
         }
     }
-}
\ No newline at end of file
+}
+
+// directories accessible using FS api, paths are canonicalized so that symlinks can't be used to escape them.
+// roots which don't exist yet are kept as is
+pub fn filesystem_roots(
+    paths: &[String],
+    home_dir: &Path,
+    plugin_data_dir: &Path,
+    plugin_cache_dir: &Path,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let roots = paths
+        .iter()
+        .map(|path| augment_path(path, home_dir, plugin_data_dir, plugin_cache_dir))
+        .collect::<anyhow::Result<Vec<_>>>()?
+        .into_iter()
+        .filter_map(std::convert::identity)
+        .map(|path| std::fs::canonicalize(&path).unwrap_or(path))
+        .collect();
+
+    Ok(roots)
+}
@@ -9,7 +9,9 @@ pub struct PluginData {
     plugin_data_dir: String,
     inline_view_entrypoint_id: Option<String>,
     entrypoint_names: HashMap<String, String>,
+    preload_entrypoint_ids: Vec<String>,
     home_dir: PathBuf,
+    environment: HashMap<String, String>,
 }
 
 impl PluginData {
@@ -20,13 +22,20 @@ impl PluginData {
         plugin_data_dir: String,
         inline_view_entrypoint_id: Option<String>,
         entrypoint_names: HashMap<EntrypointId, String>,
+        preload_entrypoint_ids: Vec<EntrypointId>,
         home_dir: PathBuf,
+        environment: HashMap<String, String>,
     ) -> Self {
         let entrypoint_names = entrypoint_names
             .into_iter()
             .map(|(entrypoint_id, value)| (entrypoint_id.to_string(), value))
             .collect();
 
+        let preload_entrypoint_ids = preload_entrypoint_ids
+            .into_iter()
+            .map(|entrypoint_id| entrypoint_id.to_string())
+            .collect();
+
         Self {
             plugin_id,
             plugin_uuid,
@@ -34,7 +43,9 @@ impl PluginData {
             plugin_data_dir,
             inline_view_entrypoint_id,
             entrypoint_names,
-            home_dir
+            preload_entrypoint_ids,
+            home_dir,
+            environment,
         }
     }
 
@@ -62,7 +73,15 @@ impl PluginData {
         &self.entrypoint_names
     }
 
+    pub fn preload_entrypoint_ids(&self) -> &Vec<String> {
+        &self.preload_entrypoint_ids
+    }
+
     pub fn home_dir(&self) -> PathBuf {
         self.home_dir.clone()
     }
+
+    pub fn environment(&self) -> &HashMap<String, String> {
+        &self.environment
+    }
 }
\ No newline at end of file
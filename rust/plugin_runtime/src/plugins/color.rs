@@ -0,0 +1,106 @@
+use anyhow::anyhow;
+use deno_core::op2;
+
+// returns color in "#rrggbb" format or None if picking was cancelled by user
+#[op2(async)]
+#[serde]
+pub async fn pick_color_from_screen() -> anyhow::Result<Option<String>> {
+    let color = tokio::task::spawn_blocking(pick_color_from_screen_blocking).await??;
+
+    Ok(color.map(|(red, green, blue)| format!("#{:02x}{:02x}{:02x}", red, green, blue)))
+}
+
+// uses Screenshot portal, which is implemented by desktop environment for both X11 and Wayland
+#[cfg(target_os = "linux")]
+fn pick_color_from_screen_blocking() -> anyhow::Result<Option<(u8, u8, u8)>> {
+    use std::collections::HashMap;
+    use std::time::{SystemTime, UNIX_EPOCH};
+    use zbus::zvariant::{OwnedValue, Value};
+
+    let connection = zbus::blocking::Connection::session()?;
+
+    let sender = connection.unique_name()
+        .ok_or(anyhow!("D-Bus connection doesn't have unique name"))?
+        .trim_start_matches(':')
+        .replace('.', "_");
+
+    let token = format!("gauntlet_{}", SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis());
+
+    // subscribe to response before calling the method so that it cannot be missed
+    let request = zbus::blocking::Proxy::new(
+        &connection,
+        "org.freedesktop.portal.Desktop",
+        format!("/org/freedesktop/portal/desktop/request/{}/{}", sender, token),
+        "org.freedesktop.portal.Request",
+    )?;
+
+    let mut responses = request.receive_signal("Response")?;
+
+    let options = HashMap::from([("handle_token", Value::from(token.as_str()))]);
+
+    connection.call_method(
+        Some("org.freedesktop.portal.Desktop"),
+        "/org/freedesktop/portal/desktop",
+        Some("org.freedesktop.portal.Screenshot"),
+        "PickColor",
+        &("", options),
+    )?;
+
+    let response = responses.next()
+        .ok_or(anyhow!("Portal didn't respond to color pick request"))?;
+
+    let (status, results): (u32, HashMap<String, OwnedValue>) = response.body().deserialize()?;
+
+    // 0 - success, 1 - cancelled by user, 2 - ended in some other way
+    match status {
+        0 => {}
+        1 => return Ok(None),
+        _ => return Err(anyhow!("Portal was unable to pick color, status: {}", status)),
+    }
+
+    let color = results.get("color")
+        .ok_or(anyhow!("Portal response doesn't contain color"))?;
+
+    // each component is in 0.0..=1.0 range
+    let (red, green, blue) = <(f64, f64, f64)>::try_from(color.try_clone()?)?;
+
+    let to_u8 = |value: f64| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+
+    Ok(Some((to_u8(red), to_u8(green), to_u8(blue))))
+}
+
+// there is no public api to sample screen directly, system color panel has a built-in screen sampler
+#[cfg(target_os = "macos")]
+fn pick_color_from_screen_blocking() -> anyhow::Result<Option<(u8, u8, u8)>> {
+    let output = std::process::Command::new("osascript")
+        .args(["-e", "choose color"])
+        .output()?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        // -128 is "User canceled" error
+        if stderr.contains("-128") {
+            return Ok(None)
+        }
+
+        return Err(anyhow!("osascript exited with {}: {}", output.status, stderr))
+    }
+
+    // "65535, 0, 0", each component is in 0..=65535 range
+    let components = String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .split(',')
+        .map(|component| component.trim().parse::<u32>().map(|value| (value / 257) as u8))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match components[..] {
+        [red, green, blue] => Ok(Some((red, green, blue))),
+        _ => Err(anyhow!("Unexpected osascript output: {:?}", components))
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn pick_color_from_screen_blocking() -> anyhow::Result<Option<(u8, u8, u8)>> {
+    Err(anyhow!("Picking color from screen is not supported on Windows"))
+}
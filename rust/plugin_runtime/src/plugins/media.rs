@@ -0,0 +1,257 @@
+use deno_core::op2;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize)]
+pub struct NowPlaying {
+    // platform specific id of the player, passed back when controlling playback
+    player: String,
+    title: String,
+    artist: Option<String>,
+    album: Option<String>,
+    artwork_url: Option<String>,
+    // in seconds
+    position: Option<f64>,
+    // in seconds
+    duration: Option<f64>,
+    playing: bool,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum MediaCommand {
+    #[serde(rename = "play-pause")]
+    PlayPause,
+    #[serde(rename = "next")]
+    Next,
+    #[serde(rename = "previous")]
+    Previous,
+}
+
+#[op2(async)]
+#[serde]
+pub async fn media_now_playing() -> anyhow::Result<Option<NowPlaying>> {
+    tokio::task::spawn_blocking(media_now_playing_blocking).await?
+}
+
+#[op2(async)]
+pub async fn media_control(#[string] player: String, #[serde] command: MediaCommand) -> anyhow::Result<()> {
+    tracing::debug!("Sending media command {:?} to player: {}", command, player);
+
+    tokio::task::spawn_blocking(move || media_control_blocking(&player, command)).await?
+}
+
+#[cfg(target_os = "linux")]
+const MPRIS_PREFIX: &str = "org.mpris.MediaPlayer2.";
+
+#[cfg(target_os = "linux")]
+const MPRIS_PATH: &str = "/org/mpris/MediaPlayer2";
+
+#[cfg(target_os = "linux")]
+const MPRIS_PLAYER_INTERFACE: &str = "org.mpris.MediaPlayer2.Player";
+
+// every media player which implements MPRIS is registered on session bus under "org.mpris.MediaPlayer2." prefix.
+// player which is currently playing is preferred, otherwise the one that was paused, otherwise any
+#[cfg(target_os = "linux")]
+fn media_now_playing_blocking() -> anyhow::Result<Option<NowPlaying>> {
+    let connection = zbus::blocking::Connection::session()?;
+
+    let reply = connection.call_method(
+        Some("org.freedesktop.DBus"),
+        "/org/freedesktop/DBus",
+        Some("org.freedesktop.DBus"),
+        "ListNames",
+        &(),
+    )?;
+
+    let names: Vec<String> = reply.body().deserialize()?;
+
+    let mut players = names.into_iter()
+        .filter(|name| name.starts_with(MPRIS_PREFIX))
+        .filter_map(|name| {
+            match mpris_player(&connection, &name) {
+                Ok(player) => player,
+                Err(err) => {
+                    tracing::debug!("Unable to get state of media player {}: {:?}", name, err);
+                    None
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    players.sort_by_key(|(status, _)| match status.as_str() {
+        "Playing" => 0,
+        "Paused" => 1,
+        _ => 2,
+    });
+
+    Ok(players.into_iter().next().map(|(_, now_playing)| now_playing))
+}
+
+#[cfg(target_os = "linux")]
+fn mpris_player(connection: &zbus::blocking::Connection, name: &str) -> anyhow::Result<Option<(String, NowPlaying)>> {
+    use std::collections::HashMap;
+    use zbus::zvariant::OwnedValue;
+
+    let reply = connection.call_method(
+        Some(name),
+        MPRIS_PATH,
+        Some("org.freedesktop.DBus.Properties"),
+        "GetAll",
+        &(MPRIS_PLAYER_INTERFACE),
+    )?;
+
+    let properties: HashMap<String, OwnedValue> = reply.body().deserialize()?;
+
+    let status = properties.get("PlaybackStatus")
+        .map(|value| String::try_from(value.try_clone()?))
+        .transpose()?
+        .unwrap_or_default();
+
+    let metadata = match properties.get("Metadata") {
+        None => return Ok(None),
+        Some(metadata) => HashMap::<String, OwnedValue>::try_from(metadata.try_clone()?)?
+    };
+
+    let string = |key: &str| {
+        metadata.get(key)
+            .and_then(|value| value.try_clone().ok())
+            .and_then(|value| String::try_from(value).ok())
+            .filter(|value| !value.is_empty())
+    };
+
+    let string_list = |key: &str| {
+        metadata.get(key)
+            .and_then(|value| value.try_clone().ok())
+            .and_then(|value| Vec::<String>::try_from(value).ok())
+            .filter(|value| !value.is_empty())
+            .map(|value| value.join(", "))
+    };
+
+    // microseconds, players are not consistent whether it is signed or not
+    let microseconds = |value: Option<&OwnedValue>| {
+        let value = value?.try_clone().ok()?;
+
+        let microseconds = match i64::try_from(value.try_clone().ok()?) {
+            Ok(value) => value as f64,
+            Err(_) => u64::try_from(value).ok()? as f64,
+        };
+
+        Some(microseconds / 1_000_000.0)
+    };
+
+    let Some(title) = string("xesam:title") else {
+        return Ok(None)
+    };
+
+    let now_playing = NowPlaying {
+        player: name.to_string(),
+        title,
+        artist: string_list("xesam:artist"),
+        album: string("xesam:album"),
+        artwork_url: string("mpris:artUrl"),
+        position: microseconds(properties.get("Position")),
+        duration: microseconds(metadata.get("mpris:length")),
+        playing: status == "Playing",
+    };
+
+    Ok(Some((status, now_playing)))
+}
+
+#[cfg(target_os = "linux")]
+fn media_control_blocking(player: &str, command: MediaCommand) -> anyhow::Result<()> {
+    let connection = zbus::blocking::Connection::session()?;
+
+    let method = match command {
+        MediaCommand::PlayPause => "PlayPause",
+        MediaCommand::Next => "Next",
+        MediaCommand::Previous => "Previous",
+    };
+
+    connection.call_method(
+        Some(player),
+        MPRIS_PATH,
+        Some(MPRIS_PLAYER_INTERFACE),
+        method,
+        &(),
+    )?;
+
+    Ok(())
+}
+
+// system media transport controls, players are identified by their app user model id
+#[cfg(target_os = "windows")]
+fn media_now_playing_blocking() -> anyhow::Result<Option<NowPlaying>> {
+    use windows::Media::Control::{GlobalSystemMediaTransportControlsSessionManager, GlobalSystemMediaTransportControlsSessionPlaybackStatus};
+
+    let manager = GlobalSystemMediaTransportControlsSessionManager::RequestAsync()?.get()?;
+
+    let Ok(session) = manager.GetCurrentSession() else {
+        return Ok(None)
+    };
+
+    let properties = session.TryGetMediaPropertiesAsync()?.get()?;
+
+    let title = properties.Title()?.to_string();
+    if title.is_empty() {
+        return Ok(None)
+    }
+
+    let non_empty = |value: String| Some(value).filter(|value| !value.is_empty());
+
+    let playing = session.GetPlaybackInfo()?.PlaybackStatus()? == GlobalSystemMediaTransportControlsSessionPlaybackStatus::Playing;
+
+    let timeline = session.GetTimelineProperties()?;
+
+    // TimeSpan is in 100 nanosecond units
+    let seconds = |duration: i64| Some(duration as f64 / 10_000_000.0).filter(|duration| *duration > 0.0);
+
+    Ok(Some(NowPlaying {
+        player: session.SourceAppUserModelId()?.to_string(),
+        title,
+        artist: non_empty(properties.Artist()?.to_string()),
+        album: non_empty(properties.AlbumTitle()?.to_string()),
+        // thumbnail is only available as stream
+        artwork_url: None,
+        position: seconds(timeline.Position()?.Duration),
+        duration: seconds(timeline.EndTime()?.Duration),
+        playing,
+    }))
+}
+
+#[cfg(target_os = "windows")]
+fn media_control_blocking(player: &str, command: MediaCommand) -> anyhow::Result<()> {
+    use windows::Media::Control::GlobalSystemMediaTransportControlsSessionManager;
+
+    let manager = GlobalSystemMediaTransportControlsSessionManager::RequestAsync()?.get()?;
+
+    let session = manager.GetSessions()?
+        .into_iter()
+        .find(|session| {
+            session.SourceAppUserModelId()
+                .map(|id| id.to_string() == player)
+                .unwrap_or(false)
+        })
+        .ok_or(anyhow::anyhow!("Media player is not available anymore: {}", player))?;
+
+    let success = match command {
+        MediaCommand::PlayPause => session.TryTogglePlayPauseAsync()?.get()?,
+        MediaCommand::Next => session.TrySkipNextAsync()?.get()?,
+        MediaCommand::Previous => session.TrySkipPreviousAsync()?.get()?,
+    };
+
+    if !success {
+        return Err(anyhow::anyhow!("Media player refused command: {:?}", command))
+    }
+
+    Ok(())
+}
+
+// the api macos uses to provide "now playing" information is private
+#[cfg(target_os = "macos")]
+fn media_now_playing_blocking() -> anyhow::Result<Option<NowPlaying>> {
+    Ok(None)
+}
+
+#[cfg(target_os = "macos")]
+fn media_control_blocking(_player: &str, _command: MediaCommand) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!("Media controls are not supported on macOS"))
+}
@@ -1,3 +1,9 @@
 pub mod applications;
+pub mod color;
+pub mod media;
 pub mod numbat;
+pub mod processes;
+pub mod quick_note;
 pub mod settings;
+pub mod shell;
+pub mod system;
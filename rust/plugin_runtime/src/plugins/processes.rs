@@ -0,0 +1,104 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use anyhow::anyhow;
+use deno_core::{op2, OpState};
+use serde::Serialize;
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, Signal, System};
+
+#[derive(Clone)]
+pub struct ProcessContext(Rc<RefCell<System>>);
+
+impl ProcessContext {
+    pub fn new() -> ProcessContext {
+        // cpu usage is calculated as difference between refreshes,
+        // so the same instance is reused for the lifetime of plugin
+        ProcessContext(Rc::new(RefCell::new(System::new())))
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProcessInfo {
+    pid: u32,
+    name: String,
+    // percentage of total cpu time, normalized by number of cpus
+    cpu_usage: f32,
+    // in bytes
+    memory: u64,
+}
+
+#[op2]
+#[serde]
+pub fn process_list(state: Rc<RefCell<OpState>>) -> Vec<ProcessInfo> {
+    let context = {
+        let state = state.borrow();
+
+        state
+            .borrow::<ProcessContext>()
+            .clone()
+    };
+
+    let mut system = context.0.borrow_mut();
+
+    system.refresh_cpu_list(sysinfo::CpuRefreshKind::nothing());
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        true,
+        ProcessRefreshKind::nothing()
+            .with_cpu()
+            .with_memory(),
+    );
+
+    let cpu_count = system.cpus().len().max(1) as f32;
+
+    system.processes()
+        .iter()
+        .map(|(pid, process)| ProcessInfo {
+            pid: pid.as_u32(),
+            name: process.name().to_string_lossy().to_string(),
+            cpu_usage: process.cpu_usage() / cpu_count,
+            memory: process.memory(),
+        })
+        .collect()
+}
+
+#[op2(fast)]
+pub fn process_terminate(state: Rc<RefCell<OpState>>, pid: u32) -> anyhow::Result<()> {
+    send_signal(state, pid, false)
+}
+
+#[op2(fast)]
+pub fn process_kill(state: Rc<RefCell<OpState>>, pid: u32) -> anyhow::Result<()> {
+    send_signal(state, pid, true)
+}
+
+fn send_signal(state: Rc<RefCell<OpState>>, pid: u32, force: bool) -> anyhow::Result<()> {
+    let context = {
+        let state = state.borrow();
+
+        state
+            .borrow::<ProcessContext>()
+            .clone()
+    };
+
+    let system = context.0.borrow();
+
+    let process = system.process(Pid::from_u32(pid))
+        .ok_or(anyhow!("Process {} not found", pid))?;
+
+    tracing::info!("Sending {} to process {} ({:?})", if force { "SIGKILL" } else { "SIGTERM" }, pid, process.name());
+
+    let sent = if force {
+        process.kill()
+    } else {
+        // windows doesn't have SIGTERM equivalent, fall back to forceful kill
+        process.kill_with(Signal::Term)
+            .unwrap_or_else(|| process.kill())
+    };
+
+    if !sent {
+        return Err(anyhow!("Unable to send signal to process {}", pid))
+    }
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use std::cell::RefCell;
+use std::io::Write;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use anyhow::anyhow;
+use deno_core::{op2, OpState};
+use gauntlet_common::model::{EntrypointId, PluginId};
+
+use crate::api::{BackendForPluginRuntimeApi, BackendForPluginRuntimeApiProxy};
+use crate::plugin_data::PluginData;
+
+// path can be absolute, start with "~" or be relative to user home directory.
+// returns path of the file the note was written to
+#[op2]
+#[string]
+pub fn quick_note_append(state: Rc<RefCell<OpState>>, #[string] path: String, #[string] text: String) -> anyhow::Result<String> {
+    let home_dir = {
+        let state = state.borrow();
+
+        state
+            .borrow::<PluginData>()
+            .home_dir()
+    };
+
+    let path = path.trim();
+
+    let path = match path.strip_prefix("~") {
+        Some(path) => home_dir.join(path.trim_start_matches(['/', '\\'])),
+        None => {
+            let path = PathBuf::from(path);
+
+            if path.is_absolute() {
+                path
+            } else {
+                home_dir.join(path)
+            }
+        }
+    };
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut file = std::fs::File::options()
+        .create(true)
+        .append(true)
+        .open(&path)?;
+
+    file.write_all(text.as_bytes())?;
+
+    Ok(path.to_string_lossy().to_string())
+}
+
+// hook is "<plugin-id>/<entrypoint-id>" of a command, note is passed as its first argument.
+// plugin id can contain slashes itself, so entrypoint id is everything after the last one
+#[op2(async)]
+pub async fn quick_note_run_hook(state: Rc<RefCell<OpState>>, #[string] hook: String, #[string] text: String) -> anyhow::Result<()> {
+    let (plugin_id, entrypoint_id) = hook.trim()
+        .rsplit_once('/')
+        .filter(|(plugin_id, entrypoint_id)| !plugin_id.is_empty() && !entrypoint_id.is_empty())
+        .ok_or_else(|| anyhow!("Note hook has to be in '<plugin-id>/<entrypoint-id>' format: {:?}", hook))?;
+
+    let api = {
+        let state = state.borrow();
+
+        let api = state
+            .borrow::<BackendForPluginRuntimeApiProxy>()
+            .clone();
+
+        api
+    };
+
+    api.run_command(PluginId::from_string(plugin_id), EntrypointId::from_string(entrypoint_id), vec![text]).await
+}
@@ -1,10 +1,21 @@
-use deno_core::op2;
+use std::cell::RefCell;
+use std::rc::Rc;
 
-#[op2(fast)]
-pub fn open_settings() -> anyhow::Result<()> {
-    std::process::Command::new(std::env::current_exe()?)
-        .args(["settings"])
-        .spawn()?;
+use deno_core::{op2, OpState};
 
-    Ok(())
-}
\ No newline at end of file
+use crate::api::{BackendForPluginRuntimeApi, BackendForPluginRuntimeApiProxy};
+
+#[op2(async)]
+pub async fn open_settings(state: Rc<RefCell<OpState>>) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state
+            .borrow::<BackendForPluginRuntimeApiProxy>()
+            .clone();
+
+        api
+    };
+
+    api.ui_open_settings().await
+}
@@ -0,0 +1,191 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read};
+use std::process::{Child, Command, Stdio};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+use anyhow::{anyhow, Context};
+use deno_core::{op2, OpState};
+use serde::Serialize;
+
+#[derive(Clone)]
+pub struct ShellContext(Rc<RefCell<ShellContextInner>>);
+
+struct ShellContextInner {
+    next_id: u32,
+    processes: HashMap<u32, ShellProcess>,
+}
+
+struct ShellProcess {
+    child: Child,
+    output: Arc<Mutex<Vec<ShellOutputLine>>>,
+    readers: Vec<JoinHandle<()>>,
+}
+
+impl ShellContext {
+    pub fn new() -> ShellContext {
+        ShellContext(Rc::new(RefCell::new(ShellContextInner {
+            next_id: 0,
+            processes: HashMap::new(),
+        })))
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type")]
+pub enum ShellOutputLine {
+    #[serde(rename = "stdout")]
+    Stdout {
+        text: String
+    },
+    #[serde(rename = "stderr")]
+    Stderr {
+        text: String
+    },
+}
+
+#[derive(Debug, Serialize)]
+pub struct ShellCommandPoll {
+    lines: Vec<ShellOutputLine>,
+    finished: bool,
+    exit_code: Option<i32>,
+}
+
+fn shell_command(command: &str) -> Command {
+    if cfg!(target_os = "windows") {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", command]);
+        cmd
+    } else {
+        let shell = std::env::var("SHELL")
+            .ok()
+            .filter(|shell| !shell.is_empty())
+            .unwrap_or_else(|| "/bin/sh".to_string());
+
+        let mut cmd = Command::new(shell);
+        cmd.args(["-c", command]);
+        cmd
+    }
+}
+
+fn spawn_reader<R: Read + Send + 'static>(reader: R, output: Arc<Mutex<Vec<ShellOutputLine>>>, to_line: fn(String) -> ShellOutputLine) -> JoinHandle<()> {
+    std::thread::spawn(move || {
+        let reader = BufReader::new(reader);
+
+        for line in reader.lines() {
+            match line {
+                Ok(line) => output.lock().unwrap().push(to_line(line)),
+                Err(err) => {
+                    tracing::warn!("unable to read shell command output: {:?}", err);
+                    break;
+                }
+            }
+        }
+    })
+}
+
+#[op2(fast)]
+pub fn shell_command_run(state: Rc<RefCell<OpState>>, #[string] command: String) -> anyhow::Result<u32> {
+    let context = {
+        let state = state.borrow();
+
+        state
+            .borrow::<ShellContext>()
+            .clone()
+    };
+
+    tracing::info!("Running shell command: {:?}", command);
+
+    let mut child = shell_command(&command)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("unable to spawn shell command")?;
+
+    let output = Arc::new(Mutex::new(vec![]));
+
+    let stdout = child.stdout.take().ok_or(anyhow!("stdout is not piped"))?;
+    let stderr = child.stderr.take().ok_or(anyhow!("stderr is not piped"))?;
+
+    let readers = vec![
+        spawn_reader(stdout, output.clone(), |text| ShellOutputLine::Stdout { text }),
+        spawn_reader(stderr, output.clone(), |text| ShellOutputLine::Stderr { text }),
+    ];
+
+    let mut context = context.0.borrow_mut();
+
+    let id = context.next_id;
+    context.next_id += 1;
+
+    context.processes.insert(id, ShellProcess { child, output, readers });
+
+    Ok(id)
+}
+
+#[op2]
+#[serde]
+pub fn shell_command_poll(state: Rc<RefCell<OpState>>, id: u32) -> anyhow::Result<ShellCommandPoll> {
+    let context = {
+        let state = state.borrow();
+
+        state
+            .borrow::<ShellContext>()
+            .clone()
+    };
+
+    let mut context = context.0.borrow_mut();
+
+    let process = context.processes
+        .get_mut(&id)
+        .ok_or(anyhow!("Unknown shell command: {}", id))?;
+
+    let status = process.child.try_wait()?;
+
+    // output needs to be fully read before reporting that command has finished
+    let readers_finished = process.readers.iter().all(|reader| reader.is_finished());
+
+    let lines = std::mem::take(&mut *process.output.lock().unwrap());
+
+    match status {
+        Some(status) if readers_finished => {
+            context.processes.remove(&id);
+
+            Ok(ShellCommandPoll {
+                lines,
+                finished: true,
+                exit_code: status.code(),
+            })
+        }
+        _ => {
+            Ok(ShellCommandPoll {
+                lines,
+                finished: false,
+                exit_code: None,
+            })
+        }
+    }
+}
+
+#[op2(fast)]
+pub fn shell_command_kill(state: Rc<RefCell<OpState>>, id: u32) -> anyhow::Result<()> {
+    let context = {
+        let state = state.borrow();
+
+        state
+            .borrow::<ShellContext>()
+            .clone()
+    };
+
+    let mut context = context.0.borrow_mut();
+
+    // killed process is waited on, so it doesn't stay as zombie. output readers finish on their own
+    if let Some(mut process) = context.processes.remove(&id) {
+        process.child.kill()?;
+        process.child.wait()?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,157 @@
+use anyhow::anyhow;
+use deno_core::op2;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum SystemCommand {
+    #[serde(rename = "lock")]
+    Lock,
+    #[serde(rename = "sleep")]
+    Sleep,
+    #[serde(rename = "log-out")]
+    LogOut,
+    #[serde(rename = "restart")]
+    Restart,
+    #[serde(rename = "shut-down")]
+    ShutDown,
+    #[serde(rename = "empty-trash")]
+    EmptyTrash,
+}
+
+#[op2(async)]
+pub async fn run_system_command(#[serde] command: SystemCommand) -> anyhow::Result<()> {
+    tracing::info!("Running system command: {:?}", command);
+
+    tokio::task::spawn_blocking(move || run_system_command_blocking(command)).await?
+}
+
+#[cfg(target_os = "linux")]
+fn run_system_command_blocking(command: SystemCommand) -> anyhow::Result<()> {
+    match command {
+        SystemCommand::Lock => logind_session_call("Lock"),
+        SystemCommand::LogOut => logind_session_call("Terminate"),
+        SystemCommand::Sleep => logind_manager_call("Suspend"),
+        SystemCommand::Restart => logind_manager_call("Reboot"),
+        SystemCommand::ShutDown => logind_manager_call("PowerOff"),
+        SystemCommand::EmptyTrash => linux_empty_trash(),
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn logind_manager_call(method: &str) -> anyhow::Result<()> {
+    let connection = zbus::blocking::Connection::system()?;
+
+    // "interactive" flag allows polkit to ask user for authentication if required
+    connection.call_method(
+        Some("org.freedesktop.login1"),
+        "/org/freedesktop/login1",
+        Some("org.freedesktop.login1.Manager"),
+        method,
+        &(true),
+    )?;
+
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn logind_session_call(method: &str) -> anyhow::Result<()> {
+    let connection = zbus::blocking::Connection::system()?;
+
+    // "auto" resolves to the session of the calling process
+    connection.call_method(
+        Some("org.freedesktop.login1"),
+        "/org/freedesktop/login1/session/auto",
+        Some("org.freedesktop.login1.Session"),
+        method,
+        &(),
+    )?;
+
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn linux_empty_trash() -> anyhow::Result<()> {
+    use std::path::PathBuf;
+
+    let data_dir = std::env::var_os("XDG_DATA_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local").join("share")))
+        .ok_or(anyhow!("Unable to find user data directory"))?;
+
+    let trash_dir = data_dir.join("Trash");
+
+    for dir in ["files", "info", "expunged"] {
+        let dir = trash_dir.join(dir);
+
+        if !dir.exists() {
+            continue;
+        }
+
+        for entry in std::fs::read_dir(&dir)? {
+            let path = entry?.path();
+
+            if path.is_dir() && !path.is_symlink() {
+                std::fs::remove_dir_all(&path)?;
+            } else {
+                std::fs::remove_file(&path)?;
+            }
+        }
+    }
+
+    let directory_sizes = trash_dir.join("directorysizes");
+    if directory_sizes.exists() {
+        std::fs::remove_file(directory_sizes)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn run_system_command_blocking(command: SystemCommand) -> anyhow::Result<()> {
+    match command {
+        SystemCommand::Lock => run_command("pmset", &["displaysleepnow"]),
+        SystemCommand::Sleep => run_command("osascript", &["-e", "tell application \"System Events\" to sleep"]),
+        SystemCommand::LogOut => run_command("osascript", &["-e", "tell application \"System Events\" to log out"]),
+        SystemCommand::Restart => run_command("osascript", &["-e", "tell application \"System Events\" to restart"]),
+        SystemCommand::ShutDown => run_command("osascript", &["-e", "tell application \"System Events\" to shut down"]),
+        SystemCommand::EmptyTrash => run_command("osascript", &["-e", "tell application \"Finder\" to empty trash"]),
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn run_system_command_blocking(command: SystemCommand) -> anyhow::Result<()> {
+    match command {
+        SystemCommand::Lock => run_command("rundll32.exe", &["user32.dll,LockWorkStation"]),
+        SystemCommand::Sleep => run_command("rundll32.exe", &["powrprof.dll,SetSuspendState", "0,1,0"]),
+        SystemCommand::LogOut => run_command("shutdown", &["/l"]),
+        SystemCommand::Restart => run_command("shutdown", &["/r", "/t", "0"]),
+        SystemCommand::ShutDown => run_command("shutdown", &["/s", "/t", "0"]),
+        SystemCommand::EmptyTrash => windows_empty_trash(),
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn windows_empty_trash() -> anyhow::Result<()> {
+    use windows::core::PCWSTR;
+    use windows::Win32::UI::Shell::{SHEmptyRecycleBinW, SHERB_NOCONFIRMATION, SHERB_NOPROGRESSUI, SHERB_NOSOUND};
+
+    unsafe {
+        SHEmptyRecycleBinW(None, PCWSTR::null(), SHERB_NOCONFIRMATION | SHERB_NOPROGRESSUI | SHERB_NOSOUND)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+fn run_command(program: &str, args: &[&str]) -> anyhow::Result<()> {
+    let output = std::process::Command::new(program)
+        .args(args)
+        .output()?;
+
+    if !output.status.success() {
+        return Err(anyhow!("{} exited with {}: {}", program, output.status, String::from_utf8_lossy(&output.stderr)))
+    }
+
+    Ok(())
+}
@@ -73,3 +73,18 @@ pub async fn entrypoint_preferences_required(state: Rc<RefCell<OpState>>, #[stri
 
     api.entrypoint_preferences_required(EntrypointId::from_string(entrypoint_id)).await
 }
+
+#[op2(async)]
+pub async fn plugin_onboarding_required(state: Rc<RefCell<OpState>>) -> anyhow::Result<bool> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state
+            .borrow::<BackendForPluginRuntimeApiProxy>()
+            .clone();
+
+        api
+    };
+
+    api.plugin_onboarding_required().await
+}
@@ -1,6 +1,7 @@
 use deno_core::{op2, OpState};
 use std::cell::RefCell;
 use std::rc::Rc;
+use gauntlet_common::model::EntrypointId;
 use crate::api::{BackendForPluginRuntimeApi, BackendForPluginRuntimeApiProxy};
 use crate::model::JsGeneratedSearchItem;
 
@@ -20,3 +21,34 @@ pub async fn reload_search_index(state: Rc<RefCell<OpState>>, #[serde] generated
 
     Ok(())
 }
+
+#[op2(async)]
+#[serde]
+pub async fn get_search_provider_entrypoint_ids(state: Rc<RefCell<OpState>>) -> anyhow::Result<Vec<String>> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state
+            .borrow::<BackendForPluginRuntimeApiProxy>()
+            .clone();
+
+        api
+    };
+
+    api.get_search_provider_entrypoint_ids().await
+}
+
+#[op2(async)]
+pub async fn search_provider_results(state: Rc<RefCell<OpState>>, search_id: u32, #[string] entrypoint_id: String, #[serde] results: Vec<JsGeneratedSearchItem>) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state
+            .borrow::<BackendForPluginRuntimeApiProxy>()
+            .clone();
+
+        api
+    };
+
+    api.search_provider_results(search_id, EntrypointId::from_string(entrypoint_id), results).await
+}
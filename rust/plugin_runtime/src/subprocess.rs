@@ -0,0 +1,252 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::process::Stdio;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, Context};
+use deno_core::{op2, OpState};
+use serde::Serialize;
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::process::Command;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+use tokio::sync::Notify;
+
+const READ_BUFFER_SIZE: usize = 8 * 1024;
+
+// processes started by plugin using Process.run, only programs listed in "exec" permission can be started.
+// processes are killed when plugin runtime stops
+#[derive(Clone)]
+pub struct Subprocesses(Rc<RefCell<SubprocessesInner>>);
+
+struct SubprocessesInner {
+    allowed_programs: HashSet<String>,
+    next_id: u32,
+    processes: HashMap<u32, Subprocess>,
+}
+
+struct Subprocess {
+    output: Rc<tokio::sync::Mutex<UnboundedReceiver<SubprocessChunk>>>,
+    kill: Arc<Notify>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type")]
+pub enum SubprocessChunk {
+    #[serde(rename = "stdout")]
+    Stdout {
+        text: String
+    },
+    #[serde(rename = "stderr")]
+    Stderr {
+        text: String
+    },
+    // always the last chunk
+    #[serde(rename = "exit")]
+    Exit {
+        code: Option<i32>,
+        #[serde(rename = "timedOut")]
+        timed_out: bool,
+        killed: bool,
+    },
+}
+
+impl Subprocesses {
+    pub fn new(allowed_programs: HashSet<String>) -> Self {
+        Self(Rc::new(RefCell::new(SubprocessesInner {
+            allowed_programs,
+            next_id: 0,
+            processes: HashMap::new(),
+        })))
+    }
+}
+
+#[op2]
+pub fn subprocess_spawn(
+    state: Rc<RefCell<OpState>>,
+    #[string] program: String,
+    #[serde] args: Vec<String>,
+    #[serde] cwd: Option<String>,
+    #[serde] env: HashMap<String, String>,
+    #[serde] timeout: Option<u64>,
+) -> anyhow::Result<u32> {
+    let subprocesses = {
+        let state = state.borrow();
+
+        state
+            .borrow::<Subprocesses>()
+            .clone()
+    };
+
+    let mut subprocesses = subprocesses.0.borrow_mut();
+
+    if !subprocesses.allowed_programs.contains(&program) {
+        return Err(anyhow!("Plugin doesn't have permission to run '{}'", program));
+    }
+
+    tracing::debug!("Starting process: {:?} with arguments: {:?}", program, args);
+
+    let mut command = Command::new(&program);
+
+    command
+        .args(args)
+        .envs(env)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+
+    if let Some(cwd) = cwd {
+        command.current_dir(cwd);
+    }
+
+    let mut child = command.spawn()
+        .with_context(|| format!("Unable to start process: {}", program))?;
+
+    let stdout = child.stdout.take().ok_or(anyhow!("stdout is not piped"))?;
+    let stderr = child.stderr.take().ok_or(anyhow!("stderr is not piped"))?;
+
+    let (sender, receiver) = unbounded_channel();
+    let kill = Arc::new(Notify::new());
+
+    tokio::spawn({
+        let kill = kill.clone();
+
+        async move {
+            let stdout_reader = tokio::spawn(read_chunks(stdout, sender.clone(), |text| SubprocessChunk::Stdout { text }));
+            let stderr_reader = tokio::spawn(read_chunks(stderr, sender.clone(), |text| SubprocessChunk::Stderr { text }));
+
+            let timeout = async {
+                match timeout {
+                    Some(timeout) => tokio::time::sleep(Duration::from_millis(timeout)).await,
+                    None => std::future::pending().await,
+                }
+            };
+
+            let (timed_out, killed) = tokio::select! {
+                _ = child.wait() => (false, false),
+                _ = timeout => (true, false),
+                _ = kill.notified() => (false, true),
+            };
+
+            if timed_out || killed {
+                if let Err(err) = child.start_kill() {
+                    tracing::warn!("Unable to kill process: {:?}", err);
+                }
+            }
+
+            let code = match child.wait().await {
+                Ok(status) => status.code(),
+                Err(err) => {
+                    tracing::warn!("Unable to get exit status of process: {:?}", err);
+                    None
+                }
+            };
+
+            // all output is delivered before exit status
+            let _ = stdout_reader.await;
+            let _ = stderr_reader.await;
+
+            let _ = sender.send(SubprocessChunk::Exit { code, timed_out, killed });
+        }
+    });
+
+    let id = subprocesses.next_id;
+    subprocesses.next_id += 1;
+
+    subprocesses.processes.insert(id, Subprocess {
+        output: Rc::new(tokio::sync::Mutex::new(receiver)),
+        kill,
+    });
+
+    Ok(id)
+}
+
+// returns none after exit chunk was read
+#[op2(async)]
+#[serde]
+pub async fn subprocess_read(state: Rc<RefCell<OpState>>, id: u32) -> anyhow::Result<Option<SubprocessChunk>> {
+    let subprocesses = {
+        let state = state.borrow();
+
+        state
+            .borrow::<Subprocesses>()
+            .clone()
+    };
+
+    let output = subprocesses.0.borrow()
+        .processes
+        .get(&id)
+        .map(|process| process.output.clone())
+        .ok_or(anyhow!("Unknown process: {}", id))?;
+
+    let chunk = output.lock()
+        .await
+        .recv()
+        .await;
+
+    if matches!(chunk, None | Some(SubprocessChunk::Exit { .. })) {
+        subprocesses.0.borrow_mut().processes.remove(&id);
+    }
+
+    Ok(chunk)
+}
+
+#[op2(fast)]
+pub fn subprocess_kill(state: Rc<RefCell<OpState>>, id: u32) -> anyhow::Result<()> {
+    let subprocesses = {
+        let state = state.borrow();
+
+        state
+            .borrow::<Subprocesses>()
+            .clone()
+    };
+
+    let subprocesses = subprocesses.0.borrow();
+
+    if let Some(process) = subprocesses.processes.get(&id) {
+        process.kill.notify_one();
+    }
+
+    Ok(())
+}
+
+// character cut at the end of the buffer is kept until the next read
+async fn read_chunks<R: AsyncRead + Unpin>(mut reader: R, sender: UnboundedSender<SubprocessChunk>, to_chunk: fn(String) -> SubprocessChunk) {
+    let mut buffer = vec![0; READ_BUFFER_SIZE];
+    let mut pending = vec![];
+
+    loop {
+        let read = match reader.read(&mut buffer).await {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(err) => {
+                tracing::warn!("Unable to read process output: {:?}", err);
+                break;
+            }
+        };
+
+        pending.extend_from_slice(&buffer[..read]);
+
+        let valid_up_to = match std::str::from_utf8(&pending) {
+            Ok(_) => pending.len(),
+            Err(err) if err.error_len().is_none() => err.valid_up_to(),
+            Err(_) => pending.len(),
+        };
+
+        let rest = pending.split_off(valid_up_to);
+
+        let text = String::from_utf8_lossy(&pending).to_string();
+
+        pending = rest;
+
+        if !text.is_empty() {
+            let _ = sender.send(to_chunk(text));
+        }
+    }
+
+    if !pending.is_empty() {
+        let _ = sender.send(to_chunk(String::from_utf8_lossy(&pending).to_string()));
+    }
+}
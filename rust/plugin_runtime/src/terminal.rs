@@ -0,0 +1,20 @@
+use deno_core::{op2, OpState};
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::api::{BackendForPluginRuntimeApi, BackendForPluginRuntimeApiProxy};
+
+// command is run by backend in terminal emulator configured by user
+#[op2(async)]
+pub async fn run_in_terminal(state: Rc<RefCell<OpState>>, #[serde] command: Vec<String>, #[serde] cwd: Option<String>) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state
+            .borrow::<BackendForPluginRuntimeApiProxy>()
+            .clone();
+
+        api
+    };
+
+    api.run_in_terminal(command, cwd).await
+}
@@ -9,12 +9,13 @@ use indexmap::IndexMap;
 use serde::{de, Deserialize, Deserializer, Serialize};
 use serde::de::Error;
 use tokio::runtime::Handle;
-use gauntlet_common::model::{ActionPanelSectionWidget, ActionPanelSectionWidgetOrderedMembers, ActionPanelWidget, ActionPanelWidgetOrderedMembers, ActionWidget, CheckboxWidget, CodeBlockWidget, ContentWidget, ContentWidgetOrderedMembers, DatePickerWidget, DetailWidget, EmptyViewWidget, EntrypointId, FormWidget, FormWidgetOrderedMembers, GridItemWidget, GridSectionWidget, GridSectionWidgetOrderedMembers, GridWidget, GridWidgetOrderedMembers, H1Widget, H2Widget, H3Widget, H4Widget, H5Widget, H6Widget, HorizontalBreakWidget, IconAccessoryWidget, ImageLike, ImageSource, ImageSourceAsset, ImageSourceUrl, ImageWidget, InlineSeparatorWidget, InlineWidget, InlineWidgetOrderedMembers, ListItemAccessories, ListItemWidget, ListSectionWidget, ListSectionWidgetOrderedMembers, ListWidget, ListWidgetOrderedMembers, MetadataIconWidget, MetadataLinkWidget, MetadataSeparatorWidget, MetadataTagItemWidget, MetadataTagListWidget, MetadataTagListWidgetOrderedMembers, MetadataValueWidget, MetadataWidget, MetadataWidgetOrderedMembers, ParagraphWidget, PasswordFieldWidget, PhysicalKey, PluginId, RootWidget, RootWidgetMembers, SearchBarWidget, SelectItemWidget, SelectWidget, SelectWidgetOrderedMembers, SeparatorWidget, TextAccessoryWidget, TextFieldWidget, UiPropertyValue, UiRenderLocation, UiWidgetId, WidgetVisitor};
+use gauntlet_common::model::{ActionPanelSectionWidget, ActionPanelSectionWidgetOrderedMembers, ActionPanelWidget, ActionPanelWidgetOrderedMembers, ActionWidget, CheckboxWidget, CodeBlockWidget, ContentWidget, ContentWidgetOrderedMembers, DatePickerWidget, DetailWidget, EmptyViewWidget, EntrypointId, FormWidget, FormWidgetOrderedMembers, GridItemWidget, GridSectionWidget, GridSectionWidgetOrderedMembers, GridWidget, GridWidgetOrderedMembers, H1Widget, H2Widget, H3Widget, H4Widget, H5Widget, H6Widget, HorizontalBreakWidget, IconAccessoryWidget, ImageLike, ImageSource, ImageSourceAsset, ImageSourceUrl, ImageWidget, InlineSeparatorWidget, InlineWidget, InlineWidgetOrderedMembers, ListItemAccessories, ListItemWidget, ListSectionWidget, ListSectionWidgetOrderedMembers, ListWidget, ListWidgetOrderedMembers, MetadataIconWidget, MetadataLinkWidget, MetadataSeparatorWidget, MetadataTagItemWidget, MetadataTagListWidget, MetadataTagListWidgetOrderedMembers, MetadataValueWidget, MetadataWidget, MetadataWidgetOrderedMembers, ParagraphWidget, PasswordFieldWidget, PhysicalKey, PhysicalShortcut, PluginId, RootWidget, RootWidgetMembers, SearchBarWidget, SelectItemWidget, SelectWidget, SelectWidgetOrderedMembers, SeparatorWidget, TextAccessoryWidget, TextFieldWidget, UiPropertyValue, UiRenderLocation, UiWidgetId, WidgetVisitor};
 use gauntlet_component_model::{Component, Property, PropertyType, SharedType};
 use gauntlet_component_model::Component::Root;
 use crate::api::{BackendForPluginRuntimeApi, BackendForPluginRuntimeApiProxy};
 use crate::component_model::ComponentModel;
-use crate::model::JsUiRenderLocation;
+use crate::fs::{validate_drag_files, PluginFileSystem};
+use crate::model::{JsKeyHandler, JsUiRenderLocation};
 use crate::plugin_data::PluginData;
 
 #[op2]
@@ -45,7 +46,7 @@ pub fn show_plugin_error_view(state: Rc<RefCell<OpState>>, #[string] entrypoint_
 }
 
 #[op2(fast)]
-pub fn show_preferences_required_view(state: Rc<RefCell<OpState>>, #[string] entrypoint_id: String, plugin_preferences_required: bool, entrypoint_preferences_required: bool) -> anyhow::Result<()> {
+pub fn show_onboarding_view(state: Rc<RefCell<OpState>>, #[string] entrypoint_id: String) -> anyhow::Result<()> {
     let api = {
         let state = state.borrow();
 
@@ -57,11 +58,7 @@ pub fn show_preferences_required_view(state: Rc<RefCell<OpState>>, #[string] ent
     };
 
     tokio::spawn(async move {
-        api.ui_show_preferences_required_view(
-            EntrypointId::from_string(entrypoint_id),
-            plugin_preferences_required,
-            entrypoint_preferences_required
-        ).await
+        api.ui_show_onboarding_view(EntrypointId::from_string(entrypoint_id)).await
     });
 
     Ok(())
@@ -102,6 +99,15 @@ pub fn op_entrypoint_names(state: Rc<RefCell<OpState>>) -> HashMap<String, Strin
         .clone()
 }
 
+#[op2]
+#[serde]
+pub fn op_preload_entrypoint_ids(state: Rc<RefCell<OpState>>) -> Vec<String> {
+    state.borrow()
+        .borrow::<PluginData>()
+        .preload_entrypoint_ids()
+        .clone()
+}
+
 #[op2]
 pub fn op_react_replace_view<'a>(
     scope: &mut v8::HandleScope,
@@ -110,6 +116,8 @@ pub fn op_react_replace_view<'a>(
     top_level_view: bool,
     #[string] entrypoint_id: &str,
     #[string] entrypoint_name: &str,
+    #[serde] breadcrumbs: Vec<String>,
+    #[serde] key_handlers: Vec<JsKeyHandler>,
     #[serde] container: serde_v8::Value<'a>,
 ) -> anyhow::Result<()> {
     tracing::trace!(target = "renderer_rs", "Calling op_react_replace_view...");
@@ -118,6 +126,25 @@ pub fn op_react_replace_view<'a>(
 
     let container = RootWidget::deserialize(&mut deserializer)?;
 
+    validate_drag_files(state.borrow().borrow::<PluginFileSystem>(), &container)?;
+
+    let key_handlers = key_handlers.into_iter()
+        .filter_map(|key_handler| {
+            let Some(physical_key) = PhysicalKey::try_from_value(&key_handler.key) else {
+                tracing::warn!("Unknown key in key handler registered by plugin: {}", key_handler.key);
+                return None
+            };
+
+            Some(PhysicalShortcut {
+                physical_key,
+                modifier_shift: key_handler.modifier_shift,
+                modifier_control: key_handler.modifier_control,
+                modifier_alt: key_handler.modifier_alt,
+                modifier_meta: key_handler.modifier_meta,
+            })
+        })
+        .collect();
+
     let entrypoint_id = EntrypointId::from_string(entrypoint_id);
     let entrypoint_name = entrypoint_name.to_string();
 
@@ -147,6 +174,8 @@ pub fn op_react_replace_view<'a>(
                 entrypoint_name,
                 render_location,
                 top_level_view,
+                breadcrumbs,
+                key_handlers,
                 container,
             ).await
         }).await
@@ -212,6 +241,22 @@ pub async fn show_hud(state: Rc<RefCell<OpState>>, #[string] display: String) ->
     api.ui_show_hud(display).await
 }
 
+// returns id of notification, action callbacks are called by js side when plugin receives it back
+#[op2(async)]
+pub async fn show_notification(state: Rc<RefCell<OpState>>, #[string] title: String, #[serde] body: Option<String>, #[serde] actions: Vec<String>) -> anyhow::Result<u32> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state
+            .borrow::<BackendForPluginRuntimeApiProxy>()
+            .clone();
+
+        api
+    };
+
+    api.ui_show_notification(title, body, actions).await
+}
+
 #[op2(async)]
 pub async fn hide_window(state: Rc<RefCell<OpState>>) -> anyhow::Result<()> {
     let api = {
@@ -227,6 +272,21 @@ pub async fn hide_window(state: Rc<RefCell<OpState>>) -> anyhow::Result<()> {
     api.ui_hide_window().await
 }
 
+#[op2(async)]
+pub async fn show_window_with_search_query(state: Rc<RefCell<OpState>>, #[string] query: String) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state
+            .borrow::<BackendForPluginRuntimeApiProxy>()
+            .clone();
+
+        api
+    };
+
+    api.ui_show_window_with_search_query(query).await
+}
+
 #[op2(async)]
 pub async fn update_loading_bar(state: Rc<RefCell<OpState>>, #[string] entrypoint_id: String, show: bool) -> anyhow::Result<()> {
     let api = {
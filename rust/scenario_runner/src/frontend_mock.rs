@@ -1,14 +1,20 @@
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-use gauntlet_common::model::{BackendRequestData, BackendResponseData, EntrypointId, PluginId, UiRequestData, UiResponseData};
+use anyhow::anyhow;
+
+use gauntlet_common::model::{BackendRequestData, BackendResponseData, EntrypointId, KeyboardEventOrigin, PhysicalKey, PluginId, SearchFilter, UiPropertyValue, UiRequestData, UiResponseData};
 use gauntlet_common::rpc::backend_api::{BackendApi, BackendForFrontendApi};
 use gauntlet_common::rpc::backend_server::wait_for_backend_server;
 use gauntlet_common::scenario_convert::{ui_render_location_to_scenario};
 use gauntlet_common::scenario_model::ScenarioFrontendEvent;
 use gauntlet_utils::channel::{RequestReceiver, RequestSender};
 
-use crate::model::ScenarioBackendEvent;
+use crate::model::{Scenario, ScenarioBackendEvent};
+
+const STEP_TIMEOUT: Duration = Duration::from_secs(5);
+const RENDER_SETTLE_TIMEOUT: Duration = Duration::from_millis(200);
 
 pub async fn start_scenario_runner_frontend(
     request_receiver: RequestReceiver<UiRequestData, UiResponseData>,
@@ -43,10 +49,18 @@ pub async fn start_scenario_runner_frontend(
     fs::create_dir_all(&scenario_out_dir)
         .expect("unable to create scenario_out_dir");
 
+    // instead of overwriting snapshots, compare produced views with them, used in ci
+    let check_mode = std::env::var("GAUNTLET_SCENARIO_CHECK").is_ok();
+
+    let mut failed_scenarios = vec![];
+
     let (sender, mut receiver) = tokio::sync::mpsc::channel(1);
 
+    let mut backend_for_frontend_client = BackendForFrontendApi::new(backend_sender);
+
+    let images_client = backend_for_frontend_client.clone();
     tokio::spawn(async move {
-        request_loop(request_receiver, sender).await
+        request_loop(request_receiver, images_client, sender).await
     });
 
     println!("waiting for backend");
@@ -55,7 +69,6 @@ pub async fn start_scenario_runner_frontend(
 
     println!("backend started");
 
-    let mut backend_for_frontend_client = BackendForFrontendApi::new(backend_sender);
     let mut backend_client = BackendApi::new().await?;
 
     println!("saving local plugin");
@@ -92,71 +105,159 @@ pub async fn start_scenario_runner_frontend(
             let scenario_data = fs::read(&scenario_path)
                 .expect("unable to read scenario scenario from file");
 
-            let event: ScenarioBackendEvent = serde_json::from_slice(&scenario_data)
+            let scenario: Scenario = serde_json::from_slice(&scenario_data)
                 .expect("unable to deserialize scenario event");
 
-            match event {
-                ScenarioBackendEvent::Search { text } => {
-                    backend_for_frontend_client.search(text, true).await?;
+            let plugin_id = PluginId::from_string(format!("file://{scenario_plugin_dir}"));
+            let entrypoint_id = EntrypointId::from_string(&entrypoint_name);
+
+            let mut last_event = None;
+
+            for step in scenario.into_steps() {
+                match step {
+                    ScenarioBackendEvent::Search { text } => {
+                        backend_for_frontend_client.search(text, SearchFilter::default(), true).await?;
+                    }
+                    ScenarioBackendEvent::RequestViewRender => {
+                        backend_for_frontend_client.request_view_render(plugin_id.clone(), entrypoint_id.clone()).await?;
+                    }
+                    ScenarioBackendEvent::RequestViewPop => {
+                        backend_for_frontend_client.request_view_pop(plugin_id.clone()).await?;
+                    }
+                    ScenarioBackendEvent::ViewEvent { widget_id, event_name, event_arguments } => {
+                        let event_arguments = event_arguments.into_iter()
+                            .map(|value| json_to_property_value(value))
+                            .collect();
+
+                        backend_for_frontend_client.send_view_event(plugin_id.clone(), widget_id, event_name, event_arguments).await?;
+                    }
+                    ScenarioBackendEvent::KeyboardEvent { key, modifier_shift, modifier_control, modifier_alt, modifier_meta } => {
+                        backend_for_frontend_client.send_keyboard_event(
+                            plugin_id.clone(),
+                            entrypoint_id.clone(),
+                            KeyboardEventOrigin::PluginView,
+                            PhysicalKey::from_value(key),
+                            modifier_shift,
+                            modifier_control,
+                            modifier_alt,
+                            modifier_meta,
+                        ).await?;
+                    }
                 }
-                ScenarioBackendEvent::RequestViewRender => {
-                    let plugin_id = PluginId::from_string(format!("file://{scenario_plugin_dir}"));
-                    let entrypoint_id = EntrypointId::from_string(&entrypoint_name);
 
-                    backend_for_frontend_client.request_view_render(plugin_id, entrypoint_id).await?;
+                println!("waiting for scenario step to finish");
+
+                // not every event results in the view being re-rendered, in that case the last received view is kept,
+                // if view is re-rendered more than once, the last render is used
+                let mut timeout = STEP_TIMEOUT;
+                while let Ok(Some(event)) = tokio::time::timeout(timeout, receiver.recv()).await {
+                    last_event = Some(event);
+                    timeout = RENDER_SETTLE_TIMEOUT;
                 }
             }
 
-            println!("waiting for scenario to finish");
+            let Some(event) = last_event else {
+                panic!("scenario {:?} didn't produce any view", &scenario_path)
+            };
 
-            match receiver.recv().await {
-                None => unreachable!(),
-                Some(event) => save_event(&scenario_out_dir, scenario_name, event)
+            if check_mode {
+                if let Err(err) = check_event(&scenario_out_dir, &scenario_name, event) {
+                    println!("scenario failed: {}", err);
+                    failed_scenarios.push(format!("{}/{}: {}", &entrypoint_name, &scenario_name, err));
+                }
+            } else {
+                save_event(&scenario_out_dir, scenario_name, event);
             }
 
             println!("scenario finished");
         }
     }
 
+    if !failed_scenarios.is_empty() {
+        println!("{} scenario(s) don't match saved snapshots:", failed_scenarios.len());
+        for failed_scenario in failed_scenarios {
+            println!("  {}", failed_scenario);
+        }
+
+        std::process::exit(1)
+    }
+
     println!("all scenarios done");
 
     std::process::exit(0)
 }
 
 fn save_event(scenario_out_dir: &Path, scenario_name: String, event: ScenarioFrontendEvent) {
+    let (out_path, json) = event_snapshot(scenario_out_dir, &scenario_name, event);
+
+    let out_dir = out_path.parent()
+        .expect("snapshot path always has parent");
+
+    fs::create_dir_all(&out_dir)
+        .expect("Unable to create scenario out dir");
+
+    fs::write(&out_path, json)
+        .expect("unable to write scenario event to file");
+}
+
+fn check_event(scenario_out_dir: &Path, scenario_name: &str, event: ScenarioFrontendEvent) -> anyhow::Result<()> {
+    let (out_path, json) = event_snapshot(scenario_out_dir, scenario_name, event);
+
+    let expected = fs::read_to_string(&out_path)
+        .map_err(|err| anyhow!("unable to read snapshot {:?}: {}", &out_path, err))?;
+
+    if expected != json {
+        return Err(anyhow!("view differs from snapshot {:?}", &out_path))
+    }
+
+    Ok(())
+}
+
+fn event_snapshot(scenario_out_dir: &Path, scenario_name: &str, event: ScenarioFrontendEvent) -> (PathBuf, String) {
     let json = serde_json::to_string_pretty(&event)
         .expect("unable to serialize scenario event");
 
     let entrypoint_id = match event {
         ScenarioFrontendEvent::ReplaceView { entrypoint_id, .. } => entrypoint_id,
-        ScenarioFrontendEvent::ShowPreferenceRequiredView { entrypoint_id, .. } => entrypoint_id,
+        ScenarioFrontendEvent::ShowOnboardingView { entrypoint_id, .. } => entrypoint_id,
         ScenarioFrontendEvent::ShowPluginErrorView { entrypoint_id, .. } => entrypoint_id,
     };
 
-    let out_dir = Path::new(scenario_out_dir)
-        .join(entrypoint_id);
-
-    fs::create_dir_all(&out_dir)
-        .expect("Unable to create scenario out dir");
-
-    let out_path = out_dir
+    let out_path = scenario_out_dir
+        .join(entrypoint_id)
         .join(format!("{}.json", scenario_name));
 
-    fs::write(&out_path, json)
-        .expect("unable to write scenario event to file");
+    (out_path, json)
+}
+
+fn json_to_property_value(value: serde_json::Value) -> UiPropertyValue {
+    match value {
+        serde_json::Value::Null => UiPropertyValue::Undefined,
+        serde_json::Value::Bool(value) => UiPropertyValue::Bool(value),
+        serde_json::Value::Number(value) => UiPropertyValue::Number(value.as_f64().expect("number should fit into f64")),
+        serde_json::Value::String(value) => UiPropertyValue::String(value),
+        serde_json::Value::Array(value) => UiPropertyValue::Array(value.into_iter().map(|value| json_to_property_value(value)).collect()),
+        serde_json::Value::Object(value) => UiPropertyValue::Object(value.into_iter().map(|(key, value)| (key, json_to_property_value(value))).collect()),
+    }
 }
 
-async fn request_loop(mut request_receiver: RequestReceiver<UiRequestData, UiResponseData>, scenario_sender: tokio::sync::mpsc::Sender<ScenarioFrontendEvent>) {
+async fn request_loop(mut request_receiver: RequestReceiver<UiRequestData, UiResponseData>, backend_api: BackendForFrontendApi, scenario_sender: tokio::sync::mpsc::Sender<ScenarioFrontendEvent>) {
     loop {
         let (request_data, responder) = request_receiver.recv().await;
 
         match request_data {
-            UiRequestData::UpdateLoadingBar { .. } | UiRequestData::ShowHud { .. } | UiRequestData::ShowWindow | UiRequestData::HideWindow | UiRequestData::ClearInlineView { .. } | UiRequestData::SetTheme { .. } => {
+            UiRequestData::ShowWindow | UiRequestData::ToggleWindow | UiRequestData::ShowSettingsWindow { .. } | UiRequestData::OpenPluginView { .. } | UiRequestData::ShowWindowWithSearchQuery { .. } | UiRequestData::ConfirmDeepLink { .. } | UiRequestData::SetTheme { .. } => {
                 unreachable!()
             }
             UiRequestData::SetGlobalShortcut { .. } | UiRequestData::RequestSearchResultUpdate => {
                 // noop
             }
+            UiRequestData::SetWindowPositionMode { .. } | UiRequestData::SetWindowOverlay { .. } | UiRequestData::SetDensity { .. } | UiRequestData::SetUiScale { .. } | UiRequestData::SetSearchResultsSort { .. } | UiRequestData::SetTrayTooltip { .. } => {
+                // noop
+            }
+            UiRequestData::UpdateLoadingBar { .. } | UiRequestData::ShowHud { .. } | UiRequestData::HideWindow | UiRequestData::ClearInlineView { .. } => {
+                // can be caused by view events sent by scenario, do not affect snapshot
+            }
             UiRequestData::ReplaceView {
                 plugin_id: _,
                 plugin_name: _,
@@ -164,9 +265,19 @@ async fn request_loop(mut request_receiver: RequestReceiver<UiRequestData, UiRes
                 entrypoint_name: _,
                 render_location,
                 top_level_view,
+                breadcrumbs: _,
+                key_handlers: _,
                 container,
                 images
             } => {
+                let mut image_data = backend_api.images(images.values().cloned().collect())
+                    .await
+                    .expect("unable to get images");
+
+                let images = images.into_iter()
+                    .map(|(widget_id, hash)| (widget_id, image_data.remove(&hash).expect("image is missing")))
+                    .collect();
+
                 let event = ScenarioFrontendEvent::ReplaceView {
                     entrypoint_id: entrypoint_id.to_string(),
                     render_location: ui_render_location_to_scenario(render_location),
@@ -189,11 +300,16 @@ async fn request_loop(mut request_receiver: RequestReceiver<UiRequestData, UiRes
                     .await
                     .expect("send failed")
             }
-            UiRequestData::ShowPreferenceRequiredView { plugin_id: _, entrypoint_id, plugin_preferences_required, entrypoint_preferences_required } => {
-                let event = ScenarioFrontendEvent::ShowPreferenceRequiredView {
+            UiRequestData::ShowOnboardingView { plugin_id: _, plugin_name: _, entrypoint_id, description, preferences } => {
+                let missing_preferences = preferences.into_iter()
+                    .filter(|preference| !preference.filled)
+                    .map(|preference| preference.name)
+                    .collect();
+
+                let event = ScenarioFrontendEvent::ShowOnboardingView {
                     entrypoint_id: entrypoint_id.to_string(),
-                    plugin_preferences_required,
-                    entrypoint_preferences_required,
+                    description,
+                    missing_preferences,
                 };
 
                 scenario_sender.send(event)
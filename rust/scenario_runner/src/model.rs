@@ -1,5 +1,23 @@
 use serde::{Deserialize, Serialize};
 
+// scenario file contains either single event or list of events which are sent one after another,
+// e.g. render of the view followed by events produced by user interacting with it
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum Scenario {
+    Steps(Vec<ScenarioBackendEvent>),
+    Single(ScenarioBackendEvent),
+}
+
+impl Scenario {
+    pub fn into_steps(self) -> Vec<ScenarioBackendEvent> {
+        match self {
+            Scenario::Steps(steps) => steps,
+            Scenario::Single(event) => vec![event],
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(tag = "type")]
 pub enum ScenarioBackendEvent {
@@ -7,4 +25,24 @@ pub enum ScenarioBackendEvent {
         text: String
     },
     RequestViewRender,
+    RequestViewPop,
+    // widget_id is "__id__" of widget in previously saved snapshot
+    ViewEvent {
+        widget_id: usize,
+        event_name: String,
+        #[serde(default)]
+        event_arguments: Vec<serde_json::Value>,
+    },
+    // key uses the same names as keyboard shortcuts in plugin manifest, e.g. "KeyA" or "Enter"
+    KeyboardEvent {
+        key: String,
+        #[serde(default)]
+        modifier_shift: bool,
+        #[serde(default)]
+        modifier_control: bool,
+        #[serde(default)]
+        modifier_alt: bool,
+        #[serde(default)]
+        modifier_meta: bool,
+    },
 }
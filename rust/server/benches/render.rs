@@ -0,0 +1,42 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+use gauntlet_server::bench::{bench_list_view, convert_widget_tree, ViewRenderBench};
+
+fn widget_tree_conversion(c: &mut Criterion) {
+    let mut group = c.benchmark_group("widget_tree_conversion");
+
+    for items in [10, 100, 1000] {
+        let view = bench_list_view(items);
+
+        group.throughput(Throughput::Elements(items as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(items), &view, |b, view| {
+            b.iter(|| convert_widget_tree(black_box(view)).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+fn view_render(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let bench = runtime.block_on(async { ViewRenderBench::new() });
+
+    let mut group = c.benchmark_group("view_render_round_trip");
+
+    for items in [10, 100, 1000] {
+        let view = bench_list_view(items);
+
+        group.bench_with_input(BenchmarkId::from_parameter(items), &view, |b, view| {
+            b.to_async(&runtime).iter(|| bench.render(black_box(view)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, widget_tree_conversion, view_render);
+criterion_main!(benches);
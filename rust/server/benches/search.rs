@@ -0,0 +1,18 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use gauntlet_server::bench::SearchBench;
+
+fn search(c: &mut Criterion) {
+    let bench = SearchBench::new(10_000).expect("unable to create search index");
+
+    let mut group = c.benchmark_group("search_10k_entrypoints");
+
+    for query in ["e", "entry", "entrypoint 42", "plugin 7 entrypoint"] {
+        group.bench_function(query, |b| b.iter(|| bench.search(black_box(query)).unwrap()));
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, search);
+criterion_main!(benches);
@@ -16,5 +16,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .add_instructions(&cargo)?
         .emit()?;
 
+    #[cfg(feature = "release")]
+    {
+        let out_dir = std::path::PathBuf::from(std::env::var("OUT_DIR")?);
+
+        gauntlet_plugin_runtime::create_runtime_snapshot(out_dir.join("RUNTIME_SNAPSHOT.bin"));
+    }
+
     Ok(())
 }
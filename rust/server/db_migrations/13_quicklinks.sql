@@ -0,0 +1,8 @@
+CREATE TABLE quicklink
+(
+    id   TEXT NOT NULL,
+    name TEXT NOT NULL,
+    url  TEXT NOT NULL,
+
+    PRIMARY KEY (id)
+);
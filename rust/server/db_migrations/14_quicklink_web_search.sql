@@ -0,0 +1,7 @@
+ALTER TABLE quicklink ADD COLUMN bang TEXT DEFAULT NULL;
+ALTER TABLE quicklink ADD COLUMN fallback BOOLEAN NOT NULL DEFAULT FALSE;
+
+INSERT INTO quicklink (id, name, url, bang, fallback)
+    VALUES ('google', 'Google', 'https://www.google.com/search?q={query}', 'g', TRUE),
+           ('wikipedia', 'Wikipedia', 'https://en.wikipedia.org/w/index.php?search={query}', 'w', FALSE),
+           ('youtube', 'YouTube', 'https://www.youtube.com/results?search_query={query}', 'yt', FALSE);
@@ -0,0 +1 @@
+ALTER TABLE plugin_entrypoint ADD COLUMN arguments JSON NOT NULL DEFAULT ('[]');
@@ -0,0 +1,3 @@
+ALTER TABLE plugin_asset_data ADD COLUMN hash TEXT;
+
+CREATE INDEX plugin_asset_data_hash_idx ON plugin_asset_data (hash);
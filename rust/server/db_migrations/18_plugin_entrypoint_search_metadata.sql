@@ -0,0 +1,3 @@
+ALTER TABLE plugin_entrypoint ADD COLUMN subtitle TEXT;
+ALTER TABLE plugin_entrypoint ADD COLUMN keywords JSON NOT NULL DEFAULT ('[]');
+ALTER TABLE plugin_entrypoint ADD COLUMN accessory TEXT;
@@ -0,0 +1,2 @@
+ALTER TABLE plugin ADD COLUMN onboarding TEXT;
+ALTER TABLE plugin ADD COLUMN onboarding_completed BOOLEAN NOT NULL DEFAULT FALSE;
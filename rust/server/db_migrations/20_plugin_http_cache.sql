@@ -0,0 +1 @@
+ALTER TABLE plugin ADD COLUMN http_cache TEXT;
@@ -0,0 +1,7 @@
+CREATE TABLE settings_profile
+(
+    name TEXT NOT NULL,
+    data JSON NOT NULL,
+
+    PRIMARY KEY (name)
+);
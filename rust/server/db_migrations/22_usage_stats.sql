@@ -0,0 +1,12 @@
+CREATE TABLE usage_stats
+(
+    entrypoint_id                  TEXT    NOT NULL,
+    plugin_id                      TEXT    NOT NULL,
+
+    launch_count                   INTEGER NOT NULL,
+    last_launched                  INTEGER NOT NULL,
+    search_to_launch_count         INTEGER NOT NULL,
+    search_to_launch_total_micros  INTEGER NOT NULL,
+
+    PRIMARY KEY (entrypoint_id, plugin_id)
+);
@@ -0,0 +1,2 @@
+ALTER TABLE plugin_entrypoint ADD COLUMN hidden BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE plugin_entrypoint ADD COLUMN position INTEGER;
@@ -0,0 +1 @@
+ALTER TABLE plugin ADD COLUMN priority REAL NOT NULL DEFAULT 1.0;
@@ -0,0 +1,10 @@
+CREATE TABLE timer
+(
+    id         TEXT    NOT NULL,
+    kind       TEXT    NOT NULL,
+    name       TEXT    NOT NULL,
+    started_at INTEGER NOT NULL,
+    fires_at   INTEGER,
+
+    PRIMARY KEY (id)
+);
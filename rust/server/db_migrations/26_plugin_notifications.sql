@@ -0,0 +1 @@
+ALTER TABLE plugin ADD COLUMN notifications_enabled BOOLEAN NOT NULL DEFAULT TRUE;
@@ -0,0 +1 @@
+ALTER TABLE plugin ADD COLUMN environment TEXT NOT NULL DEFAULT '{}';
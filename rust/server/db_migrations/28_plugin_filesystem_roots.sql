@@ -0,0 +1 @@
+ALTER TABLE plugin ADD COLUMN filesystem_roots TEXT NOT NULL DEFAULT '[]';
@@ -0,0 +1,257 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+
+use gauntlet_common::model::{EntrypointId, PluginId, RootWidget, SearchFilter, SearchResultEntrypointType, SearchResultsSort, UiRenderLocation, UiRequestData, UiResponseData};
+use gauntlet_common::rpc::frontend_api::FrontendApi;
+use gauntlet_utils::channel::channel;
+
+use crate::search::{SearchIndex, SearchIndexItem};
+
+const BENCH_ENTRYPOINTS: usize = 10_000;
+const BENCH_PLUGINS: usize = 100;
+const BENCH_LIST_ITEMS: usize = 500;
+const BENCH_ITERATIONS: usize = 200;
+
+const BENCH_QUERIES: [&str; 4] = ["e", "entry", "entrypoint 42", "plugin 7 entrypoint"];
+
+// search index filled with generated entrypoints, evenly split between plugins
+pub struct SearchBench {
+    search_index: SearchIndex,
+    // index file is removed together with directory when bench is dropped
+    _dir: TempDir,
+}
+
+impl SearchBench {
+    pub fn new(entrypoints: usize) -> anyhow::Result<Self> {
+        let dir = tempfile::tempdir()?;
+
+        // search results update is never requested, so frontend side of the channel is not needed
+        let (frontend_sender, _frontend_receiver) = channel::<UiRequestData, UiResponseData>();
+
+        let search_index = SearchIndex::create_index(FrontendApi::new(frontend_sender), dir.path().join("search_index"))?;
+
+        let per_plugin = entrypoints.div_ceil(BENCH_PLUGINS);
+
+        for plugin_index in 0..BENCH_PLUGINS {
+            let search_items = (0..per_plugin)
+                .map(|entrypoint_index| plugin_index * per_plugin + entrypoint_index)
+                .take_while(|index| *index < entrypoints)
+                .map(|index| bench_search_item(index))
+                .collect();
+
+            search_index.save_for_plugin(
+                PluginId::from_string(format!("bench://plugin-{}", plugin_index)),
+                format!("Plugin {}", plugin_index),
+                search_items,
+                false
+            )?;
+        }
+
+        Ok(Self {
+            search_index,
+            _dir: dir,
+        })
+    }
+
+    pub fn search(&self, query: &str) -> anyhow::Result<usize> {
+        let results = self.search_index.search(query, &SearchFilter::default(), &SearchResultsSort::Relevance)?;
+
+        Ok(results.len())
+    }
+}
+
+fn bench_search_item(index: usize) -> SearchIndexItem {
+    SearchIndexItem {
+        entrypoint_type: SearchResultEntrypointType::Command,
+        entrypoint_name: format!("Entrypoint {}", index),
+        entrypoint_subtitle: None,
+        entrypoint_keywords: vec![format!("keyword{}", index % 50)],
+        entrypoint_generator_name: None,
+        entrypoint_id: EntrypointId::from_string(format!("entrypoint-{}", index)),
+        entrypoint_icon: None,
+        entrypoint_frecency: 0.0,
+        entrypoint_actions: vec![],
+        entrypoint_accessories: vec![],
+        entrypoint_arguments: vec![],
+        entrypoint_hidden: false,
+        entrypoint_preview: None,
+        entrypoint_file: None,
+    }
+}
+
+// list view in the same shape as the one produced by react reconciler in plugin runtime
+pub fn bench_list_view(items: usize) -> Value {
+    let items: Vec<_> = (0..items)
+        .map(|index| {
+            json!({
+                "__type__": "gauntlet:list_item",
+                "__id__": index + 1,
+                "id": format!("item-{}", index),
+                "title": format!("List Item {}", index),
+                "subtitle": format!("Subtitle {}", index),
+                "content": []
+            })
+        })
+        .collect();
+
+    json!({
+        "content": [
+            {
+                "__type__": "gauntlet:list",
+                "__id__": 0,
+                "content": items
+            }
+        ]
+    })
+}
+
+// same steps widget tree goes through after it is created by plugin,
+// conversion from js value, which uses the same deserialize implementation as serde_json,
+// and transfer from plugin runtime process to server
+pub fn convert_widget_tree(view: &Value) -> anyhow::Result<RootWidget> {
+    let container = RootWidget::deserialize(view)?;
+
+    let encoded = bincode::encode_to_vec(&container, bincode::config::standard())?;
+
+    let (container, _): (RootWidget, _) = bincode::decode_from_slice(&encoded, bincode::config::standard())?;
+
+    Ok(container)
+}
+
+// widget tree conversion followed by request to frontend which responds as soon as it receives view,
+// real frontend is replaced by a task, so only server side of view rendering is measured
+pub struct ViewRenderBench {
+    frontend_api: FrontendApi,
+}
+
+impl ViewRenderBench {
+    pub fn new() -> Self {
+        let (frontend_sender, mut frontend_receiver) = channel::<UiRequestData, UiResponseData>();
+
+        tokio::spawn(async move {
+            while let Some((_request, responder)) = frontend_receiver.recv_checked().await {
+                responder.respond(UiResponseData::Nothing);
+            }
+        });
+
+        Self {
+            frontend_api: FrontendApi::new(frontend_sender),
+        }
+    }
+
+    pub async fn render(&self, view: &Value) -> anyhow::Result<()> {
+        let container = convert_widget_tree(view)?;
+
+        self.frontend_api.replace_view(
+            PluginId::from_string("bench://plugin"),
+            "Bench".to_string(),
+            EntrypointId::from_string("bench"),
+            "Bench".to_string(),
+            UiRenderLocation::View,
+            true,
+            vec![],
+            vec![],
+            container,
+            HashMap::new(),
+        ).await?;
+
+        Ok(())
+    }
+}
+
+pub fn run_benchmarks() {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("unable to start bench tokio runtime");
+
+    if let Err(err) = runtime.block_on(run_benchmarks_async()) {
+        eprintln!("Benchmark failed: {:#}", err);
+        std::process::exit(1)
+    }
+}
+
+async fn run_benchmarks_async() -> anyhow::Result<()> {
+    println!("Creating search index with {} entrypoints...", BENCH_ENTRYPOINTS);
+
+    let start = Instant::now();
+    let search_bench = SearchBench::new(BENCH_ENTRYPOINTS)?;
+    println!("Search index created in {:.1} ms", millis(start.elapsed()));
+    println!();
+
+    for query in BENCH_QUERIES {
+        let timings = measure(|| {
+            search_bench.search(query)?;
+
+            Ok(())
+        })?;
+
+        print_timings(&format!("search \"{}\"", query), &timings);
+    }
+
+    let view = bench_list_view(BENCH_LIST_ITEMS);
+
+    let timings = measure(|| {
+        convert_widget_tree(&view)?;
+
+        Ok(())
+    })?;
+
+    print_timings(&format!("widget tree conversion, {} list items", BENCH_LIST_ITEMS), &timings);
+
+    let view_render_bench = ViewRenderBench::new();
+
+    let mut timings = Vec::with_capacity(BENCH_ITERATIONS);
+
+    for _ in 0..BENCH_ITERATIONS {
+        let start = Instant::now();
+
+        view_render_bench.render(&view).await?;
+
+        timings.push(start.elapsed());
+    }
+
+    print_timings(&format!("view render round trip, {} list items", BENCH_LIST_ITEMS), &timings);
+
+    Ok(())
+}
+
+fn measure(mut f: impl FnMut() -> anyhow::Result<()>) -> anyhow::Result<Vec<Duration>> {
+    let mut timings = Vec::with_capacity(BENCH_ITERATIONS);
+
+    for _ in 0..BENCH_ITERATIONS {
+        let start = Instant::now();
+
+        f()?;
+
+        timings.push(start.elapsed());
+    }
+
+    Ok(timings)
+}
+
+fn print_timings(name: &str, timings: &[Duration]) {
+    let mut timings = timings.to_vec();
+    timings.sort();
+
+    let total: Duration = timings.iter().sum();
+    let average = total / timings.len() as u32;
+    let percentile = |percentile: usize| timings[(timings.len() - 1) * percentile / 100];
+
+    println!(
+        "{:<50} avg {:>8.3} ms | p50 {:>8.3} ms | p95 {:>8.3} ms | max {:>8.3} ms",
+        name,
+        millis(average),
+        millis(percentile(50)),
+        millis(percentile(95)),
+        millis(percentile(100)),
+    );
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
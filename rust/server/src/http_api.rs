@@ -0,0 +1,383 @@
+use std::sync::Arc;
+
+use anyhow::Context;
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Query, Request, State};
+use axum::http::{header, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{middleware, Json, Router};
+use serde::{Deserialize, Serialize};
+use tokio::net::TcpListener;
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+
+use gauntlet_common::model::{EntrypointId, PluginId, SearchFilter, SearchResult, SearchResultEntrypointType};
+
+use crate::plugins::browser_bridge::{BrowserItem, BrowserItemKind};
+use crate::plugins::ApplicationManager;
+
+// optional localhost api for external tools which are not able to speak grpc,
+// e.g. browser extensions, scripts or stream decks.
+// every request needs to contain token, either in "Authorization: Bearer <token>" header
+// or in "token" query parameter, because browsers are not able to set headers when opening websocket
+#[derive(Clone)]
+struct HttpApiState {
+    application_manager: Arc<ApplicationManager>,
+    token: Arc<str>,
+    notifications: broadcast::Sender<HttpApiNotification>,
+}
+
+#[derive(Debug, Deserialize)]
+struct HttpApiSearchParams {
+    query: String,
+}
+
+#[derive(Debug, Serialize)]
+struct HttpApiSearchResult {
+    plugin_id: String,
+    plugin_name: String,
+    entrypoint_id: String,
+    entrypoint_name: String,
+    entrypoint_subtitle: Option<String>,
+    entrypoint_type: &'static str,
+}
+
+#[derive(Debug, Deserialize)]
+struct HttpApiRunRequest {
+    plugin_id: String,
+    entrypoint_id: String,
+    #[serde(default)]
+    arguments: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct HttpApiNotification {
+    text: String,
+}
+
+// responses are sent in the same order requests were received
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum HttpApiWebSocketRequest {
+    Search {
+        query: String,
+    },
+    Run {
+        plugin_id: String,
+        entrypoint_id: String,
+        #[serde(default)]
+        arguments: Vec<String>,
+    },
+    Notify {
+        text: String,
+    },
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum HttpApiWebSocketMessage {
+    SearchResults {
+        results: Vec<HttpApiSearchResult>,
+    },
+    Done,
+    Error {
+        message: String,
+    },
+    Notification {
+        text: String,
+    },
+}
+
+// companion browser extension keeps this websocket open for as long as browser is running
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum HttpApiBrowserRequest {
+    Items {
+        kind: BrowserItemKind,
+        items: Vec<BrowserItem>,
+    },
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum HttpApiBrowserMessage {
+    Activate {
+        kind: BrowserItemKind,
+        id: String,
+        url: String,
+    },
+}
+
+struct HttpApiError(anyhow::Error);
+
+impl From<anyhow::Error> for HttpApiError {
+    fn from(error: anyhow::Error) -> Self {
+        Self(error)
+    }
+}
+
+impl IntoResponse for HttpApiError {
+    fn into_response(self) -> Response {
+        (StatusCode::INTERNAL_SERVER_ERROR, format!("{:#}", self.0)).into_response()
+    }
+}
+
+pub fn start_http_api(application_manager: Arc<ApplicationManager>) {
+    let Some(port) = application_manager.http_api_port() else {
+        return;
+    };
+
+    let token = match application_manager.http_api_token() {
+        Ok(token) => token,
+        Err(err) => {
+            tracing::error!("Unable to read http api token: {:?}", err);
+            return;
+        }
+    };
+
+    tokio::spawn(async move {
+        if let Err(err) = serve_http_api(application_manager, port, token).await {
+            tracing::error!("Http api has been stopped: {:?}", err);
+        }
+    });
+}
+
+async fn serve_http_api(application_manager: Arc<ApplicationManager>, port: u16, token: String) -> anyhow::Result<()> {
+    let (notifications, _) = broadcast::channel(100);
+
+    let state = HttpApiState {
+        application_manager,
+        token: token.into(),
+        notifications,
+    };
+
+    let router = Router::new()
+        .route("/search", get(search))
+        .route("/run", post(run))
+        .route("/notify", post(notify))
+        .route("/ws", get(websocket))
+        .route("/browser", get(browser_websocket))
+        .route_layer(middleware::from_fn_with_state(state.clone(), authorize))
+        .with_state(state);
+
+    let listener = TcpListener::bind(("127.0.0.1", port))
+        .await
+        .context("Unable to bind http api")?;
+
+    tracing::info!("Serving http api on http://127.0.0.1:{}", port);
+
+    axum::serve(listener, router)
+        .await
+        .context("Unable to serve http api")?;
+
+    Ok(())
+}
+
+async fn authorize(State(state): State<HttpApiState>, request: Request, next: Next) -> Response {
+    let header_token = request.headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    let query_token = request.uri()
+        .query()
+        .and_then(|query| query.split('&').find_map(|pair| pair.strip_prefix("token=")));
+
+    match header_token.or(query_token) {
+        Some(token) if token_matches(token, &state.token) => next.run(request).await,
+        _ => StatusCode::UNAUTHORIZED.into_response()
+    }
+}
+
+// compare whole token to not leak how many characters matched through timing
+fn token_matches(token: &str, expected: &str) -> bool {
+    token.len() == expected.len() && token.bytes().zip(expected.bytes()).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+async fn search(State(state): State<HttpApiState>, Query(params): Query<HttpApiSearchParams>) -> Result<Json<Vec<HttpApiSearchResult>>, HttpApiError> {
+    let results = search_results(&state, &params.query).await?;
+
+    Ok(Json(results))
+}
+
+async fn run(State(state): State<HttpApiState>, Json(request): Json<HttpApiRunRequest>) -> Result<StatusCode, HttpApiError> {
+    run_entrypoint(&state, request.plugin_id, request.entrypoint_id, request.arguments).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn notify(State(state): State<HttpApiState>, Json(notification): Json<HttpApiNotification>) -> Result<StatusCode, HttpApiError> {
+    push_notification(&state, notification.text).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn websocket(State(state): State<HttpApiState>, upgrade: WebSocketUpgrade) -> Response {
+    upgrade.on_upgrade(|socket| handle_websocket(socket, state))
+}
+
+async fn handle_websocket(mut socket: WebSocket, state: HttpApiState) {
+    let mut notifications = state.notifications.subscribe();
+
+    loop {
+        let message = tokio::select! {
+            message = socket.recv() => {
+                let request = match message {
+                    Some(Ok(Message::Text(text))) => text,
+                    Some(Ok(Message::Close(_))) | None => break,
+                    Some(Ok(_)) => continue,
+                    Some(Err(err)) => {
+                        tracing::debug!("http api websocket error: {:?}", err);
+                        break
+                    }
+                };
+
+                match serde_json::from_str::<HttpApiWebSocketRequest>(&request) {
+                    Ok(request) => handle_websocket_request(&state, request).await,
+                    Err(err) => HttpApiWebSocketMessage::Error { message: err.to_string() },
+                }
+            }
+            notification = notifications.recv() => {
+                match notification {
+                    Ok(notification) => HttpApiWebSocketMessage::Notification { text: notification.text },
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        };
+
+        let message = serde_json::to_string(&message)
+            .expect("websocket message should always be serializable");
+
+        if socket.send(Message::Text(message)).await.is_err() {
+            break
+        }
+    }
+}
+
+async fn browser_websocket(State(state): State<HttpApiState>, upgrade: WebSocketUpgrade) -> Response {
+    upgrade.on_upgrade(|socket| handle_browser_websocket(socket, state))
+}
+
+async fn handle_browser_websocket(mut socket: WebSocket, state: HttpApiState) {
+    let browser_bridge = state.application_manager.browser_bridge();
+
+    let connection = browser_bridge.connect();
+
+    let mut activations = browser_bridge.subscribe();
+
+    loop {
+        tokio::select! {
+            message = socket.recv() => {
+                let request = match message {
+                    Some(Ok(Message::Text(text))) => text,
+                    Some(Ok(Message::Close(_))) | None => break,
+                    Some(Ok(_)) => continue,
+                    Some(Err(err)) => {
+                        tracing::debug!("browser websocket error: {:?}", err);
+                        break
+                    }
+                };
+
+                match serde_json::from_str::<HttpApiBrowserRequest>(&request) {
+                    Ok(HttpApiBrowserRequest::Items { kind, items }) => browser_bridge.set_items(connection, kind, items),
+                    Err(err) => tracing::warn!("invalid message received from browser extension: {:?}", err),
+                }
+            }
+            activation = activations.recv() => {
+                let activation = match activation {
+                    Ok(activation) => activation,
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                };
+
+                // item belongs to other connected browser
+                if activation.connection != connection {
+                    continue
+                }
+
+                let message = HttpApiBrowserMessage::Activate {
+                    kind: activation.kind,
+                    id: activation.id,
+                    url: activation.url,
+                };
+
+                let message = serde_json::to_string(&message)
+                    .expect("browser message should always be serializable");
+
+                if socket.send(Message::Text(message)).await.is_err() {
+                    break
+                }
+            }
+        }
+    }
+
+    browser_bridge.disconnect(connection);
+}
+
+async fn handle_websocket_request(state: &HttpApiState, request: HttpApiWebSocketRequest) -> HttpApiWebSocketMessage {
+    let result = match request {
+        HttpApiWebSocketRequest::Search { query } => {
+            search_results(state, &query)
+                .await
+                .map(|results| HttpApiWebSocketMessage::SearchResults { results })
+        }
+        HttpApiWebSocketRequest::Run { plugin_id, entrypoint_id, arguments } => {
+            run_entrypoint(state, plugin_id, entrypoint_id, arguments)
+                .await
+                .map(|_| HttpApiWebSocketMessage::Done)
+        }
+        HttpApiWebSocketRequest::Notify { text } => {
+            push_notification(state, text)
+                .await
+                .map(|_| HttpApiWebSocketMessage::Done)
+        }
+    };
+
+    result.unwrap_or_else(|err| HttpApiWebSocketMessage::Error { message: format!("{:#}", err) })
+}
+
+async fn search_results(state: &HttpApiState, query: &str) -> anyhow::Result<Vec<HttpApiSearchResult>> {
+    let (results, _, _) = state.application_manager.search(query, &SearchFilter::default(), false)
+        .await?;
+
+    let results = results.into_iter()
+        .map(|result| search_result_to_http_api(result))
+        .collect();
+
+    Ok(results)
+}
+
+async fn run_entrypoint(state: &HttpApiState, plugin_id: String, entrypoint_id: String, arguments: Vec<String>) -> anyhow::Result<()> {
+    state.application_manager.run_entrypoint(PluginId::from_string(plugin_id), EntrypointId::from_string(entrypoint_id), arguments)
+        .await
+}
+
+// notification is shown in hud and forwarded to every connected websocket
+async fn push_notification(state: &HttpApiState, text: String) -> anyhow::Result<()> {
+    state.application_manager.show_hud(text.clone())
+        .await?;
+
+    let _ = state.notifications.send(HttpApiNotification { text });
+
+    Ok(())
+}
+
+fn search_result_to_http_api(result: SearchResult) -> HttpApiSearchResult {
+    let entrypoint_type = match result.entrypoint_type {
+        SearchResultEntrypointType::Command => "command",
+        SearchResultEntrypointType::View => "view",
+        SearchResultEntrypointType::Generated => "generated",
+    };
+
+    HttpApiSearchResult {
+        plugin_id: result.plugin_id.to_string(),
+        plugin_name: result.plugin_name,
+        entrypoint_id: result.entrypoint_id.to_string(),
+        entrypoint_name: result.entrypoint_name,
+        entrypoint_subtitle: result.entrypoint_subtitle,
+        entrypoint_type,
+    }
+}
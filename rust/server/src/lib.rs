@@ -1,32 +1,100 @@
+use std::fs::{File, OpenOptions};
+use std::net::SocketAddr;
+use std::path::PathBuf;
 use std::rc::Rc;
 use std::sync::Arc;
+use std::time::Duration;
 use vergen_pretty::vergen_pretty_env;
-use gauntlet_client::{open_window, start_client};
+use gauntlet_client::{start_client, toggle_window};
+use fs4::fs_std::FileExt;
+use fs4::lock_contended_error;
+use gauntlet_common::crash::{init_crash_handler, run_crash_monitor, CRASH_MONITOR_ENV};
+use gauntlet_common::dirs::Dirs;
+use gauntlet_common::i18n::refresh_translation_locale;
 use gauntlet_common::model::{BackendRequestData, BackendResponseData, UiRequestData, UiResponseData};
 use gauntlet_common::rpc::backend_api::BackendApi;
 use gauntlet_common::rpc::backend_server::start_backend_server;
+use gauntlet_common::rpc::frontend_router::FrontendRouter;
+use gauntlet_common::rpc::remote::serve_remote_frontend;
 use gauntlet_common::{settings_env_data_from_string, settings_env_data_to_string, SettingsEnvData};
 use gauntlet_plugin_runtime::run_plugin_runtime;
 use gauntlet_utils::channel::{channel, RequestReceiver, RequestSender};
-use crate::plugins::ApplicationManager;
+use crate::plugins::{read_or_create_token, validate_plugin_manifest, ApplicationManager};
 use crate::rpc::BackendServerImpl;
+use crate::http_api::start_http_api;
 use crate::search::SearchIndex;
+use crate::locale::watch_locale_changes;
 
 pub mod rpc;
+pub mod bench;
+pub(in crate) mod http_api;
 pub(in crate) mod search;
+pub(in crate) mod locale;
 pub(in crate) mod plugins;
 pub(in crate) mod model;
 
 const SETTINGS_ENV: &'static str = "GAUNTLET_INTERNAL_SETTINGS";
 const PLUGIN_RUNTIME_ENV: &'static str = "GAUNTLET_INTERNAL_PLUGIN_RUNTIME";
 
+// created by build script only for release builds, it takes a while and dev builds use development build of react anyway
+#[cfg(feature = "release")]
+pub(in crate) const RUNTIME_SNAPSHOT: Option<&'static [u8]> = Some(include_bytes!(concat!(env!("OUT_DIR"), "/RUNTIME_SNAPSHOT.bin")));
+#[cfg(not(feature = "release"))]
+pub(in crate) const RUNTIME_SNAPSHOT: Option<&'static [u8]> = None;
+
+// path can point to plugin manifest file or directory containing it
+pub fn validate_plugin(path: &str) {
+    let path = PathBuf::from(path);
+
+    let plugin_manifest_path = if path.is_dir() {
+        path.join("gauntlet.toml")
+    } else {
+        path
+    };
+
+    match validate_plugin_manifest(&plugin_manifest_path) {
+        Ok(diagnostics) if diagnostics.is_empty() => {
+            println!("Plugin manifest is valid: {}", plugin_manifest_path.display());
+        }
+        Ok(diagnostics) => {
+            eprintln!("Plugin manifest is not valid: {}", plugin_manifest_path.display());
+
+            for diagnostic in diagnostics {
+                eprintln!();
+                eprintln!("error: {}", diagnostic.reason);
+                eprintln!("  field: {}", diagnostic.field);
+
+                if let Some(suggestion) = diagnostic.suggestion {
+                    eprintln!("  suggestion: {}", suggestion);
+                }
+            }
+
+            std::process::exit(1)
+        }
+        Err(err) => {
+            eprintln!("Unable to validate plugin manifest: {:#}", err);
+
+            std::process::exit(1)
+        }
+    }
+}
+
 pub fn start(minimized: bool) {
     if let Ok(socket_name) = std::env::var(PLUGIN_RUNTIME_ENV) {
-        run_plugin_runtime(socket_name);
+        run_plugin_runtime(socket_name, RUNTIME_SNAPSHOT);
+
+        return;
+    }
+
+    if let Ok(socket_name) = std::env::var(CRASH_MONITOR_ENV) {
+        run_crash_monitor(socket_name);
 
         return;
     }
 
+    // server and client run in the same process
+    init_crash_handler("server and client");
+
     tracing::info!("Gauntlet Build Information:");
     for (name, value) in vergen_pretty_env!() {
         if let Some(value) = value {
@@ -39,21 +107,86 @@ pub fn start(minimized: bool) {
 
     #[cfg(not(feature = "scenario_runner"))]
     {
-        if is_server_running() {
-            open_window()
+        // lock is held until the process exits
+        let instance_lock = acquire_instance_lock()
+            .inspect_err(|err| tracing::warn!("Unable to acquire instance lock, checking if server is running instead: {:?}", err));
+
+        let already_running = match &instance_lock {
+            Ok(instance_lock) => instance_lock.is_none(),
+            Err(_) => is_server_running(),
+        };
+
+        if already_running && minimized {
+            // e.g. started by more than one autostart entry, showing window on login is not expected
+            tracing::info!("Gauntlet is already running, exiting...");
+        } else if already_running {
+            tracing::info!("Gauntlet is already running, toggling window...");
+
+            toggle_window()
         } else {
-            let (frontend_sender, frontend_receiver) = channel::<UiRequestData, UiResponseData>();
-            let (backend_sender, backend_receiver) = channel::<BackendRequestData, BackendResponseData>();
+            let (local_frontend_sender, local_frontend_receiver) = std::sync::mpsc::channel();
 
             std::thread::spawn(|| {
-                start_server(frontend_sender, backend_receiver);
+                start_routed_server(local_frontend_sender);
             });
 
+            let (frontend_receiver, backend_sender) = local_frontend_receiver.recv()
+                .expect("server thread exited before local frontend was attached");
+
             start_client(minimized, frontend_receiver, backend_sender)
         }
     }
 }
 
+// server without frontend, frontends from other machines can attach to it over network
+pub fn start_headless(listen: SocketAddr, cert: PathBuf, key: PathBuf) {
+    init_crash_handler("server");
+
+    // lock is held until the process exits
+    let instance_lock = acquire_instance_lock();
+
+    match instance_lock {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            eprintln!("Gauntlet is already running");
+            std::process::exit(1)
+        }
+        Err(err) => {
+            eprintln!("Unable to acquire instance lock: {:#}", err);
+            std::process::exit(1)
+        }
+    }
+
+    let token = match read_or_create_token(&Dirs::new().remote_frontend_token_file()) {
+        Ok(token) => token,
+        Err(err) => {
+            eprintln!("Unable to read remote frontend token: {:#}", err);
+            std::process::exit(1)
+        }
+    };
+
+    let (frontend_sender, frontend_receiver) = channel::<UiRequestData, UiResponseData>();
+    let (backend_sender, backend_receiver) = channel::<BackendRequestData, BackendResponseData>();
+
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .expect("unable to start server tokio runtime")
+        .block_on(async {
+            let router = FrontendRouter::new(frontend_receiver, backend_sender);
+
+            tokio::spawn(async move {
+                if let Err(err) = serve_remote_frontend(listen, &cert, &key, token, router).await {
+                    tracing::error!("Unable to serve remote frontend: {:?}", err);
+                    std::process::exit(1)
+                }
+            });
+
+            run_server(frontend_sender, backend_receiver).await
+        })
+        .unwrap();
+}
+
 #[cfg(feature = "scenario_runner")]
 fn run_scenario_runner() {
     let runner_type = std::env::var("GAUNTLET_SCENARIO_RUNNER_TYPE")
@@ -84,6 +217,26 @@ fn run_scenario_runner() {
 }
 
 
+// returns none if lock is already held by another instance
+fn acquire_instance_lock() -> anyhow::Result<Option<File>> {
+    let lock_file = Dirs::new().instance_lock_file();
+
+    if let Some(parent) = lock_file.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let file = OpenOptions::new()
+        .create(true)
+        .write(true)
+        .open(&lock_file)?;
+
+    match file.try_lock_exclusive() {
+        Ok(()) => Ok(Some(file)),
+        Err(err) if err.kind() == lock_contended_error().kind() => Ok(None),
+        Err(err) => Err(err.into())
+    }
+}
+
 fn is_server_running() -> bool {
     tokio::runtime::Builder::new_multi_thread()
         .enable_all()
@@ -102,6 +255,7 @@ fn is_server_running() -> bool {
         })
 }
 
+#[cfg(feature = "scenario_runner")]
 fn start_server(request_sender: RequestSender<UiRequestData, UiResponseData>, backend_receiver: RequestReceiver<BackendRequestData, BackendResponseData>) {
     tokio::runtime::Builder::new_multi_thread()
         .enable_all()
@@ -113,6 +267,32 @@ fn start_server(request_sender: RequestSender<UiRequestData, UiResponseData>, ba
         .unwrap();
 }
 
+// local frontend is attached to router the same way as remote ones,
+// so views opened by it are routed together with views of other sessions
+fn start_routed_server(
+    local_frontend_sender: std::sync::mpsc::Sender<(RequestReceiver<UiRequestData, UiResponseData>, RequestSender<BackendRequestData, BackendResponseData>)>
+) {
+    let (frontend_sender, frontend_receiver) = channel::<UiRequestData, UiResponseData>();
+    let (backend_sender, backend_receiver) = channel::<BackendRequestData, BackendResponseData>();
+
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .expect("unable to start server tokio runtime")
+        .block_on(async {
+            let router = FrontendRouter::new(frontend_receiver, backend_sender);
+
+            // session is kept attached until the process exits
+            let (_session_id, frontend_receiver, backend_sender) = router.attach();
+
+            local_frontend_sender.send((frontend_receiver, backend_sender))
+                .expect("main thread should wait for local frontend");
+
+            run_server(frontend_sender, backend_receiver).await
+        })
+        .unwrap();
+}
+
 #[cfg(feature = "scenario_runner")]
 fn start_frontend_mock(
     request_receiver: RequestReceiver<UiRequestData, UiResponseData>,
@@ -133,6 +313,14 @@ async fn run_server(frontend_sender: RequestSender<UiRequestData, UiResponseData
 
     let mut application_manager = Arc::new(application_manager);
 
+    if let Err(err) = application_manager.init_crash_reports().await {
+        tracing::error!("error initializing crash reports: {:?}", err);
+    }
+
+    if let Err(err) = application_manager.init_autostart().await {
+        tracing::error!("error initializing autostart: {:?}", err);
+    }
+
     application_manager.clear_all_icon_cache_dir()?;
 
     #[cfg(not(feature = "scenario_runner"))]
@@ -153,6 +341,14 @@ async fn run_server(frontend_sender: RequestSender<UiRequestData, UiResponseData
 
     application_manager.reload_all_plugins().await?;
 
+    application_manager.start_metrics_endpoint();
+
+    start_locale_watcher(application_manager.clone());
+
+    start_timers(application_manager.clone());
+
+    start_http_api(application_manager.clone());
+
     tokio::spawn({
         let application_manager = application_manager.clone();
 
@@ -170,8 +366,48 @@ async fn run_server(frontend_sender: RequestSender<UiRequestData, UiResponseData
     }
 }
 
+// names and descriptions of entrypoints in search index are localized,
+// so index is rebuilt when user changes system locale while application is running
+fn start_locale_watcher(application_manager: Arc<ApplicationManager>) {
+    let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+
+    watch_locale_changes(sender);
+
+    tokio::spawn(async move {
+        while let Some(system_locale) = receiver.recv().await {
+            if refresh_translation_locale(system_locale) {
+                tracing::info!("System locale has changed, rebuilding search index");
+
+                if let Err(err) = application_manager.reindex().await {
+                    tracing::error!("Unable to rebuild search index after locale change: {:?}", err);
+                }
+            }
+        }
+    });
+}
+
+// timers are checked every second, countdown in tray tooltip is updated at the same time
+fn start_timers(application_manager: Arc<ApplicationManager>) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(1));
+
+        loop {
+            interval.tick().await;
+
+            if let Err(err) = application_manager.tick_timers().await {
+                tracing::error!("Unable to update timers: {:?}", err);
+            }
+        }
+    });
+}
+
 async fn handle_request(application_manager: Arc<ApplicationManager>, request_data: BackendRequestData) -> anyhow::Result<BackendResponseData> {
+    application_manager.record_request("frontend", request_name(&request_data));
+
     let response_data = match request_data {
+        BackendRequestData::Ping => {
+            BackendResponseData::Nothing
+        }
         BackendRequestData::Setup => {
             let data = application_manager.setup_data().await?;
 
@@ -184,11 +420,14 @@ async fn handle_request(application_manager: Arc<ApplicationManager>, request_da
 
             BackendResponseData::Nothing
         }
-        BackendRequestData::Search { text, render_inline_view } => {
-            let results = application_manager.search(&text, render_inline_view)?;
+        BackendRequestData::Search { text, filter, render_inline_view } => {
+            let (results, recent_count, fallback) = application_manager.search(&text, &filter, render_inline_view)
+                .await?;
 
             BackendResponseData::Search {
                 results,
+                recent_count,
+                fallback,
             }
         }
         BackendRequestData::RequestViewRender { plugin_id, entrypoint_id } => {
@@ -204,8 +443,13 @@ async fn handle_request(application_manager: Arc<ApplicationManager>, request_da
 
             BackendResponseData::Nothing
         }
-        BackendRequestData::RequestRunCommand { plugin_id, entrypoint_id } => {
-            application_manager.handle_run_command(plugin_id, entrypoint_id)
+        BackendRequestData::RequestViewPop { plugin_id } => {
+            application_manager.handle_view_pop(plugin_id);
+
+            BackendResponseData::Nothing
+        }
+        BackendRequestData::RequestRunCommand { plugin_id, entrypoint_id, arguments } => {
+            application_manager.handle_run_command(plugin_id, entrypoint_id, arguments)
                 .await;
 
             BackendResponseData::Nothing
@@ -241,12 +485,18 @@ async fn handle_request(application_manager: Arc<ApplicationManager>, request_da
             BackendResponseData::Nothing
         }
         BackendRequestData::OpenSettingsWindow => {
-            application_manager.handle_open_settings_window();
+            application_manager.handle_open_settings_window().await;
+
+            BackendResponseData::Nothing
+        }
+        BackendRequestData::RunConfirmedDeepLink { uri } => {
+            application_manager.run_confirmed_deep_link(&uri)
+                .await?;
 
             BackendResponseData::Nothing
         }
         BackendRequestData::OpenSettingsWindowPreferences { plugin_id, entrypoint_id } => {
-            application_manager.handle_open_settings_window_preferences(plugin_id, entrypoint_id);
+            application_manager.handle_open_settings_window_preferences(plugin_id, entrypoint_id).await;
 
             BackendResponseData::Nothing
         }
@@ -256,7 +506,88 @@ async fn handle_request(application_manager: Arc<ApplicationManager>, request_da
 
             BackendResponseData::InlineViewShortcuts { shortcuts }
         }
+        BackendRequestData::GetImages { hashes } => {
+            let images = application_manager.images(hashes)
+                .await?;
+
+            BackendResponseData::Images { images }
+        }
+        BackendRequestData::GetResultPreview { plugin_id, entrypoint_id } => {
+            let preview = application_manager.result_preview(plugin_id, entrypoint_id)?;
+
+            BackendResponseData::ResultPreview { preview }
+        }
+        BackendRequestData::GetFileQuicklook { path, dark } => {
+            let quicklook = application_manager.file_quicklook(path, dark)
+                .await?;
+
+            BackendResponseData::FileQuicklook { quicklook }
+        }
+        BackendRequestData::SetPluginsPaused { paused } => {
+            application_manager.set_plugins_paused(paused)
+                .await?;
+
+            BackendResponseData::Nothing
+        }
+        BackendRequestData::SetOfflineMode { offline } => {
+            application_manager.set_offline_mode(offline)
+                .await?;
+
+            BackendResponseData::Nothing
+        }
+        BackendRequestData::SetFocusMode { enabled } => {
+            application_manager.set_focus_mode(enabled);
+
+            BackendResponseData::Nothing
+        }
+        BackendRequestData::SetEntrypointState { plugin_id, entrypoint_id, enabled } => {
+            application_manager.set_entrypoint_state(plugin_id, entrypoint_id, enabled)
+                .await?;
+
+            BackendResponseData::Nothing
+        }
+        BackendRequestData::CompletePluginOnboarding { plugin_id, entrypoint_id } => {
+            application_manager.complete_plugin_onboarding(plugin_id, entrypoint_id)
+                .await?;
+
+            BackendResponseData::Nothing
+        }
+        BackendRequestData::ReportRenderer { renderer } => {
+            application_manager.set_renderer_info(renderer);
+
+            BackendResponseData::Nothing
+        }
     };
 
     Ok(response_data)
 }
+
+fn request_name(request_data: &BackendRequestData) -> &'static str {
+    match request_data {
+        BackendRequestData::Ping => "ping",
+        BackendRequestData::Setup => "setup",
+        BackendRequestData::SetupResponse { .. } => "setup_response",
+        BackendRequestData::Search { .. } => "search",
+        BackendRequestData::RequestViewRender { .. } => "request_view_render",
+        BackendRequestData::RequestViewClose { .. } => "request_view_close",
+        BackendRequestData::RequestViewPop { .. } => "request_view_pop",
+        BackendRequestData::RequestRunCommand { .. } => "request_run_command",
+        BackendRequestData::RequestRunGeneratedEntrypoint { .. } => "request_run_generated_entrypoint",
+        BackendRequestData::SendViewEvent { .. } => "send_view_event",
+        BackendRequestData::SendKeyboardEvent { .. } => "send_keyboard_event",
+        BackendRequestData::SendOpenEvent { .. } => "send_open_event",
+        BackendRequestData::OpenSettingsWindow => "open_settings_window",
+        BackendRequestData::RunConfirmedDeepLink { .. } => "run_confirmed_deep_link",
+        BackendRequestData::OpenSettingsWindowPreferences { .. } => "open_settings_window_preferences",
+        BackendRequestData::InlineViewShortcuts => "inline_view_shortcuts",
+        BackendRequestData::SetPluginsPaused { .. } => "set_plugins_paused",
+        BackendRequestData::SetOfflineMode { .. } => "set_offline_mode",
+        BackendRequestData::SetFocusMode { .. } => "set_focus_mode",
+        BackendRequestData::SetEntrypointState { .. } => "set_entrypoint_state",
+        BackendRequestData::CompletePluginOnboarding { .. } => "complete_plugin_onboarding",
+        BackendRequestData::GetImages { .. } => "get_images",
+        BackendRequestData::GetResultPreview { .. } => "get_result_preview",
+        BackendRequestData::GetFileQuicklook { .. } => "get_file_quicklook",
+        BackendRequestData::ReportRenderer { .. } => "report_renderer",
+    }
+}
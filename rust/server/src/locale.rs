@@ -0,0 +1,171 @@
+use tokio::sync::mpsc::UnboundedSender;
+
+// sends message every time system notifies about locale change. notifications are received on separate thread,
+// because each platform needs to block on its own event loop. new locale is sent if it is known from notification
+pub fn watch_locale_changes(sender: UnboundedSender<Option<String>>) {
+    std::thread::spawn(move || {
+        if let Err(err) = platform::watch(sender) {
+            tracing::warn!("Unable to watch system locale changes: {:?}", err);
+        }
+    });
+}
+
+// locale of a running process is taken from environment, which doesn't change,
+// so new locale is read from systemd-localed, the same service "localectl set-locale" uses
+#[cfg(target_os = "linux")]
+mod platform {
+    use tokio::sync::mpsc::UnboundedSender;
+
+    pub fn watch(sender: UnboundedSender<Option<String>>) -> anyhow::Result<()> {
+        let connection = zbus::blocking::Connection::system()?;
+
+        let proxy = zbus::blocking::Proxy::new(
+            &connection,
+            "org.freedesktop.locale1",
+            "/org/freedesktop/locale1",
+            "org.freedesktop.locale1",
+        )?;
+
+        // initial value is not a change, only later ones are sent
+        let mut current = messages_locale(proxy.get_property("Locale")?);
+
+        for changed in proxy.receive_property_changed::<Vec<String>>("Locale") {
+            let locale = messages_locale(changed.get()?);
+
+            if locale != current {
+                current = locale.clone();
+
+                if sender.send(locale).is_err() {
+                    return Ok(())
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // property contains variables like "LANG=de_DE.UTF-8", the same precedence as for environment is used
+    fn messages_locale(variables: Vec<String>) -> Option<String> {
+        ["LC_ALL=", "LC_MESSAGES=", "LANG="]
+            .iter()
+            .find_map(|prefix| {
+                variables.iter()
+                    .find_map(|variable| variable.strip_prefix(prefix))
+                    .filter(|value| !value.is_empty())
+                    .map(|value| value.to_string())
+            })
+    }
+}
+
+// preferred languages are changed in system settings, region by itself changes only current locale
+#[cfg(target_os = "macos")]
+mod platform {
+    use std::ptr::NonNull;
+
+    use block2::RcBlock;
+    use objc2_foundation::{NSCurrentLocaleDidChangeNotification, NSDistributedNotificationCenter, NSNotification, NSNotificationCenter, NSRunLoop, NSString};
+    use tokio::sync::mpsc::UnboundedSender;
+
+    pub fn watch(sender: UnboundedSender<Option<String>>) -> anyhow::Result<()> {
+        let block = RcBlock::new(move |_notification: NonNull<NSNotification>| {
+            let _ = sender.send(None);
+        });
+
+        // observers are kept until process exits, run loop of this thread never returns
+        unsafe {
+            let _locale_observer = NSNotificationCenter::defaultCenter()
+                .addObserverForName_object_queue_usingBlock(Some(NSCurrentLocaleDidChangeNotification), None, None, &block);
+
+            let _languages_observer = NSDistributedNotificationCenter::defaultCenter()
+                .addObserverForName_object_queue_usingBlock(Some(&NSString::from_str("AppleLanguagePreferencesChangedNotification")), None, None, &block);
+
+            NSRunLoop::currentRunLoop().run();
+        }
+
+        Ok(())
+    }
+}
+
+// WM_SETTINGCHANGE with "intl" section is broadcast to top-level windows when regional settings change,
+// so hidden window is created for it, message-only windows don't receive broadcasts
+#[cfg(target_os = "windows")]
+mod platform {
+    use std::sync::OnceLock;
+
+    use anyhow::anyhow;
+    use tokio::sync::mpsc::UnboundedSender;
+    use windows::core::{w, PCWSTR};
+    use windows::Win32::Foundation::{HINSTANCE, HWND, LPARAM, LRESULT, WPARAM};
+    use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+    use windows::Win32::UI::WindowsAndMessaging::{CreateWindowExW, DefWindowProcW, DispatchMessageW, GetMessageW, RegisterClassW, MSG, WINDOW_EX_STYLE, WM_SETTINGCHANGE, WNDCLASSW, WS_OVERLAPPED};
+
+    static SENDER: OnceLock<UnboundedSender<Option<String>>> = OnceLock::new();
+
+    pub fn watch(sender: UnboundedSender<Option<String>>) -> anyhow::Result<()> {
+        SENDER.set(sender)
+            .map_err(|_| anyhow!("System locale is already watched"))?;
+
+        unsafe {
+            let instance = HINSTANCE::from(GetModuleHandleW(None)?);
+
+            let class = WNDCLASSW {
+                lpfnWndProc: Some(window_proc),
+                hInstance: instance,
+                lpszClassName: w!("GauntletLocaleWatcher"),
+                ..Default::default()
+            };
+
+            if RegisterClassW(&class) == 0 {
+                return Err(windows::core::Error::from_win32().into())
+            }
+
+            // window is never shown
+            let _window = CreateWindowExW(
+                WINDOW_EX_STYLE::default(),
+                w!("GauntletLocaleWatcher"),
+                w!(""),
+                WS_OVERLAPPED,
+                0,
+                0,
+                0,
+                0,
+                None,
+                None,
+                instance,
+                None,
+            )?;
+
+            let mut message = MSG::default();
+
+            while GetMessageW(&mut message, None, 0, 0).as_bool() {
+                DispatchMessageW(&message);
+            }
+        }
+
+        Ok(())
+    }
+
+    unsafe extern "system" fn window_proc(window: HWND, message: u32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+        // lparam points to name of the changed section
+        if message == WM_SETTINGCHANGE && lparam.0 != 0 {
+            let section = PCWSTR(lparam.0 as *const u16).to_string();
+
+            if section.is_ok_and(|section| section == "intl") {
+                if let Some(sender) = SENDER.get() {
+                    let _ = sender.send(None);
+                }
+            }
+        }
+
+        DefWindowProcW(window, message, wparam, lparam)
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+mod platform {
+    use tokio::sync::mpsc::UnboundedSender;
+
+    pub fn watch(_sender: UnboundedSender<Option<String>>) -> anyhow::Result<()> {
+        Err(anyhow::anyhow!("Watching system locale is not supported on this platform"))
+    }
+}
@@ -1,4 +1,5 @@
-use gauntlet_common::model::{EntrypointId, KeyboardEventOrigin, PhysicalKey, UiPropertyValue, UiWidgetId};
+use std::collections::HashMap;
+use gauntlet_common::model::{EntrypointId, KeyboardEventOrigin, PhysicalKey, UiPropertyValue, UiRenderLocation, UiWidgetId};
 
 
 #[derive(Debug)]
@@ -7,8 +8,10 @@ pub enum IntermediateUiEvent {
         entrypoint_id: EntrypointId
     },
     CloseView,
+    PopView,
     RunCommand {
-        entrypoint_id: String
+        entrypoint_id: String,
+        arguments: HashMap<String, String>,
     },
     RunGeneratedEntrypoint {
         entrypoint_id: String,
@@ -32,6 +35,19 @@ pub enum IntermediateUiEvent {
         text: String,
     },
     RefreshSearchIndex,
+    Search {
+        text: String,
+        search_id: u32,
+    },
+    NotificationClosed {
+        notification_id: u32,
+        action_index: Option<usize>,
+    },
+    ViewRenderFailed {
+        entrypoint_id: EntrypointId,
+        render_location: UiRenderLocation,
+        message: String,
+    },
 }
 
 pub enum ActionShortcutKey {
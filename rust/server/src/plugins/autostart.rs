@@ -0,0 +1,232 @@
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Context};
+use gauntlet_common::dirs::Dirs;
+
+// server and client run in the same process, so single entry starts both
+const AUTOSTART_ARGS: [&str; 1] = ["--minimized"];
+
+pub struct Autostart {
+    dirs: Dirs,
+}
+
+impl Autostart {
+    pub fn new(dirs: Dirs) -> Self {
+        Self {
+            dirs
+        }
+    }
+
+    // checks actual entry instead of stored setting, so that entries removed outside of gauntlet are reflected
+    pub fn is_enabled(&self) -> anyhow::Result<bool> {
+        platform::is_enabled(&self.dirs)
+    }
+
+    pub fn set_enabled(&self, enabled: bool) -> anyhow::Result<()> {
+        tracing::info!("Setting autostart enabled: {}", enabled);
+
+        if enabled {
+            let executable = std::env::current_exe()
+                .context("Unable to get current_exe from env")?;
+
+            platform::enable(&self.dirs, executable)
+        } else {
+            platform::disable(&self.dirs)
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use super::*;
+
+    // https://specifications.freedesktop.org/autostart-spec/latest/
+    fn desktop_file(dirs: &Dirs) -> PathBuf {
+        let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .filter(|path| path.is_absolute())
+            .unwrap_or_else(|| dirs.home_dir().join(".config"));
+
+        config_dir.join("autostart").join("gauntlet.desktop")
+    }
+
+    pub fn is_enabled(dirs: &Dirs) -> anyhow::Result<bool> {
+        let desktop_file = desktop_file(dirs);
+
+        if !desktop_file.exists() {
+            return Ok(false)
+        }
+
+        let content = std::fs::read_to_string(&desktop_file)
+            .context("Unable to read autostart desktop file")?;
+
+        // desktop environments usually disable autostart entries instead of removing them
+        let disabled = content.lines()
+            .map(|line| line.trim())
+            .any(|line| line == "Hidden=true" || line == "X-GNOME-Autostart-enabled=false");
+
+        Ok(!disabled)
+    }
+
+    pub fn enable(dirs: &Dirs, executable: PathBuf) -> anyhow::Result<()> {
+        let desktop_file = desktop_file(dirs);
+
+        let executable = executable.to_str()
+            .ok_or(anyhow!("failed to convert executable path to utf-8"))?;
+
+        let content = format!(
+            "[Desktop Entry]\nType=Application\nName=Gauntlet\nExec=\"{}\" {}\nIcon=gauntlet\nTerminal=false\nX-GNOME-Autostart-enabled=true\n",
+            executable.replace('\\', "\\\\").replace('"', "\\\""),
+            AUTOSTART_ARGS.join(" ")
+        );
+
+        if let Some(parent) = desktop_file.parent() {
+            std::fs::create_dir_all(parent)
+                .context("Unable to create autostart directory")?;
+        }
+
+        std::fs::write(&desktop_file, content)
+            .context("Unable to write autostart desktop file")?;
+
+        Ok(())
+    }
+
+    pub fn disable(dirs: &Dirs) -> anyhow::Result<()> {
+        let desktop_file = desktop_file(dirs);
+
+        if desktop_file.exists() {
+            std::fs::remove_file(&desktop_file)
+                .context("Unable to remove autostart desktop file")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use super::*;
+
+    const LAUNCH_AGENT_LABEL: &str = "dev.project-gauntlet.gauntlet";
+
+    fn plist_file(dirs: &Dirs) -> PathBuf {
+        dirs.home_dir()
+            .join("Library")
+            .join("LaunchAgents")
+            .join(format!("{}.plist", LAUNCH_AGENT_LABEL))
+    }
+
+    fn escape_xml(value: &str) -> String {
+        value.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+    }
+
+    pub fn is_enabled(dirs: &Dirs) -> anyhow::Result<bool> {
+        Ok(plist_file(dirs).exists())
+    }
+
+    pub fn enable(dirs: &Dirs, executable: PathBuf) -> anyhow::Result<()> {
+        let plist_file = plist_file(dirs);
+
+        let executable = executable.to_str()
+            .ok_or(anyhow!("failed to convert executable path to utf-8"))?;
+
+        let program_arguments = std::iter::once(executable)
+            .chain(AUTOSTART_ARGS)
+            .map(|arg| format!("        <string>{}</string>\n", escape_xml(arg)))
+            .collect::<String>();
+
+        let content = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+            <!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n\
+            <plist version=\"1.0\">\n\
+            <dict>\n\
+            \x20   <key>Label</key>\n\
+            \x20   <string>{}</string>\n\
+            \x20   <key>ProgramArguments</key>\n\
+            \x20   <array>\n\
+            {}\
+            \x20   </array>\n\
+            \x20   <key>RunAtLoad</key>\n\
+            \x20   <true/>\n\
+            \x20   <key>ProcessType</key>\n\
+            \x20   <string>Interactive</string>\n\
+            </dict>\n\
+            </plist>\n",
+            LAUNCH_AGENT_LABEL,
+            program_arguments
+        );
+
+        if let Some(parent) = plist_file.parent() {
+            std::fs::create_dir_all(parent)
+                .context("Unable to create launch agents directory")?;
+        }
+
+        std::fs::write(&plist_file, content)
+            .context("Unable to write launch agent file")?;
+
+        Ok(())
+    }
+
+    pub fn disable(dirs: &Dirs) -> anyhow::Result<()> {
+        let plist_file = plist_file(dirs);
+
+        if plist_file.exists() {
+            std::fs::remove_file(&plist_file)
+                .context("Unable to remove launch agent file")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use super::*;
+    use winreg::enums::{HKEY_CURRENT_USER, KEY_READ, KEY_SET_VALUE};
+    use winreg::RegKey;
+
+    const RUN_KEY: &str = "Software\\Microsoft\\Windows\\CurrentVersion\\Run";
+    // same value name as previously used by auto-launch crate, so that existing entries are picked up
+    const RUN_VALUE_NAME: &str = "Gauntlet";
+
+    pub fn is_enabled(_dirs: &Dirs) -> anyhow::Result<bool> {
+        let run_key = RegKey::predef(HKEY_CURRENT_USER)
+            .open_subkey_with_flags(RUN_KEY, KEY_READ)
+            .context("Unable to open Run registry key")?;
+
+        let value: std::io::Result<String> = run_key.get_value(RUN_VALUE_NAME);
+
+        Ok(value.is_ok())
+    }
+
+    pub fn enable(_dirs: &Dirs, executable: PathBuf) -> anyhow::Result<()> {
+        let executable = executable.to_str()
+            .ok_or(anyhow!("failed to convert executable path to utf-8"))?;
+
+        let run_key = RegKey::predef(HKEY_CURRENT_USER)
+            .open_subkey_with_flags(RUN_KEY, KEY_SET_VALUE)
+            .context("Unable to open Run registry key")?;
+
+        let command = format!("\"{}\" {}", executable, AUTOSTART_ARGS.join(" "));
+
+        run_key.set_value(RUN_VALUE_NAME, &command)
+            .context("Unable to set Run registry value")?;
+
+        Ok(())
+    }
+
+    pub fn disable(_dirs: &Dirs) -> anyhow::Result<()> {
+        let run_key = RegKey::predef(HKEY_CURRENT_USER)
+            .open_subkey_with_flags(RUN_KEY, KEY_SET_VALUE)
+            .context("Unable to open Run registry key")?;
+
+        match run_key.delete_value(RUN_VALUE_NAME) {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err).context("Unable to remove Run registry value")
+        }
+    }
+}
@@ -0,0 +1,262 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+use gauntlet_common::model::{EntrypointId, Icons, PluginId, SearchResult, SearchResultAccessory, SearchResultEntrypointType};
+
+pub const BROWSER_PLUGIN_ID: &str = "builtin://browser";
+const BROWSER_PLUGIN_NAME: &str = "Browser";
+const SEARCH_RESULT_LIMIT: usize = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BrowserItemKind {
+    Tab,
+    Bookmark,
+    History,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BrowserItem {
+    pub id: String,
+    pub title: String,
+    pub url: String,
+}
+
+// each connected extension, e.g. one per browser, has its own items
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BrowserConnectionId(u64);
+
+// sent to companion extension that provided the item, which focuses the tab or opens url
+#[derive(Debug, Clone)]
+pub struct BrowserItemActivation {
+    pub connection: BrowserConnectionId,
+    pub kind: BrowserItemKind,
+    pub id: String,
+    pub url: String,
+}
+
+// tabs, bookmarks and history fed by companion browser extension through http api websocket.
+// extension sends full list of items of a kind every time it changes,
+// items are kept only in memory and are removed when extension that sent them disconnects
+#[derive(Clone)]
+pub struct BrowserBridge {
+    next_connection_id: Arc<AtomicU64>,
+    items: Arc<Mutex<Vec<(BrowserConnectionId, BrowserItemKind, BrowserItem)>>>,
+    activations: broadcast::Sender<BrowserItemActivation>,
+}
+
+impl BrowserBridge {
+    pub fn new() -> Self {
+        let (activations, _) = broadcast::channel(10);
+
+        Self {
+            next_connection_id: Arc::new(AtomicU64::new(0)),
+            items: Arc::new(Mutex::new(vec![])),
+            activations,
+        }
+    }
+
+    pub fn is_browser_item(plugin_id: &PluginId) -> bool {
+        plugin_id.to_string() == BROWSER_PLUGIN_ID
+    }
+
+    pub fn connect(&self) -> BrowserConnectionId {
+        BrowserConnectionId(self.next_connection_id.fetch_add(1, Ordering::Relaxed))
+    }
+
+    pub fn set_items(&self, connection: BrowserConnectionId, kind: BrowserItemKind, items: Vec<BrowserItem>) {
+        let mut current = self.items.lock().unwrap();
+
+        current.retain(|(item_connection, item_kind, _)| *item_connection != connection || *item_kind != kind);
+        current.extend(items.into_iter().map(|item| (connection, kind, item)));
+    }
+
+    pub fn disconnect(&self, connection: BrowserConnectionId) {
+        self.items.lock().unwrap().retain(|(item_connection, _, _)| *item_connection != connection);
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<BrowserItemActivation> {
+        self.activations.subscribe()
+    }
+
+    pub fn search(&self, text: &str) -> Vec<SearchResult> {
+        let terms: Vec<_> = text.split_whitespace()
+            .map(|term| term.to_lowercase())
+            .collect();
+
+        if terms.is_empty() {
+            return vec![];
+        }
+
+        let items = self.items.lock().unwrap();
+
+        let mut results: Vec<_> = items.iter()
+            .filter(|(_, _, item)| {
+                let title = item.title.to_lowercase();
+                let url = item.url.to_lowercase();
+
+                terms.iter().all(|term| title.contains(term) || url.contains(term))
+            })
+            .collect();
+
+        // open tabs are most likely what user is looking for
+        results.sort_by_key(|(_, kind, _)| match kind {
+            BrowserItemKind::Tab => 0,
+            BrowserItemKind::Bookmark => 1,
+            BrowserItemKind::History => 2,
+        });
+
+        results.into_iter()
+            .take(SEARCH_RESULT_LIMIT)
+            .map(|(connection, kind, item)| browser_search_result(*connection, *kind, item))
+            .collect()
+    }
+
+    pub fn activate(&self, entrypoint_id: &EntrypointId) -> anyhow::Result<()> {
+        let (connection, kind, item) = {
+            let entrypoint_id = entrypoint_id.to_string();
+
+            let (connection, kind, id) = parse_browser_entrypoint_id(&entrypoint_id)
+                .ok_or_else(|| anyhow!("Invalid browser item: {}", entrypoint_id))?;
+
+            self.items.lock()
+                .unwrap()
+                .iter()
+                .find(|(item_connection, item_kind, item)| *item_connection == connection && *item_kind == kind && item.id == id)
+                .cloned()
+                .ok_or_else(|| anyhow!("Unknown browser item: {}", entrypoint_id))?
+        };
+
+        let activation = BrowserItemActivation {
+            connection,
+            kind,
+            id: item.id,
+            url: item.url,
+        };
+
+        // extension is not connected anymore, tabs cannot be focused but url can still be opened
+        if let Err(broadcast::error::SendError(activation)) = self.activations.send(activation) {
+            open::that_detached(&activation.url)?;
+        }
+
+        Ok(())
+    }
+}
+
+// item ids are only unique within a browser, e.g. tab ids of two browsers can be the same,
+// so connection which sent the item is part of entrypoint id
+fn browser_entrypoint_id(connection: BrowserConnectionId, kind: BrowserItemKind, id: &str) -> String {
+    let kind = match kind {
+        BrowserItemKind::Tab => "tab",
+        BrowserItemKind::Bookmark => "bookmark",
+        BrowserItemKind::History => "history",
+    };
+
+    format!("{}:{}:{}", connection.0, kind, id)
+}
+
+// id is the last part, so it can contain ':'
+fn parse_browser_entrypoint_id(entrypoint_id: &str) -> Option<(BrowserConnectionId, BrowserItemKind, &str)> {
+    let mut parts = entrypoint_id.splitn(3, ':');
+
+    let connection = parts.next()?.parse().ok()?;
+
+    let kind = match parts.next()? {
+        "tab" => BrowserItemKind::Tab,
+        "bookmark" => BrowserItemKind::Bookmark,
+        "history" => BrowserItemKind::History,
+        _ => return None,
+    };
+
+    let id = parts.next()?;
+
+    Some((BrowserConnectionId(connection), kind, id))
+}
+
+fn browser_search_result(connection: BrowserConnectionId, kind: BrowserItemKind, item: &BrowserItem) -> SearchResult {
+    let (icon, tooltip) = match kind {
+        BrowserItemKind::Tab => (Icons::Window, "Open tab"),
+        BrowserItemKind::Bookmark => (Icons::Bookmark, "Bookmark"),
+        BrowserItemKind::History => (Icons::Clock, "History"),
+    };
+
+    let entrypoint_name = if item.title.is_empty() {
+        item.url.clone()
+    } else {
+        item.title.clone()
+    };
+
+    SearchResult {
+        plugin_id: PluginId::from_string(BROWSER_PLUGIN_ID),
+        plugin_name: BROWSER_PLUGIN_NAME.to_string(),
+        entrypoint_id: EntrypointId::from_string(browser_entrypoint_id(connection, kind, &item.id)),
+        entrypoint_name,
+        entrypoint_name_highlights: vec![],
+        entrypoint_subtitle: None,
+        entrypoint_matched_keywords: vec![],
+        entrypoint_generator_name: None,
+        entrypoint_icon: None,
+        entrypoint_type: SearchResultEntrypointType::Command,
+        entrypoint_actions: vec![],
+        entrypoint_accessories: vec![
+            SearchResultAccessory::IconAccessory {
+                icon,
+                tooltip: Some(tooltip.to_string()),
+            }
+        ],
+        entrypoint_arguments: vec![],
+        entrypoint_file: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tab(id: &str, url: &str) -> BrowserItem {
+        BrowserItem {
+            id: id.to_string(),
+            title: "Docs".to_string(),
+            url: url.to_string(),
+        }
+    }
+
+    #[test]
+    fn tabs_with_same_id_from_different_connections_are_activated_separately() {
+        let bridge = BrowserBridge::new();
+        let mut activations = bridge.subscribe();
+
+        let first = bridge.connect();
+        let second = bridge.connect();
+
+        bridge.set_items(first, BrowserItemKind::Tab, vec![tab("1", "https://first.example.com")]);
+        bridge.set_items(second, BrowserItemKind::Tab, vec![tab("1", "https://second.example.com")]);
+
+        let results = bridge.search("docs");
+
+        assert_eq!(results.len(), 2);
+        assert_ne!(results[0].entrypoint_id, results[1].entrypoint_id);
+
+        for result in &results {
+            bridge.activate(&result.entrypoint_id).unwrap();
+
+            let activation = activations.try_recv().unwrap();
+            let expected = if activation.connection == first { "https://first.example.com" } else { "https://second.example.com" };
+
+            assert_eq!(activation.url, expected);
+        }
+    }
+
+    #[test]
+    fn item_id_can_contain_separator() {
+        let connection = BrowserBridge::new().connect();
+
+        let entrypoint_id = browser_entrypoint_id(connection, BrowserItemKind::History, "https://example.com");
+
+        assert_eq!(parse_browser_entrypoint_id(&entrypoint_id), Some((connection, BrowserItemKind::History, "https://example.com")));
+    }
+}
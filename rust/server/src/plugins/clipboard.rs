@@ -2,12 +2,17 @@ use anyhow::{anyhow, Context, Error};
 use arboard::ImageData;
 use image::RgbaImage;
 use std::io::Cursor;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
 use gauntlet_plugin_runtime::JsClipboardData;
 
+pub const DEFAULT_SENSITIVE_CLEAR_AFTER: Duration = Duration::from_secs(30);
+
 #[derive(Clone)]
 pub struct Clipboard {
     clipboard: Arc<RwLock<arboard::Clipboard>>,
+    // none means sensitive text is never cleared
+    sensitive_clear_after: Arc<Mutex<Option<Duration>>>,
 }
 
 impl Clipboard {
@@ -17,6 +22,7 @@ impl Clipboard {
 
         Ok(Self {
             clipboard: Arc::new(RwLock::new(clipboard)),
+            sensitive_clear_after: Arc::new(Mutex::new(Some(DEFAULT_SENSITIVE_CLEAR_AFTER))),
         })
     }
 
@@ -124,6 +130,43 @@ impl Clipboard {
         Ok(())
     }
 
+    pub fn set_sensitive_clear_after(&self, clear_after: Option<Duration>) {
+        *self.sensitive_clear_after.lock().unwrap() = clear_after;
+    }
+
+    pub fn sensitive_clear_after(&self) -> Option<Duration> {
+        *self.sensitive_clear_after.lock().unwrap()
+    }
+
+    // used for passwords and other sensitive values, text is not removed if it was replaced in the meantime
+    pub fn write_sensitive_text(&self, data: String) -> anyhow::Result<()> {
+        self.write_text(data.clone())?;
+
+        let Some(clear_after) = self.sensitive_clear_after() else {
+            return Ok(());
+        };
+
+        let clipboard = self.clone();
+
+        tokio::spawn(async move {
+            tokio::time::sleep(clear_after).await;
+
+            match clipboard.read_text() {
+                Ok(Some(text)) if text == data => {
+                    if let Err(err) = clipboard.clear() {
+                        tracing::warn!("Unable to clear clipboard: {:?}", err);
+                    }
+                }
+                Ok(_) => {}
+                Err(err) => {
+                    tracing::warn!("Unable to read clipboard before clearing it: {:?}", err);
+                }
+            }
+        });
+
+        Ok(())
+    }
+
     pub fn clear(&self) -> anyhow::Result<()> {
         let mut clipboard = self.clipboard.write().expect("lock is poisoned");
 
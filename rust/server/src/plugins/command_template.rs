@@ -0,0 +1,124 @@
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{anyhow, Context};
+
+pub enum TemplateValue<'a> {
+    Text(&'a str),
+    // placeholder which is the whole argument is replaced with all values as separate arguments,
+    // inside of other text values are joined and quoted for posix shell
+    Args(&'a [String]),
+}
+
+// template is split into program and arguments before placeholders are replaced,
+// so values with spaces don't need to be quoted. parts in single or double quotes are kept together,
+// e.g. "code --new-window {path}" or "osascript -e 'tell application \"Terminal\" to do script \"{command}\"'"
+pub fn run_command_template(template: &str, placeholders: &[(&str, TemplateValue)], cwd: Option<&Path>) -> anyhow::Result<()> {
+    let mut parts = vec![];
+
+    for part in split_template(template)? {
+        let whole_placeholder = placeholders.iter()
+            .find(|(name, _)| part == format!("{{{}}}", name));
+
+        match whole_placeholder {
+            Some((_, TemplateValue::Args(values))) => parts.extend(values.iter().cloned()),
+            _ => parts.push(replace_placeholders(&part, placeholders)),
+        }
+    }
+
+    let mut parts = parts.into_iter();
+
+    let program = parts.next()
+        .ok_or_else(|| anyhow!("Command template is empty"))?;
+
+    let mut command = Command::new(&program);
+
+    command.args(parts);
+
+    if let Some(cwd) = cwd {
+        command.current_dir(cwd);
+    }
+
+    tracing::debug!("Running command: {:?}", command);
+
+    let mut child = command.spawn()
+        .with_context(|| format!("Unable to run command: {}", program))?;
+
+    // reaped in background, so that no zombie process is left
+    std::thread::spawn(move || {
+        if let Err(err) = child.wait() {
+            tracing::warn!("Unable to wait for command: {:?}", err);
+        }
+    });
+
+    Ok(())
+}
+
+fn replace_placeholders(part: &str, placeholders: &[(&str, TemplateValue)]) -> String {
+    placeholders.iter()
+        .fold(part.to_string(), |part, (name, value)| {
+            let value = match value {
+                TemplateValue::Text(value) => value.to_string(),
+                TemplateValue::Args(values) => shell_join(values),
+            };
+
+            part.replace(&format!("{{{}}}", name), &value)
+        })
+}
+
+fn split_template(template: &str) -> anyhow::Result<Vec<String>> {
+    let mut parts = vec![];
+    let mut current = String::new();
+    let mut in_part = false;
+    let mut quote: Option<char> = None;
+
+    for char in template.chars() {
+        match quote {
+            Some(quote_char) if char == quote_char => {
+                quote = None;
+            }
+            Some(_) => {
+                current.push(char);
+            }
+            None if char == '\'' || char == '"' => {
+                quote = Some(char);
+                in_part = true;
+            }
+            None if char.is_whitespace() => {
+                if in_part {
+                    parts.push(std::mem::take(&mut current));
+                    in_part = false;
+                }
+            }
+            None => {
+                current.push(char);
+                in_part = true;
+            }
+        }
+    }
+
+    if quote.is_some() {
+        return Err(anyhow!("Command template has unclosed quote: {}", template));
+    }
+
+    if in_part {
+        parts.push(current);
+    }
+
+    Ok(parts)
+}
+
+pub fn shell_join(values: &[String]) -> String {
+    values.iter()
+        .map(|value| {
+            let safe = !value.is_empty() && value.chars().all(|char| char.is_ascii_alphanumeric() || "-_./:@=+,".contains(char));
+
+            if safe {
+                value.clone()
+            } else {
+                format!("'{}'", value.replace('\'', "'\\''"))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -1,14 +1,48 @@
 use std::cell::Cell;
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
 use serde::Deserialize;
 
 use gauntlet_common::dirs::Dirs;
+use gauntlet_common::model::{KeymapBinding, PluginId, UiEscapeBehavior, UiFontConfig, UiRenderer};
+use crate::plugins::clipboard::DEFAULT_SENSITIVE_CLEAR_AFTER;
 use crate::plugins::data_db_repository::{DataDbRepository, DbWritePendingPlugin};
+use crate::plugins::fallbacks::{default_search_fallbacks, SearchFallback};
+use crate::plugins::keymap::{resolve_keymap, ConfigKeymapAction, KeymapPreset};
+use crate::plugins::passwords::PasswordPolicy;
+use crate::plugins::recent_projects::DEFAULT_EDITOR_COMMAND;
+use crate::plugins::terminal::DEFAULT_TERMINAL_COMMAND;
+use crate::search::SearchTransliteration;
 
 pub struct ConfigReader {
     dirs: Dirs,
     repository: DataDbRepository,
     close_on_unfocus: AtomicBool,
+    escape_behavior: Mutex<UiEscapeBehavior>,
+    reopen_last_view: AtomicBool,
+    reduced_motion: AtomicBool,
+    tray_icon: AtomicBool,
+    inline_view_order: Mutex<Vec<PluginId>>,
+    results_per_plugin: Mutex<Option<usize>>,
+    keymap: Mutex<Vec<KeymapBinding>>,
+    metrics_port: Mutex<Option<u16>>,
+    http_api_port: Mutex<Option<u16>>,
+    network_proxy: Mutex<Option<String>>,
+    plugin_network_proxy: Mutex<HashMap<PluginId, String>>,
+    search_transliteration: Mutex<Vec<SearchTransliteration>>,
+    search_fallbacks: Mutex<Vec<SearchFallback>>,
+    font: Mutex<UiFontConfig>,
+    monitor_ui_scale: Mutex<HashMap<String, u16>>,
+    renderer: Mutex<UiRenderer>,
+    wgpu_backend: Mutex<Option<String>>,
+    timer_sound: Mutex<Option<PathBuf>>,
+    password_policy: Mutex<PasswordPolicy>,
+    clipboard_clear_sensitive_after: Mutex<Option<Duration>>,
+    terminal_command: Mutex<String>,
+    project_editor_command: Mutex<String>,
 }
 
 impl ConfigReader {
@@ -17,6 +51,28 @@ impl ConfigReader {
             dirs,
             repository,
             close_on_unfocus: AtomicBool::new(true),
+            escape_behavior: Mutex::new(UiEscapeBehavior::AlwaysHide),
+            reopen_last_view: AtomicBool::new(false),
+            reduced_motion: AtomicBool::new(false),
+            tray_icon: AtomicBool::new(true),
+            inline_view_order: Mutex::new(vec![]),
+            results_per_plugin: Mutex::new(None),
+            keymap: Mutex::new(vec![]),
+            metrics_port: Mutex::new(None),
+            http_api_port: Mutex::new(None),
+            network_proxy: Mutex::new(None),
+            plugin_network_proxy: Mutex::new(HashMap::new()),
+            search_transliteration: Mutex::new(vec![]),
+            search_fallbacks: Mutex::new(default_search_fallbacks()),
+            font: Mutex::new(UiFontConfig::default()),
+            monitor_ui_scale: Mutex::new(HashMap::new()),
+            renderer: Mutex::new(UiRenderer::Auto),
+            wgpu_backend: Mutex::new(None),
+            timer_sound: Mutex::new(None),
+            password_policy: Mutex::new(PasswordPolicy::default()),
+            clipboard_clear_sensitive_after: Mutex::new(Some(DEFAULT_SENSITIVE_CLEAR_AFTER)),
+            terminal_command: Mutex::new(DEFAULT_TERMINAL_COMMAND.to_string()),
+            project_editor_command: Mutex::new(DEFAULT_EDITOR_COMMAND.to_string()),
         }
     }
 
@@ -36,7 +92,98 @@ impl ConfigReader {
         //     }
         // }
 
-        self.close_on_unfocus.store(config.main_window.unwrap_or_default().close_on_unfocus, Ordering::SeqCst);
+        let main_window = config.main_window.unwrap_or_default();
+        self.close_on_unfocus.store(main_window.close_on_unfocus, Ordering::SeqCst);
+        *self.escape_behavior.lock().unwrap() = match main_window.escape_behavior {
+            ConfigEscapeBehavior::ClearThenHide => UiEscapeBehavior::ClearThenHide,
+            ConfigEscapeBehavior::AlwaysHide => UiEscapeBehavior::AlwaysHide,
+            ConfigEscapeBehavior::NeverHide => UiEscapeBehavior::NeverHide,
+        };
+        self.reopen_last_view.store(main_window.reopen_last_view, Ordering::SeqCst);
+        *self.inline_view_order.lock().unwrap() = main_window.inline_view_order
+            .into_iter()
+            .map(|plugin_id| PluginId::from_string(plugin_id))
+            .collect();
+        // zero means no limit
+        *self.results_per_plugin.lock().unwrap() = main_window.results_per_plugin
+            .filter(|limit| *limit > 0);
+
+        let accessibility = config.accessibility.unwrap_or_default();
+        self.reduced_motion.store(accessibility.reduced_motion, Ordering::SeqCst);
+
+        let tray = config.tray.unwrap_or_default();
+        self.tray_icon.store(tray.show_icon, Ordering::SeqCst);
+
+        let keymap = config.keymap.unwrap_or_default();
+        *self.keymap.lock().unwrap() = resolve_keymap(keymap.preset, keymap.bindings);
+
+        let metrics = config.metrics.unwrap_or_default();
+        *self.metrics_port.lock().unwrap() = metrics.prometheus_port;
+
+        let http_api = config.http_api.unwrap_or_default();
+        *self.http_api_port.lock().unwrap() = http_api.port;
+
+        let network = config.network.unwrap_or_default();
+        *self.network_proxy.lock().unwrap() = network.proxy;
+        *self.plugin_network_proxy.lock().unwrap() = network.plugin_proxy
+            .into_iter()
+            .map(|(plugin_id, proxy)| (PluginId::from_string(plugin_id), proxy))
+            .collect();
+
+        let search = config.search.unwrap_or_default();
+        *self.search_transliteration.lock().unwrap() = search.transliteration;
+        *self.search_fallbacks.lock().unwrap() = search.fallbacks.unwrap_or_else(default_search_fallbacks);
+
+        let font = config.font.unwrap_or_default();
+        *self.font.lock().unwrap() = UiFontConfig {
+            family: font.family,
+            monospace_family: font.monospace_family,
+            size: font.size.filter(|size| *size > 0.0),
+            files: font.files,
+        };
+
+        let ui_scale = config.ui_scale.unwrap_or_default();
+        *self.monitor_ui_scale.lock().unwrap() = ui_scale.monitors
+            .into_iter()
+            .filter(|(_, percent)| *percent > 0)
+            .collect();
+
+        let renderer = config.renderer.unwrap_or_default();
+        *self.renderer.lock().unwrap() = match renderer.backend {
+            ConfigRendererBackend::Auto => UiRenderer::Auto,
+            ConfigRendererBackend::Wgpu => UiRenderer::Wgpu,
+            ConfigRendererBackend::TinySkia => UiRenderer::TinySkia,
+        };
+        *self.wgpu_backend.lock().unwrap() = renderer.wgpu_backend;
+
+        let timers = config.timers.unwrap_or_default();
+        *self.timer_sound.lock().unwrap() = timers.sound;
+
+        let passwords = config.passwords.unwrap_or_default();
+        let default_policy = PasswordPolicy::default();
+        *self.password_policy.lock().unwrap() = PasswordPolicy {
+            length: passwords.length.unwrap_or(default_policy.length),
+            uppercase: passwords.uppercase.unwrap_or(default_policy.uppercase),
+            digits: passwords.digits.unwrap_or(default_policy.digits),
+            symbols: passwords.symbols.unwrap_or(default_policy.symbols),
+            exclude_ambiguous: passwords.exclude_ambiguous.unwrap_or(default_policy.exclude_ambiguous),
+        };
+
+        let clipboard = config.clipboard.unwrap_or_default();
+        // zero disables clearing
+        *self.clipboard_clear_sensitive_after.lock().unwrap() = match clipboard.clear_sensitive_after {
+            Some(0) => None,
+            Some(seconds) => Some(Duration::from_secs(seconds)),
+            None => Some(DEFAULT_SENSITIVE_CLEAR_AFTER),
+        };
+
+        let terminal = config.terminal.unwrap_or_default();
+        *self.terminal_command.lock().unwrap() = terminal.command
+            .unwrap_or_else(|| DEFAULT_TERMINAL_COMMAND.to_string());
+
+        let recent_projects = config.recent_projects.unwrap_or_default();
+        *self.project_editor_command.lock().unwrap() = recent_projects.editor_command
+            .unwrap_or_else(|| DEFAULT_EDITOR_COMMAND.to_string());
 
         Ok(())
     }
@@ -65,12 +212,115 @@ impl ConfigReader {
     pub fn close_on_unfocus(&self) -> bool {
         self.close_on_unfocus.load(Ordering::SeqCst)
     }
+
+    pub fn escape_behavior(&self) -> UiEscapeBehavior {
+        *self.escape_behavior.lock().unwrap()
+    }
+
+    pub fn reopen_last_view(&self) -> bool {
+        self.reopen_last_view.load(Ordering::SeqCst)
+    }
+
+    pub fn reduced_motion(&self) -> bool {
+        self.reduced_motion.load(Ordering::SeqCst)
+    }
+
+    pub fn tray_icon(&self) -> bool {
+        self.tray_icon.load(Ordering::SeqCst)
+    }
+
+    pub fn inline_view_order(&self) -> Vec<PluginId> {
+        self.inline_view_order.lock().unwrap().clone()
+    }
+
+    pub fn results_per_plugin(&self) -> Option<usize> {
+        *self.results_per_plugin.lock().unwrap()
+    }
+
+    pub fn keymap(&self) -> Vec<KeymapBinding> {
+        self.keymap.lock().unwrap().clone()
+    }
+
+    pub fn metrics_port(&self) -> Option<u16> {
+        *self.metrics_port.lock().unwrap()
+    }
+
+    pub fn http_api_port(&self) -> Option<u16> {
+        *self.http_api_port.lock().unwrap()
+    }
+
+    // plugin specific proxy takes priority over global one
+    pub fn network_proxy(&self, plugin_id: &PluginId) -> Option<String> {
+        self.plugin_network_proxy.lock().unwrap()
+            .get(plugin_id)
+            .cloned()
+            .or_else(|| self.network_proxy.lock().unwrap().clone())
+    }
+
+    pub fn search_transliteration(&self) -> Vec<SearchTransliteration> {
+        self.search_transliteration.lock().unwrap().clone()
+    }
+
+    pub fn search_fallbacks(&self) -> Vec<SearchFallback> {
+        self.search_fallbacks.lock().unwrap().clone()
+    }
+
+    pub fn font(&self) -> UiFontConfig {
+        self.font.lock().unwrap().clone()
+    }
+
+    pub fn monitor_ui_scale(&self) -> HashMap<String, u16> {
+        self.monitor_ui_scale.lock().unwrap().clone()
+    }
+
+    pub fn renderer(&self) -> UiRenderer {
+        *self.renderer.lock().unwrap()
+    }
+
+    pub fn wgpu_backend(&self) -> Option<String> {
+        self.wgpu_backend.lock().unwrap().clone()
+    }
+
+    pub fn timer_sound(&self) -> Option<PathBuf> {
+        self.timer_sound.lock().unwrap().clone()
+    }
+
+    pub fn password_policy(&self) -> PasswordPolicy {
+        self.password_policy.lock().unwrap().clone()
+    }
+
+    pub fn clipboard_clear_sensitive_after(&self) -> Option<Duration> {
+        *self.clipboard_clear_sensitive_after.lock().unwrap()
+    }
+
+    pub fn terminal_command(&self) -> String {
+        self.terminal_command.lock().unwrap().clone()
+    }
+
+    pub fn project_editor_command(&self) -> String {
+        self.project_editor_command.lock().unwrap().clone()
+    }
 }
 
 #[derive(Debug, Deserialize, Default)]
 #[serde(deny_unknown_fields)]
 pub struct ApplicationConfig {
-    main_window: Option<ApplicationConfigWindow>
+    main_window: Option<ApplicationConfigWindow>,
+    keymap: Option<ApplicationConfigKeymap>,
+    accessibility: Option<ApplicationConfigAccessibility>,
+    tray: Option<ApplicationConfigTray>,
+    metrics: Option<ApplicationConfigMetrics>,
+    http_api: Option<ApplicationConfigHttpApi>,
+    network: Option<ApplicationConfigNetwork>,
+    search: Option<ApplicationConfigSearch>,
+    font: Option<ApplicationConfigFont>,
+    ui_scale: Option<ApplicationConfigUiScale>,
+    renderer: Option<ApplicationConfigRenderer>,
+    timers: Option<ApplicationConfigTimers>,
+    passwords: Option<ApplicationConfigPasswords>,
+    clipboard: Option<ApplicationConfigClipboard>,
+    terminal: Option<ApplicationConfigTerminal>,
+    recent_projects: Option<ApplicationConfigRecentProjects>,
     // #[serde(default)]
     // configuration_mode: ConfigurationModeConfig,
     // #[serde(default)]
@@ -79,17 +329,183 @@ pub struct ApplicationConfig {
 
 #[derive(Debug, Deserialize)]
 pub struct ApplicationConfigWindow {
-    close_on_unfocus: bool
+    close_on_unfocus: bool,
+    #[serde(default)]
+    escape_behavior: ConfigEscapeBehavior,
+    #[serde(default)]
+    reopen_last_view: bool,
+    #[serde(default)]
+    inline_view_order: Vec<String>,
+    #[serde(default)]
+    results_per_plugin: Option<usize>,
 }
 
 impl Default for ApplicationConfigWindow {
     fn default() -> Self {
         Self {
             close_on_unfocus: true,
+            escape_behavior: ConfigEscapeBehavior::default(),
+            reopen_last_view: false,
+            inline_view_order: vec![],
+            results_per_plugin: None,
         }
     }
 }
 
+#[derive(Debug, Deserialize, Default)]
+pub enum ConfigEscapeBehavior {
+    #[serde(rename = "clear-then-hide")]
+    ClearThenHide,
+    #[default]
+    #[serde(rename = "always-hide")]
+    AlwaysHide,
+    #[serde(rename = "never-hide")]
+    NeverHide,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ApplicationConfigAccessibility {
+    #[serde(default)]
+    reduced_motion: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ApplicationConfigTray {
+    #[serde(default = "default_show_icon")]
+    show_icon: bool,
+}
+
+fn default_show_icon() -> bool {
+    true
+}
+
+impl Default for ApplicationConfigTray {
+    fn default() -> Self {
+        Self {
+            show_icon: default_show_icon(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ApplicationConfigMetrics {
+    prometheus_port: Option<u16>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ApplicationConfigHttpApi {
+    port: Option<u16>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ApplicationConfigNetwork {
+    proxy: Option<String>,
+    // plugin id to proxy url
+    #[serde(default)]
+    plugin_proxy: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ApplicationConfigSearch {
+    #[serde(default)]
+    transliteration: Vec<SearchTransliteration>,
+    // empty list disables fallbacks
+    fallbacks: Option<Vec<SearchFallback>>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ApplicationConfigFont {
+    family: Option<String>,
+    monospace_family: Option<String>,
+    size: Option<f32>,
+    // additional font files, e.g. with cjk, emoji or nerd font glyphs, used as fallback for characters missing in selected font
+    #[serde(default)]
+    files: Vec<PathBuf>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ApplicationConfigUiScale {
+    // monitor size to scale in percent, takes priority over scale selected in settings
+    #[serde(default)]
+    monitors: HashMap<String, u16>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ApplicationConfigRenderer {
+    #[serde(default)]
+    backend: ConfigRendererBackend,
+    wgpu_backend: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub enum ConfigRendererBackend {
+    #[default]
+    #[serde(rename = "auto")]
+    Auto,
+    #[serde(rename = "wgpu")]
+    Wgpu,
+    #[serde(rename = "tiny-skia")]
+    TinySkia,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ApplicationConfigTimers {
+    // sound file played when timer or alarm goes off
+    sound: Option<PathBuf>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ApplicationConfigPasswords {
+    // default length of generated passwords, can be overridden in query, e.g. "password 32"
+    length: Option<usize>,
+    uppercase: Option<bool>,
+    digits: Option<bool>,
+    symbols: Option<bool>,
+    // excludes characters which are easy to confuse, like "l", "1" and "O", "0"
+    exclude_ambiguous: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ApplicationConfigClipboard {
+    // seconds after which passwords and other sensitive values copied by gauntlet or plugins are removed from clipboard
+    clear_sensitive_after: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ApplicationConfigTerminal {
+    // "{command}" is replaced with command which runs generated script, "{cwd}" with working directory
+    command: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ApplicationConfigRecentProjects {
+    // "{path}" is replaced with project path and "{launcher}" with command line launcher of editor which opened the project
+    editor_command: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ApplicationConfigKeymap {
+    #[serde(default)]
+    preset: KeymapPreset,
+    #[serde(default)]
+    bindings: HashMap<ConfigKeymapAction, Vec<String>>,
+}
+
 // #[derive(Debug, Deserialize)]
 // struct PluginEntryConfig {
 //     id: String,
@@ -13,6 +13,7 @@ use typed_path::TypedPathBuf;
 use uuid::Uuid;
 use gauntlet_common::model::{UiTheme, PhysicalKey, PhysicalShortcut, PluginId};
 use gauntlet_common::dirs::Dirs;
+use gauntlet_common::i18n::select_translation;
 use crate::model::ActionShortcutKey;
 use crate::plugins::frecency::{FrecencyItemStats, FrecencyMetaParams};
 use crate::plugins::loader::PluginManifestActionShortcutKey;
@@ -41,6 +42,20 @@ pub struct DbReadPlugin {
     pub preferences: HashMap<String, DbPluginPreference>,
     #[sqlx(json)]
     pub preferences_user_data: HashMap<String, DbPluginPreferenceUserData>,
+    // markdown shown before first use of the plugin
+    pub onboarding: Option<String>,
+    pub onboarding_completed: bool,
+    #[sqlx(json(nullable))]
+    pub http_cache: Option<DbPluginHttpCache>,
+    // search scores of plugin results are multiplied by it
+    pub priority: f64,
+    pub notifications_enabled: bool,
+    // set by user in settings, kept when plugin is updated
+    #[sqlx(json)]
+    pub environment: HashMap<String, String>,
+    // approved by user in settings, kept when plugin is updated
+    #[sqlx(json)]
+    pub filesystem_roots: Vec<String>,
 }
 
 #[derive(sqlx::FromRow)]
@@ -62,6 +77,33 @@ pub struct DbReadPluginEntrypoint {
     pub actions: Vec<DbPluginAction>,
     #[sqlx(json)]
     pub actions_user_data: Vec<DbPluginActionUserData>,
+    #[sqlx(json)]
+    pub arguments: Vec<DbPluginArgument>,
+    #[sqlx(json)]
+    pub translations: HashMap<String, DbPluginEntrypointTranslation>,
+    pub subtitle: Option<String>,
+    #[sqlx(json)]
+    pub keywords: Vec<String>,
+    pub accessory: Option<String>,
+    // hidden entrypoints are not shown in search results, but can still be run
+    pub hidden: bool,
+    // order set by user in settings, entrypoints without it are shown after ordered ones
+    pub position: Option<i64>,
+}
+
+impl DbReadPluginEntrypoint {
+    // name translated to current locale, if plugin manifest provides translation for it
+    pub fn localized_name(&self) -> String {
+        select_translation(&self.translations)
+            .and_then(|translation| translation.name.clone())
+            .unwrap_or_else(|| self.name.clone())
+    }
+
+    pub fn localized_description(&self) -> String {
+        select_translation(&self.translations)
+            .and_then(|translation| translation.description.clone())
+            .unwrap_or_else(|| self.description.clone())
+    }
 }
 
 #[derive(Deserialize, Serialize)]
@@ -80,6 +122,8 @@ pub struct DbWritePlugin {
     pub permissions: DbPluginPermissions,
     pub plugin_type: String,
     pub preferences: HashMap<String, DbPluginPreference>,
+    pub onboarding: Option<String>,
+    pub http_cache: Option<DbPluginHttpCache>,
 }
 
 pub struct DbWritePluginEntrypoint {
@@ -90,11 +134,17 @@ pub struct DbWritePluginEntrypoint {
     pub entrypoint_type: String,
     pub preferences: HashMap<String, DbPluginPreference>,
     pub actions: Vec<DbPluginAction>,
+    pub arguments: Vec<DbPluginArgument>,
+    pub translations: HashMap<String, DbPluginEntrypointTranslation>,
+    pub subtitle: Option<String>,
+    pub keywords: Vec<String>,
+    pub accessory: Option<String>,
 }
 
 pub struct DbWritePluginAssetData {
     pub path: String,
-    pub data: Vec<u8>
+    pub data: Vec<u8>,
+    pub hash: String,
 }
 
 #[derive(Debug, Clone)]
@@ -103,6 +153,7 @@ pub enum DbPluginEntrypointType {
     View,
     InlineView,
     EntrypointGenerator,
+    SearchProvider,
 }
 
 #[derive(Debug, Clone)]
@@ -112,6 +163,12 @@ pub enum DbPluginType {
     Bundled,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DbPluginHttpCache {
+    pub max_size: u64,
+    pub stale_while_revalidate: bool,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct DbPluginPermissions {
     #[serde(default)]
@@ -203,6 +260,37 @@ pub struct DbPluginAction {
     pub kind: DbPluginActionShortcutKind
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(tag = "type")]
+pub enum DbPluginArgument {
+    #[serde(rename = "string")]
+    String {
+        id: String,
+        name: String,
+        required: bool,
+    },
+    #[serde(rename = "enum")]
+    Enum {
+        id: String,
+        name: String,
+        required: bool,
+        enum_values: Vec<DbPreferenceEnumValue>,
+    },
+    #[serde(rename = "file")]
+    File {
+        id: String,
+        name: String,
+        required: bool,
+    },
+}
+
+// manifest provided name and description for single locale
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DbPluginEntrypointTranslation {
+    pub name: Option<String>,
+    pub description: Option<String>,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct DbPluginActionUserData {
     pub id: String,
@@ -240,6 +328,31 @@ pub struct DbSettings {
     pub theme: Option<DbTheme>,
     // none is static
     pub window_position_mode: Option<DbWindowPositionMode>,
+    // none is frecency
+    pub search_results_sort: Option<DbSearchResultsSort>,
+    // none is disabled
+    pub crash_reports_enabled: Option<bool>,
+    // none means autostart entry was never changed from settings
+    pub autostart_configured: Option<bool>,
+    // none is online
+    pub offline_mode: Option<bool>,
+    // none is enabled, usage stats never leave the machine
+    pub usage_stats_enabled: Option<bool>,
+    // none is disabled
+    pub window_overlay: Option<bool>,
+    // none is comfortable
+    pub density: Option<DbDensity>,
+    // none is auto, scale in percent
+    pub ui_scale: Option<u16>,
+    // none means no deep link was confirmed yet
+    pub confirmed_deep_links: Option<Vec<DbConfirmedDeepLink>>,
+}
+
+// entrypoint which user allowed to be run by deep links
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DbConfirmedDeepLink {
+    pub plugin_id: String,
+    pub entrypoint_id: String,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -249,7 +362,9 @@ pub enum DbTheme {
     #[serde(rename = "macos_dark")]
     MacOSDark,
     #[serde(rename = "legacy")]
-    Legacy
+    Legacy,
+    #[serde(rename = "high_contrast")]
+    HighContrast,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -258,6 +373,22 @@ pub enum DbWindowPositionMode {
     ActiveMonitor,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum DbDensity {
+    #[serde(rename = "compact")]
+    Compact,
+    #[serde(rename = "large")]
+    Large,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum DbSearchResultsSort {
+    #[serde(rename = "relevance")]
+    Relevance,
+    #[serde(rename = "plugin")]
+    Plugin,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub enum DbPluginActionShortcutKind {
     #[serde(rename = "main")]
@@ -343,6 +474,65 @@ pub struct DbPluginEntrypointFrecencyStats {
     pub num_accesses: i32,
 }
 
+#[derive(sqlx::FromRow)]
+pub struct DbUsageStats {
+    pub plugin_id: String,
+    pub entrypoint_id: String,
+
+    pub launch_count: i64,
+    // seconds since unix epoch
+    pub last_launched: i64,
+    pub search_to_launch_count: i64,
+    pub search_to_launch_total_micros: i64,
+}
+
+#[derive(sqlx::FromRow)]
+pub struct DbQuicklink {
+    pub id: String,
+    pub name: String,
+    pub url: String,
+    pub bang: Option<String>,
+    pub fallback: bool,
+}
+
+#[derive(sqlx::FromRow)]
+pub struct DbTimer {
+    pub id: String,
+    pub kind: String,
+    pub name: String,
+    // milliseconds since unix epoch
+    pub started_at: i64,
+    // not set for stopwatches
+    pub fires_at: Option<i64>,
+}
+
+#[derive(sqlx::FromRow)]
+pub struct DbProfile {
+    pub name: String,
+    #[sqlx(json)]
+    pub data: DbProfileData,
+}
+
+// snapshot of plugin state and settings which can be switched between
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DbProfileData {
+    pub theme: Option<DbTheme>,
+    pub plugins: HashMap<String, DbProfilePlugin>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DbProfilePlugin {
+    pub enabled: bool,
+    pub preferences_user_data: HashMap<String, DbPluginPreferenceUserData>,
+    pub entrypoints: HashMap<String, DbProfileEntrypoint>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DbProfileEntrypoint {
+    pub enabled: bool,
+    pub preferences_user_data: HashMap<String, DbPluginPreferenceUserData>,
+}
+
 const SETTINGS_DATA_ID: &str = "settings_data"; // only one row in the table
 
 impl DataDbRepository {
@@ -724,6 +914,28 @@ impl DataDbRepository {
         Ok(result.data)
     }
 
+    // hash is null for assets of plugins installed before hashes were introduced
+    pub async fn get_asset_hash(&self, plugin_id: &str, path: &str) -> anyhow::Result<Option<String>> {
+        // language=SQLite
+        let result = sqlx::query_as::<_, (Option<String>, )>("SELECT hash FROM plugin_asset_data WHERE plugin_id = ?1 and path = ?2")
+            .bind(plugin_id)
+            .bind(path)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(result.0)
+    }
+
+    pub async fn get_asset_data_by_hash(&self, hash: &str) -> anyhow::Result<Option<Vec<u8>>> {
+        // language=SQLite
+        let result = sqlx::query_as::<_, (Vec<u8>, )>("SELECT data FROM plugin_asset_data WHERE hash = ?1 LIMIT 1")
+            .bind(hash)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(result.map(|result| result.0))
+    }
+
     async fn get_all_asset_data_paths<'a, E>(&self, plugin_id: &str, executor: E) -> anyhow::Result<HashSet<String>>
         where
             E: Executor<'a, Database=Sqlite>,
@@ -831,6 +1043,45 @@ impl DataDbRepository {
         Ok(())
     }
 
+    pub async fn record_launch(&self, plugin_id: &str, entrypoint_id: &str, last_launched: i64, search_to_launch_micros: Option<i64>) -> anyhow::Result<()> {
+        // language=SQLite
+        let sql = r#"
+            INSERT INTO usage_stats (plugin_id, entrypoint_id, launch_count, last_launched, search_to_launch_count, search_to_launch_total_micros)
+                VALUES(?1, ?2, 1, ?3, ?4, ?5)
+                    ON CONFLICT (entrypoint_id, plugin_id)
+                        DO UPDATE SET launch_count = launch_count + 1, last_launched = ?3, search_to_launch_count = search_to_launch_count + ?4, search_to_launch_total_micros = search_to_launch_total_micros + ?5
+        "#;
+
+        sqlx::query(sql)
+            .bind(plugin_id)
+            .bind(entrypoint_id)
+            .bind(last_launched)
+            .bind(if search_to_launch_micros.is_some() { 1 } else { 0 })
+            .bind(search_to_launch_micros.unwrap_or(0))
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn list_usage_stats(&self) -> anyhow::Result<Vec<DbUsageStats>> {
+        // language=SQLite
+        let stats = sqlx::query_as::<_, DbUsageStats>("SELECT * FROM usage_stats ORDER BY launch_count DESC")
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(stats)
+    }
+
+    pub async fn clear_usage_stats(&self) -> anyhow::Result<()> {
+        // language=SQLite
+        sqlx::query("DELETE FROM usage_stats")
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn get_frecency_for_plugin(&self, plugin_id: &str) -> anyhow::Result<HashMap<String, f64>> {
         // language=SQLite
         let result = sqlx::query_as::<_, (String, f64)>("SELECT entrypoint_id, frecency FROM plugin_entrypoint_frecency_stats WHERE plugin_id = ?1")
@@ -843,6 +1094,16 @@ impl DataDbRepository {
         Ok(result)
     }
 
+    pub async fn get_recent_entrypoints(&self, limit: u32) -> anyhow::Result<Vec<(String, String)>> {
+        // language=SQLite
+        let result = sqlx::query_as::<_, (String, String)>("SELECT plugin_id, entrypoint_id FROM plugin_entrypoint_frecency_stats ORDER BY last_accessed DESC LIMIT ?1")
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
     pub async fn set_plugin_enabled(&self, plugin_id: &str, enabled: bool) -> anyhow::Result<()> {
         // language=SQLite
         sqlx::query("UPDATE plugin SET enabled = ?1 WHERE id = ?2")
@@ -854,6 +1115,62 @@ impl DataDbRepository {
         Ok(())
     }
 
+    pub async fn set_plugin_priority(&self, plugin_id: &str, priority: f64) -> anyhow::Result<()> {
+        // language=SQLite
+        sqlx::query("UPDATE plugin SET priority = ?1 WHERE id = ?2")
+            .bind(priority)
+            .bind(plugin_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn set_plugin_notifications_enabled(&self, plugin_id: &str, enabled: bool) -> anyhow::Result<()> {
+        // language=SQLite
+        sqlx::query("UPDATE plugin SET notifications_enabled = ?1 WHERE id = ?2")
+            .bind(enabled)
+            .bind(plugin_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn set_plugin_environment(&self, plugin_id: &str, environment: HashMap<String, String>) -> anyhow::Result<()> {
+        // language=SQLite
+        sqlx::query("UPDATE plugin SET environment = ?1 WHERE id = ?2")
+            .bind(Json(environment))
+            .bind(plugin_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn set_plugin_filesystem_roots(&self, plugin_id: &str, filesystem_roots: Vec<String>) -> anyhow::Result<()> {
+        // language=SQLite
+        sqlx::query("UPDATE plugin SET filesystem_roots = ?1 WHERE id = ?2")
+            .bind(Json(filesystem_roots))
+            .bind(plugin_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    // onboarding completion is kept when plugin is updated, so it is shown only once
+    pub async fn set_plugin_onboarding_completed(&self, plugin_id: &str, completed: bool) -> anyhow::Result<()> {
+        // language=SQLite
+        sqlx::query("UPDATE plugin SET onboarding_completed = ?1 WHERE id = ?2")
+            .bind(completed)
+            .bind(plugin_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn set_plugin_entrypoint_enabled(&self, plugin_id: &str, entrypoint_id: &str, enabled: bool) -> anyhow::Result<()> {
         // language=SQLite
         sqlx::query("UPDATE plugin_entrypoint SET enabled = ?1 WHERE id = ?2 AND plugin_id = ?3")
@@ -866,6 +1183,37 @@ impl DataDbRepository {
         Ok(())
     }
 
+    pub async fn set_plugin_entrypoint_hidden(&self, plugin_id: &str, entrypoint_id: &str, hidden: bool) -> anyhow::Result<()> {
+        // language=SQLite
+        sqlx::query("UPDATE plugin_entrypoint SET hidden = ?1 WHERE id = ?2 AND plugin_id = ?3")
+            .bind(hidden)
+            .bind(entrypoint_id)
+            .bind(plugin_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    // entrypoints not in the list keep their position
+    pub async fn set_plugin_entrypoint_order(&self, plugin_id: &str, entrypoint_ids: &[String]) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        for (position, entrypoint_id) in entrypoint_ids.iter().enumerate() {
+            // language=SQLite
+            sqlx::query("UPDATE plugin_entrypoint SET position = ?1 WHERE id = ?2 AND plugin_id = ?3")
+                .bind(position as i64)
+                .bind(entrypoint_id)
+                .bind(plugin_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
     pub async fn set_global_shortcut(&self, shortcut: Option<PhysicalShortcut>, error: Option<String>) -> anyhow::Result<()> {
         // language=SQLite
         let sql = r#"
@@ -1013,6 +1361,156 @@ impl DataDbRepository {
         Ok(())
     }
 
+    pub async fn list_quicklinks(&self) -> anyhow::Result<Vec<DbQuicklink>> {
+        // language=SQLite
+        let quicklinks = sqlx::query_as::<_, DbQuicklink>("SELECT * FROM quicklink ORDER BY name")
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(quicklinks)
+    }
+
+    pub async fn save_quicklink(&self, quicklink: DbQuicklink) -> anyhow::Result<()> {
+        // language=SQLite
+        let sql = r#"
+            INSERT INTO quicklink (id, name, url, bang, fallback)
+                VALUES(?1, ?2, ?3, ?4, ?5)
+                    ON CONFLICT (id)
+                        DO UPDATE SET name = ?2, url = ?3, bang = ?4, fallback = ?5
+        "#;
+
+        sqlx::query(sql)
+            .bind(&quicklink.id)
+            .bind(&quicklink.name)
+            .bind(&quicklink.url)
+            .bind(&quicklink.bang)
+            .bind(quicklink.fallback)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_quicklink(&self, id: &str) -> anyhow::Result<()> {
+        // language=SQLite
+        sqlx::query("DELETE FROM quicklink WHERE id = ?1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn list_timers(&self) -> anyhow::Result<Vec<DbTimer>> {
+        // language=SQLite
+        let timers = sqlx::query_as::<_, DbTimer>("SELECT * FROM timer ORDER BY started_at")
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(timers)
+    }
+
+    pub async fn save_timer(&self, timer: DbTimer) -> anyhow::Result<()> {
+        // language=SQLite
+        let sql = r#"
+            INSERT INTO timer (id, kind, name, started_at, fires_at)
+                VALUES(?1, ?2, ?3, ?4, ?5)
+        "#;
+
+        sqlx::query(sql)
+            .bind(&timer.id)
+            .bind(&timer.kind)
+            .bind(&timer.name)
+            .bind(timer.started_at)
+            .bind(timer.fires_at)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_timer(&self, id: &str) -> anyhow::Result<()> {
+        // language=SQLite
+        sqlx::query("DELETE FROM timer WHERE id = ?1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn list_profiles(&self) -> anyhow::Result<Vec<DbProfile>> {
+        // language=SQLite
+        let profiles = sqlx::query_as::<_, DbProfile>("SELECT * FROM settings_profile ORDER BY name")
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(profiles)
+    }
+
+    pub async fn get_profile(&self, name: &str) -> anyhow::Result<Option<DbProfile>> {
+        // language=SQLite
+        let profile = sqlx::query_as::<_, DbProfile>("SELECT * FROM settings_profile WHERE name = ?1")
+            .bind(name)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(profile)
+    }
+
+    pub async fn save_profile(&self, name: &str, data: DbProfileData) -> anyhow::Result<()> {
+        // language=SQLite
+        let sql = r#"
+            INSERT INTO settings_profile (name, data)
+                VALUES(?1, ?2)
+                    ON CONFLICT (name)
+                        DO UPDATE SET data = ?2
+        "#;
+
+        sqlx::query(sql)
+            .bind(name)
+            .bind(Json(data))
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_profile(&self, name: &str) -> anyhow::Result<()> {
+        // language=SQLite
+        sqlx::query("DELETE FROM settings_profile WHERE name = ?1")
+            .bind(name)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    // replaces all preference values at once, used when switching profiles
+    pub async fn set_preferences_user_data(&self, plugin_id: &str, entrypoint_id: Option<&str>, user_data: HashMap<String, DbPluginPreferenceUserData>) -> anyhow::Result<()> {
+        match entrypoint_id {
+            None => {
+                // language=SQLite
+                sqlx::query("UPDATE plugin SET preferences_user_data = ?1 WHERE id = ?2")
+                    .bind(Json(user_data))
+                    .bind(plugin_id)
+                    .execute(&self.pool)
+                    .await?;
+            }
+            Some(entrypoint_id) => {
+                // language=SQLite
+                sqlx::query("UPDATE plugin_entrypoint SET preferences_user_data = ?1 WHERE id = ?2 AND plugin_id = ?3")
+                    .bind(Json(user_data))
+                    .bind(entrypoint_id)
+                    .bind(plugin_id)
+                    .execute(&self.pool)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
     pub async fn save_pending_plugin(&self, plugin: DbWritePendingPlugin) -> anyhow::Result<()> {
         // language=SQLite
         sqlx::query("INSERT INTO pending_plugin VALUES(?1)")
@@ -1041,10 +1539,10 @@ impl DataDbRepository {
 
         // language=SQLite
         let sql = r#"
-            INSERT INTO plugin (id, name, enabled, code, permissions, preferences, preferences_user_data, description, type, uuid)
-                VALUES(?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+            INSERT INTO plugin (id, name, enabled, code, permissions, preferences, preferences_user_data, description, type, uuid, onboarding, http_cache)
+                VALUES(?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
                     ON CONFLICT (id)
-                        DO UPDATE SET name = ?2, enabled = ?3, code = ?4, permissions = ?5, preferences = ?6, preferences_user_data = ?7, description = ?8, type = ?9, uuid = ?10
+                        DO UPDATE SET name = ?2, enabled = ?3, code = ?4, permissions = ?5, preferences = ?6, preferences_user_data = ?7, description = ?8, type = ?9, uuid = ?10, onboarding = ?11, http_cache = ?12
         "#;
 
         sqlx::query(sql)
@@ -1058,6 +1556,8 @@ impl DataDbRepository {
             .bind(new_plugin.description)
             .bind(new_plugin.plugin_type)
             .bind(uuid)
+            .bind(new_plugin.onboarding)
+            .bind(new_plugin.http_cache.map(Json))
             .execute(&mut *tx)
             .await?;
 
@@ -1069,12 +1569,12 @@ impl DataDbRepository {
         for new_entrypoint in new_plugin.entrypoints {
             old_entrypoint_ids.remove(&new_entrypoint.id);
 
-            let (uuid, preferences_user_data, actions_user_data, enabled) = self.get_entrypoint_by_id_option_with_executor(&new_plugin.id, &new_entrypoint.id, &mut *tx).await?
-                .map(|entrypoint| (entrypoint.uuid, entrypoint.preferences_user_data, entrypoint.actions_user_data, entrypoint.enabled))
-                .unwrap_or((Uuid::new_v4().to_string(), HashMap::new(), vec![], true));
+            let (uuid, preferences_user_data, actions_user_data, enabled, hidden, position) = self.get_entrypoint_by_id_option_with_executor(&new_plugin.id, &new_entrypoint.id, &mut *tx).await?
+                .map(|entrypoint| (entrypoint.uuid, entrypoint.preferences_user_data, entrypoint.actions_user_data, entrypoint.enabled, entrypoint.hidden, entrypoint.position))
+                .unwrap_or((Uuid::new_v4().to_string(), HashMap::new(), vec![], true, false, None));
 
             // language=SQLite
-            sqlx::query("INSERT OR REPLACE INTO plugin_entrypoint (id, plugin_id, name, enabled, type, preferences, preferences_user_data, description, actions, actions_user_data, icon_path, uuid) VALUES(?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)")
+            sqlx::query("INSERT OR REPLACE INTO plugin_entrypoint (id, plugin_id, name, enabled, type, preferences, preferences_user_data, description, actions, actions_user_data, icon_path, uuid, arguments, translations, subtitle, keywords, accessory, hidden, position) VALUES(?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19)")
                 .bind(&new_entrypoint.id)
                 .bind(&new_plugin.id)
                 .bind(new_entrypoint.name)
@@ -1087,6 +1587,13 @@ impl DataDbRepository {
                 .bind(Json(actions_user_data))
                 .bind(new_entrypoint.icon_path)
                 .bind(uuid)
+                .bind(Json(new_entrypoint.arguments))
+                .bind(Json(new_entrypoint.translations))
+                .bind(new_entrypoint.subtitle)
+                .bind(Json(new_entrypoint.keywords))
+                .bind(new_entrypoint.accessory)
+                .bind(hidden)
+                .bind(position)
                 .execute(&mut *tx)
                 .await?;
         }
@@ -1106,10 +1613,11 @@ impl DataDbRepository {
             old_asset_data_paths.remove(&data.path);
 
             // language=SQLite
-            sqlx::query("INSERT OR REPLACE INTO plugin_asset_data (plugin_id, path, data) VALUES(?1, ?2, ?3)")
+            sqlx::query("INSERT OR REPLACE INTO plugin_asset_data (plugin_id, path, data, hash) VALUES(?1, ?2, ?3, ?4)")
                 .bind(&new_plugin.id)
                 .bind(&data.path)
                 .bind(&data.data)
+                .bind(&data.hash)
                 .execute(&mut *tx)
                 .await?;
         }
@@ -1135,7 +1643,8 @@ pub fn db_entrypoint_to_str(value: DbPluginEntrypointType) -> &'static str {
         DbPluginEntrypointType::Command => "command",
         DbPluginEntrypointType::View => "view",
         DbPluginEntrypointType::InlineView => "inline-view",
-        DbPluginEntrypointType::EntrypointGenerator => "command-generator" // command-generator in db for backwards compatibility
+        DbPluginEntrypointType::EntrypointGenerator => "command-generator", // command-generator in db for backwards compatibility
+        DbPluginEntrypointType::SearchProvider => "search-provider",
     }
 }
 
@@ -1145,6 +1654,7 @@ pub fn db_entrypoint_from_str(value: &str) -> DbPluginEntrypointType {
         "view" => DbPluginEntrypointType::View,
         "inline-view" => DbPluginEntrypointType::InlineView,
         "command-generator" => DbPluginEntrypointType::EntrypointGenerator,
+        "search-provider" => DbPluginEntrypointType::SearchProvider,
         _ => panic!("illegal entrypoint_type: {}", value)
     }
 }
@@ -0,0 +1,413 @@
+use std::collections::{BTreeMap, HashMap};
+use std::io::{Read, Seek, SeekFrom};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::Context;
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+
+use gauntlet_common::model::{DevConsoleEvent, DevConsoleEventKind, EntrypointId, PluginId, RootWidget, UiRenderLocation};
+
+use crate::model::IntermediateUiEvent;
+
+const LOG_FILE_POLL_INTERVAL: Duration = Duration::from_millis(200);
+// amount of changed widgets listed in render message, the rest is only counted
+const MAX_LISTED_WIDGET_CHANGES: usize = 20;
+
+// collects console output, render commits and view events of plugins
+// and streams them to dev console clients, nothing is collected while no client is listening
+#[derive(Clone)]
+pub struct DevConsole {
+    sender: broadcast::Sender<DevConsoleEvent>,
+    // notified when client starts listening, log files are not followed while there are no clients
+    client_subscribed: Arc<Notify>,
+    // widgets of previous render per plugin, entrypoint and render location, used to produce render diff
+    previous_renders: Arc<Mutex<HashMap<(PluginId, EntrypointId, UiRenderLocation), BTreeMap<u64, RenderedWidget>>>>,
+}
+
+// widget with its children replaced by their ids, so only its own properties are compared
+#[derive(PartialEq)]
+struct RenderedWidget {
+    kind: String,
+    properties: serde_json::Map<String, serde_json::Value>,
+}
+
+impl DevConsole {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(1000);
+
+        Self {
+            sender,
+            client_subscribed: Arc::new(Notify::new()),
+            previous_renders: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn subscribe(&self, plugin_id: PluginId) -> tokio::sync::mpsc::Receiver<DevConsoleEvent> {
+        let mut receiver = self.sender.subscribe();
+        let (sender, result) = tokio::sync::mpsc::channel(100);
+
+        self.client_subscribed.notify_waiters();
+
+        tokio::spawn(async move {
+            loop {
+                let event = tokio::select! {
+                    _ = sender.closed() => break,
+                    event = receiver.recv() => event
+                };
+
+                let event = match event {
+                    Ok(event) => event,
+                    Err(RecvError::Lagged(count)) => {
+                        tracing::warn!("Dev console client is lagging behind, skipped {} events", count);
+                        continue
+                    }
+                    Err(RecvError::Closed) => break,
+                };
+
+                if event.plugin_id != plugin_id {
+                    continue
+                }
+
+                if sender.send(event).await.is_err() {
+                    break
+                }
+            }
+        });
+
+        result
+    }
+
+    fn is_listening(&self) -> bool {
+        self.sender.receiver_count() > 0
+    }
+
+    fn send(&self, plugin_id: PluginId, kind: DevConsoleEventKind, message: String) {
+        // error only means that there are no clients listening
+        let _ = self.sender.send(DevConsoleEvent { plugin_id, kind, message });
+    }
+
+    pub fn render(&self, plugin_id: &PluginId, entrypoint_id: &EntrypointId, entrypoint_name: &str, render_location: &UiRenderLocation, container: &RootWidget) {
+        if !self.is_listening() {
+            // renders which happened while nobody was listening are not known, so the next one is shown as initial
+            self.previous_renders.lock().unwrap().clear();
+            return
+        }
+
+        let widgets = match serde_json::to_value(container) {
+            Ok(value) => {
+                let mut widgets = BTreeMap::new();
+                collect_widgets(&value, "root", &mut widgets);
+                widgets
+            }
+            Err(err) => {
+                tracing::warn!("Unable to diff render for dev console: {:?}", err);
+                return
+            }
+        };
+
+        let widget_count = widgets.len();
+
+        let changes = {
+            let mut previous_renders = self.previous_renders.lock().unwrap();
+
+            let key = (plugin_id.clone(), entrypoint_id.clone(), *render_location);
+
+            let changes = previous_renders.get(&key)
+                .map(|previous_widgets| diff_widgets(previous_widgets, &widgets));
+
+            previous_renders.insert(key, widgets);
+
+            changes
+        };
+
+        let render_location = match render_location {
+            UiRenderLocation::InlineView => "inline view",
+            UiRenderLocation::View => "view",
+        };
+
+        let mut message = format!("Rendered {} of '{}': {} widgets", render_location, entrypoint_name, widget_count);
+
+        match changes {
+            None => message.push_str(" (initial)"),
+            Some(changes) if changes.is_empty() => message.push_str(", nothing changed"),
+            Some(changes) => {
+                let added = changes.iter().filter(|change| change.starts_with('+')).count();
+                let removed = changes.iter().filter(|change| change.starts_with('-')).count();
+                let changed = changes.len() - added - removed;
+
+                message.push_str(&format!(", {} added, {} removed, {} changed", added, removed, changed));
+
+                for change in changes.iter().take(MAX_LISTED_WIDGET_CHANGES) {
+                    message.push_str("\n  ");
+                    message.push_str(change);
+                }
+
+                if changes.len() > MAX_LISTED_WIDGET_CHANGES {
+                    message.push_str(&format!("\n  ... and {} more", changes.len() - MAX_LISTED_WIDGET_CHANGES));
+                }
+            }
+        }
+
+        self.send(plugin_id.clone(), DevConsoleEventKind::Render, message)
+    }
+
+    pub fn view_event(&self, plugin_id: &PluginId, event: &IntermediateUiEvent) {
+        if !self.is_listening() {
+            return
+        }
+
+        let message = match event {
+            IntermediateUiEvent::HandleViewEvent { widget_id, event_name, event_arguments } => {
+                format!("Event '{}' on widget {} with arguments {:?}", event_name, widget_id, event_arguments)
+            }
+            event => format!("{:?}", event)
+        };
+
+        self.send(plugin_id.clone(), DevConsoleEventKind::ViewEvent, message)
+    }
+
+    // console output of dev plugins is written to files by plugin runtime process, so follow those
+    pub fn follow_log_files(&self, plugin_id: PluginId, stdout_file: PathBuf, stderr_file: PathBuf) -> LogFilesFollower {
+        let dev_console = self.clone();
+
+        let handle = tokio::spawn(async move {
+            let mut log_files = [
+                (LogFileTail::new(stdout_file), DevConsoleEventKind::Stdout),
+                (LogFileTail::new(stderr_file), DevConsoleEventKind::Stderr),
+            ];
+
+            loop {
+                // output written while nobody was listening is skipped, only new output is streamed
+                if !dev_console.is_listening() {
+                    let client_subscribed = dev_console.client_subscribed.notified();
+                    tokio::pin!(client_subscribed);
+                    client_subscribed.as_mut().enable();
+
+                    for (log_file, _) in &mut log_files {
+                        let _ = log_file.read_lines(false);
+                    }
+
+                    if !dev_console.is_listening() {
+                        client_subscribed.await;
+                    }
+
+                    continue
+                }
+
+                tokio::time::sleep(LOG_FILE_POLL_INTERVAL).await;
+
+                let listening = dev_console.is_listening();
+
+                for (log_file, kind) in &mut log_files {
+                    match log_file.read_lines(listening) {
+                        Ok(lines) => {
+                            for line in lines {
+                                dev_console.send(plugin_id.clone(), *kind, line)
+                            }
+                        }
+                        Err(err) => {
+                            tracing::warn!("Unable to read plugin log file for dev console: {:?}", err);
+                        }
+                    }
+                }
+            }
+        });
+
+        LogFilesFollower {
+            handle
+        }
+    }
+}
+
+// stops following when plugin runtime is stopped
+pub struct LogFilesFollower {
+    handle: JoinHandle<()>,
+}
+
+impl Drop for LogFilesFollower {
+    fn drop(&mut self) {
+        self.handle.abort()
+    }
+}
+
+struct LogFileTail {
+    path: PathBuf,
+    offset: u64,
+    incomplete_line: String,
+}
+
+impl LogFileTail {
+    fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            offset: 0,
+            incomplete_line: String::new(),
+        }
+    }
+
+    fn read_lines(&mut self, listening: bool) -> anyhow::Result<Vec<String>> {
+        let mut file = std::fs::File::open(&self.path)
+            .context("Unable to open log file")?;
+
+        let len = file.metadata()
+            .context("Unable to read log file metadata")?
+            .len();
+
+        // file is recreated every time plugin is started
+        if len < self.offset {
+            self.offset = 0;
+            self.incomplete_line.clear();
+        }
+
+        if !listening {
+            self.offset = len;
+            self.incomplete_line.clear();
+            return Ok(vec![])
+        }
+
+        file.seek(SeekFrom::Start(self.offset))
+            .context("Unable to seek log file")?;
+
+        let mut buffer = vec![];
+        file.read_to_end(&mut buffer)
+            .context("Unable to read log file")?;
+
+        self.offset += buffer.len() as u64;
+        self.incomplete_line.push_str(&String::from_utf8_lossy(&buffer));
+
+        let mut lines = vec![];
+
+        while let Some(index) = self.incomplete_line.find('\n') {
+            let line = self.incomplete_line.drain(..=index).collect::<String>();
+
+            lines.push(line.trim_end_matches(['\n', '\r']).to_string());
+        }
+
+        Ok(lines)
+    }
+}
+
+// every widget is serialized with its id, widgets which are members of union are also serialized with their type,
+// for others name of the property they are in is used
+fn collect_widgets(value: &serde_json::Value, kind: &str, widgets: &mut BTreeMap<u64, RenderedWidget>) {
+    match value {
+        serde_json::Value::Object(object) => {
+            if let Some(id) = object.get("__id__").and_then(|id| id.as_u64()) {
+                let kind = object.get("__type__")
+                    .and_then(|kind| kind.as_str())
+                    .map(|kind| kind.trim_start_matches("gauntlet:"))
+                    .unwrap_or(kind);
+
+                let properties = object.iter()
+                    .filter(|(name, _)| name.as_str() != "__id__" && name.as_str() != "__type__")
+                    .map(|(name, value)| (name.clone(), without_child_widgets(value)))
+                    .collect();
+
+                widgets.insert(id, RenderedWidget { kind: kind.to_string(), properties });
+            }
+
+            for (name, value) in object {
+                collect_widgets(value, name, widgets);
+            }
+        }
+        serde_json::Value::Array(array) => {
+            for value in array {
+                collect_widgets(value, kind, widgets);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn without_child_widgets(value: &serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(object) => {
+            match object.get("__id__") {
+                Some(id) => id.clone(),
+                None => object.iter()
+                    .map(|(name, value)| (name.clone(), without_child_widgets(value)))
+                    .collect(),
+            }
+        }
+        serde_json::Value::Array(array) => array.iter().map(without_child_widgets).collect(),
+        value => value.clone()
+    }
+}
+
+// one line per added, removed or changed widget, widgets are matched by id which stays the same between renders
+fn diff_widgets(previous: &BTreeMap<u64, RenderedWidget>, current: &BTreeMap<u64, RenderedWidget>) -> Vec<String> {
+    let mut changes = vec![];
+
+    for (id, widget) in current {
+        match previous.get(id) {
+            None => changes.push(format!("+ {} #{}", widget.kind, id)),
+            Some(previous_widget) if previous_widget != widget => {
+                let changed_properties = widget.properties.keys()
+                    .chain(previous_widget.properties.keys().filter(|name| !widget.properties.contains_key(*name)))
+                    .filter(|name| widget.properties.get(*name) != previous_widget.properties.get(*name))
+                    .map(|name| name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                changes.push(format!("~ {} #{}: {}", widget.kind, id, changed_properties))
+            }
+            Some(_) => {}
+        }
+    }
+
+    for (id, widget) in previous {
+        if !current.contains_key(id) {
+            changes.push(format!("- {} #{}", widget.kind, id));
+        }
+    }
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn widgets(value: serde_json::Value) -> BTreeMap<u64, RenderedWidget> {
+        let mut widgets = BTreeMap::new();
+        collect_widgets(&value, "root", &mut widgets);
+        widgets
+    }
+
+    #[test]
+    fn diff_lists_added_removed_and_changed_widgets() {
+        let previous = widgets(serde_json::json!({
+            "content": [{
+                "__id__": 1,
+                "__type__": "gauntlet:list",
+                "children": [
+                    { "__id__": 2, "__type__": "gauntlet:list_item", "title": "First" },
+                    { "__id__": 3, "__type__": "gauntlet:list_item", "title": "Second" }
+                ]
+            }]
+        }));
+
+        let current = widgets(serde_json::json!({
+            "content": [{
+                "__id__": 1,
+                "__type__": "gauntlet:list",
+                "children": [
+                    { "__id__": 2, "__type__": "gauntlet:list_item", "title": "First", "subtitle": "Updated" },
+                    { "__id__": 4, "__type__": "gauntlet:list_item", "title": "Third" }
+                ]
+            }]
+        }));
+
+        assert_eq!(diff_widgets(&previous, &current), vec![
+            "~ list #1: children".to_string(),
+            "~ list_item #2: subtitle".to_string(),
+            "+ list_item #4".to_string(),
+            "- list_item #3".to_string(),
+        ]);
+    }
+}
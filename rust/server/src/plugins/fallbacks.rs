@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use anyhow::anyhow;
+use serde::Deserialize;
+
+use gauntlet_common::model::{EntrypointId, Icons, PluginId, SearchResult, SearchResultAccessory, SearchResultEntrypointType};
+
+use crate::plugins::data_db_repository::{db_entrypoint_from_str, DataDbRepository, DbPluginEntrypointType};
+use crate::plugins::open_targets::parse_url;
+use crate::plugins::quicklinks::Quicklinks;
+
+pub const FALLBACKS_PLUGIN_ID: &str = "builtin://fallbacks";
+const FALLBACKS_PLUGIN_NAME: &str = "Fallbacks";
+const OPEN_URL_ENTRYPOINT_ID: &str = "open-url";
+
+// shown in configured order when nothing else matched the query
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum SearchFallback {
+    Builtin {
+        builtin: BuiltinSearchFallback,
+    },
+    // command entrypoint of any plugin, query is passed to it as first argument
+    Entrypoint {
+        plugin: String,
+        entrypoint: String,
+    },
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum BuiltinSearchFallback {
+    // quicklinks marked as fallback in settings
+    #[serde(rename = "quicklinks")]
+    Quicklinks,
+    // only shown if query looks like url or domain name
+    #[serde(rename = "open-url")]
+    OpenUrl,
+}
+
+pub fn default_search_fallbacks() -> Vec<SearchFallback> {
+    vec![
+        SearchFallback::Builtin { builtin: BuiltinSearchFallback::Quicklinks },
+        SearchFallback::Builtin { builtin: BuiltinSearchFallback::OpenUrl },
+    ]
+}
+
+enum ResolvedFallback {
+    OpenUrl {
+        url: String,
+    },
+    Entrypoint {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        arguments: Vec<String>,
+    },
+}
+
+pub enum FallbackRun {
+    Done,
+    Entrypoint {
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        arguments: Vec<String>,
+    },
+}
+
+#[derive(Clone)]
+pub struct Fallbacks {
+    repository: DataDbRepository,
+    quicklinks: Quicklinks,
+    // fallbacks resolved during last search, keyed by entrypoint id of search result
+    resolved: Arc<Mutex<HashMap<String, ResolvedFallback>>>,
+}
+
+impl Fallbacks {
+    pub fn new(repository: DataDbRepository, quicklinks: Quicklinks) -> Self {
+        Self {
+            repository,
+            quicklinks,
+            resolved: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn is_fallback(plugin_id: &PluginId) -> bool {
+        plugin_id.to_string() == FALLBACKS_PLUGIN_ID
+    }
+
+    pub async fn search(&self, text: &str, fallbacks: &[SearchFallback]) -> anyhow::Result<Vec<SearchResult>> {
+        let text = text.trim();
+
+        let mut resolved = HashMap::new();
+        let mut result = vec![];
+
+        if !text.is_empty() {
+            for (index, fallback) in fallbacks.iter().enumerate() {
+                match fallback {
+                    SearchFallback::Builtin { builtin: BuiltinSearchFallback::Quicklinks } => {
+                        result.extend(self.quicklinks.search_fallback(text));
+                    }
+                    SearchFallback::Builtin { builtin: BuiltinSearchFallback::OpenUrl } => {
+                        let Some(url) = parse_url(text) else {
+                            continue;
+                        };
+
+                        let accessory = SearchResultAccessory::IconAccessory {
+                            icon: Icons::Link,
+                            tooltip: Some("Open in browser".to_string()),
+                        };
+
+                        result.push(fallback_search_result(OPEN_URL_ENTRYPOINT_ID, format!("Open {}", url), vec![accessory]));
+
+                        resolved.insert(OPEN_URL_ENTRYPOINT_ID.to_string(), ResolvedFallback::OpenUrl { url });
+                    }
+                    SearchFallback::Entrypoint { plugin, entrypoint } => {
+                        let Some(db_plugin) = self.repository.get_plugin_by_id_option(plugin).await? else {
+                            continue;
+                        };
+
+                        let Some(db_entrypoint) = self.repository.get_entrypoint_by_id_option(plugin, entrypoint).await? else {
+                            continue;
+                        };
+
+                        if !db_plugin.enabled || !db_entrypoint.enabled {
+                            continue;
+                        }
+
+                        if !matches!(db_entrypoint_from_str(&db_entrypoint.entrypoint_type), DbPluginEntrypointType::Command) {
+                            tracing::warn!("Only command entrypoints can be used as search fallback: {}", entrypoint);
+                            continue;
+                        }
+
+                        // commands without arguments are run as is
+                        let arguments = if db_entrypoint.arguments.is_empty() {
+                            vec![]
+                        } else {
+                            vec![text.to_string()]
+                        };
+
+                        let id = format!("entrypoint-{}", index);
+
+                        let accessory = SearchResultAccessory::TextAccessory {
+                            text: db_plugin.name,
+                            icon: None,
+                            tooltip: None,
+                        };
+
+                        result.push(fallback_search_result(&id, format!("{} \"{}\"", db_entrypoint.localized_name(), text), vec![accessory]));
+
+                        resolved.insert(id, ResolvedFallback::Entrypoint {
+                            plugin_id: PluginId::from_string(plugin),
+                            entrypoint_id: EntrypointId::from_string(entrypoint),
+                            arguments,
+                        });
+                    }
+                }
+            }
+        }
+
+        *self.resolved.lock().unwrap() = resolved;
+
+        Ok(result)
+    }
+
+    // plugin entrypoints are run by caller, the same way as when they are run by deep link
+    pub fn run(&self, entrypoint_id: &EntrypointId) -> anyhow::Result<FallbackRun> {
+        let resolved = self.resolved.lock().unwrap();
+
+        let fallback = resolved.get(&entrypoint_id.to_string())
+            .ok_or_else(|| anyhow!("Unknown fallback: {}", entrypoint_id.to_string()))?;
+
+        match fallback {
+            ResolvedFallback::OpenUrl { url } => {
+                tracing::info!("Opening fallback url: {}", url);
+
+                open::that_detached(url)?;
+
+                Ok(FallbackRun::Done)
+            }
+            ResolvedFallback::Entrypoint { plugin_id, entrypoint_id, arguments } => {
+                Ok(FallbackRun::Entrypoint {
+                    plugin_id: plugin_id.clone(),
+                    entrypoint_id: entrypoint_id.clone(),
+                    arguments: arguments.clone(),
+                })
+            }
+        }
+    }
+}
+
+fn fallback_search_result(entrypoint_id: &str, entrypoint_name: String, entrypoint_accessories: Vec<SearchResultAccessory>) -> SearchResult {
+    SearchResult {
+        plugin_id: PluginId::from_string(FALLBACKS_PLUGIN_ID),
+        plugin_name: FALLBACKS_PLUGIN_NAME.to_string(),
+        entrypoint_id: EntrypointId::from_string(entrypoint_id),
+        entrypoint_name,
+        entrypoint_name_highlights: vec![],
+        entrypoint_subtitle: None,
+        entrypoint_matched_keywords: vec![],
+        entrypoint_generator_name: None,
+        entrypoint_icon: None,
+        entrypoint_type: SearchResultEntrypointType::Command,
+        entrypoint_actions: vec![],
+        entrypoint_accessories,
+        entrypoint_arguments: vec![],
+        entrypoint_file: None,
+    }
+}
@@ -0,0 +1,75 @@
+use std::io::Read;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+use gauntlet_common::model::SearchResultPreview;
+
+const PREVIEW_TEXT_LIMIT: u64 = 4 * 1024;
+const PREVIEW_FOLDER_ENTRIES_LIMIT: usize = 50;
+
+// only beginning of file is read, so that preview of large files is fast
+pub fn path_preview(path: &Path) -> anyhow::Result<SearchResultPreview> {
+    let metadata = std::fs::metadata(path)?;
+
+    if metadata.is_dir() {
+        let mut entries = vec![];
+
+        for entry in std::fs::read_dir(path)? {
+            let entry = entry?;
+
+            let name = entry.file_name().to_string_lossy().to_string();
+
+            if entry.file_type().is_ok_and(|file_type| file_type.is_dir()) {
+                entries.push(format!("{}/", name));
+            } else {
+                entries.push(name);
+            }
+        }
+
+        entries.sort_by_key(|name| (!name.ends_with('/'), name.to_lowercase()));
+
+        let entry_count = entries.len();
+
+        entries.truncate(PREVIEW_FOLDER_ENTRIES_LIMIT);
+
+        return Ok(SearchResultPreview::Folder {
+            path: path.display().to_string(),
+            entries,
+            entry_count,
+        })
+    }
+
+    let modified = metadata.modified()
+        .ok()
+        .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+        .map(|modified| modified.as_secs());
+
+    let mut bytes = vec![];
+
+    std::fs::File::open(path)?
+        .take(PREVIEW_TEXT_LIMIT)
+        .read_to_end(&mut bytes)?;
+
+    Ok(SearchResultPreview::File {
+        path: path.display().to_string(),
+        size: metadata.len(),
+        modified,
+        content: text_content(bytes),
+    })
+}
+
+// files with null bytes or invalid utf-8 are considered binary,
+// character cut by the read limit at the end is dropped
+pub fn text_content(mut bytes: Vec<u8>) -> Option<String> {
+    if bytes.contains(&0) {
+        return None;
+    }
+
+    match std::str::from_utf8(&bytes) {
+        Ok(_) => {}
+        Err(err) if err.error_len().is_none() => bytes.truncate(err.valid_up_to()),
+        Err(_) => return None,
+    }
+
+    String::from_utf8(bytes).ok()
+}
@@ -0,0 +1,54 @@
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+use gauntlet_common::model::PluginId;
+
+// while focus mode is enabled plugins don't do any work in background,
+// inline views, search providers and plugin notifications are suspended.
+// search index refresh requests are deferred until focus mode is disabled
+#[derive(Clone)]
+pub struct FocusMode {
+    inner: Arc<Mutex<FocusModeInner>>,
+}
+
+#[derive(Default)]
+struct FocusModeInner {
+    enabled: bool,
+    pending_search_index_refresh: HashSet<PluginId>,
+}
+
+impl FocusMode {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(FocusModeInner::default())),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.inner.lock().expect("lock is poisoned").enabled
+    }
+
+    // returns plugins which requested search index refresh while focus mode was enabled
+    pub fn set_enabled(&self, enabled: bool) -> Vec<PluginId> {
+        let mut inner = self.inner.lock().expect("lock is poisoned");
+
+        inner.enabled = enabled;
+
+        if enabled {
+            vec![]
+        } else {
+            inner.pending_search_index_refresh.drain().collect()
+        }
+    }
+
+    // returns true if refresh should be deferred
+    pub fn defer_search_index_refresh(&self, plugin_id: &PluginId) -> bool {
+        let mut inner = self.inner.lock().expect("lock is poisoned");
+
+        if inner.enabled {
+            inner.pending_search_index_refresh.insert(plugin_id.clone());
+        }
+
+        inner.enabled
+    }
+}
@@ -1,27 +1,30 @@
 use std::collections::HashMap;
-use gauntlet_common::model::{ImageLike, ImageSource, ImageSourceAsset, ImageSourceUrl, RootWidget, UiWidgetId, WidgetVisitor};
-use gauntlet_plugin_runtime::BackendForPluginRuntimeApi;
-use crate::plugins::js::BackendForPluginRuntimeApiImpl;
+use anyhow::anyhow;
+use base64::prelude::*;
+use gauntlet_common::model::{ImageHash, ImageLike, ImageSource, ImageSourceAsset, ImageSourceUrl, PluginId, RootWidget, UiWidgetId, WidgetVisitor};
+use crate::plugins::image_store::ImageStore;
 use futures::StreamExt;
 use std::io::Read;
 
 pub struct ImageGatherer<'a> {
-    api: &'a BackendForPluginRuntimeApiImpl,
-    image_sources: HashMap<UiWidgetId, anyhow::Result<Vec<u8>>>
+    image_store: &'a ImageStore,
+    plugin_id: &'a PluginId,
+    image_sources: HashMap<UiWidgetId, anyhow::Result<ImageHash>>
 }
 
 impl<'a> WidgetVisitor for ImageGatherer<'a> {
     async fn image(&mut self, widget_id: UiWidgetId, widget: &ImageLike) {
         if let ImageLike::ImageSource(image_source) = &widget {
-            self.image_sources.insert(widget_id, get_image_date(&self.api, image_source).await);
+            self.image_sources.insert(widget_id, get_image_hash(&self.image_store, &self.plugin_id, image_source).await);
         }
     }
 }
 
 impl<'a> ImageGatherer<'a> {
-    pub async fn run_gatherer(api: &'a BackendForPluginRuntimeApiImpl, root_widget: &RootWidget) -> anyhow::Result<HashMap<UiWidgetId, Vec<u8>>> {
+    pub async fn run_gatherer(image_store: &'a ImageStore, plugin_id: &'a PluginId, root_widget: &RootWidget) -> anyhow::Result<HashMap<UiWidgetId, ImageHash>> {
         let mut gatherer = Self {
-            api,
+            image_store,
+            plugin_id,
             image_sources: HashMap::new()
         };
 
@@ -34,25 +37,39 @@ impl<'a> ImageGatherer<'a> {
     }
 }
 
-async fn get_image_date(api: &BackendForPluginRuntimeApiImpl, source: &ImageSource) -> anyhow::Result<Vec<u8>> {
+async fn get_image_hash(image_store: &ImageStore, plugin_id: &PluginId, source: &ImageSource) -> anyhow::Result<ImageHash> {
     match source {
         ImageSource::ImageSourceAsset(ImageSourceAsset { asset }) => {
-            let bytes = api.get_asset_data(&asset).await?;
-
-            Ok(bytes)
+            image_store.asset_hash(&plugin_id.to_string(), &asset).await
         }
         ImageSource::ImageSourceUrl(ImageSourceUrl { url }) => {
-            // FIXME implement error handling so it doesn't error whole view
-            // TODO implement caching
-
-            let bytes = ureq::get(&url)
-                .call()?
-                .into_reader()
-                .bytes()
-                .collect::<std::io::Result<Vec<u8>>>()?
-                .into();
+            let bytes = get_url_data(url)?;
 
-            Ok(bytes)
+            Ok(image_store.insert(bytes))
         }
     }
 }
+
+fn get_url_data(url: &str) -> anyhow::Result<Vec<u8>> {
+    if url.starts_with("data:") {
+        // "data:image/png;base64,<data>", only base64 encoded data urls are supported
+        let (_, data) = url.split_once(";base64,")
+            .ok_or(anyhow!("Only base64 encoded data urls are supported"))?;
+
+        let bytes = BASE64_STANDARD.decode(data)?;
+
+        Ok(bytes)
+    } else {
+        // FIXME implement error handling so it doesn't error whole view
+        // TODO implement caching
+
+        let bytes = ureq::get(&url)
+            .call()?
+            .into_reader()
+            .bytes()
+            .collect::<std::io::Result<Vec<u8>>>()?
+            .into();
+
+        Ok(bytes)
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use sha2::{Digest, Sha256};
+
+use gauntlet_common::model::ImageHash;
+
+use crate::plugins::data_db_repository::DataDbRepository;
+
+// enough for a few views with images in list items, frontend requests images right after render
+const IMAGE_LIMIT: usize = 512;
+
+// content addressed storage of images referenced by rendered views.
+// plugin assets are already stored in database with their hash,
+// only images which are not assets (urls, data urls) need to be kept in memory
+#[derive(Clone)]
+pub struct ImageStore {
+    repository: DataDbRepository,
+    inner: Arc<Mutex<ImageStoreInner>>,
+}
+
+struct ImageStoreInner {
+    images: HashMap<ImageHash, Arc<Vec<u8>>>,
+    order: VecDeque<ImageHash>,
+}
+
+impl ImageStore {
+    pub fn new(repository: DataDbRepository) -> Self {
+        Self {
+            repository,
+            inner: Arc::new(Mutex::new(ImageStoreInner {
+                images: HashMap::new(),
+                order: VecDeque::new(),
+            })),
+        }
+    }
+
+    pub fn insert(&self, data: Vec<u8>) -> ImageHash {
+        let hash = ImageHash::from_string(content_hash(&data));
+
+        let mut inner = self.inner.lock().unwrap();
+
+        if inner.images.contains_key(&hash) {
+            return hash
+        }
+
+        if inner.order.len() >= IMAGE_LIMIT {
+            if let Some(oldest) = inner.order.pop_front() {
+                inner.images.remove(&oldest);
+            }
+        }
+
+        inner.images.insert(hash.clone(), Arc::new(data));
+        inner.order.push_back(hash.clone());
+
+        hash
+    }
+
+    pub async fn asset_hash(&self, plugin_id: &str, path: &str) -> anyhow::Result<ImageHash> {
+        match self.repository.get_asset_hash(plugin_id, path).await? {
+            Some(hash) => Ok(ImageHash::from_string(hash)),
+            None => {
+                let data = self.repository.get_asset_data(plugin_id, path).await?;
+
+                Ok(self.insert(data))
+            }
+        }
+    }
+
+    pub async fn get(&self, hashes: Vec<ImageHash>) -> anyhow::Result<HashMap<ImageHash, Vec<u8>>> {
+        let mut result = HashMap::new();
+
+        for hash in hashes {
+            let data = self.inner.lock().unwrap().images.get(&hash).cloned();
+
+            let data = match data {
+                Some(data) => Some(data.as_ref().clone()),
+                None => self.repository.get_asset_data_by_hash(&hash.to_string()).await?,
+            };
+
+            match data {
+                Some(data) => {
+                    result.insert(hash, data);
+                }
+                None => {
+                    tracing::warn!("requested image with hash {} is not available", hash);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+pub fn content_hash(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
@@ -0,0 +1,68 @@
+use std::time::Duration;
+
+use anyhow::Context;
+use enigo::{Direction, Enigo, Key, Keyboard, Settings};
+
+use gauntlet_common::rpc::frontend_api::FrontendApi;
+
+use crate::plugins::clipboard::Clipboard;
+
+// time for window manager to move focus back to previously active application after main window is hidden
+const FOCUS_RETURN_DELAY: Duration = Duration::from_millis(150);
+
+#[derive(Clone)]
+pub struct InputInjection {
+    clipboard: Clipboard,
+    frontend_api: FrontendApi,
+}
+
+impl InputInjection {
+    pub fn new(clipboard: Clipboard, frontend_api: FrontendApi) -> Self {
+        Self {
+            clipboard,
+            frontend_api,
+        }
+    }
+
+    // text is put into clipboard, main window is hidden and paste shortcut is sent to application which gets focus.
+    // sensitive text is removed from clipboard after configured timeout
+    pub async fn paste_text(&self, text: String, sensitive: bool) -> anyhow::Result<()> {
+        if sensitive {
+            self.clipboard.write_sensitive_text(text)?;
+        } else {
+            self.clipboard.write_text(text)?;
+        }
+
+        self.frontend_api.hide_window().await?;
+
+        tokio::time::sleep(FOCUS_RETURN_DELAY).await;
+
+        tokio::task::spawn_blocking(send_paste_shortcut).await??;
+
+        Ok(())
+    }
+}
+
+// x11 is used on linux, so on wayland this only works for applications running under xwayland
+fn send_paste_shortcut() -> anyhow::Result<()> {
+    let mut enigo = Enigo::new(&Settings::default())
+        .context("Unable to initialize input injection")?;
+
+    #[cfg(target_os = "macos")]
+    let modifier = Key::Meta;
+
+    #[cfg(not(target_os = "macos"))]
+    let modifier = Key::Control;
+
+    enigo.key(modifier, Direction::Press)
+        .context("Unable to send paste shortcut")?;
+
+    let result = enigo.key(Key::Unicode('v'), Direction::Click)
+        .context("Unable to send paste shortcut");
+
+    // modifier is released even if key click failed, so it doesn't stay pressed
+    enigo.key(modifier, Direction::Release)
+        .context("Unable to send paste shortcut")?;
+
+    result
+}
@@ -1,5 +1,5 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::hash::Hash;
 use std::io;
@@ -9,7 +9,7 @@ use std::pin::Pin;
 use std::rc::Rc;
 use std::str::FromStr;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::{anyhow, Context};
 use futures::AsyncBufReadExt;
@@ -25,18 +25,29 @@ use tokio::sync::Mutex;
 use tokio::task::spawn_blocking;
 use tokio_util::sync::CancellationToken;
 use gauntlet_common::dirs::Dirs;
-use gauntlet_common::model::{EntrypointId, KeyboardEventOrigin, PhysicalKey, PluginId, RootWidget, SearchResultAccessory, SearchResultEntrypointType, UiPropertyValue, UiRenderLocation, UiWidgetId};
+use gauntlet_common::model::{EntrypointArgument, EntrypointArgumentType, EntrypointId, KeyboardEventOrigin, OnboardingPreference, PhysicalKey, PhysicalShortcut, PluginId, PreferenceEnumValue, RootWidget, SearchResult, SearchResultAccessory, SearchResultEntrypointAction, SearchResultEntrypointActionType, SearchResultEntrypointType, UiPropertyValue, UiRenderLocation, UiWidgetId};
 use gauntlet_common::rpc::frontend_api::FrontendApi;
 use gauntlet_common::settings_env_data_to_string;
-use gauntlet_plugin_runtime::{recv_message, send_message, BackendForPluginRuntimeApi, JsGeneratedSearchItem, JsClipboardData, JsInit, JsKeyboardEventOrigin, JsPluginCode, JsPluginPermissions, JsPreferenceUserData, JsEvent, JsUiPropertyValue, JsRequest, JsUiRenderLocation, JsResponse, JsMessage, JsPluginPermissionsFileSystem, JsPluginPermissionsExec, JsPluginPermissionsMainSearchBar, JsMessageSide, JsPluginRuntimeMessage, JsGeneratedSearchItemAccessory, JsGeneratedSearchItemActionType};
+use gauntlet_plugin_runtime::{recv_message, send_message, BackendForPluginRuntimeApi, JsGeneratedSearchItem, JsClipboardData, JsInit, JsKeyboardEventOrigin, JsPluginCode, JsPluginPermissions, JsPreferenceUserData, JsEvent, JsUiPropertyValue, JsRequest, JsUiRenderLocation, JsResponse, JsMessage, JsPluginPermissionsFileSystem, JsPluginPermissionsExec, JsPluginPermissionsMainSearchBar, JsMessageSide, JsPluginHttpCache, JsOAuthConfig, JsOAuthTokens, JsPluginRuntimeMessage, JsGeneratedSearchItemAccessory, JsGeneratedSearchItemActionType};
 use crate::model::{IntermediateUiEvent};
 use crate::plugins::clipboard::Clipboard;
-use crate::plugins::data_db_repository::{db_entrypoint_from_str, DataDbRepository, DbPluginClipboardPermissions, DbPluginEntrypointType, DbPluginPreference, DbPluginPreferenceUserData, DbReadPlugin, DbReadPluginEntrypoint};
+use crate::plugins::dev_console::DevConsole;
+use crate::plugins::focus_mode::FocusMode;
+use crate::plugins::notifications::Notifications;
+use crate::plugins::input_injection::InputInjection;
+use crate::plugins::terminal::Terminal;
+use crate::plugins::oauth::{OAuth, OAuthConfig};
+use crate::plugins::metrics::Metrics;
+use crate::plugins::image_store::ImageStore;
+use crate::plugins::view_updates::{PendingRender, ViewUpdates, VIEW_UPDATE_FLUSH_INTERVAL};
+use crate::plugins::data_db_repository::{db_entrypoint_from_str, DataDbRepository, DbPluginArgument, DbPluginClipboardPermissions, DbPluginEntrypointType, DbPluginPreference, DbPluginPreferenceUserData, DbPreferenceEnumValue, DbReadPlugin, DbReadPluginEntrypoint};
 use crate::plugins::icon_cache::IconCache;
 use crate::plugins::run_status::RunStatusGuard;
 use crate::search::{SearchIndex, SearchIndexItem, SearchIndexItemAction, SearchIndexItemActionActionType};
 use crate::{PLUGIN_RUNTIME_ENV, SETTINGS_ENV};
 use crate::plugins::image_gatherer::ImageGatherer;
+use crate::plugins::search_providers::SearchProviders;
+use crate::plugins::{command_argument_values, open_settings_window};
 
 pub struct PluginRuntimeData {
     pub id: PluginId,
@@ -45,14 +56,30 @@ pub struct PluginRuntimeData {
     pub entrypoint_names: HashMap<EntrypointId, String>,
     pub code: JsPluginCode,
     pub inline_view_entrypoint_id: Option<String>,
+    pub preload_entrypoint_ids: Vec<EntrypointId>,
     pub permissions: PluginPermissions,
+    pub http_cache: Option<JsPluginHttpCache>,
+    pub network_proxy: Option<String>,
+    pub environment: HashMap<String, String>,
+    pub filesystem_roots: Vec<String>,
+    pub offline_mode: bool,
     pub command_receiver: tokio::sync::broadcast::Receiver<PluginCommand>,
+    pub command_broadcaster: tokio::sync::broadcast::Sender<PluginCommand>,
     pub db_repository: DataDbRepository,
     pub search_index: SearchIndex,
     pub icon_cache: IconCache,
     pub frontend_api: FrontendApi,
     pub dirs: Dirs,
     pub clipboard: Clipboard,
+    pub dev_console: DevConsole,
+    pub metrics: Metrics,
+    pub image_store: ImageStore,
+    pub search_providers: SearchProviders,
+    pub focus_mode: FocusMode,
+    pub oauth: OAuth,
+    pub notifications: Notifications,
+    pub input_injection: InputInjection,
+    pub terminal: Terminal,
 }
 
 pub struct PluginPermissions {
@@ -68,6 +95,9 @@ pub struct PluginPermissions {
 #[derive(Clone, Debug)]
 pub struct PluginRuntimePermissions {
     pub clipboard: Vec<PluginPermissionsClipboard>,
+    // empty when offline mode is enabled
+    pub network: Vec<String>,
+    pub exec: JsPluginPermissionsExec,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -94,8 +124,10 @@ pub enum OnePluginCommandData {
         entrypoint_id: EntrypointId,
     },
     CloseView,
+    PopView,
     RunCommand {
         entrypoint_id: String,
+        arguments: HashMap<String, String>,
     },
     RunGeneratedEntrypoint {
         entrypoint_id: String,
@@ -116,19 +148,35 @@ pub enum OnePluginCommandData {
         modifier_meta: bool,
     },
     RefreshSearchIndex,
+    // action index is not set if notification was dismissed
+    NotificationClosed {
+        notification_id: u32,
+        action_index: Option<usize>,
+    },
+    ViewRenderFailed {
+        entrypoint_id: EntrypointId,
+        render_location: UiRenderLocation,
+        message: String,
+    },
 }
 
 #[derive(Clone, Debug)]
 pub enum AllPluginCommandData {
     OpenInlineView {
         text: String
-    }
+    },
+    Search {
+        text: String,
+        search_id: u32,
+    },
 }
 
 pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: RunStatusGuard) -> anyhow::Result<()> {
 
     let runtime_permissions = PluginRuntimePermissions {
         clipboard: data.permissions.clipboard,
+        network: if data.offline_mode { vec![] } else { data.permissions.network.clone() },
+        exec: data.permissions.exec.clone(),
     };
 
     let api = BackendForPluginRuntimeApiImpl::new(
@@ -141,6 +189,17 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
         data.id.clone(),
         data.name,
         runtime_permissions,
+        data.dev_console.clone(),
+        data.metrics.clone(),
+        data.image_store.clone(),
+        data.search_providers.clone(),
+        data.focus_mode,
+        data.oauth,
+        data.notifications,
+        data.input_injection,
+        data.terminal,
+        data.network_proxy.clone(),
+        data.command_broadcaster,
     );
 
     let mut command_receiver = data.command_receiver;
@@ -174,11 +233,19 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
         (None, None)
     };
 
+    let _log_files_follower = match (&stdout_file, &stderr_file) {
+        (Some(stdout_file), Some(stderr_file)) => {
+            Some(data.dev_console.follow_log_files(plugin_id.clone(), PathBuf::from(stdout_file), PathBuf::from(stderr_file)))
+        }
+        _ => None
+    };
+
     let home_dir = data.dirs.home_dir();
     let local_storage_dir = data.dirs.plugin_local_storage(&plugin_uuid);
     let uds_socket_file = data.dirs.plugin_uds_socket(&plugin_uuid);
     let plugin_cache_dir = data.dirs.plugin_cache(&plugin_uuid)?;
     let plugin_data_dir = data.dirs.plugin_data(&plugin_uuid)?;
+    let code_cache_dir = data.dirs.plugin_code_cache(&plugin_uuid);
 
     #[cfg(target_os = "windows")]
     let name_str = format!("project-gauntlet-{}", plugin_uuid);
@@ -234,9 +301,30 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
         .context("non-uft8 paths are not supported")?
         .to_string();
 
+    // dev plugins are rebuilt too often for code cache to be useful
+    let code_cache_dir = if dev_plugin {
+        None
+    } else {
+        let code_cache_dir = code_cache_dir
+            .to_str()
+            .context("non-uft8 paths are not supported")?
+            .to_string();
+
+        Some(code_cache_dir)
+    };
+
+    // without network permissions plugin is not allowed to access any host
+    let (network, inline_view_entrypoint_id) = if data.offline_mode && !data.permissions.network.is_empty() {
+        tracing::info!("Offline mode is enabled, network access and inline view are not available for plugin: {:?}", plugin_id);
+
+        (vec![], None)
+    } else {
+        (data.permissions.network, data.inline_view_entrypoint_id)
+    };
+
     let permissions = JsPluginPermissions {
         environment: data.permissions.environment,
-        network: data.permissions.network,
+        network,
         filesystem: data.permissions.filesystem,
         exec: data.permissions.exec,
         system: data.permissions.system,
@@ -248,13 +336,18 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
         plugin_uuid: plugin_uuid.clone(),
         code: data.code,
         permissions,
-        inline_view_entrypoint_id: data.inline_view_entrypoint_id,
+        inline_view_entrypoint_id,
         entrypoint_names: data.entrypoint_names,
+        preload_entrypoint_ids: data.preload_entrypoint_ids,
         dev_plugin,
         home_dir,
         local_storage_dir,
         plugin_cache_dir,
         plugin_data_dir,
+        code_cache_dir,
+        http_cache: data.http_cache,
+        environment: data.environment.clone(),
+        filesystem_roots: data.filesystem_roots,
         stdout_file,
         stderr_file,
     };
@@ -263,15 +356,33 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
         .context("unable to get current_exe")?;
 
     #[cfg(not(feature = "scenario_runner"))]
-    let mut runtime_process = std::process::Command::new(current_exe)
-        .env(PLUGIN_RUNTIME_ENV, name_str)
-        .spawn()
-        .context("start plugin runtime process")?;
+    let mut runtime_process = {
+        let mut command = std::process::Command::new(current_exe);
+
+        command.env(PLUGIN_RUNTIME_ENV, name_str);
+
+        // inherited by processes started by plugin
+        command.envs(&data.environment);
+
+        // fetch in plugin runtime picks up proxy from environment
+        if let Some(proxy) = &data.network_proxy {
+            command
+                .env("HTTP_PROXY", proxy)
+                .env("HTTPS_PROXY", proxy);
+        }
+
+        command
+            .spawn()
+            .context("start plugin runtime process")?
+    };
+
+    #[cfg(not(feature = "scenario_runner"))]
+    data.metrics.runtime_started(&plugin_id, runtime_process.id());
 
     // use only for debugging and scenario_runner, only works if only one plugin is enabled
     #[cfg(feature = "scenario_runner")]
     std::thread::spawn(move || {
-        gauntlet_plugin_runtime::run_plugin_runtime(name_str.to_str().unwrap().to_string())
+        gauntlet_plugin_runtime::run_plugin_runtime(name_str.to_str().unwrap().to_string(), crate::RUNTIME_SNAPSHOT)
     });
 
     let conn = listener.accept().await?;
@@ -303,9 +414,11 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
         result = {
             let sender = sender.clone();
             let plugin_id = plugin_id.clone();
+            let dev_console = data.dev_console.clone();
             tokio::task::unconstrained(async move {
+                let mut pending_inline_view = None;
                 loop {
-                    if let Err(err) = event_loop(&mut command_receiver, &sender, plugin_id.clone()).await {
+                    if let Err(err) = event_loop(&mut command_receiver, &sender, plugin_id.clone(), &dev_console, &mut pending_inline_view).await {
                         tracing::error!("Event loop faced an error {:?}", err);
                         break;
                     }
@@ -345,6 +458,8 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
             .context("Error while waiting for JS runtime process to finish")?
             .code();
 
+        data.metrics.runtime_stopped(&plugin_id);
+
         match code {
             Some(code) => {
                 if code == 0 {
@@ -360,8 +475,37 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
     Ok(())
 }
 
-async fn event_loop(command_receiver: &mut tokio::sync::broadcast::Receiver<PluginCommand>, send: &Mutex<SendHalf>, plugin_id: PluginId) -> anyhow::Result<()>  {
-    let command = command_receiver.recv().await?;
+// inline view is requested on every keystroke in main search bar, text is sent to plugin only after typing pauses.
+// only the latest text is kept, so a slow plugin doesn't accumulate queued renders
+const INLINE_VIEW_DEBOUNCE: Duration = Duration::from_millis(80);
+
+struct PendingInlineView {
+    text: String,
+    deadline: tokio::time::Instant,
+}
+
+async fn event_loop(
+    command_receiver: &mut tokio::sync::broadcast::Receiver<PluginCommand>,
+    send: &Mutex<SendHalf>,
+    plugin_id: PluginId,
+    dev_console: &DevConsole,
+    pending_inline_view: &mut Option<PendingInlineView>
+) -> anyhow::Result<()>  {
+    let command = match pending_inline_view.as_ref().map(|pending| pending.deadline) {
+        Some(deadline) => {
+            tokio::select! {
+                command = command_receiver.recv() => command?,
+                _ = tokio::time::sleep_until(deadline) => {
+                    let PendingInlineView { text, .. } = pending_inline_view.take().expect("checked above");
+
+                    send_event(send, &plugin_id, dev_console, IntermediateUiEvent::OpenInlineView { text }).await?;
+
+                    return Ok(())
+                }
+            }
+        }
+        None => command_receiver.recv().await?
+    };
 
     let event = match command {
         PluginCommand::One { id, data } => {
@@ -377,9 +521,13 @@ async fn event_loop(command_receiver: &mut tokio::sync::broadcast::Receiver<Plug
                     OnePluginCommandData::CloseView => {
                         Some(IntermediateUiEvent::CloseView)
                     }
-                    OnePluginCommandData::RunCommand { entrypoint_id } => {
+                    OnePluginCommandData::PopView => {
+                        Some(IntermediateUiEvent::PopView)
+                    }
+                    OnePluginCommandData::RunCommand { entrypoint_id, arguments } => {
                         Some(IntermediateUiEvent::RunCommand {
                             entrypoint_id,
+                            arguments,
                         })
                     }
                     OnePluginCommandData::RunGeneratedEntrypoint { entrypoint_id, action_index } => {
@@ -409,13 +557,34 @@ async fn event_loop(command_receiver: &mut tokio::sync::broadcast::Receiver<Plug
                     OnePluginCommandData::RefreshSearchIndex => {
                         Some(IntermediateUiEvent::RefreshSearchIndex)
                     }
+                    OnePluginCommandData::NotificationClosed { notification_id, action_index } => {
+                        Some(IntermediateUiEvent::NotificationClosed {
+                            notification_id,
+                            action_index,
+                        })
+                    }
+                    OnePluginCommandData::ViewRenderFailed { entrypoint_id, render_location, message } => {
+                        Some(IntermediateUiEvent::ViewRenderFailed {
+                            entrypoint_id,
+                            render_location,
+                            message,
+                        })
+                    }
                 }
             }
         }
         PluginCommand::All { data } => {
             match data {
                 AllPluginCommandData::OpenInlineView { text } => {
-                    Some(IntermediateUiEvent::OpenInlineView { text })
+                    *pending_inline_view = Some(PendingInlineView {
+                        text,
+                        deadline: tokio::time::Instant::now() + INLINE_VIEW_DEBOUNCE,
+                    });
+
+                    None
+                }
+                AllPluginCommandData::Search { text, search_id } => {
+                    Some(IntermediateUiEvent::Search { text, search_id })
                 }
             }
         }
@@ -423,14 +592,22 @@ async fn event_loop(command_receiver: &mut tokio::sync::broadcast::Receiver<Plug
 
 
     if let Some(event) = event {
-        let mut send = send.lock().await;
-
-        send_message(JsMessageSide::Backend, &mut send, JsMessage::Event(from_intermediate_to_js_event(event))).await?;
+        send_event(send, &plugin_id, dev_console, event).await?;
     }
 
     Ok(())
 }
 
+async fn send_event(send: &Mutex<SendHalf>, plugin_id: &PluginId, dev_console: &DevConsole, event: IntermediateUiEvent) -> anyhow::Result<()> {
+    dev_console.view_event(plugin_id, &event);
+
+    let mut send = send.lock().await;
+
+    send_message(JsMessageSide::Backend, &mut send, JsMessage::Event(from_intermediate_to_js_event(event))).await?;
+
+    Ok(())
+}
+
 
 async fn request_loop(recv: &mut RecvHalf, send: &Mutex<SendHalf>, api: &BackendForPluginRuntimeApiImpl) -> anyhow::Result<bool>  {
     match recv_message::<JsPluginRuntimeMessage>(JsMessageSide::Backend, recv).await {
@@ -470,14 +647,16 @@ async fn request_loop(recv: &mut RecvHalf, send: &Mutex<SendHalf>, api: &Backend
 }
 
 async fn handle_message(message: JsRequest, api: &BackendForPluginRuntimeApiImpl) -> anyhow::Result<JsResponse> {
+    api.metrics.record_request("plugin_runtime", request_name(&message));
+
     match message {
-        JsRequest::Render { entrypoint_id, entrypoint_name, render_location, top_level_view, container } => {
+        JsRequest::Render { entrypoint_id, entrypoint_name, render_location, top_level_view, breadcrumbs, key_handlers, container } => {
             let render_location = match render_location {
                 JsUiRenderLocation::InlineView => UiRenderLocation::InlineView,
                 JsUiRenderLocation::View => UiRenderLocation::View
             };
 
-            api.ui_render(entrypoint_id, entrypoint_name, render_location, top_level_view, container).await?;
+            api.ui_render(entrypoint_id, entrypoint_name, render_location, top_level_view, breadcrumbs, key_handlers, container).await?;
 
             Ok(JsResponse::Nothing)
         }
@@ -496,8 +675,8 @@ async fn handle_message(message: JsRequest, api: &BackendForPluginRuntimeApiImpl
 
             Ok(JsResponse::Nothing)
         }
-        JsRequest::ShowPreferenceRequiredView { entrypoint_id, plugin_preferences_required, entrypoint_preferences_required } => {
-            api.ui_show_preferences_required_view(entrypoint_id, plugin_preferences_required, entrypoint_preferences_required).await?;
+        JsRequest::ShowOnboardingView { entrypoint_id } => {
+            api.ui_show_onboarding_view(entrypoint_id).await?;
 
             Ok(JsResponse::Nothing)
         }
@@ -506,11 +685,28 @@ async fn handle_message(message: JsRequest, api: &BackendForPluginRuntimeApiImpl
 
             Ok(JsResponse::Nothing)
         }
+        JsRequest::ShowNotification { title, body, actions } => {
+            let notification_id = api.ui_show_notification(title, body, actions).await?;
+
+            Ok(JsResponse::NotificationId {
+                data: notification_id
+            })
+        }
         JsRequest::HideWindow => {
             api.ui_hide_window().await?;
 
             Ok(JsResponse::Nothing)
         }
+        JsRequest::OpenSettings => {
+            api.ui_open_settings().await?;
+
+            Ok(JsResponse::Nothing)
+        }
+        JsRequest::ShowWindowWithSearchQuery { query } => {
+            api.ui_show_window_with_search_query(query).await?;
+
+            Ok(JsResponse::Nothing)
+        }
         JsRequest::UpdateLoadingBar { entrypoint_id, show } => {
             api.ui_update_loading_bar(entrypoint_id, show).await?;
 
@@ -535,6 +731,18 @@ async fn handle_message(message: JsRequest, api: &BackendForPluginRuntimeApiImpl
                 data
             })
         }
+        JsRequest::GetSearchProviderEntrypointIds => {
+            let data = api.get_search_provider_entrypoint_ids().await?;
+
+            Ok(JsResponse::SearchProviderEntrypointIds {
+                data
+            })
+        }
+        JsRequest::SearchProviderResults { search_id, entrypoint_id, results } => {
+            api.search_provider_results(search_id, entrypoint_id, results).await?;
+
+            Ok(JsResponse::Nothing)
+        }
         JsRequest::GetPluginPreferences => {
             let data = api.get_plugin_preferences().await?;
 
@@ -563,6 +771,13 @@ async fn handle_message(message: JsRequest, api: &BackendForPluginRuntimeApiImpl
                 data
             })
         }
+        JsRequest::PluginOnboardingRequired => {
+            let data = api.plugin_onboarding_required().await?;
+
+            Ok(JsResponse::PluginOnboardingRequired {
+                data
+            })
+        }
         JsRequest::ClipboardRead => {
             let data = api.clipboard_read().await?;
 
@@ -587,11 +802,43 @@ async fn handle_message(message: JsRequest, api: &BackendForPluginRuntimeApiImpl
 
             Ok(JsResponse::Nothing)
         }
+        JsRequest::ClipboardWriteSensitiveText { data } => {
+            api.clipboard_write_sensitive_text(data).await?;
+
+            Ok(JsResponse::Nothing)
+        }
+        JsRequest::ClipboardPasteText { data, sensitive } => {
+            api.clipboard_paste_text(data, sensitive).await?;
+
+            Ok(JsResponse::Nothing)
+        }
         JsRequest::ClipboardClear => {
             api.clipboard_clear().await?;
 
             Ok(JsResponse::Nothing)
         }
+        JsRequest::RunInTerminal { command, cwd } => {
+            api.run_in_terminal(command, cwd).await?;
+
+            Ok(JsResponse::Nothing)
+        }
+        JsRequest::RunCommand { plugin_id, entrypoint_id, arguments } => {
+            api.run_command(plugin_id, entrypoint_id, arguments).await?;
+
+            Ok(JsResponse::Nothing)
+        }
+        JsRequest::OAuthAuthorize { config } => {
+            let data = api.oauth_authorize(config).await?;
+
+            Ok(JsResponse::OAuthAuthorize {
+                data
+            })
+        }
+        JsRequest::OAuthRemoveTokens { config } => {
+            api.oauth_remove_tokens(config).await?;
+
+            Ok(JsResponse::Nothing)
+        }
         JsRequest::GetActionIdForShortcut { entrypoint_id, key, modifier_shift, modifier_control, modifier_alt, modifier_meta } => {
             let data = api.ui_get_action_id_for_shortcut(
                 entrypoint_id,
@@ -609,14 +856,53 @@ async fn handle_message(message: JsRequest, api: &BackendForPluginRuntimeApiImpl
     }
 }
 
+fn request_name(message: &JsRequest) -> &'static str {
+    match message {
+        JsRequest::Render { .. } => "render",
+        JsRequest::ClearInlineView => "clear_inline_view",
+        JsRequest::ShowPluginErrorView { .. } => "show_plugin_error_view",
+        JsRequest::ShowOnboardingView { .. } => "show_onboarding_view",
+        JsRequest::ShowHud { .. } => "show_hud",
+        JsRequest::ShowNotification { .. } => "show_notification",
+        JsRequest::HideWindow => "hide_window",
+        JsRequest::OpenSettings => "open_settings",
+        JsRequest::ShowWindowWithSearchQuery { .. } => "show_window_with_search_query",
+        JsRequest::UpdateLoadingBar { .. } => "update_loading_bar",
+        JsRequest::ReloadSearchIndex { .. } => "reload_search_index",
+        JsRequest::GetAssetData { .. } => "get_asset_data",
+        JsRequest::GetEntrypointGeneratorEntrypointIds => "get_entrypoint_generator_entrypoint_ids",
+        JsRequest::GetSearchProviderEntrypointIds => "get_search_provider_entrypoint_ids",
+        JsRequest::SearchProviderResults { .. } => "search_provider_results",
+        JsRequest::GetPluginPreferences => "get_plugin_preferences",
+        JsRequest::GetEntrypointPreferences { .. } => "get_entrypoint_preferences",
+        JsRequest::PluginPreferencesRequired => "plugin_preferences_required",
+        JsRequest::EntrypointPreferencesRequired { .. } => "entrypoint_preferences_required",
+        JsRequest::PluginOnboardingRequired => "plugin_onboarding_required",
+        JsRequest::ClipboardRead => "clipboard_read",
+        JsRequest::ClipboardReadText => "clipboard_read_text",
+        JsRequest::ClipboardWrite { .. } => "clipboard_write",
+        JsRequest::ClipboardWriteText { .. } => "clipboard_write_text",
+        JsRequest::ClipboardWriteSensitiveText { .. } => "clipboard_write_sensitive_text",
+        JsRequest::ClipboardPasteText { .. } => "clipboard_paste_text",
+        JsRequest::ClipboardClear => "clipboard_clear",
+        JsRequest::RunInTerminal { .. } => "run_in_terminal",
+        JsRequest::RunCommand { .. } => "run_command",
+        JsRequest::OAuthAuthorize { .. } => "oauth_authorize",
+        JsRequest::OAuthRemoveTokens { .. } => "oauth_remove_tokens",
+        JsRequest::GetActionIdForShortcut { .. } => "get_action_id_for_shortcut",
+    }
+}
+
 fn from_intermediate_to_js_event(event: IntermediateUiEvent) -> JsEvent {
     match event {
         IntermediateUiEvent::OpenView { entrypoint_id } => JsEvent::OpenView {
             entrypoint_id: entrypoint_id.to_string(),
         },
         IntermediateUiEvent::CloseView => JsEvent::CloseView,
-        IntermediateUiEvent::RunCommand { entrypoint_id } => JsEvent::RunCommand {
-            entrypoint_id
+        IntermediateUiEvent::PopView => JsEvent::PopView,
+        IntermediateUiEvent::RunCommand { entrypoint_id, arguments } => JsEvent::RunCommand {
+            entrypoint_id,
+            arguments,
         },
         IntermediateUiEvent::RunGeneratedEntrypoint { entrypoint_id, action_index } => JsEvent::RunGeneratedEntrypoint {
             entrypoint_id,
@@ -624,15 +910,7 @@ fn from_intermediate_to_js_event(event: IntermediateUiEvent) -> JsEvent {
         },
         IntermediateUiEvent::HandleViewEvent { widget_id, event_name, event_arguments } => {
             let event_arguments = event_arguments.into_iter()
-                .map(|arg| match arg {
-                    UiPropertyValue::String(value) => JsUiPropertyValue::String { value },
-                    UiPropertyValue::Number(value) => JsUiPropertyValue::Number { value },
-                    UiPropertyValue::Bool(value) => JsUiPropertyValue::Bool { value },
-                    UiPropertyValue::Undefined => JsUiPropertyValue::Undefined,
-                    UiPropertyValue::Array(_) | UiPropertyValue::Bytes(_) | UiPropertyValue::Object(_)  => {
-                        todo!()
-                    }
-                })
+                .map(|arg| from_ui_property_value_to_js(arg))
                 .collect();
 
             JsEvent::ViewEvent {
@@ -657,6 +935,38 @@ fn from_intermediate_to_js_event(event: IntermediateUiEvent) -> JsEvent {
         }
         IntermediateUiEvent::OpenInlineView { text } => JsEvent::OpenInlineView { text },
         IntermediateUiEvent::RefreshSearchIndex => JsEvent::RefreshSearchIndex,
+        IntermediateUiEvent::Search { text, search_id } => JsEvent::Search { text, search_id },
+        IntermediateUiEvent::NotificationClosed { notification_id, action_index } => JsEvent::NotificationClosed { notification_id, action_index },
+        IntermediateUiEvent::ViewRenderFailed { entrypoint_id, render_location, message } => JsEvent::ViewRenderFailed {
+            entrypoint_id: entrypoint_id.to_string(),
+            render_location: match render_location {
+                UiRenderLocation::InlineView => JsUiRenderLocation::InlineView,
+                UiRenderLocation::View => JsUiRenderLocation::View,
+            },
+            message,
+        },
+    }
+}
+
+fn from_ui_property_value_to_js(value: UiPropertyValue) -> JsUiPropertyValue {
+    match value {
+        UiPropertyValue::String(value) => JsUiPropertyValue::String { value },
+        UiPropertyValue::Number(value) => JsUiPropertyValue::Number { value },
+        UiPropertyValue::Bool(value) => JsUiPropertyValue::Bool { value },
+        UiPropertyValue::Color(value) => JsUiPropertyValue::Color { value: value.to_hex() },
+        UiPropertyValue::Enum(value) => JsUiPropertyValue::Enum { value },
+        UiPropertyValue::Bytes(value) => JsUiPropertyValue::Bytes { value: value.to_vec() },
+        UiPropertyValue::Array(value) => {
+            JsUiPropertyValue::Array {
+                value: value.into_iter().map(|value| from_ui_property_value_to_js(value)).collect()
+            }
+        }
+        UiPropertyValue::Object(value) => {
+            JsUiPropertyValue::Object {
+                value: value.into_iter().map(|(key, value)| (key, from_ui_property_value_to_js(value))).collect()
+            }
+        }
+        UiPropertyValue::Undefined => JsUiPropertyValue::Undefined,
     }
 }
 
@@ -670,7 +980,19 @@ pub struct BackendForPluginRuntimeApiImpl {
     plugin_uuid: String,
     plugin_id: PluginId,
     plugin_name: String,
-    permissions: PluginRuntimePermissions
+    permissions: PluginRuntimePermissions,
+    dev_console: DevConsole,
+    metrics: Metrics,
+    image_store: ImageStore,
+    search_providers: SearchProviders,
+    focus_mode: FocusMode,
+    oauth: OAuth,
+    notifications: Notifications,
+    input_injection: InputInjection,
+    terminal: Terminal,
+    network_proxy: Option<String>,
+    command_broadcaster: tokio::sync::broadcast::Sender<PluginCommand>,
+    view_updates: ViewUpdates,
 }
 
 impl BackendForPluginRuntimeApiImpl {
@@ -683,7 +1005,18 @@ impl BackendForPluginRuntimeApiImpl {
         plugin_uuid: String,
         plugin_id: PluginId,
         plugin_name: String,
-        permissions: PluginRuntimePermissions
+        permissions: PluginRuntimePermissions,
+        dev_console: DevConsole,
+        metrics: Metrics,
+        image_store: ImageStore,
+        search_providers: SearchProviders,
+        focus_mode: FocusMode,
+        oauth: OAuth,
+        notifications: Notifications,
+        input_injection: InputInjection,
+        terminal: Terminal,
+        network_proxy: Option<String>,
+        command_broadcaster: tokio::sync::broadcast::Sender<PluginCommand>,
     ) -> Self {
         Self {
             icon_cache,
@@ -694,9 +1027,114 @@ impl BackendForPluginRuntimeApiImpl {
             plugin_uuid,
             plugin_id,
             plugin_name,
-            permissions
+            permissions,
+            dev_console,
+            metrics,
+            image_store,
+            search_providers,
+            focus_mode,
+            oauth,
+            notifications,
+            input_injection,
+            terminal,
+            network_proxy,
+            command_broadcaster,
+            view_updates: ViewUpdates::new(),
         }
     }
+
+    // renders are sent in background, so plugin is notified about the ones which failed using an event
+    async fn flush_view_updates(&self) {
+        let mut view_updates = self.view_updates.lock().await;
+
+        for render in view_updates.take() {
+            let entrypoint_id = render.entrypoint_id.clone();
+            let render_location = render.render_location;
+
+            if let Err(err) = self.send_view_update(render).await {
+                tracing::warn!("error occurred when sending view update to frontend {:?}", err);
+
+                // plugin may have been stopped in the meantime
+                let _ = self.command_broadcaster.send(PluginCommand::One {
+                    id: self.plugin_id.clone(),
+                    data: OnePluginCommandData::ViewRenderFailed {
+                        entrypoint_id,
+                        render_location,
+                        message: format!("{:#}", err),
+                    },
+                });
+            }
+        }
+    }
+
+    async fn send_view_update(&self, render: PendingRender) -> anyhow::Result<()> {
+        let PendingRender { entrypoint_id, entrypoint_name, render_location, top_level_view, breadcrumbs, key_handlers, container } = render;
+
+        let images = ImageGatherer::run_gatherer(&self.image_store, &self.plugin_id, &container).await?;
+
+        let start = Instant::now();
+
+        self.frontend_api.replace_view(
+            self.plugin_id.clone(),
+            self.plugin_name.clone(),
+            entrypoint_id,
+            entrypoint_name,
+            render_location,
+            top_level_view,
+            breadcrumbs,
+            key_handlers,
+            container,
+            images
+        ).await?;
+
+        self.metrics.record_render(&self.plugin_id, start.elapsed());
+
+        Ok(())
+    }
+
+    // token endpoint is requested by the server on behalf of the plugin, so it is limited by the same network permissions
+    // authorize url is opened in browser and token url is requested by server, only web urls are accepted
+    fn oauth_config(&self, config: JsOAuthConfig) -> anyhow::Result<OAuthConfig> {
+        let authorize_url = url::Url::parse(&config.authorize_url)
+            .context("Invalid OAuth authorize url")?;
+
+        if !matches!(authorize_url.scheme(), "http" | "https") {
+            return Err(anyhow!("OAuth authorize url has to use http or https scheme: {}", config.authorize_url));
+        }
+
+        let token_url = url::Url::parse(&config.token_url)
+            .context("Invalid OAuth token url")?;
+
+        if !matches!(token_url.scheme(), "http" | "https") {
+            return Err(anyhow!("OAuth token url has to use http or https scheme: {}", config.token_url));
+        }
+
+        let host = token_url.host_str()
+            .ok_or(anyhow!("OAuth token url doesn't have a host"))?;
+
+        let allow = self.permissions.network
+            .iter()
+            .any(|permission| {
+                match token_url.port() {
+                    Some(port) => permission == host || permission == &format!("{}:{}", host, port),
+                    None => permission == host,
+                }
+            });
+
+        if !allow {
+            return Err(anyhow!("Plugin doesn't have network permission for host: {}", host));
+        }
+
+        Ok(OAuthConfig {
+            client_id: config.client_id,
+            client_secret: config.client_secret,
+            authorize_url: config.authorize_url,
+            token_url: config.token_url,
+            scopes: config.scopes,
+            redirect_port: config.redirect_port,
+            extra_authorize_params: config.extra_authorize_params,
+        })
+    }
 }
 
 impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
@@ -722,7 +1160,13 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
 
         let generator_names: HashMap<_, _> = entrypoints.iter()
             .filter(|entrypoint| matches!(db_entrypoint_from_str(&entrypoint.entrypoint_type), DbPluginEntrypointType::EntrypointGenerator))
-            .map(|entrypoint| (entrypoint.id.clone(), entrypoint.name.clone()))
+            .map(|entrypoint| (entrypoint.id.clone(), entrypoint.localized_name()))
+            .collect();
+
+        // items generated by hidden generator are hidden too
+        let hidden_generators: HashSet<_> = entrypoints.iter()
+            .filter(|entrypoint| entrypoint.hidden)
+            .map(|entrypoint| entrypoint.id.clone())
             .collect();
 
         let mut generated_search_items = generated_entrypoints.into_iter()
@@ -774,15 +1218,23 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
                     .get(&item.generator_entrypoint_id)
                     .map(|name| name.to_string());
 
+                let entrypoint_hidden = hidden_generators.contains(&item.generator_entrypoint_id);
+
                 Ok(SearchIndexItem {
                     entrypoint_type: SearchResultEntrypointType::Generated,
                     entrypoint_id: EntrypointId::from_string(item.entrypoint_id),
                     entrypoint_name: item.entrypoint_name,
+                    entrypoint_subtitle: item.entrypoint_subtitle,
+                    entrypoint_keywords: item.entrypoint_keywords,
                     entrypoint_icon,
                     entrypoint_frecency,
                     entrypoint_actions,
                     entrypoint_accessories,
                     entrypoint_generator_name,
+                    entrypoint_arguments: vec![],
+                    entrypoint_hidden,
+                    entrypoint_preview: item.entrypoint_preview,
+                    entrypoint_file: item.entrypoint_file,
                 })
             })
             .collect::<anyhow::Result<Vec<_>>>()?;
@@ -808,6 +1260,12 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
 
                 let entrypoint_frecency = frecency_map.get(&entrypoint_id).cloned().unwrap_or(0.0);
 
+                let entrypoint_name = entrypoint.localized_name();
+
+                // description from plugin manifest is used as preview of entrypoints declared there
+                let entrypoint_preview = Some(entrypoint.localized_description())
+                    .filter(|description| !description.trim().is_empty());
+
                 let entrypoint_icon = match entrypoint.icon_path {
                     None => None,
                     Some(path_to_asset) => {
@@ -820,32 +1278,77 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
 
                 let entrypoint_id = EntrypointId::from_string(entrypoint_id);
 
+                let entrypoint_accessories: Vec<_> = entrypoint.accessory
+                    .into_iter()
+                    .map(|text| SearchResultAccessory::TextAccessory { text, icon: None, tooltip: None })
+                    .collect();
+
                 match &entrypoint_type {
                     DbPluginEntrypointType::Command => {
+                        let entrypoint_arguments = entrypoint.arguments
+                            .into_iter()
+                            .map(|argument| match argument {
+                                DbPluginArgument::String { id, name, required } => EntrypointArgument {
+                                    id,
+                                    name,
+                                    required,
+                                    argument_type: EntrypointArgumentType::String,
+                                },
+                                DbPluginArgument::Enum { id, name, required, enum_values } => EntrypointArgument {
+                                    id,
+                                    name,
+                                    required,
+                                    argument_type: EntrypointArgumentType::Enum {
+                                        enum_values: enum_values.into_iter()
+                                            .map(|DbPreferenceEnumValue { label, value }| PreferenceEnumValue { label, value })
+                                            .collect(),
+                                    },
+                                },
+                                DbPluginArgument::File { id, name, required } => EntrypointArgument {
+                                    id,
+                                    name,
+                                    required,
+                                    argument_type: EntrypointArgumentType::File,
+                                },
+                            })
+                            .collect();
+
                         Ok(Some(SearchIndexItem {
                             entrypoint_type: SearchResultEntrypointType::Command,
-                            entrypoint_name: entrypoint.name,
+                            entrypoint_name,
+                            entrypoint_subtitle: entrypoint.subtitle,
+                            entrypoint_keywords: entrypoint.keywords,
                             entrypoint_generator_name: None,
                             entrypoint_id,
                             entrypoint_icon,
                             entrypoint_frecency,
                             entrypoint_actions: vec![],
-                            entrypoint_accessories: vec![],
+                            entrypoint_accessories,
+                            entrypoint_arguments,
+                            entrypoint_hidden: entrypoint.hidden,
+                            entrypoint_preview,
+                            entrypoint_file: None,
                         }))
                     },
                     DbPluginEntrypointType::View => {
                         Ok(Some(SearchIndexItem {
                             entrypoint_type: SearchResultEntrypointType::View,
-                            entrypoint_name: entrypoint.name,
+                            entrypoint_name,
+                            entrypoint_subtitle: entrypoint.subtitle,
+                            entrypoint_keywords: entrypoint.keywords,
                             entrypoint_generator_name: None,
                             entrypoint_id,
                             entrypoint_icon,
                             entrypoint_frecency,
                             entrypoint_actions: vec![],
-                            entrypoint_accessories: vec![],
+                            entrypoint_accessories,
+                            entrypoint_arguments: vec![],
+                            entrypoint_hidden: entrypoint.hidden,
+                            entrypoint_preview,
+                            entrypoint_file: None,
                         }))
                     },
-                    DbPluginEntrypointType::EntrypointGenerator | DbPluginEntrypointType::InlineView => {
+                    DbPluginEntrypointType::EntrypointGenerator | DbPluginEntrypointType::InlineView | DbPluginEntrypointType::SearchProvider => {
                         Ok(None)
                     }
                 }
@@ -881,6 +1384,89 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
         Ok(result)
     }
 
+    async fn get_search_provider_entrypoint_ids(&self) -> anyhow::Result<Vec<String>> {
+        let result = self.repository.get_entrypoints_by_plugin_id(&self.plugin_id.to_string()).await?
+            .into_iter()
+            .filter(|entrypoint| entrypoint.enabled)
+            .filter(|entrypoint| matches!(db_entrypoint_from_str(&entrypoint.entrypoint_type), DbPluginEntrypointType::SearchProvider))
+            .map(|entrypoint| entrypoint.id)
+            .collect::<Vec<_>>();
+
+        Ok(result)
+    }
+
+    async fn search_provider_results(&self, search_id: u32, entrypoint_id: EntrypointId, results: Vec<JsGeneratedSearchItem>) -> anyhow::Result<()> {
+        let DbReadPlugin { name, .. } = self.repository.get_plugin_by_id(&self.plugin_id.to_string())
+            .await
+            .context("error when getting plugin by id")?;
+
+        let shortcuts = self.repository.action_shortcuts(&self.plugin_id.to_string(), &entrypoint_id.to_string()).await?;
+
+        let mut previews = HashMap::new();
+
+        let results = results.into_iter()
+            .map(|item| {
+                if let Some(preview) = item.entrypoint_preview {
+                    previews.insert(EntrypointId::from_string(&item.entrypoint_id), preview);
+                }
+
+                let entrypoint_actions = item.entrypoint_actions.iter()
+                    .map(|action| {
+                        let shortcut = action.id.as_ref()
+                            .and_then(|id| shortcuts.get(id).cloned());
+
+                        SearchResultEntrypointAction {
+                            action_type: match action.action_type {
+                                JsGeneratedSearchItemActionType::View => SearchResultEntrypointActionType::View,
+                                JsGeneratedSearchItemActionType::Command => SearchResultEntrypointActionType::Command,
+                            },
+                            label: action.label.clone(),
+                            shortcut,
+                        }
+                    })
+                    .collect();
+
+                let entrypoint_accessories = item.entrypoint_accessories.into_iter()
+                    .map(|accessory| {
+                        match accessory {
+                            JsGeneratedSearchItemAccessory::TextAccessory { text, icon, tooltip } => {
+                                SearchResultAccessory::TextAccessory { text, icon, tooltip }
+                            }
+                            JsGeneratedSearchItemAccessory::IconAccessory { icon, tooltip } => {
+                                SearchResultAccessory::IconAccessory { icon, tooltip }
+                            }
+                        }
+                    })
+                    .collect();
+
+                SearchResult {
+                    plugin_id: self.plugin_id.clone(),
+                    plugin_name: name.clone(),
+                    entrypoint_id: EntrypointId::from_string(item.entrypoint_id),
+                    entrypoint_name: item.entrypoint_name,
+                    entrypoint_name_highlights: vec![],
+                    entrypoint_subtitle: item.entrypoint_subtitle,
+                    entrypoint_matched_keywords: vec![],
+                    entrypoint_generator_name: None,
+                    entrypoint_icon: item.entrypoint_icon.map(|data| bytes::Bytes::from(data)),
+                    entrypoint_type: SearchResultEntrypointType::Generated,
+                    entrypoint_actions,
+                    entrypoint_accessories,
+                    entrypoint_arguments: vec![],
+                    entrypoint_file: item.entrypoint_file,
+                }
+            })
+            .collect();
+
+        let current = self.search_providers.set_results(self.plugin_id.clone(), entrypoint_id, search_id, results, previews);
+
+        if current {
+            self.frontend_api.request_search_results_update().await?;
+        }
+
+        Ok(())
+    }
+
     async fn get_plugin_preferences(&self) -> anyhow::Result<HashMap<String, JsPreferenceUserData>> {
         let DbReadPlugin { preferences, preferences_user_data, .. } = self.repository
             .get_plugin_by_id(&self.plugin_id.to_string())
@@ -911,6 +1497,13 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
         Ok(any_preferences_missing_value(preferences, preferences_user_data))
     }
 
+    async fn plugin_onboarding_required(&self) -> anyhow::Result<bool> {
+        let DbReadPlugin { onboarding, onboarding_completed, .. } = self.repository
+            .get_plugin_by_id(&self.plugin_id.to_string()).await?;
+
+        Ok(onboarding.is_some() && !onboarding_completed)
+    }
+
     async fn clipboard_read(&self) -> anyhow::Result<JsClipboardData> {
         let allow = self
             .permissions
@@ -971,6 +1564,36 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
         self.clipboard.write_text(data)
     }
 
+    async fn clipboard_write_sensitive_text(&self, data: String) -> anyhow::Result<()> {
+        let allow = self
+            .permissions
+            .clipboard
+            .contains(&PluginPermissionsClipboard::Write);
+
+        if !allow {
+            return Err(anyhow!("Plugin doesn't have 'write' permission for clipboard"));
+        }
+
+        tracing::debug!("Writing sensitive text to clipboard, plugin id: {:?}", self.plugin_id);
+
+        self.clipboard.write_sensitive_text(data)
+    }
+
+    async fn clipboard_paste_text(&self, data: String, sensitive: bool) -> anyhow::Result<()> {
+        let allow = self
+            .permissions
+            .clipboard
+            .contains(&PluginPermissionsClipboard::Write);
+
+        if !allow {
+            return Err(anyhow!("Plugin doesn't have 'write' permission for clipboard"));
+        }
+
+        tracing::debug!("Pasting text to active application, plugin id: {:?}", self.plugin_id);
+
+        self.input_injection.paste_text(data, sensitive).await
+    }
+
     async fn clipboard_clear(&self) -> anyhow::Result<()> {
         let allow = self
             .permissions
@@ -986,24 +1609,145 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
         self.clipboard.clear()
     }
 
+    // program has to be listed in exec permissions by name or by path, without variables.
+    // empty command only opens user's shell, so it doesn't need permission.
+    // working directory can be put into terminal command template by user, so it can't contain quotes
+    async fn run_in_terminal(&self, command: Vec<String>, cwd: Option<String>) -> anyhow::Result<()> {
+        if let Some(program) = command.first() {
+            let allow = self.permissions.exec.command.contains(program)
+                || self.permissions.exec.executable.contains(program);
+
+            if !allow {
+                return Err(anyhow!("Plugin doesn't have permission to run '{}'", program));
+            }
+        }
+
+        if let Some(cwd) = &cwd {
+            if cwd.chars().any(|char| char.is_control() || "\"'`$".contains(char)) {
+                return Err(anyhow!("Working directory contains characters which are not allowed: {:?}", cwd));
+            }
+        }
+
+        tracing::debug!("Running command in terminal, plugin id: {:?}", self.plugin_id);
+
+        self.terminal.run(command, cwd.map(PathBuf::from))
+    }
+
+    // only available to bundled plugin, e.g. quick note hands note to command of another plugin
+    async fn run_command(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, arguments: Vec<String>) -> anyhow::Result<()> {
+        let plugin = self.repository.get_plugin_by_id_option(&plugin_id.to_string())
+            .await?
+            .ok_or_else(|| anyhow!("Plugin not found: {}", plugin_id))?;
+
+        let entrypoint = self.repository.get_entrypoint_by_id_option(&plugin_id.to_string(), &entrypoint_id.to_string())
+            .await?
+            .ok_or_else(|| anyhow!("Entrypoint not found: {}", entrypoint_id))?;
+
+        if !plugin.enabled || !entrypoint.enabled {
+            return Err(anyhow!("Entrypoint is disabled: {}", entrypoint_id))
+        }
+
+        if !matches!(db_entrypoint_from_str(&entrypoint.entrypoint_type), DbPluginEntrypointType::Command) {
+            return Err(anyhow!("Entrypoint is not a command: {}", entrypoint_id))
+        }
+
+        let arguments = command_argument_values(&entrypoint_id, &entrypoint.arguments, arguments)?;
+
+        tracing::debug!("Running command '{}' of plugin '{}', plugin id: {:?}", entrypoint_id, plugin_id, self.plugin_id);
+
+        self.command_broadcaster.send(PluginCommand::One {
+            id: plugin_id,
+            data: OnePluginCommandData::RunCommand {
+                entrypoint_id: entrypoint_id.to_string(),
+                arguments,
+            },
+        }).map_err(|_| anyhow!("Plugin is not running"))?;
+
+        Ok(())
+    }
+
+    async fn oauth_authorize(&self, config: JsOAuthConfig) -> anyhow::Result<Option<JsOAuthTokens>> {
+        let config = self.oauth_config(config)?;
+
+        let tokens = self.oauth.authorize(self.plugin_id.clone(), config, self.network_proxy.clone()).await?;
+
+        Ok(tokens.map(|tokens| JsOAuthTokens {
+            access_token: tokens.access_token,
+            token_type: tokens.token_type,
+            expires_at: tokens.expires_at.map(|expires_at| (expires_at * 1000) as f64),
+            scopes: tokens.scopes,
+        }))
+    }
+
+    async fn oauth_remove_tokens(&self, config: JsOAuthConfig) -> anyhow::Result<()> {
+        let config = self.oauth_config(config)?;
+
+        tracing::debug!("Removing oauth tokens, plugin id: {:?}", self.plugin_id);
+
+        self.oauth.remove_tokens(&self.plugin_id, &config)
+    }
+
     async fn ui_update_loading_bar(&self, entrypoint_id: EntrypointId, show: bool) -> anyhow::Result<()> {
+        self.flush_view_updates().await;
+
         self.frontend_api.update_loading_bar(self.plugin_id.clone(), entrypoint_id, show).await?;
 
         Ok(())
     }
 
     async fn ui_show_hud(&self, display: String) -> anyhow::Result<()> {
+        self.flush_view_updates().await;
+
+        if self.focus_mode.is_enabled() {
+            tracing::debug!("Focus mode is enabled, not showing notification from plugin: {:?}", self.plugin_id);
+            return Ok(());
+        }
+
         self.frontend_api.show_hud(display).await?;
 
         Ok(())
     }
 
+    async fn ui_show_notification(&self, title: String, body: Option<String>, actions: Vec<String>) -> anyhow::Result<u32> {
+        if self.focus_mode.is_enabled() {
+            tracing::debug!("Focus mode is enabled, not showing notification from plugin: {:?}", self.plugin_id);
+            return Ok(0);
+        }
+
+        let DbReadPlugin { notifications_enabled, .. } = self.repository.get_plugin_by_id(&self.plugin_id.to_string())
+            .await?;
+
+        // id is still returned, action callbacks of the notification are just never called
+        if !notifications_enabled {
+            tracing::debug!("Notifications are disabled in settings, not showing notification from plugin: {:?}", self.plugin_id);
+            return Ok(0);
+        }
+
+        Ok(self.notifications.show_for_plugin(self.plugin_id.clone(), title, body, actions))
+    }
+
     async fn ui_hide_window(&self) -> anyhow::Result<()> {
+        self.flush_view_updates().await;
+
         self.frontend_api.hide_window().await?;
 
         Ok(())
     }
 
+    async fn ui_open_settings(&self) -> anyhow::Result<()> {
+        open_settings_window(&self.frontend_api, None, None).await;
+
+        Ok(())
+    }
+
+    async fn ui_show_window_with_search_query(&self, query: String) -> anyhow::Result<()> {
+        self.flush_view_updates().await;
+
+        self.frontend_api.show_window_with_search_query(query).await?;
+
+        Ok(())
+    }
+
     async fn ui_get_action_id_for_shortcut(
         &self,
         entrypoint_id: EntrypointId,
@@ -1032,21 +1776,32 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
         entrypoint_name: String,
         render_location: UiRenderLocation,
         top_level_view: bool,
+        breadcrumbs: Vec<String>,
+        key_handlers: Vec<PhysicalShortcut>,
         container: RootWidget,
     ) -> anyhow::Result<()> {
 
-        let images = ImageGatherer::run_gatherer(&self, &container).await?;
+        self.dev_console.render(&self.plugin_id, &entrypoint_id, &entrypoint_name, &render_location, &container);
 
-        self.frontend_api.replace_view(
-            self.plugin_id.clone(),
-            self.plugin_name.clone(),
+        let render = PendingRender {
             entrypoint_id,
             entrypoint_name,
             render_location,
             top_level_view,
+            breadcrumbs,
+            key_handlers,
             container,
-            images
-        ).await?;
+        };
+
+        if self.view_updates.push(render).await {
+            let api = self.clone();
+
+            tokio::spawn(async move {
+                tokio::time::sleep(VIEW_UPDATE_FLUSH_INTERVAL).await;
+
+                api.flush_view_updates().await;
+            });
+        }
 
         Ok(())
     }
@@ -1056,6 +1811,8 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
         entrypoint_id: EntrypointId,
         render_location: UiRenderLocation
     ) -> anyhow::Result<()> {
+        self.flush_view_updates().await;
+
         self.frontend_api.show_plugin_error_view(
             self.plugin_id.clone(),
             entrypoint_id,
@@ -1065,24 +1822,32 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
         Ok(())
     }
 
-    async fn ui_show_preferences_required_view(
-        &self,
-        entrypoint_id: EntrypointId,
-        plugin_preferences_required: bool,
-        entrypoint_preferences_required: bool
-    ) -> anyhow::Result<()> {
+    async fn ui_show_onboarding_view(&self, entrypoint_id: EntrypointId) -> anyhow::Result<()> {
+        let plugin = self.repository
+            .get_plugin_by_id(&self.plugin_id.to_string()).await?;
+
+        let entrypoint = self.repository
+            .get_entrypoint_by_id(&self.plugin_id.to_string(), &entrypoint_id.to_string()).await?;
+
+        let mut preferences = onboarding_preferences(None, plugin.preferences, plugin.preferences_user_data);
+        preferences.extend(onboarding_preferences(Some(entrypoint_id.clone()), entrypoint.preferences, entrypoint.preferences_user_data));
+
+        self.flush_view_updates().await;
 
-        self.frontend_api.show_preference_required_view(
+        self.frontend_api.show_onboarding_view(
             self.plugin_id.clone(),
+            plugin.name,
             entrypoint_id,
-            plugin_preferences_required,
-            entrypoint_preferences_required
+            plugin.onboarding,
+            preferences
         ).await?;
 
         Ok(())
     }
 
     async fn ui_clear_inline_view(&self) -> anyhow::Result<()> {
+        self.flush_view_updates().await;
+
         self.frontend_api.clear_inline_view(self.plugin_id.clone()).await?;
 
         Ok(())
@@ -1123,40 +1888,73 @@ fn preferences_to_js(
 }
 
 fn any_preferences_missing_value(preferences: HashMap<String, DbPluginPreference>, preferences_user_data: HashMap<String, DbPluginPreferenceUserData>) -> bool {
-    for (name, preference) in preferences {
-        match preferences_user_data.get(&name) {
-            None => {
-                let no_default = match preference {
-                    DbPluginPreference::Number { default, .. } => default.is_none(),
-                    DbPluginPreference::String { default, .. } => default.is_none(),
-                    DbPluginPreference::Enum { default, .. } => default.is_none(),
-                    DbPluginPreference::Bool { default, .. } => default.is_none(),
-                    DbPluginPreference::ListOfStrings { default, .. } => default.is_none(),
-                    DbPluginPreference::ListOfNumbers { default, .. } => default.is_none(),
-                    DbPluginPreference::ListOfEnums { default, .. } => default.is_none(),
-                };
-
-                if no_default {
-                    return true
-                }
-            }
-            Some(preference) => {
-                let no_value = match preference {
-                    DbPluginPreferenceUserData::Number { value } => value.is_none(),
-                    DbPluginPreferenceUserData::String { value } => value.is_none(),
-                    DbPluginPreferenceUserData::Enum { value } => value.is_none(),
-                    DbPluginPreferenceUserData::Bool { value } => value.is_none(),
-                    DbPluginPreferenceUserData::ListOfStrings { value } => value.is_none(),
-                    DbPluginPreferenceUserData::ListOfNumbers { value } => value.is_none(),
-                    DbPluginPreferenceUserData::ListOfEnums { value } => value.is_none(),
-                };
+    preferences.iter()
+        .any(|(id, preference)| preference_missing_value(preference, preferences_user_data.get(id)))
+}
 
-                if no_value {
-                    return true
-                }
+fn preference_missing_value(preference: &DbPluginPreference, user_data: Option<&DbPluginPreferenceUserData>) -> bool {
+    match user_data {
+        None => preference_no_default(preference),
+        Some(user_data) => {
+            match user_data {
+                DbPluginPreferenceUserData::Number { value } => value.is_none(),
+                DbPluginPreferenceUserData::String { value } => value.is_none(),
+                DbPluginPreferenceUserData::Enum { value } => value.is_none(),
+                DbPluginPreferenceUserData::Bool { value } => value.is_none(),
+                DbPluginPreferenceUserData::ListOfStrings { value } => value.is_none(),
+                DbPluginPreferenceUserData::ListOfNumbers { value } => value.is_none(),
+                DbPluginPreferenceUserData::ListOfEnums { value } => value.is_none(),
             }
         }
     }
+}
+
+fn preference_no_default(preference: &DbPluginPreference) -> bool {
+    match preference {
+        DbPluginPreference::Number { default, .. } => default.is_none(),
+        DbPluginPreference::String { default, .. } => default.is_none(),
+        DbPluginPreference::Enum { default, .. } => default.is_none(),
+        DbPluginPreference::Bool { default, .. } => default.is_none(),
+        DbPluginPreference::ListOfStrings { default, .. } => default.is_none(),
+        DbPluginPreference::ListOfNumbers { default, .. } => default.is_none(),
+        DbPluginPreference::ListOfEnums { default, .. } => default.is_none(),
+    }
+}
+
+// checklist of preferences which don't have default value and have to be set by user
+fn onboarding_preferences(
+    entrypoint_id: Option<EntrypointId>,
+    preferences: HashMap<String, DbPluginPreference>,
+    preferences_user_data: HashMap<String, DbPluginPreferenceUserData>
+) -> Vec<OnboardingPreference> {
+    let mut result: Vec<_> = preferences.into_iter()
+        .filter_map(|(id, preference)| {
+            let missing = preference_missing_value(&preference, preferences_user_data.get(&id));
+
+            if !missing && !preference_no_default(&preference) {
+                return None
+            }
+
+            let (name, description) = match preference {
+                DbPluginPreference::Number { name, description, .. } => (name, description),
+                DbPluginPreference::String { name, description, .. } => (name, description),
+                DbPluginPreference::Enum { name, description, .. } => (name, description),
+                DbPluginPreference::Bool { name, description, .. } => (name, description),
+                DbPluginPreference::ListOfStrings { name, description, .. } => (name, description),
+                DbPluginPreference::ListOfNumbers { name, description, .. } => (name, description),
+                DbPluginPreference::ListOfEnums { name, description, .. } => (name, description),
+            };
+
+            Some(OnboardingPreference {
+                entrypoint_id: entrypoint_id.clone(),
+                name: name.unwrap_or(id),
+                description,
+                filled: !missing,
+            })
+        })
+        .collect();
+
+    result.sort_by(|a, b| a.name.cmp(&b.name));
 
-    false
+    result
 }
\ No newline at end of file
@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+
+use anyhow::anyhow;
+use serde::Deserialize;
+
+use gauntlet_common::model::{KeymapAction, KeymapBinding, PhysicalKey, PhysicalShortcut};
+
+#[derive(Debug, Deserialize, Default, Clone, Copy)]
+pub enum KeymapPreset {
+    #[default]
+    #[serde(rename = "default")]
+    Default,
+    #[serde(rename = "vim")]
+    Vim,
+}
+
+#[derive(Debug, Deserialize, Hash, PartialEq, Eq)]
+pub enum ConfigKeymapAction {
+    #[serde(rename = "next")]
+    Next,
+    #[serde(rename = "previous")]
+    Previous,
+    #[serde(rename = "primary")]
+    Primary,
+    #[serde(rename = "secondary")]
+    Secondary,
+    #[serde(rename = "toggle-action-panel")]
+    ToggleActionPanel,
+    #[serde(rename = "back")]
+    Back,
+    #[serde(rename = "toggle-shortcut-hints")]
+    ToggleShortcutHints,
+    #[serde(rename = "toggle-pin")]
+    TogglePin,
+    #[serde(rename = "detach-view")]
+    DetachView,
+    #[serde(rename = "toggle-preview")]
+    TogglePreview,
+}
+
+// arrows, enter, shift+enter, alt+k, alt+p, alt+d, alt+i, escape and "?" are always available,
+// bindings from keymap are used in addition to them
+pub fn resolve_keymap(preset: KeymapPreset, bindings: HashMap<ConfigKeymapAction, Vec<String>>) -> Vec<KeymapBinding> {
+    let mut keymap = preset_bindings(preset);
+
+    for (action, shortcuts) in bindings {
+        let action = match action {
+            ConfigKeymapAction::Next => KeymapAction::Next,
+            ConfigKeymapAction::Previous => KeymapAction::Previous,
+            ConfigKeymapAction::Primary => KeymapAction::Primary,
+            ConfigKeymapAction::Secondary => KeymapAction::Secondary,
+            ConfigKeymapAction::ToggleActionPanel => KeymapAction::ToggleActionPanel,
+            ConfigKeymapAction::Back => KeymapAction::Back,
+            ConfigKeymapAction::ToggleShortcutHints => KeymapAction::ToggleShortcutHints,
+            ConfigKeymapAction::TogglePin => KeymapAction::TogglePin,
+            ConfigKeymapAction::DetachView => KeymapAction::DetachView,
+            ConfigKeymapAction::TogglePreview => KeymapAction::TogglePreview,
+        };
+
+        // configured shortcuts replace preset shortcuts for the same action
+        keymap.retain(|binding| binding.action != action);
+
+        for shortcut in shortcuts {
+            match parse_shortcut(&shortcut) {
+                Ok(shortcut) => keymap.push(KeymapBinding { action, shortcut }),
+                Err(err) => tracing::error!("Unable to parse keymap shortcut {:?}, error: {:#}", shortcut, err),
+            }
+        }
+    }
+
+    keymap
+}
+
+fn preset_bindings(preset: KeymapPreset) -> Vec<KeymapBinding> {
+    match preset {
+        KeymapPreset::Default => vec![],
+        KeymapPreset::Vim => vec![
+            KeymapBinding {
+                action: KeymapAction::Next,
+                shortcut: control(PhysicalKey::KeyJ),
+            },
+            KeymapBinding {
+                action: KeymapAction::Previous,
+                shortcut: control(PhysicalKey::KeyK),
+            },
+        ]
+    }
+}
+
+fn control(physical_key: PhysicalKey) -> PhysicalShortcut {
+    PhysicalShortcut {
+        physical_key,
+        modifier_shift: false,
+        modifier_control: true,
+        modifier_alt: false,
+        modifier_meta: false,
+    }
+}
+
+// "ctrl+shift+j" -> PhysicalShortcut
+fn parse_shortcut(value: &str) -> anyhow::Result<PhysicalShortcut> {
+    let mut shortcut = PhysicalShortcut {
+        physical_key: PhysicalKey::Escape,
+        modifier_shift: false,
+        modifier_control: false,
+        modifier_alt: false,
+        modifier_meta: false,
+    };
+
+    let mut key = None;
+
+    for part in value.split('+').map(|part| part.trim().to_lowercase()) {
+        match part.as_str() {
+            "shift" => shortcut.modifier_shift = true,
+            "ctrl" | "control" => shortcut.modifier_control = true,
+            "alt" | "option" => shortcut.modifier_alt = true,
+            "meta" | "cmd" | "super" | "win" => shortcut.modifier_meta = true,
+            part => {
+                if key.is_some() {
+                    return Err(anyhow!("more than one non-modifier key"))
+                }
+
+                key = Some(parse_key(part)?)
+            }
+        }
+    }
+
+    shortcut.physical_key = key.ok_or(anyhow!("no non-modifier key"))?;
+
+    Ok(shortcut)
+}
+
+fn parse_key(value: &str) -> anyhow::Result<PhysicalKey> {
+    let key = match value {
+        "up" => "ArrowUp",
+        "down" => "ArrowDown",
+        "left" => "ArrowLeft",
+        "right" => "ArrowRight",
+        "enter" | "return" => "Enter",
+        "escape" | "esc" => "Escape",
+        "tab" => "Tab",
+        "space" => "Space",
+        "backspace" => "Backspace",
+        "[" => "BracketLeft",
+        "]" => "BracketRight",
+        ";" => "Semicolon",
+        "," => "Comma",
+        "." => "Period",
+        "/" => "Slash",
+        "f1" => "F1",
+        "f2" => "F2",
+        "f3" => "F3",
+        "f4" => "F4",
+        "f5" => "F5",
+        "f6" => "F6",
+        "f7" => "F7",
+        "f8" => "F8",
+        "f9" => "F9",
+        "f10" => "F10",
+        "f11" => "F11",
+        "f12" => "F12",
+        _ => {
+            let mut chars = value.chars();
+
+            return match (chars.next(), chars.next()) {
+                (Some(char @ 'a'..='z'), None) => Ok(PhysicalKey::from_value(format!("Key{}", char.to_ascii_uppercase()))),
+                (Some(char @ '0'..='9'), None) => Ok(PhysicalKey::from_value(format!("Digit{}", char))),
+                _ => Err(anyhow!("unknown key: {}", value))
+            }
+        }
+    };
+
+    Ok(PhysicalKey::from_value(key.to_string()))
+}
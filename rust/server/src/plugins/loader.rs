@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::ffi::OsStr;
 use std::fs::DirEntry;
 use std::io::{ErrorKind};
@@ -12,12 +12,15 @@ use uuid::Uuid;
 use walkdir::WalkDir;
 use itertools::Itertools;
 use once_cell::sync::Lazy;
+use regex::Regex;
 use typed_path::{TypedPathBuf, Utf8TypedPath, Utf8UnixComponent, Utf8WindowsComponent, Utf8WindowsPrefix, Utf8WindowsPrefixComponent};
-use gauntlet_common::model::{DownloadStatus, PluginId};
-use gauntlet_plugin_runtime::PERMISSIONS_VARIABLE_PATTERN;
+use gauntlet_common::i18n::parse_locale;
+use gauntlet_common::model::{DownloadStatus, PluginId, PluginManifestDiagnostic};
+use gauntlet_plugin_runtime::{NODE_COMPAT_MODULES, PERMISSIONS_VARIABLE_PATTERN};
 use crate::model::ActionShortcutKey;
-use crate::plugins::data_db_repository::{DataDbRepository, db_entrypoint_to_str, db_plugin_type_to_str, DbCode, DbPluginAction, DbPluginActionShortcutKind, DbPluginEntrypointType, DbPluginPermissions, DbPluginPreference, DbPluginPreferenceUserData, DbPluginType, DbPreferenceEnumValue, DbWritePlugin, DbWritePluginAssetData, DbWritePluginEntrypoint, DbPluginClipboardPermissions, DbPluginMainSearchBarPermissions, DbPluginPermissionsFileSystem, DbPluginPermissionsExec};
+use crate::plugins::data_db_repository::{DataDbRepository, db_entrypoint_to_str, db_plugin_type_to_str, DbCode, DbPluginAction, DbPluginActionShortcutKind, DbPluginArgument, DbPluginEntrypointTranslation, DbPluginEntrypointType, DbPluginPermissions, DbPluginPreference, DbPluginPreferenceUserData, DbPluginType, DbPreferenceEnumValue, DbWritePlugin, DbWritePluginAssetData, DbWritePluginEntrypoint, DbPluginClipboardPermissions, DbPluginMainSearchBarPermissions, DbPluginPermissionsFileSystem, DbPluginPermissionsExec, DbPluginHttpCache};
 use crate::plugins::download_status::DownloadStatusHolder;
+use crate::plugins::image_store::content_hash;
 
 pub struct PluginLoader {
     db_repository: DataDbRepository,
@@ -37,7 +40,7 @@ impl PluginLoader {
         self.download_status_holder.download_status()
     }
 
-    pub async fn download_plugin(&self, plugin_id: PluginId) -> anyhow::Result<()> {
+    pub async fn download_plugin(&self, plugin_id: PluginId, proxy: Option<String>) -> anyhow::Result<()> {
         let download_status_guard = self.download_status_holder.download_started(plugin_id.clone());
 
         let data_db_repository = self.db_repository.clone();
@@ -48,7 +51,7 @@ impl PluginLoader {
             let result = handle.block_on(async move {
                 let temp_dir = tempfile::tempdir()?;
 
-                PluginLoader::download(temp_dir.path(), plugin_id_clone.clone())?;
+                PluginLoader::download(temp_dir.path(), plugin_id_clone.clone(), proxy)?;
 
                 let plugin_data = PluginLoader::read_plugin_dir(temp_dir.path(), plugin_id_clone.clone())
                     .await?;
@@ -64,6 +67,8 @@ impl PluginLoader {
                     permissions: plugin_data.permissions,
                     plugin_type: db_plugin_type_to_str(DbPluginType::Normal).to_owned(),
                     preferences: plugin_data.preferences,
+                    onboarding: plugin_data.onboarding,
+                    http_cache: plugin_data.http_cache,
                 }).await?;
 
                 anyhow::Ok(())
@@ -106,11 +111,74 @@ impl PluginLoader {
             permissions: plugin_data.permissions,
             plugin_type: db_plugin_type_to_str(DbPluginType::Normal).to_owned(),
             preferences: plugin_data.preferences,
+            onboarding: plugin_data.onboarding,
+            http_cache: plugin_data.http_cache,
         }).await?;
 
         Ok(plugin_id)
     }
 
+    pub fn validate_local_plugin(&self, path: &str) -> anyhow::Result<()> {
+        let plugin_id = PluginId::from_string(format!("file://{}", &path));
+
+        let plugin_dir = plugin_id.try_to_path()?.join("dist");
+
+        Self::validate_plugin_dir(&plugin_dir)
+    }
+
+    // all problems are put into error message, so that dev tools and settings can show them as is
+    fn validate_plugin_dir(plugin_dir: &Path) -> anyhow::Result<()> {
+        let mut diagnostics = Self::validate_plugin_manifest(&plugin_dir.join("gauntlet.toml"))?;
+
+        diagnostics.extend(Self::validate_node_imports(&plugin_dir.join("js"))?);
+
+        if !diagnostics.is_empty() {
+            return Err(anyhow!("Plugin manifest is not valid:\n{}", diagnostics.iter().join("\n")))
+        }
+
+        Ok(())
+    }
+
+    // node built-in modules are provided only partially, so unsupported ones are reported right after plugin is built
+    fn validate_node_imports(js_dir: &Path) -> anyhow::Result<Vec<PluginManifestDiagnostic>> {
+        static NODE_IMPORT_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(?:from|import|require)\s*\(?\s*["']node:(?<module>[a-zA-Z0-9_/]+)["']"#).expect("invalid regex"));
+
+        let mut diagnostics = vec![];
+
+        let js_files = std::fs::read_dir(js_dir)
+            .context(js_dir.display().to_string())?;
+
+        for js_file in js_files {
+            let js_file = js_file?.path();
+
+            if js_file.extension() != Some(OsStr::new("js")) {
+                continue;
+            }
+
+            let js_content = std::fs::read_to_string(&js_file)?;
+
+            let unsupported = NODE_IMPORT_PATTERN.captures_iter(&js_content)
+                .map(|captures| captures["module"].to_string())
+                .filter(|module| !NODE_COMPAT_MODULES.contains(&module.as_str()))
+                .unique()
+                .collect::<Vec<_>>();
+
+            if !unsupported.is_empty() {
+                let file_name = js_file.file_name()
+                    .map(|file_name| file_name.to_string_lossy().to_string())
+                    .unwrap_or_default();
+
+                diagnostics.push(PluginManifestDiagnostic {
+                    field: format!("js/{}", file_name),
+                    reason: format!("Imports node modules which are not supported in plugins: {}", unsupported.iter().map(|module| format!("node:{}", module)).join(", ")),
+                    suggestion: Some(format!("Remove dependencies which use these modules. Supported modules: {}", NODE_COMPAT_MODULES.iter().map(|module| format!("node:{}", module)).join(", "))),
+                })
+            }
+        }
+
+        Ok(diagnostics)
+    }
+
     pub async fn save_bundled_plugin(&self, id: &str, dir: &Dir<'_>) -> anyhow::Result<PluginId> {
         let plugin_id = PluginId::from_string(format!("bundled://{id}"));
         let temp_dir = tempfile::tempdir()?;
@@ -132,16 +200,28 @@ impl PluginLoader {
             permissions: plugin_data.permissions,
             plugin_type: db_plugin_type_to_str(DbPluginType::Bundled).to_owned(),
             preferences: plugin_data.preferences,
+            onboarding: plugin_data.onboarding,
+            http_cache: plugin_data.http_cache,
         }).await?;
 
         Ok(plugin_id)
     }
 
-    fn download(target_dir: &Path, plugin_id: PluginId) -> anyhow::Result<()> {
+    fn download(target_dir: &Path, plugin_id: PluginId, proxy: Option<String>) -> anyhow::Result<()> {
         let url = plugin_id.try_to_git_url()?;
 
+        let mut fetch_options = git2::FetchOptions::new();
+
+        if let Some(proxy) = proxy {
+            let mut proxy_options = git2::ProxyOptions::new();
+            proxy_options.url(&proxy);
+
+            fetch_options.proxy_options(proxy_options);
+        }
+
         let _ = git2::build::RepoBuilder::new()
             .branch("gauntlet/release")
+            .fetch_options(fetch_options)
             .clone(&url, target_dir)?;
 
         Ok(())
@@ -198,9 +278,12 @@ impl PluginLoader {
                     .ok_or(anyhow!("filename is not a valid utf-8"))?
                     .to_owned();
 
+                let hash = content_hash(&data);
+
                 Ok(DbWritePluginAssetData {
                     path,
                     data,
+                    hash,
                 })
             })
             .collect::<anyhow::Result<Vec<_>>>()
@@ -211,13 +294,11 @@ impl PluginLoader {
         let plugin_manifest_path = plugin_dir.join("gauntlet.toml");
         let plugin_manifest_path_context = plugin_manifest_path.display().to_string();
         let plugin_manifest_content = std::fs::read_to_string(plugin_manifest_path).context(plugin_manifest_path_context)?;
-        let plugin_manifest: PluginManifest = toml::from_str(&plugin_manifest_content)
-            .context("Unable to read plugin manifest")?;
+        let plugin_manifest = Self::parse_manifest(&plugin_manifest_content)
+            .map_err(|diagnostics| anyhow!("Plugin manifest is not valid:\n{}", diagnostics.iter().join("\n")))?;
 
         tracing::debug!("Plugin config read: {:?}", plugin_manifest);
 
-        Self::validate_manifest(&plugin_manifest)?;
-
         let plugin_name = plugin_manifest.gauntlet.name;
         let plugin_description = plugin_manifest.gauntlet.description;
 
@@ -233,6 +314,7 @@ impl PluginLoader {
                     PluginManifestEntrypointTypes::View => DbPluginEntrypointType::View,
                     PluginManifestEntrypointTypes::InlineView => DbPluginEntrypointType::InlineView,
                     PluginManifestEntrypointTypes::EntrypointGenerator => DbPluginEntrypointType::EntrypointGenerator,
+                    PluginManifestEntrypointTypes::SearchProvider => DbPluginEntrypointType::SearchProvider,
                 }).to_owned(),
                 preferences: entrypoint.preferences
                     .into_iter()
@@ -269,6 +351,25 @@ impl PluginLoader {
                         },
                     })
                     .collect(),
+                arguments: entrypoint.arguments.into_iter()
+                    .map(|argument| match argument {
+                        PluginManifestArgument::String { id, name, required } => DbPluginArgument::String { id, name, required },
+                        PluginManifestArgument::Enum { id, name, required, enum_values } => {
+                            let enum_values = enum_values.into_iter()
+                                .map(|PluginManifestPreferenceEnumValue { label, value } | DbPreferenceEnumValue { label, value })
+                                .collect();
+
+                            DbPluginArgument::Enum { id, name, required, enum_values }
+                        },
+                        PluginManifestArgument::File { id, name, required } => DbPluginArgument::File { id, name, required },
+                    })
+                    .collect(),
+                translations: entrypoint.translations.into_iter()
+                    .map(|(locale, PluginManifestEntrypointTranslation { name, description })| (locale, DbPluginEntrypointTranslation { name, description }))
+                    .collect(),
+                subtitle: entrypoint.subtitle,
+                keywords: entrypoint.keywords,
+                accessory: entrypoint.accessory,
             })
             .collect();
 
@@ -346,11 +447,64 @@ impl PluginLoader {
             asset_data,
             permissions,
             preferences: plugin_preferences,
-            preferences_user_data: HashMap::new()
+            preferences_user_data: HashMap::new(),
+            onboarding: plugin_manifest.onboarding.map(|onboarding| onboarding.description),
+            http_cache: plugin_manifest.http_cache.map(|http_cache| DbPluginHttpCache {
+                max_size: http_cache.max_size_mb * 1024 * 1024,
+                stale_while_revalidate: http_cache.stale_while_revalidate,
+            }),
         })
     }
 
-    fn validate_manifest(plugin_manifest: &PluginManifest) -> anyhow::Result<()> {
+    // parsing and validation report all problems at once with a field path, so that plugin author can fix them in one go
+    fn parse_manifest(plugin_manifest_content: &str) -> Result<PluginManifest, Vec<PluginManifestDiagnostic>> {
+        let deserializer = toml::Deserializer::new(plugin_manifest_content);
+
+        let plugin_manifest: PluginManifest = serde_path_to_error::deserialize(deserializer)
+            .map_err(|err| vec![Self::parse_error_diagnostic(err)])?;
+
+        let diagnostics = Self::validate_manifest(&plugin_manifest);
+
+        if diagnostics.is_empty() {
+            Ok(plugin_manifest)
+        } else {
+            Err(diagnostics)
+        }
+    }
+
+    fn parse_error_diagnostic(err: serde_path_to_error::Error<toml::de::Error>) -> PluginManifestDiagnostic {
+        let field = err.path().to_string();
+        let reason = err.inner().message().to_string();
+
+        let suggestion = if reason.starts_with("missing field") {
+            Some("Add the missing field, see manifest reference at https://gauntlet.sh/docs/plugin-development/plugin-manifest".to_string())
+        } else if reason.starts_with("unknown variant") {
+            Some("Use one of the expected values".to_string())
+        } else if reason.starts_with("invalid type") {
+            Some("Change value to the expected type".to_string())
+        } else {
+            None
+        };
+
+        PluginManifestDiagnostic {
+            // "." means error is not related to specific field, e.g. invalid toml syntax
+            field: if field == "." { "manifest".to_string() } else { field },
+            reason,
+            suggestion,
+        }
+    }
+
+    fn validate_manifest(plugin_manifest: &PluginManifest) -> Vec<PluginManifestDiagnostic> {
+        let mut diagnostics = vec![];
+
+        let mut diagnostic = |field: String, reason: String, suggestion: &str| {
+            diagnostics.push(PluginManifestDiagnostic {
+                field,
+                reason,
+                suggestion: Some(suggestion.to_string()),
+            })
+        };
+
         let supported_systems = &plugin_manifest.supported_system;
         let supported_systems_str = supported_systems.iter().format(", ");
 
@@ -360,18 +514,47 @@ impl PluginLoader {
 
         let permissions = &plugin_manifest.permissions;
 
-        Self::validate_string_permissions(&permissions.environment)?;
-        Self::validate_network_permissions(&permissions.network)?;
-        Self::validate_path_permissions(&permissions.filesystem.read, supports_linux, supports_macos, supports_windows)?;
-        Self::validate_path_permissions(&permissions.filesystem.write, supports_linux, supports_macos, supports_windows)?;
-        Self::validate_command_permissions(&permissions.exec.command)?;
-        Self::validate_path_permissions(&permissions.exec.executable, supports_linux, supports_macos, supports_windows)?;
+        for (index, value) in permissions.environment.iter().enumerate() {
+            if let Err(err) = Self::validate_string_permission(value) {
+                diagnostic(format!("permissions.environment[{}]", index), err.to_string(), "Remove empty value or specify environment variable name");
+            }
+        }
+
+        for (index, value) in permissions.network.iter().enumerate() {
+            if let Err(err) = Self::validate_network_permission(value) {
+                diagnostic(format!("permissions.network[{}]", index), format!("{:#}", err), "Specify only domain and optionally port, e.g. \"api.github.com\" or \"localhost:8080\"");
+            }
+        }
+
+        let path_permissions = [
+            ("permissions.filesystem.read", &permissions.filesystem.read),
+            ("permissions.filesystem.write", &permissions.filesystem.write),
+            ("permissions.exec.executable", &permissions.exec.executable),
+        ];
+
+        for (field, paths) in path_permissions {
+            for (index, path) in paths.iter().enumerate() {
+                if let Err(err) = Self::validate_path_permission(path, supports_linux, supports_macos, supports_windows) {
+                    diagnostic(format!("{}[{}]", field, index), err.to_string(), "Use absolute path, optionally starting with variable, e.g. \"{linux:user-home}/.config\"");
+                }
+            }
+        }
+
+        for (index, value) in permissions.exec.command.iter().enumerate() {
+            if let Err(err) = Self::validate_command_permission(value) {
+                diagnostic(format!("permissions.exec.command[{}]", index), err.to_string(), "Specify command name, e.g. \"git\", or move path to \"permissions.exec.executable\"");
+            }
+        }
 
         // even though system accepts a list of predefined values
         // unknown values are ignored to allow for easier
         // adoption to breaking changes in deno
         // TODO do a warning
-        Self::validate_string_permissions(&permissions.system)?;
+        for (index, value) in permissions.system.iter().enumerate() {
+            if let Err(err) = Self::validate_string_permission(value) {
+                diagnostic(format!("permissions.system[{}]", index), err.to_string(), "Remove empty value or specify system api name");
+            }
+        }
 
         let env_exists = !permissions.environment.is_empty();
         let fs_read_exists = !permissions.filesystem.read.is_empty();
@@ -394,139 +577,191 @@ impl PluginLoader {
             };
 
             if !supported_systems.contains(&current_system) {
-                return Err(anyhow!("Plugin doesn't support current operating system. Operating systems supported by plugin: [{}]", supported_systems_str))
+                diagnostic(
+                    "supported_system".to_string(),
+                    format!("Plugin doesn't support current operating system. Operating systems supported by plugin: [{}]", supported_systems_str),
+                    "Plugins which use environment, filesystem, exec or system permissions need to list supported operating systems, e.g. supported_system = [\"linux\", \"macos\"]"
+                );
             }
         }
 
-        let has_inline_view = plugin_manifest.entrypoint
-            .iter()
-            .find(|entrypoint| matches!(entrypoint.entrypoint_type, PluginManifestEntrypointTypes::InlineView))
-            .is_some();
+        for (index, entrypoint) in plugin_manifest.entrypoint.iter().enumerate() {
+            let search_bar_type = match entrypoint.entrypoint_type {
+                PluginManifestEntrypointTypes::InlineView => Some("inline-view"),
+                PluginManifestEntrypointTypes::SearchProvider => Some("search-provider"),
+                _ => None
+            };
 
-        if has_inline_view {
-            let main_search_bar = &permissions.main_search_bar;
-            if !main_search_bar.contains(&PluginManifestMainSearchBarPermissions::Read) {
-                return Err(anyhow!("Plugin uses entrypoint type 'inline-view' but doesn't specify main search bar 'read' permission"))
+            if let Some(search_bar_type) = search_bar_type {
+                if !permissions.main_search_bar.contains(&PluginManifestMainSearchBarPermissions::Read) {
+                    diagnostic(
+                        format!("entrypoint[{}].type", index),
+                        format!("Entrypoint '{}' has type '{}' but plugin doesn't specify main search bar 'read' permission", entrypoint.id, search_bar_type),
+                        "Add main_search_bar = [\"read\"] to [permissions] section"
+                    );
+                }
+            }
+
+            for locale in entrypoint.translations.keys() {
+                if parse_locale(locale).is_none() {
+                    diagnostic(
+                        format!("entrypoint[{}].translations.{}", index, locale),
+                        format!("Entrypoint '{}' has translation for invalid locale '{}'", entrypoint.id, locale),
+                        "Use BCP 47 language tag, e.g. \"de\" or \"pt-BR\""
+                    );
+                }
+            }
+
+            if entrypoint.arguments.is_empty() {
+                continue;
+            }
+
+            if !matches!(entrypoint.entrypoint_type, PluginManifestEntrypointTypes::Command) {
+                diagnostic(
+                    format!("entrypoint[{}].arguments", index),
+                    format!("Entrypoint '{}' specifies arguments, but only entrypoints with type 'command' can have arguments", entrypoint.id),
+                    "Remove arguments or change entrypoint type to 'command'"
+                );
+            }
+
+            let mut argument_ids = HashSet::new();
+
+            for (argument_index, argument) in entrypoint.arguments.iter().enumerate() {
+                if !argument_ids.insert(argument.id()) {
+                    diagnostic(
+                        format!("entrypoint[{}].arguments[{}].id", index, argument_index),
+                        format!("Entrypoint '{}' has multiple arguments with id '{}'", entrypoint.id, argument.id()),
+                        "Give each argument unique id"
+                    );
+                }
             }
         }
 
-        Ok(())
+        diagnostics
     }
 
-    fn validate_path_permissions(paths: &[String], supports_linux: &bool, supports_macos: &bool, supports_windows: &bool) -> anyhow::Result<()> {
-        for path in paths {
-            if path.is_empty() {
-                Err(anyhow!("Empty path is not allowed in permissions"))?
-            }
+    pub fn validate_plugin_manifest(plugin_manifest_path: &Path) -> anyhow::Result<Vec<PluginManifestDiagnostic>> {
+        let plugin_manifest_content = std::fs::read_to_string(plugin_manifest_path)
+            .context(plugin_manifest_path.display().to_string())?;
 
-            // TODO custom parser for fun? for better error reporting, that will include cross-platform path parser
+        match Self::parse_manifest(&plugin_manifest_content) {
+            Ok(_) => Ok(vec![]),
+            Err(diagnostics) => Ok(diagnostics)
+        }
+    }
 
-            let matches = PERMISSIONS_VARIABLE_PATTERN.captures_iter(path).collect::<Vec<_>>();
-            let augmented_path = match matches.as_slice() {
-                [] => path.to_owned(),
-                [variable] => {
-                    // TODO replace when https://github.com/rust-lang/regex/issues/1146 is resolved
-                    let pattern_match = variable.get(0).unwrap();
+    fn validate_path_permission(path: &str, supports_linux: &bool, supports_macos: &bool, supports_windows: &bool) -> anyhow::Result<()> {
+        if path.is_empty() {
+            Err(anyhow!("Empty path is not allowed in permissions"))?
+        }
 
-                    if pattern_match.start() != 0 {
-                        Err(anyhow!("Variable can only be used in the beginning of the path: {}", path))?
-                    }
+        // TODO custom parser for fun? for better error reporting, that will include cross-platform path parser
 
-                    let mut path_bytes = path.bytes();
-                    path_bytes.nth(pattern_match.end() - 1).expect("end of match should always exist");
+        let matches = PERMISSIONS_VARIABLE_PATTERN.captures_iter(path).collect::<Vec<_>>();
+        let augmented_path = match matches.as_slice() {
+            [] => path.to_owned(),
+            [variable] => {
+                // TODO replace when https://github.com/rust-lang/regex/issues/1146 is resolved
+                let pattern_match = variable.get(0).unwrap();
 
-                    let windows_like_path = match path_bytes.next() {
-                        Some(b'\\') => true,
-                        Some(b'/') | None => false,
-                        Some(byte) => {
-                            // this is done to prohibit "{linux:user-home}test" which for variable "/home/user" would result into "/home/usertest"
-                            Err(anyhow!("Variable should always be followed with a slash or end of string, instead followed with {}, path: {}", byte as char, path))?
-                        }
-                    };
-
-                    let namespace = &variable["namespace"];
-                    let name = &variable["name"];
-
-                    let windows_like_path = match (namespace, name) {
-                        ("macos", "user-home") => false,
-                        ("linux", "user-home") => false,
-                        ("windows", "user-home") => windows_like_path,
-                        ("common", "plugin-data") => windows_like_path,
-                        ("common", "plugin-cache") => windows_like_path,
-                        (namespace, name) => {
-                            Err(anyhow!("Unknown variable namespace and name combination in path in permissions: {}:{}", namespace, name))?
-                        }
-                    };
+                if pattern_match.start() != 0 {
+                    Err(anyhow!("Variable can only be used in the beginning of the path: {}", path))?
+                }
+
+                let mut path_bytes = path.bytes();
+                path_bytes.nth(pattern_match.end() - 1).expect("end of match should always exist");
 
-                    if windows_like_path {
-                        PERMISSIONS_VARIABLE_PATTERN.replace(path, "C:\\dummy-root").to_string()
-                    } else {
-                        PERMISSIONS_VARIABLE_PATTERN.replace(path, "/dummy-root").to_string()
+                let windows_like_path = match path_bytes.next() {
+                    Some(b'\\') => true,
+                    Some(b'/') | None => false,
+                    Some(byte) => {
+                        // this is done to prohibit "{linux:user-home}test" which for variable "/home/user" would result into "/home/usertest"
+                        Err(anyhow!("Variable should always be followed with a slash or end of string, instead followed with {}, path: {}", byte as char, path))?
                     }
+                };
+
+                let namespace = &variable["namespace"];
+                let name = &variable["name"];
+
+                let windows_like_path = match (namespace, name) {
+                    ("macos", "user-home") => false,
+                    ("linux", "user-home") => false,
+                    ("windows", "user-home") => windows_like_path,
+                    ("common", "plugin-data") => windows_like_path,
+                    ("common", "plugin-cache") => windows_like_path,
+                    (namespace, name) => {
+                        Err(anyhow!("Unknown variable namespace and name combination in path in permissions: {}:{}", namespace, name))?
+                    }
+                };
+
+                if windows_like_path {
+                    PERMISSIONS_VARIABLE_PATTERN.replace(path, "C:\\dummy-root").to_string()
+                } else {
+                    PERMISSIONS_VARIABLE_PATTERN.replace(path, "/dummy-root").to_string()
                 }
-                [_, ..] => {
-                    Err(anyhow!("Path includes more than one variable: {}", path))?
-                }
-            };
+            }
+            [_, ..] => {
+                Err(anyhow!("Path includes more than one variable: {}", path))?
+            }
+        };
 
-            let path = Utf8TypedPath::derive(&augmented_path);
+        let path = Utf8TypedPath::derive(&augmented_path);
 
-            if !path.is_absolute() {
-                Err(anyhow!("Relative path is not allowed in permissions: {}", path))?
-            }
+        if !path.is_absolute() {
+            Err(anyhow!("Relative path is not allowed in permissions: {}", path))?
+        }
 
-            match path {
-                Utf8TypedPath::Unix(path) => {
-                    if !supports_macos && !supports_linux {
-                        Err(anyhow!("When using unix-style path in permissions, plugin is required to include \"linux\" or \"macos\" in \"supported_system\" manifest property: {}", path))?
-                    }
+        match path {
+            Utf8TypedPath::Unix(path) => {
+                if !supports_macos && !supports_linux {
+                    Err(anyhow!("When using unix-style path in permissions, plugin is required to include \"linux\" or \"macos\" in \"supported_system\" manifest property: {}", path))?
+                }
 
-                    if !path.is_valid() {
-                        Err(anyhow!("Path is not valid: {}", path))?
-                    }
+                if !path.is_valid() {
+                    Err(anyhow!("Path is not valid: {}", path))?
+                }
 
-                    for component in path.components() {
-                        match component {
-                            Utf8UnixComponent::Normal(_) | Utf8UnixComponent::RootDir => {}
-                            Utf8UnixComponent::CurDir => {
-                                Err(anyhow!("Current directory '.' segment is not allowed in permission path: {}", path))?
-                            }
-                            Utf8UnixComponent::ParentDir => {
-                                Err(anyhow!("Parent directory '..' segment is not allowed in permission path: {}", path))?
-                            }
+                for component in path.components() {
+                    match component {
+                        Utf8UnixComponent::Normal(_) | Utf8UnixComponent::RootDir => {}
+                        Utf8UnixComponent::CurDir => {
+                            Err(anyhow!("Current directory '.' segment is not allowed in permission path: {}", path))?
+                        }
+                        Utf8UnixComponent::ParentDir => {
+                            Err(anyhow!("Parent directory '..' segment is not allowed in permission path: {}", path))?
                         }
                     }
                 }
-                Utf8TypedPath::Windows(path) => {
-                    if !supports_windows {
-                        Err(anyhow!("When using windows-style path in permissions, plugin is required to include \"windows\" in \"supported_system\" manifest property: {}", path))?
-                    }
+            }
+            Utf8TypedPath::Windows(path) => {
+                if !supports_windows {
+                    Err(anyhow!("When using windows-style path in permissions, plugin is required to include \"windows\" in \"supported_system\" manifest property: {}", path))?
+                }
 
-                    if !path.is_valid() {
-                        Err(anyhow!("Path is not valid: {}", path))?
-                    }
+                if !path.is_valid() {
+                    Err(anyhow!("Path is not valid: {}", path))?
+                }
 
-                    let components = path.components();
+                let components = path.components();
 
-                    let prefix = components.prefix()
-                        .expect("prefix should always be present for absolute paths");
+                let prefix = components.prefix()
+                    .expect("prefix should always be present for absolute paths");
 
-                    match prefix.kind() {
-                        Utf8WindowsPrefix::Disk('C') => {}
-                        _ => {
-                            Err(anyhow!("Only C:/ drive prefix in windows paths is supported, prefix: {}", prefix.as_str()))?
-                        }
+                match prefix.kind() {
+                    Utf8WindowsPrefix::Disk('C') => {}
+                    _ => {
+                        Err(anyhow!("Only C:/ drive prefix in windows paths is supported, prefix: {}", prefix.as_str()))?
                     }
+                }
 
-                    for component in components {
-                        match component {
-                            Utf8WindowsComponent::Normal(_) | Utf8WindowsComponent::RootDir | Utf8WindowsComponent::Prefix(_) => {}
-                            Utf8WindowsComponent::CurDir => {
-                                Err(anyhow!("Current directory '.' segment is not allowed in permission path: {}", path))?
-                            }
-                            Utf8WindowsComponent::ParentDir => {
-                                Err(anyhow!("Parent directory '..' segment is not allowed in permission path: {}", path))?
-                            }
+                for component in components {
+                    match component {
+                        Utf8WindowsComponent::Normal(_) | Utf8WindowsComponent::RootDir | Utf8WindowsComponent::Prefix(_) => {}
+                        Utf8WindowsComponent::CurDir => {
+                            Err(anyhow!("Current directory '.' segment is not allowed in permission path: {}", path))?
+                        }
+                        Utf8WindowsComponent::ParentDir => {
+                            Err(anyhow!("Parent directory '..' segment is not allowed in permission path: {}", path))?
                         }
                     }
                 }
@@ -536,47 +771,42 @@ impl PluginLoader {
         Ok(())
     }
 
-    fn validate_string_permissions(values: &[String]) -> anyhow::Result<()> {
-        for value in values {
-            if value.is_empty() {
-                Err(anyhow!("Empty string value is not allowed in permissions"))?
-            }
+    fn validate_string_permission(value: &str) -> anyhow::Result<()> {
+        if value.is_empty() {
+            Err(anyhow!("Empty string value is not allowed in permissions"))?
         }
 
         Ok(())
     }
 
-    fn validate_command_permissions(values: &[String]) -> anyhow::Result<()> {
-        Self::validate_string_permissions(values)?;
+    fn validate_command_permission(value: &str) -> anyhow::Result<()> {
+        Self::validate_string_permission(value)?;
 
-        for value in values {
-            if value.contains("/") || value.contains("\\") {
-                Err(anyhow!("Command permissions value cannot be a path"))?
-            }
+        if value.contains("/") || value.contains("\\") {
+            Err(anyhow!("Command permissions value cannot be a path"))?
         }
 
         Ok(())
     }
 
-    fn validate_network_permissions(values: &[String]) -> anyhow::Result<()> {
-        for value in values {
-            if value.is_empty() {
-                Err(anyhow!("Empty string value is not allowed in permissions"))?
-            }
+    fn validate_network_permission(value: &str) -> anyhow::Result<()> {
+        if value.is_empty() {
+            Err(anyhow!("Empty string value is not allowed in permissions"))?
+        }
 
-            let url = url::Url::parse(&format!("http://{value}"))?;
+        let url = url::Url::parse(&format!("http://{value}"))?;
 
-            let contains_username = !url.username().is_empty();
-            let contains_password = matches!(url.password(), Some(_));
-            let contains_path = url.path() != "/";
-            let contains_query = matches!(url.query(), Some(_));
-            let contains_fragment = matches!(url.fragment(), Some(_));
+        let contains_username = !url.username().is_empty();
+        let contains_password = matches!(url.password(), Some(_));
+        let contains_path = url.path() != "/";
+        let contains_query = matches!(url.query(), Some(_));
+        let contains_fragment = matches!(url.fragment(), Some(_));
 
-            // allow only domain and optional port
-            if contains_username || contains_password || contains_path || contains_query || contains_fragment {
-                Err(anyhow!("Network permission can only contain domain and optionally port: {}", value))?
-            }
+        // allow only domain and optional port
+        if contains_username || contains_password || contains_path || contains_query || contains_fragment {
+            Err(anyhow!("Network permission can only contain domain and optionally port: {}", value))?
         }
+
         Ok(())
     }
 }
@@ -591,6 +821,8 @@ struct PluginDownloadData {
     pub permissions: DbPluginPermissions,
     pub preferences: HashMap<String, DbPluginPreference>,
     pub preferences_user_data: HashMap<String, DbPluginPreferenceUserData>,
+    pub onboarding: Option<String>,
+    pub http_cache: Option<DbPluginHttpCache>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -603,6 +835,28 @@ struct PluginManifest {
     permissions: PluginManifestPermissions,
     #[serde(default)]
     preferences: Vec<PluginManifestPreference>,
+    onboarding: Option<PluginManifestOnboarding>,
+    http_cache: Option<PluginManifestHttpCache>,
+}
+
+// fetch responses are cached only when plugin opts in
+#[derive(Debug, Deserialize)]
+struct PluginManifestHttpCache {
+    #[serde(default = "default_http_cache_max_size_mb")]
+    max_size_mb: u64,
+    // cached response is returned right away even if it is stale, and revalidated in background
+    #[serde(default)]
+    stale_while_revalidate: bool,
+}
+
+fn default_http_cache_max_size_mb() -> u64 {
+    10
+}
+
+#[derive(Debug, Deserialize)]
+struct PluginManifestOnboarding {
+    // markdown, shown together with required preferences the first time one of entrypoints is used
+    description: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -619,6 +873,59 @@ struct PluginManifestEntrypoint {
     preferences: Vec<PluginManifestPreference>,
     #[serde(default)]
     actions: Vec<PluginManifestAction>,
+    #[serde(default)]
+    arguments: Vec<PluginManifestArgument>,
+    #[serde(default)]
+    translations: HashMap<String, PluginManifestEntrypointTranslation>,
+    subtitle: Option<String>,
+    // additional words by which entrypoint can be found in main search
+    #[serde(default)]
+    keywords: Vec<String>,
+    // short text shown on the right side of search result, e.g. "⌘K"
+    accessory: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PluginManifestEntrypointTranslation {
+    name: Option<String>,
+    description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type")]
+enum PluginManifestArgument {
+    #[serde(rename = "string")]
+    String {
+        id: String,
+        name: String,
+        #[serde(default)]
+        required: bool,
+    },
+    #[serde(rename = "enum")]
+    Enum {
+        id: String,
+        name: String,
+        #[serde(default)]
+        required: bool,
+        enum_values: Vec<PluginManifestPreferenceEnumValue>,
+    },
+    #[serde(rename = "file")]
+    File {
+        id: String,
+        name: String,
+        #[serde(default)]
+        required: bool,
+    },
+}
+
+impl PluginManifestArgument {
+    fn id(&self) -> &str {
+        match self {
+            PluginManifestArgument::String { id, .. } => id,
+            PluginManifestArgument::Enum { id, .. } => id,
+            PluginManifestArgument::File { id, .. } => id,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -693,6 +1000,8 @@ pub enum PluginManifestEntrypointTypes {
     InlineView,
     #[serde(rename = "entrypoint-generator")]
     EntrypointGenerator,
+    #[serde(rename = "search-provider")]
+    SearchProvider,
 }
 
 #[derive(Debug, Deserialize)]
@@ -1086,3 +1395,113 @@ pub enum PluginManifestMainSearchBarPermissions {
     Read,
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID_MANIFEST: &str = r#"
+[gauntlet]
+name = 'Test'
+description = 'Test plugin'
+
+[[entrypoint]]
+id = 'view'
+name = 'View'
+description = 'Test view'
+path = 'src/view.tsx'
+type = 'view'
+"#;
+
+    fn diagnostic_fields(manifest: &str) -> Vec<String> {
+        PluginLoader::parse_manifest(manifest)
+            .unwrap_err()
+            .into_iter()
+            .map(|diagnostic| diagnostic.field)
+            .collect()
+    }
+
+    #[test]
+    fn valid_manifest_has_no_diagnostics() {
+        assert!(PluginLoader::parse_manifest(VALID_MANIFEST).is_ok());
+    }
+
+    #[test]
+    fn parse_error_points_to_field() {
+        let manifest = VALID_MANIFEST.replace("type = 'view'", "type = 'unknown'");
+
+        let diagnostics = PluginLoader::parse_manifest(&manifest).unwrap_err();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].field, "entrypoint[0].type");
+        assert!(diagnostics[0].reason.starts_with("unknown variant"));
+        assert!(diagnostics[0].suggestion.is_some());
+    }
+
+    #[test]
+    fn all_problems_are_reported_at_once() {
+        let manifest = VALID_MANIFEST.replace("type = 'view'", "type = 'inline-view'") + r#"
+[permissions]
+environment = ['']
+"#;
+
+        assert_eq!(
+            diagnostic_fields(&manifest),
+            vec!["permissions.environment[0]", "supported_system", "entrypoint[0].type"]
+        );
+    }
+
+    #[test]
+    fn invalid_plugin_dir_is_error_with_all_problems() {
+        let dir = tempfile::tempdir().unwrap();
+
+        std::fs::create_dir(dir.path().join("js")).unwrap();
+
+        let manifest = VALID_MANIFEST.replace("type = 'view'", "type = 'inline-view'") + r#"
+[permissions]
+environment = ['']
+"#;
+
+        std::fs::write(dir.path().join("gauntlet.toml"), manifest).unwrap();
+
+        let err = PluginLoader::validate_plugin_dir(dir.path()).unwrap_err().to_string();
+
+        assert!(err.starts_with("Plugin manifest is not valid"));
+        assert!(err.contains("permissions.environment[0]: Empty string value is not allowed in permissions"));
+        assert!(err.contains("supported_system: Plugin doesn't support current operating system"));
+        assert!(err.contains("entrypoint[0].type: Entrypoint 'view' has type 'inline-view'"));
+    }
+
+    #[test]
+    fn valid_plugin_dir_is_ok() {
+        let dir = tempfile::tempdir().unwrap();
+
+        std::fs::create_dir(dir.path().join("js")).unwrap();
+
+        std::fs::write(dir.path().join("gauntlet.toml"), VALID_MANIFEST).unwrap();
+
+        assert!(PluginLoader::validate_plugin_dir(dir.path()).is_ok());
+    }
+
+    #[test]
+    fn supported_node_imports_are_allowed() {
+        let dir = tempfile::tempdir().unwrap();
+
+        std::fs::write(dir.path().join("view.js"), r#"import path from "node:path"; import { Buffer } from "node:buffer";"#).unwrap();
+
+        assert!(PluginLoader::validate_node_imports(dir.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn unsupported_node_imports_are_reported() {
+        let dir = tempfile::tempdir().unwrap();
+
+        std::fs::write(dir.path().join("view.js"), r#"import path from "node:path"; import fs from "node:fs";"#).unwrap();
+
+        let diagnostics = PluginLoader::validate_node_imports(dir.path()).unwrap();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].field, "js/view.js");
+        assert_eq!(diagnostics[0].reason, "Imports node modules which are not supported in plugins: node:fs");
+    }
+}
@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::Context;
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+use gauntlet_common::model::{MetricsTiming, PluginId, PluginMetrics, ServerMetrics};
+
+// in-memory registry of server timings and counters, reset on restart
+#[derive(Clone)]
+pub struct Metrics {
+    inner: Arc<Mutex<MetricsInner>>,
+}
+
+#[derive(Default)]
+struct MetricsInner {
+    search: MetricsTiming,
+    requests: HashMap<String, u64>,
+    plugins: HashMap<PluginId, PluginMetricsInner>,
+}
+
+#[derive(Default)]
+struct PluginMetricsInner {
+    render: MetricsTiming,
+    runtime_pid: Option<u32>,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(MetricsInner::default())),
+        }
+    }
+
+    pub fn record_search(&self, duration: Duration) {
+        self.inner.lock().unwrap().search.record(duration);
+    }
+
+    // time it took frontend to accept rendered view
+    pub fn record_render(&self, plugin_id: &PluginId, duration: Duration) {
+        self.inner.lock()
+            .unwrap()
+            .plugins
+            .entry(plugin_id.clone())
+            .or_default()
+            .render
+            .record(duration);
+    }
+
+    pub fn record_request(&self, source: &str, request: &str) {
+        *self.inner.lock()
+            .unwrap()
+            .requests
+            .entry(format!("{}/{}", source, request))
+            .or_default() += 1;
+    }
+
+    pub fn runtime_started(&self, plugin_id: &PluginId, pid: u32) {
+        self.inner.lock()
+            .unwrap()
+            .plugins
+            .entry(plugin_id.clone())
+            .or_default()
+            .runtime_pid = Some(pid);
+    }
+
+    pub fn runtime_stopped(&self, plugin_id: &PluginId) {
+        if let Some(plugin) = self.inner.lock().unwrap().plugins.get_mut(plugin_id) {
+            plugin.runtime_pid = None;
+        }
+    }
+
+    pub fn snapshot(&self) -> ServerMetrics {
+        let (search, requests, plugins) = {
+            let inner = self.inner.lock().unwrap();
+
+            let plugins = inner.plugins.iter()
+                .map(|(plugin_id, plugin)| (plugin_id.clone(), plugin.render.clone(), plugin.runtime_pid))
+                .collect::<Vec<_>>();
+
+            (inner.search.clone(), inner.requests.clone(), plugins)
+        };
+
+        // every plugin runs in separate process with single isolate,
+        // so memory of the process is used as memory of the isolate
+        let pids = plugins.iter()
+            .filter_map(|(_, _, pid)| pid.map(Pid::from_u32))
+            .collect::<Vec<_>>();
+
+        let mut system = System::new();
+        system.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&pids),
+            true,
+            ProcessRefreshKind::nothing().with_memory(),
+        );
+
+        let plugins = plugins.into_iter()
+            .map(|(plugin_id, render, pid)| {
+                let memory_bytes = pid
+                    .and_then(|pid| system.process(Pid::from_u32(pid)))
+                    .map(|process| process.memory());
+
+                PluginMetrics {
+                    plugin_id,
+                    render,
+                    memory_bytes,
+                }
+            })
+            .collect();
+
+        ServerMetrics {
+            search,
+            requests,
+            plugins,
+        }
+    }
+}
+
+// https://prometheus.io/docs/instrumenting/exposition_formats/
+pub fn prometheus_text(metrics: &ServerMetrics) -> String {
+    let mut result = String::new();
+
+    let _ = writeln!(result, "# TYPE gauntlet_search_duration_seconds summary");
+    write_timing(&mut result, "gauntlet_search_duration_seconds", "", &metrics.search);
+
+    let _ = writeln!(result, "# TYPE gauntlet_render_duration_seconds summary");
+    for plugin in &metrics.plugins {
+        let labels = format!("plugin_id=\"{}\"", escape_label(&plugin.plugin_id.to_string()));
+        write_timing(&mut result, "gauntlet_render_duration_seconds", &labels, &plugin.render);
+    }
+
+    let _ = writeln!(result, "# TYPE gauntlet_plugin_memory_bytes gauge");
+    for plugin in &metrics.plugins {
+        if let Some(memory_bytes) = plugin.memory_bytes {
+            let _ = writeln!(result, "gauntlet_plugin_memory_bytes{{plugin_id=\"{}\"}} {}", escape_label(&plugin.plugin_id.to_string()), memory_bytes);
+        }
+    }
+
+    let _ = writeln!(result, "# TYPE gauntlet_requests_total counter");
+    let mut requests = metrics.requests.iter().collect::<Vec<_>>();
+    requests.sort();
+    for (request, count) in requests {
+        let _ = writeln!(result, "gauntlet_requests_total{{request=\"{}\"}} {}", escape_label(request), count);
+    }
+
+    result
+}
+
+fn write_timing(result: &mut String, name: &str, labels: &str, timing: &MetricsTiming) {
+    let labels = if labels.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", labels)
+    };
+
+    let _ = writeln!(result, "{}_sum{} {}", name, labels, timing.total.as_secs_f64());
+    let _ = writeln!(result, "{}_count{} {}", name, labels, timing.count);
+}
+
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+// minimal http server, every request regardless of path gets metrics in response
+pub async fn serve_prometheus(metrics: Metrics, port: u16) -> anyhow::Result<()> {
+    let listener = TcpListener::bind(("127.0.0.1", port))
+        .await
+        .context("Unable to bind metrics endpoint")?;
+
+    tracing::info!("Serving metrics on http://127.0.0.1:{}/metrics", port);
+
+    loop {
+        let (mut stream, _) = listener.accept()
+            .await
+            .context("Unable to accept metrics connection")?;
+
+        let metrics = metrics.clone();
+
+        tokio::spawn(async move {
+            let mut buffer = [0; 1024];
+            let _ = stream.read(&mut buffer).await;
+
+            let body = prometheus_text(&metrics.snapshot());
+
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+
+            if let Err(err) = stream.write_all(response.as_bytes()).await {
+                tracing::warn!("Unable to write metrics response: {:?}", err);
+            }
+        });
+    }
+}
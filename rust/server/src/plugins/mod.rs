@@ -1,33 +1,63 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Mutex;
 use std::thread;
-use std::time::Duration;
-use anyhow::anyhow;
+use std::time::{Duration, Instant};
+use anyhow::{anyhow, Context};
 use include_dir::{include_dir, Dir};
 use tokio::runtime::Handle;
+use uuid::Uuid;
 
-use gauntlet_common::model::{DownloadStatus, EntrypointId, KeyboardEventOrigin, LocalSaveData, PhysicalKey, PhysicalShortcut, PluginId, PluginPreference, PluginPreferenceUserData, PreferenceEnumValue, SearchResult, SettingsEntrypoint, SettingsEntrypointType, SettingsPlugin, SettingsTheme, UiPropertyValue, UiRequestData, UiResponseData, UiSetupData, UiWidgetId, WindowPositionMode};
+use gauntlet_common::model::{Density, DevConsoleEvent, DownloadStatus, EntrypointId, FileQuicklook, ImageHash, KeyboardEventOrigin, LocalSaveData, PhysicalKey, PhysicalShortcut, PluginId, PluginPreference, PluginPreferenceUserData, PreferenceEnumValue, SearchFilter, SearchResult, SearchResultPreview, SearchResultsSort, ServerMetrics, SettingsCrashReport, SettingsEntrypoint, SettingsEntrypointType, SettingsPlugin, SettingsPluginDiskUsage, SettingsQuicklink, SettingsTheme, SettingsUsageStats, UiPropertyValue, UiRendererInfo, UiRequestData, UiResponseData, UiScale, UiSetupData, UiWidgetId, WindowPositionMode};
 use gauntlet_common::rpc::frontend_api::FrontendApi;
 use gauntlet_common::{settings_env_data_to_string, SettingsEnvData};
 use gauntlet_utils::channel::RequestSender;
+use gauntlet_common::deeplink::DeepLink;
+use gauntlet_common::crash::{crash_report_submit_url, crash_reports, remove_crash_report, set_crash_reports_enabled};
 use gauntlet_common::dirs::Dirs;
-use gauntlet_plugin_runtime::{JsPluginCode, JsPluginPermissions, JsPluginPermissionsExec, JsPluginPermissionsFileSystem, JsPluginPermissionsMainSearchBar};
+use gauntlet_common::logging::{read_recent_log_lines, SERVER_LOG_FILE_PREFIX};
+use gauntlet_plugin_runtime::{JsPluginCode, JsPluginHttpCache, JsPluginPermissions, JsPluginPermissionsExec, JsPluginPermissionsFileSystem, JsPluginPermissionsMainSearchBar};
 use crate::model::{ActionShortcutKey};
+use crate::plugins::autostart::Autostart;
 use crate::plugins::clipboard::Clipboard;
 use crate::plugins::config_reader::ConfigReader;
-use crate::plugins::data_db_repository::{db_entrypoint_from_str, DataDbRepository, DbPluginActionShortcutKind, DbPluginClipboardPermissions, DbPluginEntrypointType, DbPluginMainSearchBarPermissions, DbPluginPreference, DbPluginPreferenceUserData, DbReadPluginEntrypoint};
+use crate::plugins::dev_console::DevConsole;
+use crate::plugins::focus_mode::FocusMode;
+use crate::plugins::oauth::OAuth;
+use crate::plugins::secrets::Secrets;
+use crate::plugins::data_db_repository::{db_entrypoint_from_str, DataDbRepository, DbPluginActionShortcutKind, DbPluginArgument, DbPluginClipboardPermissions, DbPluginEntrypointType, DbPluginMainSearchBarPermissions, DbPluginPreference, DbPluginPreferenceUserData, DbProfileData, DbProfileEntrypoint, DbProfilePlugin, DbReadPluginEntrypoint};
 use crate::plugins::icon_cache::IconCache;
 use crate::plugins::js::{start_plugin_runtime, AllPluginCommandData, OnePluginCommandData, PluginCommand, PluginPermissions, PluginPermissionsClipboard, PluginRuntimeData};
 use crate::plugins::loader::PluginLoader;
+use crate::plugins::metrics::{serve_prometheus, Metrics};
+use crate::plugins::image_store::ImageStore;
+use crate::plugins::browser_bridge::BrowserBridge;
+use crate::plugins::profiles::{theme_from_db, Profiles};
+use crate::plugins::quicklinks::Quicklinks;
+use crate::plugins::fallbacks::{FallbackRun, Fallbacks};
+use crate::plugins::open_targets::OpenTargets;
+use crate::plugins::quicklook::file_quicklook;
+use crate::plugins::timers::Timers;
+use crate::plugins::passwords::Passwords;
+use crate::plugins::ssh_hosts::SshHosts;
+use crate::plugins::recent_projects::RecentProjects;
+use crate::plugins::terminal::Terminal;
+use crate::plugins::notifications::Notifications;
+use crate::plugins::input_injection::InputInjection;
+use crate::plugins::search_providers::SearchProviders;
 use crate::plugins::run_status::RunStatusHolder;
 use crate::plugins::settings::Settings;
-use crate::search::SearchIndex;
+use crate::plugins::usage_stats::UsageStats;
+use crate::search::{matches_search_filter, SearchIndex};
 use crate::SETTINGS_ENV;
 
 pub mod js;
 mod data_db_repository;
 mod config_reader;
+mod keymap;
 mod loader;
 mod run_status;
 mod download_status;
@@ -36,13 +66,44 @@ pub(super) mod frecency;
 mod clipboard;
 mod runtime;
 mod image_gatherer;
+mod image_store;
 mod settings;
 mod theme;
+mod quicklinks;
+mod fallbacks;
+mod open_targets;
+mod file_preview;
+mod quicklook;
+mod timers;
+mod passwords;
+mod command_template;
+mod terminal;
+mod ssh_hosts;
+mod recent_projects;
+mod notifications;
+mod input_injection;
+mod profiles;
+mod autostart;
+mod dev_console;
+mod metrics;
+mod view_updates;
+mod focus_mode;
+mod search_providers;
+mod secrets;
+mod oauth;
+mod usage_stats;
+pub(crate) mod browser_bridge;
+
+pub fn validate_plugin_manifest(plugin_manifest_path: &Path) -> anyhow::Result<Vec<PluginManifestDiagnostic>> {
+    PluginLoader::validate_plugin_manifest(plugin_manifest_path)
+}
 
 static BUNDLED_PLUGINS: [(&str, Dir); 1] = [
     ("gauntlet", include_dir!("$CARGO_MANIFEST_DIR/../../bundled_plugins/gauntlet/dist")),
 ];
 
+const RECENT_ENTRYPOINTS_LIMIT: u32 = 5;
+
 pub struct ApplicationManager {
     config_reader: ConfigReader,
     search_index: SearchIndex,
@@ -55,6 +116,28 @@ pub struct ApplicationManager {
     dirs: Dirs,
     clipboard: Clipboard,
     settings: Settings,
+    quicklinks: Quicklinks,
+    fallbacks: Fallbacks,
+    open_targets: OpenTargets,
+    timers: Timers,
+    passwords: Passwords,
+    ssh_hosts: SshHosts,
+    recent_projects: RecentProjects,
+    terminal: Terminal,
+    profiles: Profiles,
+    autostart: Autostart,
+    dev_console: DevConsole,
+    metrics: Metrics,
+    image_store: ImageStore,
+    browser_bridge: BrowserBridge,
+    search_providers: SearchProviders,
+    plugins_paused: AtomicBool,
+    focus_mode: FocusMode,
+    notifications: Notifications,
+    input_injection: InputInjection,
+    oauth: OAuth,
+    usage_stats: UsageStats,
+    renderer_info: Mutex<Option<UiRendererInfo>>,
 }
 
 impl ApplicationManager {
@@ -66,12 +149,39 @@ impl ApplicationManager {
         let config_reader = ConfigReader::new(dirs.clone(), db_repository.clone());
         let icon_cache = IconCache::new(dirs.clone());
         let run_status_holder = RunStatusHolder::new();
-        let search_index = SearchIndex::create_index(frontend_api.clone())?;
+        let search_index = SearchIndex::create_index(frontend_api.clone(), dirs.search_index_file())?;
         let clipboard = Clipboard::new()?;
         let settings = Settings::new(dirs.clone(), db_repository.clone(), frontend_api.clone())?;
+        let quicklinks = Quicklinks::new(db_repository.clone());
+        let fallbacks = Fallbacks::new(db_repository.clone(), quicklinks.clone());
+        let open_targets = OpenTargets::new(dirs.clone());
+        let terminal = Terminal::new(dirs.clone());
+        let ssh_hosts = SshHosts::new(dirs.clone(), terminal.clone());
+        let recent_projects = RecentProjects::new(dirs.clone(), terminal.clone());
+        let profiles = Profiles::new(db_repository.clone());
+        let autostart = Autostart::new(dirs.clone());
+        let dev_console = DevConsole::new();
+        let metrics = Metrics::new();
+        let image_store = ImageStore::new(db_repository.clone());
+        let browser_bridge = BrowserBridge::new();
+        let focus_mode = FocusMode::new();
+        let input_injection = InputInjection::new(clipboard.clone(), frontend_api.clone());
+        let secrets = Secrets::new();
+        let oauth = OAuth::new(secrets.clone(), db_repository.clone());
+        let passwords = Passwords::new(secrets, clipboard.clone(), input_injection.clone(), frontend_api.clone());
+        let usage_stats = UsageStats::new(db_repository.clone());
+
+        quicklinks.reload().await?;
+        profiles.reload().await?;
 
         let (command_broadcaster, _) = tokio::sync::broadcast::channel::<PluginCommand>(100);
 
+        let search_providers = SearchProviders::new(command_broadcaster.clone());
+        let notifications = Notifications::new(command_broadcaster.clone());
+        let timers = Timers::new(db_repository.clone(), frontend_api.clone(), notifications.clone());
+
+        timers.reload().await?;
+
         Ok(Self {
             config_reader,
             search_index,
@@ -83,7 +193,29 @@ impl ApplicationManager {
             frontend_api,
             clipboard,
             settings,
-            dirs
+            quicklinks,
+            fallbacks,
+            open_targets,
+            timers,
+            passwords,
+            ssh_hosts,
+            recent_projects,
+            terminal,
+            profiles,
+            autostart,
+            dev_console,
+            metrics,
+            image_store,
+            browser_bridge,
+            search_providers,
+            dirs,
+            plugins_paused: AtomicBool::new(false),
+            focus_mode,
+            notifications,
+            input_injection,
+            oauth,
+            usage_stats,
+            renderer_info: Mutex::new(None),
         })
     }
 
@@ -92,14 +224,46 @@ impl ApplicationManager {
         let theme = self.settings.effective_theme().await?;
         let global_shortcut = self.settings.effective_global_shortcut().await?;
         let window_position_mode = self.settings.window_position_mode_setting().await?;
+        let window_overlay = self.settings.window_overlay_setting().await?;
+        let density = self.settings.density_setting().await?;
+        let ui_scale = self.settings.ui_scale_setting().await?;
+        let search_results_sort = self.settings.search_results_sort_setting().await?;
+        let offline_mode = self.settings.offline_mode_setting().await?;
         let close_on_unfocus = self.config_reader.close_on_unfocus();
+        let escape_behavior = self.config_reader.escape_behavior();
+        let reopen_last_view = self.config_reader.reopen_last_view();
+        let reduced_motion = self.config_reader.reduced_motion();
+        let tray_icon = self.config_reader.tray_icon();
+        let inline_view_order = self.config_reader.inline_view_order();
+        let results_per_plugin = self.config_reader.results_per_plugin();
+        let keymap = self.config_reader.keymap();
+        let font = self.config_reader.font();
+        let monitor_ui_scale = self.config_reader.monitor_ui_scale();
+        let renderer = self.config_reader.renderer();
+        let wgpu_backend = self.config_reader.wgpu_backend();
 
         Ok(UiSetupData {
             window_position_file,
             theme,
             global_shortcut,
             close_on_unfocus,
-            window_position_mode
+            escape_behavior,
+            reopen_last_view,
+            reduced_motion,
+            tray_icon,
+            window_position_mode,
+            window_overlay,
+            density,
+            font,
+            ui_scale,
+            monitor_ui_scale,
+            renderer,
+            wgpu_backend,
+            search_results_sort,
+            inline_view_order,
+            results_per_plugin,
+            keymap,
+            offline_mode,
         })
     }
 
@@ -116,21 +280,118 @@ impl ApplicationManager {
     }
 
     pub async fn download_plugin(&self, plugin_id: PluginId) -> anyhow::Result<()> {
-        self.plugin_downloader.download_plugin(plugin_id).await
+        if self.settings.offline_mode_setting().await? {
+            return Err(anyhow!("Plugins cannot be downloaded while offline mode is enabled"))
+        }
+
+        let proxy = self.config_reader.network_proxy(&plugin_id);
+
+        self.plugin_downloader.download_plugin(plugin_id, proxy).await
     }
 
     pub fn download_status(&self) -> HashMap<PluginId, DownloadStatus> {
         self.plugin_downloader.download_status()
     }
 
-    pub fn search(&self, text: &str, render_inline_view: bool) -> anyhow::Result<Vec<SearchResult>> {
-        let result = self.search_index.search(&text);
+    // returns search results, amount of recently used entrypoints at the start of them and whether results are fallbacks,
+    // recent entrypoints are only shown when search query is empty, fallbacks only when nothing matched the query
+    pub async fn search(&self, text: &str, filter: &SearchFilter, render_inline_view: bool) -> anyhow::Result<(Vec<SearchResult>, usize, bool)> {
+        let start = Instant::now();
+
+        self.usage_stats.mark_search(text);
+
+        let result = self.search_entrypoints(text, filter, render_inline_view).await;
+
+        self.metrics.record_search(start.elapsed());
+
+        result
+    }
+
+    async fn search_entrypoints(&self, text: &str, filter: &SearchFilter, render_inline_view: bool) -> anyhow::Result<(Vec<SearchResult>, usize, bool)> {
+        let sort = self.settings.search_results_sort_setting().await?;
+
+        let mut result = self.open_targets.search(&text);
+
+        result.extend(self.quicklinks.search(&text));
+
+        result.extend(self.profiles.search(&text));
+
+        result.extend(self.timers.search(&text));
+
+        result.extend(self.passwords.search(&text, &self.config_reader.password_policy()));
+
+        result.extend(self.ssh_hosts.search(&text));
+
+        result.extend(self.recent_projects.search(&text).await);
 
-        if render_inline_view {
+        result.extend(self.search_index.search(&text, filter, &sort)?);
+
+        let focus_mode = self.focus_mode.is_enabled();
+
+        // search providers respond asynchronously, frontend repeats the search when their results arrive
+        if !focus_mode {
+            self.search_providers.search(&text);
+
+            result.extend(self.search_index.filter_provided_results(&text, self.search_providers.results(&text)));
+        }
+
+        result.extend(self.browser_bridge.search(&text));
+
+        result.retain(|item| matches_search_filter(filter, item));
+
+        // the same entrypoint can be matched both by quicklinks and search index,
+        // results which share entrypoint but run something different, e.g. generated entrypoints, are all kept
+        let mut seen = HashSet::new();
+        result.retain(|item| seen.insert(search_result_identity(item)));
+
+        if render_inline_view && !focus_mode {
             self.handle_inline_view(&text);
         }
 
-        result
+        if result.is_empty() {
+            let mut fallback_result = self.fallbacks.search(&text, &self.config_reader.search_fallbacks())
+                .await?;
+
+            fallback_result.retain(|item| matches_search_filter(filter, item));
+
+            let fallback = !fallback_result.is_empty();
+
+            return Ok((fallback_result, 0, fallback))
+        }
+
+        // stable sort keeps entrypoints inside the plugin group ordered by frecency,
+        // url, path or email from the query stays above everything else
+        if sort == SearchResultsSort::Plugin {
+            result.sort_by(|a, b| {
+                OpenTargets::is_open_target(&b.plugin_id).cmp(&OpenTargets::is_open_target(&a.plugin_id))
+                    .then_with(|| a.plugin_name.cmp(&b.plugin_name))
+                    .then_with(|| a.plugin_id.to_string().cmp(&b.plugin_id.to_string()))
+            });
+        }
+
+        if !text.trim().is_empty() {
+            return Ok((result, 0, false))
+        }
+
+        let recent = self.db_repository.get_recent_entrypoints(RECENT_ENTRYPOINTS_LIMIT)
+            .await?;
+
+        let mut recent_result = vec![];
+
+        for (plugin_id, entrypoint_id) in recent {
+            let index = result.iter()
+                .position(|item| item.plugin_id.to_string() == plugin_id && item.entrypoint_id.to_string() == entrypoint_id);
+
+            if let Some(index) = index {
+                recent_result.push(result.remove(index));
+            }
+        }
+
+        let recent_count = recent_result.len();
+
+        recent_result.extend(result);
+
+        Ok((recent_result, recent_count, false))
     }
 
     pub async fn show_window(&self) -> anyhow::Result<()> {
@@ -139,12 +400,134 @@ impl ApplicationManager {
         Ok(())
     }
 
+    pub async fn hide_window(&self) -> anyhow::Result<()> {
+        self.frontend_api.hide_window().await?;
+
+        Ok(())
+    }
+
+    pub async fn toggle_window(&self) -> anyhow::Result<()> {
+        self.frontend_api.toggle_window().await?;
+
+        Ok(())
+    }
+
+    pub async fn show_hud(&self, display: String) -> anyhow::Result<()> {
+        self.frontend_api.show_hud(display).await?;
+
+        Ok(())
+    }
+
+    // deep links come from other applications, so user has to confirm the first time entrypoint is run by one.
+    // search deep links only fill the search bar, so they don't need confirmation
+    pub async fn handle_deep_link(&self, uri: &str) -> anyhow::Result<()> {
+        tracing::info!("Handling deep link: {}", uri);
+
+        let deep_link = DeepLink::parse(uri)?;
+
+        let (plugin_id, entrypoint_id) = match &deep_link {
+            DeepLink::Open { plugin_id, entrypoint_id } | DeepLink::Run { plugin_id, entrypoint_id, .. } => (plugin_id.clone(), entrypoint_id.clone()),
+            DeepLink::Search { .. } => return self.run_deep_link(deep_link).await,
+        };
+
+        if self.settings.is_deep_link_confirmed(&plugin_id, &entrypoint_id).await? {
+            return self.run_deep_link(deep_link).await
+        }
+
+        let plugin_name = self.db_repository.get_plugin_by_id_option(&plugin_id.to_string())
+            .await?
+            .map(|plugin| plugin.name)
+            .unwrap_or_else(|| plugin_id.to_string());
+
+        let entrypoint_name = self.db_repository.get_entrypoint_by_id_option(&plugin_id.to_string(), &entrypoint_id.to_string())
+            .await?
+            .map(|entrypoint| entrypoint.name)
+            .unwrap_or_else(|| entrypoint_id.to_string());
+
+        self.frontend_api.confirm_deep_link(uri.to_string(), plugin_name, entrypoint_name).await?;
+
+        Ok(())
+    }
+
+    pub async fn run_confirmed_deep_link(&self, uri: &str) -> anyhow::Result<()> {
+        let deep_link = DeepLink::parse(uri)?;
+
+        if let DeepLink::Open { plugin_id, entrypoint_id } | DeepLink::Run { plugin_id, entrypoint_id, .. } = &deep_link {
+            self.settings.set_deep_link_confirmed(plugin_id, entrypoint_id).await?;
+        }
+
+        self.run_deep_link(deep_link).await
+    }
+
+    async fn run_deep_link(&self, deep_link: DeepLink) -> anyhow::Result<()> {
+        match deep_link {
+            DeepLink::Open { plugin_id, entrypoint_id } => {
+                self.run_entrypoint(plugin_id, entrypoint_id, vec![]).await?;
+            }
+            DeepLink::Run { plugin_id, entrypoint_id, arguments } => {
+                self.run_entrypoint(plugin_id, entrypoint_id, arguments).await?;
+            }
+            DeepLink::Search { query } => {
+                self.frontend_api.show_window_with_search_query(query).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // used by deep links and http api, arguments are positional
+    pub async fn run_entrypoint(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, arguments: Vec<String>) -> anyhow::Result<()> {
+        // quicklinks are not stored in plugin table, arguments are used as a search query
+        if Quicklinks::is_quicklink(&plugin_id) {
+            return self.quicklinks.open_with_query(&entrypoint_id, &arguments.join(" "));
+        }
+
+        if Profiles::is_profile(&plugin_id) {
+            return self.switch_profile(entrypoint_id.to_string()).await;
+        }
+
+        let plugin = self.db_repository.get_plugin_by_id_option(&plugin_id.to_string())
+            .await?
+            .ok_or_else(|| anyhow!("Plugin not found: {}", plugin_id))?;
+
+        let entrypoint = self.db_repository.get_entrypoint_by_id_option(&plugin_id.to_string(), &entrypoint_id.to_string())
+            .await?
+            .ok_or_else(|| anyhow!("Entrypoint not found: {}", entrypoint_id))?;
+
+        if !plugin.enabled || !entrypoint.enabled {
+            return Err(anyhow!("Entrypoint is disabled: {}", entrypoint_id))
+        }
+
+        match db_entrypoint_from_str(&entrypoint.entrypoint_type) {
+            DbPluginEntrypointType::Command => {
+                let argument_values = command_argument_values(&entrypoint_id, &entrypoint.arguments, arguments)?;
+
+                self.handle_run_command(plugin_id, entrypoint_id, argument_values).await;
+            }
+            DbPluginEntrypointType::View => {
+                if !arguments.is_empty() {
+                    return Err(anyhow!("View entrypoints don't accept arguments: {}", entrypoint_id))
+                }
+
+                self.frontend_api.open_plugin_view(plugin_id, plugin.name, entrypoint_id, entrypoint.localized_name()).await?;
+            }
+            DbPluginEntrypointType::InlineView | DbPluginEntrypointType::EntrypointGenerator | DbPluginEntrypointType::SearchProvider => {
+                return Err(anyhow!("Entrypoint type doesn't support deep links: {}", entrypoint_id))
+            }
+        }
+
+        Ok(())
+    }
+
     pub async fn save_local_plugin(
         &self,
         path: &str,
     ) -> anyhow::Result<LocalSaveData> {
         tracing::info!(target = "plugin", "Saving local plugin at path: {:?}", path);
 
+        self.plugin_downloader.validate_local_plugin(path)
+            .inspect_err(|err| tracing::warn!(target = "plugin", "Plugin at path {:?} is not valid: {:#}", path, err))?;
+
         let plugin_id = self.plugin_downloader.save_local_plugin(path).await?;
 
         let plugin = self.db_repository.get_plugin_by_id(&plugin_id.to_string())
@@ -181,18 +564,21 @@ impl ApplicationManager {
                 let entrypoints = entrypoints
                     .into_iter()
                     .map(|entrypoint| {
-                        let entrypoint_id = EntrypointId::from_string(entrypoint.id);
+                        let entrypoint_id = EntrypointId::from_string(&entrypoint.id);
 
                         let entrypoint = SettingsEntrypoint {
                             enabled: entrypoint.enabled,
+                            hidden: entrypoint.hidden,
+                            position: entrypoint.position.map(|position| position as u32),
                             entrypoint_id: entrypoint_id.clone(),
-                            entrypoint_name: entrypoint.name,
-                            entrypoint_description: entrypoint.description,
+                            entrypoint_name: entrypoint.localized_name(),
+                            entrypoint_description: entrypoint.localized_description(),
                             entrypoint_type: match db_entrypoint_from_str(&entrypoint.entrypoint_type) {
                                 DbPluginEntrypointType::Command => SettingsEntrypointType::Command,
                                 DbPluginEntrypointType::View => SettingsEntrypointType::View,
                                 DbPluginEntrypointType::InlineView => SettingsEntrypointType::InlineView,
                                 DbPluginEntrypointType::EntrypointGenerator => SettingsEntrypointType::EntrypointGenerator,
+                                DbPluginEntrypointType::SearchProvider => SettingsEntrypointType::SearchProvider,
                             }.into(),
                             preferences: entrypoint.preferences.into_iter()
                                 .map(|(key, value)| {
@@ -214,6 +600,10 @@ impl ApplicationManager {
                     plugin_name: plugin.name,
                     plugin_description: plugin.description,
                     enabled: plugin.enabled,
+                    priority: plugin.priority,
+                    notifications_enabled: plugin.notifications_enabled,
+                    environment: plugin.environment,
+                    filesystem_roots: plugin.filesystem_roots,
                     entrypoints,
                     preferences: plugin.preferences.into_iter()
                         .map(|(key, value)| {
@@ -260,43 +650,423 @@ impl ApplicationManager {
             _ => {}
         }
 
-        Ok(())
+        Ok(())
+    }
+
+    pub async fn set_plugin_priority(&self, plugin_id: PluginId, priority: f64) -> anyhow::Result<()> {
+        tracing::debug!(target = "plugin", "Setting priority for plugin id: {:?}, priority: {}", plugin_id, priority);
+
+        self.db_repository.set_plugin_priority(&plugin_id.to_string(), priority)
+            .await?;
+
+        self.search_index.set_plugin_priority(plugin_id, priority);
+
+        Ok(())
+    }
+
+    // checked every time plugin shows notification, so plugin doesn't need to be restarted
+    pub async fn set_plugin_notifications_enabled(&self, plugin_id: PluginId, enabled: bool) -> anyhow::Result<()> {
+        tracing::debug!(target = "plugin", "Setting notifications state for plugin id: {:?}, enabled: {}", plugin_id, enabled);
+
+        self.db_repository.set_plugin_notifications_enabled(&plugin_id.to_string(), enabled)
+            .await
+    }
+
+    // variables are passed to plugin runtime process when it is started, so running plugin is restarted
+    pub async fn set_plugin_environment(&self, plugin_id: PluginId, environment: HashMap<String, String>) -> anyhow::Result<()> {
+        tracing::debug!(target = "plugin", "Setting environment variables for plugin id: {:?}, names: {:?}", plugin_id, environment.keys());
+
+        for name in environment.keys() {
+            if name.is_empty() || name.contains(['=', '\0']) {
+                return Err(anyhow!("Invalid environment variable name: {:?}", name));
+            }
+        }
+
+        self.db_repository.set_plugin_environment(&plugin_id.to_string(), environment)
+            .await?;
+
+        if self.run_status_holder.is_plugin_running(&plugin_id) {
+            self.reload_plugin(plugin_id).await?;
+        }
+
+        Ok(())
+    }
+
+    // roots are passed to plugin runtime when it is started, so running plugin is restarted
+    pub async fn set_plugin_filesystem_roots(&self, plugin_id: PluginId, filesystem_roots: Vec<String>) -> anyhow::Result<()> {
+        tracing::debug!(target = "plugin", "Setting file system roots for plugin id: {:?}, roots: {:?}", plugin_id, filesystem_roots);
+
+        for root in &filesystem_roots {
+            if !Path::new(root).is_absolute() {
+                return Err(anyhow!("File system root is not an absolute path: {:?}", root));
+            }
+        }
+
+        self.db_repository.set_plugin_filesystem_roots(&plugin_id.to_string(), filesystem_roots)
+            .await?;
+
+        if self.run_status_holder.is_plugin_running(&plugin_id) {
+            self.reload_plugin(plugin_id).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn set_entrypoint_state(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, enabled: bool) -> anyhow::Result<()> {
+        tracing::debug!(target = "plugin", "Setting entrypoint state for plugin id: {:?}, entrypoint_id: {:?}, enabled: {}", plugin_id, entrypoint_id, enabled);
+
+        self.db_repository.set_plugin_entrypoint_enabled(&plugin_id.to_string(), &entrypoint_id.to_string(), enabled)
+            .await?;
+
+        // removed right away, without waiting for plugin to reindex its entrypoints
+        if !enabled {
+            self.search_index.remove_entrypoint(plugin_id.clone(), entrypoint_id)?;
+        }
+
+        self.reload_plugin(plugin_id.clone()).await?;
+
+        Ok(())
+    }
+
+    pub async fn set_entrypoint_hidden(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, hidden: bool) -> anyhow::Result<()> {
+        tracing::debug!(target = "plugin", "Setting entrypoint visibility for plugin id: {:?}, entrypoint_id: {:?}, hidden: {}", plugin_id, entrypoint_id, hidden);
+
+        self.db_repository.set_plugin_entrypoint_hidden(&plugin_id.to_string(), &entrypoint_id.to_string(), hidden)
+            .await?;
+
+        // unlike disabling, plugin doesn't need to be reloaded, only search items have to be updated
+        if self.run_status_holder.is_plugin_running(&plugin_id) {
+            self.send_command(PluginCommand::One {
+                id: plugin_id,
+                data: OnePluginCommandData::RefreshSearchIndex
+            });
+        }
+
+        Ok(())
+    }
+
+    pub async fn set_entrypoint_order(&self, plugin_id: PluginId, entrypoint_ids: Vec<EntrypointId>) -> anyhow::Result<()> {
+        tracing::debug!(target = "plugin", "Setting entrypoint order for plugin id: {:?}, entrypoint_ids: {:?}", plugin_id, entrypoint_ids);
+
+        let entrypoint_ids = entrypoint_ids.into_iter()
+            .map(|entrypoint_id| entrypoint_id.to_string())
+            .collect::<Vec<_>>();
+
+        self.db_repository.set_plugin_entrypoint_order(&plugin_id.to_string(), &entrypoint_ids)
+            .await?;
+
+        Ok(())
+    }
+
+    // entrypoint which was opened when onboarding was shown is run again,
+    // if required preferences are still missing plugin will show onboarding view once more
+    pub async fn complete_plugin_onboarding(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> anyhow::Result<()> {
+        tracing::debug!(target = "plugin", "Completing onboarding for plugin id: {:?}", plugin_id);
+
+        self.db_repository.set_plugin_onboarding_completed(&plugin_id.to_string(), true)
+            .await?;
+
+        self.run_entrypoint(plugin_id, entrypoint_id, vec![]).await
+    }
+
+    pub async fn set_global_shortcut(&self, shortcut: Option<PhysicalShortcut>) -> anyhow::Result<()> {
+        self.settings.set_global_shortcut(shortcut).await
+    }
+
+    pub async fn get_global_shortcut(&self) -> anyhow::Result<Option<(Option<PhysicalShortcut>, Option<String>)>> {
+        self.settings.global_shortcut().await
+    }
+
+    pub async fn set_theme(&self, theme: SettingsTheme) -> anyhow::Result<()> {
+        self.settings.set_theme_setting(theme).await
+    }
+
+    pub async fn get_theme(&self) -> anyhow::Result<SettingsTheme> {
+        self.settings.theme_setting().await
+    }
+
+    pub async fn set_window_position_mode(&self, mode: WindowPositionMode) -> anyhow::Result<()> {
+        self.settings.set_window_position_mode_setting(mode).await
+    }
+
+    pub async fn get_window_position_mode(&self) -> anyhow::Result<WindowPositionMode> {
+        self.settings.window_position_mode_setting().await
+    }
+
+    pub async fn set_window_overlay(&self, enabled: bool) -> anyhow::Result<()> {
+        self.settings.set_window_overlay_setting(enabled).await
+    }
+
+    pub async fn get_window_overlay(&self) -> anyhow::Result<bool> {
+        self.settings.window_overlay_setting().await
+    }
+
+    pub async fn set_density(&self, density: Density) -> anyhow::Result<()> {
+        self.settings.set_density_setting(density).await
+    }
+
+    pub async fn get_density(&self) -> anyhow::Result<Density> {
+        self.settings.density_setting().await
+    }
+
+    pub async fn set_ui_scale(&self, scale: UiScale) -> anyhow::Result<()> {
+        self.settings.set_ui_scale_setting(scale).await
+    }
+
+    pub async fn get_ui_scale(&self) -> anyhow::Result<UiScale> {
+        self.settings.ui_scale_setting().await
+    }
+
+    pub async fn set_search_results_sort(&self, sort: SearchResultsSort) -> anyhow::Result<()> {
+        self.settings.set_search_results_sort_setting(sort).await
+    }
+
+    pub async fn get_search_results_sort(&self) -> anyhow::Result<SearchResultsSort> {
+        self.settings.search_results_sort_setting().await
+    }
+
+    pub fn quicklinks(&self) -> Vec<SettingsQuicklink> {
+        self.quicklinks.quicklinks()
+    }
+
+    pub async fn save_quicklink(&self, id: Option<String>, name: String, url: String, bang: Option<String>, fallback: bool) -> anyhow::Result<()> {
+        self.quicklinks.save_quicklink(id, name, url, bang, fallback).await
+    }
+
+    pub async fn remove_quicklink(&self, id: String) -> anyhow::Result<()> {
+        self.quicklinks.remove_quicklink(id).await
+    }
+
+    pub fn profiles(&self) -> Vec<String> {
+        self.profiles.profiles()
+    }
+
+    // snapshots currently enabled plugins and entrypoints, their preferences and theme
+    pub async fn save_profile(&self, name: String) -> anyhow::Result<()> {
+        tracing::info!("Saving current settings as profile: {}", name);
+
+        let theme = self.db_repository.get_settings()
+            .await?
+            .theme;
+
+        let plugins = self.db_repository.list_plugins_and_entrypoints()
+            .await?
+            .into_iter()
+            .map(|(plugin, entrypoints)| {
+                let entrypoints = entrypoints.into_iter()
+                    .map(|entrypoint| {
+                        let entrypoint_data = DbProfileEntrypoint {
+                            enabled: entrypoint.enabled,
+                            preferences_user_data: entrypoint.preferences_user_data,
+                        };
+
+                        (entrypoint.id, entrypoint_data)
+                    })
+                    .collect();
+
+                let plugin_data = DbProfilePlugin {
+                    enabled: plugin.enabled,
+                    preferences_user_data: plugin.preferences_user_data,
+                    entrypoints,
+                };
+
+                (plugin.id, plugin_data)
+            })
+            .collect();
+
+        self.profiles.save_profile(name, DbProfileData { theme, plugins }).await
+    }
+
+    // plugins installed after the profile was saved are left as they are
+    pub async fn switch_profile(&self, name: String) -> anyhow::Result<()> {
+        tracing::info!("Switching to profile: {}", name);
+
+        let profile = self.profiles.profile_data(&name).await?;
+
+        for (plugin_id, plugin_data) in profile.plugins {
+            if self.db_repository.get_plugin_by_id_option(&plugin_id).await?.is_none() {
+                continue;
+            }
+
+            self.db_repository.set_preferences_user_data(&plugin_id, None, plugin_data.preferences_user_data)
+                .await?;
+
+            for (entrypoint_id, entrypoint_data) in plugin_data.entrypoints {
+                if self.db_repository.get_entrypoint_by_id_option(&plugin_id, &entrypoint_id).await?.is_none() {
+                    continue;
+                }
+
+                self.db_repository.set_plugin_entrypoint_enabled(&plugin_id, &entrypoint_id, entrypoint_data.enabled)
+                    .await?;
+
+                self.db_repository.set_preferences_user_data(&plugin_id, Some(&entrypoint_id), entrypoint_data.preferences_user_data)
+                    .await?;
+            }
+
+            self.db_repository.set_plugin_enabled(&plugin_id, plugin_data.enabled)
+                .await?;
+
+            let plugin_id = PluginId::from_string(plugin_id);
+
+            if !plugin_data.enabled {
+                self.search_index.remove_for_plugin(plugin_id.clone())?;
+            }
+
+            // running plugins are restarted to pick up changed preferences and entrypoints
+            self.reload_plugin(plugin_id).await?;
+        }
+
+        self.settings.set_theme_setting(theme_from_db(profile.theme)).await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_profile(&self, name: String) -> anyhow::Result<()> {
+        self.profiles.remove_profile(name).await
+    }
+
+    pub fn recent_log_lines(&self, limit: u32) -> anyhow::Result<Vec<String>> {
+        read_recent_log_lines(SERVER_LOG_FILE_PREFIX, limit as usize)
+    }
+
+    pub fn dev_console(&self, plugin_id: PluginId) -> tokio::sync::mpsc::Receiver<DevConsoleEvent> {
+        self.dev_console.subscribe(plugin_id)
+    }
+
+    pub fn metrics(&self) -> ServerMetrics {
+        self.metrics.snapshot()
+    }
+
+    pub async fn images(&self, hashes: Vec<ImageHash>) -> anyhow::Result<HashMap<ImageHash, Vec<u8>>> {
+        self.image_store.get(hashes).await
+    }
+
+    // fetched separately for focused search result, so that search doesn't need to read files or keep previews in results
+    pub fn result_preview(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> anyhow::Result<Option<SearchResultPreview>> {
+        if OpenTargets::is_open_target(&plugin_id) {
+            return self.open_targets.preview(&entrypoint_id);
+        }
+
+        if RecentProjects::is_recent_project(&plugin_id) {
+            return self.recent_projects.preview(&entrypoint_id);
+        }
+
+        let preview = self.search_providers.preview(&plugin_id, &entrypoint_id)
+            .or_else(|| self.search_index.preview(&plugin_id, &entrypoint_id))
+            .map(|content| SearchResultPreview::Markdown { content });
+
+        Ok(preview)
+    }
+
+    // path comes from search result shown in frontend, it is not limited to plugin's file system permissions
+    pub async fn file_quicklook(&self, path: String, dark: bool) -> anyhow::Result<FileQuicklook> {
+        file_quicklook(path, dark).await
+    }
+
+    pub fn record_request(&self, source: &str, request: &str) {
+        self.metrics.record_request(source, request)
+    }
+
+    // port is only read on start
+    pub fn start_metrics_endpoint(&self) {
+        if let Some(port) = self.config_reader.metrics_port() {
+            let metrics = self.metrics.clone();
+
+            tokio::spawn(async move {
+                if let Err(err) = serve_prometheus(metrics, port).await {
+                    tracing::error!("Metrics endpoint has been stopped: {:?}", err);
+                }
+            });
+        }
+    }
+
+    pub async fn tick_timers(&self) -> anyhow::Result<()> {
+        self.timers.tick(self.config_reader.timer_sound()).await
+    }
+
+    pub fn browser_bridge(&self) -> BrowserBridge {
+        self.browser_bridge.clone()
+    }
+
+    // port is only read on start
+    pub fn http_api_port(&self) -> Option<u16> {
+        self.config_reader.http_api_port()
+    }
+
+    pub fn http_api_token(&self) -> anyhow::Result<String> {
+        read_or_create_token(&self.dirs.http_api_token_file())
+    }
+
+    pub async fn init_crash_reports(&self) -> anyhow::Result<()> {
+        let enabled = self.settings.crash_reports_enabled_setting().await?;
+
+        set_crash_reports_enabled(enabled);
+
+        Ok(())
+    }
+
+    pub async fn set_crash_reports_enabled(&self, enabled: bool) -> anyhow::Result<()> {
+        self.settings.set_crash_reports_enabled_setting(enabled).await
+    }
+
+    pub async fn get_crash_reports_enabled(&self) -> anyhow::Result<bool> {
+        self.settings.crash_reports_enabled_setting().await
+    }
+
+    pub async fn set_usage_stats_enabled(&self, enabled: bool) -> anyhow::Result<()> {
+        self.settings.set_usage_stats_enabled_setting(enabled).await
+    }
+
+    pub async fn get_usage_stats_enabled(&self) -> anyhow::Result<bool> {
+        self.settings.usage_stats_enabled_setting().await
+    }
+
+    pub async fn usage_stats(&self) -> anyhow::Result<SettingsUsageStats> {
+        self.usage_stats.stats().await
+    }
+
+    pub async fn clear_usage_stats(&self) -> anyhow::Result<()> {
+        self.usage_stats.clear().await
     }
 
-    pub async fn set_entrypoint_state(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, enabled: bool) -> anyhow::Result<()> {
-        tracing::debug!(target = "plugin", "Setting entrypoint state for plugin id: {:?}, entrypoint_id: {:?}, enabled: {}", plugin_id, entrypoint_id, enabled);
+    // autostart used to be always enabled on macOS and Windows, keep it enabled by default there
+    // until user changes the setting for the first time
+    pub async fn init_autostart(&self) -> anyhow::Result<()> {
+        if self.settings.autostart_configured_setting().await? {
+            return Ok(())
+        }
 
-        self.db_repository.set_plugin_entrypoint_enabled(&plugin_id.to_string(), &entrypoint_id.to_string(), enabled)
-            .await?;
+        if cfg!(feature = "release") && cfg!(any(target_os = "macos", target_os = "windows")) {
+            self.autostart.set_enabled(true)?;
+        }
 
+        self.settings.set_autostart_configured_setting().await
+    }
 
-        self.reload_plugin(plugin_id.clone()).await?;
+    pub async fn set_autostart_enabled(&self, enabled: bool) -> anyhow::Result<()> {
+        self.autostart.set_enabled(enabled)?;
 
-        Ok(())
+        self.settings.set_autostart_configured_setting().await
     }
 
-    pub async fn set_global_shortcut(&self, shortcut: Option<PhysicalShortcut>) -> anyhow::Result<()> {
-        self.settings.set_global_shortcut(shortcut).await
+    pub fn get_autostart_enabled(&self) -> anyhow::Result<bool> {
+        self.autostart.is_enabled()
     }
 
-    pub async fn get_global_shortcut(&self) -> anyhow::Result<Option<(Option<PhysicalShortcut>, Option<String>)>> {
-        self.settings.global_shortcut().await
+    pub fn crash_reports(&self) -> anyhow::Result<Vec<SettingsCrashReport>> {
+        crash_reports()
     }
 
-    pub async fn set_theme(&self, theme: SettingsTheme) -> anyhow::Result<()> {
-        self.settings.set_theme_setting(theme).await
+    pub fn remove_crash_report(&self, id: String) -> anyhow::Result<()> {
+        remove_crash_report(&id)
     }
 
-    pub async fn get_theme(&self) -> anyhow::Result<SettingsTheme> {
-        self.settings.theme_setting().await
-    }
+    pub fn submit_crash_report(&self, id: String) -> anyhow::Result<()> {
+        let url = crash_report_submit_url(&id)?;
 
-    pub async fn set_window_position_mode(&self, mode: WindowPositionMode) -> anyhow::Result<()> {
-        self.settings.set_window_position_mode_setting(mode).await
-    }
+        open::that_detached(url)?;
 
-    pub async fn get_window_position_mode(&self) -> anyhow::Result<WindowPositionMode> {
-        self.settings.window_position_mode_setting().await
+        Ok(())
     }
 
     pub async fn set_preference_value(&self, plugin_id: PluginId, entrypoint_id: Option<EntrypointId>, preference_id: String, preference_value: PluginPreferenceUserData) -> anyhow::Result<()> {
@@ -315,6 +1085,12 @@ impl ApplicationManager {
     pub async fn reload_config(&self) -> anyhow::Result<()> {
         self.config_reader.reload_config().await?;
 
+        self.search_index.set_transliteration(self.config_reader.search_transliteration())?;
+
+        self.clipboard.set_sensitive_clear_after(self.config_reader.clipboard_clear_sensitive_after());
+
+        self.terminal.set_command_template(self.config_reader.terminal_command());
+
         Ok(())
     }
 
@@ -323,8 +1099,20 @@ impl ApplicationManager {
 
         self.reload_config().await?;
 
-        for plugin in self.db_repository.list_plugins().await? {
+        let plugins = self.db_repository.list_plugins().await?;
+
+        // search index is loaded from disk and may contain plugins which were removed since then
+        let plugin_ids = plugins.iter()
+            .map(|plugin| PluginId::from_string(&plugin.id))
+            .collect::<HashSet<_>>();
+
+        self.search_index.retain_plugins(&plugin_ids)?;
+
+        for plugin in plugins {
             let plugin_id = PluginId::from_string(plugin.id);
+
+            self.search_index.set_plugin_priority(plugin_id.clone(), plugin.priority);
+
             let running = self.run_status_holder.is_plugin_running(&plugin_id);
             match (running, plugin.enabled) {
                 (false, true) => {
@@ -334,6 +1122,9 @@ impl ApplicationManager {
                     self.stop_plugin(plugin_id.clone()).await;
                     self.search_index.remove_for_plugin(plugin_id)?;
                 }
+                (false, false) => {
+                    self.search_index.remove_for_plugin(plugin_id)?;
+                }
                 _ => {}
             }
         }
@@ -341,6 +1132,90 @@ impl ApplicationManager {
         Ok(())
     }
 
+    // paused plugins stay enabled, they are just not running until resumed
+    pub async fn set_plugins_paused(&self, paused: bool) -> anyhow::Result<()> {
+        tracing::info!("Setting all plugins paused: {}", paused);
+
+        self.plugins_paused.store(paused, Ordering::SeqCst);
+
+        if paused {
+            for plugin in self.db_repository.list_plugins().await? {
+                let plugin_id = PluginId::from_string(plugin.id);
+
+                if self.run_status_holder.is_plugin_running(&plugin_id) {
+                    self.stop_plugin(plugin_id.clone()).await;
+                    self.search_index.remove_for_plugin(plugin_id)?;
+                }
+            }
+
+            Ok(())
+        } else {
+            self.reload_all_plugins().await
+        }
+    }
+
+    // plugins are restarted, so that network permissions are applied to new runtime
+    pub async fn set_offline_mode(&self, offline: bool) -> anyhow::Result<()> {
+        tracing::info!("Setting offline mode: {}", offline);
+
+        self.settings.set_offline_mode_setting(offline).await?;
+
+        for plugin in self.db_repository.list_plugins().await? {
+            let plugin_id = PluginId::from_string(plugin.id);
+
+            if self.run_status_holder.is_plugin_running(&plugin_id) {
+                self.reload_plugin(plugin_id).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // rebuilds search index from scratch using data provided by plugins, in case stored index is out of sync
+    pub async fn reindex(&self) -> anyhow::Result<()> {
+        tracing::info!("Rebuilding search index");
+
+        self.search_index.remove_all()?;
+
+        for plugin in self.db_repository.list_plugins().await? {
+            let plugin_id = PluginId::from_string(plugin.id);
+
+            if self.run_status_holder.is_plugin_running(&plugin_id) {
+                self.send_command(PluginCommand::One {
+                    id: plugin_id,
+                    data: OnePluginCommandData::RefreshSearchIndex
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn set_renderer_info(&self, renderer: UiRendererInfo) {
+        tracing::info!("Main window is using renderer: {} ({})", renderer.backend, renderer.adapter);
+
+        *self.renderer_info.lock().unwrap() = Some(renderer);
+    }
+
+    pub fn renderer_info(&self) -> Option<UiRendererInfo> {
+        self.renderer_info.lock().unwrap().clone()
+    }
+
+    pub fn set_focus_mode(&self, enabled: bool) {
+        tracing::info!("Setting focus mode: {}", enabled);
+
+        let pending_refresh = self.focus_mode.set_enabled(enabled);
+
+        if enabled {
+            // inline view handlers render nothing for empty query, so currently shown inline views are closed
+            self.handle_inline_view("");
+        }
+
+        for plugin_id in pending_refresh {
+            self.request_search_index_refresh(plugin_id);
+        }
+    }
+
     pub async fn remove_plugin(&self, plugin_id: PluginId) -> anyhow::Result<()> {
         tracing::info!(target = "plugin", "Removing plugin with id: {:?}", plugin_id);
 
@@ -348,11 +1223,90 @@ impl ApplicationManager {
         if running {
             self.stop_plugin(plugin_id.clone()).await;
         }
+
+        let plugin_uuid = self.db_repository.get_plugin_by_id(&plugin_id.to_string())
+            .await?
+            .uuid;
+
         self.db_repository.remove_plugin(&plugin_id.to_string()).await?;
         self.search_index.remove_for_plugin(plugin_id)?;
+
+        // includes databases opened by plugin
+        for dir in [self.dirs.plugin_data(&plugin_uuid)?, self.dirs.plugin_cache(&plugin_uuid)?] {
+            if dir.exists() {
+                if let Err(err) = std::fs::remove_dir_all(&dir) {
+                    tracing::warn!(target = "plugin", "Unable to remove plugin directory {:?}: {:?}", dir, err);
+                }
+            }
+        }
+
         Ok(())
     }
 
+    pub async fn plugin_disk_usage(&self, plugin_id: PluginId) -> anyhow::Result<SettingsPluginDiskUsage> {
+        let plugin = self.db_repository.get_plugin_by_id(&plugin_id.to_string())
+            .await?;
+
+        let data_dir = self.dirs.plugin_data(&plugin.uuid)?;
+        let cache_dir = self.dirs.plugin_cache(&plugin.uuid)?;
+        let (out_log_file, err_log_file) = self.dirs.plugin_log_files(&plugin.uuid);
+
+        let usage = tokio::task::spawn_blocking(move || {
+            SettingsPluginDiskUsage {
+                data: directory_size(&data_dir),
+                cache: directory_size(&cache_dir),
+                logs: directory_size(&out_log_file) + directory_size(&err_log_file),
+            }
+        }).await?;
+
+        Ok(usage)
+    }
+
+    pub async fn clear_plugin_cache(&self, plugin_id: PluginId) -> anyhow::Result<()> {
+        tracing::info!(target = "plugin", "Clearing cache of plugin with id: {:?}", plugin_id);
+
+        let plugin = self.db_repository.get_plugin_by_id(&plugin_id.to_string())
+            .await?;
+
+        let cache_dir = self.dirs.plugin_cache(&plugin.uuid)?;
+
+        self.clear_plugin_directory(plugin_id, cache_dir).await
+    }
+
+    // includes databases and files written by plugin, preferences are stored separately and are kept
+    pub async fn clear_plugin_data(&self, plugin_id: PluginId) -> anyhow::Result<()> {
+        tracing::info!(target = "plugin", "Clearing data of plugin with id: {:?}", plugin_id);
+
+        let plugin = self.db_repository.get_plugin_by_id(&plugin_id.to_string())
+            .await?;
+
+        let data_dir = self.dirs.plugin_data(&plugin.uuid)?;
+
+        self.clear_plugin_directory(plugin_id, data_dir).await
+    }
+
+    // running plugin is stopped while directory is removed, so that it doesn't keep files open.
+    // directory is created again by plugin runtime when it starts
+    async fn clear_plugin_directory(&self, plugin_id: PluginId, dir: PathBuf) -> anyhow::Result<()> {
+        let running = self.run_status_holder.is_plugin_running(&plugin_id);
+        if running {
+            self.stop_plugin(plugin_id.clone()).await;
+        }
+
+        let result = if dir.exists() {
+            std::fs::remove_dir_all(&dir)
+                .with_context(|| format!("Unable to remove plugin directory: {:?}", dir))
+        } else {
+            Ok(())
+        };
+
+        if running {
+            self.start_plugin(plugin_id).await?;
+        }
+
+        result
+    }
+
     pub fn handle_inline_view(&self, text: &str) {
         self.send_command(PluginCommand::All {
             data: AllPluginCommandData::OpenInlineView {
@@ -361,11 +1315,91 @@ impl ApplicationManager {
         })
     }
 
-    pub async fn handle_run_command(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) {
+    pub async fn handle_run_command(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, arguments: HashMap<String, String>) {
+        if Quicklinks::is_quicklink(&plugin_id) {
+            if let Err(err) = self.quicklinks.open(&entrypoint_id) {
+                tracing::error!("An error occurred when opening quicklink '{}': {:?}", entrypoint_id, err);
+            }
+
+            return;
+        }
+
+        if OpenTargets::is_open_target(&plugin_id) {
+            if let Err(err) = self.open_targets.open(&entrypoint_id) {
+                tracing::error!("An error occurred when opening search query target '{}': {:?}", entrypoint_id, err);
+            }
+
+            return;
+        }
+
+        if Timers::is_timer(&plugin_id) {
+            if let Err(err) = self.timers.run(&entrypoint_id).await {
+                tracing::error!("An error occurred when running timer action '{}': {:?}", entrypoint_id, err);
+            }
+
+            return;
+        }
+
+        if Passwords::is_password(&plugin_id) {
+            if let Err(err) = self.passwords.run(&entrypoint_id, false).await {
+                tracing::error!("An error occurred when running password action '{}': {:?}", entrypoint_id, err);
+            }
+
+            return;
+        }
+
+        if SshHosts::is_ssh_host(&plugin_id) {
+            if let Err(err) = self.ssh_hosts.open(&entrypoint_id) {
+                tracing::error!("An error occurred when opening ssh host '{}': {:?}", entrypoint_id, err);
+            }
+
+            return;
+        }
+
+        if RecentProjects::is_recent_project(&plugin_id) {
+            self.open_recent_project(&entrypoint_id, 0);
+
+            return;
+        }
+
+        if Fallbacks::is_fallback(&plugin_id) {
+            match self.fallbacks.run(&entrypoint_id) {
+                Ok(FallbackRun::Done) => {}
+                Ok(FallbackRun::Entrypoint { plugin_id, entrypoint_id, arguments }) => {
+                    // boxed because run_entrypoint runs commands using this method
+                    if let Err(err) = Box::pin(self.run_entrypoint(plugin_id, entrypoint_id.clone(), arguments)).await {
+                        tracing::error!("An error occurred when running fallback entrypoint '{}': {:?}", entrypoint_id, err);
+                    }
+                }
+                Err(err) => {
+                    tracing::error!("An error occurred when running fallback '{}': {:?}", entrypoint_id, err);
+                }
+            }
+
+            return;
+        }
+
+        if Profiles::is_profile(&plugin_id) {
+            if let Err(err) = self.switch_profile(entrypoint_id.to_string()).await {
+                tracing::error!("An error occurred when switching to profile '{}': {:?}", entrypoint_id, err);
+            }
+
+            return;
+        }
+
+        if BrowserBridge::is_browser_item(&plugin_id) {
+            if let Err(err) = self.browser_bridge.activate(&entrypoint_id) {
+                tracing::error!("An error occurred when activating browser item '{}': {:?}", entrypoint_id, err);
+            }
+
+            return;
+        }
+
         self.send_command(PluginCommand::One {
             id: plugin_id.clone(),
             data: OnePluginCommandData::RunCommand {
                 entrypoint_id: entrypoint_id.to_string(),
+                arguments,
             }
         });
 
@@ -373,6 +1407,21 @@ impl ApplicationManager {
     }
 
     pub async fn handle_run_generated_entrypoint(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, action_index: usize) {
+        // second action of password results pastes value into active application
+        if Passwords::is_password(&plugin_id) {
+            if let Err(err) = self.passwords.run(&entrypoint_id, action_index == 1).await {
+                tracing::error!("An error occurred when running password action '{}': {:?}", entrypoint_id, err);
+            }
+
+            return;
+        }
+
+        if RecentProjects::is_recent_project(&plugin_id) {
+            self.open_recent_project(&entrypoint_id, action_index);
+
+            return;
+        }
+
         self.send_command(PluginCommand::One {
             id: plugin_id.clone(),
             data: OnePluginCommandData::RunGeneratedEntrypoint {
@@ -384,6 +1433,14 @@ impl ApplicationManager {
         self.mark_entrypoint_frecency(plugin_id, entrypoint_id).await
     }
 
+    fn open_recent_project(&self, entrypoint_id: &EntrypointId, action_index: usize) {
+        let editor_command = self.config_reader.project_editor_command();
+
+        if let Err(err) = self.recent_projects.open(entrypoint_id, action_index, &editor_command) {
+            tracing::error!("An error occurred when opening recent project '{}': {:?}", entrypoint_id, err);
+        }
+    }
+
     pub async fn handle_render_view(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> anyhow::Result<HashMap<String, PhysicalShortcut>> {
         self.send_command(PluginCommand::One {
             id: plugin_id.clone(),
@@ -406,6 +1463,13 @@ impl ApplicationManager {
         })
     }
 
+    pub fn handle_view_pop(&self, plugin_id: PluginId) {
+        self.send_command(PluginCommand::One {
+            id: plugin_id,
+            data: OnePluginCommandData::PopView
+        })
+    }
+
     pub fn handle_view_event(&self, plugin_id: PluginId, widget_id: UiWidgetId, event_name: String, event_arguments: Vec<UiPropertyValue>) {
         self.send_command(PluginCommand::One {
             id: plugin_id,
@@ -433,6 +1497,11 @@ impl ApplicationManager {
     }
 
     pub fn request_search_index_refresh(&self, plugin_id: PluginId) {
+        if self.focus_mode.defer_search_index_refresh(&plugin_id) {
+            tracing::debug!("Focus mode is enabled, deferring search index refresh for plugin: {:?}", plugin_id);
+            return;
+        }
+
         self.send_command(PluginCommand::One {
             id: plugin_id,
             data: OnePluginCommandData::RefreshSearchIndex
@@ -446,36 +1515,12 @@ impl ApplicationManager {
         }
     }
 
-    pub fn handle_open_settings_window(&self) {
-        let current_exe = std::env::current_exe()
-            .expect("unable to get current_exe");
-
-        std::process::Command::new(current_exe)
-            .args(["settings"])
-            .spawn()
-            .expect("failed to execute settings process");
+    pub async fn handle_open_settings_window(&self) {
+        open_settings_window(&self.frontend_api, None, None).await
     }
 
-    pub fn handle_open_settings_window_preferences(&self, plugin_id: PluginId, entrypoint_id: Option<EntrypointId>) {
-        let data = if let Some(entrypoint_id) = entrypoint_id {
-            SettingsEnvData::OpenEntrypointPreferences {
-                plugin_id: plugin_id.to_string(),
-                entrypoint_id: entrypoint_id.to_string(),
-            }
-        } else {
-            SettingsEnvData::OpenPluginPreferences {
-                plugin_id: plugin_id.to_string()
-            }
-        };
-
-        let current_exe = std::env::current_exe()
-            .expect("unable to get current_exe");
-
-        std::process::Command::new(current_exe)
-            .args(["settings"])
-            .env(SETTINGS_ENV, settings_env_data_to_string(data))
-            .spawn()
-            .expect("failed to execute settings process"); // this can fail in dev if binary was replaced by more recent compilation
+    pub async fn handle_open_settings_window_preferences(&self, plugin_id: PluginId, entrypoint_id: Option<EntrypointId>) {
+        open_settings_window(&self.frontend_api, Some(plugin_id), entrypoint_id).await
     }
 
     async fn reload_plugin(&self, plugin_id: PluginId) -> anyhow::Result<()> {
@@ -502,7 +1547,14 @@ impl ApplicationManager {
         self.db_repository.action_shortcuts(&plugin_id.to_string(), &entrypoint_id.to_string()).await
     }
 
+    // every path which starts plugins goes through here, so paused plugins are not started by
+    // enabling them, changing their settings or reloading them
     async fn start_plugin(&self, plugin_id: PluginId) -> anyhow::Result<()> {
+        if self.plugins_paused.load(Ordering::SeqCst) {
+            tracing::info!(target = "plugin", "Plugins are paused, not starting plugin with id: {:?}", plugin_id);
+            return Ok(())
+        }
+
         tracing::info!(target = "plugin", "Starting plugin with id: {:?}", plugin_id);
 
         let plugin_id_str = plugin_id.to_string();
@@ -510,15 +1562,28 @@ impl ApplicationManager {
         let plugin = self.db_repository.get_plugin_by_id(&plugin_id_str)
             .await?;
 
-        let entrypoint_names = self.db_repository.get_entrypoints_by_plugin_id(&plugin_id_str)
-            .await?
+        let entrypoints = self.db_repository.get_entrypoints_by_plugin_id(&plugin_id_str)
+            .await?;
+
+        // modules of views are loaded ahead of time, so that first render doesn't have to wait for them
+        let preload_entrypoint_ids = entrypoints.iter()
+            .filter(|entrypoint| entrypoint.enabled)
+            .filter(|entrypoint| matches!(db_entrypoint_from_str(&entrypoint.entrypoint_type), DbPluginEntrypointType::View | DbPluginEntrypointType::InlineView))
+            .map(|entrypoint| EntrypointId::from_string(&entrypoint.id))
+            .collect::<Vec<_>>();
+
+        let entrypoint_names = entrypoints
             .into_iter()
-            .map(|entrypoint| (EntrypointId::from_string(entrypoint.id), entrypoint.name))
+            .map(|entrypoint| (EntrypointId::from_string(&entrypoint.id), entrypoint.localized_name()))
             .collect::<HashMap<EntrypointId, String>>();
 
         let inline_view_entrypoint_id = self.db_repository.get_inline_view_entrypoint_id_for_plugin(&plugin_id_str)
             .await?;
 
+        let network_proxy = self.config_reader.network_proxy(&plugin_id);
+
+        let offline_mode = self.settings.offline_mode_setting().await?;
+
         let receiver = self.command_broadcaster.subscribe();
 
         let clipboard_permissions = plugin.permissions
@@ -546,6 +1611,7 @@ impl ApplicationManager {
             entrypoint_names,
             code: JsPluginCode { js: plugin.code.js },
             inline_view_entrypoint_id,
+            preload_entrypoint_ids,
             permissions: PluginPermissions {
                 environment: plugin.permissions.environment,
                 network: plugin.permissions.network,
@@ -561,13 +1627,31 @@ impl ApplicationManager {
                 clipboard: clipboard_permissions,
                 main_search_bar: main_search_bar_permissions
             },
+            http_cache: plugin.http_cache.map(|http_cache| JsPluginHttpCache {
+                max_size: http_cache.max_size,
+                stale_while_revalidate: http_cache.stale_while_revalidate,
+            }),
+            network_proxy,
+            environment: plugin.environment,
+            filesystem_roots: plugin.filesystem_roots,
+            offline_mode,
             command_receiver: receiver,
+            command_broadcaster: self.command_broadcaster.clone(),
             db_repository: self.db_repository.clone(),
             search_index: self.search_index.clone(),
             icon_cache: self.icon_cache.clone(),
             frontend_api: self.frontend_api.clone(),
             dirs: self.dirs.clone(),
             clipboard: self.clipboard.clone(),
+            dev_console: self.dev_console.clone(),
+            metrics: self.metrics.clone(),
+            image_store: self.image_store.clone(),
+            search_providers: self.search_providers.clone(),
+            focus_mode: self.focus_mode.clone(),
+            notifications: self.notifications.clone(),
+            input_injection: self.input_injection.clone(),
+            terminal: self.terminal.clone(),
+            oauth: self.oauth.clone(),
         };
 
         self.start_plugin_runtime(data);
@@ -578,6 +1662,8 @@ impl ApplicationManager {
     async fn stop_plugin(&self, plugin_id: PluginId) {
         tracing::info!(target = "plugin", "Stopping plugin with id: {:?}", plugin_id);
 
+        self.search_providers.remove_for_plugin(&plugin_id);
+
         self.run_status_holder.stop_plugin(&plugin_id)
     }
 
@@ -604,9 +1690,23 @@ impl ApplicationManager {
             tracing::warn!(target = "rpc", "error occurred when marking entrypoint frecency {:?}", err)
         }
 
+        self.record_launch(&plugin_id, &entrypoint_id).await;
+
         self.request_search_index_refresh(plugin_id);
     }
 
+    async fn record_launch(&self, plugin_id: &PluginId, entrypoint_id: &EntrypointId) {
+        let result = match self.settings.usage_stats_enabled_setting().await {
+            Ok(true) => self.usage_stats.record_launch(plugin_id, entrypoint_id).await,
+            Ok(false) => Ok(()),
+            Err(err) => Err(err),
+        };
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when recording usage stats {:?}", err)
+        }
+    }
+
     pub async fn inline_view_shortcuts(&self) -> anyhow::Result<HashMap<PluginId, HashMap<String, PhysicalShortcut>>> {
         let result: HashMap<_, _> = self.db_repository.inline_view_shortcuts()
             .await?
@@ -706,3 +1806,160 @@ fn plugin_preference_user_data_from_db(value: DbPluginPreferenceUserData) -> Plu
     }
 }
 
+// everything which decides what happens when search result is run
+#[derive(PartialEq, Eq, Hash)]
+struct SearchResultIdentity {
+    plugin_id: PluginId,
+    entrypoint_id: EntrypointId,
+    entrypoint_generator_name: Option<String>,
+    entrypoint_name: String,
+    entrypoint_file: Option<String>,
+    argument_ids: Vec<String>,
+    action_labels: Vec<String>,
+}
+
+fn search_result_identity(item: &SearchResult) -> SearchResultIdentity {
+    SearchResultIdentity {
+        plugin_id: item.plugin_id.clone(),
+        entrypoint_id: item.entrypoint_id.clone(),
+        entrypoint_generator_name: item.entrypoint_generator_name.clone(),
+        entrypoint_name: item.entrypoint_name.clone(),
+        entrypoint_file: item.entrypoint_file.clone(),
+        argument_ids: item.entrypoint_arguments
+            .iter()
+            .map(|argument| argument.id.clone())
+            .collect(),
+        action_labels: item.entrypoint_actions
+            .iter()
+            .map(|action| action.label.clone())
+            .collect(),
+    }
+}
+
+// token is generated on first use and kept in state dir,
+// so clients don't need to be reconfigured after restart
+// files which can't be read are skipped
+fn directory_size(path: &Path) -> u64 {
+    walkdir::WalkDir::new(path)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.metadata().ok())
+        .filter(|metadata| metadata.is_file())
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+pub fn read_or_create_token(token_file: &Path) -> anyhow::Result<String> {
+    if token_file.exists() {
+        let token = std::fs::read_to_string(token_file)
+            .context("Unable to read token file")?;
+
+        let token = token.trim();
+
+        if !token.is_empty() {
+            return Ok(token.to_string())
+        }
+
+        // empty token would allow any request with empty bearer token
+        tracing::warn!("Token file {:?} is empty, generating new token", token_file);
+
+        std::fs::remove_file(token_file)
+            .context("Unable to remove empty token file")?;
+    }
+
+    let token = Uuid::new_v4().simple().to_string();
+
+    if let Some(parent) = token_file.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+
+    // file is created with restricted permissions, so token is never readable by other users
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+
+        options.mode(0o600);
+    }
+
+    let mut file = options.open(token_file)
+        .context("Unable to create token file")?;
+
+    file.write_all(token.as_bytes())
+        .context("Unable to write token file")?;
+
+    tracing::info!("Token has been generated and saved to {:?}", token_file);
+
+    Ok(token)
+}
+
+// arguments are positional and are assigned in order in which arguments are declared in manifest
+pub fn command_argument_values(entrypoint_id: &EntrypointId, declared: &[DbPluginArgument], arguments: Vec<String>) -> anyhow::Result<HashMap<String, String>> {
+    if arguments.len() > declared.len() {
+        return Err(anyhow!("Entrypoint accepts at most {} arguments: {}", declared.len(), entrypoint_id))
+    }
+
+    let mut arguments = arguments.into_iter();
+
+    let mut argument_values = HashMap::new();
+
+    for argument in declared {
+        let (id, required) = match argument {
+            DbPluginArgument::String { id, required, .. } => (id, *required),
+            DbPluginArgument::Enum { id, required, .. } => (id, *required),
+            DbPluginArgument::File { id, required, .. } => (id, *required),
+        };
+
+        match arguments.next() {
+            Some(value) => {
+                argument_values.insert(id.clone(), value);
+            }
+            None => {
+                if required {
+                    return Err(anyhow!("Required argument '{}' is missing: {}", id, entrypoint_id))
+                }
+            }
+        }
+    }
+
+    Ok(argument_values)
+}
+
+// settings are shown as a window of frontend, separate settings process is only started if there is no frontend
+pub async fn open_settings_window(frontend_api: &FrontendApi, plugin_id: Option<PluginId>, entrypoint_id: Option<EntrypointId>) {
+    let result = frontend_api.show_settings_window(plugin_id.clone(), entrypoint_id.clone())
+        .await;
+
+    let Err(err) = result else {
+        return
+    };
+
+    tracing::debug!("Unable to show settings window in frontend, starting settings process: {:?}", err);
+
+    let data = match (plugin_id, entrypoint_id) {
+        (Some(plugin_id), Some(entrypoint_id)) => Some(SettingsEnvData::OpenEntrypointPreferences {
+            plugin_id: plugin_id.to_string(),
+            entrypoint_id: entrypoint_id.to_string(),
+        }),
+        (Some(plugin_id), None) => Some(SettingsEnvData::OpenPluginPreferences {
+            plugin_id: plugin_id.to_string()
+        }),
+        (None, _) => None,
+    };
+
+    let current_exe = std::env::current_exe()
+        .expect("unable to get current_exe");
+
+    let mut command = std::process::Command::new(current_exe);
+
+    command.args(["settings"]);
+
+    if let Some(data) = data {
+        command.env(SETTINGS_ENV, settings_env_data_to_string(data));
+    }
+
+    command.spawn()
+        .expect("failed to execute settings process"); // this can fail in dev if binary was replaced by more recent compilation
+}
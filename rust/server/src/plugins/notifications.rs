@@ -0,0 +1,104 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+use gauntlet_common::model::PluginId;
+
+use crate::plugins::js::{OnePluginCommandData, PluginCommand};
+
+const APP_NAME: &str = "Gauntlet";
+
+// desktop notifications, shown using freedesktop notification service on linux
+// and system notification center on macos and windows.
+// action buttons are only supported on linux, on other platforms notification is shown without them
+#[derive(Clone)]
+pub struct Notifications {
+    next_id: Arc<AtomicU32>,
+    command_broadcaster: tokio::sync::broadcast::Sender<PluginCommand>,
+}
+
+impl Notifications {
+    pub fn new(command_broadcaster: tokio::sync::broadcast::Sender<PluginCommand>) -> Self {
+        Self {
+            next_id: Arc::new(AtomicU32::new(0)),
+            command_broadcaster,
+        }
+    }
+
+    pub fn show(&self, title: String, body: Option<String>) {
+        deliver(title, body, vec![], |_| {});
+    }
+
+    // returns id of notification, plugin receives it back together with index of clicked action button
+    pub fn show_for_plugin(&self, plugin_id: PluginId, title: String, body: Option<String>, actions: Vec<String>) -> u32 {
+        let notification_id = self.next_id.fetch_add(1, Ordering::SeqCst);
+
+        if actions.is_empty() {
+            deliver(title, body, actions, |_| {});
+        } else {
+            let command_broadcaster = self.command_broadcaster.clone();
+
+            deliver(title, body, actions, move |action_index| {
+                // plugin may have been stopped in the meantime
+                let _ = command_broadcaster.send(PluginCommand::One {
+                    id: plugin_id,
+                    data: OnePluginCommandData::NotificationClosed {
+                        notification_id,
+                        action_index,
+                    },
+                });
+            });
+        }
+
+        notification_id
+    }
+}
+
+// on_close receives index of clicked action button, or none if notification was dismissed or has expired
+fn deliver(title: String, body: Option<String>, actions: Vec<String>, on_close: impl FnOnce(Option<usize>) + Send + 'static) {
+    let mut notification = notify_rust::Notification::new();
+
+    notification.appname(APP_NAME)
+        .summary(&title);
+
+    if let Some(body) = &body {
+        notification.body(body);
+    }
+
+    // waiting for notification service and for user to click the action blocks the thread
+    std::thread::spawn(move || {
+        #[cfg(target_os = "linux")]
+        {
+            for (index, label) in actions.iter().enumerate() {
+                notification.action(&index.to_string(), label);
+            }
+
+            match notification.show() {
+                Ok(handle) => {
+                    if actions.is_empty() {
+                        on_close(None)
+                    } else {
+                        // "__closed" is reported when notification is dismissed
+                        handle.wait_for_action(|action| on_close(action.parse().ok()));
+                    }
+                }
+                Err(err) => {
+                    tracing::warn!("Unable to show desktop notification: {:?}", err);
+                    on_close(None)
+                }
+            }
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            if !actions.is_empty() {
+                tracing::debug!("Notification action buttons are not supported on this platform, showing notification without them");
+            }
+
+            if let Err(err) = notification.show() {
+                tracing::warn!("Unable to show desktop notification: {:?}", err);
+            }
+
+            on_close(None)
+        }
+    });
+}
@@ -0,0 +1,449 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Context};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use url::Url;
+use uuid::Uuid;
+
+use gauntlet_common::model::PluginId;
+
+use crate::plugins::data_db_repository::DataDbRepository;
+use crate::plugins::secrets::Secrets;
+
+// user has this much time to log in after browser is opened
+const AUTHORIZATION_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+// access token is refreshed slightly before it expires, so it is still valid when plugin uses it
+const EXPIRATION_MARGIN: Duration = Duration::from_secs(60);
+
+pub struct OAuthConfig {
+    pub client_id: String,
+    // not needed for public clients, some providers require it even when pkce is used
+    pub client_secret: Option<String>,
+    pub authorize_url: String,
+    pub token_url: String,
+    pub scopes: Vec<String>,
+    // for providers which require exact redirect url, random free port is used otherwise
+    pub redirect_port: Option<u16>,
+    pub extra_authorize_params: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OAuthTokens {
+    pub access_token: String,
+    pub token_type: String,
+    pub refresh_token: Option<String>,
+    // seconds since unix epoch
+    pub expires_at: Option<u64>,
+    pub scopes: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    token_type: Option<String>,
+    refresh_token: Option<String>,
+    expires_in: Option<u64>,
+    scope: Option<String>,
+}
+
+enum OAuthFlow {
+    Pending,
+    Finished(Result<OAuthTokens, String>),
+}
+
+// authorization code flow with pkce, tokens are stored in secrets store and refreshed when expired.
+// because browser part of the flow can take minutes, it runs in background
+// and plugin polls for its result, so other requests of the plugin are not blocked
+#[derive(Clone)]
+pub struct OAuth {
+    secrets: Secrets,
+    repository: DataDbRepository,
+    flows: Arc<Mutex<HashMap<(PluginId, String), OAuthFlow>>>,
+}
+
+impl OAuth {
+    pub fn new(secrets: Secrets, repository: DataDbRepository) -> Self {
+        Self {
+            secrets,
+            repository,
+            flows: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    // returns none while authorization in browser is in progress
+    pub async fn authorize(&self, plugin_id: PluginId, config: OAuthConfig, proxy: Option<String>) -> anyhow::Result<Option<OAuthTokens>> {
+        let key = secret_key(&config);
+
+        {
+            let mut flows = self.flows.lock().expect("lock is poisoned");
+
+            let flow_key = (plugin_id.clone(), key.clone());
+
+            match flows.remove(&flow_key) {
+                Some(OAuthFlow::Pending) => {
+                    flows.insert(flow_key, OAuthFlow::Pending);
+
+                    return Ok(None)
+                }
+                Some(OAuthFlow::Finished(result)) => {
+                    return result
+                        .map(Some)
+                        .map_err(|err| anyhow!(err))
+                }
+                None => {}
+            }
+        }
+
+        if let Some(tokens) = self.stored_tokens(&plugin_id, &key)? {
+            let has_scopes = config.scopes
+                .iter()
+                .all(|scope| tokens.scopes.contains(scope));
+
+            if has_scopes {
+                if !is_expired(&tokens) {
+                    return Ok(Some(tokens))
+                }
+
+                if let Some(refresh_token) = &tokens.refresh_token {
+                    self.ensure_online().await?;
+
+                    match refresh(&config, refresh_token, &tokens, proxy.clone()).await {
+                        Ok(tokens) => {
+                            self.store_tokens(&plugin_id, &key, &tokens)?;
+
+                            return Ok(Some(tokens))
+                        }
+                        Err(err) => {
+                            tracing::warn!("Unable to refresh OAuth token for plugin {:?}, authorizing again: {:#}", plugin_id, err);
+                        }
+                    }
+                }
+            }
+        }
+
+        self.ensure_online().await?;
+
+        self.flows.lock()
+            .expect("lock is poisoned")
+            .insert((plugin_id.clone(), key.clone()), OAuthFlow::Pending);
+
+        let oauth = self.clone();
+
+        tokio::spawn(async move {
+            let result = authorization_code_flow(&oauth, &config, proxy).await
+                .and_then(|tokens| {
+                    oauth.store_tokens(&plugin_id, &key, &tokens)?;
+
+                    Ok(tokens)
+                })
+                .map_err(|err| {
+                    tracing::warn!("OAuth authorization for plugin {:?} failed: {:#}", plugin_id, err);
+
+                    format!("{:#}", err)
+                });
+
+            oauth.flows.lock()
+                .expect("lock is poisoned")
+                .insert((plugin_id, key), OAuthFlow::Finished(result));
+        });
+
+        Ok(None)
+    }
+
+    pub fn remove_tokens(&self, plugin_id: &PluginId, config: &OAuthConfig) -> anyhow::Result<()> {
+        self.secrets.remove(plugin_id, &secret_key(config))
+    }
+
+    // offline mode can be enabled while authorization in browser is in progress, so it is checked right before token request
+    async fn ensure_online(&self) -> anyhow::Result<()> {
+        let settings = self.repository.get_settings().await?;
+
+        if settings.offline_mode.unwrap_or(false) {
+            return Err(anyhow!("OAuth token can't be requested while offline mode is enabled"))
+        }
+
+        Ok(())
+    }
+
+    fn stored_tokens(&self, plugin_id: &PluginId, key: &str) -> anyhow::Result<Option<OAuthTokens>> {
+        let Some(value) = self.secrets.get(plugin_id, key)? else {
+            return Ok(None)
+        };
+
+        match serde_json::from_str(&value) {
+            Ok(tokens) => Ok(Some(tokens)),
+            Err(err) => {
+                tracing::warn!("Stored OAuth tokens for plugin {:?} are invalid, authorizing again: {:?}", plugin_id, err);
+
+                Ok(None)
+            }
+        }
+    }
+
+    fn store_tokens(&self, plugin_id: &PluginId, key: &str, tokens: &OAuthTokens) -> anyhow::Result<()> {
+        self.secrets.set(plugin_id, key, &serde_json::to_string(tokens)?)
+    }
+}
+
+// one set of tokens per provider and client
+fn secret_key(config: &OAuthConfig) -> String {
+    format!("oauth:{}:{}", config.token_url, config.client_id)
+}
+
+fn is_expired(tokens: &OAuthTokens) -> bool {
+    match tokens.expires_at {
+        None => false,
+        Some(expires_at) => now_secs() + EXPIRATION_MARGIN.as_secs() >= expires_at
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time went backwards")
+        .as_secs()
+}
+
+async fn authorization_code_flow(oauth: &OAuth, config: &OAuthConfig, proxy: Option<String>) -> anyhow::Result<OAuthTokens> {
+    let listener = TcpListener::bind(("127.0.0.1", config.redirect_port.unwrap_or(0)))
+        .await
+        .context("Unable to start OAuth redirect listener")?;
+
+    let redirect_uri = format!("http://127.0.0.1:{}/callback", listener.local_addr()?.port());
+
+    // 64 characters, only unreserved characters are used
+    let code_verifier = format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+    let code_challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()));
+    let state = Uuid::new_v4().simple().to_string();
+
+    let mut authorize_url = Url::parse(&config.authorize_url)
+        .context("Invalid authorize url")?;
+
+    // url is opened by system handler, so other schemes could start arbitrary applications
+    if !matches!(authorize_url.scheme(), "http" | "https") {
+        return Err(anyhow!("Authorize url has to use http or https scheme"))
+    }
+
+    {
+        let mut query = authorize_url.query_pairs_mut();
+
+        query
+            .append_pair("response_type", "code")
+            .append_pair("client_id", &config.client_id)
+            .append_pair("redirect_uri", &redirect_uri)
+            .append_pair("code_challenge", &code_challenge)
+            .append_pair("code_challenge_method", "S256")
+            .append_pair("state", &state);
+
+        if !config.scopes.is_empty() {
+            query.append_pair("scope", &config.scopes.join(" "));
+        }
+
+        for (name, value) in &config.extra_authorize_params {
+            query.append_pair(name, value);
+        }
+    }
+
+    open::that_detached(authorize_url.as_str())
+        .context("Unable to open browser")?;
+
+    let code = tokio::time::timeout(AUTHORIZATION_TIMEOUT, wait_for_redirect(&listener, &state))
+        .await
+        .map_err(|_| anyhow!("Authorization was not completed in time"))??;
+
+    let mut params = vec![
+        ("grant_type", "authorization_code".to_string()),
+        ("code", code),
+        ("redirect_uri", redirect_uri),
+        ("client_id", config.client_id.clone()),
+        ("code_verifier", code_verifier),
+    ];
+
+    if let Some(client_secret) = &config.client_secret {
+        params.push(("client_secret", client_secret.clone()));
+    }
+
+    oauth.ensure_online().await?;
+
+    let response = request_tokens(config.token_url.clone(), params, proxy).await?;
+
+    Ok(tokens_from_response(response, None, &config.scopes))
+}
+
+async fn refresh(config: &OAuthConfig, refresh_token: &str, tokens: &OAuthTokens, proxy: Option<String>) -> anyhow::Result<OAuthTokens> {
+    let mut params = vec![
+        ("grant_type", "refresh_token".to_string()),
+        ("refresh_token", refresh_token.to_string()),
+        ("client_id", config.client_id.clone()),
+    ];
+
+    if let Some(client_secret) = &config.client_secret {
+        params.push(("client_secret", client_secret.clone()));
+    }
+
+    let response = request_tokens(config.token_url.clone(), params, proxy).await?;
+
+    Ok(tokens_from_response(response, Some(refresh_token.to_string()), &tokens.scopes))
+}
+
+// some providers don't return new refresh token or granted scopes, previous values are used in that case
+fn tokens_from_response(response: TokenResponse, refresh_token: Option<String>, scopes: &[String]) -> OAuthTokens {
+    let scopes = match response.scope {
+        Some(scope) => {
+            scope.split(|char: char| char == ' ' || char == ',')
+                .filter(|scope| !scope.is_empty())
+                .map(|scope| scope.to_string())
+                .collect()
+        }
+        None => scopes.to_vec()
+    };
+
+    OAuthTokens {
+        access_token: response.access_token,
+        token_type: response.token_type.unwrap_or_else(|| "Bearer".to_string()),
+        refresh_token: response.refresh_token.or(refresh_token),
+        expires_at: response.expires_in.map(|expires_in| now_secs() + expires_in),
+        scopes,
+    }
+}
+
+async fn request_tokens(token_url: String, params: Vec<(&'static str, String)>, proxy: Option<String>) -> anyhow::Result<TokenResponse> {
+    tokio::task::spawn_blocking(move || {
+        let mut agent = ureq::AgentBuilder::new();
+
+        if let Some(proxy) = proxy {
+            agent = agent.proxy(ureq::Proxy::new(proxy)?);
+        }
+
+        let form = params.iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect::<Vec<_>>();
+
+        // github returns form encoded response unless json is explicitly requested
+        let response = agent.build()
+            .post(&token_url)
+            .set("Accept", "application/json")
+            .send_form(&form);
+
+        let response = match response {
+            Ok(response) => response,
+            Err(ureq::Error::Status(status, response)) => {
+                let body = response.into_string().unwrap_or_default();
+
+                return Err(anyhow!("Token request failed with status {}: {}", status, body))
+            }
+            Err(err) => return Err(anyhow!(err).context("Token request failed")),
+        };
+
+        let body = response.into_string()?;
+
+        let response = serde_json::from_str::<TokenResponse>(&body)
+            .context("Unable to parse token response")?;
+
+        Ok(response)
+    }).await?
+}
+
+async fn wait_for_redirect(listener: &TcpListener, state: &str) -> anyhow::Result<String> {
+    loop {
+        let (mut stream, _) = listener.accept().await?;
+
+        let path = match read_request_path(&mut stream).await {
+            Ok(path) => path,
+            Err(err) => {
+                tracing::debug!("Unable to read OAuth redirect request: {:?}", err);
+                continue
+            }
+        };
+
+        let url = match Url::parse(&format!("http://127.0.0.1{}", path)) {
+            Ok(url) => url,
+            Err(_) => {
+                respond(&mut stream, "400 Bad Request", "Bad Request").await;
+                continue
+            }
+        };
+
+        // browsers can request other paths, e.g. favicon
+        if url.path() != "/callback" {
+            respond(&mut stream, "404 Not Found", "Not Found").await;
+            continue
+        }
+
+        let params = url.query_pairs()
+            .into_owned()
+            .collect::<HashMap<String, String>>();
+
+        // request with wrong state is not a response to this flow, e.g. another page sending request to the port,
+        // so it is ignored instead of aborting the flow
+        if params.get("state").map(|value| value.as_str()) != Some(state) {
+            respond(&mut stream, "400 Bad Request", "Bad Request").await;
+            continue
+        }
+
+        let result = if let Some(error) = params.get("error") {
+            let description = params.get("error_description").unwrap_or(error);
+
+            Err(anyhow!("Authorization failed: {}", description))
+        } else {
+            params.get("code")
+                .cloned()
+                .ok_or(anyhow!("Authorization response doesn't contain code"))
+        };
+
+        let body = match result {
+            Ok(_) => "Authorization completed, you can close this page and return to Gauntlet",
+            Err(_) => "Authorization failed, you can close this page and return to Gauntlet",
+        };
+
+        respond(&mut stream, "200 OK", body).await;
+
+        return result
+    }
+}
+
+// only request line is needed, the rest of request is ignored
+async fn read_request_path(stream: &mut TcpStream) -> anyhow::Result<String> {
+    let mut buffer = vec![0u8; 8 * 1024];
+    let mut len = 0;
+
+    loop {
+        let read = stream.read(&mut buffer[len..]).await?;
+
+        len += read;
+
+        if read == 0 || len == buffer.len() || buffer[..len].windows(2).any(|window| window == b"\r\n") {
+            break
+        }
+    }
+
+    let request = String::from_utf8_lossy(&buffer[..len]);
+
+    let path = request.lines()
+        .next()
+        .and_then(|line| line.split(' ').nth(1))
+        .ok_or(anyhow!("Invalid request"))?;
+
+    Ok(path.to_string())
+}
+
+async fn respond(stream: &mut TcpStream, status: &str, body: &str) {
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+
+    if let Err(err) = stream.write_all(response.as_bytes()).await {
+        tracing::debug!("Unable to respond to OAuth redirect request: {:?}", err);
+    }
+}
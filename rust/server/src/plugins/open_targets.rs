@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use anyhow::anyhow;
+
+use gauntlet_common::dirs::Dirs;
+use gauntlet_common::model::{EntrypointId, Icons, PluginId, SearchResult, SearchResultAccessory, SearchResultEntrypointType, SearchResultPreview};
+
+use crate::plugins::file_preview::path_preview;
+
+pub const OPEN_TARGETS_PLUGIN_ID: &str = "builtin://open";
+const OPEN_TARGETS_PLUGIN_NAME: &str = "Open";
+const MAILTO_PREFIX: &str = "mailto:";
+
+enum OpenTarget {
+    Url(String),
+    Folder(PathBuf),
+    File(PathBuf),
+    Email(String),
+}
+
+// shown above other results when the whole query is url, existing local path or email address
+#[derive(Clone)]
+pub struct OpenTargets {
+    dirs: Dirs,
+    // target resolved during last search, keyed by entrypoint id of search result
+    resolved: Arc<Mutex<HashMap<String, String>>>,
+}
+
+impl OpenTargets {
+    pub fn new(dirs: Dirs) -> Self {
+        Self {
+            dirs,
+            resolved: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn is_open_target(plugin_id: &PluginId) -> bool {
+        plugin_id.to_string() == OPEN_TARGETS_PLUGIN_ID
+    }
+
+    pub fn search(&self, text: &str) -> Vec<SearchResult> {
+        let mut resolved = self.resolved.lock().unwrap();
+        resolved.clear();
+
+        let Some(target) = self.parse(text.trim()) else {
+            return vec![];
+        };
+
+        let entrypoint_file = match &target {
+            OpenTarget::File(path) => Some(path.display().to_string()),
+            _ => None,
+        };
+
+        let (entrypoint_id, entrypoint_name, subtitle, icon, open_target) = match target {
+            OpenTarget::Url(url) => ("url", "Open in Browser", url.clone(), Icons::Globe, url),
+            OpenTarget::Folder(path) => ("folder", "Open Folder", path.display().to_string(), Icons::Folder, path.display().to_string()),
+            OpenTarget::File(path) => ("file", "Open File", path.display().to_string(), Icons::Document, path.display().to_string()),
+            OpenTarget::Email(address) => ("email", "Compose Email", address.clone(), Icons::Envelope, format!("{}{}", MAILTO_PREFIX, address)),
+        };
+
+        resolved.insert(entrypoint_id.to_string(), open_target);
+
+        vec![
+            SearchResult {
+                plugin_id: PluginId::from_string(OPEN_TARGETS_PLUGIN_ID),
+                plugin_name: OPEN_TARGETS_PLUGIN_NAME.to_string(),
+                entrypoint_id: EntrypointId::from_string(entrypoint_id),
+                entrypoint_name: entrypoint_name.to_string(),
+                entrypoint_name_highlights: vec![],
+                entrypoint_subtitle: Some(subtitle),
+                entrypoint_matched_keywords: vec![],
+                entrypoint_generator_name: None,
+                entrypoint_icon: None,
+                entrypoint_type: SearchResultEntrypointType::Command,
+                entrypoint_actions: vec![],
+                entrypoint_accessories: vec![
+                    SearchResultAccessory::IconAccessory {
+                        icon,
+                        tooltip: None,
+                    }
+                ],
+                entrypoint_arguments: vec![],
+                entrypoint_file,
+            }
+        ]
+    }
+
+    pub fn open(&self, entrypoint_id: &EntrypointId) -> anyhow::Result<()> {
+        let target = self.resolved.lock()
+            .unwrap()
+            .get(&entrypoint_id.to_string())
+            .cloned()
+            .ok_or_else(|| anyhow!("Unknown open target: {}", entrypoint_id.to_string()))?;
+
+        tracing::info!("Opening search query target: {}", target);
+
+        open::that_detached(target)?;
+
+        Ok(())
+    }
+
+    // only local files and folders have preview
+    pub fn preview(&self, entrypoint_id: &EntrypointId) -> anyhow::Result<Option<SearchResultPreview>> {
+        let entrypoint_id = entrypoint_id.to_string();
+
+        if entrypoint_id != "file" && entrypoint_id != "folder" {
+            return Ok(None);
+        }
+
+        let Some(target) = self.resolved.lock().unwrap().get(&entrypoint_id).cloned() else {
+            return Ok(None);
+        };
+
+        Ok(Some(path_preview(Path::new(&target))?))
+    }
+
+    // email is checked first, because "user@example.com" would also be accepted as url
+    fn parse(&self, text: &str) -> Option<OpenTarget> {
+        if text.is_empty() {
+            return None;
+        }
+
+        if let Some(address) = parse_email(text) {
+            return Some(OpenTarget::Email(address));
+        }
+
+        if let Some(path) = self.parse_path(text) {
+            return if path.is_dir() {
+                Some(OpenTarget::Folder(path))
+            } else {
+                Some(OpenTarget::File(path))
+            };
+        }
+
+        parse_url(text).map(OpenTarget::Url)
+    }
+
+    // only paths which exist are shown, "~" is expanded to home directory
+    fn parse_path(&self, text: &str) -> Option<PathBuf> {
+        let path = match text.strip_prefix('~') {
+            Some(rest) if rest.is_empty() || rest.starts_with(['/', '\\']) => {
+                self.dirs.home_dir().join(rest.trim_start_matches(['/', '\\']))
+            }
+            Some(_) => return None,
+            None => PathBuf::from(text),
+        };
+
+        if !path.is_absolute() || !path.exists() {
+            return None;
+        }
+
+        Some(path)
+    }
+}
+
+// "https://example.com/page" is used as is, "example.com/page" gets https scheme
+pub fn parse_url(text: &str) -> Option<String> {
+    if text.contains(char::is_whitespace) {
+        return None;
+    }
+
+    if let Ok(url) = url::Url::parse(text) {
+        if matches!(url.scheme(), "http" | "https") {
+            return Some(url.to_string());
+        }
+    }
+
+    let url = url::Url::parse(&format!("https://{}", text)).ok()?;
+
+    let host = url.host_str()?;
+
+    // top level domain is required, so that single words are not treated as urls
+    let (_, tld) = host.rsplit_once('.')?;
+
+    if tld.len() < 2 || !tld.chars().all(|char| char.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    Some(url.to_string())
+}
+
+fn parse_email(text: &str) -> Option<String> {
+    let text = text.strip_prefix(MAILTO_PREFIX).unwrap_or(text);
+
+    if text.contains(char::is_whitespace) {
+        return None;
+    }
+
+    let (local, domain) = text.split_once('@')?;
+
+    if local.is_empty() || local.contains(['/', ':']) || domain.contains('@') {
+        return None;
+    }
+
+    let (name, tld) = domain.rsplit_once('.')?;
+
+    if name.is_empty() || tld.len() < 2 || !tld.chars().all(|char| char.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    Some(text.to_string())
+}
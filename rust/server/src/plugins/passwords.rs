@@ -0,0 +1,510 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Context};
+use hmac::{Hmac, Mac};
+use rand::rngs::OsRng;
+use rand::seq::SliceRandom;
+use sha1::Sha1;
+
+use gauntlet_common::model::{EntrypointId, Icons, PluginId, SearchResult, SearchResultAccessory, SearchResultEntrypointAction, SearchResultEntrypointActionType, SearchResultEntrypointType};
+use gauntlet_common::rpc::frontend_api::FrontendApi;
+
+use crate::plugins::clipboard::Clipboard;
+use crate::plugins::input_injection::InputInjection;
+use crate::plugins::secrets::Secrets;
+
+pub const PASSWORDS_PLUGIN_ID: &str = "builtin://passwords";
+const PASSWORDS_PLUGIN_NAME: &str = "Passwords";
+const GENERATE_ENTRYPOINT_ID: &str = "generate";
+const ADD_TOTP_ENTRYPOINT_ID: &str = "totp-add";
+const REMOVE_TOTP_ENTRYPOINT_ID: &str = "totp-remove";
+const COPY_TOTP_ENTRYPOINT_PREFIX: &str = "totp:";
+// keychain can't be enumerated, so names of totp accounts are kept in separate entry
+const TOTP_ACCOUNTS_SECRET_KEY: &str = "totp-accounts";
+const MIN_PASSWORD_LENGTH: usize = 8;
+const MAX_PASSWORD_LENGTH: usize = 128;
+
+const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const DIGITS: &str = "0123456789";
+const SYMBOLS: &str = "!@#$%^&*()-_=+[]{};:,.?/";
+const AMBIGUOUS: &str = "Il1O0o";
+
+#[derive(Debug, Clone)]
+pub struct PasswordPolicy {
+    pub length: usize,
+    pub uppercase: bool,
+    pub digits: bool,
+    pub symbols: bool,
+    pub exclude_ambiguous: bool,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            length: 24,
+            uppercase: true,
+            digits: true,
+            symbols: true,
+            exclude_ambiguous: false,
+        }
+    }
+}
+
+enum PasswordAction {
+    Generate {
+        policy: PasswordPolicy,
+    },
+    AddTotp {
+        name: String,
+        secret: String,
+    },
+    RemoveTotp {
+        name: String,
+    },
+    CopyTotp {
+        name: String,
+    },
+}
+
+// generated passwords and totp codes are copied to clipboard or pasted into active application
+// and removed from clipboard after timeout, totp secrets are kept in os keychain
+#[derive(Clone)]
+pub struct Passwords {
+    secrets: Secrets,
+    clipboard: Clipboard,
+    input_injection: InputInjection,
+    frontend_api: FrontendApi,
+    // loaded on first totp search, so keychain is not accessed unless needed
+    totp_accounts: Arc<Mutex<Option<Vec<String>>>>,
+    // actions resolved during last search, keyed by entrypoint id of search result
+    resolved: Arc<Mutex<HashMap<String, PasswordAction>>>,
+}
+
+impl Passwords {
+    pub fn new(secrets: Secrets, clipboard: Clipboard, input_injection: InputInjection, frontend_api: FrontendApi) -> Self {
+        Self {
+            secrets,
+            clipboard,
+            input_injection,
+            frontend_api,
+            totp_accounts: Arc::new(Mutex::new(None)),
+            resolved: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn is_password(plugin_id: &PluginId) -> bool {
+        plugin_id.to_string() == PASSWORDS_PLUGIN_ID
+    }
+
+    // "password", "generate password 32", "totp github", "totp add github JBSWY3DPEHPK3PXP" and "totp remove github"
+    pub fn search(&self, text: &str, policy: &PasswordPolicy) -> Vec<SearchResult> {
+        let mut resolved = self.resolved.lock().unwrap();
+        resolved.clear();
+
+        let text = text.trim();
+        let lowercase = text.to_lowercase();
+
+        let mut result = vec![];
+
+        if let Some(length) = parse_generate(&lowercase, policy.length) {
+            let policy = PasswordPolicy {
+                length,
+                ..policy.clone()
+            };
+
+            let subtitle = format!("{} characters", length);
+
+            result.push(output_search_result(GENERATE_ENTRYPOINT_ID.to_string(), "Generate Password".to_string(), Some(subtitle), Icons::Key));
+
+            resolved.insert(GENERATE_ENTRYPOINT_ID.to_string(), PasswordAction::Generate { policy });
+
+            return result;
+        }
+
+        let Some(query) = strip_prefix_ignore_case(text, "totp").or_else(|| strip_prefix_ignore_case(text, "otp")) else {
+            return result;
+        };
+
+        if !query.is_empty() && !query.starts_with(char::is_whitespace) {
+            return result;
+        }
+
+        let query = query.trim();
+
+        if let Some(rest) = strip_prefix_ignore_case(query, "add ") {
+            let Some((name, secret)) = rest.trim().rsplit_once(char::is_whitespace) else {
+                return result;
+            };
+
+            let name = name.trim().to_string();
+
+            if parse_totp_secret(secret).is_none() {
+                return result;
+            }
+
+            result.push(password_search_result(ADD_TOTP_ENTRYPOINT_ID.to_string(), format!("Add TOTP Secret for {}", name), None, Icons::Lock));
+
+            resolved.insert(ADD_TOTP_ENTRYPOINT_ID.to_string(), PasswordAction::AddTotp { name, secret: secret.to_string() });
+
+            return result;
+        }
+
+        let accounts = match self.totp_accounts() {
+            Ok(accounts) => accounts,
+            Err(err) => {
+                tracing::warn!("Unable to read totp accounts: {:?}", err);
+                return result;
+            }
+        };
+
+        if let Some(name) = strip_prefix_ignore_case(query, "remove ") {
+            let name = name.trim();
+
+            if let Some(name) = accounts.iter().find(|account| account.eq_ignore_ascii_case(name)) {
+                result.push(password_search_result(REMOVE_TOTP_ENTRYPOINT_ID.to_string(), format!("Remove TOTP Secret for {}", name), None, Icons::Trash));
+
+                resolved.insert(REMOVE_TOTP_ENTRYPOINT_ID.to_string(), PasswordAction::RemoveTotp { name: name.clone() });
+            }
+
+            return result;
+        }
+
+        let query = query.to_lowercase();
+
+        for name in accounts.iter().filter(|account| account.to_lowercase().contains(&query)) {
+            let entrypoint_id = format!("{}{}", COPY_TOTP_ENTRYPOINT_PREFIX, name);
+
+            // code is computed when result is run, so it is not shown on screen
+            result.push(output_search_result(entrypoint_id.clone(), format!("TOTP Code for {}", name), None, Icons::Shield));
+
+            resolved.insert(entrypoint_id, PasswordAction::CopyTotp { name: name.clone() });
+        }
+
+        result
+    }
+
+    // generated password and totp code are pasted into active application instead of copied when paste is true
+    pub async fn run(&self, entrypoint_id: &EntrypointId, paste: bool) -> anyhow::Result<()> {
+        let action = self.resolved.lock()
+            .unwrap()
+            .remove(&entrypoint_id.to_string())
+            .ok_or_else(|| anyhow!("Unknown password action: {}", entrypoint_id.to_string()))?;
+
+        match action {
+            PasswordAction::Generate { policy } => {
+                let password = generate_password(&policy);
+
+                self.output(password, "Password", paste).await?;
+            }
+            PasswordAction::AddTotp { name, secret } => {
+                tracing::info!("Adding totp secret: {}", name);
+
+                self.secrets.set(&plugin_id(), &totp_secret_key(&name), &secret)?;
+
+                let mut accounts = self.totp_accounts()?;
+                accounts.retain(|account| account != &name);
+                accounts.push(name.clone());
+                accounts.sort_by_key(|account| account.to_lowercase());
+
+                self.store_totp_accounts(accounts)?;
+
+                self.frontend_api.show_hud(format!("TOTP secret for {} added", name)).await?;
+            }
+            PasswordAction::RemoveTotp { name } => {
+                tracing::info!("Removing totp secret: {}", name);
+
+                self.secrets.remove(&plugin_id(), &totp_secret_key(&name))?;
+
+                let mut accounts = self.totp_accounts()?;
+                accounts.retain(|account| account != &name);
+
+                self.store_totp_accounts(accounts)?;
+
+                self.frontend_api.show_hud(format!("TOTP secret for {} removed", name)).await?;
+            }
+            PasswordAction::CopyTotp { name } => {
+                let secret = self.secrets.get(&plugin_id(), &totp_secret_key(&name))?
+                    .ok_or_else(|| anyhow!("TOTP secret for {} is not in keychain", name))?;
+
+                let secret = parse_totp_secret(&secret)
+                    .ok_or_else(|| anyhow!("Invalid TOTP secret for {}", name))?;
+
+                let code = totp_code(&secret, now_secs());
+
+                self.output(code, "TOTP code", paste).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn totp_accounts(&self) -> anyhow::Result<Vec<String>> {
+        let mut totp_accounts = self.totp_accounts.lock().unwrap();
+
+        if let Some(accounts) = totp_accounts.as_ref() {
+            return Ok(accounts.clone());
+        }
+
+        let accounts = match self.secrets.get(&plugin_id(), TOTP_ACCOUNTS_SECRET_KEY)? {
+            Some(value) => serde_json::from_str(&value).context("Invalid list of totp accounts")?,
+            None => vec![],
+        };
+
+        *totp_accounts = Some(accounts.clone());
+
+        Ok(accounts)
+    }
+
+    fn store_totp_accounts(&self, accounts: Vec<String>) -> anyhow::Result<()> {
+        self.secrets.set(&plugin_id(), TOTP_ACCOUNTS_SECRET_KEY, &serde_json::to_string(&accounts)?)?;
+
+        *self.totp_accounts.lock().unwrap() = Some(accounts);
+
+        Ok(())
+    }
+
+    async fn output(&self, value: String, what: &str, paste: bool) -> anyhow::Result<()> {
+        if paste {
+            return self.input_injection.paste_text(value, true).await;
+        }
+
+        self.clipboard.write_sensitive_text(value)?;
+
+        let display = match self.clipboard.sensitive_clear_after() {
+            Some(clear_after) => format!("{} copied to clipboard, it will be cleared in {} seconds", what, clear_after.as_secs()),
+            None => format!("{} copied to clipboard", what),
+        };
+
+        self.frontend_api.show_hud(display).await?;
+
+        Ok(())
+    }
+}
+
+// returns password length, query without length uses length from policy
+fn parse_generate(text: &str, default_length: usize) -> Option<usize> {
+    let text = text.strip_prefix("generate ").unwrap_or(text);
+
+    let (word, length) = match text.split_once(char::is_whitespace) {
+        Some((word, length)) => (word, Some(length.trim())),
+        None => (text, None),
+    };
+
+    // "pass" and "passw" also match, but not "pa", so that other results are not shadowed
+    if word.len() < 4 || !"password".starts_with(word) {
+        return None;
+    }
+
+    match length {
+        None => Some(default_length.clamp(MIN_PASSWORD_LENGTH, MAX_PASSWORD_LENGTH)),
+        Some(length) => {
+            let length: usize = length.parse().ok()?;
+
+            (MIN_PASSWORD_LENGTH..=MAX_PASSWORD_LENGTH).contains(&length)
+                .then_some(length)
+        }
+    }
+}
+
+// at least one character of each enabled class is included
+fn generate_password(policy: &PasswordPolicy) -> String {
+    let mut rng = OsRng;
+
+    let filter = |chars: &str| -> Vec<char> {
+        chars.chars()
+            .filter(|char| !policy.exclude_ambiguous || !AMBIGUOUS.contains(*char))
+            .collect()
+    };
+
+    let mut classes = vec![filter(LOWERCASE)];
+
+    if policy.uppercase {
+        classes.push(filter(UPPERCASE));
+    }
+
+    if policy.digits {
+        classes.push(filter(DIGITS));
+    }
+
+    if policy.symbols {
+        classes.push(filter(SYMBOLS));
+    }
+
+    let all: Vec<char> = classes.iter()
+        .flatten()
+        .copied()
+        .collect();
+
+    let mut password: Vec<char> = classes.iter()
+        .filter_map(|class| class.choose(&mut rng).copied())
+        .collect();
+
+    while password.len() < policy.length {
+        password.push(*all.choose(&mut rng).expect("lowercase characters are always included"));
+    }
+
+    password.shuffle(&mut rng);
+
+    password.into_iter().collect()
+}
+
+struct TotpSecret {
+    key: Vec<u8>,
+    digits: u32,
+    period: u64,
+}
+
+// base32 encoded secret or "otpauth://totp/..." uri, only sha1 is supported
+fn parse_totp_secret(text: &str) -> Option<TotpSecret> {
+    if text.starts_with("otpauth://") {
+        let url = url::Url::parse(text).ok()?;
+
+        if url.host_str() != Some("totp") {
+            return None;
+        }
+
+        let params: HashMap<_, _> = url.query_pairs().collect();
+
+        if params.get("algorithm").is_some_and(|algorithm| !algorithm.eq_ignore_ascii_case("sha1")) {
+            return None;
+        }
+
+        let digits = match params.get("digits") {
+            Some(digits) => digits.parse().ok().filter(|digits| (6..=8).contains(digits))?,
+            None => 6,
+        };
+
+        let period = match params.get("period") {
+            Some(period) => period.parse().ok().filter(|period| *period > 0)?,
+            None => 30,
+        };
+
+        let key = decode_base32(params.get("secret")?)?;
+
+        return Some(TotpSecret { key, digits, period });
+    }
+
+    Some(TotpSecret {
+        key: decode_base32(text)?,
+        digits: 6,
+        period: 30,
+    })
+}
+
+fn totp_code(secret: &TotpSecret, now_secs: u64) -> String {
+    let counter = now_secs / secret.period;
+
+    let mut mac = Hmac::<Sha1>::new_from_slice(&secret.key)
+        .expect("hmac accepts key of any length");
+
+    mac.update(&counter.to_be_bytes());
+
+    let hash = mac.finalize().into_bytes();
+
+    let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+
+    let code = u32::from_be_bytes([hash[offset] & 0x7f, hash[offset + 1], hash[offset + 2], hash[offset + 3]]);
+
+    format!("{:0width$}", code % 10u32.pow(secret.digits), width = secret.digits as usize)
+}
+
+// rfc 4648 alphabet, padding and whitespace are ignored
+fn decode_base32(text: &str) -> Option<Vec<u8>> {
+    let mut result = vec![];
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+
+    for char in text.chars().filter(|char| !char.is_whitespace() && *char != '=') {
+        let value = match char.to_ascii_uppercase() {
+            char @ 'A'..='Z' => char as u32 - 'A' as u32,
+            char @ '2'..='7' => char as u32 - '2' as u32 + 26,
+            _ => return None,
+        };
+
+        buffer = (buffer << 5) | value;
+        bits += 5;
+
+        if bits >= 8 {
+            bits -= 8;
+            result.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    if result.is_empty() {
+        return None;
+    }
+
+    Some(result)
+}
+
+fn strip_prefix_ignore_case<'a>(text: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = text.get(..prefix.len())?;
+
+    head.eq_ignore_ascii_case(prefix)
+        .then(|| &text[prefix.len()..])
+}
+
+fn totp_secret_key(name: &str) -> String {
+    format!("totp:{}", name)
+}
+
+fn plugin_id() -> PluginId {
+    PluginId::from_string(PASSWORDS_PLUGIN_ID)
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time is before unix epoch")
+        .as_secs()
+}
+
+// results which produce value have two actions, first one copies it and second one pastes it into active application
+fn output_search_result(entrypoint_id: String, entrypoint_name: String, entrypoint_subtitle: Option<String>, icon: Icons) -> SearchResult {
+    let actions = vec![
+        SearchResultEntrypointAction {
+            action_type: SearchResultEntrypointActionType::Command,
+            label: "Copy to Clipboard".to_string(),
+            shortcut: None,
+        },
+        SearchResultEntrypointAction {
+            action_type: SearchResultEntrypointActionType::Command,
+            label: "Paste to Active Application".to_string(),
+            shortcut: None,
+        },
+    ];
+
+    SearchResult {
+        entrypoint_type: SearchResultEntrypointType::Generated,
+        entrypoint_actions: actions,
+        ..password_search_result(entrypoint_id, entrypoint_name, entrypoint_subtitle, icon)
+    }
+}
+
+fn password_search_result(entrypoint_id: String, entrypoint_name: String, entrypoint_subtitle: Option<String>, icon: Icons) -> SearchResult {
+    SearchResult {
+        plugin_id: PluginId::from_string(PASSWORDS_PLUGIN_ID),
+        plugin_name: PASSWORDS_PLUGIN_NAME.to_string(),
+        entrypoint_id: EntrypointId::from_string(entrypoint_id),
+        entrypoint_name,
+        entrypoint_name_highlights: vec![],
+        entrypoint_subtitle,
+        entrypoint_matched_keywords: vec![],
+        entrypoint_generator_name: None,
+        entrypoint_icon: None,
+        entrypoint_type: SearchResultEntrypointType::Command,
+        entrypoint_actions: vec![],
+        entrypoint_accessories: vec![
+            SearchResultAccessory::IconAccessory {
+                icon,
+                tooltip: None,
+            }
+        ],
+        entrypoint_arguments: vec![],
+        entrypoint_file: None,
+    }
+}
@@ -0,0 +1,129 @@
+use std::sync::{Arc, Mutex};
+
+use anyhow::anyhow;
+
+use gauntlet_common::model::{EntrypointId, PluginId, SearchResult, SearchResultAccessory, SearchResultEntrypointType, SettingsTheme};
+
+use crate::plugins::data_db_repository::{DataDbRepository, DbProfileData, DbTheme};
+
+pub const PROFILES_PLUGIN_ID: &str = "builtin://profiles";
+const PROFILES_PLUGIN_NAME: &str = "Profiles";
+const SWITCH_PROFILE_COMMAND: &str = "switch profile";
+
+#[derive(Clone)]
+pub struct Profiles {
+    repository: DataDbRepository,
+    profiles: Arc<Mutex<Vec<String>>>,
+}
+
+impl Profiles {
+    pub fn new(repository: DataDbRepository) -> Self {
+        Self {
+            repository,
+            profiles: Arc::new(Mutex::new(vec![])),
+        }
+    }
+
+    pub fn is_profile(plugin_id: &PluginId) -> bool {
+        plugin_id.to_string() == PROFILES_PLUGIN_ID
+    }
+
+    pub async fn reload(&self) -> anyhow::Result<()> {
+        let profiles = self.repository.list_profiles()
+            .await?
+            .into_iter()
+            .map(|profile| profile.name)
+            .collect();
+
+        *self.profiles.lock().unwrap() = profiles;
+
+        Ok(())
+    }
+
+    pub fn profiles(&self) -> Vec<String> {
+        self.profiles.lock().unwrap().clone()
+    }
+
+    pub async fn save_profile(&self, name: String, data: DbProfileData) -> anyhow::Result<()> {
+        let name = name.trim().to_string();
+
+        if name.is_empty() {
+            return Err(anyhow!("Profile name cannot be empty"));
+        }
+
+        self.repository.save_profile(&name, data).await?;
+
+        self.reload().await
+    }
+
+    pub async fn remove_profile(&self, name: String) -> anyhow::Result<()> {
+        self.repository.remove_profile(&name).await?;
+
+        self.reload().await
+    }
+
+    pub async fn profile_data(&self, name: &str) -> anyhow::Result<DbProfileData> {
+        let profile = self.repository.get_profile(name)
+            .await?
+            .ok_or_else(|| anyhow!("Unknown profile: {}", name))?;
+
+        Ok(profile.data)
+    }
+
+    // "switch profile work", "work" -> "Switch to Work Profile"
+    pub fn search(&self, text: &str) -> Vec<SearchResult> {
+        let text = text.trim().to_lowercase();
+
+        if text.is_empty() {
+            return vec![];
+        }
+
+        let query = match text.strip_prefix(SWITCH_PROFILE_COMMAND) {
+            Some(rest) => rest.trim().to_string(),
+            None if SWITCH_PROFILE_COMMAND.starts_with(&text) => String::new(),
+            None => text,
+        };
+
+        self.profiles.lock()
+            .unwrap()
+            .iter()
+            .filter(|name| name.to_lowercase().contains(&query))
+            .map(|name| profile_search_result(name))
+            .collect()
+    }
+}
+
+pub fn theme_from_db(theme: Option<DbTheme>) -> SettingsTheme {
+    match theme {
+        None => SettingsTheme::AutoDetect,
+        Some(DbTheme::MacOSLight) => SettingsTheme::MacOSLight,
+        Some(DbTheme::MacOSDark) => SettingsTheme::MacOSDark,
+        Some(DbTheme::Legacy) => SettingsTheme::Legacy,
+        Some(DbTheme::HighContrast) => SettingsTheme::HighContrast,
+    }
+}
+
+fn profile_search_result(name: &str) -> SearchResult {
+    SearchResult {
+        plugin_id: PluginId::from_string(PROFILES_PLUGIN_ID),
+        plugin_name: PROFILES_PLUGIN_NAME.to_string(),
+        entrypoint_id: EntrypointId::from_string(name),
+        entrypoint_name: format!("Switch to {} Profile", name),
+        entrypoint_name_highlights: vec![],
+        entrypoint_subtitle: None,
+        entrypoint_matched_keywords: vec![],
+        entrypoint_generator_name: None,
+        entrypoint_icon: None,
+        entrypoint_type: SearchResultEntrypointType::Command,
+        entrypoint_actions: vec![],
+        entrypoint_accessories: vec![
+            SearchResultAccessory::TextAccessory {
+                text: "Switch Profile".to_string(),
+                icon: None,
+                tooltip: None,
+            }
+        ],
+        entrypoint_arguments: vec![],
+        entrypoint_file: None,
+    }
+}
@@ -0,0 +1,420 @@
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::anyhow;
+use uuid::Uuid;
+
+use gauntlet_common::model::{EntrypointId, Icons, PluginId, SearchResult, SearchResultAccessory, SearchResultEntrypointType, SettingsQuicklink};
+
+use crate::plugins::data_db_repository::{DataDbRepository, DbQuicklink};
+use crate::search::matches_query;
+
+pub const QUICKLINKS_PLUGIN_ID: &str = "builtin://quicklinks";
+const QUICKLINKS_PLUGIN_NAME: &str = "Quicklinks";
+const QUERY_PLACEHOLDER: &str = "{query}";
+const BANG_PREFIX: char = '!';
+const PROVIDER_ICON_SIZE: u32 = 32;
+const PROVIDER_ICON_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Clone)]
+pub struct Quicklinks {
+    repository: DataDbRepository,
+    quicklinks: Arc<Mutex<Vec<SettingsQuicklink>>>,
+    // urls resolved during last search, keyed by quicklink id
+    resolved_urls: Arc<Mutex<HashMap<String, String>>>,
+    // favicons of search providers, keyed by url origin. None while icon is being downloaded
+    provider_icons: Arc<Mutex<HashMap<String, Option<bytes::Bytes>>>>,
+}
+
+impl Quicklinks {
+    pub fn new(repository: DataDbRepository) -> Self {
+        Self {
+            repository,
+            quicklinks: Arc::new(Mutex::new(vec![])),
+            resolved_urls: Arc::new(Mutex::new(HashMap::new())),
+            provider_icons: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn is_quicklink(plugin_id: &PluginId) -> bool {
+        plugin_id.to_string() == QUICKLINKS_PLUGIN_ID
+    }
+
+    pub async fn reload(&self) -> anyhow::Result<()> {
+        let quicklinks = self.repository.list_quicklinks()
+            .await?
+            .into_iter()
+            .map(|quicklink| SettingsQuicklink {
+                id: quicklink.id,
+                name: quicklink.name,
+                url: quicklink.url,
+                bang: quicklink.bang,
+                fallback: quicklink.fallback,
+            })
+            .collect();
+
+        self.load_provider_icons(&quicklinks);
+
+        *self.quicklinks.lock().unwrap() = quicklinks;
+
+        Ok(())
+    }
+
+    // icons are downloaded in background, until they are available results are shown without them
+    fn load_provider_icons(&self, quicklinks: &[SettingsQuicklink]) {
+        let mut provider_icons = self.provider_icons.lock().unwrap();
+
+        for quicklink in quicklinks {
+            let Some(origin) = url_origin(&quicklink.url) else {
+                continue;
+            };
+
+            if provider_icons.contains_key(&origin) {
+                continue;
+            }
+
+            provider_icons.insert(origin.clone(), None);
+
+            let provider_icons = self.provider_icons.clone();
+
+            tokio::spawn(async move {
+                let icon_origin = origin.clone();
+
+                let icon = tokio::task::spawn_blocking(move || download_provider_icon(&icon_origin))
+                    .await
+                    .map_err(anyhow::Error::from)
+                    .and_then(|icon| icon);
+
+                match icon {
+                    Ok(icon) => {
+                        provider_icons.lock().unwrap().insert(origin, Some(icon));
+                    }
+                    Err(err) => {
+                        tracing::debug!("Unable to download icon of {}: {:?}", origin, err);
+
+                        // download is retried next time quicklinks are loaded
+                        provider_icons.lock().unwrap().remove(&origin);
+                    }
+                }
+            });
+        }
+    }
+
+    fn provider_icon(&self, quicklink: &SettingsQuicklink) -> Option<bytes::Bytes> {
+        let origin = url_origin(&quicklink.url)?;
+
+        self.provider_icons.lock()
+            .unwrap()
+            .get(&origin)
+            .cloned()
+            .flatten()
+    }
+
+    pub fn quicklinks(&self) -> Vec<SettingsQuicklink> {
+        self.quicklinks.lock().unwrap().clone()
+    }
+
+    pub async fn save_quicklink(&self, id: Option<String>, name: String, url: String, bang: Option<String>, fallback: bool) -> anyhow::Result<()> {
+        let name = name.trim().to_string();
+        let url = url.trim().to_string();
+        let bang = bang
+            .map(|bang| bang.trim().trim_start_matches(BANG_PREFIX).to_string())
+            .filter(|bang| !bang.is_empty());
+
+        if name.is_empty() {
+            return Err(anyhow!("Quicklink name cannot be empty"));
+        }
+
+        if url.is_empty() {
+            return Err(anyhow!("Quicklink url cannot be empty"));
+        }
+
+        if let Some(bang) = &bang {
+            if bang.contains(char::is_whitespace) {
+                return Err(anyhow!("Quicklink bang cannot contain whitespace"));
+            }
+        }
+
+        let id = id.unwrap_or_else(|| Uuid::new_v4().to_string());
+
+        self.repository.save_quicklink(DbQuicklink { id, name, url, bang, fallback }).await?;
+
+        self.reload().await
+    }
+
+    pub async fn remove_quicklink(&self, id: String) -> anyhow::Result<()> {
+        self.repository.remove_quicklink(&id).await?;
+
+        self.reload().await
+    }
+
+    pub fn search(&self, text: &str) -> Vec<SearchResult> {
+        let text = text.trim();
+
+        let mut resolved_urls = self.resolved_urls.lock().unwrap();
+        resolved_urls.clear();
+
+        if text.is_empty() {
+            return vec![];
+        }
+
+        let quicklinks = self.quicklinks.lock().unwrap();
+
+        // "!g rust" -> only quicklinks with matching bang
+        if let Some((bang, query)) = parse_bang(text) {
+            let results: Vec<_> = quicklinks.iter()
+                .filter(|quicklink| quicklink.bang.as_ref().is_some_and(|quicklink_bang| quicklink_bang.eq_ignore_ascii_case(bang)))
+                .map(|quicklink| web_search_result(&mut resolved_urls, quicklink, self.provider_icon(quicklink), query))
+                .collect();
+
+            if !results.is_empty() {
+                return results;
+            }
+        }
+
+        quicklinks.iter()
+            .filter_map(|quicklink| {
+                let query = match_quicklink(&quicklink.name, text)?;
+
+                resolved_urls.insert(quicklink.id.clone(), resolve_url(&quicklink.url, query));
+
+                let entrypoint_accessories = if query.is_empty() {
+                    vec![]
+                } else {
+                    vec![
+                        SearchResultAccessory::TextAccessory {
+                            text: query.to_string(),
+                            icon: None,
+                            tooltip: None,
+                        }
+                    ]
+                };
+
+                Some(quicklink_search_result(quicklink, quicklink.name.clone(), self.provider_icon(quicklink), entrypoint_accessories))
+            })
+            .collect()
+    }
+
+    // used when nothing else matched the query
+    pub fn search_fallback(&self, text: &str) -> Vec<SearchResult> {
+        let text = text.trim();
+
+        if text.is_empty() {
+            return vec![];
+        }
+
+        let mut resolved_urls = self.resolved_urls.lock().unwrap();
+
+        self.quicklinks.lock()
+            .unwrap()
+            .iter()
+            .filter(|quicklink| quicklink.fallback)
+            .map(|quicklink| web_search_result(&mut resolved_urls, quicklink, self.provider_icon(quicklink), text))
+            .collect()
+    }
+
+    pub fn open(&self, entrypoint_id: &EntrypointId) -> anyhow::Result<()> {
+        let url = self.resolved_urls.lock()
+            .unwrap()
+            .get(&entrypoint_id.to_string())
+            .cloned()
+            .ok_or_else(|| anyhow!("Unknown quicklink: {}", entrypoint_id.to_string()))?;
+
+        open_url(&url)
+    }
+
+    // used by deep links, which don't go through search
+    pub fn open_with_query(&self, entrypoint_id: &EntrypointId, query: &str) -> anyhow::Result<()> {
+        let url = self.quicklinks.lock()
+            .unwrap()
+            .iter()
+            .find(|quicklink| quicklink.id == entrypoint_id.to_string())
+            .map(|quicklink| resolve_url(&quicklink.url, query))
+            .ok_or_else(|| anyhow!("Unknown quicklink: {}", entrypoint_id.to_string()))?;
+
+        open_url(&url)
+    }
+}
+
+fn open_url(url: &str) -> anyhow::Result<()> {
+    tracing::info!("Opening quicklink url: {}", url);
+
+    open::that_detached(url)?;
+
+    Ok(())
+}
+
+// returns the part of the search query which should be substituted into url
+fn match_quicklink<'a>(name: &str, text: &'a str) -> Option<&'a str> {
+    let name = name.to_lowercase();
+
+    // "search github rust" -> "rust"
+    if let (Some(prefix), Some(rest)) = (text.get(..name.len()), text.get(name.len()..)) {
+        if prefix.to_lowercase() == name && (rest.is_empty() || rest.starts_with(char::is_whitespace)) {
+            return Some(rest.trim());
+        }
+    }
+
+    // "github rust" -> "rust", first word is matched against the name the same way as in search
+    let (head, rest) = match text.split_once(char::is_whitespace) {
+        None => (text, ""),
+        Some((head, rest)) => (head, rest.trim()),
+    };
+
+    if matches_query(&name, head) {
+        Some(rest)
+    } else {
+        None
+    }
+}
+
+fn parse_bang(text: &str) -> Option<(&str, &str)> {
+    let text = text.strip_prefix(BANG_PREFIX)?;
+
+    match text.split_once(char::is_whitespace) {
+        None => Some((text, "")),
+        Some((bang, rest)) => Some((bang, rest.trim())),
+    }
+}
+
+fn web_search_result(resolved_urls: &mut HashMap<String, String>, quicklink: &SettingsQuicklink, icon: Option<bytes::Bytes>, query: &str) -> SearchResult {
+    resolved_urls.insert(quicklink.id.clone(), resolve_url(&quicklink.url, query));
+
+    let name = if query.is_empty() {
+        format!("Search {}", quicklink.name)
+    } else {
+        format!("Search {} for \"{}\"", quicklink.name, query)
+    };
+
+    let mut entrypoint_accessories = vec![];
+
+    if let Some(bang) = &quicklink.bang {
+        entrypoint_accessories.push(SearchResultAccessory::TextAccessory {
+            text: format!("{}{}", BANG_PREFIX, bang),
+            icon: None,
+            tooltip: None,
+        });
+    }
+
+    // provider icon is shown instead when it is available
+    if icon.is_none() {
+        entrypoint_accessories.push(SearchResultAccessory::IconAccessory {
+            icon: Icons::Globe,
+            tooltip: Some("Search the web".to_string()),
+        });
+    }
+
+    quicklink_search_result(quicklink, name, icon, entrypoint_accessories)
+}
+
+fn quicklink_search_result(quicklink: &SettingsQuicklink, entrypoint_name: String, entrypoint_icon: Option<bytes::Bytes>, entrypoint_accessories: Vec<SearchResultAccessory>) -> SearchResult {
+    SearchResult {
+        plugin_id: PluginId::from_string(QUICKLINKS_PLUGIN_ID),
+        plugin_name: QUICKLINKS_PLUGIN_NAME.to_string(),
+        entrypoint_id: EntrypointId::from_string(&quicklink.id),
+        entrypoint_name,
+        entrypoint_name_highlights: vec![],
+        entrypoint_subtitle: None,
+        entrypoint_matched_keywords: vec![],
+        entrypoint_generator_name: None,
+        entrypoint_icon,
+        entrypoint_type: SearchResultEntrypointType::Command,
+        entrypoint_actions: vec![],
+        entrypoint_accessories,
+        entrypoint_arguments: vec![],
+        entrypoint_file: None,
+    }
+}
+
+// "https://www.google.com/search?q={query}" -> "https://www.google.com"
+fn url_origin(url: &str) -> Option<String> {
+    let url = url::Url::parse(&url.replace(QUERY_PLACEHOLDER, "")).ok()?;
+
+    match url.scheme() {
+        "http" | "https" => Some(url.origin().ascii_serialization()),
+        _ => None,
+    }
+}
+
+// favicons are usually ico files, they are converted to png which is supported by the client
+fn download_provider_icon(origin: &str) -> anyhow::Result<bytes::Bytes> {
+    let mut data = vec![];
+
+    // unresponsive provider shouldn't keep blocking thread forever
+    let agent = ureq::AgentBuilder::new()
+        .timeout(PROVIDER_ICON_TIMEOUT)
+        .build();
+
+    agent.get(&format!("{}/favicon.ico", origin))
+        .call()?
+        .into_reader()
+        .take(1024 * 1024)
+        .read_to_end(&mut data)?;
+
+    let icon = image::load_from_memory(&data)?
+        .resize(PROVIDER_ICON_SIZE, PROVIDER_ICON_SIZE, image::imageops::FilterType::Lanczos3);
+
+    let mut result = Cursor::new(vec![]);
+
+    icon.write_to(&mut result, image::ImageFormat::Png)?;
+
+    Ok(result.into_inner().into())
+}
+
+fn resolve_url(url: &str, query: &str) -> String {
+    let query: String = url::form_urlencoded::byte_serialize(query.as_bytes()).collect();
+
+    url.replace(QUERY_PLACEHOLDER, &query)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_name_prefix_is_removed_from_query() {
+        assert_eq!(match_quicklink("Search GitHub", "search github rust"), Some("rust"));
+        assert_eq!(match_quicklink("Search GitHub", "Search GitHub"), Some(""));
+    }
+
+    #[test]
+    fn first_word_is_matched_against_name() {
+        assert_eq!(match_quicklink("GitHub", "git rust lang"), Some("rust lang"));
+        assert_eq!(match_quicklink("GitHub", "hub rust"), Some("rust"));
+        assert_eq!(match_quicklink("Search GitHub", "github"), Some(""));
+    }
+
+    #[test]
+    fn diacritics_and_case_are_ignored() {
+        assert_eq!(match_quicklink("Café Menu", "CAFE pasta"), Some("pasta"));
+    }
+
+    #[test]
+    fn unrelated_query_does_not_match() {
+        assert_eq!(match_quicklink("GitHub", "gitlab rust"), None);
+        assert_eq!(match_quicklink("GitHub", "gh rust"), None);
+        // punctuation alone is not a search term
+        assert_eq!(match_quicklink("GitHub", "- rust"), None);
+    }
+
+    #[test]
+    fn bang_is_parsed() {
+        assert_eq!(parse_bang("!gh rust lang"), Some(("gh", "rust lang")));
+        assert_eq!(parse_bang("!gh"), Some(("gh", "")));
+        assert_eq!(parse_bang("gh rust"), None);
+    }
+
+    #[test]
+    fn provider_icon_is_looked_up_by_origin() {
+        assert_eq!(url_origin("https://www.google.com/search?q={query}"), Some("https://www.google.com".to_string()));
+        assert_eq!(url_origin("{query}"), None);
+        assert_eq!(url_origin("file:///home/user/{query}"), None);
+    }
+
+    #[test]
+    fn query_is_encoded_into_url() {
+        assert_eq!(resolve_url("https://github.com/search?q={query}", "rust & go"), "https://github.com/search?q=rust+%26+go");
+    }
+}
@@ -0,0 +1,192 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{anyhow, Context};
+use once_cell::sync::Lazy;
+use syntect::easy::HighlightLines;
+use syntect::highlighting::ThemeSet;
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
+
+use gauntlet_common::model::{FileQuicklook, FileQuicklookSpan};
+
+use crate::plugins::file_preview::text_content;
+
+const IMAGE_SIZE_LIMIT: u64 = 20 * 1024 * 1024;
+const PDF_SIZE_LIMIT: u64 = 50 * 1024 * 1024;
+const TEXT_READ_LIMIT: u64 = 256 * 1024;
+const TEXT_LINE_LIMIT: usize = 2000;
+const PDF_PAGE_SIZE: u32 = 1024;
+const PDF_RENDER_TIMEOUT: Duration = Duration::from_secs(10);
+
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "gif", "bmp", "webp", "ico", "tif", "tiff"];
+
+const DARK_THEME: &str = "base16-ocean.dark";
+const LIGHT_THEME: &str = "InspiredGitHub";
+
+static SYNTAX_SET: Lazy<SyntaxSet> = Lazy::new(SyntaxSet::load_defaults_newlines);
+static THEME_SET: Lazy<ThemeSet> = Lazy::new(ThemeSet::load_defaults);
+
+// images are sent as is and decoded by frontend, pdf is rendered to image by external tool,
+// everything else is shown as syntax highlighted text if it is not binary
+pub async fn file_quicklook(path: String, dark: bool) -> anyhow::Result<FileQuicklook> {
+    let metadata = tokio::fs::metadata(&path)
+        .await
+        .with_context(|| format!("Unable to read metadata of file: {}", path))?;
+
+    if metadata.is_dir() {
+        return Ok(FileQuicklook::Unsupported { path });
+    }
+
+    let extension = Path::new(&path)
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase());
+
+    match extension.as_deref() {
+        Some(extension) if IMAGE_EXTENSIONS.contains(&extension) => {
+            if metadata.len() > IMAGE_SIZE_LIMIT {
+                return Ok(FileQuicklook::TooLarge { path, size: metadata.len() });
+            }
+
+            let data = tokio::fs::read(&path).await?;
+
+            Ok(FileQuicklook::Image { path, data })
+        }
+        Some("pdf") => {
+            if metadata.len() > PDF_SIZE_LIMIT {
+                return Ok(FileQuicklook::TooLarge { path, size: metadata.len() });
+            }
+
+            match render_pdf_page(&path).await {
+                Ok(data) => Ok(FileQuicklook::Image { path, data }),
+                Err(err) => {
+                    tracing::warn!("Unable to render first page of pdf {}: {:?}", path, err);
+
+                    Ok(FileQuicklook::Unsupported { path })
+                }
+            }
+        }
+        _ => {
+            let size = metadata.len();
+
+            tokio::task::spawn_blocking(move || text_quicklook(path, size, dark)).await?
+        }
+    }
+}
+
+// spans which use default foreground color of the theme are left without color, so that they follow launcher theme
+fn text_quicklook(path: String, size: u64, dark: bool) -> anyhow::Result<FileQuicklook> {
+    let mut bytes = vec![];
+
+    std::fs::File::open(&path)?
+        .take(TEXT_READ_LIMIT)
+        .read_to_end(&mut bytes)?;
+
+    let Some(content) = text_content(bytes) else {
+        return Ok(FileQuicklook::Unsupported { path });
+    };
+
+    let syntax = Path::new(&path)
+        .extension()
+        .and_then(|extension| SYNTAX_SET.find_syntax_by_extension(&extension.to_string_lossy()))
+        .or_else(|| SYNTAX_SET.find_syntax_by_first_line(&content))
+        .unwrap_or_else(|| SYNTAX_SET.find_syntax_plain_text());
+
+    let theme = &THEME_SET.themes[if dark { DARK_THEME } else { LIGHT_THEME }];
+
+    let default_foreground = theme.settings.foreground;
+
+    let mut highlighter = HighlightLines::new(syntax, theme);
+
+    let mut lines = vec![];
+    let mut truncated = size > TEXT_READ_LIMIT;
+
+    for line in LinesWithEndings::from(&content) {
+        if lines.len() >= TEXT_LINE_LIMIT {
+            truncated = true;
+            break;
+        }
+
+        let spans = highlighter.highlight_line(line, &SYNTAX_SET)?
+            .into_iter()
+            .map(|(style, text)| {
+                let color = if Some(style.foreground) == default_foreground {
+                    None
+                } else {
+                    Some((style.foreground.r, style.foreground.g, style.foreground.b))
+                };
+
+                FileQuicklookSpan {
+                    text: text.trim_end_matches(['\n', '\r']).to_string(),
+                    color,
+                }
+            })
+            .filter(|span| !span.text.is_empty())
+            .collect();
+
+        lines.push(spans);
+    }
+
+    Ok(FileQuicklook::Text { path, lines, truncated })
+}
+
+// pdftoppm from poppler is used on linux and windows, quick look thumbnail generator on macos
+async fn render_pdf_page(path: &str) -> anyhow::Result<Vec<u8>> {
+    let output_dir = tempfile::tempdir()?;
+
+    #[cfg(target_os = "macos")]
+    let mut command = {
+        let mut command = tokio::process::Command::new("qlmanage");
+        command.arg("-t")
+            .arg("-s")
+            .arg(PDF_PAGE_SIZE.to_string())
+            .arg("-o")
+            .arg(output_dir.path())
+            .arg(path);
+        command
+    };
+
+    #[cfg(not(target_os = "macos"))]
+    let mut command = {
+        let mut command = tokio::process::Command::new("pdftoppm");
+        command.arg("-png")
+            .arg("-f")
+            .arg("1")
+            .arg("-l")
+            .arg("1")
+            .arg("-scale-to")
+            .arg(PDF_PAGE_SIZE.to_string())
+            .arg("-singlefile")
+            .arg(path)
+            .arg(output_dir.path().join("page"));
+        command
+    };
+
+    let output = tokio::time::timeout(PDF_RENDER_TIMEOUT, command.kill_on_drop(true).output())
+        .await
+        .context("Rendering of pdf page timed out")?
+        .context("Unable to start pdf renderer")?;
+
+    if !output.status.success() {
+        return Err(anyhow!("Pdf renderer exited with {}: {}", output.status, String::from_utf8_lossy(&output.stderr)));
+    }
+
+    let image_path = find_png(output_dir.path())?
+        .ok_or_else(|| anyhow!("Pdf renderer did not produce an image"))?;
+
+    Ok(tokio::fs::read(image_path).await?)
+}
+
+// qlmanage names output file after input file, so the only png in directory is used
+fn find_png(dir: &Path) -> anyhow::Result<Option<PathBuf>> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        if path.extension().is_some_and(|extension| extension == "png") {
+            return Ok(Some(path));
+        }
+    }
+
+    Ok(None)
+}
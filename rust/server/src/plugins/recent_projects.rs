@@ -0,0 +1,331 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Context};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Deserialize;
+use sqlx::{ConnectOptions, Connection};
+use sqlx::sqlite::SqliteConnectOptions;
+
+use gauntlet_common::dirs::Dirs;
+use gauntlet_common::model::{EntrypointId, Icons, PluginId, SearchResult, SearchResultAccessory, SearchResultEntrypointAction, SearchResultEntrypointActionType, SearchResultEntrypointType, SearchResultPreview};
+
+use crate::plugins::command_template::{run_command_template, TemplateValue};
+use crate::plugins::file_preview::path_preview;
+use crate::plugins::terminal::Terminal;
+
+pub const RECENT_PROJECTS_PLUGIN_ID: &str = "builtin://projects";
+const RECENT_PROJECTS_PLUGIN_NAME: &str = "Recent Projects";
+const PROJECT_ENTRYPOINT_PREFIX: &str = "project:";
+const RELOAD_INTERVAL: Duration = Duration::from_secs(30);
+const MAX_RESULTS: usize = 10;
+const VSCODE_RECENT_KEY: &str = "history.recentlyOpenedPathsList";
+
+pub const DEFAULT_EDITOR_COMMAND: &str = "{launcher} {path}";
+
+// directory name in os config directory, editor name and command line launcher
+const VSCODE_EDITORS: &[(&str, &str, &str)] = &[
+    ("Code", "VS Code", "code"),
+    ("Code - Insiders", "VS Code Insiders", "code-insiders"),
+    ("VSCodium", "VSCodium", "codium"),
+];
+
+// prefix of directory name in jetbrains config directory, product name and command line launcher
+const JETBRAINS_EDITORS: &[(&str, &str, &str)] = &[
+    ("IntelliJIdea", "IntelliJ IDEA", "idea"),
+    ("IdeaIC", "IntelliJ IDEA", "idea"),
+    ("PyCharm", "PyCharm", "pycharm"),
+    ("WebStorm", "WebStorm", "webstorm"),
+    ("CLion", "CLion", "clion"),
+    ("GoLand", "GoLand", "goland"),
+    ("RustRover", "RustRover", "rustrover"),
+    ("PhpStorm", "PhpStorm", "phpstorm"),
+    ("Rider", "Rider", "rider"),
+    ("RubyMine", "RubyMine", "rubymine"),
+    ("DataGrip", "DataGrip", "datagrip"),
+];
+
+static JETBRAINS_ENTRY: Lazy<Regex> = Lazy::new(|| Regex::new(r#"<entry key="([^"]+)">"#).unwrap());
+
+#[derive(Debug, Clone)]
+struct RecentProject {
+    path: PathBuf,
+    editor: &'static str,
+    launcher: &'static str,
+}
+
+#[derive(Deserialize)]
+struct VsCodeRecent {
+    entries: Vec<VsCodeRecentEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct VsCodeRecentEntry {
+    folder_uri: Option<String>,
+    workspace: Option<VsCodeWorkspace>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct VsCodeWorkspace {
+    config_path: String,
+}
+
+// folders and workspaces recently opened in vs code and jetbrains ides, only projects which still exist are shown
+#[derive(Clone)]
+pub struct RecentProjects {
+    dirs: Dirs,
+    terminal: Terminal,
+    projects: Arc<Mutex<Option<(Instant, Vec<RecentProject>)>>>,
+    // projects shown during last search, keyed by entrypoint id of search result
+    resolved: Arc<Mutex<HashMap<String, RecentProject>>>,
+}
+
+impl RecentProjects {
+    pub fn new(dirs: Dirs, terminal: Terminal) -> Self {
+        Self {
+            dirs,
+            terminal,
+            projects: Arc::new(Mutex::new(None)),
+            resolved: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn is_recent_project(plugin_id: &PluginId) -> bool {
+        plugin_id.to_string() == RECENT_PROJECTS_PLUGIN_ID
+    }
+
+    // matched by directory name
+    pub async fn search(&self, text: &str) -> Vec<SearchResult> {
+        let text = text.trim().to_lowercase();
+
+        if text.len() < 2 {
+            self.resolved.lock().unwrap().clear();
+            return vec![];
+        }
+
+        let projects = self.projects().await;
+
+        let mut resolved = self.resolved.lock().unwrap();
+        resolved.clear();
+
+        let mut result = vec![];
+
+        for project in projects {
+            let Some(name) = project.path.file_name().map(|name| name.to_string_lossy().to_string()) else {
+                continue;
+            };
+
+            if !name.to_lowercase().contains(&text) {
+                continue;
+            }
+
+            let entrypoint_id = format!("{}{}", PROJECT_ENTRYPOINT_PREFIX, project.path.display());
+
+            let subtitle = match project.path.strip_prefix(self.dirs.home_dir()) {
+                Ok(relative) => format!("~/{}", relative.display()),
+                Err(_) => project.path.display().to_string(),
+            };
+
+            result.push(SearchResult {
+                plugin_id: PluginId::from_string(RECENT_PROJECTS_PLUGIN_ID),
+                plugin_name: RECENT_PROJECTS_PLUGIN_NAME.to_string(),
+                entrypoint_id: EntrypointId::from_string(&entrypoint_id),
+                entrypoint_name: name,
+                entrypoint_name_highlights: vec![],
+                entrypoint_subtitle: Some(subtitle),
+                entrypoint_matched_keywords: vec![],
+                entrypoint_generator_name: None,
+                entrypoint_icon: None,
+                entrypoint_type: SearchResultEntrypointType::Generated,
+                entrypoint_actions: vec![
+                    SearchResultEntrypointAction {
+                        action_type: SearchResultEntrypointActionType::Command,
+                        label: format!("Open in {}", project.editor),
+                        shortcut: None,
+                    },
+                    SearchResultEntrypointAction {
+                        action_type: SearchResultEntrypointActionType::Command,
+                        label: "Open in Terminal".to_string(),
+                        shortcut: None,
+                    },
+                ],
+                entrypoint_accessories: vec![
+                    SearchResultAccessory::TextAccessory {
+                        text: project.editor.to_string(),
+                        icon: Some(Icons::Code),
+                        tooltip: None,
+                    }
+                ],
+                entrypoint_arguments: vec![],
+                entrypoint_file: None,
+            });
+
+            resolved.insert(entrypoint_id, project);
+
+            if result.len() >= MAX_RESULTS {
+                break;
+            }
+        }
+
+        result
+    }
+
+    // first action opens project in editor, second one opens terminal in project directory
+    pub fn open(&self, entrypoint_id: &EntrypointId, action_index: usize, editor_command: &str) -> anyhow::Result<()> {
+        let project = self.resolved.lock()
+            .unwrap()
+            .get(&entrypoint_id.to_string())
+            .cloned()
+            .ok_or_else(|| anyhow!("Unknown recent project: {}", entrypoint_id.to_string()))?;
+
+        let path = project.path.display().to_string();
+
+        let placeholders = [("path", TemplateValue::Text(&path)), ("launcher", TemplateValue::Text(project.launcher))];
+
+        // vs code workspaces are files
+        let cwd = if project.path.is_dir() {
+            project.path.as_path()
+        } else {
+            project.path.parent().unwrap_or(&project.path)
+        };
+
+        match action_index {
+            0 => {
+                tracing::info!("Opening recent project in editor: {}", path);
+
+                run_command_template(editor_command, &placeholders, Some(cwd))
+            }
+            1 => {
+                tracing::info!("Opening recent project in terminal: {}", path);
+
+                self.terminal.run(vec![], Some(cwd.to_path_buf()))
+            }
+            _ => Err(anyhow!("Unknown recent project action: {}", action_index))
+        }
+    }
+
+    pub fn preview(&self, entrypoint_id: &EntrypointId) -> anyhow::Result<Option<SearchResultPreview>> {
+        let Some(project) = self.resolved.lock().unwrap().get(&entrypoint_id.to_string()).cloned() else {
+            return Ok(None);
+        };
+
+        Ok(Some(path_preview(&project.path)?))
+    }
+
+    async fn projects(&self) -> Vec<RecentProject> {
+        if let Some((loaded_at, projects)) = self.projects.lock().unwrap().as_ref() {
+            if loaded_at.elapsed() < RELOAD_INTERVAL {
+                return projects.clone();
+            }
+        }
+
+        let config_dir = self.dirs.system_config_dir();
+
+        let mut projects = vec![];
+
+        for &(dir_name, editor, launcher) in VSCODE_EDITORS {
+            match read_vscode_projects(&config_dir.join(dir_name), editor, launcher).await {
+                Ok(editor_projects) => projects.extend(editor_projects),
+                Err(err) => tracing::debug!("Unable to read recent projects of {}: {:?}", editor, err),
+            }
+        }
+
+        match read_jetbrains_projects(&config_dir.join("JetBrains"), &self.dirs.home_dir()) {
+            Ok(editor_projects) => projects.extend(editor_projects),
+            Err(err) => tracing::debug!("Unable to read recent projects of jetbrains ides: {:?}", err),
+        }
+
+        let mut seen = HashSet::new();
+
+        let projects: Vec<_> = projects.into_iter()
+            .filter(|project| project.path.exists())
+            .filter(|project| seen.insert(project.path.clone()))
+            .collect();
+
+        *self.projects.lock().unwrap() = Some((Instant::now(), projects.clone()));
+
+        projects
+    }
+}
+
+// newer versions keep recent list in sqlite database, older ones in json file
+async fn read_vscode_projects(dir: &Path, editor: &'static str, launcher: &'static str) -> anyhow::Result<Vec<RecentProject>> {
+    let global_storage = dir.join("User").join("globalStorage");
+
+    let state_db = global_storage.join("state.vscdb");
+
+    let recent: VsCodeRecent = if state_db.exists() {
+        let mut connection = SqliteConnectOptions::new()
+            .filename(&state_db)
+            .read_only(true)
+            .connect()
+            .await
+            .context("Unable to open vs code state database")?;
+
+        let value: Option<String> = sqlx::query_scalar("SELECT CAST(value AS TEXT) FROM ItemTable WHERE key = ?")
+            .bind(VSCODE_RECENT_KEY)
+            .fetch_optional(&mut connection)
+            .await?;
+
+        connection.close().await?;
+
+        match value {
+            Some(value) => serde_json::from_str(&value)?,
+            None => return Ok(vec![]),
+        }
+    } else {
+        let storage = std::fs::read_to_string(global_storage.join("storage.json"))?;
+
+        let mut storage: serde_json::Value = serde_json::from_str(&storage)?;
+
+        match storage.get_mut("openedPathsList") {
+            Some(value) => serde_json::from_value(value.take())?,
+            None => return Ok(vec![]),
+        }
+    };
+
+    let projects = recent.entries
+        .into_iter()
+        .filter_map(|entry| entry.folder_uri.or(entry.workspace.map(|workspace| workspace.config_path)))
+        .filter_map(|uri| url::Url::parse(&uri).ok()?.to_file_path().ok())
+        .map(|path| RecentProject { path, editor, launcher })
+        .collect();
+
+    Ok(projects)
+}
+
+// every installed version has its own config directory, e.g. "PyCharm2024.1"
+fn read_jetbrains_projects(dir: &Path, home_dir: &Path) -> anyhow::Result<Vec<RecentProject>> {
+    let mut projects = vec![];
+
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+
+        let dir_name = entry.file_name().to_string_lossy().to_string();
+
+        let Some(&(_, editor, launcher)) = JETBRAINS_EDITORS.iter().find(|(prefix, _, _)| dir_name.starts_with(prefix)) else {
+            continue;
+        };
+
+        let Ok(content) = std::fs::read_to_string(entry.path().join("options").join("recentProjects.xml")) else {
+            continue;
+        };
+
+        for captures in JETBRAINS_ENTRY.captures_iter(&content) {
+            let path = captures[1].replace("$USER_HOME$", &home_dir.display().to_string());
+
+            projects.push(RecentProject {
+                path: PathBuf::from(path),
+                editor,
+                launcher,
+            });
+        }
+    }
+
+    Ok(projects)
+}
@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use gauntlet_common::model::{EntrypointId, PluginId, SearchResult};
+
+use crate::plugins::js::{AllPluginCommandData, PluginCommand};
+
+// plugins are asked for results only after user stopped typing for a moment
+const SEARCH_DEBOUNCE: Duration = Duration::from_millis(150);
+const PROVIDER_RESULT_LIMIT: usize = 20;
+
+// results of "search-provider" entrypoints for current search query.
+// every query gets new search id, results for previous queries which arrive late are discarded,
+// plugin runtime aborts handling of previous query by itself when it receives new one
+#[derive(Clone)]
+pub struct SearchProviders {
+    inner: Arc<Mutex<SearchProvidersInner>>,
+    command_broadcaster: tokio::sync::broadcast::Sender<PluginCommand>,
+}
+
+#[derive(Default)]
+struct SearchProvidersInner {
+    search_id: u32,
+    query: String,
+    results: HashMap<(PluginId, EntrypointId), Vec<SearchResult>>,
+    // keyed by plugin id and entrypoint id of result, not of search provider
+    previews: HashMap<(PluginId, EntrypointId), String>,
+}
+
+impl SearchProviders {
+    pub fn new(command_broadcaster: tokio::sync::broadcast::Sender<PluginCommand>) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(SearchProvidersInner::default())),
+            command_broadcaster,
+        }
+    }
+
+    pub fn search(&self, query: &str) {
+        let search_id = {
+            let mut inner = self.inner.lock().unwrap();
+
+            // search is repeated with the same query when results are updated
+            if inner.query == query {
+                return;
+            }
+
+            inner.search_id = inner.search_id.wrapping_add(1);
+            inner.query = query.to_string();
+            inner.results.clear();
+            inner.previews.clear();
+
+            inner.search_id
+        };
+
+        if query.trim().is_empty() {
+            return;
+        }
+
+        let search_providers = self.clone();
+        let query = query.to_string();
+
+        tokio::spawn(async move {
+            tokio::time::sleep(SEARCH_DEBOUNCE).await;
+
+            if search_providers.inner.lock().unwrap().search_id != search_id {
+                return;
+            }
+
+            // it is possible to have 0 plugins
+            let _ = search_providers.command_broadcaster.send(PluginCommand::All {
+                data: AllPluginCommandData::Search {
+                    text: query,
+                    search_id,
+                }
+            });
+        });
+    }
+
+    // returns false if results are for a query which is not current anymore
+    pub fn set_results(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, search_id: u32, mut results: Vec<SearchResult>, previews: HashMap<EntrypointId, String>) -> bool {
+        let mut inner = self.inner.lock().unwrap();
+
+        if inner.search_id != search_id {
+            return false;
+        }
+
+        results.truncate(PROVIDER_RESULT_LIMIT);
+
+        for (result_entrypoint_id, preview) in previews {
+            inner.previews.insert((plugin_id.clone(), result_entrypoint_id), preview);
+        }
+
+        inner.results.insert((plugin_id, entrypoint_id), results);
+
+        true
+    }
+
+    pub fn results(&self, query: &str) -> Vec<SearchResult> {
+        let inner = self.inner.lock().unwrap();
+
+        if inner.query != query {
+            return vec![];
+        }
+
+        // keep order of providers stable while their results arrive
+        let mut results = inner.results.iter().collect::<Vec<_>>();
+
+        results.sort_by_key(|((plugin_id, entrypoint_id), _)| (plugin_id.to_string(), entrypoint_id.to_string()));
+
+        results.into_iter()
+            .flat_map(|(_, results)| results.iter().cloned())
+            .collect()
+    }
+
+    pub fn preview(&self, plugin_id: &PluginId, entrypoint_id: &EntrypointId) -> Option<String> {
+        self.inner.lock()
+            .unwrap()
+            .previews
+            .get(&(plugin_id.clone(), entrypoint_id.clone()))
+            .cloned()
+    }
+
+    pub fn remove_for_plugin(&self, plugin_id: &PluginId) {
+        let mut inner = self.inner.lock().unwrap();
+
+        inner.results.retain(|(result_plugin_id, _), _| result_plugin_id != plugin_id);
+        inner.previews.retain(|(result_plugin_id, _), _| result_plugin_id != plugin_id);
+    }
+}
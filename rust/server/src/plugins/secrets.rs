@@ -0,0 +1,44 @@
+use anyhow::Context;
+
+use gauntlet_common::model::PluginId;
+
+const SERVICE_NAME: &str = "gauntlet";
+
+// values are stored in os keychain (keychain on macos, credential manager on windows, secret service on linux)
+// and are namespaced by plugin id
+#[derive(Clone)]
+pub struct Secrets;
+
+impl Secrets {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn get(&self, plugin_id: &PluginId, key: &str) -> anyhow::Result<Option<String>> {
+        match entry(plugin_id, key)?.get_password() {
+            Ok(value) => Ok(Some(value)),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(err) => Err(err).context("Unable to read secret from keychain"),
+        }
+    }
+
+    pub fn set(&self, plugin_id: &PluginId, key: &str, value: &str) -> anyhow::Result<()> {
+        entry(plugin_id, key)?
+            .set_password(value)
+            .context("Unable to write secret to keychain")
+    }
+
+    pub fn remove(&self, plugin_id: &PluginId, key: &str) -> anyhow::Result<()> {
+        match entry(plugin_id, key)?.delete_credential() {
+            Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(err) => Err(err).context("Unable to remove secret from keychain"),
+        }
+    }
+}
+
+fn entry(plugin_id: &PluginId, key: &str) -> anyhow::Result<keyring::Entry> {
+    let user = format!("{}/{}", plugin_id.to_string(), key);
+
+    keyring::Entry::new(SERVICE_NAME, &user)
+        .context("Unable to access keychain")
+}
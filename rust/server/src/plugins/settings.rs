@@ -1,9 +1,10 @@
-use crate::plugins::data_db_repository::{DataDbRepository, DbTheme, DbWindowPositionMode};
+use crate::plugins::data_db_repository::{DataDbRepository, DbConfirmedDeepLink, DbDensity, DbSearchResultsSort, DbTheme, DbWindowPositionMode};
 use crate::plugins::theme::{read_theme_file, BundledThemes};
 use anyhow::anyhow;
 use dark_light::Mode;
+use gauntlet_common::crash::set_crash_reports_enabled;
 use gauntlet_common::dirs::Dirs;
-use gauntlet_common::model::{PhysicalKey, PhysicalShortcut, SettingsTheme, UiTheme, WindowPositionMode};
+use gauntlet_common::model::{Density, EntrypointId, PhysicalKey, PluginId, PhysicalShortcut, SearchResultsSort, SettingsTheme, UiScale, UiTheme, WindowPositionMode};
 use gauntlet_common::rpc::frontend_api::FrontendApi;
 use std::env::consts::OS;
 
@@ -94,6 +95,7 @@ impl Settings {
                 DbTheme::MacOSLight => self.themes.macos_light_theme.clone(),
                 DbTheme::MacOSDark => self.themes.macos_dark_theme.clone(),
                 DbTheme::Legacy => self.themes.legacy_theme.clone(),
+                DbTheme::HighContrast => self.themes.high_contrast_theme.clone(),
             }
         };
 
@@ -116,6 +118,7 @@ impl Settings {
              Some(DbTheme::MacOSLight) => Ok(SettingsTheme::MacOSLight),
              Some(DbTheme::MacOSDark) => Ok(SettingsTheme::MacOSDark),
              Some(DbTheme::Legacy) => Ok(SettingsTheme::Legacy),
+             Some(DbTheme::HighContrast) => Ok(SettingsTheme::HighContrast),
         }
     }
 
@@ -130,6 +133,7 @@ impl Settings {
             SettingsTheme::MacOSLight => Some(DbTheme::MacOSLight),
             SettingsTheme::MacOSDark => Some(DbTheme::MacOSDark),
             SettingsTheme::Legacy => Some(DbTheme::Legacy),
+            SettingsTheme::HighContrast => Some(DbTheme::HighContrast),
             // these should not be visible in settings ui
             SettingsTheme::Config => Err(anyhow!("Unable to set current theme to config"))?,
             SettingsTheme::ThemeFile => Err(anyhow!("Unable to set current theme to a file"))?
@@ -141,6 +145,7 @@ impl Settings {
                 DbTheme::MacOSLight => self.themes.macos_light_theme.clone(),
                 DbTheme::MacOSDark => self.themes.macos_dark_theme.clone(),
                 DbTheme::Legacy => self.themes.legacy_theme.clone(),
+                DbTheme::HighContrast => self.themes.high_contrast_theme.clone(),
             }
         };
 
@@ -184,6 +189,236 @@ impl Settings {
         Ok(())
     }
 
+    pub async fn is_deep_link_confirmed(&self, plugin_id: &PluginId, entrypoint_id: &EntrypointId) -> anyhow::Result<bool> {
+        let settings = self.repository
+            .get_settings()
+            .await?;
+
+        let confirmed = settings.confirmed_deep_links
+            .unwrap_or_default()
+            .contains(&confirmed_deep_link(plugin_id, entrypoint_id));
+
+        Ok(confirmed)
+    }
+
+    pub async fn set_deep_link_confirmed(&self, plugin_id: &PluginId, entrypoint_id: &EntrypointId) -> anyhow::Result<()> {
+        let mut settings = self.repository
+            .get_settings()
+            .await?;
+
+        let confirmed_deep_link = confirmed_deep_link(plugin_id, entrypoint_id);
+
+        let confirmed_deep_links = settings.confirmed_deep_links.get_or_insert_with(Vec::new);
+
+        if !confirmed_deep_links.contains(&confirmed_deep_link) {
+            confirmed_deep_links.push(confirmed_deep_link);
+        }
+
+        self.repository.set_settings(settings).await?;
+
+        Ok(())
+    }
+
+    pub async fn window_overlay_setting(&self) -> anyhow::Result<bool> {
+        let settings = self.repository
+            .get_settings()
+            .await?;
+
+        Ok(settings.window_overlay.unwrap_or(false))
+    }
+
+    pub async fn set_window_overlay_setting(&self, enabled: bool) -> anyhow::Result<()> {
+        let mut settings = self.repository
+            .get_settings()
+            .await?;
+
+        settings.window_overlay = Some(enabled);
+
+        self.repository.set_settings(settings).await?;
+
+        self.frontend_api.set_window_overlay(enabled).await?;
+
+        Ok(())
+    }
+
+    pub async fn density_setting(&self) -> anyhow::Result<Density> {
+        let settings = self.repository
+            .get_settings()
+            .await?;
+
+        let density = match &settings.density {
+            None => Density::Comfortable,
+            Some(DbDensity::Compact) => Density::Compact,
+            Some(DbDensity::Large) => Density::Large,
+        };
+
+        Ok(density)
+    }
+
+    pub async fn set_density_setting(&self, density: Density) -> anyhow::Result<()> {
+        let mut settings = self.repository
+            .get_settings()
+            .await?;
+
+        settings.density = match density {
+            Density::Compact => Some(DbDensity::Compact),
+            Density::Comfortable => None,
+            Density::Large => Some(DbDensity::Large),
+        };
+
+        self.repository.set_settings(settings).await?;
+
+        self.frontend_api.set_density(density).await?;
+
+        Ok(())
+    }
+
+    pub async fn ui_scale_setting(&self) -> anyhow::Result<UiScale> {
+        let settings = self.repository
+            .get_settings()
+            .await?;
+
+        let scale = match settings.ui_scale {
+            None => UiScale::Auto,
+            Some(percent) => UiScale::Manual { percent },
+        };
+
+        Ok(scale)
+    }
+
+    pub async fn set_ui_scale_setting(&self, scale: UiScale) -> anyhow::Result<()> {
+        let mut settings = self.repository
+            .get_settings()
+            .await?;
+
+        settings.ui_scale = match scale {
+            UiScale::Auto => None,
+            UiScale::Manual { percent } => Some(percent),
+        };
+
+        self.repository.set_settings(settings).await?;
+
+        self.frontend_api.set_ui_scale(scale).await?;
+
+        Ok(())
+    }
+
+    pub async fn search_results_sort_setting(&self) -> anyhow::Result<SearchResultsSort> {
+        let settings = self.repository
+            .get_settings()
+            .await?;
+
+        let search_results_sort = match &settings.search_results_sort {
+            None => SearchResultsSort::Frecency,
+            Some(DbSearchResultsSort::Relevance) => SearchResultsSort::Relevance,
+            Some(DbSearchResultsSort::Plugin) => SearchResultsSort::Plugin,
+        };
+
+        Ok(search_results_sort)
+    }
+
+    pub async fn set_search_results_sort_setting(&self, sort: SearchResultsSort) -> anyhow::Result<()> {
+        let mut settings = self.repository
+            .get_settings()
+            .await?;
+
+        let search_results_sort = match sort {
+            SearchResultsSort::Frecency => None,
+            SearchResultsSort::Relevance => Some(DbSearchResultsSort::Relevance),
+            SearchResultsSort::Plugin => Some(DbSearchResultsSort::Plugin),
+        };
+
+        settings.search_results_sort = search_results_sort;
+
+        self.repository.set_settings(settings).await?;
+
+        self.frontend_api.set_search_results_sort(sort).await?;
+
+        Ok(())
+    }
+
+    pub async fn crash_reports_enabled_setting(&self) -> anyhow::Result<bool> {
+        let settings = self.repository
+            .get_settings()
+            .await?;
+
+        Ok(settings.crash_reports_enabled.unwrap_or(false))
+    }
+
+    pub async fn set_crash_reports_enabled_setting(&self, enabled: bool) -> anyhow::Result<()> {
+        let mut settings = self.repository
+            .get_settings()
+            .await?;
+
+        settings.crash_reports_enabled = Some(enabled);
+
+        self.repository.set_settings(settings).await?;
+
+        set_crash_reports_enabled(enabled);
+
+        Ok(())
+    }
+
+    pub async fn offline_mode_setting(&self) -> anyhow::Result<bool> {
+        let settings = self.repository
+            .get_settings()
+            .await?;
+
+        Ok(settings.offline_mode.unwrap_or(false))
+    }
+
+    pub async fn set_offline_mode_setting(&self, offline: bool) -> anyhow::Result<()> {
+        let mut settings = self.repository
+            .get_settings()
+            .await?;
+
+        settings.offline_mode = Some(offline);
+
+        self.repository.set_settings(settings).await?;
+
+        Ok(())
+    }
+
+    pub async fn usage_stats_enabled_setting(&self) -> anyhow::Result<bool> {
+        let settings = self.repository
+            .get_settings()
+            .await?;
+
+        Ok(settings.usage_stats_enabled.unwrap_or(true))
+    }
+
+    pub async fn set_usage_stats_enabled_setting(&self, enabled: bool) -> anyhow::Result<()> {
+        let mut settings = self.repository
+            .get_settings()
+            .await?;
+
+        settings.usage_stats_enabled = Some(enabled);
+
+        self.repository.set_settings(settings).await?;
+
+        Ok(())
+    }
+
+    pub async fn autostart_configured_setting(&self) -> anyhow::Result<bool> {
+        let settings = self.repository
+            .get_settings()
+            .await?;
+
+        Ok(settings.autostart_configured.unwrap_or(false))
+    }
+
+    pub async fn set_autostart_configured_setting(&self) -> anyhow::Result<()> {
+        let mut settings = self.repository
+            .get_settings()
+            .await?;
+
+        settings.autostart_configured = Some(true);
+
+        self.repository.set_settings(settings).await?;
+
+        Ok(())
+    }
+
     fn autodetect_theme(&self) -> UiTheme {
         match OS {
             "macos" => {
@@ -197,3 +432,10 @@ impl Settings {
         }
     }
 }
+
+fn confirmed_deep_link(plugin_id: &PluginId, entrypoint_id: &EntrypointId) -> DbConfirmedDeepLink {
+    DbConfirmedDeepLink {
+        plugin_id: plugin_id.to_string(),
+        entrypoint_id: entrypoint_id.to_string(),
+    }
+}
@@ -0,0 +1,224 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use anyhow::anyhow;
+
+use gauntlet_common::dirs::Dirs;
+use gauntlet_common::model::{EntrypointId, Icons, PluginId, SearchResult, SearchResultAccessory, SearchResultEntrypointType};
+
+use crate::plugins::terminal::Terminal;
+
+pub const SSH_HOSTS_PLUGIN_ID: &str = "builtin://ssh";
+const SSH_HOSTS_PLUGIN_NAME: &str = "SSH";
+const SSH_HOST_ENTRYPOINT_PREFIX: &str = "host:";
+const RELOAD_INTERVAL: Duration = Duration::from_secs(30);
+// guards against include cycles
+const MAX_INCLUDE_DEPTH: usize = 8;
+
+#[derive(Debug, Clone)]
+struct SshHost {
+    alias: String,
+    host_name: Option<String>,
+    user: Option<String>,
+}
+
+// hosts from "~/.ssh/config", wildcard patterns are skipped. file is re-read at most every 30 seconds
+#[derive(Clone)]
+pub struct SshHosts {
+    dirs: Dirs,
+    terminal: Terminal,
+    hosts: Arc<Mutex<Option<(Instant, Vec<SshHost>)>>>,
+    // hosts shown during last search, keyed by entrypoint id of search result
+    resolved: Arc<Mutex<HashMap<String, String>>>,
+}
+
+impl SshHosts {
+    pub fn new(dirs: Dirs, terminal: Terminal) -> Self {
+        Self {
+            dirs,
+            terminal,
+            hosts: Arc::new(Mutex::new(None)),
+            resolved: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn is_ssh_host(plugin_id: &PluginId) -> bool {
+        plugin_id.to_string() == SSH_HOSTS_PLUGIN_ID
+    }
+
+    // "ssh" lists all hosts, "ssh prod" or "prod" lists matching hosts
+    pub fn search(&self, text: &str) -> Vec<SearchResult> {
+        let mut resolved = self.resolved.lock().unwrap();
+        resolved.clear();
+
+        let text = text.trim().to_lowercase();
+
+        if text.len() < 2 {
+            return vec![];
+        }
+
+        let query = match text.strip_prefix("ssh") {
+            Some(rest) if rest.is_empty() || rest.starts_with(' ') => rest.trim().to_string(),
+            _ => text,
+        };
+
+        let mut result = vec![];
+
+        for host in self.hosts() {
+            let matches = host.alias.to_lowercase().contains(&query)
+                || host.host_name.as_ref().is_some_and(|host_name| host_name.to_lowercase().contains(&query));
+
+            if !matches {
+                continue;
+            }
+
+            let entrypoint_id = format!("{}{}", SSH_HOST_ENTRYPOINT_PREFIX, host.alias);
+
+            let subtitle = match (&host.user, &host.host_name) {
+                (Some(user), Some(host_name)) => Some(format!("{}@{}", user, host_name)),
+                (None, Some(host_name)) => Some(host_name.clone()),
+                (Some(user), None) => Some(format!("{}@{}", user, host.alias)),
+                (None, None) => None,
+            };
+
+            result.push(SearchResult {
+                plugin_id: PluginId::from_string(SSH_HOSTS_PLUGIN_ID),
+                plugin_name: SSH_HOSTS_PLUGIN_NAME.to_string(),
+                entrypoint_id: EntrypointId::from_string(&entrypoint_id),
+                entrypoint_name: format!("SSH to {}", host.alias),
+                entrypoint_name_highlights: vec![],
+                entrypoint_subtitle: subtitle,
+                entrypoint_matched_keywords: vec![],
+                entrypoint_generator_name: None,
+                entrypoint_icon: None,
+                entrypoint_type: SearchResultEntrypointType::Command,
+                entrypoint_actions: vec![],
+                entrypoint_accessories: vec![
+                    SearchResultAccessory::IconAccessory {
+                        icon: Icons::Terminal,
+                        tooltip: Some("Open in terminal".to_string()),
+                    }
+                ],
+                entrypoint_arguments: vec![],
+                entrypoint_file: None,
+            });
+
+            resolved.insert(entrypoint_id, host.alias.clone());
+        }
+
+        result
+    }
+
+    pub fn open(&self, entrypoint_id: &EntrypointId) -> anyhow::Result<()> {
+        let host = self.resolved.lock()
+            .unwrap()
+            .get(&entrypoint_id.to_string())
+            .cloned()
+            .ok_or_else(|| anyhow!("Unknown ssh host: {}", entrypoint_id.to_string()))?;
+
+        tracing::info!("Opening ssh connection to host: {}", host);
+
+        self.terminal.run(vec!["ssh".to_string(), host], None)
+    }
+
+    fn hosts(&self) -> Vec<SshHost> {
+        let mut hosts = self.hosts.lock().unwrap();
+
+        match hosts.as_ref() {
+            Some((loaded_at, hosts)) if loaded_at.elapsed() < RELOAD_INTERVAL => hosts.clone(),
+            _ => {
+                let ssh_dir = self.dirs.home_dir().join(".ssh");
+
+                let mut result = vec![];
+                read_ssh_config(&ssh_dir, &ssh_dir.join("config"), 0, &mut result);
+
+                *hosts = Some((Instant::now(), result.clone()));
+
+                result
+            }
+        }
+    }
+}
+
+// only options shown in search results are read, host specific options are applied to all aliases of "Host" line
+fn read_ssh_config(ssh_dir: &Path, path: &Path, depth: usize, result: &mut Vec<SshHost>) {
+    if depth > MAX_INCLUDE_DEPTH {
+        return;
+    }
+
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return;
+    };
+
+    let mut current: Vec<usize> = vec![];
+
+    for line in content.lines() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (keyword, value) = match line.split_once(|char: char| char.is_whitespace() || char == '=') {
+            Some((keyword, value)) => (keyword.to_lowercase(), value.trim().trim_start_matches('=').trim()),
+            None => continue,
+        };
+
+        match keyword.as_str() {
+            "host" => {
+                current.clear();
+
+                for alias in value.split_whitespace() {
+                    if alias.contains(['*', '?', '!']) {
+                        continue;
+                    }
+
+                    if result.iter().any(|host| host.alias == alias) {
+                        continue;
+                    }
+
+                    current.push(result.len());
+
+                    result.push(SshHost {
+                        alias: alias.to_string(),
+                        host_name: None,
+                        user: None,
+                    });
+                }
+            }
+            "match" => {
+                current.clear();
+            }
+            "hostname" => {
+                for index in &current {
+                    result[*index].host_name.get_or_insert_with(|| value.to_string());
+                }
+            }
+            "user" => {
+                for index in &current {
+                    result[*index].user.get_or_insert_with(|| value.to_string());
+                }
+            }
+            "include" => {
+                for include in value.split_whitespace() {
+                    // glob patterns are not supported
+                    if include.contains(['*', '?']) {
+                        continue;
+                    }
+
+                    let include_path = match include.strip_prefix("~/") {
+                        Some(rest) => ssh_dir.parent().map(|home| home.join(rest)),
+                        None => Some(ssh_dir.join(include)),
+                    };
+
+                    if let Some(include_path) = include_path {
+                        read_ssh_config(ssh_dir, &include_path, depth + 1, result);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use anyhow::Context;
+use gauntlet_common::dirs::Dirs;
+
+use crate::plugins::command_template::{run_command_template, shell_join, TemplateValue};
+
+// "{command}" is replaced with command which runs generated script, "{cwd}" with working directory
+#[cfg(target_os = "linux")]
+pub const DEFAULT_TERMINAL_COMMAND: &str = "x-terminal-emulator -e {command}";
+#[cfg(target_os = "macos")]
+pub const DEFAULT_TERMINAL_COMMAND: &str = r#"osascript -e 'tell application "Terminal" to do script "{command}"' -e 'tell application "Terminal" to activate'"#;
+#[cfg(target_os = "windows")]
+pub const DEFAULT_TERMINAL_COMMAND: &str = r#"cmd /C start "" {command}"#;
+
+// runs commands in terminal emulator preferred by user, used by built-in commands and plugins
+#[derive(Clone)]
+pub struct Terminal {
+    dirs: Dirs,
+    command_template: Arc<Mutex<String>>,
+}
+
+impl Terminal {
+    pub fn new(dirs: Dirs) -> Self {
+        Self {
+            dirs,
+            command_template: Arc::new(Mutex::new(DEFAULT_TERMINAL_COMMAND.to_string())),
+        }
+    }
+
+    pub fn set_command_template(&self, command_template: String) {
+        *self.command_template.lock().unwrap() = command_template;
+    }
+
+    // when command is empty user's shell is started, home directory is used when working directory is not specified.
+    // program and arguments are written to script file instead of being put into the template,
+    // because template can pass them through other interpreters, e.g. applescript string or cmd
+    pub fn run(&self, command: Vec<String>, cwd: Option<PathBuf>) -> anyhow::Result<()> {
+        let command = if command.is_empty() {
+            vec![default_shell()]
+        } else {
+            command
+        };
+
+        let cwd = cwd.unwrap_or_else(|| self.dirs.home_dir());
+
+        let cwd_value = [cwd.display().to_string()];
+
+        let command_template = self.command_template.lock().unwrap().clone();
+
+        tracing::info!("Running command in terminal: {:?}, working directory: {:?}", command, cwd);
+
+        let script_command = write_script(&command, &cwd)?;
+
+        let placeholders = [
+            ("command", TemplateValue::Args(&script_command)),
+            ("cwd", TemplateValue::Args(&cwd_value)),
+        ];
+
+        run_command_template(&command_template, &placeholders, Some(&cwd))
+    }
+}
+
+fn default_shell() -> String {
+    #[cfg(unix)]
+    let shell = std::env::var("SHELL").unwrap_or_else(|_| "/bin/sh".to_string());
+
+    #[cfg(windows)]
+    let shell = "cmd".to_string();
+
+    shell
+}
+
+// script removes itself when started
+#[cfg(unix)]
+fn write_script(command: &[String], cwd: &Path) -> anyhow::Result<Vec<String>> {
+    let path = write_script_file(".sh", &shell_script(command, cwd))?;
+
+    Ok(vec!["/bin/sh".to_string(), path])
+}
+
+#[cfg(windows)]
+fn write_script(command: &[String], cwd: &Path) -> anyhow::Result<Vec<String>> {
+    let path = write_script_file(".ps1", &powershell_script(command, cwd))?;
+
+    Ok(vec![
+        "powershell".to_string(),
+        "-NoExit".to_string(),
+        "-ExecutionPolicy".to_string(),
+        "Bypass".to_string(),
+        "-File".to_string(),
+        path,
+    ])
+}
+
+fn write_script_file(suffix: &str, content: &str) -> anyhow::Result<String> {
+    let mut file = tempfile::Builder::new()
+        .prefix("gauntlet-terminal-")
+        .suffix(suffix)
+        .tempfile()
+        .context("Unable to create terminal script")?;
+
+    file.write_all(content.as_bytes())
+        .context("Unable to write terminal script")?;
+
+    let (_, path) = file.keep()
+        .context("Unable to keep terminal script")?;
+
+    Ok(path.display().to_string())
+}
+
+#[cfg(any(unix, test))]
+fn shell_script(command: &[String], cwd: &Path) -> String {
+    format!(
+        "rm -f -- \"$0\"\ncd -- {} || exit 1\nexec {}\n",
+        shell_join(&[cwd.display().to_string()]),
+        shell_join(command)
+    )
+}
+
+// values in single quotes are not interpreted by powershell, only quote itself needs to be doubled
+#[cfg(any(windows, test))]
+fn powershell_script(command: &[String], cwd: &Path) -> String {
+    let quote = |value: &str| format!("'{}'", value.replace('\'', "''"));
+
+    let command = command.iter()
+        .map(|value| quote(value))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    format!(
+        "Remove-Item -LiteralPath $PSCommandPath\r\nSet-Location -LiteralPath {}\r\n& {}\r\n",
+        quote(&cwd.display().to_string()),
+        command
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command() -> Vec<String> {
+        vec![
+            "printf".to_string(),
+            "%s\\n".to_string(),
+            "say \"hi\"".to_string(),
+            "a & b".to_string(),
+            "it's".to_string(),
+        ]
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn shell_script_passes_arguments_unchanged() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let path = write_script_file(".sh", &shell_script(&command(), dir.path())).unwrap();
+
+        let output = std::process::Command::new("/bin/sh")
+            .arg(&path)
+            .output()
+            .unwrap();
+
+        assert_eq!(String::from_utf8(output.stdout).unwrap(), "say \"hi\"\na & b\nit's\n");
+        assert!(!Path::new(&path).exists());
+    }
+
+    #[test]
+    fn powershell_script_quotes_arguments() {
+        let script = powershell_script(&command(), Path::new("C:\\it's"));
+
+        assert_eq!(
+            script,
+            "Remove-Item -LiteralPath $PSCommandPath\r\nSet-Location -LiteralPath 'C:\\it''s'\r\n& 'printf' '%s\\n' 'say \"hi\"' 'a & b' 'it''s'\r\n"
+        );
+    }
+}
@@ -13,11 +13,13 @@ pub struct BundledThemes {
     pub legacy_theme: UiTheme,
     pub macos_dark_theme: UiTheme,
     pub macos_light_theme: UiTheme,
+    pub high_contrast_theme: UiTheme,
 }
 
 const LEGACY_THEME: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../bundled_themes/legacy.toml"));
 const MACOS_DARK_THEME: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../bundled_themes/macos_dark.toml"));
 const MACOS_LIGHT_THEME: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../bundled_themes/macos_light.toml"));
+const HIGH_CONTRAST_THEME: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../bundled_themes/high_contrast.toml"));
 
 impl BundledThemes {
     pub fn new() -> anyhow::Result<Self> {
@@ -25,6 +27,7 @@ impl BundledThemes {
             legacy_theme: parse_theme(LEGACY_THEME).expect("bundled theme should always be valid"),
             macos_dark_theme: parse_theme(MACOS_DARK_THEME).expect("bundled theme should always be valid"),
             macos_light_theme: parse_theme(MACOS_LIGHT_THEME).expect("bundled theme should always be valid"),
+            high_contrast_theme: parse_theme(HIGH_CONTRAST_THEME).expect("bundled theme should always be valid"),
         })
     }
 }
@@ -0,0 +1,545 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::{Arc, Mutex};
+
+use anyhow::anyhow;
+use chrono::{DateTime, Local, NaiveTime, TimeZone, Utc};
+use uuid::Uuid;
+
+use gauntlet_common::model::{EntrypointId, Icons, PluginId, SearchResult, SearchResultAccessory, SearchResultEntrypointType};
+use gauntlet_common::rpc::frontend_api::FrontendApi;
+
+use crate::plugins::data_db_repository::{DataDbRepository, DbTimer};
+use crate::plugins::notifications::Notifications;
+
+pub const TIMERS_PLUGIN_ID: &str = "builtin://timers";
+const TIMERS_PLUGIN_NAME: &str = "Timers";
+const START_ENTRYPOINT_ID: &str = "start";
+const STOP_ENTRYPOINT_PREFIX: &str = "stop:";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TimerKind {
+    Timer,
+    Stopwatch,
+    Alarm,
+}
+
+impl TimerKind {
+    fn from_str(value: &str) -> Option<Self> {
+        match value {
+            "timer" => Some(TimerKind::Timer),
+            "stopwatch" => Some(TimerKind::Stopwatch),
+            "alarm" => Some(TimerKind::Alarm),
+            _ => None
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            TimerKind::Timer => "timer",
+            TimerKind::Stopwatch => "stopwatch",
+            TimerKind::Alarm => "alarm",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            TimerKind::Timer => "Timer",
+            TimerKind::Stopwatch => "Stopwatch",
+            TimerKind::Alarm => "Alarm",
+        }
+    }
+
+    fn icon(&self) -> Icons {
+        match self {
+            TimerKind::Timer => Icons::Hourglass,
+            TimerKind::Stopwatch => Icons::Stopwatch,
+            TimerKind::Alarm => Icons::Alarm,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RunningTimer {
+    id: String,
+    kind: TimerKind,
+    // duration of timer or time of alarm, as typed by user
+    name: String,
+    // milliseconds since unix epoch
+    started_at: i64,
+    fires_at: Option<i64>,
+}
+
+impl RunningTimer {
+    fn title(&self) -> String {
+        match self.kind {
+            TimerKind::Stopwatch => self.kind.label().to_string(),
+            TimerKind::Timer | TimerKind::Alarm => format!("{} {}", self.kind.label(), self.name),
+        }
+    }
+
+    fn status(&self, now: i64) -> String {
+        match self.fires_at {
+            Some(fires_at) => format!("{} left", format_countdown(fires_at - now)),
+            None => format!("{} elapsed", format_countdown(now - self.started_at)),
+        }
+    }
+}
+
+enum TimerAction {
+    Start {
+        kind: TimerKind,
+        name: String,
+        fires_at: Option<i64>,
+        resolved_at: i64,
+    },
+    Stop {
+        id: String,
+    },
+}
+
+// timers, alarms and stopwatches are kept in database, so they keep running after restart,
+// timers which went off while application was not running fire on the first tick
+#[derive(Clone)]
+pub struct Timers {
+    repository: DataDbRepository,
+    frontend_api: FrontendApi,
+    notifications: Notifications,
+    timers: Arc<Mutex<Vec<RunningTimer>>>,
+    // actions resolved during last search, keyed by entrypoint id of search result
+    resolved: Arc<Mutex<HashMap<String, TimerAction>>>,
+    last_tooltip: Arc<Mutex<Option<String>>>,
+}
+
+impl Timers {
+    pub fn new(repository: DataDbRepository, frontend_api: FrontendApi, notifications: Notifications) -> Self {
+        Self {
+            repository,
+            frontend_api,
+            notifications,
+            timers: Arc::new(Mutex::new(vec![])),
+            resolved: Arc::new(Mutex::new(HashMap::new())),
+            last_tooltip: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub fn is_timer(plugin_id: &PluginId) -> bool {
+        plugin_id.to_string() == TIMERS_PLUGIN_ID
+    }
+
+    pub async fn reload(&self) -> anyhow::Result<()> {
+        let timers = self.repository.list_timers()
+            .await?
+            .into_iter()
+            .filter_map(|timer| {
+                let Some(kind) = TimerKind::from_str(&timer.kind) else {
+                    tracing::warn!("Unknown timer kind: {}", timer.kind);
+                    return None;
+                };
+
+                Some(RunningTimer {
+                    id: timer.id,
+                    kind,
+                    name: timer.name,
+                    started_at: timer.started_at,
+                    fires_at: timer.fires_at,
+                })
+            })
+            .collect();
+
+        *self.timers.lock().unwrap() = timers;
+
+        Ok(())
+    }
+
+    // "timer 10m", "set alarm 7:30" and "start stopwatch" create new timer,
+    // running timers are listed when query is a prefix of their kind, e.g. "tim"
+    pub fn search(&self, text: &str) -> Vec<SearchResult> {
+        let mut resolved = self.resolved.lock().unwrap();
+        resolved.clear();
+
+        let text = text.trim().to_lowercase();
+
+        if text.is_empty() {
+            return vec![];
+        }
+
+        let mut result = vec![];
+
+        let now = now_millis();
+
+        if let Some((kind, name, fires_at)) = parse_start_action(&text) {
+            let entrypoint_name = match kind {
+                TimerKind::Timer => format!("Start Timer for {}", name),
+                TimerKind::Stopwatch => "Start Stopwatch".to_string(),
+                TimerKind::Alarm => format!("Set Alarm for {}", name),
+            };
+
+            let subtitle = fires_at.map(|fires_at| format!("Goes off at {}", format_local_time(fires_at)));
+
+            result.push(timer_search_result(START_ENTRYPOINT_ID.to_string(), entrypoint_name, subtitle, kind));
+
+            resolved.insert(START_ENTRYPOINT_ID.to_string(), TimerAction::Start { kind, name, fires_at, resolved_at: now });
+        }
+
+        for timer in self.timers.lock().unwrap().iter() {
+            let matches = text.len() >= 2 && (timer.kind.as_str().starts_with(&text) || text.starts_with(timer.kind.as_str()));
+
+            if !matches {
+                continue;
+            }
+
+            let entrypoint_id = format!("{}{}", STOP_ENTRYPOINT_PREFIX, timer.id);
+
+            let entrypoint_name = match timer.kind {
+                TimerKind::Stopwatch => format!("Stop {}", timer.title()),
+                TimerKind::Timer | TimerKind::Alarm => format!("Cancel {}", timer.title()),
+            };
+
+            result.push(timer_search_result(entrypoint_id.clone(), entrypoint_name, Some(timer.status(now)), timer.kind));
+
+            resolved.insert(entrypoint_id, TimerAction::Stop { id: timer.id.clone() });
+        }
+
+        result
+    }
+
+    pub async fn run(&self, entrypoint_id: &EntrypointId) -> anyhow::Result<()> {
+        let action = self.resolved.lock()
+            .unwrap()
+            .remove(&entrypoint_id.to_string())
+            .ok_or_else(|| anyhow!("Unknown timer action: {}", entrypoint_id.to_string()))?;
+
+        match action {
+            TimerAction::Start { kind, name, fires_at, resolved_at } => {
+                let now = now_millis();
+
+                // timer duration is counted from the moment it is started, alarm time doesn't change
+                let fires_at = match kind {
+                    TimerKind::Timer => fires_at.map(|fires_at| fires_at + now - resolved_at),
+                    TimerKind::Stopwatch | TimerKind::Alarm => fires_at,
+                };
+
+                let timer = RunningTimer {
+                    id: Uuid::new_v4().to_string(),
+                    kind,
+                    name,
+                    started_at: now,
+                    fires_at,
+                };
+
+                tracing::info!("Starting {}: {}", kind.as_str(), timer.title());
+
+                self.repository.save_timer(DbTimer {
+                    id: timer.id.clone(),
+                    kind: kind.as_str().to_string(),
+                    name: timer.name.clone(),
+                    started_at: timer.started_at,
+                    fires_at: timer.fires_at,
+                }).await?;
+
+                self.timers.lock().unwrap().push(timer);
+            }
+            TimerAction::Stop { id } => {
+                let timer = {
+                    let mut timers = self.timers.lock().unwrap();
+
+                    let index = timers.iter()
+                        .position(|timer| timer.id == id)
+                        .ok_or_else(|| anyhow!("Timer is not running: {}", id))?;
+
+                    timers.remove(index)
+                };
+
+                tracing::info!("Stopping {}: {}", timer.kind.as_str(), timer.title());
+
+                self.repository.remove_timer(&timer.id).await?;
+
+                if timer.kind == TimerKind::Stopwatch {
+                    let elapsed = format_countdown(now_millis() - timer.started_at);
+
+                    self.frontend_api.show_hud(format!("Stopwatch stopped at {}", elapsed)).await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // called every second, fires timers which are due and keeps countdown in tray tooltip up to date
+    pub async fn tick(&self, sound: Option<PathBuf>) -> anyhow::Result<()> {
+        let now = now_millis();
+
+        let (fired, tooltip) = {
+            let mut timers = self.timers.lock().unwrap();
+
+            let (fired, running): (Vec<_>, Vec<_>) = timers.drain(..)
+                .partition(|timer| matches!(timer.fires_at, Some(fires_at) if fires_at <= now));
+
+            *timers = running;
+
+            let tooltip = timers.iter()
+                .map(|timer| format!("{}: {}", timer.title(), timer.status(now)))
+                .collect::<Vec<_>>();
+
+            let tooltip = if tooltip.is_empty() {
+                None
+            } else {
+                Some(tooltip.join("\n"))
+            };
+
+            (fired, tooltip)
+        };
+
+        for timer in fired {
+            tracing::info!("{} went off: {}", timer.kind.label(), timer.title());
+
+            self.repository.remove_timer(&timer.id).await?;
+
+            let title = match timer.kind {
+                TimerKind::Timer => format!("Timer for {} is done", timer.name),
+                TimerKind::Stopwatch => unreachable!(),
+                TimerKind::Alarm => format!("Alarm for {}", timer.name),
+            };
+
+            let body = format!("Started at {}", format_local_time(timer.started_at));
+
+            self.notifications.show(title, Some(body));
+
+            if let Some(sound) = &sound {
+                play_sound(sound);
+            }
+        }
+
+        let changed = {
+            let mut last_tooltip = self.last_tooltip.lock().unwrap();
+
+            let changed = *last_tooltip != tooltip;
+
+            *last_tooltip = tooltip.clone();
+
+            changed
+        };
+
+        if changed {
+            if let Err(err) = self.frontend_api.set_tray_tooltip(tooltip).await {
+                tracing::warn!("Unable to update tray tooltip: {:?}", err);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// returns kind, name and time when new timer goes off
+fn parse_start_action(text: &str) -> Option<(TimerKind, String, Option<i64>)> {
+    let text = text.strip_prefix("set ")
+        .or_else(|| text.strip_prefix("start "))
+        .unwrap_or(text)
+        .trim();
+
+    if text == "stopwatch" {
+        return Some((TimerKind::Stopwatch, TimerKind::Stopwatch.label().to_string(), None));
+    }
+
+    if let Some(duration) = text.strip_prefix("timer ") {
+        let seconds = parse_duration(duration.trim())?;
+
+        return Some((TimerKind::Timer, format_duration(seconds), Some(now_millis() + seconds * 1000)));
+    }
+
+    if let Some(time) = text.strip_prefix("alarm ") {
+        let time = parse_time(time.trim())?;
+        let fires_at = next_occurrence(time)?;
+
+        return Some((TimerKind::Alarm, time.format("%H:%M").to_string(), Some(fires_at.timestamp_millis())));
+    }
+
+    None
+}
+
+// "10m", "1h30m", "1h 30m", "90s" or "5 min", number without unit is minutes
+fn parse_duration(text: &str) -> Option<i64> {
+    let text: String = text.chars()
+        .filter(|char| !char.is_whitespace())
+        .collect();
+
+    let mut chars = text.chars().peekable();
+    let mut total = 0;
+    let mut segments = 0;
+
+    while chars.peek().is_some() {
+        let mut number = String::new();
+        while let Some(char) = chars.next_if(|char| char.is_ascii_digit()) {
+            number.push(char);
+        }
+
+        let mut unit = String::new();
+        while let Some(char) = chars.next_if(|char| char.is_ascii_alphabetic()) {
+            unit.push(char);
+        }
+
+        let number: i64 = number.parse().ok()?;
+
+        let multiplier = match unit.as_str() {
+            "" if segments == 0 && chars.peek().is_none() => 60,
+            "s" | "sec" | "secs" | "second" | "seconds" => 1,
+            "m" | "min" | "mins" | "minute" | "minutes" => 60,
+            "h" | "hr" | "hrs" | "hour" | "hours" => 60 * 60,
+            _ => return None
+        };
+
+        total += number.checked_mul(multiplier)?;
+        segments += 1;
+    }
+
+    if total == 0 {
+        return None;
+    }
+
+    Some(total)
+}
+
+// "7:30", "19:45", "7pm" or "7:30 am"
+fn parse_time(text: &str) -> Option<NaiveTime> {
+    let text = text.replace(' ', "");
+
+    let (text, pm) = if let Some(text) = text.strip_suffix("pm") {
+        (text, Some(true))
+    } else if let Some(text) = text.strip_suffix("am") {
+        (text, Some(false))
+    } else {
+        (text.as_str(), None)
+    };
+
+    let (hour, minute) = match text.split_once(':') {
+        Some((hour, minute)) => (hour.parse::<u32>().ok()?, minute.parse::<u32>().ok()?),
+        None if pm.is_some() => (text.parse::<u32>().ok()?, 0),
+        None => return None,
+    };
+
+    let hour = match pm {
+        Some(_) if hour == 0 || hour > 12 => return None,
+        Some(true) => hour % 12 + 12,
+        Some(false) => hour % 12,
+        None => hour,
+    };
+
+    NaiveTime::from_hms_opt(hour, minute, 0)
+}
+
+fn next_occurrence(time: NaiveTime) -> Option<DateTime<Local>> {
+    let now = Local::now();
+
+    let today = Local.from_local_datetime(&now.date_naive().and_time(time)).earliest()?;
+
+    if today > now {
+        return Some(today);
+    }
+
+    let tomorrow = now.date_naive().succ_opt()?.and_time(time);
+
+    Local.from_local_datetime(&tomorrow).earliest()
+}
+
+fn format_duration(seconds: i64) -> String {
+    let hours = seconds / 3600;
+    let minutes = seconds % 3600 / 60;
+    let seconds = seconds % 60;
+
+    [(hours, "h"), (minutes, "m"), (seconds, "s")]
+        .into_iter()
+        .filter(|(value, _)| *value > 0)
+        .map(|(value, unit)| format!("{}{}", value, unit))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn format_countdown(millis: i64) -> String {
+    let seconds = millis.max(0) / 1000;
+
+    let hours = seconds / 3600;
+    let minutes = seconds % 3600 / 60;
+    let seconds = seconds % 60;
+
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{:02}:{:02}", minutes, seconds)
+    }
+}
+
+fn format_local_time(millis: i64) -> String {
+    match Local.timestamp_millis_opt(millis).single() {
+        Some(time) => time.format("%H:%M").to_string(),
+        None => "-".to_string(),
+    }
+}
+
+fn now_millis() -> i64 {
+    Utc::now().timestamp_millis()
+}
+
+// played using command line player available on the platform, so no audio stack is needed in server
+fn play_sound(path: &Path) {
+    #[cfg(target_os = "linux")]
+    let mut command = {
+        let mut command = Command::new("paplay");
+        command.arg(path);
+        command
+    };
+
+    #[cfg(target_os = "macos")]
+    let mut command = {
+        let mut command = Command::new("afplay");
+        command.arg(path);
+        command
+    };
+
+    #[cfg(target_os = "windows")]
+    let mut command = {
+        let mut command = Command::new("powershell");
+        command.arg("-NoProfile")
+            .arg("-Command")
+            .arg(format!("(New-Object Media.SoundPlayer '{}').PlaySync()", path.display()));
+        command
+    };
+
+    match command.spawn() {
+        Ok(mut child) => {
+            std::thread::spawn(move || {
+                if let Err(err) = child.wait() {
+                    tracing::warn!("Unable to wait for sound player: {:?}", err);
+                }
+            });
+        }
+        Err(err) => {
+            tracing::warn!("Unable to play timer sound {:?}: {:?}", path, err);
+        }
+    }
+}
+
+fn timer_search_result(entrypoint_id: String, entrypoint_name: String, entrypoint_subtitle: Option<String>, kind: TimerKind) -> SearchResult {
+    SearchResult {
+        plugin_id: PluginId::from_string(TIMERS_PLUGIN_ID),
+        plugin_name: TIMERS_PLUGIN_NAME.to_string(),
+        entrypoint_id: EntrypointId::from_string(entrypoint_id),
+        entrypoint_name,
+        entrypoint_name_highlights: vec![],
+        entrypoint_subtitle,
+        entrypoint_matched_keywords: vec![],
+        entrypoint_generator_name: None,
+        entrypoint_icon: None,
+        entrypoint_type: SearchResultEntrypointType::Command,
+        entrypoint_actions: vec![],
+        entrypoint_accessories: vec![
+            SearchResultAccessory::IconAccessory {
+                icon: kind.icon(),
+                tooltip: None,
+            }
+        ],
+        entrypoint_arguments: vec![],
+        entrypoint_file: None,
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use gauntlet_common::model::{EntrypointId, PluginId, SettingsEntrypointUsage, SettingsUnusedPlugin, SettingsUsageStats};
+
+use crate::plugins::data_db_repository::DataDbRepository;
+
+// searches older than that are not considered to be part of the same search session as the launch
+const SEARCH_SESSION_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+// launch counts and search-to-launch times are only stored locally
+#[derive(Clone)]
+pub struct UsageStats {
+    repository: DataDbRepository,
+    // time of the first non-empty search since last launch
+    search_started: Arc<Mutex<Option<Instant>>>,
+}
+
+impl UsageStats {
+    pub fn new(repository: DataDbRepository) -> Self {
+        Self {
+            repository,
+            search_started: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub fn mark_search(&self, text: &str) {
+        if text.trim().is_empty() {
+            return;
+        }
+
+        let mut search_started = self.search_started.lock().unwrap();
+
+        let expired = search_started.is_some_and(|search_started| search_started.elapsed() > SEARCH_SESSION_TIMEOUT);
+
+        if search_started.is_none() || expired {
+            *search_started = Some(Instant::now());
+        }
+    }
+
+    pub async fn record_launch(&self, plugin_id: &PluginId, entrypoint_id: &EntrypointId) -> anyhow::Result<()> {
+        let search_to_launch = self.search_started.lock()
+            .unwrap()
+            .take()
+            .map(|search_started| search_started.elapsed())
+            .filter(|search_to_launch| *search_to_launch <= SEARCH_SESSION_TIMEOUT);
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)?
+            .as_secs() as i64;
+
+        self.repository.record_launch(
+            &plugin_id.to_string(),
+            &entrypoint_id.to_string(),
+            now,
+            search_to_launch.map(|search_to_launch| search_to_launch.as_micros() as i64)
+        ).await
+    }
+
+    pub async fn stats(&self) -> anyhow::Result<SettingsUsageStats> {
+        let plugins = self.repository.list_plugins_and_entrypoints()
+            .await?;
+
+        let plugin_names: HashMap<_, _> = plugins.iter()
+            .map(|(plugin, _)| (plugin.id.clone(), plugin.name.clone()))
+            .collect();
+
+        let entrypoint_names: HashMap<_, _> = plugins.iter()
+            .flat_map(|(_, entrypoints)| entrypoints)
+            .map(|entrypoint| ((entrypoint.plugin_id.clone(), entrypoint.id.clone()), entrypoint.name.clone()))
+            .collect();
+
+        let mut search_to_launch_count = 0;
+        let mut search_to_launch_total_micros = 0;
+
+        // stats of removed plugins and entrypoints are kept until purged, but not shown
+        let entrypoints: Vec<_> = self.repository.list_usage_stats()
+            .await?
+            .into_iter()
+            .filter_map(|stats| {
+                let plugin_name = plugin_names.get(&stats.plugin_id)?.clone();
+                let entrypoint_name = entrypoint_names.get(&(stats.plugin_id.clone(), stats.entrypoint_id.clone()))?.clone();
+
+                search_to_launch_count += stats.search_to_launch_count;
+                search_to_launch_total_micros += stats.search_to_launch_total_micros;
+
+                Some(SettingsEntrypointUsage {
+                    plugin_id: PluginId::from_string(stats.plugin_id),
+                    plugin_name,
+                    entrypoint_id: EntrypointId::from_string(stats.entrypoint_id),
+                    entrypoint_name,
+                    launch_count: stats.launch_count as u64,
+                    average_search_to_launch: average(stats.search_to_launch_count, stats.search_to_launch_total_micros),
+                })
+            })
+            .collect();
+
+        let unused_plugins = plugins.into_iter()
+            .filter(|(plugin, _)| !entrypoints.iter().any(|entrypoint| entrypoint.plugin_id.to_string() == plugin.id))
+            .map(|(plugin, _)| SettingsUnusedPlugin {
+                plugin_id: PluginId::from_string(plugin.id),
+                plugin_name: plugin.name,
+            })
+            .collect();
+
+        Ok(SettingsUsageStats {
+            entrypoints,
+            unused_plugins,
+            average_search_to_launch: average(search_to_launch_count, search_to_launch_total_micros),
+        })
+    }
+
+    pub async fn clear(&self) -> anyhow::Result<()> {
+        tracing::info!("Removing all usage stats");
+
+        self.repository.clear_usage_stats().await
+    }
+}
+
+fn average(count: i64, total_micros: i64) -> Option<Duration> {
+    if count == 0 {
+        None
+    } else {
+        Some(Duration::from_micros((total_micros / count) as u64))
+    }
+}
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::{Mutex, MutexGuard};
+
+use gauntlet_common::model::{EntrypointId, PhysicalShortcut, RootWidget, UiRenderLocation};
+
+// pending renders are sent to frontend once per animation frame
+pub const VIEW_UPDATE_FLUSH_INTERVAL: Duration = Duration::from_millis(16);
+
+pub struct PendingRender {
+    pub entrypoint_id: EntrypointId,
+    pub entrypoint_name: String,
+    pub render_location: UiRenderLocation,
+    pub top_level_view: bool,
+    pub breadcrumbs: Vec<String>,
+    pub key_handlers: Vec<PhysicalShortcut>,
+    pub container: RootWidget,
+}
+
+// every render replaces whole view at its location, so when plugin renders faster than
+// frontend is able to show, only the latest render for each location needs to be sent.
+// lock is held while pending renders are sent, other view updates wait for it and
+// flush before being sent themselves, so frontend receives updates in the same order
+#[derive(Clone)]
+pub struct ViewUpdates {
+    inner: Arc<Mutex<ViewUpdatesInner>>,
+}
+
+pub struct ViewUpdatesInner {
+    renders: Vec<PendingRender>,
+    flush_scheduled: bool,
+}
+
+impl ViewUpdates {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(ViewUpdatesInner {
+                renders: vec![],
+                flush_scheduled: false,
+            })),
+        }
+    }
+
+    // returns true if flush needs to be scheduled
+    pub async fn push(&self, render: PendingRender) -> bool {
+        let mut inner = self.inner.lock().await;
+
+        let existing = inner.renders.iter_mut()
+            .find(|pending| pending.render_location == render.render_location);
+
+        match existing {
+            Some(existing) => *existing = render,
+            None => inner.renders.push(render),
+        }
+
+        !std::mem::replace(&mut inner.flush_scheduled, true)
+    }
+
+    pub async fn lock(&self) -> MutexGuard<'_, ViewUpdatesInner> {
+        self.inner.lock().await
+    }
+}
+
+impl ViewUpdatesInner {
+    pub fn take(&mut self) -> Vec<PendingRender> {
+        self.flush_scheduled = false;
+
+        std::mem::take(&mut self.renders)
+    }
+}
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use std::rc::Rc;
 use std::sync::Arc;
 use gauntlet_common::{settings_env_data_to_string, SettingsEnvData};
-use gauntlet_common::model::{DownloadStatus, EntrypointId, PluginId, PluginPreferenceUserData, SettingsPlugin, UiPropertyValue, SearchResult, UiWidgetId, PhysicalKey, PhysicalShortcut, LocalSaveData, SettingsTheme, WindowPositionMode};
+use gauntlet_common::model::{Density, DevConsoleEvent, DownloadStatus, EntrypointId, LocalSaveData, PhysicalKey, PhysicalShortcut, PluginId, PluginPreferenceUserData, SearchResult, SearchResultsSort, ServerMetrics, SettingsCrashReport, SettingsPlugin, SettingsPluginDiskUsage, SettingsQuicklink, SettingsTheme, SettingsUsageStats, UiPropertyValue, UiRendererInfo, UiScale, UiWidgetId, WindowPositionMode};
 use gauntlet_common::rpc::backend_server::BackendServer;
 
 use crate::plugins::ApplicationManager;
@@ -28,8 +28,27 @@ impl BackendServer for BackendServerImpl {
         self.application_manager.show_window().await
     }
 
+    async fn hide_window(&self) -> anyhow::Result<()> {
+        self.application_manager.hide_window().await
+    }
+
+    async fn toggle_window(&self) -> anyhow::Result<()> {
+        self.application_manager.toggle_window().await
+    }
+
+    async fn handle_deep_link(&self, uri: String) -> anyhow::Result<()> {
+        let result = self.application_manager.handle_deep_link(&uri)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'handle_deep_link' request {:?}", err)
+        }
+
+        result
+    }
+
     async fn show_settings_window(&self) -> anyhow::Result<()> {
-        self.application_manager.handle_open_settings_window();
+        self.application_manager.handle_open_settings_window().await;
 
         Ok(())
     }
@@ -56,6 +75,83 @@ impl BackendServer for BackendServerImpl {
         Ok(())
     }
 
+    async fn set_plugin_priority(&self, plugin_id: PluginId, priority: f64) -> anyhow::Result<()> {
+        let result = self.application_manager.set_plugin_priority(plugin_id, priority)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'set_plugin_priority' request {:?}", err)
+        }
+
+        Ok(())
+    }
+
+    async fn set_plugin_notifications_enabled(&self, plugin_id: PluginId, enabled: bool) -> anyhow::Result<()> {
+        let result = self.application_manager.set_plugin_notifications_enabled(plugin_id, enabled)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'set_plugin_notifications_enabled' request {:?}", err)
+        }
+
+        Ok(())
+    }
+
+    async fn set_plugin_environment(&self, plugin_id: PluginId, environment: HashMap<String, String>) -> anyhow::Result<()> {
+        let result = self.application_manager.set_plugin_environment(plugin_id, environment)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'set_plugin_environment' request {:?}", err)
+        }
+
+        Ok(())
+    }
+
+    async fn set_plugin_filesystem_roots(&self, plugin_id: PluginId, filesystem_roots: Vec<String>) -> anyhow::Result<()> {
+        let result = self.application_manager.set_plugin_filesystem_roots(plugin_id, filesystem_roots)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'set_plugin_filesystem_roots' request {:?}", err)
+        }
+
+        Ok(())
+    }
+
+    async fn plugin_disk_usage(&self, plugin_id: PluginId) -> anyhow::Result<SettingsPluginDiskUsage> {
+        let result = self.application_manager.plugin_disk_usage(plugin_id)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'plugin_disk_usage' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn clear_plugin_cache(&self, plugin_id: PluginId) -> anyhow::Result<()> {
+        let result = self.application_manager.clear_plugin_cache(plugin_id)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'clear_plugin_cache' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn clear_plugin_data(&self, plugin_id: PluginId) -> anyhow::Result<()> {
+        let result = self.application_manager.clear_plugin_data(plugin_id)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'clear_plugin_data' request {:?}", err)
+        }
+
+        result
+    }
+
     async fn set_entrypoint_state(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, enabled: bool) -> anyhow::Result<()> {
         let result = self.application_manager.set_entrypoint_state(plugin_id, entrypoint_id, enabled)
             .await;
@@ -67,6 +163,28 @@ impl BackendServer for BackendServerImpl {
         Ok(())
     }
 
+    async fn set_entrypoint_hidden(&self, plugin_id: PluginId, entrypoint_id: EntrypointId, hidden: bool) -> anyhow::Result<()> {
+        let result = self.application_manager.set_entrypoint_hidden(plugin_id, entrypoint_id, hidden)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'set_entrypoint_hidden' request {:?}", err)
+        }
+
+        Ok(())
+    }
+
+    async fn set_entrypoint_order(&self, plugin_id: PluginId, entrypoint_ids: Vec<EntrypointId>) -> anyhow::Result<()> {
+        let result = self.application_manager.set_entrypoint_order(plugin_id, entrypoint_ids)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'set_entrypoint_order' request {:?}", err)
+        }
+
+        Ok(())
+    }
+
     async fn set_global_shortcut(&self, shortcut: Option<PhysicalShortcut>) -> anyhow::Result<()> {
         let result = self.application_manager.set_global_shortcut(shortcut)
             .await;
@@ -102,6 +220,42 @@ impl BackendServer for BackendServerImpl {
         self.application_manager.get_window_position_mode().await
     }
 
+    async fn set_window_overlay(&self, enabled: bool) -> anyhow::Result<()> {
+        self.application_manager.set_window_overlay(enabled).await
+    }
+
+    async fn get_window_overlay(&self) -> anyhow::Result<bool> {
+        self.application_manager.get_window_overlay().await
+    }
+
+    async fn set_density(&self, density: Density) -> anyhow::Result<()> {
+        self.application_manager.set_density(density).await
+    }
+
+    async fn get_density(&self) -> anyhow::Result<Density> {
+        self.application_manager.get_density().await
+    }
+
+    async fn renderer_info(&self) -> anyhow::Result<Option<UiRendererInfo>> {
+        Ok(self.application_manager.renderer_info())
+    }
+
+    async fn set_ui_scale(&self, scale: UiScale) -> anyhow::Result<()> {
+        self.application_manager.set_ui_scale(scale).await
+    }
+
+    async fn get_ui_scale(&self) -> anyhow::Result<UiScale> {
+        self.application_manager.get_ui_scale().await
+    }
+
+    async fn set_search_results_sort(&self, sort: SearchResultsSort) -> anyhow::Result<()> {
+        self.application_manager.set_search_results_sort(sort).await
+    }
+
+    async fn get_search_results_sort(&self) -> anyhow::Result<SearchResultsSort> {
+        self.application_manager.get_search_results_sort().await
+    }
+
     async fn set_preference_value(&self, plugin_id: PluginId, entrypoint_id: Option<EntrypointId>, preference_id: String, preference_value: PluginPreferenceUserData) -> anyhow::Result<()> {
         let result = self.application_manager.set_preference_value(plugin_id, entrypoint_id, preference_id, preference_value)
             .await;
@@ -139,10 +293,219 @@ impl BackendServer for BackendServerImpl {
         Ok(())
     }
 
+    async fn quicklinks(&self) -> anyhow::Result<Vec<SettingsQuicklink>> {
+        Ok(self.application_manager.quicklinks())
+    }
+
+    async fn save_quicklink(&self, id: Option<String>, name: String, url: String, bang: Option<String>, fallback: bool) -> anyhow::Result<()> {
+        let result = self.application_manager.save_quicklink(id, name, url, bang, fallback)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'save_quicklink' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn remove_quicklink(&self, id: String) -> anyhow::Result<()> {
+        let result = self.application_manager.remove_quicklink(id)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'remove_quicklink' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn profiles(&self) -> anyhow::Result<Vec<String>> {
+        Ok(self.application_manager.profiles())
+    }
+
+    async fn save_profile(&self, name: String) -> anyhow::Result<()> {
+        let result = self.application_manager.save_profile(name)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'save_profile' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn switch_profile(&self, name: String) -> anyhow::Result<()> {
+        let result = self.application_manager.switch_profile(name)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'switch_profile' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn remove_profile(&self, name: String) -> anyhow::Result<()> {
+        let result = self.application_manager.remove_profile(name)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'remove_profile' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn recent_log_lines(&self, limit: u32) -> anyhow::Result<Vec<String>> {
+        let result = self.application_manager.recent_log_lines(limit);
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'recent_log_lines' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn reindex(&self) -> anyhow::Result<()> {
+        let result = self.application_manager.reindex()
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'reindex' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn set_crash_reports_enabled(&self, enabled: bool) -> anyhow::Result<()> {
+        let result = self.application_manager.set_crash_reports_enabled(enabled)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'set_crash_reports_enabled' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn get_crash_reports_enabled(&self) -> anyhow::Result<bool> {
+        let result = self.application_manager.get_crash_reports_enabled()
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'get_crash_reports_enabled' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn crash_reports(&self) -> anyhow::Result<Vec<SettingsCrashReport>> {
+        let result = self.application_manager.crash_reports();
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'crash_reports' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn remove_crash_report(&self, id: String) -> anyhow::Result<()> {
+        let result = self.application_manager.remove_crash_report(id);
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'remove_crash_report' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn submit_crash_report(&self, id: String) -> anyhow::Result<()> {
+        let result = self.application_manager.submit_crash_report(id);
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'submit_crash_report' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn set_usage_stats_enabled(&self, enabled: bool) -> anyhow::Result<()> {
+        let result = self.application_manager.set_usage_stats_enabled(enabled)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'set_usage_stats_enabled' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn get_usage_stats_enabled(&self) -> anyhow::Result<bool> {
+        let result = self.application_manager.get_usage_stats_enabled()
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'get_usage_stats_enabled' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn usage_stats(&self) -> anyhow::Result<SettingsUsageStats> {
+        let result = self.application_manager.usage_stats()
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'usage_stats' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn clear_usage_stats(&self) -> anyhow::Result<()> {
+        let result = self.application_manager.clear_usage_stats()
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'clear_usage_stats' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn set_autostart_enabled(&self, enabled: bool) -> anyhow::Result<()> {
+        let result = self.application_manager.set_autostart_enabled(enabled)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'set_autostart_enabled' request {:?}", err)
+        }
+
+        result
+    }
+
+    async fn get_autostart_enabled(&self) -> anyhow::Result<bool> {
+        let result = self.application_manager.get_autostart_enabled();
+
+        if let Err(err) = &result {
+            tracing::warn!(target = "rpc", "error occurred when handling 'get_autostart_enabled' request {:?}", err)
+        }
+
+        result
+    }
+
     async fn save_local_plugin(&self, path: String) -> anyhow::Result<LocalSaveData> {
         let result = self.application_manager.save_local_plugin(&path)
             .await?;
 
         Ok(result)
     }
+
+    async fn dev_console(&self, plugin_id: PluginId) -> anyhow::Result<tokio::sync::mpsc::Receiver<DevConsoleEvent>> {
+        Ok(self.application_manager.dev_console(plugin_id))
+    }
+
+    async fn metrics(&self) -> anyhow::Result<ServerMetrics> {
+        Ok(self.application_manager.metrics())
+    }
 }
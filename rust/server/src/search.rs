@@ -1,44 +1,82 @@
 use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::io::ErrorKind;
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
+use bincode::{Decode, Encode};
 use tantivy::{doc, Index, IndexReader, IndexWriter, ReloadPolicy, Searcher};
 use tantivy::collector::TopDocs;
 use tantivy::query::{AllQuery, BooleanQuery, FuzzyTermQuery, Query, RegexQuery, TermQuery};
 use tantivy::schema::*;
-use tantivy::tokenizer::TokenizerManager;
-use gauntlet_common::model::{EntrypointId, PhysicalShortcut, PluginId, SearchResult, SearchResultAccessory, SearchResultEntrypointAction, SearchResultEntrypointActionType, SearchResultEntrypointType};
+use tantivy::tokenizer::{AsciiFoldingFilter, LowerCaser, RemoveLongFilter, SimpleTokenizer, TextAnalyzer, TokenizerManager};
+use pinyin::ToPinyin;
+use serde::Deserialize;
+use wana_kana::ConvertJapanese;
+use gauntlet_common::model::{EntrypointArgument, EntrypointId, PhysicalShortcut, PluginId, SearchFilter, SearchResult, SearchResultAccessory, SearchResultHighlight, SearchResultMatchedKeyword, SearchResultEntrypointAction, SearchResultEntrypointActionType, SearchResultEntrypointType, SearchResultsSort};
 use gauntlet_common::rpc::frontend_api::FrontendApi;
 
+// lowercases and removes diacritics, so that "cafe" matches "Café"
+const TOKENIZER_NAME: &str = "gauntlet";
+
+// additional latin spelling of entrypoint names written in non-latin scripts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum SearchTransliteration {
+    // pinyin, both full and initials, e.g. "weixin" and "wx"
+    #[serde(rename = "zh")]
+    Chinese,
+    // romaji, only for names written in kana
+    #[serde(rename = "ja")]
+    Japanese,
+}
+
 #[derive(Clone)]
 pub struct SearchIndex {
     frontend_api: FrontendApi,
     index: Index,
     index_reader: IndexReader,
     index_writer_mutex: Arc<Mutex<()>>,
+    index_file: PathBuf,
+    transliteration: Arc<Mutex<Vec<SearchTransliteration>>>,
+    // set by user in settings, plugins without it have priority of 1.0
+    plugin_priority: Arc<Mutex<HashMap<PluginId, f64>>>,
 
     entrypoint_data: Arc<Mutex<HashMap<PluginId, HashMap<EntrypointId, EntrypointData>>>>,
 
     entrypoint_name: Field,
+    entrypoint_name_transliterated: Field,
+    entrypoint_keywords: Field,
     entrypoint_id: Field,
     plugin_name: Field,
     plugin_id: Field,
 }
 
+// stored on disk, so that search works right after start without waiting for plugins to index their entrypoints
+#[derive(Encode, Decode)]
 struct EntrypointData {
+    entrypoint_name: String,
+    plugin_name: String,
+    entrypoint_subtitle: Option<String>,
+    entrypoint_keywords: Vec<String>,
     entrypoint_generator_name: Option<String>,
     entrypoint_type: SearchResultEntrypointType,
-    icon: Option<bytes::Bytes>,
+    icon: Option<Vec<u8>>,
     frecency: f64,
     actions: Vec<EntrypointActionData>,
     accessories: Vec<SearchResultAccessory>,
+    arguments: Vec<EntrypointArgument>,
+    hidden: bool,
+    preview: Option<String>,
+    file: Option<String>,
 }
 
+#[derive(Encode, Decode)]
 struct EntrypointActionData {
     label: String,
     action_type: EntrypointActionType,
     shortcut: Option<PhysicalShortcut>,
 }
 
+#[derive(Encode, Decode)]
 enum EntrypointActionType {
     Command,
     View,
@@ -48,12 +86,20 @@ enum EntrypointActionType {
 pub struct SearchIndexItem {
     pub entrypoint_type: SearchResultEntrypointType,
     pub entrypoint_name: String,
+    pub entrypoint_subtitle: Option<String>,
+    pub entrypoint_keywords: Vec<String>,
     pub entrypoint_generator_name: Option<String>,
     pub entrypoint_id: EntrypointId,
     pub entrypoint_icon: Option<bytes::Bytes>,
     pub entrypoint_frecency: f64,
     pub entrypoint_actions: Vec<SearchIndexItemAction>,
     pub entrypoint_accessories: Vec<SearchResultAccessory>,
+    pub entrypoint_arguments: Vec<EntrypointArgument>,
+    // still indexed, so that hiding and showing entrypoint doesn't require reindexing
+    pub entrypoint_hidden: bool,
+    // markdown shown in preview pane of main view, not sent with search results
+    pub entrypoint_preview: Option<String>,
+    pub entrypoint_file: Option<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -71,113 +117,371 @@ pub enum SearchIndexItemActionActionType {
 
 
 impl SearchIndex {
-    pub fn create_index(frontend_api: FrontendApi) -> tantivy::Result<Self> {
+    pub fn create_index(frontend_api: FrontendApi, index_file: PathBuf) -> anyhow::Result<Self> {
         let schema = {
             let mut schema_builder = Schema::builder();
 
-            schema_builder.add_text_field("entrypoint_name", TEXT | STORED);
+            let text = TextOptions::default()
+                .set_indexing_options(
+                    TextFieldIndexing::default()
+                        .set_tokenizer(TOKENIZER_NAME)
+                        .set_index_option(IndexRecordOption::WithFreqsAndPositions)
+                );
+
+            schema_builder.add_text_field("entrypoint_name", text.clone() | STORED);
+            schema_builder.add_text_field("entrypoint_name_transliterated", text.clone());
+            schema_builder.add_text_field("entrypoint_keywords", text.clone());
             schema_builder.add_text_field("entrypoint_id", STRING | STORED);
-            schema_builder.add_text_field("plugin_name", TEXT | STORED);
+            schema_builder.add_text_field("plugin_name", text | STORED);
             schema_builder.add_text_field("plugin_id", STRING | STORED);
 
             schema_builder.build()
         };
 
         let entrypoint_name = schema.get_field("entrypoint_name").expect("entrypoint_name field should exist");
+        let entrypoint_name_transliterated = schema.get_field("entrypoint_name_transliterated").expect("entrypoint_name_transliterated field should exist");
+        let entrypoint_keywords = schema.get_field("entrypoint_keywords").expect("entrypoint_keywords field should exist");
         let entrypoint_id = schema.get_field("entrypoint_id").expect("entrypoint_id field should exist");
         let plugin_name = schema.get_field("plugin_name").expect("plugin_name field should exist");
         let plugin_id = schema.get_field("plugin_id").expect("plugin_id field should exist");
 
         let index = Index::create_in_ram(schema.clone());
 
+        index.tokenizers().register(TOKENIZER_NAME, text_analyzer());
+
         let index_reader = index
             .reader_builder()
             .reload_policy(ReloadPolicy::Manual)
             .try_into()?;
 
-        Ok(Self {
+        let search_index = Self {
             frontend_api,
             index,
             index_reader,
             index_writer_mutex: Arc::new(Mutex::new(())),
+            index_file,
+            transliteration: Arc::new(Mutex::new(vec![])),
+            plugin_priority: Arc::new(Mutex::new(HashMap::new())),
             entrypoint_data: Arc::new(Mutex::new(HashMap::new())),
             entrypoint_name,
+            entrypoint_name_transliterated,
+            entrypoint_keywords,
             entrypoint_id,
             plugin_name,
             plugin_id,
-        })
+        };
+
+        if let Err(err) = search_index.load() {
+            tracing::warn!("Unable to load search index from disk, it will be rebuilt by plugins: {:?}", err);
+
+            let _ = std::fs::remove_file(&search_index.index_file);
+        }
+
+        Ok(search_index)
     }
 
-    pub fn remove_for_plugin(&self, plugin_id: PluginId) -> tantivy::Result<()> {
+    fn load(&self) -> anyhow::Result<()> {
+        let bytes = match std::fs::read(&self.index_file) {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err.into()),
+        };
+
+        let (data, _): (HashMap<PluginId, HashMap<EntrypointId, EntrypointData>>, _) = bincode::decode_from_slice(&bytes, bincode::config::standard())?;
+
         // writer panics if another writer exists
         let _guard = self.index_writer_mutex.lock().expect("lock is poisoned");
         let mut entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
 
         let mut index_writer = self.index.writer::<TantivyDocument>(15_000_000)?;
 
-        index_writer.delete_query(Box::new(
-            TermQuery::new(Term::from_field_text(self.plugin_id, &plugin_id.to_string()), IndexRecordOption::Basic)
-        ))?;
+        for (plugin_id, entrypoints) in &data {
+            for (entrypoint_id, entrypoint) in entrypoints {
+                index_writer.add_document(self.create_document(plugin_id, entrypoint_id, entrypoint))?;
+            }
+        }
+
         index_writer.commit()?;
         self.index_reader.reload()?;
 
-        entrypoint_data.remove(&plugin_id);
+        tracing::debug!("Loaded search index for {} plugins from disk", data.len());
+
+        *entrypoint_data = data;
 
         Ok(())
     }
 
-    pub fn save_for_plugin(&self, plugin_id: PluginId, plugin_name: String, search_items: Vec<SearchIndexItem>, refresh_search_list: bool) -> tantivy::Result<()> {
-        tracing::debug!("Reloading search index for plugin {:?}", plugin_id);
+    fn persist(&self, entrypoint_data: &HashMap<PluginId, HashMap<EntrypointId, EntrypointData>>) {
+        let result = (|| -> anyhow::Result<()> {
+            let bytes = bincode::encode_to_vec(entrypoint_data, bincode::config::standard())?;
+
+            if let Some(parent) = self.index_file.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+
+            // written to temporary file first, so that index file is never left half-written
+            let temp_file = self.index_file.with_extension("tmp");
+
+            std::fs::write(&temp_file, bytes)?;
+            std::fs::rename(&temp_file, &self.index_file)?;
+
+            Ok(())
+        })();
+
+        if let Err(err) = result {
+            tracing::warn!("Unable to save search index to disk: {:?}", err)
+        }
+    }
+
+    fn create_document(&self, plugin_id: &PluginId, entrypoint_id: &EntrypointId, data: &EntrypointData) -> TantivyDocument {
+        let mut document = doc!(
+            self.entrypoint_name => data.entrypoint_name.clone(),
+            self.entrypoint_id => entrypoint_id.to_string(),
+            self.plugin_name => data.plugin_name.clone(),
+            self.plugin_id => plugin_id.to_string(),
+        );
+
+        for keyword in &data.entrypoint_keywords {
+            document.add_text(self.entrypoint_keywords, keyword);
+        }
+
+        let transliteration = self.transliteration.lock().expect("lock is poisoned");
+
+        for name in transliterate(&data.entrypoint_name, &transliteration) {
+            document.add_text(self.entrypoint_name_transliterated, name);
+        }
 
+        document
+    }
+
+    // documents of all entrypoints are recreated, because transliterated names are part of the document
+    pub fn set_transliteration(&self, transliteration: Vec<SearchTransliteration>) -> anyhow::Result<()> {
+        // writer panics if another writer exists
+        let _guard = self.index_writer_mutex.lock().expect("lock is poisoned");
+        let entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
+
+        {
+            let mut current = self.transliteration.lock().expect("lock is poisoned");
+
+            if *current == transliteration {
+                return Ok(())
+            }
+
+            *current = transliteration;
+        }
+
+        tracing::debug!("Transliteration changed, recreating search index documents");
+
+        let mut index_writer = self.index.writer::<TantivyDocument>(15_000_000)?;
+
+        index_writer.delete_all_documents()?;
+
+        for (plugin_id, entrypoints) in entrypoint_data.iter() {
+            for (entrypoint_id, entrypoint) in entrypoints {
+                index_writer.add_document(self.create_document(plugin_id, entrypoint_id, entrypoint))?;
+            }
+        }
+
+        index_writer.commit()?;
+        self.index_reader.reload()?;
+
+        Ok(())
+    }
+
+    fn entrypoint_query(&self, plugin_id: &PluginId, entrypoint_id: &EntrypointId) -> Box<dyn Query> {
+        Box::new(BooleanQuery::intersection(vec![
+            Box::new(TermQuery::new(Term::from_field_text(self.plugin_id, &plugin_id.to_string()), IndexRecordOption::Basic)),
+            Box::new(TermQuery::new(Term::from_field_text(self.entrypoint_id, &entrypoint_id.to_string()), IndexRecordOption::Basic)),
+        ]))
+    }
+
+    // documents of changed entrypoints are both removed and added
+    fn update_documents(&self, plugin_id: &PluginId, removed: &[EntrypointId], added: &[(&EntrypointId, &EntrypointData)]) -> anyhow::Result<()> {
+        if removed.is_empty() && added.is_empty() {
+            return Ok(())
+        }
+
+        let mut index_writer = self.index.writer::<TantivyDocument>(15_000_000)?;
+
+        for entrypoint_id in removed {
+            index_writer.delete_query(self.entrypoint_query(plugin_id, entrypoint_id))?;
+        }
+
+        for (entrypoint_id, entrypoint) in added {
+            index_writer.add_document(self.create_document(plugin_id, entrypoint_id, entrypoint))?;
+        }
+
+        index_writer.commit()?;
+        self.index_reader.reload()?;
+
+        Ok(())
+    }
+
+    // only affects ordering of results, so documents don't need to be recreated
+    pub fn set_plugin_priority(&self, plugin_id: PluginId, priority: f64) {
+        let mut plugin_priority = self.plugin_priority.lock().expect("lock is poisoned");
+
+        plugin_priority.insert(plugin_id, priority);
+    }
+
+    pub fn preview(&self, plugin_id: &PluginId, entrypoint_id: &EntrypointId) -> Option<String> {
+        let entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
+
+        entrypoint_data.get(plugin_id)
+            .and_then(|entrypoints| entrypoints.get(entrypoint_id))
+            .and_then(|entrypoint| entrypoint.preview.clone())
+    }
+
+    pub fn remove_for_plugin(&self, plugin_id: PluginId) -> anyhow::Result<()> {
         // writer panics if another writer exists
         let _guard = self.index_writer_mutex.lock().expect("lock is poisoned");
         let mut entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
 
+        if !entrypoint_data.contains_key(&plugin_id) {
+            return Ok(())
+        }
+
         let mut index_writer = self.index.writer::<TantivyDocument>(15_000_000)?;
 
         index_writer.delete_query(Box::new(
             TermQuery::new(Term::from_field_text(self.plugin_id, &plugin_id.to_string()), IndexRecordOption::Basic)
         ))?;
+        index_writer.commit()?;
+        self.index_reader.reload()?;
+
+        entrypoint_data.remove(&plugin_id);
+
+        self.persist(&entrypoint_data);
 
-        for search_item in &search_items {
-            index_writer.add_document(doc!(
-                self.entrypoint_name => search_item.entrypoint_name.clone(),
-                self.entrypoint_id => search_item.entrypoint_id.to_string(),
-                self.plugin_name => plugin_name.clone(),
-                self.plugin_id => plugin_id.to_string(),
-            ))?;
+        Ok(())
+    }
+
+    // removes plugins which don't exist anymore, e.g. index was saved before plugin was removed
+    pub fn retain_plugins(&self, plugin_ids: &HashSet<PluginId>) -> anyhow::Result<()> {
+        let removed = {
+            let entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
+
+            entrypoint_data.keys()
+                .filter(|plugin_id| !plugin_ids.contains(plugin_id))
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+
+        for plugin_id in removed {
+            self.remove_for_plugin(plugin_id)?;
         }
 
+        Ok(())
+    }
+
+    pub fn remove_all(&self) -> anyhow::Result<()> {
+        // writer panics if another writer exists
+        let _guard = self.index_writer_mutex.lock().expect("lock is poisoned");
+        let mut entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
+
+        let mut index_writer = self.index.writer::<TantivyDocument>(15_000_000)?;
+
+        index_writer.delete_all_documents()?;
         index_writer.commit()?;
         self.index_reader.reload()?;
 
-        let data = search_items.into_iter()
-            .map(|item| {
-                let actions = item.entrypoint_actions.into_iter()
-                    .map(|action| EntrypointActionData {
-                        label: action.label,
-                        action_type: match action.action_type {
-                            SearchIndexItemActionActionType::Command => EntrypointActionType::Command,
-                            SearchIndexItemActionActionType::View => EntrypointActionType::View,
-                        },
-                        shortcut: action.shortcut,
-                    })
-                    .collect();
+        entrypoint_data.clear();
 
-                let data = EntrypointData {
-                    entrypoint_generator_name: item.entrypoint_generator_name,
-                    entrypoint_type: item.entrypoint_type,
-                    icon: item.entrypoint_icon,
-                    frecency: item.entrypoint_frecency,
-                    actions,
-                    accessories: item.entrypoint_accessories,
-                };
+        self.persist(&entrypoint_data);
 
-                (item.entrypoint_id.clone(), data)
-            })
+        Ok(())
+    }
+
+    pub fn save_entrypoint(&self, plugin_id: PluginId, plugin_name: String, search_item: SearchIndexItem) -> anyhow::Result<()> {
+        // writer panics if another writer exists
+        let _guard = self.index_writer_mutex.lock().expect("lock is poisoned");
+        let mut entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
+
+        let entrypoint_id = search_item.entrypoint_id.clone();
+        let data = EntrypointData::new(plugin_name, search_item);
+
+        self.update_documents(&plugin_id, &[entrypoint_id.clone()], &[(&entrypoint_id, &data)])?;
+
+        entrypoint_data.entry(plugin_id)
+            .or_default()
+            .insert(entrypoint_id, data);
+
+        self.persist(&entrypoint_data);
+
+        Ok(())
+    }
+
+    pub fn remove_entrypoint(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> anyhow::Result<()> {
+        // writer panics if another writer exists
+        let _guard = self.index_writer_mutex.lock().expect("lock is poisoned");
+        let mut entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
+
+        let exists = entrypoint_data.get(&plugin_id)
+            .map(|plugin_data| plugin_data.contains_key(&entrypoint_id))
+            .unwrap_or(false);
+
+        if !exists {
+            return Ok(())
+        }
+
+        self.update_documents(&plugin_id, &[entrypoint_id.clone()], &[])?;
+
+        if let Some(plugin_data) = entrypoint_data.get_mut(&plugin_id) {
+            plugin_data.remove(&entrypoint_id);
+        }
+
+        self.persist(&entrypoint_data);
+
+        Ok(())
+    }
+
+    pub fn save_for_plugin(&self, plugin_id: PluginId, plugin_name: String, search_items: Vec<SearchIndexItem>, refresh_search_list: bool) -> anyhow::Result<()> {
+        tracing::debug!("Reloading search index for plugin {:?}", plugin_id);
+
+        // writer panics if another writer exists
+        let _guard = self.index_writer_mutex.lock().expect("lock is poisoned");
+        let mut entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
+
+        let data: HashMap<_, _> = search_items.into_iter()
+            .map(|item| (item.entrypoint_id.clone(), EntrypointData::new(plugin_name.clone(), item)))
             .collect();
 
-        entrypoint_data.insert(plugin_id.clone(), data);
+        let previous_data = entrypoint_data.remove(&plugin_id)
+            .unwrap_or_default();
+
+        // only entrypoints which were added, removed or changed their searchable text are reindexed
+        let removed = previous_data.iter()
+            .filter(|(entrypoint_id, previous)| {
+                data.get(entrypoint_id)
+                    .map(|current| !current.same_document(previous))
+                    .unwrap_or(true)
+            })
+            .map(|(entrypoint_id, _)| entrypoint_id.clone())
+            .collect::<Vec<_>>();
+
+        let added = data.iter()
+            .filter(|(entrypoint_id, current)| {
+                previous_data.get(entrypoint_id)
+                    .map(|previous| !current.same_document(previous))
+                    .unwrap_or(true)
+            })
+            .collect::<Vec<_>>();
+
+        let result = self.update_documents(&plugin_id, &removed, &added);
+
+        // keep in-memory data consistent with index even if update failed
+        match result {
+            Ok(()) => {
+                entrypoint_data.insert(plugin_id.clone(), data);
+            }
+            Err(err) => {
+                entrypoint_data.insert(plugin_id.clone(), previous_data);
+
+                return Err(err)
+            }
+        }
+
+        self.persist(&entrypoint_data);
 
         if refresh_search_list {
             let mut frontend_api = self.frontend_api.clone();
@@ -196,7 +500,7 @@ impl SearchIndex {
         Ok(())
     }
 
-    pub fn search(&self, query: &str) -> anyhow::Result<Vec<SearchResult>> {
+    pub fn search(&self, query: &str, filter: &SearchFilter, sort: &SearchResultsSort) -> anyhow::Result<Vec<SearchResult>> {
         let entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
 
         let searcher = self.index_reader.searcher();
@@ -204,15 +508,19 @@ impl SearchIndex {
         let query_parser = QueryParser::new(
             self.index.tokenizers().clone(),
             self.entrypoint_name,
+            self.entrypoint_name_transliterated,
+            self.entrypoint_keywords,
             self.plugin_name,
         );
 
+        let terms = query_parser.tokenize(query);
+
         let query = query_parser.create_query(query);
 
         let mut index = 0;
 
-        let fetch = std::iter::from_fn(|| -> Option<anyhow::Result<Vec<(SearchResult, f64)>>> {
-            let result = self.fetch(&entrypoint_data, &query, TopDocs::with_limit(20).and_offset(index * 20), &searcher);
+        let fetch = std::iter::from_fn(|| -> Option<anyhow::Result<Vec<(SearchResult, f32, f64)>>> {
+            let result = self.fetch(&entrypoint_data, &query, &query_parser, &terms, TopDocs::with_limit(20).and_offset(index * 20), &searcher);
 
             index += 1;
 
@@ -234,12 +542,38 @@ impl SearchIndex {
 
         let mut result = result.into_iter()
             .flatten()
+            .filter(|(item, _, _)| matches_search_filter(filter, item))
             .collect::<Vec<_>>();
 
-        result.sort_by(|(_, score_a), (_, score_b)| score_b.total_cmp(score_a));
+        let plugin_priority = self.plugin_priority.lock().expect("lock is poisoned");
+
+        let priority = |item: &SearchResult| plugin_priority.get(&item.plugin_id).cloned().unwrap_or(1.0);
+
+        // tantivy returns results ordered by relevance, sort is stable so that order is kept when priorities are the same
+        match sort {
+            SearchResultsSort::Relevance => {
+                result.sort_by(|(item_a, relevance_a, _), (item_b, relevance_b, _)| {
+                    let score_a = *relevance_a as f64 * priority(item_a);
+                    let score_b = *relevance_b as f64 * priority(item_b);
+
+                    score_b.total_cmp(&score_a)
+                });
+            }
+            SearchResultsSort::Frecency | SearchResultsSort::Plugin => {
+                // frecency is shifted, so that priority also affects entrypoints which were never used
+                result.sort_by(|(item_a, _, frecency_a), (item_b, _, frecency_b)| {
+                    let score_a = (frecency_a + 1.0) * priority(item_a);
+                    let score_b = (frecency_b + 1.0) * priority(item_b);
+
+                    score_b.total_cmp(&score_a)
+                });
+            }
+        }
+
+        drop(plugin_priority);
 
         let result = result.into_iter()
-            .map(|(item, _)| item)
+            .map(|(item, _, _)| item)
             .collect::<Vec<_>>();
 
         drop(entrypoint_data);
@@ -247,7 +581,40 @@ impl SearchIndex {
         Ok(result)
     }
 
-    fn fetch(&self, entrypoint_data: &HashMap<PluginId, HashMap<EntrypointId, EntrypointData>>, query: &dyn Query, collector: TopDocs, searcher: &Searcher) -> anyhow::Result<Vec<(SearchResult, f64)>> {
+    // results of search providers are not indexed, they are matched the same way as indexed entrypoints,
+    // every term has to be contained in entrypoint name, subtitle or plugin name
+    pub fn filter_provided_results(&self, query: &str, results: Vec<SearchResult>) -> Vec<SearchResult> {
+        let query_parser = QueryParser::new(
+            self.index.tokenizers().clone(),
+            self.entrypoint_name,
+            self.entrypoint_name_transliterated,
+            self.entrypoint_keywords,
+            self.plugin_name,
+        );
+
+        let terms = query_parser.tokenize(query);
+
+        if terms.is_empty() {
+            return results;
+        }
+
+        results.into_iter()
+            .filter(|item| {
+                query_parser.contains_terms(&item.entrypoint_name, &terms)
+                    || item.entrypoint_subtitle.as_ref().is_some_and(|subtitle| query_parser.contains_terms(subtitle, &terms))
+                    || query_parser.contains_terms(&item.plugin_name, &terms)
+            })
+            .map(|mut item| {
+                if item.entrypoint_name_highlights.is_empty() {
+                    item.entrypoint_name_highlights = query_parser.highlight(&item.entrypoint_name, &terms);
+                }
+
+                item
+            })
+            .collect()
+    }
+
+    fn fetch(&self, entrypoint_data: &HashMap<PluginId, HashMap<EntrypointId, EntrypointData>>, query: &dyn Query, query_parser: &QueryParser, terms: &[String], collector: TopDocs, searcher: &Searcher) -> anyhow::Result<Vec<(SearchResult, f32, f64)>> {
         let get_str_field = |retrieved_doc: &TantivyDocument, field: Field| -> String {
             retrieved_doc.get_first(field)
                 .unwrap_or_else(|| panic!("there should be a field with name {:?}", searcher.schema().get_field_name(field)))
@@ -258,7 +625,7 @@ impl SearchIndex {
 
         let result = searcher.search(query, &collector)?
             .into_iter()
-            .map(|(_score, doc_address)| {
+            .filter_map(|(score, doc_address)| {
                 let retrieved_doc = searcher.doc::<TantivyDocument>(doc_address)
                     .expect("index should contain just searched results");
 
@@ -273,6 +640,10 @@ impl SearchIndex {
                     .get(&entrypoint_id)
                     .expect("Entrypoint should always exist in plugin in entrypoint data");
 
+                if entrypoint_data.hidden {
+                    return None
+                }
+
                 let entrypoint_actions = entrypoint_data.actions.iter()
                     .map(|data| SearchResultEntrypointAction {
                         action_type: match data.action_type {
@@ -288,19 +659,42 @@ impl SearchIndex {
                     .cloned()
                     .collect();
 
+                let entrypoint_matched_keywords = entrypoint_data.entrypoint_keywords.iter()
+                    .filter_map(|keyword| {
+                        let highlights = query_parser.highlight(keyword, terms);
+
+                        if highlights.is_empty() {
+                            None
+                        } else {
+                            Some(SearchResultMatchedKeyword {
+                                keyword: keyword.clone(),
+                                highlights,
+                            })
+                        }
+                    })
+                    .collect();
+
+                // empty if entrypoint was found by transliterated name, keyword or plugin name
+                let entrypoint_name_highlights = query_parser.highlight(&entrypoint_name, terms);
+
                 let result_item = SearchResult {
                     entrypoint_type: entrypoint_data.entrypoint_type.clone(),
                     entrypoint_name,
+                    entrypoint_name_highlights,
+                    entrypoint_subtitle: entrypoint_data.entrypoint_subtitle.clone(),
+                    entrypoint_matched_keywords,
                     entrypoint_generator_name: entrypoint_data.entrypoint_generator_name.clone(),
                     entrypoint_id,
-                    entrypoint_icon: entrypoint_data.icon.clone(),
+                    entrypoint_icon: entrypoint_data.icon.as_deref().map(bytes::Bytes::copy_from_slice),
                     plugin_name,
                     plugin_id,
                     entrypoint_actions,
                     entrypoint_accessories,
+                    entrypoint_arguments: entrypoint_data.arguments.clone(),
+                    entrypoint_file: entrypoint_data.file.clone(),
                 };
 
-                (result_item, entrypoint_data.frecency)
+                Some((result_item, score, entrypoint_data.frecency))
             })
             .collect::<Vec<_>>();
 
@@ -308,17 +702,89 @@ impl SearchIndex {
     }
 }
 
+impl EntrypointData {
+    fn new(plugin_name: String, item: SearchIndexItem) -> Self {
+        let actions = item.entrypoint_actions.into_iter()
+            .map(|action| EntrypointActionData {
+                label: action.label,
+                action_type: match action.action_type {
+                    SearchIndexItemActionActionType::Command => EntrypointActionType::Command,
+                    SearchIndexItemActionActionType::View => EntrypointActionType::View,
+                },
+                shortcut: action.shortcut,
+            })
+            .collect();
+
+        Self {
+            entrypoint_name: item.entrypoint_name,
+            plugin_name,
+            entrypoint_subtitle: item.entrypoint_subtitle,
+            entrypoint_keywords: item.entrypoint_keywords,
+            entrypoint_generator_name: item.entrypoint_generator_name,
+            entrypoint_type: item.entrypoint_type,
+            icon: item.entrypoint_icon.map(|icon| icon.to_vec()),
+            frecency: item.entrypoint_frecency,
+            actions,
+            accessories: item.entrypoint_accessories,
+            arguments: item.entrypoint_arguments,
+            hidden: item.entrypoint_hidden,
+            preview: item.entrypoint_preview,
+            file: item.entrypoint_file,
+        }
+    }
+
+    // whether tantivy document created from this data would be the same
+    fn same_document(&self, other: &EntrypointData) -> bool {
+        self.entrypoint_name == other.entrypoint_name
+            && self.plugin_name == other.plugin_name
+            && self.entrypoint_keywords == other.entrypoint_keywords
+    }
+}
+
+// "@clip" matches plugin named "Clipboard History", plugin name is compared in lowercase with spaces replaced by dashes
+pub fn matches_search_filter(filter: &SearchFilter, search_result: &SearchResult) -> bool {
+    if let Some(plugin_id) = &filter.plugin_id {
+        if &search_result.plugin_id != plugin_id {
+            return false
+        }
+    }
+
+    if let Some(plugin_name) = &filter.plugin_name {
+        let name = search_result.plugin_name
+            .to_lowercase()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join("-");
+
+        if !name.starts_with(&plugin_name.to_lowercase()) {
+            return false
+        }
+    }
+
+    if let Some(entrypoint_type) = &filter.entrypoint_type {
+        if &search_result.entrypoint_type != entrypoint_type {
+            return false
+        }
+    }
+
+    true
+}
+
 struct QueryParser {
     tokenizer_manager: TokenizerManager,
     entrypoint_name: Field,
+    entrypoint_name_transliterated: Field,
+    entrypoint_keywords: Field,
     plugin_name: Field,
 }
 
 impl QueryParser {
-    fn new(tokenizer_manager: TokenizerManager, entrypoint_name: Field, plugin_name: Field) -> Self {
+    fn new(tokenizer_manager: TokenizerManager, entrypoint_name: Field, entrypoint_name_transliterated: Field, entrypoint_keywords: Field, plugin_name: Field) -> Self {
         Self {
             tokenizer_manager,
             entrypoint_name,
+            entrypoint_name_transliterated,
+            entrypoint_keywords,
             plugin_name,
         }
     }
@@ -350,11 +816,15 @@ impl QueryParser {
         };
 
         let entrypoint_name_terms = terms_fn(self.entrypoint_name);
+        let entrypoint_name_transliterated_terms = terms_fn(self.entrypoint_name_transliterated);
+        let entrypoint_keywords_terms = terms_fn(self.entrypoint_keywords);
         let plugin_name_terms = terms_fn(self.plugin_name);
 
         Box::new(
             BooleanQuery::union(vec![
                 Box::new(entrypoint_name_terms),
+                Box::new(entrypoint_name_transliterated_terms),
+                Box::new(entrypoint_keywords_terms),
                 Box::new(plugin_name_terms),
             ]),
         )
@@ -363,15 +833,246 @@ impl QueryParser {
     fn tokenize(&self, query: &str) -> Vec<String> {
         let mut text_analyzer = self
             .tokenizer_manager
-            .get("default")
-            .expect("default tokenizer should exist");
+            .get(TOKENIZER_NAME)
+            .expect("gauntlet tokenizer should exist");
+
+        analyze(&mut text_analyzer, query)
+    }
 
-        let mut terms: Vec<String> = Vec::new();
-        let mut token_stream = text_analyzer.token_stream(query);
+    // same as "contains" query created for the field
+    fn contains_terms(&self, text: &str, terms: &[String]) -> bool {
+        tokens_contain_terms(&self.tokenize(text), terms)
+    }
+
+    // finds where terms occur in the original text, text is normalized by the same tokenizer as terms,
+    // but character by character, to be able to map positions back to the original text
+    fn highlight(&self, text: &str, terms: &[String]) -> Vec<SearchResultHighlight> {
+        let mut text_analyzer = self
+            .tokenizer_manager
+            .get(TOKENIZER_NAME)
+            .expect("gauntlet tokenizer should exist");
+
+        let mut tokens = vec![];
+        let mut token_stream = text_analyzer.token_stream(text);
         token_stream.process(&mut |token| {
-            terms.push(token.text.to_string());
+            tokens.push((token.offset_from, token.offset_to));
         });
 
-        terms
+        let mut highlights: Vec<SearchResultHighlight> = vec![];
+
+        for (offset_from, offset_to) in tokens {
+            // normalized token and offset in original text for every byte of it
+            let mut normalized = String::new();
+            let mut offsets = vec![];
+
+            for (char_offset, char) in text[offset_from..offset_to].char_indices() {
+                let start = offset_from + char_offset;
+                let end = start + char.len_utf8();
+
+                let normalized_char = self.tokenize(&text[start..end]).join("");
+
+                for _ in 0..normalized_char.len() {
+                    offsets.push((start, end));
+                }
+
+                normalized.push_str(&normalized_char);
+            }
+
+            for term in terms {
+                if term.is_empty() {
+                    continue
+                }
+
+                for (index, _) in normalized.match_indices(term.as_str()) {
+                    highlights.push(SearchResultHighlight {
+                        start: offsets[index].0,
+                        end: offsets[index + term.len() - 1].1,
+                    });
+                }
+            }
+        }
+
+        highlights.sort_by_key(|highlight| highlight.start);
+
+        // merge overlapping ranges
+        let mut result: Vec<SearchResultHighlight> = vec![];
+
+        for highlight in highlights {
+            match result.last_mut() {
+                Some(last) if highlight.start <= last.end => {
+                    last.end = last.end.max(highlight.end);
+                }
+                _ => result.push(highlight),
+            }
+        }
+
+        result
+    }
+}
+
+fn text_analyzer() -> TextAnalyzer {
+    TextAnalyzer::builder(SimpleTokenizer::default())
+        .filter(RemoveLongFilter::limit(40))
+        .filter(LowerCaser)
+        .filter(AsciiFoldingFilter)
+        .build()
+}
+
+fn analyze(text_analyzer: &mut TextAnalyzer, text: &str) -> Vec<String> {
+    let mut tokens = vec![];
+    let mut token_stream = text_analyzer.token_stream(text);
+    token_stream.process(&mut |token| {
+        tokens.push(token.text.to_string());
+    });
+
+    tokens
+}
+
+fn tokens_contain_terms(tokens: &[String], terms: &[String]) -> bool {
+    terms.iter().all(|term| tokens.iter().any(|token| token.contains(term.as_str())))
+}
+
+// matches text which is not in the index the same way as search matches entrypoint names,
+// every term of the query has to be contained in one of the words of the text
+pub fn matches_query(text: &str, query: &str) -> bool {
+    let mut text_analyzer = text_analyzer();
+
+    let terms = analyze(&mut text_analyzer, query);
+
+    if terms.is_empty() {
+        return false
+    }
+
+    tokens_contain_terms(&analyze(&mut text_analyzer, text), &terms)
+}
+
+fn transliterate(text: &str, transliteration: &[SearchTransliteration]) -> Vec<String> {
+    let mut result = vec![];
+
+    for transliteration in transliteration {
+        match transliteration {
+            SearchTransliteration::Chinese => {
+                let mut full = String::new();
+                let mut initials = String::new();
+                let mut contains_chinese = false;
+
+                // characters without pinyin are kept as is
+                for (char, pinyin) in text.chars().zip(text.to_pinyin()) {
+                    match pinyin {
+                        Some(pinyin) => {
+                            contains_chinese = true;
+                            full.push_str(pinyin.plain());
+                            initials.push_str(pinyin.first_letter());
+                        }
+                        None => {
+                            full.push(char);
+                            initials.push(char);
+                        }
+                    }
+                }
+
+                if contains_chinese {
+                    result.push(full);
+                    result.push(initials);
+                }
+            }
+            SearchTransliteration::Japanese => {
+                let contains_kana = text.chars()
+                    .any(|char| matches!(char, '\u{3040}'..='\u{30FF}'));
+
+                if contains_kana {
+                    result.push(text.to_romaji());
+                }
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use gauntlet_common::model::{UiRequestData, UiResponseData};
+    use gauntlet_utils::channel::channel;
+
+    use super::*;
+
+    fn search_index(dir: &tempfile::TempDir, names: &[&str]) -> SearchIndex {
+        // search results update is never requested, so frontend side of the channel is not needed
+        let (frontend_sender, _frontend_receiver) = channel::<UiRequestData, UiResponseData>();
+
+        let search_index = SearchIndex::create_index(FrontendApi::new(frontend_sender), dir.path().join("search_index")).unwrap();
+
+        let search_items = names.iter()
+            .enumerate()
+            .map(|(index, name)| SearchIndexItem {
+                entrypoint_type: SearchResultEntrypointType::Command,
+                entrypoint_name: name.to_string(),
+                entrypoint_subtitle: None,
+                entrypoint_keywords: vec![],
+                entrypoint_generator_name: None,
+                entrypoint_id: EntrypointId::from_string(format!("entrypoint-{}", index)),
+                entrypoint_icon: None,
+                entrypoint_frecency: 0.0,
+                entrypoint_actions: vec![],
+                entrypoint_accessories: vec![],
+                entrypoint_arguments: vec![],
+                entrypoint_hidden: false,
+                entrypoint_preview: None,
+                entrypoint_file: None,
+            })
+            .collect();
+
+        search_index.save_for_plugin(PluginId::from_string("test://plugin"), "Test".to_string(), search_items, false).unwrap();
+
+        search_index
+    }
+
+    fn search(search_index: &SearchIndex, query: &str) -> Vec<String> {
+        search_index.search(query, &SearchFilter::default(), &SearchResultsSort::Relevance)
+            .unwrap()
+            .into_iter()
+            .map(|result| result.entrypoint_name)
+            .collect()
+    }
+
+    #[test]
+    fn diacritics_are_ignored() {
+        let dir = tempfile::tempdir().unwrap();
+        let search_index = search_index(&dir, &["Café Menu", "Zürich Weather"]);
+
+        assert_eq!(search(&search_index, "cafe"), vec!["Café Menu"]);
+        assert_eq!(search(&search_index, "CAFÉ"), vec!["Café Menu"]);
+        assert_eq!(search(&search_index, "zurich"), vec!["Zürich Weather"]);
+    }
+
+    #[test]
+    fn transliterated_names_are_searchable_only_when_enabled() {
+        let dir = tempfile::tempdir().unwrap();
+        let search_index = search_index(&dir, &["微信", "カメラ"]);
+
+        assert!(search(&search_index, "weixin").is_empty());
+
+        search_index.set_transliteration(vec![SearchTransliteration::Chinese, SearchTransliteration::Japanese]).unwrap();
+
+        assert_eq!(search(&search_index, "weixin"), vec!["微信"]);
+        assert_eq!(search(&search_index, "wx"), vec!["微信"]);
+        assert_eq!(search(&search_index, "kamera"), vec!["カメラ"]);
+
+        search_index.set_transliteration(vec![]).unwrap();
+
+        assert!(search(&search_index, "weixin").is_empty());
+    }
+
+    #[test]
+    fn chinese_transliteration_keeps_other_characters() {
+        assert_eq!(transliterate("微信 2", &[SearchTransliteration::Chinese]), vec!["weixin 2", "wx 2"]);
+        assert!(transliterate("Settings", &[SearchTransliteration::Chinese]).is_empty());
+    }
+
+    #[test]
+    fn japanese_transliteration_is_only_for_kana() {
+        assert_eq!(transliterate("カメラ", &[SearchTransliteration::Japanese]), vec!["kamera"]);
+        assert!(transliterate("設定", &[SearchTransliteration::Japanese]).is_empty());
     }
 }
@@ -37,6 +37,14 @@ impl<Res> ResponseReceiver<Res> {
             .await
             .expect("oneshot was dropped before sending")
     }
+
+    // same as recv, but doesn't panic if the other side was dropped without responding
+    pub async fn recv_checked(&mut self) -> Result<Res, RequestError> {
+        self.response_receiver.take()
+            .expect("recv was called second time")
+            .await
+            .map_err(|_| RequestError::OtherSideWasDropped)
+    }
 }
 
 
@@ -67,7 +75,8 @@ impl<Req: std::fmt::Debug, Res: std::fmt::Debug> RequestSender<Req, Res> {
 
         let duration = Duration::from_secs(30);
 
-        let result = tokio::time::timeout(duration, receiver.recv()).await?;
+        // responder can be dropped without responding, e.g. when connection to remote server is lost
+        let result = tokio::time::timeout(duration, receiver.recv_checked()).await??;
 
         Ok(result)
     }
@@ -99,6 +108,15 @@ impl<Req, Res> RequestReceiver<Req, Res> {
             .await
             .expect("the other side of a channel was dropped")
     }
+
+    // returns none after the other side was dropped
+    pub async fn recv_checked(&mut self) -> Option<Payload<Req, Res>> {
+        self.request_receiver.recv().await
+    }
+
+    pub fn try_recv(&mut self) -> Option<Payload<Req, Res>> {
+        self.request_receiver.try_recv().ok()
+    }
 }
 
 impl<Res: std::fmt::Debug> Responder<Res> {